@@ -1,19 +1,151 @@
-use {crate::traits::ValueObject, lazy_static::lazy_static, nutype::nutype, regex::Regex};
+use {
+    crate::{
+        result::{ValobsError, ValobsResult},
+        traits::ValueObject,
+    },
+    lazy_static::lazy_static,
+    regex::Regex,
+    serde::{Deserialize, Serialize},
+    std::str::FromStr,
+};
 
 lazy_static! {
     static ref EMAIL_REGEX: Regex =
         Regex::new("[A-Z0-9a-z._%+-]+@[A-Za-z0-9.-]+\\.[A-Za-z]{2,64}").unwrap();
 }
 
-#[nutype(
-    sanitize(trim, lowercase),
-    validate(
-        len_char_min = 5,
-        len_char_max = 20,
-        regex = EMAIL_REGEX,
-    ),
-    derive(Debug, PartialEq, Eq, AsRef, Serialize, Deserialize),
-)]
+/// The minimum number of characters of an email address.
+const MIN_LENGTH: usize = 5;
+
+/// The maximum number of characters of an email address.
+const MAX_LENGTH: usize = 20;
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
 pub struct Email(String);
 
+impl Email {
+    /// Create a new `Email` instance.
+    ///
+    /// Surrounding whitespace is removed and letters are converted to lower case before the address is validated.
+    pub fn new(value: &str) -> ValobsResult<Email> {
+        let email = value.trim().to_lowercase();
+        let length = email.chars().count();
+        if !(MIN_LENGTH..=MAX_LENGTH).contains(&length) {
+            return Err(ValobsError::InvalidLength {
+                field: "email",
+                length,
+                min: MIN_LENGTH,
+                max: MAX_LENGTH,
+            });
+        }
+        if !EMAIL_REGEX.is_match(&email) {
+            return Err(ValobsError::InvalidFormat {
+                field: "email",
+                value: value.into(),
+            });
+        }
+        Ok(Self(email))
+    }
+
+    /// Get the email address, such as `john@example.com`.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
 impl<'de> ValueObject<'de> for Email {}
+
+impl AsRef<str> for Email {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl TryFrom<String> for Email {
+    type Error = ValobsError;
+
+    fn try_from(value: String) -> ValobsResult<Self> {
+        Self::new(&value)
+    }
+}
+
+impl From<Email> for String {
+    fn from(email: Email) -> Self {
+        email.0
+    }
+}
+
+impl FromStr for Email {
+    type Err = ValobsError;
+
+    fn from_str(email: &str) -> ValobsResult<Self> {
+        Self::new(email)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_email_with_valid_data() -> ValobsResult<()> {
+        // Arrange
+        let email = " John@Example.com ";
+
+        // Act
+        let result: Email = email.parse()?;
+
+        // Assert
+        assert_eq!(result.as_ref(), "john@example.com");
+
+        Ok(())
+    }
+
+    #[test]
+    fn fails_to_parse_email_with_invalid_data() {
+        assert_eq!(
+            "a@b".parse::<Email>(),
+            Err(ValobsError::InvalidLength {
+                field: "email",
+                length: 3,
+                min: 5,
+                max: 20,
+            })
+        );
+        assert_eq!(
+            "not-an-email".parse::<Email>(),
+            Err(ValobsError::InvalidFormat {
+                field: "email",
+                value: "not-an-email".into(),
+            })
+        );
+    }
+
+    #[test]
+    fn fails_to_create_email_longer_than_max_length() {
+        assert_eq!(
+            Email::new("someone@example.co.uk"),
+            Err(ValobsError::InvalidLength {
+                field: "email",
+                length: 21,
+                min: 5,
+                max: 20,
+            })
+        );
+    }
+
+    #[test]
+    fn deserialize_email_from_json() {
+        // Act
+        let result = serde_json::from_str::<Email>(r#"" John@Example.com ""#).unwrap();
+
+        // Assert
+        assert_eq!(result, Email::new("john@example.com").unwrap());
+        assert_eq!(
+            serde_json::to_string(&result).unwrap(),
+            r#""john@example.com""#
+        );
+        assert!(serde_json::from_str::<Email>(r#""not-an-email""#).is_err());
+    }
+}
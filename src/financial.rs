@@ -1,7 +1,160 @@
+mod allocation;
+mod bic;
 mod currency;
+mod discount;
+mod exchange_rate;
+mod format;
+mod iban;
+mod invoice;
 mod money;
+mod money_bag;
+mod price;
+mod rounding;
+mod rusty;
+mod securities;
+
+/// Tax calculation
+///
+/// This module splits prices into their net amounts and taxes, such as VAT, GST or sales tax.
+/// Every calculation is done with exact decimals on top of [Money], and every breakdown reconciles to the minor unit.
+///
+/// ## Example
+///
+/// ```
+/// use valobs::financial::{Currency, Decimal, Money, tax::{TaxablePrice, TaxCalculator, TaxRate}};
+/// use valobs::result::ValobsResult;
+///
+/// fn main() -> ValobsResult<()> {
+///   let calculator = TaxCalculator::new(vec![TaxRate::from_percent(Decimal::from(10))?]);
+///   let result = calculator.breakdown(&TaxablePrice::Exclusive(Money::new(1000, Currency::AUD)))?;
+///
+///   assert_eq!(result.gross(), Money::new(1100, Currency::AUD));
+///
+///   Ok(())
+/// }
+/// ```
+///
+/// ## Value objects
+///
+/// - [TaxRate](crate::financial::tax::TaxRate)
+/// - [TaxablePrice](crate::financial::tax::TaxablePrice)
+/// - [TaxBreakdown](crate::financial::tax::TaxBreakdown)
+pub mod tax;
+
+/// Serde representations of money
+///
+/// This module contains helpers for `#[serde(with = ...)]`, which serialize [Money] in other shapes than its default
+/// `{"amount":1234,"currency":"USD"}`. Amounts are always written as strings, so they keep their precision in JavaScript.
+///
+/// [decimal_object](crate::financial::money_serde::decimal_object) and [compact](crate::financial::money_serde::compact)
+/// keep the currency next to the amount. [decimal_string](crate::financial::money_serde::decimal_string) and
+/// [minor_units](crate::financial::money_serde::minor_units) write the bare amount, for APIs that give the currency
+/// elsewhere, and deserialize it in a currency given by the caller.
+///
+/// ## Example
+///
+/// ```
+/// use serde::{Deserialize, Serialize};
+/// use valobs::financial::{Currency, Money, money_serde};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Payment {
+///   #[serde(with = "money_serde::decimal_object")]
+///   amount: Money,
+/// }
+///
+/// let json = r#"{"amount":{"value":"12.34","currency":"EUR"}}"#;
+/// let payment: Payment = serde_json::from_str(json).unwrap();
+///
+/// assert_eq!(payment.amount, Money::new(1234, Currency::EUR));
+/// ```
+///
+/// ## Representations
+///
+/// - [decimal_string](crate::financial::money_serde::decimal_string): `"12.34"`
+/// - [minor_units](crate::financial::money_serde::minor_units): `"1234"`
+/// - [decimal_object](crate::financial::money_serde::decimal_object): `{"value":"12.34","currency":"USD"}`
+/// - [compact](crate::financial::money_serde::compact): `"USD 12.34"`
+pub mod money_serde;
+
+/// Payment cards
+///
+/// This module validates the numbers, expiry dates and security codes of payment cards.
+/// Card numbers are masked by `Debug`, `Display` and `Serialize`, so they don't end up in logs by accident.
+///
+/// ## Example
+///
+/// ```
+/// use valobs::financial::card::{CardBrand, CardExpiry, CardNumber, Cvv};
+/// use valobs::result::ValobsResult;
+///
+/// fn main() -> ValobsResult<()> {
+///   let number: CardNumber = "5555 5555 5555 4444".parse()?;
+///   let expiry: CardExpiry = "09/30".parse()?;
+///   let cvv = Cvv::new("737", number.brand())?;
+///
+///   assert_eq!(number.brand(), CardBrand::Mastercard);
+///   assert_eq!(number.to_string(), "**** **** **** 4444");
+///
+///   Ok(())
+/// }
+/// ```
+///
+/// ## Value objects
+///
+/// - [CardNumber](crate::financial::card::CardNumber)
+/// - [CardExpiry](crate::financial::card::CardExpiry)
+/// - [Cvv](crate::financial::card::Cvv)
+pub mod card;
+
+/// Interest and loan amortization
+///
+/// This module accrues interest with day count conventions, and splits loans into dated installments of interest and principal.
+/// Every schedule reconciles exactly to the amount borrowed.
+///
+/// ## Example
+///
+/// ```
+/// use valobs::financial::{Currency, Money, loan::{DayCount, Loan}};
+/// use valobs::numeric::Percentage;
+/// use valobs::result::ValobsResult;
+/// use valobs::temporal::Date;
+///
+/// fn main() -> ValobsResult<()> {
+///   let loan = Loan::new(
+///     Money::new(120_000, Currency::EUR),
+///     Percentage::from_basis_points(500),
+///     24,
+///     Date::from_ymd_opt(2024, 6, 1).unwrap(),
+///   )?
+///   .with_day_count(DayCount::Actual365);
+///
+///   assert_eq!(loan.schedule()?.total_principal()?, Money::new(120_000, Currency::EUR));
+///
+///   Ok(())
+/// }
+/// ```
+///
+/// ## Value objects
+///
+/// - [DayCount](crate::financial::loan::DayCount)
+/// - [Loan](crate::financial::loan::Loan)
+/// - [AmortizationSchedule](crate::financial::loan::AmortizationSchedule)
+pub mod loan;
 
 pub use {
+    allocation::Allocation,
+    bic::Bic,
     currency::Currency,
+    discount::{Discount, DiscountKind},
+    exchange_rate::{ExchangeRate, RateProvider, StaticRateProvider},
+    format::{CurrencyDisplay, Locale, MoneyFormatter},
+    iban::Iban,
+    invoice::{Invoice, InvoiceLine},
     money::{Money, MoneyAmount},
+    money_bag::MoneyBag,
+    price::Price,
+    rounding::RoundingMode,
+    rust_decimal::Decimal,
+    securities::{Cusip, Isin, Lei, Sedol},
 };
@@ -0,0 +1,298 @@
+use super::money::{Money, MoneyAmount};
+use crate::result::{ValobsError, ValobsResult};
+use rust_decimal::{
+    prelude::{FromPrimitive, ToPrimitive},
+    Decimal,
+};
+use std::cmp::Reverse;
+
+/// A set of ratios to split money by, such that the parts always add up to the original amount.
+///
+/// ## How does it work?
+///
+/// Each part first gets its exact share of the amount, rounded down to the minor unit of the currency.
+/// The minor units left over are then handed out one by one to the parts with the largest remainders,
+/// and to the first of them when remainders are equal. This is known as the _largest remainder method_.
+///
+/// The ratios are compared exactly, so `1:1:1` and `0.5:0.5:0.5` split money the same way.
+/// By default, every ratio must be greater than zero. Use [Allocation::allow_zero_parts]
+/// to accept ratios of zero, whose parts get no money.
+///
+/// ## When to use
+///
+/// Use it to split a bill, prorate a discount across invoice lines, or distribute a payment between accounts,
+/// where losing or inventing a cent is not acceptable.
+///
+/// ## Example
+///
+/// ```
+/// use valobs::financial::{Allocation, Currency, Money};
+/// use valobs::result::ValobsResult;
+///
+/// fn main() -> ValobsResult<()> {
+///   let money = Money::new(100, Currency::USD);
+///
+///   // Split 1.00 USD into three equal parts
+///   let result = Allocation::from_integers(&[1, 1, 1]).allocate(&money)?;
+///
+///   assert_eq!(result, vec![
+///     Money::new(34, Currency::USD),
+///     Money::new(33, Currency::USD),
+///     Money::new(33, Currency::USD),
+///   ]);
+///
+///   // Give nothing to the second part
+///   let result = Allocation::from_integers(&[1, 0, 1]).allow_zero_parts().allocate(&money)?;
+///
+///   assert_eq!(result[1], Money::zero(Currency::USD));
+///
+///   Ok(())
+/// }
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Allocation {
+    ratios: Vec<Decimal>,
+    allow_zero_parts: bool,
+}
+
+impl Allocation {
+    /// Create a new `Allocation` instance from integer ratios, such as `1:2:3`.
+    pub fn from_integers(ratios: &[u64]) -> Self {
+        Self::from_decimals(
+            &ratios
+                .iter()
+                .map(|&ratio| Decimal::from(ratio))
+                .collect::<Vec<_>>(),
+        )
+    }
+
+    /// Create a new `Allocation` instance from decimal ratios, such as `0.2:0.3:0.5`.
+    pub fn from_decimals(ratios: &[Decimal]) -> Self {
+        Self {
+            ratios: ratios.to_vec(),
+            allow_zero_parts: false,
+        }
+    }
+
+    /// Create a new `Allocation` instance from floating point ratios.
+    ///
+    /// Each ratio is converted to the shortest decimal that rounds to it, so `0.1` is taken as exactly `0.1`.
+    pub fn from_floats(ratios: &[f64]) -> ValobsResult<Self> {
+        let ratios = ratios
+            .iter()
+            .map(|&ratio| {
+                if ratio.is_nan() {
+                    return Err(ValobsError::NotANumber { field: "ratio" });
+                }
+                if ratio.is_infinite() {
+                    return Err(ValobsError::Infinite {
+                        field: "ratio",
+                        value: ratio,
+                    });
+                }
+                Decimal::from_f64(ratio).ok_or(ValobsError::Overflow { field: "ratio" })
+            })
+            .collect::<ValobsResult<Vec<_>>>()?;
+        Ok(Self::from_decimals(&ratios))
+    }
+
+    /// Accept ratios of zero, whose parts get no money.
+    ///
+    /// At least one ratio must still be greater than zero.
+    pub fn allow_zero_parts(mut self) -> Self {
+        self.allow_zero_parts = true;
+        self
+    }
+
+    /// Get the ratios.
+    pub fn ratios(&self) -> &[Decimal] {
+        &self.ratios
+    }
+
+    /// Split money by the ratios.
+    ///
+    /// The result has one part for each ratio, in the same order, and the parts add up to the original amount.
+    /// A negative amount is split the same way as a positive one, and every part is negative.
+    /// Without ratios, the result is empty.
+    pub fn allocate(&self, money: &Money) -> ValobsResult<Vec<Money>> {
+        if self.ratios.is_empty() {
+            return Ok(Vec::new());
+        }
+        let weights = self.weights()?;
+        let total = weights
+            .iter()
+            .try_fold(0u128, |total, weight| total.checked_add(*weight))
+            .ok_or(ValobsError::Overflow { field: "ratio" })?;
+        if total == 0 {
+            return Err(ValobsError::NotPositive {
+                field: "ratio",
+                value: 0.0,
+            });
+        }
+
+        let amount = u128::from(money.amount().unsigned_abs());
+        let mut shares = Vec::with_capacity(weights.len());
+        let mut remainders = Vec::with_capacity(weights.len());
+        for (index, weight) in weights.iter().enumerate() {
+            let product = amount
+                .checked_mul(*weight)
+                .ok_or(ValobsError::Overflow { field: "ratio" })?;
+            shares.push(product / total);
+            remainders.push((Reverse(product % total), index));
+        }
+
+        let leftover = amount - shares.iter().sum::<u128>();
+        remainders.sort_unstable();
+        for &(_, index) in remainders.iter().take(leftover as usize) {
+            shares[index] += 1;
+        }
+
+        Ok(shares
+            .into_iter()
+            .map(|share| {
+                // Each share is at most the original amount, so it always fits.
+                let share = share as i128 * i128::from(money.amount().signum());
+                Money::new(share as MoneyAmount, *money.currency())
+            })
+            .collect())
+    }
+
+    /// Validates the ratios, and scales them into integers with the same proportions.
+    fn weights(&self) -> ValobsResult<Vec<u128>> {
+        let ratios = self
+            .ratios
+            .iter()
+            .map(|ratio| ratio.normalize())
+            .collect::<Vec<_>>();
+        for ratio in &ratios {
+            if ratio.is_sign_negative() || (ratio.is_zero() && !self.allow_zero_parts) {
+                return Err(ValobsError::NotPositive {
+                    field: "ratio",
+                    value: ratio.to_f64().unwrap_or_default(),
+                });
+            }
+        }
+
+        let scale = ratios.iter().map(Decimal::scale).max().unwrap_or_default();
+        ratios
+            .iter()
+            .map(|ratio| {
+                10u128
+                    .checked_pow(scale - ratio.scale())
+                    .and_then(|factor| (ratio.mantissa() as u128).checked_mul(factor))
+                    .ok_or(ValobsError::Overflow { field: "ratio" })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::financial::Currency;
+
+    fn amounts(parts: &[Money]) -> Vec<MoneyAmount> {
+        parts.iter().map(Money::amount).collect()
+    }
+
+    #[test]
+    fn allocate_money_by_largest_remainder() -> ValobsResult<()> {
+        // Arrange
+        let money = Money::new(100, Currency::USD);
+
+        // Act
+        let equal = Allocation::from_integers(&[1, 1, 1]).allocate(&money)?;
+        let weighted = Allocation::from_integers(&[1, 2, 3]).allocate(&money)?;
+        let decimal = Allocation::from_decimals(&[Decimal::new(7, 1), Decimal::new(3, 2)])
+            .allocate(&money)?;
+
+        // Assert
+        assert_eq!(amounts(&equal), vec![34, 33, 33]);
+        assert_eq!(amounts(&weighted), vec![17, 33, 50]);
+        assert_eq!(amounts(&decimal), vec![96, 4]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn parts_always_add_up_to_the_original_amount() -> ValobsResult<()> {
+        for amount in [
+            -1001,
+            -1,
+            0,
+            1,
+            7,
+            100,
+            999,
+            MoneyAmount::MAX,
+            MoneyAmount::MIN,
+        ] {
+            // Arrange
+            let money = Money::new(amount, Currency::USD);
+
+            for ratios in [
+                &[1.0, 1.0, 1.0][..],
+                &[0.1, 0.2, 0.7],
+                &[3.0, 7.0, 11.0, 13.0],
+            ] {
+                // Act
+                let result = Allocation::from_floats(ratios)?.allocate(&money)?;
+
+                // Assert
+                let total: i128 = result.iter().map(|part| i128::from(part.amount())).sum();
+                assert_eq!(total, i128::from(amount), "{amount} by {ratios:?}");
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn allocate_money_with_zero_parts() -> ValobsResult<()> {
+        // Arrange
+        let money = Money::new(-100, Currency::USD);
+        let allocation = Allocation::from_integers(&[0, 1, 2]);
+
+        // Act
+        let result = allocation.clone().allow_zero_parts().allocate(&money)?;
+
+        // Assert
+        assert_eq!(amounts(&result), vec![0, -33, -67]);
+        assert_eq!(
+            allocation.allocate(&money),
+            Err(ValobsError::NotPositive {
+                field: "ratio",
+                value: 0.0,
+            })
+        );
+        assert!(Allocation::from_integers(&[0, 0])
+            .allow_zero_parts()
+            .allocate(&money)
+            .is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn fails_to_allocate_money_by_invalid_ratios() {
+        assert_eq!(
+            Allocation::from_floats(&[1.0, f64::NAN]),
+            Err(ValobsError::NotANumber { field: "ratio" })
+        );
+        assert_eq!(
+            Allocation::from_floats(&[1.0, f64::INFINITY]),
+            Err(ValobsError::Infinite {
+                field: "ratio",
+                value: f64::INFINITY,
+            })
+        );
+        assert_eq!(
+            Allocation::from_decimals(&[Decimal::ONE, Decimal::NEGATIVE_ONE])
+                .allocate(&Money::new(100, Currency::USD)),
+            Err(ValobsError::NotPositive {
+                field: "ratio",
+                value: -1.0,
+            })
+        );
+    }
+}
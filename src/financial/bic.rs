@@ -0,0 +1,231 @@
+use crate::{
+    geography::Country,
+    result::{ValobsError, ValobsResult},
+    traits::ValueObject,
+};
+use serde::{Deserialize, Serialize};
+use std::{fmt, str::FromStr};
+
+/// A value object representing a Business Identifier Code (BIC), as defined by ISO 9362.
+///
+/// ## What is a _BIC_?
+///
+/// A BIC, also known as a SWIFT code, identifies a bank or another financial institution.
+/// It has 8 or 11 characters, such as `DEUTDEFF500`:
+///
+/// - a 4 character _business party prefix_, or bank code, such as `DEUT`
+/// - the 2 letter alpha-2 code of the country of the institution, such as `DE`
+/// - a 2 character _business party suffix_, or location code, such as `FF`
+/// - an optional 3 character branch code, such as `500`. A BIC without a branch code, or with the branch code `XXX`,
+///   identifies the primary office of the institution
+///
+/// ## When to use
+///
+/// Use it alongside an [Iban](crate::financial::Iban) for international transfers, or to identify a counterparty bank.
+///
+/// ## Example
+///
+/// ```
+/// use valobs::financial::Bic;
+/// use valobs::geography::Country;
+/// use valobs::result::ValobsResult;
+///
+/// fn main() -> ValobsResult<()> {
+///   let bic: Bic = "DEUTDEFF500".parse()?;
+///
+///   assert_eq!(bic.bank_code(), "DEUT");
+///   assert_eq!(bic.country(), Some(Country::DEU));
+///   assert_eq!(bic.location_code(), "FF");
+///   assert_eq!(bic.branch_code(), Some("500"));
+///   assert!(!bic.is_primary_office());
+///
+///   Ok(())
+/// }
+/// ```
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct Bic(String);
+
+impl Bic {
+    /// Create a new `Bic` instance from 8 or 11 characters.
+    ///
+    /// Surrounding whitespace is removed and letters are converted to upper case before the BIC is validated.
+    pub fn new(value: &str) -> ValobsResult<Bic> {
+        let bic = value.trim().to_ascii_uppercase();
+        if bic.is_empty() {
+            return Err(ValobsError::Empty { field: "bic" });
+        }
+        if bic.len() != 8 && bic.len() != 11 {
+            return Err(ValobsError::InvalidLength {
+                field: "bic",
+                length: bic.chars().count(),
+                min: 8,
+                max: 11,
+            });
+        }
+        if !bic.chars().all(|char| char.is_ascii_alphanumeric())
+            || !bic[4..6].chars().all(|char| char.is_ascii_alphabetic())
+        {
+            return Err(ValobsError::InvalidFormat {
+                field: "bic",
+                value: value.into(),
+            });
+        }
+        if &bic[4..6] != "XK" && Country::from_alpha2(&bic[4..6]).is_none() {
+            return Err(ValobsError::UnknownCode {
+                field: "bic",
+                value: bic[4..6].into(),
+            });
+        }
+        Ok(Self(bic))
+    }
+
+    /// Get the BIC, such as `DEUTDEFF500`.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Get the business party prefix, which identifies the institution, such as `DEUT`.
+    pub fn bank_code(&self) -> &str {
+        &self.0[..4]
+    }
+
+    /// Get the alpha-2 code of the country of the institution, such as `DE`.
+    pub fn country_code(&self) -> &str {
+        &self.0[4..6]
+    }
+
+    /// Get the country of the institution.
+    ///
+    /// It is `None` for Kosovo, whose code `XK` is used by SWIFT but not assigned by ISO 3166-1.
+    pub fn country(&self) -> Option<Country> {
+        Country::from_alpha2(self.country_code())
+    }
+
+    /// Get the business party suffix, which identifies the location of the institution, such as `FF`.
+    pub fn location_code(&self) -> &str {
+        &self.0[6..8]
+    }
+
+    /// Get the branch code, such as `500`, if the BIC has 11 characters.
+    pub fn branch_code(&self) -> Option<&str> {
+        self.0.get(8..).filter(|code| !code.is_empty())
+    }
+
+    /// Returns `true` if the BIC identifies the primary office of the institution,
+    /// because it has no branch code, or the branch code `XXX`.
+    pub fn is_primary_office(&self) -> bool {
+        matches!(self.branch_code(), None | Some("XXX"))
+    }
+
+    /// Returns `true` if the BIC is a test and training code, whose location code ends with `0`.
+    pub fn is_test(&self) -> bool {
+        self.location_code().ends_with('0')
+    }
+}
+
+impl ValueObject<'_> for Bic {}
+
+impl AsRef<str> for Bic {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl TryFrom<String> for Bic {
+    type Error = ValobsError;
+
+    fn try_from(value: String) -> ValobsResult<Self> {
+        Self::new(&value)
+    }
+}
+
+impl From<Bic> for String {
+    fn from(bic: Bic) -> Self {
+        bic.0
+    }
+}
+
+impl fmt::Display for Bic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl FromStr for Bic {
+    type Err = ValobsError;
+
+    fn from_str(value: &str) -> ValobsResult<Self> {
+        Self::new(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn create_bic() -> ValobsResult<()> {
+        // Act
+        let result = Bic::new(" nedszajj ")?;
+
+        // Assert
+        assert_eq!(result.as_str(), "NEDSZAJJ");
+        assert_eq!(result.bank_code(), "NEDS");
+        assert_eq!(result.country(), Some(Country::ZAF));
+        assert_eq!(result.location_code(), "JJ");
+        assert_eq!(result.branch_code(), None);
+        assert!(result.is_primary_office());
+        assert!(!result.is_test());
+        assert!(Bic::new("DEUTDEFFXXX")?.is_primary_office());
+        assert!(Bic::new("DEUTDEF0")?.is_test());
+
+        Ok(())
+    }
+
+    #[test]
+    fn fails_to_create_invalid_bic() {
+        assert_eq!(Bic::new(""), Err(ValobsError::Empty { field: "bic" }));
+        assert_eq!(
+            Bic::new("DEUTDEFF50"),
+            Err(ValobsError::InvalidLength {
+                field: "bic",
+                length: 10,
+                min: 8,
+                max: 11,
+            })
+        );
+        assert_eq!(
+            Bic::new("DEUT12FF"),
+            Err(ValobsError::InvalidFormat {
+                field: "bic",
+                value: "DEUT12FF".into(),
+            })
+        );
+        assert_eq!(
+            Bic::new("DEUTZZFF"),
+            Err(ValobsError::UnknownCode {
+                field: "bic",
+                value: "ZZ".into(),
+            })
+        );
+        assert!(Bic::new("DEUT-DEFF").is_err());
+    }
+
+    #[test]
+    fn serialize_bic_to_json() -> ValobsResult<()> {
+        // Arrange
+        let bic = Bic::new("deutdeff500")?;
+
+        // Act
+        let json = serde_json::to_string(&bic).unwrap();
+        let result = serde_json::from_str::<Bic>(&json).unwrap();
+
+        // Assert
+        assert_eq!(json, r#""DEUTDEFF500""#);
+        assert_eq!(result, bic);
+        assert!(serde_json::from_str::<Bic>(r#""DEUTDEFF5""#).is_err());
+
+        Ok(())
+    }
+}
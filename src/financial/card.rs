@@ -0,0 +1,725 @@
+use crate::{
+    result::{ValobsError, ValobsResult},
+    temporal::{Date, Utc},
+    traits::ValueObject,
+};
+use chrono::Datelike;
+use serde::{Deserialize, Serialize, Serializer};
+use std::{fmt, str::FromStr};
+
+/// The network that issued a payment card, detected from the first digits of its number.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Serialize, Deserialize)]
+pub enum CardBrand {
+    /// American Express, starting with `34` or `37`.
+    AmericanExpress,
+    /// Diners Club International, starting with `300` to `305`, `36`, `38` or `39`.
+    DinersClub,
+    /// Discover, starting with `6011`, `622126` to `622925`, `644` to `649` or `65`.
+    Discover,
+    /// JCB, starting with `3528` to `3589`.
+    Jcb,
+    /// Maestro, starting with `5018`, `5020`, `5038`, `5893`, `6304`, `6759` or `6761` to `6763`.
+    Maestro,
+    /// Mastercard, starting with `51` to `55` or `2221` to `2720`.
+    Mastercard,
+    /// Mir, starting with `2200` to `2204`.
+    Mir,
+    /// UnionPay, starting with `62`.
+    UnionPay,
+    /// Visa, starting with `4`.
+    Visa,
+    /// A brand that is not known by this crate.
+    Unknown,
+}
+
+/// The prefix ranges of every brand, in the order they are matched, so narrow ranges come before wide ones.
+const IIN_RANGES: &[(CardBrand, u32, u32)] = &[
+    (CardBrand::AmericanExpress, 34, 34),
+    (CardBrand::AmericanExpress, 37, 37),
+    (CardBrand::DinersClub, 300, 305),
+    (CardBrand::DinersClub, 36, 36),
+    (CardBrand::DinersClub, 38, 39),
+    (CardBrand::Discover, 6011, 6011),
+    (CardBrand::Discover, 622126, 622925),
+    (CardBrand::Discover, 644, 649),
+    (CardBrand::Discover, 65, 65),
+    (CardBrand::Jcb, 3528, 3589),
+    (CardBrand::Maestro, 5018, 5018),
+    (CardBrand::Maestro, 5020, 5020),
+    (CardBrand::Maestro, 5038, 5038),
+    (CardBrand::Maestro, 5893, 5893),
+    (CardBrand::Maestro, 6304, 6304),
+    (CardBrand::Maestro, 6759, 6759),
+    (CardBrand::Maestro, 6761, 6763),
+    (CardBrand::Mastercard, 51, 55),
+    (CardBrand::Mastercard, 2221, 2720),
+    (CardBrand::Mir, 2200, 2204),
+    (CardBrand::UnionPay, 62, 62),
+    (CardBrand::Visa, 4, 4),
+];
+
+impl CardBrand {
+    /// Detect the brand of a card from the first digits of its number.
+    pub fn detect(digits: &str) -> CardBrand {
+        IIN_RANGES
+            .iter()
+            .find(|(_, start, end)| {
+                let length = start.to_string().len();
+                digits
+                    .get(..length)
+                    .and_then(|prefix| prefix.parse::<u32>().ok())
+                    .is_some_and(|prefix| (*start..=*end).contains(&prefix))
+            })
+            .map(|(brand, _, _)| *brand)
+            .unwrap_or(CardBrand::Unknown)
+    }
+
+    /// Get the minimum and maximum number of digits of a card number of the brand.
+    pub fn lengths(&self) -> (usize, usize) {
+        match self {
+            Self::AmericanExpress => (15, 15),
+            Self::DinersClub => (14, 19),
+            Self::Mastercard => (16, 16),
+            Self::Visa => (13, 19),
+            Self::Discover | Self::Jcb | Self::Mir | Self::UnionPay => (16, 19),
+            Self::Maestro | Self::Unknown => (12, 19),
+        }
+    }
+
+    /// Get the minimum and maximum number of digits of the security code of the brand.
+    pub fn cvv_lengths(&self) -> (usize, usize) {
+        match self {
+            Self::AmericanExpress => (4, 4),
+            Self::Unknown => (3, 4),
+            _ => (3, 3),
+        }
+    }
+
+    /// Get the size of each group of digits when a card number of the brand is printed.
+    fn groups(&self, length: usize) -> &'static [usize] {
+        match (self, length) {
+            (Self::AmericanExpress, 15) => &[4, 6, 5],
+            (Self::DinersClub, 14) => &[4, 6, 4],
+            _ => &[4, 4, 4, 4, 3],
+        }
+    }
+}
+
+/// A value object representing the number of a payment card, also known as the primary account number (PAN).
+///
+/// ## What is a _Card Number_?
+///
+/// A card number has 12 to 19 digits. Its first digits identify the brand and the issuer of the card,
+/// and its last digit is a check digit, computed with the Luhn algorithm.
+///
+/// ## When to use
+///
+/// Use it to validate card numbers before they are sent to a payment processor.
+///
+/// Card numbers are sensitive. `Debug`, `Display` and `Serialize` only show the last four digits,
+/// such as `**** **** **** 1111`. The full number is only available through [CardNumber::expose],
+/// [CardNumber::expose_grouped], and the [unmasked] serde helper.
+///
+/// ## Example
+///
+/// ```
+/// use valobs::financial::card::{CardBrand, CardNumber};
+/// use valobs::result::ValobsResult;
+///
+/// fn main() -> ValobsResult<()> {
+///   let number: CardNumber = "4111 1111 1111 1111".parse()?;
+///
+///   assert_eq!(number.brand(), CardBrand::Visa);
+///   assert_eq!(number.bin(), "411111");
+///   assert_eq!(number.last_four(), "1111");
+///   assert_eq!(number.to_string(), "**** **** **** 1111");
+///   assert_eq!(number.expose(), "4111111111111111");
+///   assert!("4111 1111 1111 1112".parse::<CardNumber>().is_err());
+///
+///   Ok(())
+/// }
+/// ```
+#[derive(PartialEq, Eq, Hash, Clone, Deserialize)]
+#[serde(try_from = "String")]
+pub struct CardNumber(String);
+
+impl CardNumber {
+    /// Create a new `CardNumber` instance from its digits, which may be separated by spaces or hyphens.
+    ///
+    /// The number of digits must be valid for the brand of the card, and the check digit must match.
+    /// Errors never contain the full number.
+    pub fn new(value: &str) -> ValobsResult<CardNumber> {
+        let digits = value
+            .chars()
+            .filter(|char| *char != ' ' && *char != '-')
+            .collect::<String>();
+        if digits.is_empty() {
+            return Err(ValobsError::Empty {
+                field: "card_number",
+            });
+        }
+        if !digits.chars().all(|char| char.is_ascii_digit()) {
+            return Err(ValobsError::InvalidFormat {
+                field: "card_number",
+                value: mask(&digits),
+            });
+        }
+        let (min, max) = CardBrand::detect(&digits).lengths();
+        if !(min..=max).contains(&digits.len()) {
+            return Err(ValobsError::InvalidLength {
+                field: "card_number",
+                length: digits.len(),
+                min,
+                max,
+            });
+        }
+        if !luhn(&digits) {
+            return Err(ValobsError::InvalidChecksum {
+                field: "card_number",
+                value: mask(&digits),
+            });
+        }
+        Ok(Self(digits))
+    }
+
+    /// Get the brand of the card.
+    pub fn brand(&self) -> CardBrand {
+        CardBrand::detect(&self.0)
+    }
+
+    /// Get the bank identification number (BIN), which is the first 6 digits and identifies the issuer.
+    pub fn bin(&self) -> &str {
+        &self.0[..6]
+    }
+
+    /// Get the last 4 digits, which are safe to show to the card holder.
+    pub fn last_four(&self) -> &str {
+        &self.0[self.0.len() - 4..]
+    }
+
+    /// Get the number with every digit but the last four replaced by `*`, grouped like the brand prints it.
+    pub fn masked(&self) -> String {
+        self.group(&mask(&self.0))
+    }
+
+    /// Get the full number, without separators.
+    ///
+    /// Only use it to send the number to a payment processor, and never log it.
+    pub fn expose(&self) -> &str {
+        &self.0
+    }
+
+    /// Get the full number, grouped like the brand prints it, such as `3782 822463 10005` for American Express.
+    ///
+    /// Only use it to show the number to the card holder, and never log it.
+    pub fn expose_grouped(&self) -> String {
+        self.group(&self.0)
+    }
+
+    fn group(&self, digits: &str) -> String {
+        let mut groups = Vec::new();
+        let mut start = 0;
+        for size in self.brand().groups(digits.len()) {
+            let end = digits.len().min(start + size);
+            if start < end {
+                groups.push(&digits[start..end]);
+            }
+            start = end;
+        }
+        groups.join(" ")
+    }
+}
+
+impl ValueObject<'_> for CardNumber {}
+
+impl TryFrom<String> for CardNumber {
+    type Error = ValobsError;
+
+    fn try_from(value: String) -> ValobsResult<Self> {
+        Self::new(&value)
+    }
+}
+
+impl FromStr for CardNumber {
+    type Err = ValobsError;
+
+    fn from_str(value: &str) -> ValobsResult<Self> {
+        Self::new(value)
+    }
+}
+
+impl fmt::Debug for CardNumber {
+    /// Formats the brand and the masked number, such as `CardNumber(Visa, "**** **** **** 1111")`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("CardNumber")
+            .field(&self.brand())
+            .field(&self.masked())
+            .finish()
+    }
+}
+
+impl fmt::Display for CardNumber {
+    /// Formats the masked number, such as `**** **** **** 1111`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.masked())
+    }
+}
+
+impl Serialize for CardNumber {
+    /// Serializes the masked number, such as `"**** **** **** 1111"`, which can't be deserialized back.
+    ///
+    /// Use the [unmasked] serde helper to serialize the full number.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.masked())
+    }
+}
+
+/// Serde helper that serializes the full number of a [CardNumber], for fields that must be stored or sent in full.
+///
+/// ## Example
+///
+/// ```
+/// use serde::{Deserialize, Serialize};
+/// use valobs::financial::card::{self, CardNumber};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct PaymentRequest {
+///   #[serde(with = "card::unmasked")]
+///   number: CardNumber,
+/// }
+/// ```
+pub mod unmasked {
+    use super::CardNumber;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(number: &CardNumber, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(number.expose())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<CardNumber, D::Error> {
+        CardNumber::deserialize(deserializer)
+    }
+}
+
+/// A value object representing the expiry date of a payment card, such as `12/27`.
+///
+/// A card expires at the end of its expiry month, so it can still be used on the last day of that month.
+///
+/// ## Example
+///
+/// ```
+/// use valobs::financial::card::CardExpiry;
+/// use valobs::result::ValobsResult;
+/// use valobs::temporal::Date;
+///
+/// fn main() -> ValobsResult<()> {
+///   let expiry: CardExpiry = "02/28".parse()?;
+///
+///   assert_eq!(expiry.year(), 2028);
+///   assert!(!expiry.is_expired_on(Date::from_ymd_opt(2028, 2, 29).unwrap()));
+///   assert!(expiry.is_expired_on(Date::from_ymd_opt(2028, 3, 1).unwrap()));
+///
+///   Ok(())
+/// }
+/// ```
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct CardExpiry {
+    year: i32,
+    month: u32,
+}
+
+impl CardExpiry {
+    /// Create a new `CardExpiry` instance from a month between 1 and 12, and a four-digit year.
+    pub fn new(month: u32, year: i32) -> ValobsResult<CardExpiry> {
+        if !(1..=12).contains(&month) {
+            return Err(ValobsError::OutOfRange {
+                field: "month",
+                value: month as f64,
+                min: 1.0,
+                max: 12.0,
+            });
+        }
+        if !(2000..=9999).contains(&year) {
+            return Err(ValobsError::OutOfRange {
+                field: "year",
+                value: year as f64,
+                min: 2000.0,
+                max: 9999.0,
+            });
+        }
+        Ok(Self { year, month })
+    }
+
+    /// Get the expiry month, between 1 and 12.
+    pub fn month(&self) -> u32 {
+        self.month
+    }
+
+    /// Get the four-digit expiry year.
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// Get the last day the card can be used.
+    pub fn last_day(&self) -> Date {
+        let (year, month) = if self.month == 12 {
+            (self.year + 1, 1)
+        } else {
+            (self.year, self.month + 1)
+        };
+        Date::from_ymd_opt(year, month, 1)
+            .and_then(|first_day| first_day.pred_opt())
+            .unwrap_or(Date::MAX)
+    }
+
+    /// Returns `true` if the card can no longer be used on the given date.
+    pub fn is_expired_on(&self, date: Date) -> bool {
+        date > self.last_day()
+    }
+
+    /// Returns `true` if the card can no longer be used today, in UTC.
+    pub fn is_expired(&self) -> bool {
+        self.is_expired_on(Utc::now().date_naive())
+    }
+}
+
+impl ValueObject<'_> for CardExpiry {}
+
+impl TryFrom<String> for CardExpiry {
+    type Error = ValobsError;
+
+    fn try_from(value: String) -> ValobsResult<Self> {
+        value.parse()
+    }
+}
+
+impl From<CardExpiry> for String {
+    fn from(expiry: CardExpiry) -> Self {
+        expiry.to_string()
+    }
+}
+
+impl fmt::Display for CardExpiry {
+    /// Formats the expiry date as it is printed on cards, such as `12/27`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02}/{:02}", self.month, self.year % 100)
+    }
+}
+
+impl FromStr for CardExpiry {
+    type Err = ValobsError;
+
+    /// Parses an expiry date with a two or four-digit year, such as `12/27` or `12/2027`.
+    fn from_str(value: &str) -> ValobsResult<Self> {
+        let invalid_format = || ValobsError::InvalidFormat {
+            field: "card_expiry",
+            value: value.into(),
+        };
+        let (month, year) = value.trim().split_once('/').ok_or_else(invalid_format)?;
+        let (month, year) = (month.trim(), year.trim());
+        if !matches!(month.len(), 1 | 2)
+            || !matches!(year.len(), 2 | 4)
+            || !month
+                .chars()
+                .chain(year.chars())
+                .all(|char| char.is_ascii_digit())
+        {
+            return Err(invalid_format());
+        }
+        let month = month.parse().map_err(|_| invalid_format())?;
+        let year: i32 = year.parse().map_err(|_| invalid_format())?;
+        Self::new(month, if year < 100 { 2000 + year } else { year })
+    }
+}
+
+impl From<CardExpiry> for Date {
+    /// Converts into the last day the card can be used.
+    fn from(expiry: CardExpiry) -> Self {
+        expiry.last_day()
+    }
+}
+
+impl TryFrom<Date> for CardExpiry {
+    type Error = ValobsError;
+
+    /// Converts the month of a date into an expiry date.
+    fn try_from(date: Date) -> ValobsResult<Self> {
+        Self::new(date.month(), date.year())
+    }
+}
+
+/// A value object representing the security code of a payment card, also known as CVV, CVC or CID.
+///
+/// American Express cards have a 4 digit code on their front, and other cards have a 3 digit code on their back.
+///
+/// The code must never be stored once a payment is authorized, so it can be deserialized but not serialized,
+/// and `Debug` never shows it.
+///
+/// ## Example
+///
+/// ```
+/// use valobs::financial::card::{CardBrand, Cvv};
+/// use valobs::result::ValobsResult;
+///
+/// fn main() -> ValobsResult<()> {
+///   let cvv = Cvv::new("1234", CardBrand::AmericanExpress)?;
+///
+///   assert_eq!(cvv.expose(), "1234");
+///   assert_eq!(format!("{cvv:?}"), "Cvv(\"****\")");
+///   assert!(Cvv::new("1234", CardBrand::Visa).is_err());
+///
+///   Ok(())
+/// }
+/// ```
+#[derive(PartialEq, Eq, Clone, Deserialize)]
+#[serde(try_from = "String")]
+pub struct Cvv(String);
+
+impl Cvv {
+    /// Create a new `Cvv` instance, whose number of digits must be valid for the brand of the card.
+    pub fn new(value: &str, brand: CardBrand) -> ValobsResult<Cvv> {
+        let value = value.trim();
+        if value.is_empty() {
+            return Err(ValobsError::Empty { field: "cvv" });
+        }
+        if !value.chars().all(|char| char.is_ascii_digit()) {
+            return Err(ValobsError::InvalidFormat {
+                field: "cvv",
+                value: "*".repeat(value.chars().count()),
+            });
+        }
+        let (min, max) = brand.cvv_lengths();
+        if !(min..=max).contains(&value.len()) {
+            return Err(ValobsError::InvalidLength {
+                field: "cvv",
+                length: value.len(),
+                min,
+                max,
+            });
+        }
+        Ok(Self(value.into()))
+    }
+
+    /// Get the code, to send it to a payment processor.
+    pub fn expose(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns `true` if the number of digits of the code is valid for the brand of the card.
+    pub fn is_valid_for(&self, brand: CardBrand) -> bool {
+        let (min, max) = brand.cvv_lengths();
+        (min..=max).contains(&self.0.len())
+    }
+}
+
+impl TryFrom<String> for Cvv {
+    type Error = ValobsError;
+
+    /// Converts a code of 3 or 4 digits, whose brand is not known yet.
+    fn try_from(value: String) -> ValobsResult<Self> {
+        Self::new(&value, CardBrand::Unknown)
+    }
+}
+
+impl fmt::Debug for Cvv {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Cvv")
+            .field(&"*".repeat(self.0.len()))
+            .finish()
+    }
+}
+
+/// Replaces every character but the last four with `*`.
+fn mask(value: &str) -> String {
+    let length = value.chars().count();
+    value
+        .chars()
+        .enumerate()
+        .map(|(index, char)| if index + 4 < length { '*' } else { char })
+        .collect()
+}
+
+/// Returns `true` if the last digit is the Luhn check digit of the others.
+pub(super) fn luhn(digits: &str) -> bool {
+    let sum: u32 = digits
+        .chars()
+        .rev()
+        .filter_map(|char| char.to_digit(10))
+        .enumerate()
+        .map(|(index, digit)| match (index % 2, digit * 2) {
+            (0, _) => digit,
+            (_, doubled) if doubled > 9 => doubled - 9,
+            (_, doubled) => doubled,
+        })
+        .sum();
+    sum.is_multiple_of(10)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detect_card_brand() -> ValobsResult<()> {
+        for (number, brand) in [
+            ("4111111111111111", CardBrand::Visa),
+            ("5555555555554444", CardBrand::Mastercard),
+            ("2223003122003222", CardBrand::Mastercard),
+            ("378282246310005", CardBrand::AmericanExpress),
+            ("6011111111111117", CardBrand::Discover),
+            ("3530111333300000", CardBrand::Jcb),
+            ("6200000000000005", CardBrand::UnionPay),
+            ("36227206271667", CardBrand::DinersClub),
+            ("6759649826438453", CardBrand::Maestro),
+            ("2200000000000004", CardBrand::Mir),
+            ("9999999999999995", CardBrand::Unknown),
+        ] {
+            // Act
+            let result = CardNumber::new(number)?;
+
+            // Assert
+            assert_eq!(result.brand(), brand, "{number}");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn mask_card_number() -> ValobsResult<()> {
+        // Arrange
+        let number = CardNumber::new("3782-822463-10005")?;
+
+        // Assert
+        assert_eq!(number.bin(), "378282");
+        assert_eq!(number.last_four(), "0005");
+        assert_eq!(number.to_string(), "**** ****** *0005");
+        assert_eq!(
+            format!("{number:?}"),
+            r#"CardNumber(AmericanExpress, "**** ****** *0005")"#
+        );
+        assert_eq!(number.expose_grouped(), "3782 822463 10005");
+        assert_eq!(
+            CardNumber::new("4222222222222")?.expose_grouped(),
+            "4222 2222 2222 2"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn fails_to_create_invalid_card_number_without_exposing_it() {
+        assert_eq!(
+            CardNumber::new("4111 1111 1111 1112"),
+            Err(ValobsError::InvalidChecksum {
+                field: "card_number",
+                value: "************1112".into(),
+            })
+        );
+        assert_eq!(
+            CardNumber::new("4111 1111 1111 111x"),
+            Err(ValobsError::InvalidFormat {
+                field: "card_number",
+                value: "************111x".into(),
+            })
+        );
+        assert_eq!(
+            CardNumber::new("37828224631000"),
+            Err(ValobsError::InvalidLength {
+                field: "card_number",
+                length: 14,
+                min: 15,
+                max: 15,
+            })
+        );
+        assert!(CardNumber::new("").is_err());
+    }
+
+    #[test]
+    fn serialize_card_number_to_json() -> ValobsResult<()> {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Payment {
+            #[serde(with = "unmasked")]
+            number: CardNumber,
+        }
+
+        // Arrange
+        let number = CardNumber::new("5555 5555 5555 4444")?;
+
+        // Act
+        let masked = serde_json::to_string(&number).unwrap();
+        let unmasked = serde_json::to_string(&Payment {
+            number: number.clone(),
+        })
+        .unwrap();
+        let result = serde_json::from_str::<Payment>(&unmasked).unwrap();
+
+        // Assert
+        assert_eq!(masked, r#""**** **** **** 4444""#);
+        assert_eq!(unmasked, r#"{"number":"5555555555554444"}"#);
+        assert_eq!(result.number, number);
+        assert!(serde_json::from_str::<CardNumber>(&masked).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn create_card_expiry() -> ValobsResult<()> {
+        // Act
+        let result: CardExpiry = "12/2027".parse()?;
+
+        // Assert
+        assert_eq!(result, CardExpiry::new(12, 2027)?);
+        assert_eq!(result.to_string(), "12/27");
+        assert_eq!(result.last_day(), Date::from_ymd_opt(2027, 12, 31).unwrap());
+        assert!(!result.is_expired_on(Date::from_ymd_opt(2027, 12, 31).unwrap()));
+        assert!(result.is_expired_on(Date::from_ymd_opt(2028, 1, 1).unwrap()));
+        assert!(CardExpiry::new(1, 2027)? < result);
+        assert_eq!(
+            serde_json::to_string(&result).unwrap(),
+            r#""12/27""#.to_string()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn fails_to_create_invalid_card_expiry() {
+        assert_eq!(
+            "13/27".parse::<CardExpiry>(),
+            Err(ValobsError::OutOfRange {
+                field: "month",
+                value: 13.0,
+                min: 1.0,
+                max: 12.0,
+            })
+        );
+        assert!("1227".parse::<CardExpiry>().is_err());
+        assert!("12/027".parse::<CardExpiry>().is_err());
+        assert!("+1/27".parse::<CardExpiry>().is_err());
+    }
+
+    #[test]
+    fn create_cvv_for_brand() -> ValobsResult<()> {
+        // Act
+        let result = Cvv::new("123", CardBrand::Visa)?;
+
+        // Assert
+        assert_eq!(result.expose(), "123");
+        assert_eq!(format!("{result:?}"), r#"Cvv("***")"#);
+        assert!(!result.is_valid_for(CardBrand::AmericanExpress));
+        assert_eq!(
+            Cvv::new("12", CardBrand::Mastercard),
+            Err(ValobsError::InvalidLength {
+                field: "cvv",
+                length: 2,
+                min: 3,
+                max: 3,
+            })
+        );
+        assert!(serde_json::from_str::<Cvv>(r#""1234""#).is_ok());
+        assert!(serde_json::from_str::<Cvv>(r#""12a""#).is_err());
+
+        Ok(())
+    }
+}
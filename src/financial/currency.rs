@@ -1,9 +1,17 @@
+mod data;
+
+use self::data::{CurrencyData, CURRENCIES};
+use crate::{
+    result::{ValobsError, ValobsResult},
+    temporal::Date,
+};
 use serde::{Deserialize, Serialize};
+use std::{fmt, str::FromStr};
 
 /// Currency code, as defined by ISO 4217.
 /// Updated to the CLDR version 44.1.0.
 #[allow(dead_code)]
-#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Serialize, Deserialize)]
 pub enum Currency {
     ADP, // Andorran Peseta,
     AED, // United Arab Emirates Dirham,
@@ -310,3 +318,297 @@ pub enum Currency {
     ZWD, // Zimbabwean Dollar (1980–2008),
     ZWL, // Zimbabwean Dollar (2009),
 }
+
+impl Currency {
+    /// Returns the ISO 4217 minor unit of the currency, which is the number of digits after the decimal separator.
+    ///
+    /// For example, 1 US dollar is 100 cents, so the minor unit of `USD` is 2,
+    /// while the Japanese yen has no subdivision, so the minor unit of `JPY` is 0.
+    ///
+    /// Currencies without a minor unit in ISO 4217, such as precious metals and testing codes, return 0.
+    /// Historic currencies that are no longer listed in ISO 4217 follow the CLDR currency data.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use valobs::financial::Currency;
+    ///
+    /// assert_eq!(Currency::JPY.minor_units(), 0);
+    /// assert_eq!(Currency::USD.minor_units(), 2);
+    /// assert_eq!(Currency::BHD.minor_units(), 3);
+    /// assert_eq!(Currency::CLF.minor_units(), 4);
+    /// ```
+    pub const fn minor_units(&self) -> u32 {
+        match self {
+            Self::CLF | Self::UYW => 4,
+            Self::BHD | Self::IQD | Self::JOD | Self::KWD | Self::LYD | Self::OMR | Self::TND => 3,
+            Self::BIF
+            | Self::CLP
+            | Self::DJF
+            | Self::GNF
+            | Self::ISK
+            | Self::JPY
+            | Self::KMF
+            | Self::KRW
+            | Self::PYG
+            | Self::RWF
+            | Self::UGX
+            | Self::UYI
+            | Self::VND
+            | Self::VUV
+            | Self::XAF
+            | Self::XOF
+            | Self::XPF => 0,
+            Self::XAG
+            | Self::XAU
+            | Self::XBA
+            | Self::XBB
+            | Self::XBC
+            | Self::XBD
+            | Self::XDR
+            | Self::XEU
+            | Self::XFO
+            | Self::XFU
+            | Self::XPD
+            | Self::XPT
+            | Self::XRE
+            | Self::XSU
+            | Self::XTS
+            | Self::XUA
+            | Self::XXX => 0,
+            Self::ADP
+            | Self::BYR
+            | Self::ESP
+            | Self::ITL
+            | Self::LUF
+            | Self::MGF
+            | Self::MRO
+            | Self::STD
+            | Self::TMM
+            | Self::TRL
+            | Self::ZMK
+            | Self::ZWD => 0,
+            _ => 2,
+        }
+    }
+
+    /// Returns an iterator over every currency, in the alphabetical order of their codes.
+    pub fn all() -> impl Iterator<Item = Currency> {
+        CURRENCIES.iter().map(|data| data.currency)
+    }
+
+    /// Returns the ISO 4217 alphabetic code of the currency, such as `USD`.
+    pub fn code(&self) -> &'static str {
+        self.data().code
+    }
+
+    /// Returns the ISO 4217 numeric code of the currency, such as 840 for `USD`.
+    ///
+    /// Numeric codes are reused when a currency is replaced, so a historic currency may share its code with the current one.
+    /// Codes that only exist in CLDR, such as the offshore yuan `CNH`, have no numeric code.
+    pub fn numeric_code(&self) -> Option<u16> {
+        self.data().numeric_code
+    }
+
+    /// Returns the English name of the currency, as defined by CLDR, such as `US Dollar`.
+    pub fn name(&self) -> &'static str {
+        self.data().name
+    }
+
+    /// Returns the English symbol of the currency, such as `$` for `USD` or `CA$` for `CAD`.
+    ///
+    /// Currencies without a symbol in CLDR return their code.
+    pub fn symbol(&self) -> &'static str {
+        self.data().symbol.unwrap_or(self.code())
+    }
+
+    /// Returns the narrow symbol of the currency, such as `$` for both `USD` and `CAD`.
+    ///
+    /// It is shorter than [Currency::symbol], but it is ambiguous, so use it only where the currency is clear from the context.
+    /// Currencies without a narrow symbol in CLDR return their symbol.
+    pub fn narrow_symbol(&self) -> &'static str {
+        self.data().narrow_symbol.unwrap_or(self.symbol())
+    }
+
+    /// Returns `true` if the currency has been withdrawn, such as the German mark `DEM`.
+    pub fn is_historic(&self) -> bool {
+        self.data().withdrawn.is_some()
+    }
+
+    /// Returns the date when the currency was withdrawn, or `None` if it is still in use.
+    ///
+    /// ISO 4217 only records the month of withdrawal, so the date is the first day of that month.
+    /// For currencies that were withdrawn before ISO 4217 recorded them, it is the first day of the year
+    /// they stopped being legal tender.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use valobs::financial::Currency;
+    /// use valobs::temporal::Date;
+    ///
+    /// assert_eq!(Currency::DEM.withdrawal_date(), Date::from_ymd_opt(2002, 3, 1));
+    /// assert_eq!(Currency::EUR.withdrawal_date(), None);
+    /// ```
+    pub fn withdrawal_date(&self) -> Option<Date> {
+        self.data()
+            .withdrawn
+            .and_then(|(year, month)| Date::from_ymd_opt(year, month, 1))
+    }
+
+    fn data(&self) -> &'static CurrencyData {
+        &CURRENCIES[*self as usize]
+    }
+}
+
+impl fmt::Display for Currency {
+    /// Formats the currency as its ISO 4217 code, such as `USD`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+impl FromStr for Currency {
+    type Err = ValobsError;
+
+    /// Parses a currency from its ISO 4217 alphabetic code, such as `USD` or `usd`,
+    /// or from its three-digit numeric code, such as `840`.
+    ///
+    /// A numeric code shared by several currencies is parsed as described in [Currency::try_from].
+    fn from_str(code: &str) -> ValobsResult<Self> {
+        if code.len() != 3 {
+            return Err(ValobsError::InvalidFormat {
+                field: "currency",
+                value: code.into(),
+            });
+        }
+        if code.bytes().all(|byte| byte.is_ascii_digit()) {
+            let numeric_code = code.parse::<u16>().expect("three digits fit in u16");
+            return Self::try_from(numeric_code);
+        }
+        if !code.bytes().all(|byte| byte.is_ascii_alphabetic()) {
+            return Err(ValobsError::InvalidFormat {
+                field: "currency",
+                value: code.into(),
+            });
+        }
+        CURRENCIES
+            .iter()
+            .find(|data| data.code.eq_ignore_ascii_case(code))
+            .map(|data| data.currency)
+            .ok_or_else(|| ValobsError::UnknownCode {
+                field: "currency",
+                value: code.into(),
+            })
+    }
+}
+
+impl TryFrom<u16> for Currency {
+    type Error = ValobsError;
+
+    /// Finds the currency with the given ISO 4217 numeric code, such as 840 for `USD`.
+    ///
+    /// When a numeric code is shared by several currencies, the one still in use is returned,
+    /// or else the one withdrawn most recently.
+    fn try_from(numeric_code: u16) -> ValobsResult<Self> {
+        CURRENCIES
+            .iter()
+            .filter(|data| data.numeric_code == Some(numeric_code))
+            .max_by_key(|data| (data.withdrawn.is_none(), data.withdrawn))
+            .map(|data| data.currency)
+            .ok_or_else(|| ValobsError::UnknownCode {
+                field: "currency",
+                value: format!("{numeric_code:03}"),
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn minor_units_of_currency() {
+        assert_eq!(Currency::JPY.minor_units(), 0);
+        assert_eq!(Currency::KRW.minor_units(), 0);
+        assert_eq!(Currency::USD.minor_units(), 2);
+        assert_eq!(Currency::EUR.minor_units(), 2);
+        assert_eq!(Currency::BHD.minor_units(), 3);
+        assert_eq!(Currency::CLF.minor_units(), 4);
+        assert_eq!(Currency::XAU.minor_units(), 0);
+        assert_eq!(Currency::ITL.minor_units(), 0);
+    }
+
+    #[test]
+    fn display_currency_as_code() {
+        assert_eq!(Currency::USD.to_string(), "USD");
+        assert_eq!(Currency::KRW.to_string(), "KRW");
+    }
+
+    #[test]
+    fn parse_currency_from_code() -> ValobsResult<()> {
+        assert_eq!("USD".parse::<Currency>()?, Currency::USD);
+        assert_eq!(
+            "US".parse::<Currency>(),
+            Err(ValobsError::InvalidFormat {
+                field: "currency",
+                value: "US".into(),
+            })
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn currency_data_follows_variant_order() {
+        for (index, data) in CURRENCIES.iter().enumerate() {
+            assert_eq!(data.currency as usize, index);
+            assert_eq!(format!("{:?}", data.currency), data.code);
+        }
+        assert_eq!(Currency::all().count(), CURRENCIES.len());
+    }
+
+    #[test]
+    fn metadata_of_currency() {
+        assert_eq!(Currency::USD.numeric_code(), Some(840));
+        assert_eq!(Currency::ALL.numeric_code(), Some(8));
+        assert_eq!(Currency::CNH.numeric_code(), None);
+        assert_eq!(Currency::USD.name(), "US Dollar");
+        assert_eq!(Currency::CAD.symbol(), "CA$");
+        assert_eq!(Currency::CAD.narrow_symbol(), "$");
+        assert_eq!(Currency::CHF.symbol(), "CHF");
+        assert_eq!(Currency::CHF.narrow_symbol(), "CHF");
+    }
+
+    #[test]
+    fn historic_currency() {
+        assert!(Currency::DEM.is_historic());
+        assert!(Currency::FRF.is_historic());
+        assert!(Currency::AZM.is_historic());
+        assert!(!Currency::AZN.is_historic());
+        assert_eq!(
+            Currency::AZM.withdrawal_date(),
+            Date::from_ymd_opt(2006, 12, 1)
+        );
+        assert_eq!(Currency::USD.withdrawal_date(), None);
+    }
+
+    #[test]
+    fn parse_currency_from_numeric_code() -> ValobsResult<()> {
+        assert_eq!("840".parse::<Currency>()?, Currency::USD);
+        assert_eq!("usd".parse::<Currency>()?, Currency::USD);
+        assert_eq!(Currency::try_from(978)?, Currency::EUR);
+        assert_eq!(Currency::try_from(8)?, Currency::ALL);
+        assert_eq!(Currency::try_from(810)?, Currency::RUR);
+        assert_eq!(
+            Currency::try_from(1),
+            Err(ValobsError::UnknownCode {
+                field: "currency",
+                value: "001".into(),
+            })
+        );
+        assert!("ABC".parse::<Currency>().is_err());
+
+        Ok(())
+    }
+}
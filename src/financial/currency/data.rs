@@ -0,0 +1,2755 @@
+//! ISO 4217 and CLDR reference data of every [Currency].
+//!
+//! The entries follow the order of the variants of [Currency], so the data of a currency is found by its discriminant.
+
+use super::Currency;
+
+pub(super) struct CurrencyData {
+    pub currency: Currency,
+    pub code: &'static str,
+    pub numeric_code: Option<u16>,
+    pub name: &'static str,
+    pub symbol: Option<&'static str>,
+    pub narrow_symbol: Option<&'static str>,
+    /// The year and month the currency was withdrawn from ISO 4217, or the year it stopped being legal tender.
+    pub withdrawn: Option<(i32, u32)>,
+}
+
+pub(super) const CURRENCIES: &[CurrencyData] = &[
+    CurrencyData {
+        currency: Currency::ADP,
+        code: "ADP",
+        numeric_code: Some(20),
+        name: "Andorran Peseta",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((2002, 3)),
+    },
+    CurrencyData {
+        currency: Currency::AED,
+        code: "AED",
+        numeric_code: Some(784),
+        name: "United Arab Emirates Dirham",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::AFA,
+        code: "AFA",
+        numeric_code: Some(4),
+        name: "Afghan Afghani (1927–2002)",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((2003, 1)),
+    },
+    CurrencyData {
+        currency: Currency::AFN,
+        code: "AFN",
+        numeric_code: Some(971),
+        name: "Afghan Afghani",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::ALK,
+        code: "ALK",
+        numeric_code: Some(8),
+        name: "Albanian Lek (1946–1965)",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((1989, 12)),
+    },
+    CurrencyData {
+        currency: Currency::ALL,
+        code: "ALL",
+        numeric_code: Some(8),
+        name: "Albanian Lek",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::AMD,
+        code: "AMD",
+        numeric_code: Some(51),
+        name: "Armenian Dram",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::ANG,
+        code: "ANG",
+        numeric_code: Some(532),
+        name: "Netherlands Antillean Guilder",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::AOA,
+        code: "AOA",
+        numeric_code: Some(973),
+        name: "Angolan Kwanza",
+        symbol: None,
+        narrow_symbol: Some("Kz"),
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::AOK,
+        code: "AOK",
+        numeric_code: Some(24),
+        name: "Angolan Kwanza (1977–1991)",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((1991, 3)),
+    },
+    CurrencyData {
+        currency: Currency::AON,
+        code: "AON",
+        numeric_code: Some(24),
+        name: "Angolan New Kwanza (1990–2000)",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((2000, 2)),
+    },
+    CurrencyData {
+        currency: Currency::AOR,
+        code: "AOR",
+        numeric_code: Some(982),
+        name: "Angolan Readjusted Kwanza (1995–1999)",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((2000, 2)),
+    },
+    CurrencyData {
+        currency: Currency::ARA,
+        code: "ARA",
+        numeric_code: Some(32),
+        name: "Argentine Austral",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((1992, 1)),
+    },
+    CurrencyData {
+        currency: Currency::ARL,
+        code: "ARL",
+        numeric_code: None,
+        name: "Argentine Peso Ley (1970–1983)",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((1983, 1)),
+    },
+    CurrencyData {
+        currency: Currency::ARM,
+        code: "ARM",
+        numeric_code: None,
+        name: "Argentine Peso (1881–1970)",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((1970, 1)),
+    },
+    CurrencyData {
+        currency: Currency::ARP,
+        code: "ARP",
+        numeric_code: Some(32),
+        name: "Argentine Peso (1983–1985)",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((1985, 7)),
+    },
+    CurrencyData {
+        currency: Currency::ARS,
+        code: "ARS",
+        numeric_code: Some(32),
+        name: "Argentine Peso",
+        symbol: None,
+        narrow_symbol: Some("$"),
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::ATS,
+        code: "ATS",
+        numeric_code: Some(40),
+        name: "Austrian Schilling",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((2002, 3)),
+    },
+    CurrencyData {
+        currency: Currency::AUD,
+        code: "AUD",
+        numeric_code: Some(36),
+        name: "Australian Dollar",
+        symbol: Some("A$"),
+        narrow_symbol: Some("$"),
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::AWG,
+        code: "AWG",
+        numeric_code: Some(533),
+        name: "Aruban Florin",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::AZM,
+        code: "AZM",
+        numeric_code: Some(31),
+        name: "Azerbaijani Manat (1993–2006)",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((2006, 12)),
+    },
+    CurrencyData {
+        currency: Currency::AZN,
+        code: "AZN",
+        numeric_code: Some(944),
+        name: "Azerbaijani Manat",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::BAD,
+        code: "BAD",
+        numeric_code: Some(70),
+        name: "Bosnia-Herzegovina Dinar (1992–1994)",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((1997, 7)),
+    },
+    CurrencyData {
+        currency: Currency::BAM,
+        code: "BAM",
+        numeric_code: Some(977),
+        name: "Bosnia-Herzegovina Convertible Mark",
+        symbol: None,
+        narrow_symbol: Some("KM"),
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::BAN,
+        code: "BAN",
+        numeric_code: None,
+        name: "Bosnia-Herzegovina New Dinar (1994–1997)",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((1997, 1)),
+    },
+    CurrencyData {
+        currency: Currency::BBD,
+        code: "BBD",
+        numeric_code: Some(52),
+        name: "Barbadian Dollar",
+        symbol: None,
+        narrow_symbol: Some("$"),
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::BDT,
+        code: "BDT",
+        numeric_code: Some(50),
+        name: "Bangladeshi Taka",
+        symbol: None,
+        narrow_symbol: Some("৳"),
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::BEC,
+        code: "BEC",
+        numeric_code: Some(993),
+        name: "Belgian Franc (convertible)",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((1990, 3)),
+    },
+    CurrencyData {
+        currency: Currency::BEF,
+        code: "BEF",
+        numeric_code: Some(56),
+        name: "Belgian Franc",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((2002, 3)),
+    },
+    CurrencyData {
+        currency: Currency::BEL,
+        code: "BEL",
+        numeric_code: Some(992),
+        name: "Belgian Franc (financial)",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((1990, 3)),
+    },
+    CurrencyData {
+        currency: Currency::BGL,
+        code: "BGL",
+        numeric_code: Some(100),
+        name: "Bulgarian Hard Lev",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((2003, 11)),
+    },
+    CurrencyData {
+        currency: Currency::BGM,
+        code: "BGM",
+        numeric_code: None,
+        name: "Bulgarian Socialist Lev",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((1962, 1)),
+    },
+    CurrencyData {
+        currency: Currency::BGN,
+        code: "BGN",
+        numeric_code: Some(975),
+        name: "Bulgarian Lev",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::BGO,
+        code: "BGO",
+        numeric_code: None,
+        name: "Bulgarian Lev (1879–1952)",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((1952, 1)),
+    },
+    CurrencyData {
+        currency: Currency::BHD,
+        code: "BHD",
+        numeric_code: Some(48),
+        name: "Bahraini Dinar",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::BIF,
+        code: "BIF",
+        numeric_code: Some(108),
+        name: "Burundian Franc",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::BMD,
+        code: "BMD",
+        numeric_code: Some(60),
+        name: "Bermudan Dollar",
+        symbol: None,
+        narrow_symbol: Some("$"),
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::BND,
+        code: "BND",
+        numeric_code: Some(96),
+        name: "Brunei Dollar",
+        symbol: None,
+        narrow_symbol: Some("$"),
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::BOB,
+        code: "BOB",
+        numeric_code: Some(68),
+        name: "Bolivian Boliviano",
+        symbol: None,
+        narrow_symbol: Some("Bs"),
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::BOL,
+        code: "BOL",
+        numeric_code: None,
+        name: "Bolivian Boliviano (1863–1963)",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((1963, 1)),
+    },
+    CurrencyData {
+        currency: Currency::BOP,
+        code: "BOP",
+        numeric_code: Some(68),
+        name: "Bolivian Peso",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((1987, 2)),
+    },
+    CurrencyData {
+        currency: Currency::BOV,
+        code: "BOV",
+        numeric_code: Some(984),
+        name: "Bolivian Mvdol",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::BRB,
+        code: "BRB",
+        numeric_code: Some(76),
+        name: "Brazilian New Cruzeiro (1967–1986)",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((1986, 3)),
+    },
+    CurrencyData {
+        currency: Currency::BRC,
+        code: "BRC",
+        numeric_code: Some(76),
+        name: "Brazilian Cruzado (1986–1989)",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((1989, 2)),
+    },
+    CurrencyData {
+        currency: Currency::BRE,
+        code: "BRE",
+        numeric_code: Some(76),
+        name: "Brazilian Cruzeiro (1990–1993)",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((1993, 3)),
+    },
+    CurrencyData {
+        currency: Currency::BRL,
+        code: "BRL",
+        numeric_code: Some(986),
+        name: "Brazilian Real",
+        symbol: Some("R$"),
+        narrow_symbol: Some("R$"),
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::BRN,
+        code: "BRN",
+        numeric_code: Some(76),
+        name: "Brazilian New Cruzado (1989–1990)",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((1990, 3)),
+    },
+    CurrencyData {
+        currency: Currency::BRR,
+        code: "BRR",
+        numeric_code: Some(987),
+        name: "Brazilian Cruzeiro (1993–1994)",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((1994, 7)),
+    },
+    CurrencyData {
+        currency: Currency::BRZ,
+        code: "BRZ",
+        numeric_code: None,
+        name: "Brazilian Cruzeiro (1942–1967)",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((1967, 1)),
+    },
+    CurrencyData {
+        currency: Currency::BSD,
+        code: "BSD",
+        numeric_code: Some(44),
+        name: "Bahamian Dollar",
+        symbol: None,
+        narrow_symbol: Some("$"),
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::BTN,
+        code: "BTN",
+        numeric_code: Some(64),
+        name: "Bhutanese Ngultrum",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::BUK,
+        code: "BUK",
+        numeric_code: Some(104),
+        name: "Burmese Kyat",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((1990, 2)),
+    },
+    CurrencyData {
+        currency: Currency::BWP,
+        code: "BWP",
+        numeric_code: Some(72),
+        name: "Botswanan Pula",
+        symbol: None,
+        narrow_symbol: Some("P"),
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::BYB,
+        code: "BYB",
+        numeric_code: Some(112),
+        name: "Belarusian Ruble (1994–1999)",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((2001, 1)),
+    },
+    CurrencyData {
+        currency: Currency::BYN,
+        code: "BYN",
+        numeric_code: Some(933),
+        name: "Belarusian Ruble",
+        symbol: None,
+        narrow_symbol: Some("р."),
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::BYR,
+        code: "BYR",
+        numeric_code: Some(974),
+        name: "Belarusian Ruble (2000–2016)",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((2017, 1)),
+    },
+    CurrencyData {
+        currency: Currency::BZD,
+        code: "BZD",
+        numeric_code: Some(84),
+        name: "Belize Dollar",
+        symbol: None,
+        narrow_symbol: Some("$"),
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::CAD,
+        code: "CAD",
+        numeric_code: Some(124),
+        name: "Canadian Dollar",
+        symbol: Some("CA$"),
+        narrow_symbol: Some("$"),
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::CDF,
+        code: "CDF",
+        numeric_code: Some(976),
+        name: "Congolese Franc",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::CHE,
+        code: "CHE",
+        numeric_code: Some(947),
+        name: "WIR Euro",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::CHF,
+        code: "CHF",
+        numeric_code: Some(756),
+        name: "Swiss Franc",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::CHW,
+        code: "CHW",
+        numeric_code: Some(948),
+        name: "WIR Franc",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::CLE,
+        code: "CLE",
+        numeric_code: None,
+        name: "Chilean Escudo",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((1975, 1)),
+    },
+    CurrencyData {
+        currency: Currency::CLF,
+        code: "CLF",
+        numeric_code: Some(990),
+        name: "Chilean Unit of Account (UF)",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::CLP,
+        code: "CLP",
+        numeric_code: Some(152),
+        name: "Chilean Peso",
+        symbol: None,
+        narrow_symbol: Some("$"),
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::CNH,
+        code: "CNH",
+        numeric_code: None,
+        name: "Chinese Yuan (offshore)",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::CNX,
+        code: "CNX",
+        numeric_code: None,
+        name: "Chinese People’s Bank Dollar",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((1989, 12)),
+    },
+    CurrencyData {
+        currency: Currency::CNY,
+        code: "CNY",
+        numeric_code: Some(156),
+        name: "Chinese Yuan",
+        symbol: Some("CN¥"),
+        narrow_symbol: Some("¥"),
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::COP,
+        code: "COP",
+        numeric_code: Some(170),
+        name: "Colombian Peso",
+        symbol: None,
+        narrow_symbol: Some("$"),
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::COU,
+        code: "COU",
+        numeric_code: Some(970),
+        name: "Colombian Real Value Unit",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::CRC,
+        code: "CRC",
+        numeric_code: Some(188),
+        name: "Costa Rican Colón",
+        symbol: None,
+        narrow_symbol: Some("₡"),
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::CSD,
+        code: "CSD",
+        numeric_code: Some(891),
+        name: "Serbian Dinar (2002–2006)",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((2006, 10)),
+    },
+    CurrencyData {
+        currency: Currency::CSK,
+        code: "CSK",
+        numeric_code: Some(200),
+        name: "Czechoslovak Hard Koruna",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((1993, 3)),
+    },
+    CurrencyData {
+        currency: Currency::CUC,
+        code: "CUC",
+        numeric_code: Some(931),
+        name: "Cuban Convertible Peso",
+        symbol: None,
+        narrow_symbol: Some("$"),
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::CUP,
+        code: "CUP",
+        numeric_code: Some(192),
+        name: "Cuban Peso",
+        symbol: None,
+        narrow_symbol: Some("$"),
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::CVE,
+        code: "CVE",
+        numeric_code: Some(132),
+        name: "Cape Verdean Escudo",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::CYP,
+        code: "CYP",
+        numeric_code: Some(196),
+        name: "Cypriot Pound",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((2008, 1)),
+    },
+    CurrencyData {
+        currency: Currency::CZK,
+        code: "CZK",
+        numeric_code: Some(203),
+        name: "Czech Koruna",
+        symbol: None,
+        narrow_symbol: Some("Kč"),
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::DDM,
+        code: "DDM",
+        numeric_code: Some(278),
+        name: "East German Mark",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((1990, 9)),
+    },
+    CurrencyData {
+        currency: Currency::DEM,
+        code: "DEM",
+        numeric_code: Some(276),
+        name: "German Mark",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((2002, 3)),
+    },
+    CurrencyData {
+        currency: Currency::DJF,
+        code: "DJF",
+        numeric_code: Some(262),
+        name: "Djiboutian Franc",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::DKK,
+        code: "DKK",
+        numeric_code: Some(208),
+        name: "Danish Krone",
+        symbol: None,
+        narrow_symbol: Some("kr"),
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::DOP,
+        code: "DOP",
+        numeric_code: Some(214),
+        name: "Dominican Peso",
+        symbol: None,
+        narrow_symbol: Some("$"),
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::DZD,
+        code: "DZD",
+        numeric_code: Some(12),
+        name: "Algerian Dinar",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::ECS,
+        code: "ECS",
+        numeric_code: Some(218),
+        name: "Ecuadorian Sucre",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((2000, 9)),
+    },
+    CurrencyData {
+        currency: Currency::ECV,
+        code: "ECV",
+        numeric_code: Some(983),
+        name: "Ecuadorian Unit of Constant Value",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((2000, 9)),
+    },
+    CurrencyData {
+        currency: Currency::EEK,
+        code: "EEK",
+        numeric_code: Some(233),
+        name: "Estonian Kroon",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((2011, 1)),
+    },
+    CurrencyData {
+        currency: Currency::EGP,
+        code: "EGP",
+        numeric_code: Some(818),
+        name: "Egyptian Pound",
+        symbol: None,
+        narrow_symbol: Some("E£"),
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::ERN,
+        code: "ERN",
+        numeric_code: Some(232),
+        name: "Eritrean Nakfa",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::ESA,
+        code: "ESA",
+        numeric_code: Some(996),
+        name: "Spanish Peseta (A account)",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((1981, 1)),
+    },
+    CurrencyData {
+        currency: Currency::ESB,
+        code: "ESB",
+        numeric_code: Some(995),
+        name: "Spanish Peseta (convertible account)",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((1994, 12)),
+    },
+    CurrencyData {
+        currency: Currency::ESP,
+        code: "ESP",
+        numeric_code: Some(724),
+        name: "Spanish Peseta",
+        symbol: None,
+        narrow_symbol: Some("₧"),
+        withdrawn: Some((2002, 3)),
+    },
+    CurrencyData {
+        currency: Currency::ETB,
+        code: "ETB",
+        numeric_code: Some(230),
+        name: "Ethiopian Birr",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::EUR,
+        code: "EUR",
+        numeric_code: Some(978),
+        name: "Euro",
+        symbol: Some("€"),
+        narrow_symbol: Some("€"),
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::FIM,
+        code: "FIM",
+        numeric_code: Some(246),
+        name: "Finnish Markka",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((2002, 3)),
+    },
+    CurrencyData {
+        currency: Currency::FJD,
+        code: "FJD",
+        numeric_code: Some(242),
+        name: "Fijian Dollar",
+        symbol: None,
+        narrow_symbol: Some("$"),
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::FKP,
+        code: "FKP",
+        numeric_code: Some(238),
+        name: "Falkland Islands Pound",
+        symbol: None,
+        narrow_symbol: Some("£"),
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::FRF,
+        code: "FRF",
+        numeric_code: Some(250),
+        name: "French Franc",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((2002, 3)),
+    },
+    CurrencyData {
+        currency: Currency::GBP,
+        code: "GBP",
+        numeric_code: Some(826),
+        name: "British Pound",
+        symbol: Some("£"),
+        narrow_symbol: Some("£"),
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::GEK,
+        code: "GEK",
+        numeric_code: Some(268),
+        name: "Georgian Kupon Larit",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((1995, 10)),
+    },
+    CurrencyData {
+        currency: Currency::GEL,
+        code: "GEL",
+        numeric_code: Some(981),
+        name: "Georgian Lari",
+        symbol: None,
+        narrow_symbol: Some("₾"),
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::GHC,
+        code: "GHC",
+        numeric_code: Some(288),
+        name: "Ghanaian Cedi (1979–2007)",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((2008, 1)),
+    },
+    CurrencyData {
+        currency: Currency::GHS,
+        code: "GHS",
+        numeric_code: Some(936),
+        name: "Ghanaian Cedi",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::GIP,
+        code: "GIP",
+        numeric_code: Some(292),
+        name: "Gibraltar Pound",
+        symbol: None,
+        narrow_symbol: Some("£"),
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::GMD,
+        code: "GMD",
+        numeric_code: Some(270),
+        name: "Gambian Dalasi",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::GNF,
+        code: "GNF",
+        numeric_code: Some(324),
+        name: "Guinean Franc",
+        symbol: None,
+        narrow_symbol: Some("FG"),
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::GNS,
+        code: "GNS",
+        numeric_code: Some(324),
+        name: "Guinean Syli",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((1986, 2)),
+    },
+    CurrencyData {
+        currency: Currency::GQE,
+        code: "GQE",
+        numeric_code: Some(226),
+        name: "Equatorial Guinean Ekwele",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((1986, 6)),
+    },
+    CurrencyData {
+        currency: Currency::GRD,
+        code: "GRD",
+        numeric_code: Some(300),
+        name: "Greek Drachma",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((2002, 3)),
+    },
+    CurrencyData {
+        currency: Currency::GTQ,
+        code: "GTQ",
+        numeric_code: Some(320),
+        name: "Guatemalan Quetzal",
+        symbol: None,
+        narrow_symbol: Some("Q"),
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::GWE,
+        code: "GWE",
+        numeric_code: Some(624),
+        name: "Portuguese Guinea Escudo",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((1978, 12)),
+    },
+    CurrencyData {
+        currency: Currency::GWP,
+        code: "GWP",
+        numeric_code: Some(624),
+        name: "Guinea-Bissau Peso",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((1997, 5)),
+    },
+    CurrencyData {
+        currency: Currency::GYD,
+        code: "GYD",
+        numeric_code: Some(328),
+        name: "Guyanaese Dollar",
+        symbol: None,
+        narrow_symbol: Some("$"),
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::HKD,
+        code: "HKD",
+        numeric_code: Some(344),
+        name: "Hong Kong Dollar",
+        symbol: Some("HK$"),
+        narrow_symbol: Some("$"),
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::HNL,
+        code: "HNL",
+        numeric_code: Some(340),
+        name: "Honduran Lempira",
+        symbol: None,
+        narrow_symbol: Some("L"),
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::HRD,
+        code: "HRD",
+        numeric_code: Some(191),
+        name: "Croatian Dinar",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((1995, 1)),
+    },
+    CurrencyData {
+        currency: Currency::HRK,
+        code: "HRK",
+        numeric_code: Some(191),
+        name: "Croatian Kuna",
+        symbol: None,
+        narrow_symbol: Some("kn"),
+        withdrawn: Some((2023, 1)),
+    },
+    CurrencyData {
+        currency: Currency::HTG,
+        code: "HTG",
+        numeric_code: Some(332),
+        name: "Haitian Gourde",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::HUF,
+        code: "HUF",
+        numeric_code: Some(348),
+        name: "Hungarian Forint",
+        symbol: None,
+        narrow_symbol: Some("Ft"),
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::IDR,
+        code: "IDR",
+        numeric_code: Some(360),
+        name: "Indonesian Rupiah",
+        symbol: None,
+        narrow_symbol: Some("Rp"),
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::IEP,
+        code: "IEP",
+        numeric_code: Some(372),
+        name: "Irish Pound",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((2002, 3)),
+    },
+    CurrencyData {
+        currency: Currency::ILP,
+        code: "ILP",
+        numeric_code: Some(376),
+        name: "Israeli Pound",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((1980, 2)),
+    },
+    CurrencyData {
+        currency: Currency::ILR,
+        code: "ILR",
+        numeric_code: Some(376),
+        name: "Israeli Shekel (1980–1985)",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((1990, 1)),
+    },
+    CurrencyData {
+        currency: Currency::ILS,
+        code: "ILS",
+        numeric_code: Some(376),
+        name: "Israeli New Shekel",
+        symbol: Some("₪"),
+        narrow_symbol: Some("₪"),
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::INR,
+        code: "INR",
+        numeric_code: Some(356),
+        name: "Indian Rupee",
+        symbol: Some("₹"),
+        narrow_symbol: Some("₹"),
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::IQD,
+        code: "IQD",
+        numeric_code: Some(368),
+        name: "Iraqi Dinar",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::IRR,
+        code: "IRR",
+        numeric_code: Some(364),
+        name: "Iranian Rial",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::ISJ,
+        code: "ISJ",
+        numeric_code: Some(352),
+        name: "Icelandic Króna (1918–1981)",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((1990, 1)),
+    },
+    CurrencyData {
+        currency: Currency::ISK,
+        code: "ISK",
+        numeric_code: Some(352),
+        name: "Icelandic Króna",
+        symbol: None,
+        narrow_symbol: Some("kr"),
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::ITL,
+        code: "ITL",
+        numeric_code: Some(380),
+        name: "Italian Lira",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((2002, 3)),
+    },
+    CurrencyData {
+        currency: Currency::JMD,
+        code: "JMD",
+        numeric_code: Some(388),
+        name: "Jamaican Dollar",
+        symbol: None,
+        narrow_symbol: Some("$"),
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::JOD,
+        code: "JOD",
+        numeric_code: Some(400),
+        name: "Jordanian Dinar",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::JPY,
+        code: "JPY",
+        numeric_code: Some(392),
+        name: "Japanese Yen",
+        symbol: Some("¥"),
+        narrow_symbol: Some("¥"),
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::KES,
+        code: "KES",
+        numeric_code: Some(404),
+        name: "Kenyan Shilling",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::KGS,
+        code: "KGS",
+        numeric_code: Some(417),
+        name: "Kyrgystani Som",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::KHR,
+        code: "KHR",
+        numeric_code: Some(116),
+        name: "Cambodian Riel",
+        symbol: None,
+        narrow_symbol: Some("៛"),
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::KMF,
+        code: "KMF",
+        numeric_code: Some(174),
+        name: "Comorian Franc",
+        symbol: None,
+        narrow_symbol: Some("CF"),
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::KPW,
+        code: "KPW",
+        numeric_code: Some(408),
+        name: "North Korean Won",
+        symbol: None,
+        narrow_symbol: Some("₩"),
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::KRH,
+        code: "KRH",
+        numeric_code: None,
+        name: "South Korean Hwan (1953–1962)",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((1962, 1)),
+    },
+    CurrencyData {
+        currency: Currency::KRO,
+        code: "KRO",
+        numeric_code: None,
+        name: "South Korean Won (1945–1953)",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((1953, 1)),
+    },
+    CurrencyData {
+        currency: Currency::KRW,
+        code: "KRW",
+        numeric_code: Some(410),
+        name: "South Korean Won",
+        symbol: Some("₩"),
+        narrow_symbol: Some("₩"),
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::KWD,
+        code: "KWD",
+        numeric_code: Some(414),
+        name: "Kuwaiti Dinar",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::KYD,
+        code: "KYD",
+        numeric_code: Some(136),
+        name: "Cayman Islands Dollar",
+        symbol: None,
+        narrow_symbol: Some("$"),
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::KZT,
+        code: "KZT",
+        numeric_code: Some(398),
+        name: "Kazakhstani Tenge",
+        symbol: None,
+        narrow_symbol: Some("₸"),
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::LAK,
+        code: "LAK",
+        numeric_code: Some(418),
+        name: "Laotian Kip",
+        symbol: None,
+        narrow_symbol: Some("₭"),
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::LBP,
+        code: "LBP",
+        numeric_code: Some(422),
+        name: "Lebanese Pound",
+        symbol: None,
+        narrow_symbol: Some("L£"),
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::LKR,
+        code: "LKR",
+        numeric_code: Some(144),
+        name: "Sri Lankan Rupee",
+        symbol: None,
+        narrow_symbol: Some("Rs"),
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::LRD,
+        code: "LRD",
+        numeric_code: Some(430),
+        name: "Liberian Dollar",
+        symbol: None,
+        narrow_symbol: Some("$"),
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::LSL,
+        code: "LSL",
+        numeric_code: Some(426),
+        name: "Lesotho Loti",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::LTL,
+        code: "LTL",
+        numeric_code: Some(440),
+        name: "Lithuanian Litas",
+        symbol: None,
+        narrow_symbol: Some("Lt"),
+        withdrawn: Some((2015, 1)),
+    },
+    CurrencyData {
+        currency: Currency::LTT,
+        code: "LTT",
+        numeric_code: Some(440),
+        name: "Lithuanian Talonas",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((1993, 7)),
+    },
+    CurrencyData {
+        currency: Currency::LUC,
+        code: "LUC",
+        numeric_code: Some(989),
+        name: "Luxembourgian Convertible Franc",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((1990, 3)),
+    },
+    CurrencyData {
+        currency: Currency::LUF,
+        code: "LUF",
+        numeric_code: Some(442),
+        name: "Luxembourgian Franc",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((2002, 3)),
+    },
+    CurrencyData {
+        currency: Currency::LUL,
+        code: "LUL",
+        numeric_code: Some(988),
+        name: "Luxembourg Financial Franc",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((1990, 3)),
+    },
+    CurrencyData {
+        currency: Currency::LVL,
+        code: "LVL",
+        numeric_code: Some(428),
+        name: "Latvian Lats",
+        symbol: None,
+        narrow_symbol: Some("Ls"),
+        withdrawn: Some((2014, 1)),
+    },
+    CurrencyData {
+        currency: Currency::LVR,
+        code: "LVR",
+        numeric_code: Some(428),
+        name: "Latvian Ruble",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((1994, 12)),
+    },
+    CurrencyData {
+        currency: Currency::LYD,
+        code: "LYD",
+        numeric_code: Some(434),
+        name: "Libyan Dinar",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::MAD,
+        code: "MAD",
+        numeric_code: Some(504),
+        name: "Moroccan Dirham",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::MAF,
+        code: "MAF",
+        numeric_code: None,
+        name: "Moroccan Franc",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((1959, 1)),
+    },
+    CurrencyData {
+        currency: Currency::MCF,
+        code: "MCF",
+        numeric_code: None,
+        name: "Monegasque Franc",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((2002, 3)),
+    },
+    CurrencyData {
+        currency: Currency::MDC,
+        code: "MDC",
+        numeric_code: None,
+        name: "Moldovan Cupon",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((1993, 1)),
+    },
+    CurrencyData {
+        currency: Currency::MDL,
+        code: "MDL",
+        numeric_code: Some(498),
+        name: "Moldovan Leu",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::MGA,
+        code: "MGA",
+        numeric_code: Some(969),
+        name: "Malagasy Ariary",
+        symbol: None,
+        narrow_symbol: Some("Ar"),
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::MGF,
+        code: "MGF",
+        numeric_code: Some(450),
+        name: "Malagasy Franc",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((2004, 12)),
+    },
+    CurrencyData {
+        currency: Currency::MKD,
+        code: "MKD",
+        numeric_code: Some(807),
+        name: "Macedonian Denar",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::MKN,
+        code: "MKN",
+        numeric_code: None,
+        name: "Macedonian Denar (1992–1993)",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((1993, 1)),
+    },
+    CurrencyData {
+        currency: Currency::MLF,
+        code: "MLF",
+        numeric_code: Some(466),
+        name: "Malian Franc",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((1984, 11)),
+    },
+    CurrencyData {
+        currency: Currency::MMK,
+        code: "MMK",
+        numeric_code: Some(104),
+        name: "Myanmar Kyat",
+        symbol: None,
+        narrow_symbol: Some("K"),
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::MNT,
+        code: "MNT",
+        numeric_code: Some(496),
+        name: "Mongolian Tugrik",
+        symbol: None,
+        narrow_symbol: Some("₮"),
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::MOP,
+        code: "MOP",
+        numeric_code: Some(446),
+        name: "Macanese Pataca",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::MRO,
+        code: "MRO",
+        numeric_code: Some(478),
+        name: "Mauritanian Ouguiya (1973–2017)",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((2018, 1)),
+    },
+    CurrencyData {
+        currency: Currency::MRU,
+        code: "MRU",
+        numeric_code: Some(929),
+        name: "Mauritanian Ouguiya",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::MTL,
+        code: "MTL",
+        numeric_code: Some(470),
+        name: "Maltese Lira",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((2008, 1)),
+    },
+    CurrencyData {
+        currency: Currency::MTP,
+        code: "MTP",
+        numeric_code: Some(470),
+        name: "Maltese Pound",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((1983, 6)),
+    },
+    CurrencyData {
+        currency: Currency::MUR,
+        code: "MUR",
+        numeric_code: Some(480),
+        name: "Mauritian Rupee",
+        symbol: None,
+        narrow_symbol: Some("Rs"),
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::MVP,
+        code: "MVP",
+        numeric_code: None,
+        name: "Maldivian Rupee (1947–1981)",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((1981, 1)),
+    },
+    CurrencyData {
+        currency: Currency::MVR,
+        code: "MVR",
+        numeric_code: Some(462),
+        name: "Maldivian Rufiyaa",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::MWK,
+        code: "MWK",
+        numeric_code: Some(454),
+        name: "Malawian Kwacha",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::MXN,
+        code: "MXN",
+        numeric_code: Some(484),
+        name: "Mexican Peso",
+        symbol: Some("MX$"),
+        narrow_symbol: Some("$"),
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::MXP,
+        code: "MXP",
+        numeric_code: Some(484),
+        name: "Mexican Silver Peso (1861–1992)",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((1993, 1)),
+    },
+    CurrencyData {
+        currency: Currency::MXV,
+        code: "MXV",
+        numeric_code: Some(979),
+        name: "Mexican Investment Unit",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::MYR,
+        code: "MYR",
+        numeric_code: Some(458),
+        name: "Malaysian Ringgit",
+        symbol: None,
+        narrow_symbol: Some("RM"),
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::MZE,
+        code: "MZE",
+        numeric_code: Some(508),
+        name: "Mozambican Escudo",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((1981, 1)),
+    },
+    CurrencyData {
+        currency: Currency::MZM,
+        code: "MZM",
+        numeric_code: Some(508),
+        name: "Mozambican Metical (1980–2006)",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((2006, 6)),
+    },
+    CurrencyData {
+        currency: Currency::MZN,
+        code: "MZN",
+        numeric_code: Some(943),
+        name: "Mozambican Metical",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::NAD,
+        code: "NAD",
+        numeric_code: Some(516),
+        name: "Namibian Dollar",
+        symbol: None,
+        narrow_symbol: Some("$"),
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::NGN,
+        code: "NGN",
+        numeric_code: Some(566),
+        name: "Nigerian Naira",
+        symbol: None,
+        narrow_symbol: Some("₦"),
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::NIC,
+        code: "NIC",
+        numeric_code: Some(558),
+        name: "Nicaraguan Córdoba (1988–1991)",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((1990, 10)),
+    },
+    CurrencyData {
+        currency: Currency::NIO,
+        code: "NIO",
+        numeric_code: Some(558),
+        name: "Nicaraguan Córdoba",
+        symbol: None,
+        narrow_symbol: Some("C$"),
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::NLG,
+        code: "NLG",
+        numeric_code: Some(528),
+        name: "Dutch Guilder",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((2002, 3)),
+    },
+    CurrencyData {
+        currency: Currency::NOK,
+        code: "NOK",
+        numeric_code: Some(578),
+        name: "Norwegian Krone",
+        symbol: None,
+        narrow_symbol: Some("kr"),
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::NPR,
+        code: "NPR",
+        numeric_code: Some(524),
+        name: "Nepalese Rupee",
+        symbol: None,
+        narrow_symbol: Some("Rs"),
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::NZD,
+        code: "NZD",
+        numeric_code: Some(554),
+        name: "New Zealand Dollar",
+        symbol: Some("NZ$"),
+        narrow_symbol: Some("$"),
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::OMR,
+        code: "OMR",
+        numeric_code: Some(512),
+        name: "Omani Rial",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::PAB,
+        code: "PAB",
+        numeric_code: Some(590),
+        name: "Panamanian Balboa",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::PEI,
+        code: "PEI",
+        numeric_code: Some(604),
+        name: "Peruvian Inti",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((1991, 7)),
+    },
+    CurrencyData {
+        currency: Currency::PEN,
+        code: "PEN",
+        numeric_code: Some(604),
+        name: "Peruvian Sol",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::PES,
+        code: "PES",
+        numeric_code: Some(604),
+        name: "Peruvian Sol (1863–1965)",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((1986, 2)),
+    },
+    CurrencyData {
+        currency: Currency::PGK,
+        code: "PGK",
+        numeric_code: Some(598),
+        name: "Papua New Guinean Kina",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::PHP,
+        code: "PHP",
+        numeric_code: Some(608),
+        name: "Philippine Peso",
+        symbol: Some("₱"),
+        narrow_symbol: Some("₱"),
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::PKR,
+        code: "PKR",
+        numeric_code: Some(586),
+        name: "Pakistani Rupee",
+        symbol: None,
+        narrow_symbol: Some("Rs"),
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::PLN,
+        code: "PLN",
+        numeric_code: Some(985),
+        name: "Polish Zloty",
+        symbol: None,
+        narrow_symbol: Some("zł"),
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::PLZ,
+        code: "PLZ",
+        numeric_code: Some(616),
+        name: "Polish Zloty (1950–1995)",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((1997, 1)),
+    },
+    CurrencyData {
+        currency: Currency::PTE,
+        code: "PTE",
+        numeric_code: Some(620),
+        name: "Portuguese Escudo",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((2002, 3)),
+    },
+    CurrencyData {
+        currency: Currency::PYG,
+        code: "PYG",
+        numeric_code: Some(600),
+        name: "Paraguayan Guarani",
+        symbol: None,
+        narrow_symbol: Some("₲"),
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::QAR,
+        code: "QAR",
+        numeric_code: Some(634),
+        name: "Qatari Riyal",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::RHD,
+        code: "RHD",
+        numeric_code: Some(716),
+        name: "Rhodesian Dollar",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((1981, 1)),
+    },
+    CurrencyData {
+        currency: Currency::ROL,
+        code: "ROL",
+        numeric_code: Some(642),
+        name: "Romanian Leu (1952–2006)",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((2005, 6)),
+    },
+    CurrencyData {
+        currency: Currency::RON,
+        code: "RON",
+        numeric_code: Some(946),
+        name: "Romanian Leu",
+        symbol: None,
+        narrow_symbol: Some("lei"),
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::RSD,
+        code: "RSD",
+        numeric_code: Some(941),
+        name: "Serbian Dinar",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::RUB,
+        code: "RUB",
+        numeric_code: Some(643),
+        name: "Russian Ruble",
+        symbol: None,
+        narrow_symbol: Some("₽"),
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::RUR,
+        code: "RUR",
+        numeric_code: Some(810),
+        name: "Russian Ruble (1991–1998)",
+        symbol: None,
+        narrow_symbol: Some("р."),
+        withdrawn: Some((1998, 1)),
+    },
+    CurrencyData {
+        currency: Currency::RWF,
+        code: "RWF",
+        numeric_code: Some(646),
+        name: "Rwandan Franc",
+        symbol: None,
+        narrow_symbol: Some("RF"),
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::SAR,
+        code: "SAR",
+        numeric_code: Some(682),
+        name: "Saudi Riyal",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::SBD,
+        code: "SBD",
+        numeric_code: Some(90),
+        name: "Solomon Islands Dollar",
+        symbol: None,
+        narrow_symbol: Some("$"),
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::SCR,
+        code: "SCR",
+        numeric_code: Some(690),
+        name: "Seychellois Rupee",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::SDD,
+        code: "SDD",
+        numeric_code: Some(736),
+        name: "Sudanese Dinar (1992–2007)",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((2007, 7)),
+    },
+    CurrencyData {
+        currency: Currency::SDG,
+        code: "SDG",
+        numeric_code: Some(938),
+        name: "Sudanese Pound",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::SDP,
+        code: "SDP",
+        numeric_code: Some(736),
+        name: "Sudanese Pound (1957–1998)",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((1998, 6)),
+    },
+    CurrencyData {
+        currency: Currency::SEK,
+        code: "SEK",
+        numeric_code: Some(752),
+        name: "Swedish Krona",
+        symbol: None,
+        narrow_symbol: Some("kr"),
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::SGD,
+        code: "SGD",
+        numeric_code: Some(702),
+        name: "Singapore Dollar",
+        symbol: None,
+        narrow_symbol: Some("$"),
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::SHP,
+        code: "SHP",
+        numeric_code: Some(654),
+        name: "St. Helena Pound",
+        symbol: None,
+        narrow_symbol: Some("£"),
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::SIT,
+        code: "SIT",
+        numeric_code: Some(705),
+        name: "Slovenian Tolar",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((2007, 1)),
+    },
+    CurrencyData {
+        currency: Currency::SKK,
+        code: "SKK",
+        numeric_code: Some(703),
+        name: "Slovak Koruna",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((2009, 1)),
+    },
+    CurrencyData {
+        currency: Currency::SLE,
+        code: "SLE",
+        numeric_code: Some(925),
+        name: "Sierra Leonean Leone",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::SLL,
+        code: "SLL",
+        numeric_code: Some(694),
+        name: "Sierra Leonean Leone (1964—2022)",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::SOS,
+        code: "SOS",
+        numeric_code: Some(706),
+        name: "Somali Shilling",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::SRD,
+        code: "SRD",
+        numeric_code: Some(968),
+        name: "Surinamese Dollar",
+        symbol: None,
+        narrow_symbol: Some("$"),
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::SRG,
+        code: "SRG",
+        numeric_code: Some(740),
+        name: "Surinamese Guilder",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((2004, 1)),
+    },
+    CurrencyData {
+        currency: Currency::SSP,
+        code: "SSP",
+        numeric_code: Some(728),
+        name: "South Sudanese Pound",
+        symbol: None,
+        narrow_symbol: Some("£"),
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::STD,
+        code: "STD",
+        numeric_code: Some(678),
+        name: "São Tomé & Príncipe Dobra (1977–2017)",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((2018, 1)),
+    },
+    CurrencyData {
+        currency: Currency::STN,
+        code: "STN",
+        numeric_code: Some(930),
+        name: "São Tomé & Príncipe Dobra",
+        symbol: None,
+        narrow_symbol: Some("Db"),
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::SUR,
+        code: "SUR",
+        numeric_code: Some(810),
+        name: "Soviet Rouble",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((1990, 12)),
+    },
+    CurrencyData {
+        currency: Currency::SVC,
+        code: "SVC",
+        numeric_code: Some(222),
+        name: "Salvadoran Colón",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::SYP,
+        code: "SYP",
+        numeric_code: Some(760),
+        name: "Syrian Pound",
+        symbol: None,
+        narrow_symbol: Some("£"),
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::SZL,
+        code: "SZL",
+        numeric_code: Some(748),
+        name: "Swazi Lilangeni",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::THB,
+        code: "THB",
+        numeric_code: Some(764),
+        name: "Thai Baht",
+        symbol: None,
+        narrow_symbol: Some("฿"),
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::TJR,
+        code: "TJR",
+        numeric_code: Some(762),
+        name: "Tajikistani Ruble",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((2001, 4)),
+    },
+    CurrencyData {
+        currency: Currency::TJS,
+        code: "TJS",
+        numeric_code: Some(972),
+        name: "Tajikistani Somoni",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::TMM,
+        code: "TMM",
+        numeric_code: Some(795),
+        name: "Turkmenistani Manat (1993–2009)",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((2009, 1)),
+    },
+    CurrencyData {
+        currency: Currency::TMT,
+        code: "TMT",
+        numeric_code: Some(934),
+        name: "Turkmenistani Manat",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::TND,
+        code: "TND",
+        numeric_code: Some(788),
+        name: "Tunisian Dinar",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::TOP,
+        code: "TOP",
+        numeric_code: Some(776),
+        name: "Tongan Paʻanga",
+        symbol: None,
+        narrow_symbol: Some("T$"),
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::TPE,
+        code: "TPE",
+        numeric_code: Some(626),
+        name: "Timorese Escudo",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((2002, 11)),
+    },
+    CurrencyData {
+        currency: Currency::TRL,
+        code: "TRL",
+        numeric_code: Some(792),
+        name: "Turkish Lira (1922–2005)",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((2005, 12)),
+    },
+    CurrencyData {
+        currency: Currency::TRY,
+        code: "TRY",
+        numeric_code: Some(949),
+        name: "Turkish Lira",
+        symbol: None,
+        narrow_symbol: Some("₺"),
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::TTD,
+        code: "TTD",
+        numeric_code: Some(780),
+        name: "Trinidad & Tobago Dollar",
+        symbol: None,
+        narrow_symbol: Some("$"),
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::TWD,
+        code: "TWD",
+        numeric_code: Some(901),
+        name: "New Taiwan Dollar",
+        symbol: Some("NT$"),
+        narrow_symbol: Some("$"),
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::TZS,
+        code: "TZS",
+        numeric_code: Some(834),
+        name: "Tanzanian Shilling",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::UAH,
+        code: "UAH",
+        numeric_code: Some(980),
+        name: "Ukrainian Hryvnia",
+        symbol: None,
+        narrow_symbol: Some("₴"),
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::UAK,
+        code: "UAK",
+        numeric_code: Some(804),
+        name: "Ukrainian Karbovanets",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((1996, 9)),
+    },
+    CurrencyData {
+        currency: Currency::UGS,
+        code: "UGS",
+        numeric_code: Some(800),
+        name: "Ugandan Shilling (1966–1987)",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((1987, 5)),
+    },
+    CurrencyData {
+        currency: Currency::UGX,
+        code: "UGX",
+        numeric_code: Some(800),
+        name: "Ugandan Shilling",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::USD,
+        code: "USD",
+        numeric_code: Some(840),
+        name: "US Dollar",
+        symbol: Some("$"),
+        narrow_symbol: Some("$"),
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::USN,
+        code: "USN",
+        numeric_code: Some(997),
+        name: "US Dollar (Next day)",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::USS,
+        code: "USS",
+        numeric_code: Some(998),
+        name: "US Dollar (Same day)",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((2014, 3)),
+    },
+    CurrencyData {
+        currency: Currency::UYI,
+        code: "UYI",
+        numeric_code: Some(940),
+        name: "Uruguayan Peso (Indexed Units)",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::UYP,
+        code: "UYP",
+        numeric_code: Some(858),
+        name: "Uruguayan Peso (1975–1993)",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((1993, 3)),
+    },
+    CurrencyData {
+        currency: Currency::UYU,
+        code: "UYU",
+        numeric_code: Some(858),
+        name: "Uruguayan Peso",
+        symbol: None,
+        narrow_symbol: Some("$"),
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::UYW,
+        code: "UYW",
+        numeric_code: Some(927),
+        name: "Uruguayan Nominal Wage Index Unit",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::UZS,
+        code: "UZS",
+        numeric_code: Some(860),
+        name: "Uzbekistani Som",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::VEB,
+        code: "VEB",
+        numeric_code: Some(862),
+        name: "Venezuelan Bolívar (1871–2008)",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((2008, 1)),
+    },
+    CurrencyData {
+        currency: Currency::VED,
+        code: "VED",
+        numeric_code: Some(926),
+        name: "Bolívar Soberano",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::VEF,
+        code: "VEF",
+        numeric_code: Some(937),
+        name: "Venezuelan Bolívar (2008–2018)",
+        symbol: None,
+        narrow_symbol: Some("Bs"),
+        withdrawn: Some((2018, 8)),
+    },
+    CurrencyData {
+        currency: Currency::VES,
+        code: "VES",
+        numeric_code: Some(928),
+        name: "Venezuelan Bolívar",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::VND,
+        code: "VND",
+        numeric_code: Some(704),
+        name: "Vietnamese Dong",
+        symbol: Some("₫"),
+        narrow_symbol: Some("₫"),
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::VNN,
+        code: "VNN",
+        numeric_code: None,
+        name: "Vietnamese Dong (1978–1985)",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((1985, 1)),
+    },
+    CurrencyData {
+        currency: Currency::VUV,
+        code: "VUV",
+        numeric_code: Some(548),
+        name: "Vanuatu Vatu",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::WST,
+        code: "WST",
+        numeric_code: Some(882),
+        name: "Samoan Tala",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::XAF,
+        code: "XAF",
+        numeric_code: Some(950),
+        name: "Central African CFA Franc",
+        symbol: Some("FCFA"),
+        narrow_symbol: None,
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::XAG,
+        code: "XAG",
+        numeric_code: Some(961),
+        name: "Silver",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::XAU,
+        code: "XAU",
+        numeric_code: Some(959),
+        name: "Gold",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::XBA,
+        code: "XBA",
+        numeric_code: Some(955),
+        name: "European Composite Unit",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::XBB,
+        code: "XBB",
+        numeric_code: Some(956),
+        name: "European Monetary Unit",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::XBC,
+        code: "XBC",
+        numeric_code: Some(957),
+        name: "European Unit of Account (XBC)",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::XBD,
+        code: "XBD",
+        numeric_code: Some(958),
+        name: "European Unit of Account (XBD)",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::XCD,
+        code: "XCD",
+        numeric_code: Some(951),
+        name: "East Caribbean Dollar",
+        symbol: Some("EC$"),
+        narrow_symbol: Some("$"),
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::XDR,
+        code: "XDR",
+        numeric_code: Some(960),
+        name: "Special Drawing Rights",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::XEU,
+        code: "XEU",
+        numeric_code: Some(954),
+        name: "European Currency Unit",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((1999, 1)),
+    },
+    CurrencyData {
+        currency: Currency::XFO,
+        code: "XFO",
+        numeric_code: None,
+        name: "French Gold Franc",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((2006, 10)),
+    },
+    CurrencyData {
+        currency: Currency::XFU,
+        code: "XFU",
+        numeric_code: None,
+        name: "French UIC-Franc",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((2013, 11)),
+    },
+    CurrencyData {
+        currency: Currency::XOF,
+        code: "XOF",
+        numeric_code: Some(952),
+        name: "West African CFA Franc",
+        symbol: Some("F\u{202f}CFA"),
+        narrow_symbol: None,
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::XPD,
+        code: "XPD",
+        numeric_code: Some(964),
+        name: "Palladium",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::XPF,
+        code: "XPF",
+        numeric_code: Some(953),
+        name: "CFP Franc",
+        symbol: Some("CFPF"),
+        narrow_symbol: None,
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::XPT,
+        code: "XPT",
+        numeric_code: Some(962),
+        name: "Platinum",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::XRE,
+        code: "XRE",
+        numeric_code: None,
+        name: "RINET Funds",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((1999, 11)),
+    },
+    CurrencyData {
+        currency: Currency::XSU,
+        code: "XSU",
+        numeric_code: Some(994),
+        name: "Sucre",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::XTS,
+        code: "XTS",
+        numeric_code: Some(963),
+        name: "Testing Currency Code",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::XUA,
+        code: "XUA",
+        numeric_code: Some(965),
+        name: "ADB Unit of Account",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::XXX,
+        code: "XXX",
+        numeric_code: Some(999),
+        name: "Unknown Currency",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::YDD,
+        code: "YDD",
+        numeric_code: Some(720),
+        name: "Yemeni Dinar",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((1991, 9)),
+    },
+    CurrencyData {
+        currency: Currency::YER,
+        code: "YER",
+        numeric_code: Some(886),
+        name: "Yemeni Rial",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::YUD,
+        code: "YUD",
+        numeric_code: Some(890),
+        name: "Yugoslavian Hard Dinar (1966–1990)",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((1990, 1)),
+    },
+    CurrencyData {
+        currency: Currency::YUM,
+        code: "YUM",
+        numeric_code: Some(891),
+        name: "Yugoslavian New Dinar (1994–2002)",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((2003, 7)),
+    },
+    CurrencyData {
+        currency: Currency::YUN,
+        code: "YUN",
+        numeric_code: Some(890),
+        name: "Yugoslavian Convertible Dinar (1990–1992)",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((1995, 11)),
+    },
+    CurrencyData {
+        currency: Currency::YUR,
+        code: "YUR",
+        numeric_code: None,
+        name: "Yugoslavian Reformed Dinar (1992–1993)",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((1993, 1)),
+    },
+    CurrencyData {
+        currency: Currency::ZAL,
+        code: "ZAL",
+        numeric_code: Some(991),
+        name: "South African Rand (financial)",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((1995, 3)),
+    },
+    CurrencyData {
+        currency: Currency::ZAR,
+        code: "ZAR",
+        numeric_code: Some(710),
+        name: "South African Rand",
+        symbol: None,
+        narrow_symbol: Some("R"),
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::ZMK,
+        code: "ZMK",
+        numeric_code: Some(894),
+        name: "Zambian Kwacha (1968–2012)",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((2013, 1)),
+    },
+    CurrencyData {
+        currency: Currency::ZMW,
+        code: "ZMW",
+        numeric_code: Some(967),
+        name: "Zambian Kwacha",
+        symbol: None,
+        narrow_symbol: Some("ZK"),
+        withdrawn: None,
+    },
+    CurrencyData {
+        currency: Currency::ZRN,
+        code: "ZRN",
+        numeric_code: Some(180),
+        name: "Zairean New Zaire (1993–1998)",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((1999, 6)),
+    },
+    CurrencyData {
+        currency: Currency::ZRZ,
+        code: "ZRZ",
+        numeric_code: Some(180),
+        name: "Zairean Zaire (1971–1993)",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((1994, 2)),
+    },
+    CurrencyData {
+        currency: Currency::ZWD,
+        code: "ZWD",
+        numeric_code: Some(716),
+        name: "Zimbabwean Dollar (1980–2008)",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: Some((2008, 8)),
+    },
+    CurrencyData {
+        currency: Currency::ZWL,
+        code: "ZWL",
+        numeric_code: Some(932),
+        name: "Zimbabwean Dollar (2009)",
+        symbol: None,
+        narrow_symbol: None,
+        withdrawn: None,
+    },
+];
@@ -0,0 +1,344 @@
+use super::{money::Money, rounding::RoundingMode};
+use crate::{
+    numeric::Percentage,
+    result::{ValobsError, ValobsResult},
+    traits::ValueObject,
+};
+use serde::{Deserialize, Serialize};
+
+/// A value object representing a discount, which takes an amount off a price.
+///
+/// ## What is a _Discount_?
+///
+/// A discount is either a percentage of the price, such as 10% off, or a fixed amount, such as 5.00 USD off.
+/// A discount may also be limited: a _cap_ is the most it takes off, and a _floor_ is the least it takes off,
+/// such as "10% off, at least 2.00 USD and at most 50.00 USD".
+///
+/// A discount never takes off more than the price itself, so a discounted price is never negative.
+///
+/// ## When to use
+///
+/// Use it for coupons, promotions and negotiated rebates, where the same discount must always give the same result.
+/// Percentage discounts are rounded to the minor unit of the currency with a [RoundingMode], half to even by default.
+///
+/// ## Example
+///
+/// ```
+/// use valobs::financial::{Currency, Discount, Money};
+/// use valobs::numeric::Percentage;
+/// use valobs::result::ValobsResult;
+///
+/// fn main() -> ValobsResult<()> {
+///   let discount = Discount::percentage(Percentage::from_basis_points(1000))?
+///     .with_cap(Money::new(500, Currency::USD))?;
+///
+///   // 10% off 20.00 USD
+///   assert_eq!(discount.apply(&Money::new(2000, Currency::USD))?, Money::new(1800, Currency::USD));
+///
+///   // 10% off 80.00 USD would be 8.00 USD, but the discount is capped at 5.00 USD
+///   assert_eq!(discount.apply(&Money::new(8000, Currency::USD))?, Money::new(7500, Currency::USD));
+///
+///   Ok(())
+/// }
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Serialize, Deserialize)]
+#[serde(try_from = "DiscountFields")]
+pub struct Discount {
+    kind: DiscountKind,
+    cap: Option<Money>,
+    floor: Option<Money>,
+    rounding: RoundingMode,
+}
+
+/// How much a [Discount] takes off, before it is limited by its cap and floor.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Serialize, Deserialize)]
+pub enum DiscountKind {
+    /// A percentage of the price, between 0% and 100%.
+    Percentage(Percentage),
+    /// A fixed amount of money.
+    Fixed(Money),
+}
+
+impl Discount {
+    /// Create a new `Discount` instance that takes a percentage of the price off.
+    ///
+    /// The percentage must be between 0% and 100%.
+    pub fn percentage(percentage: Percentage) -> ValobsResult<Discount> {
+        if !percentage.is_proportion() {
+            return Err(ValobsError::OutOfRange {
+                field: "percentage",
+                value: percentage.percent().try_into().unwrap_or_default(),
+                min: 0.0,
+                max: 100.0,
+            });
+        }
+        Ok(Self::with_kind(DiscountKind::Percentage(percentage)))
+    }
+
+    /// Create a new `Discount` instance that takes a fixed amount of money off.
+    ///
+    /// The amount must not be negative.
+    pub fn fixed(amount: Money) -> ValobsResult<Discount> {
+        check_not_negative("amount", &amount)?;
+        Ok(Self::with_kind(DiscountKind::Fixed(amount)))
+    }
+
+    /// Set the most the discount takes off.
+    ///
+    /// The cap must not be negative, nor less than the floor.
+    pub fn with_cap(mut self, cap: Money) -> ValobsResult<Discount> {
+        check_not_negative("cap", &cap)?;
+        self.cap = Some(cap);
+        self.check_limits()?;
+        Ok(self)
+    }
+
+    /// Set the least the discount takes off, as long as the price is not lower.
+    ///
+    /// The floor must not be negative, nor greater than the cap.
+    pub fn with_floor(mut self, floor: Money) -> ValobsResult<Discount> {
+        check_not_negative("floor", &floor)?;
+        self.floor = Some(floor);
+        self.check_limits()?;
+        Ok(self)
+    }
+
+    /// Set the rounding mode of a percentage discount.
+    pub fn with_rounding(mut self, rounding: RoundingMode) -> Discount {
+        self.rounding = rounding;
+        self
+    }
+
+    /// Get how much the discount takes off, before it is limited by its cap and floor.
+    pub fn kind(&self) -> DiscountKind {
+        self.kind
+    }
+
+    /// Get the most the discount takes off.
+    pub fn cap(&self) -> Option<Money> {
+        self.cap
+    }
+
+    /// Get the least the discount takes off.
+    pub fn floor(&self) -> Option<Money> {
+        self.floor
+    }
+
+    /// Get the amount the discount takes off a price.
+    ///
+    /// The price must not be negative, and must have the same currency as the fixed amount, cap and floor of the discount.
+    pub fn amount_off(&self, price: &Money) -> ValobsResult<Money> {
+        check_not_negative("price", price)?;
+        let mut amount = match self.kind {
+            DiscountKind::Percentage(percentage) => percentage.of(price, self.rounding)?,
+            DiscountKind::Fixed(amount) => {
+                price.check_currency(&amount)?;
+                amount
+            }
+        };
+        if let Some(floor) = self.floor {
+            price.check_currency(&floor)?;
+            amount = max(amount, floor);
+        }
+        if let Some(cap) = self.cap {
+            price.check_currency(&cap)?;
+            amount = min(amount, cap);
+        }
+        Ok(min(amount, *price))
+    }
+
+    /// Apply the discount to a price, and get the discounted price.
+    pub fn apply(&self, price: &Money) -> ValobsResult<Money> {
+        price.subtract(&self.amount_off(price)?)
+    }
+
+    fn with_kind(kind: DiscountKind) -> Self {
+        Self {
+            kind,
+            cap: None,
+            floor: None,
+            rounding: RoundingMode::default(),
+        }
+    }
+
+    fn check_limits(&self) -> ValobsResult<()> {
+        let (Some(floor), Some(cap)) = (self.floor, self.cap) else {
+            return Ok(());
+        };
+        floor.check_currency(&cap)?;
+        if floor.amount() > cap.amount() {
+            return Err(ValobsError::OutOfRange {
+                field: "floor",
+                value: floor.amount() as f64,
+                min: 0.0,
+                max: cap.amount() as f64,
+            });
+        }
+        Ok(())
+    }
+}
+
+impl ValueObject<'_> for Discount {}
+
+#[derive(Deserialize)]
+struct DiscountFields {
+    kind: DiscountKind,
+    cap: Option<Money>,
+    floor: Option<Money>,
+    rounding: RoundingMode,
+}
+
+impl TryFrom<DiscountFields> for Discount {
+    type Error = ValobsError;
+
+    fn try_from(fields: DiscountFields) -> ValobsResult<Self> {
+        let mut discount = match fields.kind {
+            DiscountKind::Percentage(percentage) => Self::percentage(percentage)?,
+            DiscountKind::Fixed(amount) => Self::fixed(amount)?,
+        }
+        .with_rounding(fields.rounding);
+        if let Some(cap) = fields.cap {
+            discount = discount.with_cap(cap)?;
+        }
+        if let Some(floor) = fields.floor {
+            discount = discount.with_floor(floor)?;
+        }
+        Ok(discount)
+    }
+}
+
+fn check_not_negative(field: &'static str, money: &Money) -> ValobsResult<()> {
+    if money.is_negative() {
+        return Err(ValobsError::Negative {
+            field,
+            value: money.amount() as f64,
+        });
+    }
+    Ok(())
+}
+
+/// Returns the smaller of two amounts in the same currency.
+fn min(money: Money, other: Money) -> Money {
+    if other.amount() < money.amount() {
+        other
+    } else {
+        money
+    }
+}
+
+/// Returns the larger of two amounts in the same currency.
+fn max(money: Money, other: Money) -> Money {
+    if other.amount() > money.amount() {
+        other
+    } else {
+        money
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::financial::Currency;
+
+    fn usd(amount: i64) -> Money {
+        Money::new(amount, Currency::USD)
+    }
+
+    #[test]
+    fn apply_percentage_discount() -> ValobsResult<()> {
+        // Arrange
+        let discount = Discount::percentage(Percentage::from_basis_points(1250))?;
+
+        // Act
+        let result = discount.apply(&usd(1004))?;
+
+        // Assert
+        assert_eq!(discount.amount_off(&usd(1004))?, usd(126));
+        assert_eq!(result, usd(878));
+        assert_eq!(
+            discount
+                .with_rounding(RoundingMode::Floor)
+                .amount_off(&usd(1004))?,
+            usd(125)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn apply_fixed_discount() -> ValobsResult<()> {
+        // Arrange
+        let discount = Discount::fixed(usd(500))?;
+
+        // Act
+        let result = discount.apply(&usd(1200))?;
+
+        // Assert
+        assert_eq!(result, usd(700));
+        assert_eq!(discount.apply(&usd(300))?, usd(0));
+        assert!(discount.apply(&Money::new(1200, Currency::EUR)).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn limit_discount_with_cap_and_floor() -> ValobsResult<()> {
+        // Arrange
+        let discount = Discount::percentage(Percentage::from_basis_points(1000))?
+            .with_floor(usd(200))?
+            .with_cap(usd(5000))?;
+
+        // Assert
+        assert_eq!(discount.amount_off(&usd(1000))?, usd(200));
+        assert_eq!(discount.amount_off(&usd(10000))?, usd(1000));
+        assert_eq!(discount.amount_off(&usd(100000))?, usd(5000));
+        assert_eq!(discount.amount_off(&usd(150))?, usd(150));
+
+        Ok(())
+    }
+
+    #[test]
+    fn fails_to_create_invalid_discount() {
+        assert!(Discount::percentage(Percentage::from_basis_points(10001)).is_err());
+        assert!(Discount::percentage(Percentage::from_basis_points(-1)).is_err());
+        assert_eq!(
+            Discount::fixed(usd(-1)),
+            Err(ValobsError::Negative {
+                field: "amount",
+                value: -1.0,
+            })
+        );
+        assert!(Discount::fixed(usd(100))
+            .and_then(|discount| discount.with_cap(usd(50)))
+            .and_then(|discount| discount.with_floor(usd(60)))
+            .is_err());
+        assert!(Discount::fixed(usd(100))
+            .unwrap()
+            .amount_off(&usd(-100))
+            .is_err());
+    }
+
+    #[test]
+    fn serialize_discount_to_json() -> ValobsResult<()> {
+        // Arrange
+        let discount =
+            Discount::percentage(Percentage::from_basis_points(1000))?.with_cap(usd(500))?;
+
+        // Act
+        let json = serde_json::to_string(&discount).unwrap();
+        let result = serde_json::from_str::<Discount>(&json).unwrap();
+
+        // Assert
+        assert_eq!(
+            json,
+            r#"{"kind":{"Percentage":"10"},"cap":{"amount":500,"currency":"USD"},"floor":null,"rounding":"HalfEven"}"#
+        );
+        assert_eq!(result, discount);
+        assert!(serde_json::from_str::<Discount>(
+            r#"{"kind":{"Percentage":"150"},"cap":null,"floor":null,"rounding":"HalfEven"}"#
+        )
+        .is_err());
+
+        Ok(())
+    }
+}
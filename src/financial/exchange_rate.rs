@@ -0,0 +1,477 @@
+use super::{currency::Currency, money::Money, rounding::RoundingMode};
+use crate::{
+    result::{ValobsError, ValobsResult},
+    temporal::{DateTimeTZ, Utc},
+    traits::ValueObject,
+};
+use rust_decimal::{prelude::ToPrimitive, Decimal};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// A value object representing the rate to exchange one currency for another at a point in time.
+///
+/// ## What is an _Exchange Rate_?
+///
+/// An exchange rate is the price of the `base` currency expressed in the `quote` currency.
+/// A rate of `1.1` from EUR to USD means that 1 euro buys 1.10 US dollars.
+/// Rates change constantly, so each rate records the moment it was observed (`as_of`).
+///
+/// ## When to use
+///
+/// Use it to convert [Money] between currencies, either directly with [ExchangeRate::convert],
+/// or through a [RateProvider] with [Money::convert_to](crate::financial::Money::convert_to).
+///
+/// ## Example
+///
+/// ```
+/// use valobs::financial::{Currency, Decimal, ExchangeRate, Money, RoundingMode};
+/// use valobs::result::ValobsResult;
+/// use valobs::temporal::Utc;
+///
+/// fn main() -> ValobsResult<()> {
+///   // 1 EUR = 1.0850 USD
+///   let rate = ExchangeRate::new(Currency::EUR, Currency::USD, Decimal::new(10850, 4), Utc::now())?;
+///
+///   let result = rate.convert(&Money::new(1000, Currency::EUR), RoundingMode::HalfEven)?;
+///
+///   assert_eq!(result, Money::new(1085, Currency::USD));
+///
+///   Ok(())
+/// }
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+#[serde(try_from = "ExchangeRateFields")]
+pub struct ExchangeRate {
+    base: Currency,
+    quote: Currency,
+    rate: Decimal,
+    as_of: DateTimeTZ<Utc>,
+}
+
+impl ExchangeRate {
+    /// Create a new `ExchangeRate` instance.
+    ///
+    /// The rate must be greater than zero, and exactly `1` when the base and quote currencies are the same.
+    pub fn new(
+        base: Currency,
+        quote: Currency,
+        rate: Decimal,
+        as_of: DateTimeTZ<Utc>,
+    ) -> ValobsResult<ExchangeRate> {
+        if rate <= Decimal::ZERO {
+            return Err(ValobsError::NotPositive {
+                field: "rate",
+                value: rate.to_f64().unwrap_or_default(),
+            });
+        }
+        if base == quote && rate != Decimal::ONE {
+            return Err(ValobsError::OutOfRange {
+                field: "rate",
+                value: rate.to_f64().unwrap_or_default(),
+                min: 1.0,
+                max: 1.0,
+            });
+        }
+        Ok(Self {
+            base,
+            quote,
+            rate,
+            as_of,
+        })
+    }
+
+    /// Get the currency being priced.
+    pub fn base(&self) -> Currency {
+        self.base
+    }
+
+    /// Get the currency the price is expressed in.
+    pub fn quote(&self) -> Currency {
+        self.quote
+    }
+
+    /// Get the amount of the quote currency that one unit of the base currency buys.
+    pub fn rate(&self) -> Decimal {
+        self.rate
+    }
+
+    /// Get the moment the rate was observed.
+    pub fn as_of(&self) -> DateTimeTZ<Utc> {
+        self.as_of
+    }
+
+    /// Get the rate to exchange the quote currency back into the base currency.
+    pub fn inverse(&self) -> ValobsResult<ExchangeRate> {
+        let rate = Decimal::ONE
+            .checked_div(self.rate)
+            .ok_or(ValobsError::Overflow { field: "rate" })?;
+        Ok(Self {
+            base: self.quote,
+            quote: self.base,
+            rate,
+            as_of: self.as_of,
+        })
+    }
+
+    /// Chain this rate with a rate from its quote currency to a third currency.
+    ///
+    /// For example, chaining EUR to USD with USD to JPY gives the cross rate from EUR to JPY.
+    /// A cross rate is only as recent as its inputs, so it is dated at the older of the two.
+    /// A cross rate back into the base currency is exactly `1`, whatever the two rates are.
+    pub fn cross(&self, next: &ExchangeRate) -> ValobsResult<ExchangeRate> {
+        if self.quote != next.base {
+            return Err(ValobsError::CurrencyMismatch {
+                expected: self.quote,
+                found: next.base,
+            });
+        }
+        let rate = if self.base == next.quote {
+            Decimal::ONE
+        } else {
+            self.rate
+                .checked_mul(next.rate)
+                .ok_or(ValobsError::Overflow { field: "rate" })?
+        };
+        Ok(Self {
+            base: self.base,
+            quote: next.quote,
+            rate,
+            as_of: self.as_of.min(next.as_of),
+        })
+    }
+
+    /// Convert money in the base currency into the quote currency.
+    ///
+    /// The result is rounded to the minor unit of the quote currency with the given rounding mode.
+    pub fn convert(&self, money: &Money, rounding: RoundingMode) -> ValobsResult<Money> {
+        if *money.currency() != self.base {
+            return Err(ValobsError::CurrencyMismatch {
+                expected: self.base,
+                found: *money.currency(),
+            });
+        }
+        let amount = money
+            .to_decimal()
+            .checked_mul(self.rate)
+            .ok_or(ValobsError::Overflow { field: "amount" })?;
+        Money::from_decimal(amount, self.quote, rounding)
+    }
+}
+
+impl ValueObject<'_> for ExchangeRate {}
+
+#[derive(Deserialize)]
+struct ExchangeRateFields {
+    base: Currency,
+    quote: Currency,
+    rate: Decimal,
+    as_of: DateTimeTZ<Utc>,
+}
+
+impl TryFrom<ExchangeRateFields> for ExchangeRate {
+    type Error = ValobsError;
+
+    fn try_from(fields: ExchangeRateFields) -> ValobsResult<Self> {
+        Self::new(fields.base, fields.quote, fields.rate, fields.as_of)
+    }
+}
+
+/// A source of exchange rates, such as a cache of rates fetched from a bank or a market data feed.
+///
+/// Implement it to let [Money::convert_to](crate::financial::Money::convert_to) use your own rates.
+pub trait RateProvider {
+    /// Returns the rate to exchange the `base` currency into the `quote` currency.
+    ///
+    /// Fails with [ValobsError::MissingExchangeRate] if the provider doesn't know the rate.
+    fn rate(&self, base: Currency, quote: Currency) -> ValobsResult<ExchangeRate>;
+}
+
+/// A [RateProvider] that keeps a fixed set of exchange rates in memory.
+///
+/// Besides the rates it holds, it derives the inverse of every rate,
+/// and, when a pivot currency is set, the cross rates between any two currencies that have a rate to the pivot.
+///
+/// ## When to use
+///
+/// Use it for rates that are loaded once, such as a daily reference rate table, and in tests.
+///
+/// ## Example
+///
+/// ```
+/// use valobs::financial::{Currency, Decimal, ExchangeRate, Money, RoundingMode, StaticRateProvider};
+/// use valobs::result::ValobsResult;
+/// use valobs::temporal::Utc;
+///
+/// fn main() -> ValobsResult<()> {
+///   let now = Utc::now();
+///   let provider = StaticRateProvider::new()
+///     .with_pivot(Currency::EUR)
+///     .with_rate(ExchangeRate::new(Currency::EUR, Currency::USD, Decimal::new(125, 2), now)?)
+///     .with_rate(ExchangeRate::new(Currency::EUR, Currency::GBP, Decimal::new(8, 1), now)?);
+///
+///   // USD to GBP through EUR: 1 USD = 0.80 EUR = 0.64 GBP
+///   let result = Money::new(10000, Currency::USD).convert_to(Currency::GBP, &provider, RoundingMode::HalfEven)?;
+///
+///   assert_eq!(result, Money::new(6400, Currency::GBP));
+///
+///   Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct StaticRateProvider {
+    rates: HashMap<(Currency, Currency), ExchangeRate>,
+    pivot: Option<Currency>,
+}
+
+impl StaticRateProvider {
+    /// Create an empty `StaticRateProvider` instance without a pivot currency.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the currency used to derive cross rates, such as `EUR` for the reference rates of the European Central Bank.
+    pub fn with_pivot(mut self, pivot: Currency) -> Self {
+        self.pivot = Some(pivot);
+        self
+    }
+
+    /// Add a rate, replacing any rate between the same currencies in the same direction.
+    pub fn with_rate(mut self, rate: ExchangeRate) -> Self {
+        self.insert(rate);
+        self
+    }
+
+    /// Add a rate, replacing any rate between the same currencies in the same direction.
+    pub fn insert(&mut self, rate: ExchangeRate) {
+        self.rates.insert((rate.base, rate.quote), rate);
+    }
+
+    /// Get the currency used to derive cross rates.
+    pub fn pivot(&self) -> Option<Currency> {
+        self.pivot
+    }
+
+    fn direct_or_inverse(&self, base: Currency, quote: Currency) -> ValobsResult<ExchangeRate> {
+        if let Some(rate) = self.rates.get(&(base, quote)) {
+            return Ok(*rate);
+        }
+        match self.rates.get(&(quote, base)) {
+            Some(rate) => rate.inverse(),
+            None => Err(ValobsError::MissingExchangeRate { base, quote }),
+        }
+    }
+}
+
+impl RateProvider for StaticRateProvider {
+    fn rate(&self, base: Currency, quote: Currency) -> ValobsResult<ExchangeRate> {
+        let direct = self.direct_or_inverse(base, quote);
+        let pivot = match (&direct, self.pivot) {
+            (Err(ValobsError::MissingExchangeRate { .. }), Some(pivot))
+                if pivot != base && pivot != quote =>
+            {
+                pivot
+            }
+            _ => return direct,
+        };
+        match (
+            self.direct_or_inverse(base, pivot),
+            self.direct_or_inverse(pivot, quote),
+        ) {
+            (Ok(to_pivot), Ok(from_pivot)) => to_pivot.cross(&from_pivot),
+            (Err(ValobsError::MissingExchangeRate { .. }), _)
+            | (_, Err(ValobsError::MissingExchangeRate { .. })) => direct,
+            (Err(error), _) | (_, Err(error)) => Err(error),
+        }
+    }
+}
+
+impl FromIterator<ExchangeRate> for StaticRateProvider {
+    fn from_iter<I: IntoIterator<Item = ExchangeRate>>(rates: I) -> Self {
+        let mut provider = Self::new();
+        for rate in rates {
+            provider.insert(rate);
+        }
+        provider
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::temporal::TimeZone;
+    use rust_decimal_macros::dec;
+
+    fn rate(base: Currency, quote: Currency, rate: Decimal) -> ExchangeRate {
+        let as_of = Utc.with_ymd_and_hms(2024, 1, 2, 16, 0, 0).unwrap();
+        ExchangeRate::new(base, quote, rate, as_of).unwrap()
+    }
+
+    #[test]
+    fn create_exchange_rate() -> ValobsResult<()> {
+        // Act
+        let result = ExchangeRate::new(Currency::EUR, Currency::USD, dec!(1.085), Utc::now())?;
+
+        // Assert
+        assert_eq!(result.base(), Currency::EUR);
+        assert_eq!(result.quote(), Currency::USD);
+        assert_eq!(result.rate(), dec!(1.085));
+
+        Ok(())
+    }
+
+    #[test]
+    fn fails_to_create_exchange_rate_when_rate_is_not_positive() {
+        assert_eq!(
+            ExchangeRate::new(Currency::EUR, Currency::USD, dec!(0), Utc::now()),
+            Err(ValobsError::NotPositive {
+                field: "rate",
+                value: 0.0,
+            })
+        );
+        assert!(ExchangeRate::new(Currency::EUR, Currency::USD, dec!(-1), Utc::now()).is_err());
+    }
+
+    #[test]
+    fn fails_to_create_exchange_rate_between_same_currency_unless_rate_is_one() -> ValobsResult<()>
+    {
+        assert_eq!(
+            ExchangeRate::new(Currency::EUR, Currency::EUR, dec!(1.3), Utc::now()),
+            Err(ValobsError::OutOfRange {
+                field: "rate",
+                value: 1.3,
+                min: 1.0,
+                max: 1.0,
+            })
+        );
+        assert_eq!(
+            ExchangeRate::new(Currency::EUR, Currency::EUR, dec!(1.000), Utc::now())?.rate(),
+            Decimal::ONE
+        );
+
+        // A cross rate back into the base currency is exactly 1
+        let eur_usd = rate(Currency::EUR, Currency::USD, dec!(1.0845));
+        let usd_eur = rate(Currency::USD, Currency::EUR, dec!(0.9221));
+        assert_eq!(eur_usd.cross(&usd_eur)?.rate(), Decimal::ONE);
+
+        Ok(())
+    }
+
+    #[test]
+    fn convert_money_with_exchange_rate() -> ValobsResult<()> {
+        // Arrange
+        let eur_usd = rate(Currency::EUR, Currency::USD, dec!(1.0845));
+
+        // Act
+        let even = eur_usd.convert(&Money::new(1000, Currency::EUR), RoundingMode::HalfEven)?;
+        let up = eur_usd.convert(&Money::new(1000, Currency::EUR), RoundingMode::HalfUp)?;
+        let yen = rate(Currency::USD, Currency::JPY, dec!(141.5))
+            .convert(&Money::new(1001, Currency::USD), RoundingMode::HalfEven)?;
+
+        // Assert
+        assert_eq!(even, Money::new(1084, Currency::USD));
+        assert_eq!(up, Money::new(1085, Currency::USD));
+        assert_eq!(yen, Money::new(1416, Currency::JPY));
+        assert_eq!(
+            eur_usd.convert(&Money::new(1000, Currency::USD), RoundingMode::HalfEven),
+            Err(ValobsError::CurrencyMismatch {
+                expected: Currency::EUR,
+                found: Currency::USD,
+            })
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn inverse_and_cross_rates() -> ValobsResult<()> {
+        // Arrange
+        let eur_usd = rate(Currency::EUR, Currency::USD, dec!(1.25));
+        let usd_jpy = rate(Currency::USD, Currency::JPY, dec!(140));
+
+        // Act
+        let inverse = eur_usd.inverse()?;
+        let cross = eur_usd.cross(&usd_jpy)?;
+
+        // Assert
+        assert_eq!(
+            (inverse.base(), inverse.quote()),
+            (Currency::USD, Currency::EUR)
+        );
+        assert_eq!(inverse.rate(), dec!(0.8));
+        assert_eq!(
+            (cross.base(), cross.quote()),
+            (Currency::EUR, Currency::JPY)
+        );
+        assert_eq!(cross.rate(), dec!(175));
+        assert!(usd_jpy.cross(&eur_usd).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn provide_direct_inverse_and_triangulated_rates() -> ValobsResult<()> {
+        // Arrange
+        let provider = StaticRateProvider::from_iter([
+            rate(Currency::EUR, Currency::USD, dec!(1.25)),
+            rate(Currency::EUR, Currency::GBP, dec!(0.8)),
+        ])
+        .with_pivot(Currency::EUR);
+
+        // Act
+        let direct = provider.rate(Currency::EUR, Currency::USD)?;
+        let inverse = provider.rate(Currency::GBP, Currency::EUR)?;
+        let triangulated = provider.rate(Currency::USD, Currency::GBP)?;
+
+        // Assert
+        assert_eq!(direct.rate(), dec!(1.25));
+        assert_eq!(inverse.rate(), dec!(1.25));
+        assert_eq!(triangulated.rate(), dec!(0.64));
+        assert_eq!(
+            provider.rate(Currency::USD, Currency::JPY),
+            Err(ValobsError::MissingExchangeRate {
+                base: Currency::USD,
+                quote: Currency::JPY,
+            })
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn fails_to_triangulate_without_pivot() {
+        // Arrange
+        let provider = StaticRateProvider::from_iter([
+            rate(Currency::EUR, Currency::USD, dec!(1.25)),
+            rate(Currency::EUR, Currency::GBP, dec!(0.8)),
+        ]);
+
+        // Act
+        let result = provider.rate(Currency::USD, Currency::GBP);
+
+        // Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn serialize_exchange_rate_to_json() -> ValobsResult<()> {
+        // Arrange
+        let eur_usd = rate(Currency::EUR, Currency::USD, dec!(1.085));
+
+        // Act
+        let json = serde_json::to_string(&eur_usd).unwrap();
+        let result = serde_json::from_str::<ExchangeRate>(&json).unwrap();
+
+        // Assert
+        assert_eq!(
+            json,
+            r#"{"base":"EUR","quote":"USD","rate":"1.085","as_of":"2024-01-02T16:00:00Z"}"#
+        );
+        assert_eq!(result, eur_usd);
+        assert!(serde_json::from_str::<ExchangeRate>(
+            r#"{"base":"EUR","quote":"USD","rate":"0","as_of":"2024-01-02T16:00:00Z"}"#
+        )
+        .is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,755 @@
+use super::{currency::Currency, money::Money};
+use crate::result::{ValobsError, ValobsResult};
+use serde::{Deserialize, Serialize};
+
+/// A locale to format and parse money with.
+///
+/// Each locale carries the decimal and grouping separators, the position of the currency symbol,
+/// and the currency symbols that are used in it.
+/// The data is derived from the [CLDR](https://cldr.unicode.org/) version 44, and embedded in the crate.
+///
+/// ## Example
+///
+/// ```
+/// use valobs::financial::{Currency, Locale};
+///
+/// assert_eq!(Locale::DeDe.currency(), Currency::EUR);
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+pub enum Locale {
+    /// English (United States)
+    EnUs,
+    /// English (United Kingdom)
+    EnGb,
+    /// English (India)
+    EnIn,
+    /// German (Germany)
+    DeDe,
+    /// German (Switzerland)
+    DeCh,
+    /// French (France)
+    FrFr,
+    /// Spanish (Spain)
+    EsEs,
+    /// Italian (Italy)
+    ItIt,
+    /// Dutch (Netherlands)
+    NlNl,
+    /// Portuguese (Brazil)
+    PtBr,
+    /// Japanese (Japan)
+    JaJp,
+    /// Korean (South Korea)
+    KoKr,
+    /// Chinese (China)
+    ZhCn,
+}
+
+impl Locale {
+    /// Returns the currency of the region of the locale, such as `USD` for `EnUs`.
+    pub fn currency(&self) -> Currency {
+        self.data().currency
+    }
+
+    fn data(&self) -> &'static LocaleData {
+        match self {
+            Self::EnUs => &EN_US,
+            Self::EnGb => &EN_GB,
+            Self::EnIn => &EN_IN,
+            Self::DeDe => &DE_DE,
+            Self::DeCh => &DE_CH,
+            Self::FrFr => &FR_FR,
+            Self::EsEs => &ES_ES,
+            Self::ItIt => &IT_IT,
+            Self::NlNl => &NL_NL,
+            Self::PtBr => &PT_BR,
+            Self::JaJp => &JA_JP,
+            Self::KoKr => &KO_KR,
+            Self::ZhCn => &ZH_CN,
+        }
+    }
+}
+
+/// How the currency is shown when money is formatted.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, Serialize, Deserialize)]
+pub enum CurrencyDisplay {
+    /// The currency symbol of the locale, such as `$` or `€`.
+    /// Currencies without a symbol in the locale fall back to their ISO 4217 code.
+    #[default]
+    Symbol,
+    /// The ISO 4217 code, such as `USD` or `EUR`.
+    Code,
+}
+
+/// Formats `Money` for a [Locale], and parses it back.
+///
+/// ## Example
+///
+/// ```
+/// use valobs::financial::{Currency, CurrencyDisplay, Locale, Money, MoneyFormatter};
+/// use valobs::result::ValobsResult;
+///
+/// fn main() -> ValobsResult<()> {
+///   let money = Money::new(123456, Currency::EUR);
+///
+///   let formatter = MoneyFormatter::new(Locale::DeDe);
+///   assert_eq!(formatter.format(&money), "1.234,56\u{a0}€");
+///   assert_eq!(formatter.parse("1.234,56 €")?, money);
+///
+///   let formatter = MoneyFormatter::new(Locale::EnUs).with_currency_display(CurrencyDisplay::Code);
+///   assert_eq!(formatter.format(&money), "EUR\u{a0}1,234.56");
+///
+///   Ok(())
+/// }
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct MoneyFormatter {
+    locale: Locale,
+    currency_display: CurrencyDisplay,
+}
+
+impl MoneyFormatter {
+    /// Create a new `MoneyFormatter` for the given locale, which shows currency symbols.
+    pub fn new(locale: Locale) -> Self {
+        Self {
+            locale,
+            currency_display: CurrencyDisplay::default(),
+        }
+    }
+
+    /// Set how the currency is shown.
+    pub fn with_currency_display(self, currency_display: CurrencyDisplay) -> Self {
+        Self {
+            currency_display,
+            ..self
+        }
+    }
+
+    /// Get the locale of the formatter.
+    pub fn locale(&self) -> Locale {
+        self.locale
+    }
+
+    /// Get how the currency is shown.
+    pub fn currency_display(&self) -> CurrencyDisplay {
+        self.currency_display
+    }
+
+    /// Format the money, such as `$1,234.56` in `EnUs` or `1.234,56 €` in `DeDe`.
+    ///
+    /// Spaces are written as no-break spaces (U+00A0), and the grouping separator of `FrFr` is
+    /// a narrow no-break space (U+202F), as in the CLDR.
+    pub fn format(&self, money: &Money) -> String {
+        let data = self.locale.data();
+        let symbol = match self.currency_display {
+            CurrencyDisplay::Symbol => data.symbol(*money.currency()),
+            CurrencyDisplay::Code => money.currency().to_string(),
+        };
+
+        let decimal = money.to_decimal_string();
+        let unsigned = decimal.trim_start_matches('-');
+        let (integer, fraction) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+        let mut number = data.group(integer);
+        if !fraction.is_empty() {
+            number.push(data.decimal_separator);
+            number.push_str(fraction);
+        }
+
+        let pattern = if money.is_negative() {
+            data.negative_pattern
+        } else {
+            data.positive_pattern
+        };
+        // Separate a symbol made of letters from the digits, so that `CHF1.00` reads as `CHF 1.00`.
+        let symbol_before = match symbol.chars().last() {
+            Some(last) if last.is_alphabetic() && pattern.contains("¤#") => {
+                format!("{symbol}\u{a0}")
+            }
+            _ => symbol.clone(),
+        };
+        let symbol = match symbol.chars().next() {
+            Some(first) if first.is_alphabetic() && pattern.contains("#¤") => {
+                format!("\u{a0}{symbol}")
+            }
+            _ => symbol,
+        };
+
+        pattern
+            .replace("¤#", &format!("{symbol_before}#"))
+            .replace('¤', &symbol)
+            .replace('#', &number)
+    }
+
+    /// Parse money that was formatted for the locale, such as `$1,234.56` in `EnUs`.
+    ///
+    /// The currency is resolved from the currency symbols of the locale first, and from ISO 4217 codes second.
+    /// Any whitespace may be used where the locale uses a space.
+    /// The digits may be left ungrouped, such as `$1234.56`, but grouped digits must follow the grouping of the locale,
+    /// so `$1,23` is rejected in `EnUs`, while `₹12,34,567.00` is accepted in `EnIn`.
+    pub fn parse(&self, value: &str) -> ValobsResult<Money> {
+        let invalid_format = || ValobsError::InvalidFormat {
+            field: "money",
+            value: value.into(),
+        };
+        let data = self.locale.data();
+
+        let start = value
+            .find(|c: char| c.is_ascii_digit())
+            .ok_or_else(invalid_format)?;
+        let end = value
+            .rfind(|c: char| c.is_ascii_digit())
+            .ok_or_else(invalid_format)?
+            + 1;
+
+        let mut negative = false;
+        let mut symbols = Vec::new();
+        for affix in [&value[..start], &value[end..]] {
+            let mut symbol = String::new();
+            for c in affix.chars() {
+                match c {
+                    '-' | '\u{2212}' if !negative => negative = true,
+                    c => symbol.push(c),
+                }
+            }
+            let symbol = symbol.trim();
+            if !symbol.is_empty() {
+                symbols.push(symbol.to_string());
+            }
+        }
+        let [symbol] = symbols.as_slice() else {
+            return Err(invalid_format());
+        };
+        let currency = data.currency_of(symbol).ok_or_else(invalid_format)?;
+
+        let number = &value[start..end];
+        let (integer, fraction) = number
+            .split_once(data.decimal_separator)
+            .unwrap_or((number, ""));
+        let groups = integer
+            .split(|c| data.is_grouping_separator(c))
+            .collect::<Vec<_>>();
+        let digits = groups.concat();
+        if !digits
+            .chars()
+            .chain(fraction.chars())
+            .all(|c| c.is_ascii_digit())
+        {
+            return Err(invalid_format());
+        }
+        // Digits may be left ungrouped, but grouped digits must be grouped the way the locale formats them.
+        if groups.len() > 1
+            && !data
+                .group(&digits)
+                .split(data.grouping_separator)
+                .map(str::len)
+                .eq(groups.iter().map(|group| group.len()))
+        {
+            return Err(invalid_format());
+        }
+
+        let mut decimal = String::from(if negative { "-" } else { "" });
+        decimal.push_str(&digits);
+        if number.contains(data.decimal_separator) {
+            decimal.push('.');
+            decimal.push_str(fraction);
+        }
+
+        Money::from_decimal_str(&decimal, currency).map_err(|error| match error {
+            ValobsError::InvalidFormat { .. } => invalid_format(),
+            error => error,
+        })
+    }
+}
+
+/// The number and currency formats of a locale.
+///
+/// In the patterns, `¤` is replaced with the currency symbol, and `#` with the formatted number.
+struct LocaleData {
+    currency: Currency,
+    decimal_separator: char,
+    grouping_separator: char,
+    primary_grouping: usize,
+    secondary_grouping: usize,
+    minimum_grouping_digits: usize,
+    positive_pattern: &'static str,
+    negative_pattern: &'static str,
+    symbols: &'static [(Currency, &'static str)],
+}
+
+impl LocaleData {
+    fn symbol(&self, currency: Currency) -> String {
+        self.symbols
+            .iter()
+            .find(|(candidate, _)| *candidate == currency)
+            .map(|(_, symbol)| symbol.to_string())
+            .unwrap_or_else(|| currency.to_string())
+    }
+
+    fn currency_of(&self, symbol: &str) -> Option<Currency> {
+        // Any whitespace may stand in for the spaces of a symbol, such as in `F CFA`.
+        let normalize = |c: char| if c.is_whitespace() { ' ' } else { c };
+        self.symbols
+            .iter()
+            .find(|(_, candidate)| {
+                candidate
+                    .chars()
+                    .map(normalize)
+                    .eq(symbol.chars().map(normalize))
+            })
+            .map(|(currency, _)| *currency)
+            .or_else(|| symbol.parse().ok())
+    }
+
+    fn is_grouping_separator(&self, c: char) -> bool {
+        c == self.grouping_separator
+            || (self.grouping_separator.is_whitespace() && c.is_whitespace())
+    }
+
+    fn group(&self, integer: &str) -> String {
+        if integer.len() < self.primary_grouping + self.minimum_grouping_digits {
+            return integer.into();
+        }
+        let (mut head, tail) = integer.split_at(integer.len() - self.primary_grouping);
+        let mut groups = vec![tail];
+        while head.len() > self.secondary_grouping {
+            let (rest, group) = head.split_at(head.len() - self.secondary_grouping);
+            groups.push(group);
+            head = rest;
+        }
+        groups.push(head);
+        groups.reverse();
+        groups.join(&self.grouping_separator.to_string())
+    }
+}
+
+const EN_SYMBOLS: &[(Currency, &str)] = &[
+    (Currency::USD, "$"),
+    (Currency::EUR, "€"),
+    (Currency::GBP, "£"),
+    (Currency::JPY, "¥"),
+    (Currency::CNY, "CN¥"),
+    (Currency::KRW, "₩"),
+    (Currency::INR, "₹"),
+    (Currency::CAD, "CA$"),
+    (Currency::AUD, "A$"),
+    (Currency::NZD, "NZ$"),
+    (Currency::HKD, "HK$"),
+    (Currency::TWD, "NT$"),
+    (Currency::MXN, "MX$"),
+    (Currency::BRL, "R$"),
+    (Currency::ILS, "₪"),
+    (Currency::VND, "₫"),
+    (Currency::PHP, "₱"),
+    (Currency::XCD, "EC$"),
+    (Currency::XAF, "FCFA"),
+    (Currency::XOF, "F\u{202f}CFA"),
+    (Currency::XPF, "CFPF"),
+];
+
+const EN_US: LocaleData = LocaleData {
+    currency: Currency::USD,
+    decimal_separator: '.',
+    grouping_separator: ',',
+    primary_grouping: 3,
+    secondary_grouping: 3,
+    minimum_grouping_digits: 1,
+    positive_pattern: "¤#",
+    negative_pattern: "-¤#",
+    symbols: EN_SYMBOLS,
+};
+
+const EN_GB: LocaleData = LocaleData {
+    currency: Currency::GBP,
+    symbols: &[
+        (Currency::GBP, "£"),
+        (Currency::EUR, "€"),
+        (Currency::USD, "US$"),
+        (Currency::JPY, "JP¥"),
+        (Currency::CNY, "CN¥"),
+        (Currency::INR, "₹"),
+        (Currency::CAD, "CA$"),
+        (Currency::AUD, "A$"),
+        (Currency::NZD, "NZ$"),
+        (Currency::HKD, "HK$"),
+    ],
+    ..EN_US
+};
+
+const EN_IN: LocaleData = LocaleData {
+    currency: Currency::INR,
+    secondary_grouping: 2,
+    ..EN_US
+};
+
+const DE_DE: LocaleData = LocaleData {
+    currency: Currency::EUR,
+    decimal_separator: ',',
+    grouping_separator: '.',
+    primary_grouping: 3,
+    secondary_grouping: 3,
+    minimum_grouping_digits: 1,
+    positive_pattern: "#\u{a0}¤",
+    negative_pattern: "-#\u{a0}¤",
+    symbols: &[
+        (Currency::EUR, "€"),
+        (Currency::USD, "$"),
+        (Currency::GBP, "£"),
+        (Currency::JPY, "¥"),
+        (Currency::CNY, "CN¥"),
+        (Currency::INR, "₹"),
+        (Currency::BRL, "R$"),
+        (Currency::ILS, "₪"),
+    ],
+};
+
+const DE_CH: LocaleData = LocaleData {
+    currency: Currency::CHF,
+    decimal_separator: '.',
+    grouping_separator: '’',
+    positive_pattern: "¤\u{a0}#",
+    negative_pattern: "¤-#",
+    ..DE_DE
+};
+
+const FR_FR: LocaleData = LocaleData {
+    currency: Currency::EUR,
+    decimal_separator: ',',
+    grouping_separator: '\u{202f}',
+    primary_grouping: 3,
+    secondary_grouping: 3,
+    minimum_grouping_digits: 1,
+    positive_pattern: "#\u{a0}¤",
+    negative_pattern: "-#\u{a0}¤",
+    symbols: &[
+        (Currency::EUR, "€"),
+        (Currency::USD, "$US"),
+        (Currency::GBP, "£GB"),
+        (Currency::CAD, "$CA"),
+        (Currency::AUD, "$AU"),
+        (Currency::XAF, "FCFA"),
+        (Currency::XOF, "F\u{202f}CFA"),
+        (Currency::XPF, "FCFP"),
+    ],
+};
+
+const ES_ES: LocaleData = LocaleData {
+    currency: Currency::EUR,
+    decimal_separator: ',',
+    grouping_separator: '.',
+    primary_grouping: 3,
+    secondary_grouping: 3,
+    minimum_grouping_digits: 2,
+    positive_pattern: "#\u{a0}¤",
+    negative_pattern: "-#\u{a0}¤",
+    symbols: &[(Currency::EUR, "€"), (Currency::USD, "US$")],
+};
+
+const IT_IT: LocaleData = LocaleData {
+    currency: Currency::EUR,
+    minimum_grouping_digits: 1,
+    symbols: &[(Currency::EUR, "€"), (Currency::USD, "USD")],
+    ..ES_ES
+};
+
+const NL_NL: LocaleData = LocaleData {
+    currency: Currency::EUR,
+    decimal_separator: ',',
+    grouping_separator: '.',
+    primary_grouping: 3,
+    secondary_grouping: 3,
+    minimum_grouping_digits: 1,
+    positive_pattern: "¤\u{a0}#",
+    negative_pattern: "¤\u{a0}-#",
+    symbols: &[
+        (Currency::EUR, "€"),
+        (Currency::USD, "US$"),
+        (Currency::GBP, "£"),
+        (Currency::JPY, "JP¥"),
+    ],
+};
+
+const PT_BR: LocaleData = LocaleData {
+    currency: Currency::BRL,
+    negative_pattern: "-¤\u{a0}#",
+    symbols: &[
+        (Currency::BRL, "R$"),
+        (Currency::USD, "US$"),
+        (Currency::EUR, "€"),
+        (Currency::GBP, "£"),
+        (Currency::JPY, "JP¥"),
+    ],
+    ..NL_NL
+};
+
+const JA_JP: LocaleData = LocaleData {
+    currency: Currency::JPY,
+    symbols: &[
+        (Currency::JPY, "￥"),
+        (Currency::USD, "$"),
+        (Currency::EUR, "€"),
+        (Currency::GBP, "£"),
+        (Currency::CNY, "元"),
+        (Currency::KRW, "₩"),
+    ],
+    ..EN_US
+};
+
+const KO_KR: LocaleData = LocaleData {
+    currency: Currency::KRW,
+    symbols: &[
+        (Currency::KRW, "₩"),
+        (Currency::USD, "US$"),
+        (Currency::EUR, "€"),
+        (Currency::GBP, "£"),
+        (Currency::JPY, "JP¥"),
+        (Currency::CNY, "CN¥"),
+    ],
+    ..EN_US
+};
+
+const ZH_CN: LocaleData = LocaleData {
+    currency: Currency::CNY,
+    symbols: &[
+        (Currency::CNY, "¥"),
+        (Currency::USD, "US$"),
+        (Currency::EUR, "€"),
+        (Currency::GBP, "£"),
+        (Currency::JPY, "JP¥"),
+        (Currency::KRW, "￦"),
+    ],
+    ..EN_US
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_money_with_symbol() {
+        let money = Money::new(123456, Currency::USD);
+        assert_eq!(
+            MoneyFormatter::new(Locale::EnUs).format(&money),
+            "$1,234.56"
+        );
+
+        let money = Money::new(123456, Currency::EUR);
+        assert_eq!(
+            MoneyFormatter::new(Locale::DeDe).format(&money),
+            "1.234,56\u{a0}€"
+        );
+        assert_eq!(
+            MoneyFormatter::new(Locale::FrFr).format(&money),
+            "1\u{202f}234,56\u{a0}€"
+        );
+        assert_eq!(
+            MoneyFormatter::new(Locale::NlNl).format(&money),
+            "€\u{a0}1.234,56"
+        );
+
+        let money = Money::new(1235, Currency::JPY);
+        assert_eq!(MoneyFormatter::new(Locale::EnUs).format(&money), "¥1,235");
+        assert_eq!(MoneyFormatter::new(Locale::JaJp).format(&money), "￥1,235");
+    }
+
+    #[test]
+    fn format_negative_money() {
+        let money = Money::new(-123456, Currency::USD);
+        assert_eq!(
+            MoneyFormatter::new(Locale::EnUs).format(&money),
+            "-$1,234.56"
+        );
+
+        let money = Money::new(-123456, Currency::EUR);
+        assert_eq!(
+            MoneyFormatter::new(Locale::DeDe).format(&money),
+            "-1.234,56\u{a0}€"
+        );
+        assert_eq!(
+            MoneyFormatter::new(Locale::NlNl).format(&money),
+            "€\u{a0}-1.234,56"
+        );
+
+        let money = Money::new(-123456, Currency::CHF);
+        assert_eq!(
+            MoneyFormatter::new(Locale::DeCh).format(&money),
+            "CHF-1’234.56"
+        );
+    }
+
+    #[test]
+    fn format_money_with_code() {
+        let formatter =
+            MoneyFormatter::new(Locale::EnUs).with_currency_display(CurrencyDisplay::Code);
+        assert_eq!(
+            formatter.format(&Money::new(123456, Currency::USD)),
+            "USD\u{a0}1,234.56"
+        );
+
+        let formatter =
+            MoneyFormatter::new(Locale::DeDe).with_currency_display(CurrencyDisplay::Code);
+        assert_eq!(
+            formatter.format(&Money::new(123456, Currency::USD)),
+            "1.234,56\u{a0}USD"
+        );
+    }
+
+    #[test]
+    fn format_money_without_symbol_in_locale_falls_back_to_code() {
+        let money = Money::new(123456, Currency::CHF);
+        assert_eq!(
+            MoneyFormatter::new(Locale::EnUs).format(&money),
+            "CHF\u{a0}1,234.56"
+        );
+    }
+
+    #[test]
+    fn format_money_with_locale_specific_grouping() {
+        let money = Money::new(123456789, Currency::INR);
+        assert_eq!(
+            MoneyFormatter::new(Locale::EnIn).format(&money),
+            "₹12,34,567.89"
+        );
+
+        let formatter = MoneyFormatter::new(Locale::EsEs);
+        assert_eq!(
+            formatter.format(&Money::new(123456, Currency::EUR)),
+            "1234,56\u{a0}€"
+        );
+        assert_eq!(
+            formatter.format(&Money::new(1234567, Currency::EUR)),
+            "12.345,67\u{a0}€"
+        );
+    }
+
+    #[test]
+    fn parse_formatted_money() -> ValobsResult<()> {
+        assert_eq!(
+            MoneyFormatter::new(Locale::EnUs).parse("$1,234.56")?,
+            Money::new(123456, Currency::USD)
+        );
+        assert_eq!(
+            MoneyFormatter::new(Locale::EnUs).parse("-$1,234.56")?,
+            Money::new(-123456, Currency::USD)
+        );
+        assert_eq!(
+            MoneyFormatter::new(Locale::EnUs).parse("¥1,235")?,
+            Money::new(1235, Currency::JPY)
+        );
+        assert_eq!(
+            MoneyFormatter::new(Locale::ZhCn).parse("¥1,235.00")?,
+            Money::new(123500, Currency::CNY)
+        );
+        assert_eq!(
+            MoneyFormatter::new(Locale::DeDe).parse("1.234,56 €")?,
+            Money::new(123456, Currency::EUR)
+        );
+        assert_eq!(
+            MoneyFormatter::new(Locale::FrFr).parse("1 234,56 $US")?,
+            Money::new(123456, Currency::USD)
+        );
+        assert_eq!(
+            MoneyFormatter::new(Locale::DeCh).parse("CHF-1’234.56")?,
+            Money::new(-123456, Currency::CHF)
+        );
+        assert_eq!(
+            MoneyFormatter::new(Locale::EnUs).parse("GBP 12.50")?,
+            Money::new(1250, Currency::GBP)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse_what_was_formatted() -> ValobsResult<()> {
+        let locales = [
+            Locale::EnUs,
+            Locale::EnGb,
+            Locale::EnIn,
+            Locale::DeDe,
+            Locale::DeCh,
+            Locale::FrFr,
+            Locale::EsEs,
+            Locale::ItIt,
+            Locale::NlNl,
+            Locale::PtBr,
+            Locale::JaJp,
+            Locale::KoKr,
+            Locale::ZhCn,
+        ];
+        let amounts = [
+            Money::new(-123456789, Currency::USD),
+            Money::new(5, Currency::EUR),
+            Money::new(1234567, Currency::JPY),
+            Money::new(1234, Currency::BHD),
+            Money::new(100000, Currency::XOF),
+            Money::new(123456, Currency::CHF),
+        ];
+
+        for locale in locales {
+            for display in [CurrencyDisplay::Symbol, CurrencyDisplay::Code] {
+                let formatter = MoneyFormatter::new(locale).with_currency_display(display);
+                for money in amounts {
+                    assert_eq!(formatter.parse(&formatter.format(&money))?, money);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn fails_to_parse_invalid_money() {
+        let formatter = MoneyFormatter::new(Locale::EnUs);
+        for value in [
+            "",
+            "$",
+            "1,234.56",
+            "1.234,56 €",
+            "$12.345",
+            "XYZ 12",
+            "--$12",
+            "$12 USD",
+        ] {
+            assert!(formatter.parse(value).is_err(), "{value}");
+        }
+    }
+
+    #[test]
+    fn parse_grouped_and_ungrouped_money() -> ValobsResult<()> {
+        assert_eq!(
+            MoneyFormatter::new(Locale::EnUs).parse("$1234567.89")?,
+            Money::new(123456789, Currency::USD)
+        );
+        assert_eq!(
+            MoneyFormatter::new(Locale::EnIn).parse("₹12,34,567.00")?,
+            Money::new(123456700, Currency::INR)
+        );
+        assert_eq!(
+            MoneyFormatter::new(Locale::EsEs).parse("1234,56 €")?,
+            Money::new(123456, Currency::EUR)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn fails_to_parse_badly_grouped_money() {
+        for (locale, value) in [
+            (Locale::EnUs, "$1,23"),
+            (Locale::EnUs, "$1,2,3,4"),
+            (Locale::EnUs, "$12,34"),
+            (Locale::EnUs, "$1234,567.00"),
+            (Locale::EnUs, "$1,,234"),
+            (Locale::EnUs, "$1,234.5,6"),
+            (Locale::EnIn, "₹1,234,567.00"),
+            (Locale::DeDe, "1.23,45 €"),
+            // Spanish doesn't group four digits
+            (Locale::EsEs, "1.234,56 €"),
+        ] {
+            assert!(
+                MoneyFormatter::new(locale).parse(value).is_err(),
+                "{locale:?} {value}"
+            );
+        }
+    }
+}
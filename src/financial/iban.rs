@@ -0,0 +1,391 @@
+use crate::{
+    geography::Country,
+    result::{ValobsError, ValobsResult},
+    traits::ValueObject,
+};
+use serde::{Deserialize, Serialize};
+use std::{fmt, str::FromStr};
+
+/// The BBAN structure of every country in the IBAN registry, in the notation of the registry.
+///
+/// Each part is a length followed by `!` and a kind of character: `n` for digits, `a` for upper case letters,
+/// and `c` for upper case letters and digits.
+const BBAN_FORMATS: &[(&str, &str)] = &[
+    ("AD", "4!n4!n12!c"),
+    ("AE", "3!n16!n"),
+    ("AL", "8!n16!c"),
+    ("AT", "5!n11!n"),
+    ("AZ", "4!a20!c"),
+    ("BA", "3!n3!n8!n2!n"),
+    ("BE", "3!n7!n2!n"),
+    ("BG", "4!a4!n2!n8!c"),
+    ("BH", "4!a14!c"),
+    ("BI", "5!n5!n11!n2!n"),
+    ("BR", "8!n5!n10!n1!a1!c"),
+    ("BY", "4!c4!n16!c"),
+    ("CH", "5!n12!c"),
+    ("CR", "4!n14!n"),
+    ("CY", "3!n5!n16!c"),
+    ("CZ", "4!n6!n10!n"),
+    ("DE", "8!n10!n"),
+    ("DJ", "5!n5!n11!n2!n"),
+    ("DK", "4!n9!n1!n"),
+    ("DO", "4!c20!n"),
+    ("EE", "2!n2!n11!n1!n"),
+    ("EG", "4!n4!n17!n"),
+    ("ES", "4!n4!n1!n1!n10!n"),
+    ("FI", "3!n11!n"),
+    ("FK", "2!a12!n"),
+    ("FO", "4!n9!n1!n"),
+    ("FR", "5!n5!n11!c2!n"),
+    ("GB", "4!a6!n8!n"),
+    ("GE", "2!a16!n"),
+    ("GI", "4!a15!c"),
+    ("GL", "4!n9!n1!n"),
+    ("GR", "3!n4!n16!c"),
+    ("GT", "4!c20!c"),
+    ("HR", "7!n10!n"),
+    ("HU", "3!n4!n1!n15!n1!n"),
+    ("IE", "4!a6!n8!n"),
+    ("IL", "3!n3!n13!n"),
+    ("IQ", "4!a3!n12!n"),
+    ("IS", "4!n2!n6!n10!n"),
+    ("IT", "1!a5!n5!n12!c"),
+    ("JO", "4!a4!n18!c"),
+    ("KW", "4!a22!c"),
+    ("KZ", "3!n13!c"),
+    ("LB", "4!n20!c"),
+    ("LC", "4!a24!c"),
+    ("LI", "5!n12!c"),
+    ("LT", "5!n11!n"),
+    ("LU", "3!n13!c"),
+    ("LV", "4!a13!c"),
+    ("LY", "3!n3!n15!n"),
+    ("MC", "5!n5!n11!c2!n"),
+    ("MD", "2!c18!c"),
+    ("ME", "3!n13!n2!n"),
+    ("MK", "3!n10!c2!n"),
+    ("MN", "4!n12!n"),
+    ("MR", "5!n5!n11!n2!n"),
+    ("MT", "4!a5!n18!c"),
+    ("MU", "4!a2!n2!n12!n3!n3!a"),
+    ("NI", "4!a20!n"),
+    ("NL", "4!a10!n"),
+    ("NO", "4!n6!n1!n"),
+    ("OM", "3!n16!c"),
+    ("PK", "4!a16!c"),
+    ("PL", "8!n16!n"),
+    ("PS", "4!a21!c"),
+    ("PT", "4!n4!n11!n2!n"),
+    ("QA", "4!a21!c"),
+    ("RO", "4!a16!c"),
+    ("RS", "3!n13!n2!n"),
+    ("RU", "9!n5!n15!c"),
+    ("SA", "2!n18!c"),
+    ("SC", "4!a2!n2!n16!n3!a"),
+    ("SD", "2!n12!n"),
+    ("SE", "3!n16!n1!n"),
+    ("SI", "5!n8!n2!n"),
+    ("SK", "4!n6!n10!n"),
+    ("SM", "1!a5!n5!n12!c"),
+    ("SO", "4!n3!n12!n"),
+    ("ST", "4!n4!n11!n2!n"),
+    ("SV", "4!a20!n"),
+    ("TL", "3!n14!n2!n"),
+    ("TN", "2!n3!n13!n2!n"),
+    ("TR", "5!n1!n16!c"),
+    ("UA", "6!n19!c"),
+    ("VA", "3!n15!n"),
+    ("VG", "4!a16!n"),
+    ("XK", "4!n10!n2!n"),
+];
+
+/// A value object representing an International Bank Account Number (IBAN), as defined by ISO 13616.
+///
+/// ## What is an _IBAN_?
+///
+/// An IBAN identifies a bank account across borders. It starts with the alpha-2 code of the country of the account
+/// and two check digits, followed by the Basic Bank Account Number (BBAN), whose length and structure depend on the country.
+/// For example, `GB82 WEST 1234 5698 7654 32` is a British IBAN.
+///
+/// The IBAN is validated against the structure of its country in the IBAN registry, and its check digits
+/// are verified with the ISO 7064 MOD 97-10 algorithm.
+///
+/// ## When to use
+///
+/// Use it for the bank accounts of customers and suppliers, such as for SEPA transfers and direct debits.
+///
+/// ## Example
+///
+/// ```
+/// use valobs::financial::Iban;
+/// use valobs::geography::Country;
+/// use valobs::result::ValobsResult;
+///
+/// fn main() -> ValobsResult<()> {
+///   let iban: Iban = "gb82 west 1234 5698 7654 32".parse()?;
+///
+///   assert_eq!(iban.as_str(), "GB82WEST12345698765432");
+///   assert_eq!(iban.to_string(), "GB82 WEST 1234 5698 7654 32");
+///   assert_eq!(iban.country(), Some(Country::GBR));
+///   assert_eq!(iban.bban(), "WEST12345698765432");
+///   assert!("GB83 WEST 1234 5698 7654 32".parse::<Iban>().is_err());
+///
+///   Ok(())
+/// }
+/// ```
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct Iban(String);
+
+impl Iban {
+    /// Create a new `Iban` instance from its electronic or print format.
+    ///
+    /// Spaces are removed and letters are converted to upper case before the IBAN is validated.
+    pub fn new(value: &str) -> ValobsResult<Iban> {
+        let iban = value
+            .chars()
+            .filter(|char| !char.is_whitespace())
+            .collect::<String>()
+            .to_ascii_uppercase();
+        if iban.is_empty() {
+            return Err(ValobsError::Empty { field: "iban" });
+        }
+        let invalid_format = || ValobsError::InvalidFormat {
+            field: "iban",
+            value: value.into(),
+        };
+        if iban.len() < 4
+            || !iban.chars().all(|char| char.is_ascii_alphanumeric())
+            || !iban[..2].chars().all(|char| char.is_ascii_alphabetic())
+            || !iban[2..4].chars().all(|char| char.is_ascii_digit())
+        {
+            return Err(invalid_format());
+        }
+
+        let format = BBAN_FORMATS
+            .iter()
+            .find(|(country, _)| *country == &iban[..2])
+            .map(|(_, format)| *format)
+            .ok_or_else(|| ValobsError::UnknownCode {
+                field: "iban",
+                value: iban[..2].into(),
+            })?;
+        let length = 4 + bban_parts(format).map(|(length, _)| length).sum::<usize>();
+        if iban.len() != length {
+            return Err(ValobsError::InvalidLength {
+                field: "iban",
+                length: iban.len(),
+                min: length,
+                max: length,
+            });
+        }
+        if !matches_bban(format, &iban[4..]) {
+            return Err(invalid_format());
+        }
+        if mod97(&format!("{}{}", &iban[4..], &iban[..4])) != 1 {
+            return Err(ValobsError::InvalidChecksum {
+                field: "iban",
+                value: value.into(),
+            });
+        }
+        Ok(Self(iban))
+    }
+
+    /// Get the electronic format of the IBAN, without spaces, such as `GB82WEST12345698765432`.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Get the print format of the IBAN, in groups of four characters, such as `GB82 WEST 1234 5698 7654 32`.
+    pub fn to_print_format(&self) -> String {
+        self.0
+            .as_bytes()
+            .chunks(4)
+            .map(|chunk| std::str::from_utf8(chunk).unwrap_or_default())
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// Get the alpha-2 code of the country of the account, such as `GB`.
+    pub fn country_code(&self) -> &str {
+        &self.0[..2]
+    }
+
+    /// Get the country of the account.
+    ///
+    /// It is `None` for Kosovo, whose code `XK` is used by the IBAN registry but not assigned by ISO 3166-1.
+    pub fn country(&self) -> Option<Country> {
+        Country::from_alpha2(self.country_code())
+    }
+
+    /// Get the two check digits, such as `82`.
+    pub fn check_digits(&self) -> &str {
+        &self.0[2..4]
+    }
+
+    /// Get the Basic Bank Account Number, which is the national part of the IBAN, such as `WEST12345698765432`.
+    pub fn bban(&self) -> &str {
+        &self.0[4..]
+    }
+}
+
+impl ValueObject<'_> for Iban {}
+
+impl AsRef<str> for Iban {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl TryFrom<String> for Iban {
+    type Error = ValobsError;
+
+    fn try_from(value: String) -> ValobsResult<Self> {
+        Self::new(&value)
+    }
+}
+
+impl From<Iban> for String {
+    fn from(iban: Iban) -> Self {
+        iban.0
+    }
+}
+
+impl fmt::Display for Iban {
+    /// Formats the IBAN in its print format, such as `GB82 WEST 1234 5698 7654 32`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_print_format())
+    }
+}
+
+impl FromStr for Iban {
+    type Err = ValobsError;
+
+    fn from_str(value: &str) -> ValobsResult<Self> {
+        Self::new(value)
+    }
+}
+
+/// Splits a BBAN format, such as `4!a14!n`, into the length and kind of each part.
+fn bban_parts(format: &'static str) -> impl Iterator<Item = (usize, char)> {
+    format.split_inclusive(['n', 'a', 'c']).map(|part| {
+        let (length, kind) = part.split_at(part.len() - 2);
+        (
+            length.parse().unwrap_or_default(),
+            kind.chars().nth(1).unwrap_or_default(),
+        )
+    })
+}
+
+fn matches_bban(format: &'static str, bban: &str) -> bool {
+    let mut chars = bban.chars();
+    bban_parts(format).all(|(length, kind)| {
+        chars.by_ref().take(length).all(|char| match kind {
+            'n' => char.is_ascii_digit(),
+            'a' => char.is_ascii_uppercase(),
+            _ => char.is_ascii_digit() || char.is_ascii_uppercase(),
+        })
+    })
+}
+
+/// Computes the remainder of the number formed by replacing every letter with two digits, `A` being `10`, divided by 97.
+pub(super) fn mod97(value: &str) -> u32 {
+    value.chars().fold(0, |remainder, char| {
+        let digit = char.to_digit(36).unwrap_or_default();
+        let factor = if digit < 10 { 10 } else { 100 };
+        (remainder * factor + digit) % 97
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn create_iban() -> ValobsResult<()> {
+        for (value, country) in [
+            ("DE89 3704 0044 0532 0130 00", Some(Country::DEU)),
+            ("FR14 2004 1010 0505 0001 3M02 606", Some(Country::FRA)),
+            ("NL91ABNA0417164300", Some(Country::NLD)),
+            ("be68539007547034", Some(Country::BEL)),
+            ("XK05 1212 0123 4567 8906", None),
+        ] {
+            // Act
+            let result = Iban::new(value)?;
+
+            // Assert
+            assert_eq!(result.country(), country, "{value}");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn format_iban() -> ValobsResult<()> {
+        // Arrange
+        let iban = Iban::new("FR1420041010050500013M02606")?;
+
+        // Assert
+        assert_eq!(iban.as_str(), "FR1420041010050500013M02606");
+        assert_eq!(iban.to_string(), "FR14 2004 1010 0505 0001 3M02 606");
+        assert_eq!(iban.country_code(), "FR");
+        assert_eq!(iban.check_digits(), "14");
+        assert_eq!(iban.bban(), "20041010050500013M02606");
+
+        Ok(())
+    }
+
+    #[test]
+    fn fails_to_create_invalid_iban() {
+        assert_eq!(Iban::new(" "), Err(ValobsError::Empty { field: "iban" }));
+        assert_eq!(
+            Iban::new("ZZ68539007547034"),
+            Err(ValobsError::UnknownCode {
+                field: "iban",
+                value: "ZZ".into(),
+            })
+        );
+        assert_eq!(
+            Iban::new("BE6853900754703"),
+            Err(ValobsError::InvalidLength {
+                field: "iban",
+                length: 15,
+                min: 16,
+                max: 16,
+            })
+        );
+        assert_eq!(
+            Iban::new("GB82 1234 1234 5698 7654 32"),
+            Err(ValobsError::InvalidFormat {
+                field: "iban",
+                value: "GB82 1234 1234 5698 7654 32".into(),
+            })
+        );
+        assert_eq!(
+            Iban::new("DE88370400440532013000"),
+            Err(ValobsError::InvalidChecksum {
+                field: "iban",
+                value: "DE88370400440532013000".into(),
+            })
+        );
+        assert!(Iban::new("DE89-3704-0044-0532-0130-00").is_err());
+    }
+
+    #[test]
+    fn serialize_iban_to_json() -> ValobsResult<()> {
+        // Arrange
+        let iban = Iban::new("DE89 3704 0044 0532 0130 00")?;
+
+        // Act
+        let json = serde_json::to_string(&iban).unwrap();
+        let result = serde_json::from_str::<Iban>(&json).unwrap();
+
+        // Assert
+        assert_eq!(json, r#""DE89370400440532013000""#);
+        assert_eq!(result, iban);
+        assert!(serde_json::from_str::<Iban>(r#""DE00370400440532013000""#).is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,481 @@
+use super::{
+    currency::Currency,
+    discount::Discount,
+    money::Money,
+    rounding::RoundingMode,
+    tax::{TaxBreakdown, TaxCalculator, TaxRate, TaxRounding, TaxablePrice},
+};
+use crate::{
+    result::{ValobsError, ValobsResult},
+    traits::ValueObject,
+};
+use rust_decimal::{prelude::ToPrimitive, Decimal};
+use serde::{Deserialize, Serialize};
+
+/// A value object representing one line of an [Invoice], such as "3 × consulting hours at 120.00 EUR".
+///
+/// The unit price excludes taxes. The amount of the line is the quantity times the unit price,
+/// rounded half to even to the minor unit of the currency, minus the discount of the line, if any.
+///
+/// ## Example
+///
+/// ```
+/// use valobs::financial::{Currency, Decimal, Discount, InvoiceLine, Money, tax::TaxRate};
+/// use valobs::numeric::Percentage;
+/// use valobs::result::ValobsResult;
+///
+/// fn main() -> ValobsResult<()> {
+///   let line = InvoiceLine::new(
+///     "Consulting hours",
+///     Decimal::new(15, 1),
+///     Money::new(12000, Currency::EUR),
+///     TaxRate::from_percent(Decimal::from(20))?,
+///   )?
+///   .with_discount(Discount::percentage(Percentage::from_basis_points(1000))?)?;
+///
+///   assert_eq!(line.subtotal()?, Money::new(18000, Currency::EUR));
+///   assert_eq!(line.discount_amount()?, Money::new(1800, Currency::EUR));
+///   assert_eq!(line.net()?, Money::new(16200, Currency::EUR));
+///
+///   Ok(())
+/// }
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
+#[serde(try_from = "InvoiceLineFields")]
+pub struct InvoiceLine {
+    description: String,
+    quantity: Decimal,
+    unit_price: Money,
+    tax_rate: TaxRate,
+    discount: Option<Discount>,
+}
+
+impl InvoiceLine {
+    /// Create a new `InvoiceLine` instance without a discount.
+    ///
+    /// The description must not be empty, the quantity must be positive, and the unit price must not be negative.
+    pub fn new(
+        description: &str,
+        quantity: Decimal,
+        unit_price: Money,
+        tax_rate: TaxRate,
+    ) -> ValobsResult<InvoiceLine> {
+        let description = description.trim();
+        if description.is_empty() {
+            return Err(ValobsError::Empty {
+                field: "description",
+            });
+        }
+        if quantity.is_sign_negative() || quantity.is_zero() {
+            return Err(ValobsError::NotPositive {
+                field: "quantity",
+                value: quantity.to_f64().unwrap_or_default(),
+            });
+        }
+        if unit_price.is_negative() {
+            return Err(ValobsError::Negative {
+                field: "unit_price",
+                value: unit_price.amount() as f64,
+            });
+        }
+        let line = Self {
+            description: description.into(),
+            quantity: quantity.normalize(),
+            unit_price,
+            tax_rate,
+            discount: None,
+        };
+        line.subtotal()?;
+        Ok(line)
+    }
+
+    /// Set the discount of the line, which must apply to money in the currency of the unit price.
+    pub fn with_discount(mut self, discount: Discount) -> ValobsResult<InvoiceLine> {
+        self.discount = Some(discount);
+        self.net()?;
+        Ok(self)
+    }
+
+    /// Get the description.
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    /// Get the quantity.
+    pub fn quantity(&self) -> Decimal {
+        self.quantity
+    }
+
+    /// Get the price of one unit, without taxes.
+    pub fn unit_price(&self) -> Money {
+        self.unit_price
+    }
+
+    /// Get the tax rate.
+    pub fn tax_rate(&self) -> TaxRate {
+        self.tax_rate
+    }
+
+    /// Get the discount.
+    pub fn discount(&self) -> Option<Discount> {
+        self.discount
+    }
+
+    /// Get the quantity times the unit price, before the discount.
+    pub fn subtotal(&self) -> ValobsResult<Money> {
+        self.unit_price
+            .multiply(self.quantity, RoundingMode::HalfEven)
+    }
+
+    /// Get the amount taken off by the discount, which is zero without a discount.
+    pub fn discount_amount(&self) -> ValobsResult<Money> {
+        let subtotal = self.subtotal()?;
+        match self.discount {
+            Some(discount) => discount.amount_off(&subtotal),
+            None => Ok(Money::zero(*subtotal.currency())),
+        }
+    }
+
+    /// Get the amount of the line after the discount, without taxes.
+    pub fn net(&self) -> ValobsResult<Money> {
+        self.subtotal()?.subtract(&self.discount_amount()?)
+    }
+}
+
+impl ValueObject<'_> for InvoiceLine {}
+
+#[derive(Deserialize)]
+struct InvoiceLineFields {
+    description: String,
+    quantity: Decimal,
+    unit_price: Money,
+    tax_rate: TaxRate,
+    discount: Option<Discount>,
+}
+
+impl TryFrom<InvoiceLineFields> for InvoiceLine {
+    type Error = ValobsError;
+
+    fn try_from(fields: InvoiceLineFields) -> ValobsResult<Self> {
+        let line = Self::new(
+            &fields.description,
+            fields.quantity,
+            fields.unit_price,
+            fields.tax_rate,
+        )?;
+        match fields.discount {
+            Some(discount) => line.with_discount(discount),
+            None => Ok(line),
+        }
+    }
+}
+
+/// A value object representing an invoice, which adds up lines of goods or services in one currency.
+///
+/// ## What is an _Invoice_?
+///
+/// An invoice lists what a buyer owes to a seller. Each [InvoiceLine] has a quantity, a unit price without taxes,
+/// a tax rate and an optional discount. The invoice adds up the lines into a subtotal, calculates the taxes of each tax rate,
+/// and adds them to the subtotal to get the total.
+///
+/// Every line must be in the currency of the invoice. Invoices with the same lines in the same order are equal,
+/// have the same hash, and serialize to the same JSON.
+///
+/// ## When to use
+///
+/// Use it to calculate and store the amounts of an invoice, a quote or an order, where every amount must reconcile.
+///
+/// ## Example
+///
+/// ```
+/// use valobs::financial::{Currency, Decimal, Invoice, InvoiceLine, Money, tax::TaxRate};
+/// use valobs::result::ValobsResult;
+///
+/// fn main() -> ValobsResult<()> {
+///   let standard = TaxRate::from_percent(Decimal::from(19))?;
+///   let reduced = TaxRate::from_percent(Decimal::from(7))?;
+///   let invoice = Invoice::new(Currency::EUR, vec![
+///     InvoiceLine::new("Keyboard", Decimal::from(2), Money::new(4999, Currency::EUR), standard)?,
+///     InvoiceLine::new("Manual", Decimal::ONE, Money::new(1500, Currency::EUR), reduced)?,
+///   ])?;
+///
+///   assert_eq!(invoice.subtotal()?, Money::new(11498, Currency::EUR));
+///   assert_eq!(invoice.tax()?, Money::new(1900 + 105, Currency::EUR));
+///   assert_eq!(invoice.total()?, Money::new(13503, Currency::EUR));
+///
+///   Ok(())
+/// }
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
+#[serde(try_from = "InvoiceFields")]
+pub struct Invoice {
+    currency: Currency,
+    lines: Vec<InvoiceLine>,
+    tax_rounding: TaxRounding,
+}
+
+impl Invoice {
+    /// Create a new `Invoice` instance, which rounds the taxes of every line.
+    ///
+    /// There must be at least one line, and every line must be in the currency of the invoice.
+    pub fn new(currency: Currency, lines: Vec<InvoiceLine>) -> ValobsResult<Invoice> {
+        if lines.is_empty() {
+            return Err(ValobsError::Empty { field: "lines" });
+        }
+        for line in &lines {
+            Money::zero(currency).check_currency(&line.unit_price)?;
+        }
+        let invoice = Self {
+            currency,
+            lines,
+            tax_rounding: TaxRounding::default(),
+        };
+        invoice.total()?;
+        Ok(invoice)
+    }
+
+    /// Set when the taxes of the lines are rounded.
+    pub fn with_tax_rounding(mut self, tax_rounding: TaxRounding) -> ValobsResult<Invoice> {
+        self.tax_rounding = tax_rounding;
+        self.total()?;
+        Ok(self)
+    }
+
+    /// Get the currency.
+    pub fn currency(&self) -> Currency {
+        self.currency
+    }
+
+    /// Get the lines, in their order on the invoice.
+    pub fn lines(&self) -> &[InvoiceLine] {
+        &self.lines
+    }
+
+    /// Get when the taxes of the lines are rounded.
+    pub fn tax_rounding(&self) -> TaxRounding {
+        self.tax_rounding
+    }
+
+    /// Get the total of every line after its discount, without taxes.
+    pub fn subtotal(&self) -> ValobsResult<Money> {
+        self.sum(InvoiceLine::net)
+    }
+
+    /// Get the total taken off by the discounts of the lines.
+    pub fn discount(&self) -> ValobsResult<Money> {
+        self.sum(InvoiceLine::discount_amount)
+    }
+
+    /// Get the taxes of each tax rate, in the order the tax rates first appear on the invoice.
+    pub fn tax_breakdowns(&self) -> ValobsResult<Vec<(TaxRate, TaxBreakdown)>> {
+        let mut groups: Vec<(TaxRate, Vec<TaxablePrice>)> = Vec::new();
+        for line in &self.lines {
+            let price = TaxablePrice::Exclusive(line.net()?);
+            match groups.iter_mut().find(|(rate, _)| *rate == line.tax_rate) {
+                Some((_, prices)) => prices.push(price),
+                None => groups.push((line.tax_rate, vec![price])),
+            }
+        }
+        groups
+            .into_iter()
+            .map(|(rate, prices)| {
+                let breakdown = TaxCalculator::new(vec![rate])
+                    .with_strategy(self.tax_rounding)
+                    .breakdown_lines(&prices)?;
+                Ok((rate, breakdown))
+            })
+            .collect()
+    }
+
+    /// Get the total of every tax.
+    pub fn tax(&self) -> ValobsResult<Money> {
+        self.tax_breakdowns()?
+            .iter()
+            .try_fold(Money::zero(self.currency), |total, (_, breakdown)| {
+                total.add(&breakdown.tax()?)
+            })
+    }
+
+    /// Get the amount owed, which is the subtotal plus the taxes.
+    pub fn total(&self) -> ValobsResult<Money> {
+        self.subtotal()?.add(&self.tax()?)
+    }
+
+    fn sum(&self, amount: fn(&InvoiceLine) -> ValobsResult<Money>) -> ValobsResult<Money> {
+        let amounts = self
+            .lines
+            .iter()
+            .map(amount)
+            .collect::<ValobsResult<Vec<_>>>()?;
+        Money::try_sum(amounts, self.currency)
+    }
+}
+
+impl ValueObject<'_> for Invoice {}
+
+#[derive(Deserialize)]
+struct InvoiceFields {
+    currency: Currency,
+    lines: Vec<InvoiceLine>,
+    tax_rounding: TaxRounding,
+}
+
+impl TryFrom<InvoiceFields> for Invoice {
+    type Error = ValobsError;
+
+    fn try_from(fields: InvoiceFields) -> ValobsResult<Self> {
+        Self::new(fields.currency, fields.lines)?.with_tax_rounding(fields.tax_rounding)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::numeric::Percentage;
+    use std::{
+        collections::hash_map::DefaultHasher,
+        hash::{Hash, Hasher},
+    };
+
+    fn vat(percent: i64) -> TaxRate {
+        TaxRate::from_percent(Decimal::from(percent)).unwrap()
+    }
+
+    fn eur(amount: i64) -> Money {
+        Money::new(amount, Currency::EUR)
+    }
+
+    fn invoice() -> ValobsResult<Invoice> {
+        Invoice::new(
+            Currency::EUR,
+            vec![
+                InvoiceLine::new("Keyboard", Decimal::from(3), eur(3333), vat(20))?
+                    .with_discount(Discount::fixed(eur(999))?)?,
+                InvoiceLine::new("Book", Decimal::new(5, 1), eur(1001), vat(5))?,
+                InvoiceLine::new("Mouse", Decimal::ONE, eur(1501), vat(20))?,
+            ],
+        )
+    }
+
+    fn hash(invoice: &Invoice) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        invoice.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn calculate_invoice_totals() -> ValobsResult<()> {
+        // Arrange
+        let invoice = invoice()?;
+
+        // Act
+        let breakdowns = invoice.tax_breakdowns()?;
+
+        // Assert
+        assert_eq!(invoice.discount()?, eur(999));
+        assert_eq!(invoice.subtotal()?, eur(9000 + 500 + 1501));
+        assert_eq!(breakdowns.len(), 2);
+        assert_eq!(breakdowns[0].0, vat(20));
+        assert_eq!(breakdowns[0].1.net(), eur(10501));
+        assert_eq!(breakdowns[0].1.tax()?, eur(1800 + 300));
+        assert_eq!(breakdowns[1].1.tax()?, eur(25));
+        assert_eq!(invoice.tax()?, eur(2125));
+        assert_eq!(invoice.total()?, eur(11001 + 2125));
+
+        Ok(())
+    }
+
+    #[test]
+    fn round_taxes_once_per_invoice() -> ValobsResult<()> {
+        // Arrange
+        let line = InvoiceLine::new("Sticker", Decimal::ONE, eur(2), vat(20))?;
+        let invoice = Invoice::new(Currency::EUR, vec![line.clone(), line.clone(), line])?;
+
+        // Act
+        let per_invoice = invoice.clone().with_tax_rounding(TaxRounding::PerInvoice)?;
+
+        // Assert
+        assert_eq!(invoice.tax()?, eur(0));
+        assert_eq!(per_invoice.tax()?, eur(1));
+
+        Ok(())
+    }
+
+    #[test]
+    fn fails_to_create_inconsistent_invoice() -> ValobsResult<()> {
+        assert_eq!(
+            Invoice::new(
+                Currency::EUR,
+                vec![InvoiceLine::new(
+                    "Keyboard",
+                    Decimal::ONE,
+                    Money::new(100, Currency::USD),
+                    vat(20)
+                )?]
+            ),
+            Err(ValobsError::CurrencyMismatch {
+                expected: Currency::EUR,
+                found: Currency::USD,
+            })
+        );
+        assert_eq!(
+            Invoice::new(Currency::EUR, vec![]),
+            Err(ValobsError::Empty { field: "lines" })
+        );
+        assert!(InvoiceLine::new(" ", Decimal::ONE, eur(100), vat(20)).is_err());
+        assert!(InvoiceLine::new("Keyboard", Decimal::ZERO, eur(100), vat(20)).is_err());
+        assert!(InvoiceLine::new("Keyboard", Decimal::ONE, eur(-100), vat(20)).is_err());
+        assert!(
+            InvoiceLine::new("Keyboard", Decimal::ONE, eur(100), vat(20))?
+                .with_discount(Discount::fixed(Money::new(10, Currency::USD))?)
+                .is_err()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn equal_invoices_hash_and_serialize_the_same() -> ValobsResult<()> {
+        // Arrange
+        let invoice = invoice()?;
+        let other = Invoice::new(
+            Currency::EUR,
+            vec![
+                InvoiceLine::new("Keyboard ", Decimal::new(300, 2), eur(3333), vat(20))?
+                    .with_discount(Discount::fixed(eur(999))?)?,
+                InvoiceLine::new("Book", Decimal::new(50, 2), eur(1001), vat(5))?,
+                InvoiceLine::new("Mouse", Decimal::ONE, eur(1501), vat(20))?,
+            ],
+        )?;
+
+        // Act
+        let json = serde_json::to_string(&invoice).unwrap();
+        let result = serde_json::from_str::<Invoice>(&json).unwrap();
+
+        // Assert
+        assert_eq!(other, invoice);
+        assert_eq!(hash(&other), hash(&invoice));
+        assert_eq!(serde_json::to_string(&other).unwrap(), json);
+        assert_eq!(result, invoice);
+        assert!(json.starts_with(
+            r#"{"currency":"EUR","lines":[{"description":"Keyboard","quantity":"3","unit_price":{"amount":3333,"currency":"EUR"}"#
+        ));
+        assert!(serde_json::from_str::<Invoice>(&json.replace("Keyboard", "")).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn discount_line_by_percentage() -> ValobsResult<()> {
+        // Arrange
+        let line = InvoiceLine::new("Chair", Decimal::from(4), eur(2499), vat(20))?
+            .with_discount(Discount::percentage(Percentage::from_basis_points(1250))?)?;
+
+        // Assert
+        assert_eq!(line.subtotal()?, eur(9996));
+        assert_eq!(line.discount_amount()?, eur(1250));
+        assert_eq!(line.net()?, eur(8746));
+
+        Ok(())
+    }
+}
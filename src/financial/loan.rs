@@ -0,0 +1,733 @@
+use super::{money::Money, rounding::RoundingMode};
+use crate::{
+    numeric::Percentage,
+    result::{ValobsError, ValobsResult},
+    temporal::Date,
+    traits::ValueObject,
+};
+use chrono::{Datelike, Months};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// A convention that decides which fraction of a year lies between two dates, to accrue interest over it.
+///
+/// ## Example
+///
+/// ```
+/// use valobs::financial::{Decimal, loan::DayCount};
+/// use valobs::temporal::Date;
+///
+/// let start = Date::from_ymd_opt(2024, 1, 31).unwrap();
+/// let end = Date::from_ymd_opt(2024, 3, 31).unwrap();
+///
+/// assert_eq!(DayCount::Thirty360.year_fraction(start, end), Decimal::from(60) / Decimal::from(360));
+/// assert_eq!(DayCount::Actual365.year_fraction(start, end), Decimal::from(60) / Decimal::from(365));
+/// assert_eq!(DayCount::ActualActual.year_fraction(start, end), Decimal::from(60) / Decimal::from(366));
+/// ```
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+pub enum DayCount {
+    /// _30/360_, also known as the bond basis: every month has 30 days, and every year has 360 days.
+    ///
+    /// The 31st of a month counts as the 30th. The end date only does so when the start date is the 30th or 31st.
+    #[default]
+    Thirty360,
+    /// _ACT/365 Fixed_: the actual number of days, and every year has 365 days.
+    Actual365,
+    /// _ACT/ACT ISDA_: the actual number of days, where days in a leap year count as 1/366 of a year,
+    /// and other days count as 1/365 of a year.
+    ActualActual,
+}
+
+impl DayCount {
+    /// Get the fraction of a year between two dates.
+    ///
+    /// The fraction is negative when the end date comes before the start date.
+    pub fn year_fraction(&self, start: Date, end: Date) -> Decimal {
+        if end < start {
+            return -self.year_fraction(end, start);
+        }
+        match self {
+            Self::Thirty360 => {
+                let start_day = start.day().min(30);
+                let end_day = if start_day == 30 {
+                    end.day().min(30)
+                } else {
+                    end.day()
+                };
+                let days = 360 * (end.year() - start.year()) as i64
+                    + 30 * (end.month() as i64 - start.month() as i64)
+                    + (end_day as i64 - start_day as i64);
+                Decimal::from(days) / Decimal::from(360)
+            }
+            Self::Actual365 => Decimal::from((end - start).num_days()) / Decimal::from(365),
+            Self::ActualActual => {
+                let mut fraction = Decimal::ZERO;
+                let mut from = start;
+                while from < end {
+                    let next_year = Date::from_ymd_opt(from.year() + 1, 1, 1).unwrap_or(end);
+                    let to = end.min(next_year);
+                    let days_in_year = if from.leap_year() { 366 } else { 365 };
+                    fraction += Decimal::from((to - from).num_days()) / Decimal::from(days_in_year);
+                    from = to;
+                }
+                fraction
+            }
+        }
+    }
+
+    /// Get the interest accrued on an amount of money between two dates, at an annual rate,
+    /// rounded to the minor unit of its currency.
+    pub fn interest(
+        &self,
+        money: &Money,
+        annual_rate: Percentage,
+        start: Date,
+        end: Date,
+        rounding: RoundingMode,
+    ) -> ValobsResult<Money> {
+        let factor = annual_rate
+            .fraction()
+            .checked_mul(self.year_fraction(start, end))
+            .ok_or(ValobsError::Overflow { field: "rate" })?;
+        money.multiply(factor, rounding)
+    }
+}
+
+/// A loan that is paid back in equal monthly installments, also known as an annuity loan.
+///
+/// ## How does it work?
+///
+/// The installment is calculated from the monthly rate, which is the annual rate divided by 12,
+/// and rounded to the minor unit of the currency. Each month, the interest accrues on the remaining balance
+/// with the [DayCount] of the loan, and the rest of the installment pays back the principal.
+/// The last installment pays back whatever principal remains, so the schedule always reconciles exactly.
+///
+/// With [DayCount::Actual365] and [DayCount::ActualActual], a long month accrues more interest than
+/// the monthly rate assumes. On long loans with high rates, its interest can exceed the installment,
+/// which would make the balance grow, so such loans have no schedule.
+///
+/// ## Example
+///
+/// ```
+/// use valobs::financial::{Currency, Money, loan::Loan};
+/// use valobs::numeric::Percentage;
+/// use valobs::result::ValobsResult;
+/// use valobs::temporal::Date;
+///
+/// fn main() -> ValobsResult<()> {
+///   // Borrow 10,000.00 USD at 6% a year, paid back over 12 months
+///   let loan = Loan::new(
+///     Money::new(1_000_000, Currency::USD),
+///     Percentage::from_basis_points(600),
+///     12,
+///     Date::from_ymd_opt(2024, 1, 15).unwrap(),
+///   )?;
+///   let schedule = loan.schedule()?;
+///
+///   assert_eq!(schedule.rows().len(), 12);
+///   assert_eq!(schedule.rows()[0].payment(), Money::new(86_066, Currency::USD));
+///   assert_eq!(schedule.rows()[0].interest(), Money::new(5_000, Currency::USD));
+///   assert_eq!(schedule.total_principal()?, Money::new(1_000_000, Currency::USD));
+///
+///   Ok(())
+/// }
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+#[serde(try_from = "LoanFields")]
+pub struct Loan {
+    principal: Money,
+    annual_rate: Percentage,
+    months: u32,
+    start: Date,
+    day_count: DayCount,
+    rounding: RoundingMode,
+}
+
+impl Loan {
+    /// Create a new `Loan` instance, paid back in monthly installments from one month after the start date.
+    ///
+    /// The principal must be positive, the annual rate must not be negative, and there must be at least one month.
+    pub fn new(
+        principal: Money,
+        annual_rate: Percentage,
+        months: u32,
+        start: Date,
+    ) -> ValobsResult<Loan> {
+        if !principal.is_positive() {
+            return Err(ValobsError::NotPositive {
+                field: "principal",
+                value: principal.amount() as f64,
+            });
+        }
+        if annual_rate.basis_points() < 0 {
+            return Err(ValobsError::Negative {
+                field: "rate",
+                value: annual_rate.basis_points() as f64 / 100.0,
+            });
+        }
+        if months == 0 {
+            return Err(ValobsError::NotPositive {
+                field: "months",
+                value: 0.0,
+            });
+        }
+        Ok(Self {
+            principal,
+            annual_rate,
+            months,
+            start,
+            day_count: DayCount::default(),
+            rounding: RoundingMode::default(),
+        })
+    }
+
+    /// Set the day count convention, which is 30/360 by default.
+    pub fn with_day_count(mut self, day_count: DayCount) -> Self {
+        self.day_count = day_count;
+        self
+    }
+
+    /// Set the rounding mode of the installment and of the interest, which is half to even by default.
+    pub fn with_rounding(mut self, rounding: RoundingMode) -> Self {
+        self.rounding = rounding;
+        self
+    }
+
+    /// Get the amount borrowed.
+    pub fn principal(&self) -> Money {
+        self.principal
+    }
+
+    /// Get the annual interest rate.
+    pub fn annual_rate(&self) -> Percentage {
+        self.annual_rate
+    }
+
+    /// Get the number of monthly installments.
+    pub fn months(&self) -> u32 {
+        self.months
+    }
+
+    /// Get the date the loan starts accruing interest.
+    pub fn start(&self) -> Date {
+        self.start
+    }
+
+    /// Get the day count convention.
+    pub fn day_count(&self) -> DayCount {
+        self.day_count
+    }
+
+    /// Get the monthly installment, rounded to the minor unit of the currency.
+    ///
+    /// The last installment of the schedule may differ slightly, to pay back the exact principal.
+    pub fn installment(&self) -> ValobsResult<Money> {
+        let months = Decimal::from(self.months);
+        let rate = self.annual_rate.fraction() / Decimal::from(12);
+        if rate.is_zero() {
+            return self.principal.divide(months, self.rounding);
+        }
+        let growth = (0..self.months)
+            .try_fold(Decimal::ONE, |growth, _| {
+                growth.checked_mul(Decimal::ONE + rate)
+            })
+            .and_then(|growth| rate.checked_mul(growth)?.checked_div(growth - Decimal::ONE))
+            .ok_or(ValobsError::Overflow { field: "rate" })?;
+        self.principal.multiply(growth, self.rounding)
+    }
+
+    /// Get the amortization schedule, with one row for each monthly installment.
+    ///
+    /// Fails with [ValobsError::InsufficientAmount] if the interest of a month exceeds the installment.
+    pub fn schedule(&self) -> ValobsResult<AmortizationSchedule> {
+        let installment = self.installment()?;
+        let mut balance = self.principal;
+        let mut previous = self.start;
+        let mut rows = Vec::with_capacity(self.months as usize);
+        for month in 1..=self.months {
+            let date = self
+                .start
+                .checked_add_months(Months::new(month))
+                .ok_or(ValobsError::Overflow { field: "date" })?;
+            let interest = self.day_count.interest(
+                &balance,
+                self.annual_rate,
+                previous,
+                date,
+                self.rounding,
+            )?;
+            let principal = installment.subtract(&interest)?;
+            if principal.is_negative() {
+                return Err(ValobsError::InsufficientAmount {
+                    field: "installment",
+                    available: installment.amount(),
+                    requested: interest.amount(),
+                });
+            }
+            let principal = if month == self.months || principal.amount() > balance.amount() {
+                balance
+            } else {
+                principal
+            };
+            balance = balance.subtract(&principal)?;
+            rows.push(AmortizationRow {
+                date,
+                payment: interest.add(&principal)?,
+                interest,
+                principal,
+                balance,
+            });
+            previous = date;
+        }
+        Ok(AmortizationSchedule { rows })
+    }
+}
+
+impl ValueObject<'_> for Loan {}
+
+#[derive(Deserialize)]
+struct LoanFields {
+    principal: Money,
+    annual_rate: Percentage,
+    months: u32,
+    start: Date,
+    day_count: DayCount,
+    rounding: RoundingMode,
+}
+
+impl TryFrom<LoanFields> for Loan {
+    type Error = ValobsError;
+
+    fn try_from(fields: LoanFields) -> ValobsResult<Self> {
+        Ok(Self::new(
+            fields.principal,
+            fields.annual_rate,
+            fields.months,
+            fields.start,
+        )?
+        .with_day_count(fields.day_count)
+        .with_rounding(fields.rounding))
+    }
+}
+
+/// One installment of an [AmortizationSchedule].
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+#[serde(try_from = "AmortizationRowFields")]
+pub struct AmortizationRow {
+    date: Date,
+    payment: Money,
+    interest: Money,
+    principal: Money,
+    balance: Money,
+}
+
+#[derive(Deserialize)]
+struct AmortizationRowFields {
+    date: Date,
+    payment: Money,
+    interest: Money,
+    principal: Money,
+    balance: Money,
+}
+
+impl TryFrom<AmortizationRowFields> for AmortizationRow {
+    type Error = ValobsError;
+
+    fn try_from(fields: AmortizationRowFields) -> ValobsResult<Self> {
+        for (field, money) in [
+            ("interest", fields.interest),
+            ("principal", fields.principal),
+            ("balance", fields.balance),
+        ] {
+            if money.is_negative() {
+                return Err(ValobsError::Negative {
+                    field,
+                    value: money.amount() as f64,
+                });
+            }
+        }
+        let payment = fields.interest.add(&fields.principal)?;
+        fields.payment.check_currency(&payment)?;
+        fields.balance.check_currency(&payment)?;
+        if fields.payment != payment {
+            return Err(ValobsError::Unreconciled {
+                field: "payment",
+                expected: payment.amount(),
+                found: fields.payment.amount(),
+            });
+        }
+        Ok(Self {
+            date: fields.date,
+            payment: fields.payment,
+            interest: fields.interest,
+            principal: fields.principal,
+            balance: fields.balance,
+        })
+    }
+}
+
+impl AmortizationRow {
+    /// Get the date the installment is due.
+    pub fn date(&self) -> Date {
+        self.date
+    }
+
+    /// Get the amount paid, which is the interest plus the principal.
+    pub fn payment(&self) -> Money {
+        self.payment
+    }
+
+    /// Get the interest accrued since the previous installment.
+    pub fn interest(&self) -> Money {
+        self.interest
+    }
+
+    /// Get the principal paid back.
+    pub fn principal(&self) -> Money {
+        self.principal
+    }
+
+    /// Get the principal that remains to be paid back after the installment.
+    pub fn balance(&self) -> Money {
+        self.balance
+    }
+}
+
+/// The installments of a [Loan], in the order they are due.
+///
+/// It always reconciles exactly: the principal paid back by every installment equals the amount borrowed,
+/// and the balance after the last installment is zero.
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(try_from = "AmortizationScheduleFields")]
+pub struct AmortizationSchedule {
+    rows: Vec<AmortizationRow>,
+}
+
+impl AmortizationSchedule {
+    /// Get the installments, in the order they are due.
+    pub fn rows(&self) -> &[AmortizationRow] {
+        &self.rows
+    }
+
+    /// Get the total paid over the loan.
+    pub fn total_payment(&self) -> ValobsResult<Money> {
+        self.total(AmortizationRow::payment)
+    }
+
+    /// Get the total interest paid over the loan.
+    pub fn total_interest(&self) -> ValobsResult<Money> {
+        self.total(AmortizationRow::interest)
+    }
+
+    /// Get the total principal paid back, which equals the amount borrowed.
+    pub fn total_principal(&self) -> ValobsResult<Money> {
+        self.total(AmortizationRow::principal)
+    }
+
+    fn total(&self, amount: fn(&AmortizationRow) -> Money) -> ValobsResult<Money> {
+        self.rows.iter().map(amount).sum()
+    }
+}
+
+impl ValueObject<'_> for AmortizationSchedule {}
+
+#[derive(Deserialize)]
+struct AmortizationScheduleFields {
+    rows: Vec<AmortizationRow>,
+}
+
+impl TryFrom<AmortizationScheduleFields> for AmortizationSchedule {
+    type Error = ValobsError;
+
+    fn try_from(fields: AmortizationScheduleFields) -> ValobsResult<Self> {
+        let last = fields
+            .rows
+            .last()
+            .ok_or(ValobsError::Empty { field: "rows" })?;
+        for pair in fields.rows.windows(2) {
+            let [previous, row] = pair else {
+                unreachable!("windows of two rows");
+            };
+            if row.date <= previous.date {
+                return Err(ValobsError::InvalidFormat {
+                    field: "date",
+                    value: row.date.to_string(),
+                });
+            }
+            let balance = previous.balance.subtract(&row.principal)?;
+            if row.balance != balance {
+                return Err(ValobsError::Unreconciled {
+                    field: "balance",
+                    expected: balance.amount(),
+                    found: row.balance.amount(),
+                });
+            }
+        }
+        if !last.balance.is_zero() {
+            return Err(ValobsError::Unreconciled {
+                field: "balance",
+                expected: 0,
+                found: last.balance.amount(),
+            });
+        }
+        Ok(Self { rows: fields.rows })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::financial::Currency;
+
+    fn date(year: i32, month: u32, day: u32) -> Date {
+        Date::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    fn loan(principal: i64, basis_points: i64, months: u32) -> ValobsResult<Loan> {
+        Loan::new(
+            Money::new(principal, Currency::USD),
+            Percentage::from_basis_points(basis_points),
+            months,
+            date(2024, 1, 31),
+        )
+    }
+
+    #[test]
+    fn count_days_with_every_convention() {
+        let cases = [
+            (date(2024, 1, 31), date(2024, 2, 29), 29, 29),
+            (date(2024, 2, 29), date(2024, 3, 31), 32, 31),
+            (date(2023, 3, 30), date(2023, 3, 31), 0, 1),
+        ];
+        for (start, end, thirty, actual) in cases {
+            assert_eq!(
+                DayCount::Thirty360.year_fraction(start, end),
+                Decimal::from(thirty) / Decimal::from(360),
+                "{start} to {end}"
+            );
+            assert_eq!(
+                DayCount::Actual365.year_fraction(start, end),
+                Decimal::from(actual) / Decimal::from(365),
+                "{start} to {end}"
+            );
+        }
+        assert_eq!(
+            DayCount::ActualActual.year_fraction(date(2023, 12, 1), date(2024, 1, 31)),
+            Decimal::from(31) / Decimal::from(365) + Decimal::from(30) / Decimal::from(366)
+        );
+        assert_eq!(
+            DayCount::ActualActual.year_fraction(date(2024, 1, 1), date(2025, 1, 1)),
+            Decimal::ONE
+        );
+        assert_eq!(
+            DayCount::Actual365.year_fraction(date(2024, 1, 31), date(2024, 1, 1)),
+            -Decimal::from(30) / Decimal::from(365)
+        );
+    }
+
+    #[test]
+    fn create_amortization_schedule() -> ValobsResult<()> {
+        // Arrange
+        let loan = loan(1_000_000, 600, 12)?;
+
+        // Act
+        let result = loan.schedule()?;
+
+        // Assert
+        let first = result.rows()[0];
+        assert_eq!(first.date(), date(2024, 2, 29));
+        assert_eq!(first.payment().amount(), 86_066);
+        assert_eq!(first.interest().amount(), 4_833);
+        assert_eq!(first.principal().amount(), 81_233);
+        assert_eq!(first.balance().amount(), 918_767);
+
+        let last = result.rows()[11];
+        assert_eq!(last.date(), date(2025, 1, 31));
+        assert_eq!(last.balance(), Money::zero(Currency::USD));
+        assert_eq!(result.total_principal()?, loan.principal());
+        assert_eq!(
+            result.total_payment()?,
+            result.total_interest()?.add(&loan.principal())?
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn schedules_always_reconcile_to_the_principal() -> ValobsResult<()> {
+        for day_count in [
+            DayCount::Thirty360,
+            DayCount::Actual365,
+            DayCount::ActualActual,
+        ] {
+            for (principal, basis_points, months) in [
+                (1_000_000, 600, 12),
+                (25_000_000, 425, 360),
+                (1_001, 1999, 7),
+            ] {
+                // Arrange
+                let loan = loan(principal, basis_points, months)?.with_day_count(day_count);
+
+                // Act
+                let result = loan.schedule()?;
+
+                // Assert
+                assert_eq!(result.rows().len(), months as usize);
+                assert_eq!(result.total_principal()?, loan.principal(), "{day_count:?}");
+                assert!(result.rows()[months as usize - 1].balance().is_zero());
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn create_schedule_without_interest() -> ValobsResult<()> {
+        // Act
+        let result = loan(10_000, 0, 3)?.schedule()?;
+
+        // Assert
+        let payments = result
+            .rows()
+            .iter()
+            .map(|row| row.payment().amount())
+            .collect::<Vec<_>>();
+        assert_eq!(payments, vec![3_333, 3_333, 3_334]);
+        assert!(result.total_interest()?.is_zero());
+
+        Ok(())
+    }
+
+    #[test]
+    fn fails_to_create_invalid_loan() {
+        assert_eq!(
+            loan(0, 600, 12),
+            Err(ValobsError::NotPositive {
+                field: "principal",
+                value: 0.0,
+            })
+        );
+        assert_eq!(
+            loan(1_000, -100, 12),
+            Err(ValobsError::Negative {
+                field: "rate",
+                value: -1.0,
+            })
+        );
+        assert!(loan(1_000, 600, 0).is_err());
+    }
+
+    #[test]
+    fn fails_to_schedule_when_interest_exceeds_installment() -> ValobsResult<()> {
+        // Arrange
+        let moderate = loan(25_000_000, 500, 360)?.with_day_count(DayCount::Actual365);
+        let high = loan(25_000_000, 3000, 360)?.with_day_count(DayCount::Actual365);
+
+        // Act
+        let result = high.schedule();
+
+        // Assert
+        assert!(moderate.schedule().is_ok());
+        // The 31 days of March accrue more interest than the installment, which assumes 1/12 of a year
+        let Err(ValobsError::InsufficientAmount {
+            field: "installment",
+            available,
+            requested,
+        }) = result
+        else {
+            panic!("expected the installment to be insufficient, got {result:?}");
+        };
+        assert_eq!(available, high.installment()?.amount());
+        assert!(requested > available);
+
+        Ok(())
+    }
+
+    #[test]
+    fn serialize_loan_to_json() -> ValobsResult<()> {
+        // Arrange
+        let loan = loan(1_000, 600, 12)?.with_day_count(DayCount::ActualActual);
+
+        // Act
+        let json = serde_json::to_string(&loan).unwrap();
+        let result = serde_json::from_str::<Loan>(&json).unwrap();
+
+        // Assert
+        assert_eq!(
+            json,
+            r#"{"principal":{"amount":1000,"currency":"USD"},"annual_rate":"6","months":12,"start":"2024-01-31","day_count":"ActualActual","rounding":"HalfEven"}"#
+        );
+        assert_eq!(result, loan);
+        assert!(serde_json::from_str::<Loan>(&json.replace("12,", "0,")).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn serialize_schedule_to_json() -> ValobsResult<()> {
+        // Arrange
+        let schedule = loan(10_000, 0, 2)?.schedule()?;
+
+        // Act
+        let json = serde_json::to_string(&schedule).unwrap();
+        let result = serde_json::from_str::<AmortizationSchedule>(&json).unwrap();
+
+        // Assert
+        assert_eq!(
+            json,
+            r#"{"rows":[{"date":"2024-02-29","payment":{"amount":5000,"currency":"USD"},"interest":{"amount":0,"currency":"USD"},"principal":{"amount":5000,"currency":"USD"},"balance":{"amount":5000,"currency":"USD"}},{"date":"2024-03-31","payment":{"amount":5000,"currency":"USD"},"interest":{"amount":0,"currency":"USD"},"principal":{"amount":5000,"currency":"USD"},"balance":{"amount":0,"currency":"USD"}}]}"#
+        );
+        assert_eq!(result, schedule);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fails_to_deserialize_unreconciled_schedule() {
+        let row = |date: &str, payment: i64, interest: i64, principal: i64, balance: i64| {
+            format!(
+                r#"{{"date":"{date}","payment":{{"amount":{payment},"currency":"USD"}},"interest":{{"amount":{interest},"currency":"USD"}},"principal":{{"amount":{principal},"currency":"USD"}},"balance":{{"amount":{balance},"currency":"USD"}}}}"#
+            )
+        };
+        let schedule = |rows: &[String]| {
+            serde_json::from_str::<AmortizationSchedule>(&format!(
+                r#"{{"rows":[{}]}}"#,
+                rows.join(",")
+            ))
+        };
+
+        // The payment is not the interest plus the principal
+        assert!(schedule(&[row("2024-02-29", 9000, 100, 5000, 0)]).is_err());
+        // The balance grows
+        assert!(schedule(&[
+            row("2024-02-29", 100, 200, -100, 10100),
+            row("2024-03-31", 10300, 200, 10100, 0),
+        ])
+        .is_err());
+        // The balance doesn't follow from the principal paid back
+        assert!(schedule(&[
+            row("2024-02-29", 5000, 0, 5000, 4000),
+            row("2024-03-31", 3000, 0, 3000, 0),
+        ])
+        .is_err());
+        // The loan is not paid back
+        assert!(schedule(&[
+            row("2024-02-29", 5000, 0, 5000, 5000),
+            row("2024-03-31", 4000, 0, 4000, 1000),
+        ])
+        .is_err());
+        // The installments are out of order
+        assert!(schedule(&[
+            row("2024-03-31", 5000, 0, 5000, 5000),
+            row("2024-02-29", 5000, 0, 5000, 0),
+        ])
+        .is_err());
+        assert!(schedule(&[]).is_err());
+        // A schedule that reconciles
+        assert!(schedule(&[
+            row("2024-02-29", 5000, 0, 5000, 5000),
+            row("2024-03-31", 5000, 0, 5000, 0),
+        ])
+        .is_ok());
+    }
+}
@@ -1,16 +1,39 @@
-use super::currency::Currency;
-use crate::{result::ValobsResult, traits::ValueObject};
+use super::{
+    allocation::Allocation,
+    currency::Currency,
+    exchange_rate::RateProvider,
+    format::{Locale, MoneyFormatter},
+    rounding::RoundingMode,
+};
+use crate::{
+    result::{ValidationReport, ValidationResult, ValobsError, ValobsResult},
+    traits::ValueObject,
+};
+use rust_decimal::{prelude::ToPrimitive, Decimal};
 use serde::{Deserialize, Serialize};
+use std::{
+    cmp::Ordering,
+    fmt,
+    iter::Sum,
+    ops::{Add, Mul, Neg, Sub},
+    str::FromStr,
+};
 
 /// A value object representing money for simple use cases.
 ///
 /// ## Fields
-/// - `amount` - The amount of money.
+/// - `amount` - The amount of money in the minor unit of the currency (e.g. cents for USD), which may be zero or negative.
+///   See [Currency::minor_units](crate::financial::Currency::minor_units) for the number of minor units of each currency.
 /// - `currency` - The currency of the money, represented as an ISO 4217 currency code.
 ///
+/// A positive amount is money held or received (a credit), and a negative amount is money owed or
+/// paid out (a debit). This makes `Money` suitable for balances, refunds and ledger entries.
+/// If the amount must always be greater than zero, such as for a price, use [Price](crate::financial::Price) instead.
+///
 /// ## When to use
 /// This is useful for simple use cases where you need to work with money.
 /// For more complex use cases, consider using a library like [rusty_money](https://crates.io/crates/rusty_money).
+/// `Money` and [Currency] convert to and from their `rusty_money` counterparts with [TryFrom].
 ///
 /// ## Example
 /// ```
@@ -18,19 +41,25 @@ use serde::{Deserialize, Serialize};
 /// use valobs::result::ValobsResult;
 ///
 /// fn main() -> ValobsResult<()> {
-///   let money = Money::new(100, Currency::USD)?;
-///   let other = Money::new(50, Currency::USD)?;
+///   let money = Money::new(100, Currency::USD);
+///   let other = Money::new(150, Currency::USD);
 ///   
-///   // Add 100 USD to 50 USD
+///   // Add 1.50 USD to 1.00 USD
 ///   let result = money.add(&other)?;
 ///
-///   assert_eq!(result.amount(), 150);
+///   assert_eq!(result.amount(), 250);
 ///   assert_eq!(result.currency(), &Currency::USD);
 ///
+///   // Subtract 1.50 USD from 1.00 USD
+///   let result = money.subtract(&other)?;
+///
+///   assert_eq!(result.amount(), -50);
+///   assert!(result.is_negative());
+///
 ///   Ok(())
 /// }
 /// ```
-#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Clone, Copy)]
+#[derive(Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Clone, Copy)]
 pub struct Money {
     amount: MoneyAmount,
     currency: Currency,
@@ -38,73 +67,430 @@ pub struct Money {
 
 impl Money {
     /// Create a new `Money` instance.
-    pub fn new(amount: MoneyAmount, currency: Currency) -> ValobsResult<Money> {
-        if amount == 0 {
-            return Err("Amount must be greater than 0".into());
+    pub fn new(amount: MoneyAmount, currency: Currency) -> Money {
+        Money { amount, currency }
+    }
+
+    /// Create a new `Money` instance with an amount of zero.
+    pub fn zero(currency: Currency) -> Money {
+        Self::new(0, currency)
+    }
+
+    /// Create a new `Money` instance from an amount in the major unit of the currency.
+    ///
+    /// ## Example
+    /// ```
+    /// use valobs::financial::{Currency, Money};
+    /// use valobs::result::ValobsResult;
+    ///
+    /// fn main() -> ValobsResult<()> {
+    ///   assert_eq!(Money::from_major(12, Currency::USD)?.amount(), 1200);
+    ///   assert_eq!(Money::from_major(12, Currency::JPY)?.amount(), 12);
+    ///
+    ///   Ok(())
+    /// }
+    /// ```
+    pub fn from_major(major: MoneyAmount, currency: Currency) -> ValobsResult<Money> {
+        let amount = major
+            .checked_mul(minor_unit_scale(currency))
+            .ok_or(ValobsError::Overflow { field: "amount" })?;
+        Ok(Self::new(amount, currency))
+    }
+
+    /// Create a new `Money` instance from a decimal string in the major unit of the currency, such as `"12.34"`.
+    ///
+    /// The string may start with a sign, and must not have more fractional digits than the minor unit of the currency,
+    /// so no precision is silently lost.
+    ///
+    /// ## Example
+    /// ```
+    /// use valobs::financial::{Currency, Money};
+    /// use valobs::result::ValobsResult;
+    ///
+    /// fn main() -> ValobsResult<()> {
+    ///   assert_eq!(Money::from_decimal_str("12.34", Currency::USD)?.amount(), 1234);
+    ///   assert_eq!(Money::from_decimal_str("-0.5", Currency::USD)?.amount(), -50);
+    ///   assert_eq!(Money::from_decimal_str("1.234", Currency::BHD)?.amount(), 1234);
+    ///   assert!(Money::from_decimal_str("12.34", Currency::JPY).is_err());
+    ///
+    ///   Ok(())
+    /// }
+    /// ```
+    pub fn from_decimal_str(value: &str, currency: Currency) -> ValobsResult<Money> {
+        let invalid_format = || ValobsError::InvalidFormat {
+            field: "amount",
+            value: value.into(),
+        };
+        let (sign, unsigned) = match value.strip_prefix('-') {
+            Some(unsigned) => ("-", unsigned),
+            None => ("", value.strip_prefix('+').unwrap_or(value)),
+        };
+        let (major, minor) = match unsigned.split_once('.') {
+            Some((major, minor)) if !minor.is_empty() => (major, minor),
+            Some(_) => return Err(invalid_format()),
+            None => (unsigned, ""),
+        };
+        let minor_units = currency.minor_units() as usize;
+        if major.is_empty()
+            || !major.bytes().all(|byte| byte.is_ascii_digit())
+            || !minor.bytes().all(|byte| byte.is_ascii_digit())
+            || minor.len() > minor_units
+        {
+            return Err(invalid_format());
         }
-        Ok(Money { amount, currency })
+
+        let amount = format!("{sign}{major}{minor:0<minor_units$}")
+            .parse::<MoneyAmount>()
+            .map_err(|_| ValobsError::Overflow { field: "amount" })?;
+        Ok(Self::new(amount, currency))
+    }
+
+    /// Create a new `Money` instance from a decimal amount in the major unit of the currency.
+    ///
+    /// Unlike [Money::from_decimal_str], it accepts more fractional digits than the minor unit of the currency,
+    /// and rounds them away with the given rounding mode.
+    ///
+    /// ## Example
+    /// ```
+    /// use valobs::financial::{Currency, Decimal, Money, RoundingMode};
+    /// use valobs::result::ValobsResult;
+    ///
+    /// fn main() -> ValobsResult<()> {
+    ///   let value = Decimal::new(12345, 3); // 12.345
+    ///
+    ///   assert_eq!(Money::from_decimal(value, Currency::USD, RoundingMode::HalfEven)?.amount(), 1234);
+    ///   assert_eq!(Money::from_decimal(value, Currency::USD, RoundingMode::HalfUp)?.amount(), 1235);
+    ///
+    ///   Ok(())
+    /// }
+    /// ```
+    pub fn from_decimal(
+        value: Decimal,
+        currency: Currency,
+        rounding: RoundingMode,
+    ) -> ValobsResult<Money> {
+        let amount = rounding
+            .round(value, currency.minor_units())
+            .checked_mul(Decimal::from(minor_unit_scale(currency)))
+            .and_then(|amount| amount.to_i64())
+            .ok_or(ValobsError::Overflow { field: "amount" })?;
+        Ok(Self::new(amount, currency))
     }
+
+    /// Create a new `Money` instance from a decimal string and a currency code, reporting every invalid field at once.
+    ///
+    /// The amount is parsed as in [Money::from_decimal_str], and the currency as in [Currency::from_str].
+    /// When the currency is invalid, the amount is still checked against the currency with the most minor units,
+    /// so a malformed amount is reported along with the currency.
+    ///
+    /// ## Example
+    /// ```
+    /// use valobs::financial::{Currency, Money};
+    ///
+    /// assert_eq!(Money::validate("12.34", "usd"), Ok(Money::new(1234, Currency::USD)));
+    ///
+    /// let report = Money::validate("12,34", "US").unwrap_err();
+    ///
+    /// assert_eq!(report.fields().collect::<Vec<_>>(), vec!["amount", "currency"]);
+    /// ```
+    pub fn validate(amount: &str, currency: &str) -> ValidationResult<Money> {
+        let currency = currency.parse::<Currency>();
+        let widest = || {
+            Currency::all()
+                .max_by_key(|currency| currency.minor_units())
+                .expect("there are currencies")
+        };
+
+        let mut report = ValidationReport::new();
+        let money = report.check(Self::from_decimal_str(
+            amount,
+            *currency.as_ref().unwrap_or(&widest()),
+        ));
+        let currency = report.check(currency);
+
+        match (money, currency) {
+            (Some(money), Some(_)) => Ok(money),
+            _ => Err(report),
+        }
+    }
+
     /// Get the amount of money.
     pub fn amount(&self) -> MoneyAmount {
         self.amount
     }
 
+    /// Get the whole part of the amount, in the major unit of the currency.
+    ///
+    /// Together with [Money::minor], it splits the amount at the decimal separator,
+    /// so `12.34 USD` has a major part of `12` and a minor part of `34`.
+    /// Both parts have the same sign as the amount.
+    pub fn major(&self) -> MoneyAmount {
+        self.amount / minor_unit_scale(self.currency)
+    }
+
+    /// Get the fractional part of the amount, in the minor unit of the currency.
+    ///
+    /// See [Money::major] for an example.
+    pub fn minor(&self) -> MoneyAmount {
+        self.amount % minor_unit_scale(self.currency)
+    }
+
+    /// Format the amount as a decimal string in the major unit of the currency, such as `"12.34"`.
+    ///
+    /// ## Example
+    /// ```
+    /// use valobs::financial::{Currency, Money};
+    ///
+    /// assert_eq!(Money::new(1234, Currency::USD).to_decimal_string(), "12.34");
+    /// assert_eq!(Money::new(-5, Currency::USD).to_decimal_string(), "-0.05");
+    /// assert_eq!(Money::new(1234, Currency::JPY).to_decimal_string(), "1234");
+    /// ```
+    pub fn to_decimal_string(&self) -> String {
+        let minor_units = self.currency.minor_units() as usize;
+        if minor_units == 0 {
+            return self.amount.to_string();
+        }
+        let sign = if self.is_negative() { "-" } else { "" };
+        let scale = minor_unit_scale(self.currency).unsigned_abs();
+        let amount = self.amount.unsigned_abs();
+        format!("{sign}{}.{:0minor_units$}", amount / scale, amount % scale)
+    }
+
+    /// Get the amount as an exact decimal in the major unit of the currency, such as `12.34` for `1234` cents.
+    pub fn to_decimal(&self) -> Decimal {
+        Decimal::new(self.amount, self.currency.minor_units())
+    }
+
     /// Get the currency of the money.
     pub fn currency(&self) -> &Currency {
         &self.currency
     }
 
+    /// Returns `true` if the amount is zero.
+    pub fn is_zero(&self) -> bool {
+        self.amount == 0
+    }
+
+    /// Returns `true` if the amount is greater than zero.
+    pub fn is_positive(&self) -> bool {
+        self.amount > 0
+    }
+
+    /// Returns `true` if the amount is less than zero.
+    pub fn is_negative(&self) -> bool {
+        self.amount < 0
+    }
+
     pub fn check_currency(&self, other: &Money) -> ValobsResult<()> {
         if self.currency != other.currency {
-            return Err("Currencies must be the same".into());
+            return Err(ValobsError::CurrencyMismatch {
+                expected: self.currency,
+                found: other.currency,
+            });
         }
         Ok(())
     }
 
+    /// Format the money for a locale, using the currency symbol of the locale, such as `$1,234.56`.
+    ///
+    /// See [MoneyFormatter](crate::financial::MoneyFormatter) for more options, and for parsing.
+    pub fn format(&self, locale: Locale) -> String {
+        MoneyFormatter::new(locale).format(self)
+    }
+
+    /// Convert the money into another currency, with a rate from the given provider.
+    ///
+    /// The result is rounded to the minor unit of the target currency with the given rounding mode.
+    /// Converting into the same currency returns the money unchanged, without asking the provider.
+    ///
+    /// ## Example
+    /// ```
+    /// use valobs::financial::{Currency, Decimal, ExchangeRate, Money, RoundingMode, StaticRateProvider};
+    /// use valobs::result::ValobsResult;
+    /// use valobs::temporal::Utc;
+    ///
+    /// fn main() -> ValobsResult<()> {
+    ///   let provider = StaticRateProvider::new()
+    ///     .with_rate(ExchangeRate::new(Currency::USD, Currency::JPY, Decimal::new(1415, 1), Utc::now())?);
+    ///
+    ///   // Convert 10.00 USD to JPY, and back with the inverse rate
+    ///   let yen = Money::new(1000, Currency::USD).convert_to(Currency::JPY, &provider, RoundingMode::HalfEven)?;
+    ///   let dollars = yen.convert_to(Currency::USD, &provider, RoundingMode::HalfEven)?;
+    ///
+    ///   assert_eq!(yen, Money::new(1415, Currency::JPY));
+    ///   assert_eq!(dollars, Money::new(1000, Currency::USD));
+    ///
+    ///   Ok(())
+    /// }
+    /// ```
+    pub fn convert_to<P>(
+        &self,
+        currency: Currency,
+        provider: &P,
+        rounding: RoundingMode,
+    ) -> ValobsResult<Money>
+    where
+        P: RateProvider + ?Sized,
+    {
+        if self.currency == currency {
+            return Ok(*self);
+        }
+        provider
+            .rate(self.currency, currency)?
+            .convert(self, rounding)
+    }
+
+    /// Add two `Money` instances together, or return `None` if the currencies differ or the sum overflows.
+    ///
+    /// Use [Money::add] to find out why the sum failed.
+    pub fn checked_add(&self, other: &Money) -> Option<Money> {
+        self.add(other).ok()
+    }
+
+    /// Subtract one `Money` instance from another, or return `None` if the currencies differ or the difference overflows.
+    ///
+    /// Use [Money::subtract] to find out why the difference failed.
+    pub fn checked_sub(&self, other: &Money) -> Option<Money> {
+        self.subtract(other).ok()
+    }
+
+    /// Multiply the amount by an integer, or return `None` if the product overflows.
+    ///
+    /// Use [Money::multiply] to scale by a fraction.
+    pub fn checked_mul(&self, factor: MoneyAmount) -> Option<Money> {
+        let amount = self.amount.checked_mul(factor)?;
+        Some(Self::new(amount, self.currency))
+    }
+
+    /// Add up money of one currency, starting from zero.
+    ///
+    /// Unlike summing into a [ValobsResult], an empty iterator gives zero in the given currency.
+    ///
+    /// ## Example
+    /// ```
+    /// use valobs::financial::{Currency, Money};
+    /// use valobs::result::ValobsResult;
+    ///
+    /// fn main() -> ValobsResult<()> {
+    ///   let lines = vec![Money::new(100, Currency::USD), Money::new(250, Currency::USD)];
+    ///
+    ///   assert_eq!(Money::try_sum(lines.clone(), Currency::USD)?, Money::new(350, Currency::USD));
+    ///   assert_eq!(Money::try_sum(vec![], Currency::USD)?, Money::zero(Currency::USD));
+    ///
+    ///   // Or with the `Sum` trait, when the iterator is known not to be empty
+    ///   let total: ValobsResult<Money> = lines.into_iter().sum();
+    ///   assert_eq!(total?, Money::new(350, Currency::USD));
+    ///
+    ///   Ok(())
+    /// }
+    /// ```
+    pub fn try_sum<I: IntoIterator<Item = Money>>(
+        money: I,
+        currency: Currency,
+    ) -> ValobsResult<Money> {
+        money
+            .into_iter()
+            .try_fold(Self::zero(currency), |total, money| total.add(&money))
+    }
+
     /// Add two `Money` instances together.
     pub fn add(&self, other: &Money) -> ValobsResult<Money> {
         self.check_currency(other)?;
+        let amount = self
+            .amount
+            .checked_add(other.amount)
+            .ok_or(ValobsError::Overflow { field: "amount" })?;
         Ok(Money {
-            amount: self.amount + other.amount,
-            currency: self.currency.clone(),
+            amount,
+            currency: self.currency,
         })
     }
 
     /// Subtract one `Money` instance from another.
+    ///
+    /// The result is negative if the other amount is larger.
     pub fn subtract(&self, other: &Money) -> ValobsResult<Money> {
-        if self.amount < other.amount {
-            return Err("Amount must be greater than or equal to the other amount".into());
-        }
         self.check_currency(other)?;
+        let amount = self
+            .amount
+            .checked_sub(other.amount)
+            .ok_or(ValobsError::Overflow { field: "amount" })?;
+        Ok(Money {
+            amount,
+            currency: self.currency,
+        })
+    }
+
+    /// Flip the sign of the amount, turning a credit into a debit and vice versa.
+    pub fn negate(&self) -> ValobsResult<Money> {
+        let amount = self
+            .amount
+            .checked_neg()
+            .ok_or(ValobsError::Overflow { field: "amount" })?;
+        Ok(Money {
+            amount,
+            currency: self.currency,
+        })
+    }
+
+    /// Get the absolute value of the amount.
+    pub fn abs(&self) -> ValobsResult<Money> {
+        let amount = self
+            .amount
+            .checked_abs()
+            .ok_or(ValobsError::Overflow { field: "amount" })?;
         Ok(Money {
-            amount: self.amount - other.amount,
-            currency: self.currency.clone(),
+            amount,
+            currency: self.currency,
         })
     }
 
     /// Multiply a `Money` instance by a scalar.
-    pub fn multiply(&self, scalar: f64) -> Money {
-        Money {
-            amount: (self.amount as f64 * scalar).round() as MoneyAmount,
-            currency: self.currency.clone(),
-        }
+    ///
+    /// The product is computed exactly with decimal arithmetic,
+    /// and then rounded to the minor unit of the currency with the given rounding mode.
+    ///
+    /// ## Example
+    /// ```
+    /// use valobs::financial::{Currency, Decimal, Money, RoundingMode};
+    /// use valobs::result::ValobsResult;
+    ///
+    /// fn main() -> ValobsResult<()> {
+    ///   let money = Money::new(25, Currency::USD);
+    ///   let scalar = Decimal::new(5, 1); // 0.5
+    ///
+    ///   // 0.25 USD * 0.5 = 0.125 USD
+    ///   assert_eq!(money.multiply(scalar, RoundingMode::HalfEven)?.amount(), 12);
+    ///   assert_eq!(money.multiply(scalar, RoundingMode::HalfUp)?.amount(), 13);
+    ///
+    ///   Ok(())
+    /// }
+    /// ```
+    pub fn multiply(&self, scalar: Decimal, rounding: RoundingMode) -> ValobsResult<Money> {
+        self.scale(Decimal::from(self.amount).checked_mul(scalar), rounding)
     }
 
     /// Divide a `Money` instance by a scalar.
-    pub fn divide(&self, scalar: f64) -> ValobsResult<Money> {
-        if scalar == 0.0 {
-            return Err("Scalar must be greater than 0".into());
+    ///
+    /// Like [Money::multiply], the quotient is rounded to the minor unit of the currency with the given rounding mode.
+    pub fn divide(&self, scalar: Decimal, rounding: RoundingMode) -> ValobsResult<Money> {
+        if scalar.is_zero() {
+            return Err(ValobsError::DivisionByZero { field: "scalar" });
         }
-        Ok(Money {
-            amount: (self.amount as f64 / scalar).round() as MoneyAmount,
-            currency: self.currency.clone(),
-        })
+        self.scale(Decimal::from(self.amount).checked_div(scalar), rounding)
+    }
+
+    fn scale(&self, amount: Option<Decimal>, rounding: RoundingMode) -> ValobsResult<Money> {
+        let amount = amount
+            .and_then(|amount| rounding.round(amount, 0).to_i64())
+            .ok_or(ValobsError::Overflow { field: "amount" })?;
+        Ok(Self::new(amount, self.currency))
     }
 
     /// Allocate the `Money` instance between a number of parts.
     ///
     /// The result is an array of `Money` instances, where the sum of the parts equals the original amount.
-    /// The first parts will be larger than the last parts if there is a remainder.
+    /// The first parts will be larger (in absolute value) than the last parts if there is a remainder.
     ///
     /// ## When to use
     /// This is useful for **splitting a bill** between a number of people.
@@ -115,10 +501,10 @@ impl Money {
     /// use valobs::result::ValobsResult;
     ///
     /// fn main() -> ValobsResult<()> {
-    ///    let money = Money::new(100, Currency::USD)?;
+    ///    let money = Money::new(100, Currency::USD);
     ///    let parts = 3;
     ///
-    ///    // Split 100 USD between 3 people
+    ///    // Split 1.00 USD between 3 people
     ///    let result = money.allocate(parts)?;
     ///
     ///    assert_eq!(result.len(), 3);
@@ -133,31 +519,35 @@ impl Money {
     /// ```
     pub fn allocate(&self, parts: u32) -> ValobsResult<Vec<Money>> {
         if parts == 0 {
-            return Err("Parts must be greater than 0".into());
+            return Err(ValobsError::NotPositive {
+                field: "parts",
+                value: parts as f64,
+            });
         }
-        let low_result = Self::new(self.amount / parts as MoneyAmount, self.currency)?;
-        let remainder = self.amount % parts as MoneyAmount;
+        let low_amount = self.amount / parts as MoneyAmount;
+        let remainder = (self.amount % parts as MoneyAmount).abs();
 
-        (0..parts as MoneyAmount)
+        Ok((0..parts as MoneyAmount)
             .map(|i| {
                 if i < remainder {
-                    Self::new(low_result.amount + 1, self.currency.clone())
+                    Self::new(low_amount + self.amount.signum(), self.currency)
                 } else {
-                    Ok(low_result)
+                    Self::new(low_amount, self.currency)
                 }
             })
-            .collect::<ValobsResult<Vec<Money>>>()
+            .collect())
     }
 
     /// Allocate the `Money` instance by a set of ratios.
     /// It works the same way as `allocate`, but instead of splitting the money evenly, you can split it by a set of ratios.
-    /// The ratios must be positive and finite, and the sum of the ratios must be greater than 0.
+    /// The ratios must be positive and finite.
     ///
-    /// Just like `allocate`, if there is a remainder, the first parts will be larger than the last parts.
+    /// The parts always add up to the original amount. The minor units left over after rounding every part down
+    /// go to the parts with the largest remainders, as described in [Allocation].
     ///
     /// ## When to use
     /// This is useful for **splitting a bill** between a number of people, where some people should pay more than others.
-    /// For example, if you have a bill of 100 USD, and you want to split it by the ratios 1:2:3, you would get 17 USD, 33 USD, and 50 USD.
+    /// For example, if you have a bill of 1.00 USD, and you want to split it by the ratios 1:2:3, you would get 0.17 USD, 0.33 USD, and 0.50 USD.
     ///
     /// ## Example
     /// ```
@@ -165,10 +555,10 @@ impl Money {
     /// use valobs::result::ValobsResult;
     ///
     /// fn main() -> ValobsResult<()> {
-    ///   let money = Money::new(100, Currency::USD)?;
+    ///   let money = Money::new(100, Currency::USD);
     ///   let ratios = vec![1.0, 2.0, 3.0];
     ///
-    ///   // Split 100 USD by the ratios 1:2:3
+    ///   // Split 1.00 USD by the ratios 1:2:3
     ///   let result = money.allocate_by_ratio(ratios)?;
     ///
     ///   assert_eq!(result.len(), 3);
@@ -183,25 +573,145 @@ impl Money {
     /// }
     /// ```
     pub fn allocate_by_ratio(&self, ratios: Vec<f64>) -> ValobsResult<Vec<Money>> {
-        let total: f64 = ratios.iter().sum();
+        Allocation::from_floats(&ratios)?.allocate(self)
+    }
 
-        ratios
-            .iter()
-            .map(|ratio| {
-                let amount = (self.amount as f64 * ratio / total).round() as MoneyAmount;
-                Money::new(amount, self.currency.clone())
-            })
-            .collect::<ValobsResult<Vec<Money>>>()
+    /// Allocate the `Money` instance by an [Allocation], such as one built from integer or decimal ratios.
+    pub fn allocate_by(&self, allocation: &Allocation) -> ValobsResult<Vec<Money>> {
+        allocation.allocate(self)
     }
 }
 
 impl ValueObject<'_> for Money {}
 
-pub type MoneyAmount = u64;
+impl fmt::Display for Money {
+    /// Formats the money as its currency code and decimal amount, such as `USD 12.34`.
+    ///
+    /// The output doesn't depend on a locale, and can be parsed back with [FromStr].
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.currency, self.to_decimal_string())
+    }
+}
+
+impl FromStr for Money {
+    type Err = ValobsError;
+
+    /// Parses money from its currency code and decimal amount, such as `USD 12.34`.
+    fn from_str(value: &str) -> ValobsResult<Self> {
+        let (currency, amount) =
+            value
+                .split_once(' ')
+                .ok_or_else(|| ValobsError::InvalidFormat {
+                    field: "money",
+                    value: value.into(),
+                })?;
+        Self::from_decimal_str(amount, currency.parse()?)
+    }
+}
+
+impl PartialOrd for Money {
+    /// Compares the amounts of money in the same currency.
+    ///
+    /// Money in different currencies can't be compared without an exchange rate, so it returns `None`.
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if self.currency != other.currency {
+            return None;
+        }
+        Some(self.amount.cmp(&other.amount))
+    }
+}
+
+impl Add for Money {
+    type Output = ValobsResult<Money>;
+
+    /// Adds money like [Money::add], so it fails on different currencies and on overflow instead of panicking.
+    fn add(self, other: Money) -> ValobsResult<Money> {
+        Money::add(&self, &other)
+    }
+}
+
+impl Add<&Money> for Money {
+    type Output = ValobsResult<Money>;
+
+    fn add(self, other: &Money) -> ValobsResult<Money> {
+        Money::add(&self, other)
+    }
+}
+
+impl Add<Money> for ValobsResult<Money> {
+    type Output = ValobsResult<Money>;
+
+    /// Adds money to the result of a previous operation, so sums can be chained, such as `a + b + c`.
+    fn add(self, other: Money) -> ValobsResult<Money> {
+        self?.add(&other)
+    }
+}
+
+impl Sub for Money {
+    type Output = ValobsResult<Money>;
+
+    /// Subtracts money like [Money::subtract], so it fails on different currencies and on overflow instead of panicking.
+    fn sub(self, other: Money) -> ValobsResult<Money> {
+        self.subtract(&other)
+    }
+}
+
+impl Sub<Money> for ValobsResult<Money> {
+    type Output = ValobsResult<Money>;
+
+    /// Subtracts money from the result of a previous operation, so differences can be chained, such as `a - b - c`.
+    fn sub(self, other: Money) -> ValobsResult<Money> {
+        self?.subtract(&other)
+    }
+}
+
+impl Mul<MoneyAmount> for Money {
+    type Output = ValobsResult<Money>;
+
+    /// Multiplies the amount by an integer, failing on overflow instead of panicking.
+    fn mul(self, factor: MoneyAmount) -> ValobsResult<Money> {
+        self.checked_mul(factor)
+            .ok_or(ValobsError::Overflow { field: "amount" })
+    }
+}
+
+impl Neg for Money {
+    type Output = ValobsResult<Money>;
+
+    /// Flips the sign like [Money::negate], failing on overflow instead of panicking.
+    fn neg(self) -> ValobsResult<Money> {
+        self.negate()
+    }
+}
+
+impl Sum<Money> for ValobsResult<Money> {
+    /// Adds up money of one currency.
+    ///
+    /// The currency is taken from the first item, so an empty iterator fails with [ValobsError::Empty].
+    /// Use [Money::try_sum] to get zero instead.
+    fn sum<I: Iterator<Item = Money>>(mut money: I) -> Self {
+        let first = money.next().ok_or(ValobsError::Empty { field: "money" })?;
+        money.try_fold(first, |total, money| total.add(&money))
+    }
+}
+
+impl<'a> Sum<&'a Money> for ValobsResult<Money> {
+    fn sum<I: Iterator<Item = &'a Money>>(money: I) -> Self {
+        money.copied().sum()
+    }
+}
+
+/// Returns the number of minor units in one major unit of the currency, such as 100 for `USD`.
+fn minor_unit_scale(currency: Currency) -> MoneyAmount {
+    (10 as MoneyAmount).pow(currency.minor_units())
+}
+
+pub type MoneyAmount = i64;
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::financial::StaticRateProvider;
 
     #[test]
     fn create_money() {
@@ -213,20 +723,318 @@ mod test {
         let result = Money::new(amount, currency);
 
         // Assert
-        assert!(result.is_ok());
+        assert_eq!(result.amount(), 100);
+        assert_eq!(result.currency(), &currency);
+        assert!(result.is_positive());
+    }
+
+    #[test]
+    fn create_money_with_zero_amount() {
+        // Act
+        let result = Money::zero(Currency::USD);
+
+        // Assert
+        assert_eq!(result, Money::new(0, Currency::USD));
+        assert!(result.is_zero());
+        assert!(!result.is_positive());
+        assert!(!result.is_negative());
     }
 
     #[test]
-    fn fails_to_create_money_when_amount_is_zero() {
+    fn create_money_with_negative_amount() {
+        // Act
+        let result = Money::new(-100, Currency::USD);
+
+        // Assert
+        assert_eq!(result.amount(), -100);
+        assert!(result.is_negative());
+    }
+
+    #[test]
+    fn create_money_from_major_amount() -> ValobsResult<()> {
+        assert_eq!(Money::from_major(12, Currency::USD)?.amount(), 1200);
+        assert_eq!(Money::from_major(-12, Currency::BHD)?.amount(), -12000);
+        assert_eq!(Money::from_major(12, Currency::JPY)?.amount(), 12);
+        assert_eq!(
+            Money::from_major(MoneyAmount::MAX, Currency::USD),
+            Err(ValobsError::Overflow { field: "amount" })
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn create_money_from_decimal_str() -> ValobsResult<()> {
+        assert_eq!(
+            Money::from_decimal_str("12.34", Currency::USD)?.amount(),
+            1234
+        );
+        assert_eq!(
+            Money::from_decimal_str("12.3", Currency::USD)?.amount(),
+            1230
+        );
+        assert_eq!(Money::from_decimal_str("12", Currency::USD)?.amount(), 1200);
+        assert_eq!(Money::from_decimal_str("+0.01", Currency::USD)?.amount(), 1);
+        assert_eq!(
+            Money::from_decimal_str("-12.34", Currency::USD)?.amount(),
+            -1234
+        );
+        assert_eq!(
+            Money::from_decimal_str("1235", Currency::JPY)?.amount(),
+            1235
+        );
+        assert_eq!(
+            Money::from_decimal_str("0.0001", Currency::CLF)?.amount(),
+            1
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn fails_to_create_money_from_invalid_decimal_str() {
+        for value in [
+            "", "-", "12.", ".5", "12.345", "1,234.56", "12.3a", "USD 12",
+        ] {
+            assert_eq!(
+                Money::from_decimal_str(value, Currency::USD),
+                Err(ValobsError::InvalidFormat {
+                    field: "amount",
+                    value: value.into(),
+                })
+            );
+        }
+        assert!(Money::from_decimal_str("1.5", Currency::JPY).is_err());
+        assert_eq!(
+            Money::from_decimal_str("99999999999999999999", Currency::USD),
+            Err(ValobsError::Overflow { field: "amount" })
+        );
+    }
+
+    #[test]
+    fn split_money_into_major_and_minor_parts() {
+        let money = Money::new(1234, Currency::USD);
+        assert_eq!((money.major(), money.minor()), (12, 34));
+
+        let money = Money::new(-1234, Currency::USD);
+        assert_eq!((money.major(), money.minor()), (-12, -34));
+
+        let money = Money::new(1234, Currency::BHD);
+        assert_eq!((money.major(), money.minor()), (1, 234));
+
+        let money = Money::new(1234, Currency::JPY);
+        assert_eq!((money.major(), money.minor()), (1234, 0));
+    }
+
+    #[test]
+    fn validate_money() {
+        // Act
+        let result = Money::validate("12.345", "XYZ");
+
+        // Assert
+        let report = result.unwrap_err();
+        assert_eq!(report.fields().collect::<Vec<_>>(), vec!["currency"]);
+        assert_eq!(
+            Money::validate("12.345", "USD")
+                .unwrap_err()
+                .fields()
+                .collect::<Vec<_>>(),
+            vec!["amount"]
+        );
+        assert_eq!(
+            Money::validate("-0.5", "978"),
+            Ok(Money::new(-50, Currency::EUR))
+        );
+    }
+
+    #[test]
+    fn format_money_as_decimal_string() {
+        assert_eq!(Money::new(1234, Currency::USD).to_decimal_string(), "12.34");
+        assert_eq!(Money::new(5, Currency::USD).to_decimal_string(), "0.05");
+        assert_eq!(
+            Money::new(-1234, Currency::USD).to_decimal_string(),
+            "-12.34"
+        );
+        assert_eq!(Money::new(1234, Currency::JPY).to_decimal_string(), "1234");
+        assert_eq!(Money::new(1, Currency::CLF).to_decimal_string(), "0.0001");
+        assert_eq!(
+            Money::new(MoneyAmount::MIN, Currency::USD).to_decimal_string(),
+            "-92233720368547758.08"
+        );
+    }
+
+    #[test]
+    fn checked_arithmetic_on_money() {
         // Arrange
-        let amount: MoneyAmount = 0;
-        let currency = Currency::USD;
+        let money = Money::new(100, Currency::USD);
+        let other = Money::new(50, Currency::USD);
+        let euros = Money::new(50, Currency::EUR);
+        let max = Money::new(MoneyAmount::MAX, Currency::USD);
+
+        // Assert
+        assert_eq!(
+            money.checked_add(&other),
+            Some(Money::new(150, Currency::USD))
+        );
+        assert_eq!(
+            money.checked_sub(&other),
+            Some(Money::new(50, Currency::USD))
+        );
+        assert_eq!(money.checked_mul(3), Some(Money::new(300, Currency::USD)));
+        assert_eq!(money.checked_add(&euros), None);
+        assert_eq!(max.checked_add(&other), None);
+        assert_eq!(max.checked_mul(2), None);
+    }
+
+    #[test]
+    fn money_operators() -> ValobsResult<()> {
+        // Arrange
+        let money = Money::new(100, Currency::USD);
+        let other = Money::new(50, Currency::USD);
 
         // Act
-        let result = Money::new(amount, currency);
+        let result = (money + other - Money::new(25, Currency::USD))?;
 
         // Assert
-        assert!(result.is_err());
+        assert_eq!(result, Money::new(125, Currency::USD));
+        assert_eq!((money * 3)?, Money::new(300, Currency::USD));
+        assert_eq!((-money)?, Money::new(-100, Currency::USD));
+        assert_eq!(
+            money + Money::new(50, Currency::EUR),
+            Err(ValobsError::CurrencyMismatch {
+                expected: Currency::USD,
+                found: Currency::EUR,
+            })
+        );
+        assert_eq!(
+            Money::new(MoneyAmount::MIN, Currency::USD) - other,
+            Err(ValobsError::Overflow { field: "amount" })
+        );
+        assert!((-Money::new(MoneyAmount::MIN, Currency::USD)).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn compare_money_within_currency() {
+        // Arrange
+        let money = Money::new(100, Currency::USD);
+
+        // Assert
+        assert!(money > Money::new(50, Currency::USD));
+        assert!(money <= Money::new(100, Currency::USD));
+        assert_eq!(money.partial_cmp(&Money::new(50, Currency::EUR)), None);
+    }
+
+    #[test]
+    fn sum_money() -> ValobsResult<()> {
+        // Arrange
+        let lines = [
+            Money::new(100, Currency::USD),
+            Money::new(250, Currency::USD),
+            Money::new(-50, Currency::USD),
+        ];
+
+        // Act
+        let result: ValobsResult<Money> = lines.iter().sum();
+
+        // Assert
+        assert_eq!(result?, Money::new(300, Currency::USD));
+        assert_eq!(
+            Vec::<Money>::new().into_iter().sum::<ValobsResult<Money>>(),
+            Err(ValobsError::Empty { field: "money" })
+        );
+        assert_eq!(
+            Money::try_sum(Vec::new(), Currency::JPY)?,
+            Money::zero(Currency::JPY)
+        );
+        assert!(Money::try_sum(
+            vec![Money::new(1, Currency::USD), Money::new(1, Currency::EUR)],
+            Currency::USD
+        )
+        .is_err());
+        assert_eq!(
+            Money::try_sum(
+                vec![
+                    Money::new(MoneyAmount::MAX, Currency::USD),
+                    Money::new(1, Currency::USD)
+                ],
+                Currency::USD
+            ),
+            Err(ValobsError::Overflow { field: "amount" })
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn convert_money_to_and_from_decimal() -> ValobsResult<()> {
+        // Arrange
+        let value = Decimal::new(-12345, 3);
+
+        // Act
+        let result = Money::from_decimal(value, Currency::USD, RoundingMode::HalfEven)?;
+
+        // Assert
+        assert_eq!(result.amount(), -1234);
+        assert_eq!(result.to_decimal(), Decimal::new(-1234, 2));
+        assert_eq!(
+            Money::from_decimal(Decimal::MAX, Currency::USD, RoundingMode::HalfEven),
+            Err(ValobsError::Overflow { field: "amount" })
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn convert_money_to_same_currency_without_rate() -> ValobsResult<()> {
+        // Arrange
+        let money = Money::new(1234, Currency::USD);
+        let provider = StaticRateProvider::new();
+
+        // Act
+        let result = money.convert_to(Currency::USD, &provider, RoundingMode::HalfEven)?;
+
+        // Assert
+        assert_eq!(result, money);
+        assert_eq!(
+            money.convert_to(Currency::EUR, &provider, RoundingMode::HalfEven),
+            Err(ValobsError::MissingExchangeRate {
+                base: Currency::USD,
+                quote: Currency::EUR,
+            })
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn display_money() {
+        assert_eq!(Money::new(1234, Currency::USD).to_string(), "USD 12.34");
+        assert_eq!(Money::new(-5, Currency::EUR).to_string(), "EUR -0.05");
+        assert_eq!(Money::new(1235, Currency::JPY).to_string(), "JPY 1235");
+    }
+
+    #[test]
+    fn parse_money_from_display_form() -> ValobsResult<()> {
+        assert_eq!(
+            "USD 12.34".parse::<Money>()?,
+            Money::new(1234, Currency::USD)
+        );
+        assert_eq!("EUR -0.05".parse::<Money>()?, Money::new(-5, Currency::EUR));
+        assert!("USD12.34".parse::<Money>().is_err());
+        assert!("XYZ 12.34".parse::<Money>().is_err());
+        assert!("JPY 12.34".parse::<Money>().is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn format_money_for_locale() {
+        assert_eq!(
+            Money::new(123456, Currency::USD).format(Locale::EnUs),
+            "$1,234.56"
+        );
     }
 
     #[test]
@@ -234,8 +1042,8 @@ mod test {
         // Arrange
         let amount: MoneyAmount = 100;
         let currency = Currency::USD;
-        let money = Money::new(amount, currency)?;
-        let other = Money::new(50, currency)?;
+        let money = Money::new(amount, currency);
+        let other = Money::new(50, currency);
 
         // Act
         let result = money.add(&other).unwrap();
@@ -247,13 +1055,34 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn fails_to_add_money_with_different_currencies() -> ValobsResult<()> {
+        // Arrange
+        let money = Money::new(100, Currency::USD);
+        let other = Money::new(50, Currency::EUR);
+
+        // Act
+        let result = money.add(&other);
+
+        // Assert
+        assert_eq!(
+            result,
+            Err(ValobsError::CurrencyMismatch {
+                expected: Currency::USD,
+                found: Currency::EUR,
+            })
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn subtract_money() -> ValobsResult<()> {
         // Arrange
         let amount: MoneyAmount = 100;
         let currency = Currency::USD;
-        let money = Money::new(amount, currency)?;
-        let other = Money::new(50, currency)?;
+        let money = Money::new(amount, currency);
+        let other = Money::new(50, currency);
 
         // Act
         let result = money.subtract(&other).unwrap();
@@ -266,18 +1095,65 @@ mod test {
     }
 
     #[test]
-    fn fails_to_subtract_money_when_given_amout_is_larger() -> ValobsResult<()> {
+    fn subtract_money_below_zero() -> ValobsResult<()> {
         // Arrange
         let amount: MoneyAmount = 100;
         let currency = Currency::USD;
-        let money = Money::new(amount, currency)?;
-        let other = Money::new(150, currency)?;
+        let money = Money::new(amount, currency);
+        let other = Money::new(150, currency);
+
+        // Act
+        let result = money.subtract(&other)?;
+
+        // Assert
+        assert_eq!(result.amount(), -50);
+        assert!(result.is_negative());
+
+        Ok(())
+    }
+
+    #[test]
+    fn fails_to_subtract_money_when_result_overflows() {
+        // Arrange
+        let money = Money::new(MoneyAmount::MIN, Currency::USD);
+        let other = Money::new(1, Currency::USD);
 
         // Act
         let result = money.subtract(&other);
 
         // Assert
-        assert!(result.is_err());
+        assert_eq!(result, Err(ValobsError::Overflow { field: "amount" }));
+    }
+
+    #[test]
+    fn negate_money() -> ValobsResult<()> {
+        // Arrange
+        let money = Money::new(100, Currency::USD);
+
+        // Act
+        let result = money.negate()?;
+
+        // Assert
+        assert_eq!(result, Money::new(-100, Currency::USD));
+        assert_eq!(result.negate()?, money);
+
+        Ok(())
+    }
+
+    #[test]
+    fn absolute_value_of_money() -> ValobsResult<()> {
+        // Arrange
+        let money = Money::new(-100, Currency::USD);
+
+        // Act
+        let result = money.abs()?;
+
+        // Assert
+        assert_eq!(result, Money::new(100, Currency::USD));
+        assert_eq!(
+            Money::new(MoneyAmount::MIN, Currency::USD).abs(),
+            Err(ValobsError::Overflow { field: "amount" })
+        );
 
         Ok(())
     }
@@ -287,11 +1163,11 @@ mod test {
         // Arrange
         let amount: MoneyAmount = 100;
         let currency = Currency::USD;
-        let money = Money::new(amount, currency)?;
-        let scalar = 2.0;
+        let money = Money::new(amount, currency);
+        let scalar = Decimal::from(2);
 
         // Act
-        let result = money.multiply(scalar);
+        let result = money.multiply(scalar, RoundingMode::HalfEven)?;
 
         // Assert
         assert_eq!(result.amount(), 200);
@@ -305,11 +1181,11 @@ mod test {
         // Arrange
         let amount: MoneyAmount = 100;
         let currency = Currency::USD;
-        let money = Money::new(amount, currency)?;
-        let scalar = 2.0;
+        let money = Money::new(amount, currency);
+        let scalar = Decimal::from(2);
 
         // Act
-        let result = money.divide(scalar)?;
+        let result = money.divide(scalar, RoundingMode::HalfEven)?;
 
         // Assert
         assert_eq!(result.amount(), 50);
@@ -323,11 +1199,11 @@ mod test {
         // Arrange
         let amount: MoneyAmount = 100;
         let currency = Currency::USD;
-        let money = Money::new(amount, currency)?;
-        let scalar = 0.0;
+        let money = Money::new(amount, currency);
+        let scalar = Decimal::ZERO;
 
         // Act
-        let result = money.divide(scalar);
+        let result = money.divide(scalar, RoundingMode::HalfEven);
 
         // Assert
         assert!(result.is_err());
@@ -335,12 +1211,61 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn scale_money_with_every_rounding_mode() -> ValobsResult<()> {
+        // Arrange
+        let money = Money::new(-25, Currency::USD);
+        let scalar = Decimal::new(5, 1);
+        let cases = [
+            (RoundingMode::HalfEven, -12),
+            (RoundingMode::HalfUp, -13),
+            (RoundingMode::HalfDown, -12),
+            (RoundingMode::Floor, -13),
+            (RoundingMode::Ceiling, -12),
+            (RoundingMode::Truncate, -12),
+        ];
+
+        for (rounding, expected) in cases {
+            // Act
+            let result = money.multiply(scalar, rounding)?;
+
+            // Assert
+            assert_eq!(result.amount(), expected, "{rounding:?}");
+        }
+        assert_eq!(
+            Money::new(100, Currency::USD)
+                .divide(Decimal::from(3), RoundingMode::Ceiling)?
+                .amount(),
+            34
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn multiply_large_money_without_losing_precision() -> ValobsResult<()> {
+        // Arrange
+        let money = Money::new(9_007_199_254_740_993, Currency::USD);
+
+        // Act
+        let result = money.multiply(Decimal::ONE, RoundingMode::HalfEven)?;
+
+        // Assert
+        assert_eq!(result, money);
+        assert_eq!(
+            money.multiply(Decimal::from(2000), RoundingMode::HalfEven),
+            Err(ValobsError::Overflow { field: "amount" })
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn allocate_money() -> ValobsResult<()> {
         // Arrange
         let amount: MoneyAmount = 100;
         let currency = Currency::USD;
-        let money = Money::new(amount, currency)?;
+        let money = Money::new(amount, currency);
         let parts = 3;
 
         // Act
@@ -363,7 +1288,7 @@ mod test {
         // Arrange
         let amount: MoneyAmount = 100;
         let currency = Currency::USD;
-        let money = Money::new(amount, currency)?;
+        let money = Money::new(amount, currency);
         let parts = 0;
 
         // Act
@@ -375,12 +1300,29 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn allocate_negative_money() -> ValobsResult<()> {
+        // Arrange
+        let money = Money::new(-100, Currency::USD);
+
+        // Act
+        let result = money.allocate(3)?;
+
+        // Assert
+        assert_eq!(
+            result.iter().map(Money::amount).collect::<Vec<_>>(),
+            vec![-34, -33, -33]
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn allocate_money_by_ratio() -> ValobsResult<()> {
         // Arrange
         let amount: MoneyAmount = 100;
         let currency = Currency::USD;
-        let money = Money::new(amount, currency)?;
+        let money = Money::new(amount, currency);
         let ratios = vec![1.0, 2.0, 3.0];
 
         // Act
@@ -398,12 +1340,34 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn allocate_money_by_equal_ratios_without_losing_money() -> ValobsResult<()> {
+        // Arrange
+        let money = Money::new(100, Currency::USD);
+        let ratios = vec![1.0, 1.0, 1.0];
+
+        // Act
+        let result = money.allocate_by_ratio(ratios)?;
+
+        // Assert
+        assert_eq!(
+            result.iter().map(Money::amount).collect::<Vec<_>>(),
+            vec![34, 33, 33]
+        );
+        assert_eq!(
+            money.allocate_by(&Allocation::from_integers(&[1, 1, 1]))?,
+            result
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn fails_to_allocate_money_by_ratio_when_ratio_is_zero() -> ValobsResult<()> {
         // Arrange
         let amount: MoneyAmount = 100;
         let currency = Currency::USD;
-        let money = Money::new(amount, currency)?;
+        let money = Money::new(amount, currency);
         let ratios = vec![1.0, 0.0, 3.0];
 
         // Act
@@ -420,7 +1384,7 @@ mod test {
         // Arrange
         let amount: MoneyAmount = 100;
         let currency = Currency::USD;
-        let money = Money::new(amount, currency)?;
+        let money = Money::new(amount, currency);
         let ratios = vec![1.0, -2.0, 3.0];
 
         // Act
@@ -437,7 +1401,7 @@ mod test {
         // Arrange
         let amount: MoneyAmount = 100;
         let currency = Currency::USD;
-        let money = Money::new(amount, currency)?;
+        let money = Money::new(amount, currency);
         let ratios = vec![1.0, f64::INFINITY, 3.0];
 
         // Act
@@ -454,7 +1418,7 @@ mod test {
         // Arrange
         let amount: MoneyAmount = 100;
         let currency = Currency::USD;
-        let money = Money::new(amount, currency)?;
+        let money = Money::new(amount, currency);
         let ratios = vec![1.0, f64::NAN, 3.0];
 
         // Act
@@ -471,7 +1435,7 @@ mod test {
         // Arrange
         let amount: MoneyAmount = 100;
         let currency = Currency::USD;
-        let money = Money::new(amount, currency)?;
+        let money = Money::new(amount, currency);
         let ratios = vec![];
 
         // Act
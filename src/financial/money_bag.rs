@@ -0,0 +1,368 @@
+use super::{
+    currency::Currency,
+    exchange_rate::RateProvider,
+    money::{Money, MoneyAmount},
+    rounding::RoundingMode,
+};
+use crate::{
+    result::{ValobsError, ValobsResult},
+    traits::ValueObject,
+};
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeMap, fmt};
+
+/// A value object representing a collection of money in several currencies.
+///
+/// ## What is a _Money Bag_?
+///
+/// A money bag keeps one total per currency, so money of different currencies can be added up
+/// without converting it first. Adding 1.00 USD and 2.00 EUR to an empty bag gives a bag with both amounts,
+/// while adding another 0.50 USD gives a bag with 1.50 USD and 2.00 EUR.
+/// Currencies whose total comes back to zero are removed from the bag.
+///
+/// ## When to use
+///
+/// Use it for shopping carts, wallets and reports that hold several currencies at once.
+/// Collapse it into a single currency with [MoneyBag::total_in] when one amount is needed.
+///
+/// ## Example
+///
+/// ```
+/// use valobs::financial::{Currency, Money, MoneyBag};
+/// use valobs::result::ValobsResult;
+///
+/// fn main() -> ValobsResult<()> {
+///   let mut bag = MoneyBag::new();
+///   bag.add(Money::new(100, Currency::USD))?;
+///   bag.add(Money::new(200, Currency::EUR))?;
+///   bag.add(Money::new(50, Currency::USD))?;
+///
+///   assert_eq!(bag.get(Currency::USD), Money::new(150, Currency::USD));
+///   assert_eq!(bag.get(Currency::EUR), Money::new(200, Currency::EUR));
+///   assert_eq!(bag.get(Currency::JPY), Money::zero(Currency::JPY));
+///
+///   Ok(())
+/// }
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Default, Serialize, Deserialize)]
+#[serde(
+    from = "BTreeMap<Currency, MoneyAmount>",
+    into = "BTreeMap<Currency, MoneyAmount>"
+)]
+pub struct MoneyBag {
+    amounts: BTreeMap<Currency, MoneyAmount>,
+}
+
+impl MoneyBag {
+    /// Create an empty `MoneyBag` instance.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a new `MoneyBag` instance holding the sum of the given money.
+    pub fn try_from_iter<I: IntoIterator<Item = Money>>(money: I) -> ValobsResult<Self> {
+        let mut bag = Self::new();
+        for money in money {
+            bag.add(money)?;
+        }
+        Ok(bag)
+    }
+
+    /// Add money to the total of its currency.
+    ///
+    /// The bag is left unchanged if the total overflows.
+    pub fn add(&mut self, money: Money) -> ValobsResult<()> {
+        let total = self.get(*money.currency()).add(&money)?;
+        self.set(total);
+        Ok(())
+    }
+
+    /// Subtract money from the total of its currency.
+    ///
+    /// Like [Money::subtract], the total may become negative.
+    /// The bag is left unchanged if the total overflows.
+    pub fn subtract(&mut self, money: Money) -> ValobsResult<()> {
+        let total = self.get(*money.currency()).subtract(&money)?;
+        self.set(total);
+        Ok(())
+    }
+
+    /// Add every total of another bag to this bag.
+    ///
+    /// The bag is left unchanged if any of the totals overflows.
+    pub fn merge(&mut self, other: &MoneyBag) -> ValobsResult<()> {
+        let totals = other
+            .iter()
+            .map(|money| self.get(*money.currency()).add(&money))
+            .collect::<ValobsResult<Vec<_>>>()?;
+        for total in totals {
+            self.set(total);
+        }
+        Ok(())
+    }
+
+    /// Get the total of a currency, which is zero if the bag holds none of it.
+    pub fn get(&self, currency: Currency) -> Money {
+        let amount = self.amounts.get(&currency).copied().unwrap_or_default();
+        Money::new(amount, currency)
+    }
+
+    /// Returns `true` if the bag holds a non-zero total of the currency.
+    pub fn contains(&self, currency: Currency) -> bool {
+        self.amounts.contains_key(&currency)
+    }
+
+    /// Returns the currencies with a non-zero total, in the alphabetical order of their codes.
+    pub fn currencies(&self) -> impl Iterator<Item = Currency> + '_ {
+        self.amounts.keys().copied()
+    }
+
+    /// Returns the non-zero totals, in the alphabetical order of their currency codes.
+    pub fn iter(&self) -> impl Iterator<Item = Money> + '_ {
+        self.amounts
+            .iter()
+            .map(|(currency, amount)| Money::new(*amount, *currency))
+    }
+
+    /// Returns the number of currencies with a non-zero total.
+    pub fn len(&self) -> usize {
+        self.amounts.len()
+    }
+
+    /// Returns `true` if the bag holds no money.
+    pub fn is_empty(&self) -> bool {
+        self.amounts.is_empty()
+    }
+
+    /// Convert every total into one currency, with rates from the given provider, and add them up.
+    ///
+    /// Each total is rounded to the minor unit of the target currency before it is added,
+    /// as it would be if the money were exchanged.
+    ///
+    /// ## Example
+    /// ```
+    /// use valobs::financial::{Currency, Decimal, ExchangeRate, Money, MoneyBag, RoundingMode, StaticRateProvider};
+    /// use valobs::result::ValobsResult;
+    /// use valobs::temporal::Utc;
+    ///
+    /// fn main() -> ValobsResult<()> {
+    ///   let provider = StaticRateProvider::new()
+    ///     .with_rate(ExchangeRate::new(Currency::EUR, Currency::USD, Decimal::new(11, 1), Utc::now())?);
+    ///   let bag = MoneyBag::try_from_iter([
+    ///     Money::new(100, Currency::USD),
+    ///     Money::new(200, Currency::EUR),
+    ///   ])?;
+    ///
+    ///   let result = bag.total_in(Currency::USD, &provider, RoundingMode::HalfEven)?;
+    ///
+    ///   assert_eq!(result, Money::new(320, Currency::USD));
+    ///
+    ///   Ok(())
+    /// }
+    /// ```
+    pub fn total_in<P>(
+        &self,
+        currency: Currency,
+        provider: &P,
+        rounding: RoundingMode,
+    ) -> ValobsResult<Money>
+    where
+        P: RateProvider + ?Sized,
+    {
+        self.iter().try_fold(Money::zero(currency), |total, money| {
+            total.add(&money.convert_to(currency, provider, rounding)?)
+        })
+    }
+
+    fn set(&mut self, total: Money) {
+        if total.is_zero() {
+            self.amounts.remove(total.currency());
+        } else {
+            self.amounts.insert(*total.currency(), total.amount());
+        }
+    }
+}
+
+impl ValueObject<'_> for MoneyBag {}
+
+impl From<Money> for MoneyBag {
+    fn from(money: Money) -> Self {
+        let mut bag = Self::new();
+        bag.set(money);
+        bag
+    }
+}
+
+impl From<BTreeMap<Currency, MoneyAmount>> for MoneyBag {
+    fn from(mut amounts: BTreeMap<Currency, MoneyAmount>) -> Self {
+        amounts.retain(|_, amount| *amount != 0);
+        Self { amounts }
+    }
+}
+
+impl From<MoneyBag> for BTreeMap<Currency, MoneyAmount> {
+    fn from(bag: MoneyBag) -> Self {
+        bag.amounts
+    }
+}
+
+impl TryFrom<Vec<Money>> for MoneyBag {
+    type Error = ValobsError;
+
+    fn try_from(money: Vec<Money>) -> ValobsResult<Self> {
+        Self::try_from_iter(money)
+    }
+}
+
+impl fmt::Display for MoneyBag {
+    /// Formats the totals like [Money], separated by commas, such as `EUR 2.00, USD 1.50`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, money) in self.iter().enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{money}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        financial::{Decimal, ExchangeRate, StaticRateProvider},
+        temporal::Utc,
+    };
+
+    #[test]
+    fn add_money_of_several_currencies() -> ValobsResult<()> {
+        // Arrange
+        let mut bag = MoneyBag::new();
+
+        // Act
+        bag.add(Money::new(100, Currency::USD))?;
+        bag.add(Money::new(200, Currency::EUR))?;
+        bag.add(Money::new(50, Currency::USD))?;
+
+        // Assert
+        assert_eq!(bag.len(), 2);
+        assert_eq!(bag.get(Currency::USD).amount(), 150);
+        assert_eq!(bag.get(Currency::EUR).amount(), 200);
+        assert_eq!(
+            bag.currencies().collect::<Vec<_>>(),
+            vec![Currency::EUR, Currency::USD]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn subtract_money_removes_zero_totals() -> ValobsResult<()> {
+        // Arrange
+        let mut bag = MoneyBag::try_from_iter([
+            Money::new(100, Currency::USD),
+            Money::new(200, Currency::EUR),
+        ])?;
+
+        // Act
+        bag.subtract(Money::new(100, Currency::USD))?;
+        bag.subtract(Money::new(300, Currency::EUR))?;
+
+        // Assert
+        assert!(!bag.contains(Currency::USD));
+        assert_eq!(bag.get(Currency::EUR).amount(), -100);
+        assert_eq!(bag.len(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn merge_money_bags() -> ValobsResult<()> {
+        // Arrange
+        let mut bag = MoneyBag::from(Money::new(100, Currency::USD));
+        let other = MoneyBag::try_from_iter([
+            Money::new(50, Currency::USD),
+            Money::new(200, Currency::EUR),
+        ])?;
+
+        // Act
+        bag.merge(&other)?;
+
+        // Assert
+        assert_eq!(bag.to_string(), "EUR 2.00, USD 1.50");
+
+        Ok(())
+    }
+
+    #[test]
+    fn fails_to_merge_overflowing_bags_without_changes() -> ValobsResult<()> {
+        // Arrange
+        let mut bag = MoneyBag::try_from_iter([
+            Money::new(100, Currency::EUR),
+            Money::new(MoneyAmount::MAX, Currency::USD),
+        ])?;
+        let other = MoneyBag::try_from_iter([
+            Money::new(100, Currency::EUR),
+            Money::new(1, Currency::USD),
+        ])?;
+        let before = bag.clone();
+
+        // Act
+        let result = bag.merge(&other);
+
+        // Assert
+        assert_eq!(result, Err(ValobsError::Overflow { field: "amount" }));
+        assert_eq!(bag, before);
+
+        Ok(())
+    }
+
+    #[test]
+    fn total_money_bag_in_one_currency() -> ValobsResult<()> {
+        // Arrange
+        let provider = StaticRateProvider::new().with_rate(ExchangeRate::new(
+            Currency::EUR,
+            Currency::USD,
+            Decimal::new(11, 1),
+            Utc::now(),
+        )?);
+        let bag = MoneyBag::try_from_iter([
+            Money::new(100, Currency::USD),
+            Money::new(200, Currency::EUR),
+        ])?;
+
+        // Act
+        let result = bag.total_in(Currency::USD, &provider, RoundingMode::HalfEven)?;
+
+        // Assert
+        assert_eq!(result, Money::new(320, Currency::USD));
+        assert!(bag
+            .total_in(Currency::JPY, &provider, RoundingMode::HalfEven)
+            .is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn serialize_money_bag_to_json() -> ValobsResult<()> {
+        // Arrange
+        let bag = MoneyBag::try_from_iter([
+            Money::new(100, Currency::USD),
+            Money::new(200, Currency::EUR),
+        ])?;
+
+        // Act
+        let json = serde_json::to_string(&bag).unwrap();
+        let result = serde_json::from_str::<MoneyBag>(&json).unwrap();
+
+        // Assert
+        assert_eq!(json, r#"{"EUR":200,"USD":100}"#);
+        assert_eq!(result, bag);
+        assert!(serde_json::from_str::<MoneyBag>(r#"{"EUR":0}"#)
+            .unwrap()
+            .is_empty());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,279 @@
+use super::{
+    currency::Currency,
+    money::{Money, MoneyAmount},
+};
+use crate::result::ValobsError;
+use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+
+#[derive(Serialize, Deserialize)]
+struct ValueFields {
+    value: String,
+    currency: Currency,
+}
+
+/// Serializes [Money] as a bare decimal string in the major unit of its currency, such as `"12.34"` for 12.34 USD.
+///
+/// The currency is left out, for APIs that give it in another field, or that deal in a single currency.
+/// An amount can't be turned back into money without its currency, so there is no `deserialize` function
+/// for `#[serde(with = ...)]`: [deserialize_in](decimal_string::deserialize_in) takes the currency from the caller instead.
+///
+/// ## Example
+///
+/// ```
+/// use serde::Serialize;
+/// use valobs::financial::{Currency, Money, money_serde};
+///
+/// #[derive(Serialize)]
+/// struct Order {
+///   currency: Currency,
+///   #[serde(serialize_with = "money_serde::decimal_string::serialize")]
+///   total: Money,
+/// }
+///
+/// let order = Order { currency: Currency::USD, total: Money::new(1234, Currency::USD) };
+/// let json = serde_json::to_string(&order).unwrap();
+///
+/// assert_eq!(json, r#"{"currency":"USD","total":"12.34"}"#);
+///
+/// let total = money_serde::decimal_string::deserialize_in(serde_json::json!("12.34"), order.currency).unwrap();
+///
+/// assert_eq!(total, order.total);
+/// ```
+pub mod decimal_string {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(money: &Money, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&money.to_decimal_string())
+    }
+
+    /// Deserializes money from a decimal string, such as `"12.34"`, in the given currency.
+    pub fn deserialize_in<'de, D: Deserializer<'de>>(
+        deserializer: D,
+        currency: Currency,
+    ) -> Result<Money, D::Error> {
+        let amount = String::deserialize(deserializer)?;
+        Money::from_decimal_str(&amount, currency).map_err(D::Error::custom)
+    }
+}
+
+/// Serializes [Money] as a bare string of minor units, such as `"1234"` for 12.34 USD.
+///
+/// Like [decimal_string], the currency is left out, so [deserialize_in](minor_units::deserialize_in) takes it from the caller.
+///
+/// ## Example
+///
+/// ```
+/// use serde::Serialize;
+/// use valobs::financial::{Currency, Money, money_serde};
+///
+/// #[derive(Serialize)]
+/// struct Charge {
+///   #[serde(serialize_with = "money_serde::minor_units::serialize")]
+///   amount: Money,
+///   currency: Currency,
+/// }
+///
+/// let charge = Charge { amount: Money::new(1234, Currency::USD), currency: Currency::USD };
+/// let json = serde_json::to_string(&charge).unwrap();
+///
+/// assert_eq!(json, r#"{"amount":"1234","currency":"USD"}"#);
+///
+/// let amount = money_serde::minor_units::deserialize_in(serde_json::json!("1234"), charge.currency).unwrap();
+///
+/// assert_eq!(amount, charge.amount);
+/// ```
+pub mod minor_units {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(money: &Money, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&money.amount())
+    }
+
+    /// Deserializes money from a string of minor units, such as `"1234"`, in the given currency.
+    pub fn deserialize_in<'de, D: Deserializer<'de>>(
+        deserializer: D,
+        currency: Currency,
+    ) -> Result<Money, D::Error> {
+        let amount = String::deserialize(deserializer)?;
+        let minor = amount.parse::<MoneyAmount>().map_err(|_| {
+            D::Error::custom(ValobsError::InvalidFormat {
+                field: "amount",
+                value: amount.clone(),
+            })
+        })?;
+        Ok(Money::new(minor, currency))
+    }
+}
+
+/// Serializes [Money] as an object with a decimal value and a currency,
+/// such as `{"value":"12.34","currency":"USD"}`, which is common in payment APIs.
+///
+/// ## Example
+///
+/// ```
+/// use serde::{Deserialize, Serialize};
+/// use valobs::financial::{Currency, Money, money_serde};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Order {
+///   #[serde(with = "money_serde::decimal_object")]
+///   total: Money,
+/// }
+///
+/// let json = serde_json::to_string(&Order { total: Money::new(1234, Currency::USD) }).unwrap();
+///
+/// assert_eq!(json, r#"{"total":{"value":"12.34","currency":"USD"}}"#);
+/// ```
+pub mod decimal_object {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(money: &Money, serializer: S) -> Result<S::Ok, S::Error> {
+        ValueFields {
+            value: money.to_decimal_string(),
+            currency: *money.currency(),
+        }
+        .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Money, D::Error> {
+        let fields = ValueFields::deserialize(deserializer)?;
+        Money::from_decimal_str(&fields.value, fields.currency).map_err(D::Error::custom)
+    }
+}
+
+/// Serializes [Money] as a single string with its currency code and decimal amount, such as `"USD 12.34"`,
+/// like its `Display` implementation.
+///
+/// ## Example
+///
+/// ```
+/// use serde::{Deserialize, Serialize};
+/// use valobs::financial::{Currency, Money, money_serde};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Order {
+///   #[serde(with = "money_serde::compact")]
+///   total: Money,
+/// }
+///
+/// let json = serde_json::to_string(&Order { total: Money::new(1234, Currency::USD) }).unwrap();
+///
+/// assert_eq!(json, r#"{"total":"USD 12.34"}"#);
+/// ```
+pub mod compact {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(money: &Money, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(money)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Money, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(D::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::{json, Value};
+
+    #[derive(Serialize)]
+    struct Amounts {
+        #[serde(serialize_with = "decimal_string::serialize")]
+        decimal_string: Money,
+        #[serde(serialize_with = "minor_units::serialize")]
+        minor_units: Money,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Objects {
+        #[serde(with = "decimal_object")]
+        decimal_object: Money,
+        #[serde(with = "compact")]
+        compact: Money,
+    }
+
+    #[test]
+    fn serialize_money_in_every_format() {
+        // Arrange
+        let money = Money::new(-1234, Currency::USD);
+
+        // Act
+        let amounts = serde_json::to_string(&Amounts {
+            decimal_string: money,
+            minor_units: money,
+        })
+        .unwrap();
+        let objects = serde_json::to_string(&Objects {
+            decimal_object: money,
+            compact: money,
+        })
+        .unwrap();
+
+        // Assert
+        assert_eq!(
+            amounts,
+            r#"{"decimal_string":"-12.34","minor_units":"-1234"}"#
+        );
+        assert_eq!(
+            objects,
+            r#"{"decimal_object":{"value":"-12.34","currency":"USD"},"compact":"USD -12.34"}"#
+        );
+    }
+
+    #[test]
+    fn money_round_trips_in_every_format() {
+        for money in [
+            Money::new(1234, Currency::USD),
+            Money::new(-5, Currency::EUR),
+            Money::new(1234, Currency::JPY),
+            Money::new(1234, Currency::BHD),
+            Money::new(MoneyAmount::MAX, Currency::USD),
+            Money::new(MoneyAmount::MIN, Currency::USD),
+        ] {
+            // Arrange
+            let currency = *money.currency();
+            let amounts = Amounts {
+                decimal_string: money,
+                minor_units: money,
+            };
+            let objects = Objects {
+                decimal_object: money,
+                compact: money,
+            };
+
+            // Act
+            let amounts = serde_json::to_value(&amounts).unwrap();
+            let decimal_string =
+                decimal_string::deserialize_in(amounts["decimal_string"].clone(), currency)
+                    .unwrap();
+            let minor_units =
+                minor_units::deserialize_in(amounts["minor_units"].clone(), currency).unwrap();
+            let json = serde_json::to_string(&objects).unwrap();
+            let result = serde_json::from_str::<Objects>(&json).unwrap();
+
+            // Assert
+            assert_eq!(decimal_string, money, "{amounts}");
+            assert_eq!(minor_units, money, "{amounts}");
+            assert_eq!(result, objects, "{json}");
+        }
+    }
+
+    #[test]
+    fn fails_to_deserialize_invalid_money() {
+        assert!(decimal_string::deserialize_in(json!("12.345"), Currency::USD).is_err());
+        assert!(decimal_string::deserialize_in(json!(1234), Currency::USD).is_err());
+        assert!(minor_units::deserialize_in(json!("1.5"), Currency::USD).is_err());
+        assert!(minor_units::deserialize_in(Value::Null, Currency::USD).is_err());
+        assert!(serde_json::from_str::<Objects>(
+            r#"{"decimal_object":{"value":"1.5","currency":"JPY"},"compact":"USD 1"}"#
+        )
+        .is_err());
+        assert!(serde_json::from_str::<Objects>(
+            r#"{"decimal_object":{"value":"1","currency":"USD"},"compact":"USD"}"#
+        )
+        .is_err());
+    }
+}
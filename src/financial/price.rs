@@ -0,0 +1,173 @@
+use super::{
+    currency::Currency,
+    money::{Money, MoneyAmount},
+};
+use crate::{
+    result::{ValidationResult, ValobsError, ValobsResult},
+    traits::ValueObject,
+};
+use serde::{Deserialize, Serialize};
+
+/// A value object representing a price, which is an amount of money that is always greater than zero.
+///
+/// ## What is a _Price_?
+///
+/// A price is the amount of money asked for a product or a service.
+/// Unlike [Money](crate::financial::Money), which can also be zero or negative to represent balances and refunds,
+/// a price of zero or less doesn't make sense, so it is rejected when the price is created.
+///
+/// ## When to use
+///
+/// Use this type for catalog prices, unit prices and fees, where a non-positive amount is a mistake.
+/// Convert it into [Money](crate::financial::Money) to do arithmetic that may leave the positive range.
+///
+/// ## Example
+///
+/// ```
+/// use valobs::financial::{Currency, Money, Price};
+/// use valobs::result::ValobsResult;
+///
+/// fn main() -> ValobsResult<()> {
+///   let price = Price::new(100, Currency::USD)?;
+///   let money: Money = price.into();
+///
+///   assert_eq!(money.amount(), 100);
+///   assert!(Price::new(0, Currency::USD).is_err());
+///
+///   Ok(())
+/// }
+/// ```
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Clone, Copy)]
+#[serde(try_from = "Money", into = "Money")]
+pub struct Price(Money);
+
+impl Price {
+    /// Create a new `Price` instance.
+    pub fn new(amount: MoneyAmount, currency: Currency) -> ValobsResult<Price> {
+        Self::try_from(Money::new(amount, currency))
+    }
+
+    /// Create a new `Price` instance, reporting every invalid field at once.
+    ///
+    /// It accepts the same values as [Price::new], but returns a [ValidationReport](crate::result::ValidationReport),
+    /// so it can be combined with the reports of other value objects.
+    pub fn validate(amount: MoneyAmount, currency: Currency) -> ValidationResult<Price> {
+        Ok(Self::new(amount, currency)?)
+    }
+
+    /// Get the amount of the price.
+    pub fn amount(&self) -> MoneyAmount {
+        self.0.amount()
+    }
+
+    /// Get the currency of the price.
+    pub fn currency(&self) -> &Currency {
+        self.0.currency()
+    }
+
+    /// Get the price as `Money`.
+    pub fn money(&self) -> Money {
+        self.0
+    }
+}
+
+impl TryFrom<Money> for Price {
+    type Error = ValobsError;
+
+    fn try_from(money: Money) -> ValobsResult<Self> {
+        if !money.is_positive() {
+            return Err(ValobsError::NotPositive {
+                field: "amount",
+                value: money.amount() as f64,
+            });
+        }
+        Ok(Self(money))
+    }
+}
+
+impl From<Price> for Money {
+    fn from(price: Price) -> Self {
+        price.0
+    }
+}
+
+impl ValueObject<'_> for Price {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn create_price() -> ValobsResult<()> {
+        // Act
+        let result = Price::new(100, Currency::USD)?;
+
+        // Assert
+        assert_eq!(result.amount(), 100);
+        assert_eq!(result.currency(), &Currency::USD);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fails_to_create_price_when_amount_is_not_positive() {
+        assert_eq!(
+            Price::new(0, Currency::USD),
+            Err(ValobsError::NotPositive {
+                field: "amount",
+                value: 0.0,
+            })
+        );
+        assert!(Price::new(-100, Currency::USD).is_err());
+    }
+
+    #[test]
+    fn validate_price() {
+        // Act
+        let result = Price::validate(0, Currency::USD);
+
+        // Assert
+        let report = result.unwrap_err();
+        assert_eq!(report.fields().collect::<Vec<_>>(), vec!["amount"]);
+        assert!(Price::validate(100, Currency::USD).is_ok());
+    }
+
+    #[test]
+    fn convert_between_price_and_money() -> ValobsResult<()> {
+        // Arrange
+        let money = Money::new(100, Currency::USD);
+
+        // Act
+        let price = Price::try_from(money)?;
+
+        // Assert
+        assert_eq!(Money::from(price), money);
+        assert_eq!(price.money(), money);
+        assert!(Price::try_from(Money::zero(Currency::USD)).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn serialize_price_to_json() -> ValobsResult<()> {
+        // Arrange
+        let price = Price::new(100, Currency::USD)?;
+
+        // Act
+        let result = serde_json::to_string(&price).unwrap();
+
+        // Assert
+        assert_eq!(result, r#"{"amount":100,"currency":"USD"}"#);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fails_to_deserialize_non_positive_price_from_json() {
+        // Act
+        let result = serde_json::from_str::<Price>(r#"{"amount":0,"currency":"USD"}"#);
+
+        // Assert
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,83 @@
+use rust_decimal::{Decimal, RoundingStrategy};
+use serde::{Deserialize, Serialize};
+
+/// The rule used to round an amount to the minor unit of its currency.
+///
+/// ## When to use
+///
+/// Pass it to the operations of [Money](crate::financial::Money) that may produce fractions of the minor unit,
+/// such as converting between currencies. Accounting usually requires [RoundingMode::HalfEven],
+/// which is also known as banker's rounding, while prices shown to customers are often rounded with [RoundingMode::HalfUp].
+///
+/// ## Example
+///
+/// ```
+/// use valobs::financial::{Decimal, RoundingMode};
+///
+/// let value = Decimal::new(125, 2); // 1.25
+///
+/// assert_eq!(RoundingMode::HalfEven.round(value, 1), Decimal::new(12, 1));
+/// assert_eq!(RoundingMode::HalfUp.round(value, 1), Decimal::new(13, 1));
+/// assert_eq!(RoundingMode::Floor.round(-value, 1), Decimal::new(-13, 1));
+/// ```
+#[derive(Debug, Default, PartialEq, Eq, Hash, Clone, Copy, Serialize, Deserialize)]
+pub enum RoundingMode {
+    /// Round to the nearest value, and a half to the even neighbour, so `0.5` becomes `0` and `1.5` becomes `2`.
+    #[default]
+    HalfEven,
+    /// Round to the nearest value, and a half away from zero, so `0.5` becomes `1` and `-0.5` becomes `-1`.
+    HalfUp,
+    /// Round to the nearest value, and a half towards zero, so `0.5` becomes `0` and `-0.5` becomes `0`.
+    HalfDown,
+    /// Round towards negative infinity, so `0.9` becomes `0` and `-0.1` becomes `-1`.
+    Floor,
+    /// Round towards positive infinity, so `0.1` becomes `1` and `-0.9` becomes `0`.
+    Ceiling,
+    /// Round towards zero, dropping the extra digits, so `0.9` becomes `0` and `-0.9` becomes `0`.
+    Truncate,
+}
+
+impl RoundingMode {
+    /// Round a decimal value to the given number of digits after the decimal separator.
+    pub fn round(&self, value: Decimal, decimal_places: u32) -> Decimal {
+        value.round_dp_with_strategy(decimal_places, self.strategy())
+    }
+
+    fn strategy(&self) -> RoundingStrategy {
+        match self {
+            Self::HalfEven => RoundingStrategy::MidpointNearestEven,
+            Self::HalfUp => RoundingStrategy::MidpointAwayFromZero,
+            Self::HalfDown => RoundingStrategy::MidpointTowardZero,
+            Self::Floor => RoundingStrategy::ToNegativeInfinity,
+            Self::Ceiling => RoundingStrategy::ToPositiveInfinity,
+            Self::Truncate => RoundingStrategy::ToZero,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_with_every_mode() {
+        // Arrange
+        let values = [15, 25, -15, 11, -11].map(|value| Decimal::new(value, 1));
+        let cases = [
+            (RoundingMode::HalfEven, [2, 2, -2, 1, -1]),
+            (RoundingMode::HalfUp, [2, 3, -2, 1, -1]),
+            (RoundingMode::HalfDown, [1, 2, -1, 1, -1]),
+            (RoundingMode::Floor, [1, 2, -2, 1, -2]),
+            (RoundingMode::Ceiling, [2, 3, -1, 2, -1]),
+            (RoundingMode::Truncate, [1, 2, -1, 1, -1]),
+        ];
+
+        for (mode, expected) in cases {
+            // Act
+            let result = values.map(|value| mode.round(value, 0));
+
+            // Assert
+            assert_eq!(result, expected.map(Decimal::from), "{mode:?}");
+        }
+    }
+}
@@ -0,0 +1,176 @@
+use super::{currency::Currency, money::Money, rounding::RoundingMode};
+use crate::result::{ValobsError, ValobsResult};
+use rusty_money::iso;
+
+/// A [rusty_money] amount of money in an ISO 4217 currency.
+type RustyMoney = rusty_money::Money<'static, iso::Currency>;
+
+impl TryFrom<Currency> for &'static iso::Currency {
+    type Error = ValobsError;
+
+    /// Looks up the [rusty_money] currency with the same ISO 4217 code,
+    /// which fails for currencies that [rusty_money] does not know, such as most historic currencies.
+    fn try_from(currency: Currency) -> ValobsResult<Self> {
+        iso::find(currency.code()).ok_or_else(|| ValobsError::UnknownCode {
+            field: "currency",
+            value: currency.code().into(),
+        })
+    }
+}
+
+impl TryFrom<&iso::Currency> for Currency {
+    type Error = ValobsError;
+
+    /// Parses the ISO 4217 code of a [rusty_money] currency.
+    fn try_from(currency: &iso::Currency) -> ValobsResult<Self> {
+        currency.iso_alpha_code.parse()
+    }
+}
+
+impl TryFrom<Money> for RustyMoney {
+    type Error = ValobsError;
+
+    /// Converts the money into [rusty_money] with the exact same decimal amount.
+    ///
+    /// ## Example
+    /// ```
+    /// use valobs::financial::{Currency, Money};
+    /// use valobs::result::ValobsResult;
+    ///
+    /// fn main() -> ValobsResult<()> {
+    ///   let money = rusty_money::Money::try_from(Money::new(123456, Currency::USD))?;
+    ///
+    ///   assert_eq!(money.currency(), rusty_money::iso::USD);
+    ///   assert_eq!(money.to_string(), "$1,234.56");
+    ///
+    ///   Ok(())
+    /// }
+    /// ```
+    fn try_from(money: Money) -> ValobsResult<Self> {
+        let currency = <&iso::Currency>::try_from(*money.currency())?;
+        Ok(RustyMoney::from_decimal(money.to_decimal(), currency))
+    }
+}
+
+impl TryFrom<RustyMoney> for Money {
+    type Error = ValobsError;
+
+    /// Converts [rusty_money] money into the minor unit of its currency.
+    ///
+    /// Fails if the amount has more fractional digits than the currency allows,
+    /// so round it first with [rusty_money::Money::round] if it is the result of a calculation.
+    ///
+    /// ## Example
+    /// ```
+    /// use rusty_money::iso;
+    /// use valobs::financial::{Currency, Money};
+    /// use valobs::result::ValobsResult;
+    ///
+    /// fn main() -> ValobsResult<()> {
+    ///   let money = Money::try_from(rusty_money::Money::from_str("1,234.56", iso::USD).unwrap())?;
+    ///
+    ///   assert_eq!(money, Money::new(123456, Currency::USD));
+    ///
+    ///   Ok(())
+    /// }
+    /// ```
+    fn try_from(money: RustyMoney) -> ValobsResult<Self> {
+        let currency = Currency::try_from(money.currency())?;
+        let amount = money.amount().normalize();
+        if amount.scale() > currency.minor_units() {
+            return Err(ValobsError::InvalidFormat {
+                field: "amount",
+                value: amount.to_string(),
+            });
+        }
+        Money::from_decimal(amount, currency, RoundingMode::default())
+    }
+}
+
+impl TryFrom<&RustyMoney> for Money {
+    type Error = ValobsError;
+
+    fn try_from(money: &RustyMoney) -> ValobsResult<Self> {
+        Money::try_from(money.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal::Decimal;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn convert_currency_to_rusty_money() -> ValobsResult<()> {
+        // Act
+        let currency = <&iso::Currency>::try_from(Currency::JPY)?;
+
+        // Assert
+        assert_eq!(currency, iso::JPY);
+        assert_eq!(Currency::try_from(currency)?, Currency::JPY);
+        Ok(())
+    }
+
+    #[test]
+    fn money_round_trips_through_rusty_money() -> ValobsResult<()> {
+        for money in [
+            Money::new(123456, Currency::USD),
+            Money::new(-5, Currency::EUR),
+            Money::new(1234, Currency::JPY),
+            Money::new(1234, Currency::BHD),
+            Money::zero(Currency::GBP),
+        ] {
+            // Act
+            let converted = RustyMoney::try_from(money)?;
+
+            // Assert
+            assert_eq!(*converted.amount(), money.to_decimal());
+            assert_eq!(Money::try_from(&converted)?, money);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn fails_to_convert_rusty_money_with_too_many_fractional_digits() {
+        // Arrange
+        let money = RustyMoney::from_decimal(dec!(12.345), iso::USD);
+
+        // Act
+        let result = Money::try_from(money);
+
+        // Assert
+        assert_eq!(
+            result,
+            Err(ValobsError::InvalidFormat {
+                field: "amount",
+                value: "12.345".into(),
+            })
+        );
+    }
+
+    #[test]
+    fn converts_rusty_money_with_trailing_zeros() -> ValobsResult<()> {
+        // Arrange
+        let money = RustyMoney::from_decimal(dec!(12.3400), iso::USD);
+
+        // Act
+        let result = Money::try_from(money)?;
+
+        // Assert
+        assert_eq!(result, Money::new(1234, Currency::USD));
+        Ok(())
+    }
+
+    #[test]
+    fn fails_to_convert_rusty_money_out_of_range() {
+        // Arrange
+        let money = RustyMoney::from_decimal(Decimal::MAX, iso::USD);
+
+        // Act
+        let result = Money::try_from(money);
+
+        // Assert
+        assert_eq!(result, Err(ValobsError::Overflow { field: "amount" }));
+    }
+}
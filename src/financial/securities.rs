@@ -0,0 +1,544 @@
+use super::{card::luhn, iban::mod97};
+use crate::{
+    geography::Country,
+    result::{ValobsError, ValobsResult},
+    traits::ValueObject,
+};
+use serde::{Deserialize, Serialize};
+use std::{fmt, str::FromStr};
+
+/// A value object representing an International Securities Identification Number (ISIN), as defined by ISO 6166.
+///
+/// ## What is an _ISIN_?
+///
+/// An ISIN identifies a security, such as a share or a bond, across markets. It has 12 characters, such as `US0378331005`:
+///
+/// - the alpha-2 code of the country that issued it, such as `US`, or a code such as `XS` for international securities
+/// - the 9 character National Securities Identifying Number (NSIN), such as the [Cusip] `037833100`
+/// - a check digit, computed with the Luhn algorithm after every letter is replaced by two digits
+///
+/// ## Example
+///
+/// ```
+/// use valobs::financial::{Cusip, Isin};
+/// use valobs::geography::Country;
+/// use valobs::result::ValobsResult;
+///
+/// fn main() -> ValobsResult<()> {
+///   let isin: Isin = "US0378331005".parse()?;
+///
+///   assert_eq!(isin.country(), Some(Country::USA));
+///   assert_eq!(isin.nsin(), "037833100");
+///   assert_eq!(isin.to_cusip(), Some(Cusip::new("037833100")?));
+///   assert!("US0378331006".parse::<Isin>().is_err());
+///
+///   Ok(())
+/// }
+/// ```
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct Isin(String);
+
+impl Isin {
+    /// Create a new `Isin` instance from 12 characters.
+    pub fn new(value: &str) -> ValobsResult<Isin> {
+        let isin = normalize("isin", value, 12)?;
+        let valid = isin.char_indices().all(|(index, char)| match index {
+            0 | 1 => char.is_ascii_uppercase(),
+            11 => char.is_ascii_digit(),
+            _ => char.is_ascii_digit() || char.is_ascii_uppercase(),
+        });
+        if !valid {
+            return Err(invalid_format("isin", value));
+        }
+        if !isin_checksum(&isin) {
+            return Err(ValobsError::InvalidChecksum {
+                field: "isin",
+                value: value.into(),
+            });
+        }
+        Ok(Self(isin))
+    }
+
+    /// Create a new `Isin` instance from the country that issued the security and its national identifier.
+    ///
+    /// The national identifier is padded with leading zeros to 9 characters, and the check digit is computed.
+    pub fn from_nsin(country: Country, nsin: &str) -> ValobsResult<Isin> {
+        let nsin = nsin.trim().to_ascii_uppercase();
+        if nsin.is_empty()
+            || nsin.len() > 9
+            || !nsin.chars().all(|char| char.is_ascii_alphanumeric())
+        {
+            return Err(invalid_format("nsin", &nsin));
+        }
+        let isin = format!("{}{:0>9}", country.alpha2(), nsin);
+        let check_digit = (0..10)
+            .find(|digit| isin_checksum(&format!("{isin}{digit}")))
+            .unwrap_or_default();
+        Ok(Self(format!("{isin}{check_digit}")))
+    }
+
+    /// Get the ISIN, such as `US0378331005`.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Get the prefix, which is the alpha-2 code of the country that issued the security, such as `US`.
+    pub fn country_code(&self) -> &str {
+        &self.0[..2]
+    }
+
+    /// Get the country that issued the security.
+    ///
+    /// It is `None` for prefixes that are not countries, such as `XS` for international securities and `EU` for the European Union.
+    pub fn country(&self) -> Option<Country> {
+        Country::from_alpha2(self.country_code())
+    }
+
+    /// Get the National Securities Identifying Number, such as `037833100`.
+    pub fn nsin(&self) -> &str {
+        &self.0[2..11]
+    }
+
+    /// Get the check digit.
+    pub fn check_digit(&self) -> char {
+        self.0.as_bytes()[11] as char
+    }
+
+    /// Get the CUSIP of a security issued in the United States or Canada.
+    pub fn to_cusip(&self) -> Option<Cusip> {
+        match self.country_code() {
+            "US" | "CA" => Cusip::new(self.nsin()).ok(),
+            _ => None,
+        }
+    }
+
+    /// Get the SEDOL of a security issued in the United Kingdom, Ireland or the Crown Dependencies.
+    pub fn to_sedol(&self) -> Option<Sedol> {
+        match (self.country_code(), self.nsin().split_at(2)) {
+            ("GB" | "IE" | "GG" | "IM" | "JE", ("00", sedol)) => Sedol::new(sedol).ok(),
+            _ => None,
+        }
+    }
+}
+
+/// A value object representing a Committee on Uniform Securities Identification Procedures (CUSIP) number.
+///
+/// ## What is a _CUSIP_?
+///
+/// A CUSIP identifies a security issued in the United States or Canada. It has 9 characters, such as `037833100`:
+/// a 6 character issuer code, a 2 character issue number, and a check digit.
+///
+/// ## Example
+///
+/// ```
+/// use valobs::financial::Cusip;
+/// use valobs::geography::Country;
+/// use valobs::result::ValobsResult;
+///
+/// fn main() -> ValobsResult<()> {
+///   let cusip: Cusip = "037833100".parse()?;
+///
+///   assert_eq!(cusip.issuer(), "037833");
+///   assert_eq!(cusip.to_isin(Country::USA)?.as_str(), "US0378331005");
+///
+///   Ok(())
+/// }
+/// ```
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct Cusip(String);
+
+impl Cusip {
+    /// Create a new `Cusip` instance from 9 characters.
+    pub fn new(value: &str) -> ValobsResult<Cusip> {
+        let cusip = normalize("cusip", value, 9)?;
+        let valid = cusip.char_indices().all(|(index, char)| match index {
+            8 => char.is_ascii_digit(),
+            _ => char.is_ascii_digit() || char.is_ascii_uppercase() || "*@#".contains(char),
+        });
+        if !valid {
+            return Err(invalid_format("cusip", value));
+        }
+        let sum: u32 = cusip[..8]
+            .chars()
+            .enumerate()
+            .map(|(index, char)| {
+                let value = match char {
+                    '*' => 36,
+                    '@' => 37,
+                    '#' => 38,
+                    _ => char.to_digit(36).unwrap_or_default(),
+                };
+                let value = if index % 2 == 1 { value * 2 } else { value };
+                value / 10 + value % 10
+            })
+            .sum();
+        if char::from_digit((10 - sum % 10) % 10, 10) != cusip.chars().last() {
+            return Err(ValobsError::InvalidChecksum {
+                field: "cusip",
+                value: value.into(),
+            });
+        }
+        Ok(Self(cusip))
+    }
+
+    /// Get the CUSIP, such as `037833100`.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Get the issuer code, such as `037833`.
+    pub fn issuer(&self) -> &str {
+        &self.0[..6]
+    }
+
+    /// Get the issue number, such as `10`.
+    pub fn issue(&self) -> &str {
+        &self.0[6..8]
+    }
+
+    /// Get the ISIN of the security, which is issued in the given country, usually the United States or Canada.
+    pub fn to_isin(&self, country: Country) -> ValobsResult<Isin> {
+        Isin::from_nsin(country, &self.0)
+    }
+}
+
+/// A value object representing a Stock Exchange Daily Official List (SEDOL) number.
+///
+/// ## What is a _SEDOL_?
+///
+/// A SEDOL identifies a security listed in the United Kingdom or Ireland. It has 7 characters, such as `0263494`:
+/// 6 digits or consonants, and a check digit.
+///
+/// ## Example
+///
+/// ```
+/// use valobs::financial::Sedol;
+/// use valobs::geography::Country;
+/// use valobs::result::ValobsResult;
+///
+/// fn main() -> ValobsResult<()> {
+///   let sedol: Sedol = "0263494".parse()?;
+///
+///   assert_eq!(sedol.to_isin(Country::GBR)?.as_str(), "GB0002634946");
+///
+///   Ok(())
+/// }
+/// ```
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct Sedol(String);
+
+impl Sedol {
+    /// Create a new `Sedol` instance from 7 characters.
+    pub fn new(value: &str) -> ValobsResult<Sedol> {
+        let sedol = normalize("sedol", value, 7)?;
+        let valid = sedol.char_indices().all(|(index, char)| match index {
+            6 => char.is_ascii_digit(),
+            _ => char.is_ascii_digit() || (char.is_ascii_uppercase() && !"AEIOU".contains(char)),
+        });
+        if !valid {
+            return Err(invalid_format("sedol", value));
+        }
+        let sum: u32 = sedol[..6]
+            .chars()
+            .zip([1, 3, 1, 7, 3, 9])
+            .map(|(char, weight)| char.to_digit(36).unwrap_or_default() * weight)
+            .sum();
+        if char::from_digit((10 - sum % 10) % 10, 10) != sedol.chars().last() {
+            return Err(ValobsError::InvalidChecksum {
+                field: "sedol",
+                value: value.into(),
+            });
+        }
+        Ok(Self(sedol))
+    }
+
+    /// Get the SEDOL, such as `0263494`.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Get the ISIN of the security, which is issued in the given country, usually the United Kingdom or Ireland.
+    pub fn to_isin(&self, country: Country) -> ValobsResult<Isin> {
+        Isin::from_nsin(country, &self.0)
+    }
+}
+
+/// A value object representing a Legal Entity Identifier (LEI), as defined by ISO 17442.
+///
+/// ## What is a _LEI_?
+///
+/// A LEI identifies a legal entity that takes part in financial transactions. It has 20 characters, such as `5493001KJTIIGC8Y1R12`:
+/// the 4 character prefix of the Local Operating Unit (LOU) that issued it, a 14 character entity code,
+/// and 2 check digits, computed with the ISO 7064 MOD 97-10 algorithm like the check digits of an [Iban](crate::financial::Iban).
+///
+/// ## Example
+///
+/// ```
+/// use valobs::financial::Lei;
+/// use valobs::result::ValobsResult;
+///
+/// fn main() -> ValobsResult<()> {
+///   let lei: Lei = "5493001KJTIIGC8Y1R12".parse()?;
+///
+///   assert_eq!(lei.lou_prefix(), "5493");
+///   assert_eq!(lei.entity_code(), "001KJTIIGC8Y1R");
+///   assert_eq!(lei.check_digits(), "12");
+///
+///   Ok(())
+/// }
+/// ```
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct Lei(String);
+
+impl Lei {
+    /// Create a new `Lei` instance from 20 characters.
+    pub fn new(value: &str) -> ValobsResult<Lei> {
+        let lei = normalize("lei", value, 20)?;
+        let valid = lei.char_indices().all(|(index, char)| match index {
+            18 | 19 => char.is_ascii_digit(),
+            _ => char.is_ascii_digit() || char.is_ascii_uppercase(),
+        });
+        if !valid {
+            return Err(invalid_format("lei", value));
+        }
+        if mod97(&lei) != 1 {
+            return Err(ValobsError::InvalidChecksum {
+                field: "lei",
+                value: value.into(),
+            });
+        }
+        Ok(Self(lei))
+    }
+
+    /// Get the LEI, such as `5493001KJTIIGC8Y1R12`.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Get the prefix of the Local Operating Unit that issued the LEI, such as `5493`.
+    pub fn lou_prefix(&self) -> &str {
+        &self.0[..4]
+    }
+
+    /// Get the code of the entity, such as `001KJTIIGC8Y1R`.
+    pub fn entity_code(&self) -> &str {
+        &self.0[4..18]
+    }
+
+    /// Get the two check digits, such as `12`.
+    pub fn check_digits(&self) -> &str {
+        &self.0[18..]
+    }
+}
+
+/// Implements the conversions and formatting shared by every securities identifier.
+macro_rules! impl_identifier {
+    ($($identifier:ident),*) => {
+        $(
+            impl ValueObject<'_> for $identifier {}
+
+            impl AsRef<str> for $identifier {
+                fn as_ref(&self) -> &str {
+                    self.as_str()
+                }
+            }
+
+            impl TryFrom<String> for $identifier {
+                type Error = ValobsError;
+
+                fn try_from(value: String) -> ValobsResult<Self> {
+                    Self::new(&value)
+                }
+            }
+
+            impl From<$identifier> for String {
+                fn from(identifier: $identifier) -> Self {
+                    identifier.0
+                }
+            }
+
+            impl fmt::Display for $identifier {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    write!(f, "{}", self.0)
+                }
+            }
+
+            impl FromStr for $identifier {
+                type Err = ValobsError;
+
+                fn from_str(value: &str) -> ValobsResult<Self> {
+                    Self::new(value)
+                }
+            }
+        )*
+    };
+}
+
+impl_identifier!(Isin, Cusip, Sedol, Lei);
+
+/// Trims and converts an identifier to upper case, and checks that it is ASCII and has the expected length.
+fn normalize(field: &'static str, value: &str, length: usize) -> ValobsResult<String> {
+    let identifier = value.trim().to_ascii_uppercase();
+    if identifier.is_empty() {
+        return Err(ValobsError::Empty { field });
+    }
+    if !identifier.is_ascii() {
+        return Err(invalid_format(field, value));
+    }
+    if identifier.len() != length {
+        return Err(ValobsError::InvalidLength {
+            field,
+            length: identifier.len(),
+            min: length,
+            max: length,
+        });
+    }
+    Ok(identifier)
+}
+
+fn invalid_format(field: &'static str, value: &str) -> ValobsError {
+    ValobsError::InvalidFormat {
+        field,
+        value: value.into(),
+    }
+}
+
+/// Returns `true` if the Luhn algorithm holds for the digits of an ISIN, where every letter is replaced by two digits.
+fn isin_checksum(isin: &str) -> bool {
+    let digits = isin
+        .chars()
+        .filter_map(|char| char.to_digit(36))
+        .map(|digit| digit.to_string())
+        .collect::<String>();
+    luhn(&digits)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn create_isin() -> ValobsResult<()> {
+        for (value, country) in [
+            ("US0378331005", Some(Country::USA)),
+            (" au0000xvgza3 ", Some(Country::AUS)),
+            ("IE00B4L5Y983", Some(Country::IRL)),
+            ("XS2021832634", None),
+        ] {
+            // Act
+            let result = Isin::new(value)?;
+
+            // Assert
+            assert_eq!(result.country(), country, "{value}");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn fails_to_create_invalid_isin() {
+        assert_eq!(
+            Isin::new("US0378331004"),
+            Err(ValobsError::InvalidChecksum {
+                field: "isin",
+                value: "US0378331004".into(),
+            })
+        );
+        assert_eq!(
+            Isin::new("US037833100"),
+            Err(ValobsError::InvalidLength {
+                field: "isin",
+                length: 11,
+                min: 12,
+                max: 12,
+            })
+        );
+        assert!(Isin::new("120378331005").is_err());
+        assert!(Isin::new("US037833100A").is_err());
+    }
+
+    #[test]
+    fn convert_between_isin_cusip_and_sedol() -> ValobsResult<()> {
+        // Arrange
+        let cusip = Cusip::new("067901108")?;
+        let sedol = Sedol::new("B4L5Y98")?;
+
+        // Act
+        let canadian = cusip.to_isin(Country::CAN)?;
+        let irish = sedol.to_isin(Country::IRL)?;
+
+        // Assert
+        assert_eq!(canadian.as_str(), "CA0679011084");
+        assert_eq!(canadian.to_cusip(), Some(cusip));
+        assert_eq!(canadian.to_sedol(), None);
+        assert_eq!(irish.as_str(), "IE00B4L5Y983");
+        assert_eq!(irish.to_sedol(), Some(sedol));
+        assert_eq!(irish.to_cusip(), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn create_cusip_and_sedol() -> ValobsResult<()> {
+        // Act
+        let cusip = Cusip::new("037833100")?;
+        let sedol = Sedol::new("0263494")?;
+
+        // Assert
+        assert_eq!(cusip.issuer(), "037833");
+        assert_eq!(cusip.issue(), "10");
+        assert_eq!(sedol.as_str(), "0263494");
+        assert!(Cusip::new("037833101").is_err());
+        assert!(Sedol::new("0263495").is_err());
+        assert_eq!(
+            Sedol::new("A263494"),
+            Err(ValobsError::InvalidFormat {
+                field: "sedol",
+                value: "A263494".into(),
+            })
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn create_lei() -> ValobsResult<()> {
+        // Act
+        let result = Lei::new("7ltwfzyicnsx8d621k86")?;
+
+        // Assert
+        assert_eq!(result.as_str(), "7LTWFZYICNSX8D621K86");
+        assert_eq!(result.lou_prefix(), "7LTW");
+        assert_eq!(
+            Lei::new("7LTWFZYICNSX8D621K87"),
+            Err(ValobsError::InvalidChecksum {
+                field: "lei",
+                value: "7LTWFZYICNSX8D621K87".into(),
+            })
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn serialize_securities_identifiers_to_json() -> ValobsResult<()> {
+        // Arrange
+        let isin = Isin::new("us0378331005")?;
+
+        // Act
+        let json = serde_json::to_string(&isin).unwrap();
+        let result = serde_json::from_str::<Isin>(&json).unwrap();
+
+        // Assert
+        assert_eq!(json, r#""US0378331005""#);
+        assert_eq!(result, isin);
+        assert!(serde_json::from_str::<Cusip>(r#""037833101""#).is_err());
+        assert!(serde_json::from_str::<Lei>(r#""5493001KJTIIGC8Y1R12""#).is_ok());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,618 @@
+use super::{money::Money, rounding::RoundingMode};
+use crate::{
+    result::{ValobsError, ValobsResult},
+    traits::ValueObject,
+};
+use rust_decimal::{prelude::ToPrimitive, Decimal};
+use serde::{Deserialize, Serialize};
+
+/// A value object representing the rate of a tax, such as a 20% VAT.
+///
+/// ## What is a _Tax Rate_?
+///
+/// A tax rate is the fraction of a net amount that is charged as tax.
+/// It is stored as an exact decimal fraction, so a rate of 20% is `0.2`.
+///
+/// Most taxes are charged on the net amount only. A _compound_ tax is charged on the net amount
+/// plus every tax listed before it, such as the former Quebec sales tax, which was charged on top of the federal GST.
+///
+/// ## Example
+///
+/// ```
+/// use valobs::financial::{Decimal, tax::TaxRate};
+/// use valobs::result::ValobsResult;
+///
+/// fn main() -> ValobsResult<()> {
+///   let vat = TaxRate::from_percent(Decimal::from(20))?;
+///
+///   assert_eq!(vat.rate(), Decimal::new(2, 1));
+///   assert!(!vat.is_compound());
+///   assert!(TaxRate::from_percent(Decimal::from(-1)).is_err());
+///
+///   Ok(())
+/// }
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Serialize, Deserialize)]
+#[serde(try_from = "TaxRateFields")]
+pub struct TaxRate {
+    rate: Decimal,
+    compound: bool,
+}
+
+impl TaxRate {
+    /// Create a new `TaxRate` instance from a fraction, such as `0.2` for 20%.
+    ///
+    /// The rate must not be negative. A rate of zero is allowed for zero-rated goods.
+    pub fn new(rate: Decimal) -> ValobsResult<TaxRate> {
+        if rate.is_sign_negative() && !rate.is_zero() {
+            return Err(ValobsError::Negative {
+                field: "rate",
+                value: rate.to_f64().unwrap_or_default(),
+            });
+        }
+        Ok(Self {
+            rate: rate.normalize(),
+            compound: false,
+        })
+    }
+
+    /// Create a new `TaxRate` instance from a percentage, such as `20` for 20%.
+    pub fn from_percent(percent: Decimal) -> ValobsResult<TaxRate> {
+        let rate = percent
+            .checked_div(Decimal::ONE_HUNDRED)
+            .ok_or(ValobsError::Overflow { field: "rate" })?;
+        Self::new(rate)
+    }
+
+    /// Make the tax compound, so it is charged on the net amount plus every tax listed before it.
+    pub fn compounded(mut self) -> Self {
+        self.compound = true;
+        self
+    }
+
+    /// Get the rate as a fraction, such as `0.2` for 20%.
+    pub fn rate(&self) -> Decimal {
+        self.rate
+    }
+
+    /// Returns `true` if the tax is charged on the net amount plus every tax listed before it.
+    pub fn is_compound(&self) -> bool {
+        self.compound
+    }
+}
+
+impl ValueObject<'_> for TaxRate {}
+
+#[derive(Deserialize)]
+struct TaxRateFields {
+    rate: Decimal,
+    compound: bool,
+}
+
+impl TryFrom<TaxRateFields> for TaxRate {
+    type Error = ValobsError;
+
+    fn try_from(fields: TaxRateFields) -> ValobsResult<Self> {
+        let rate = Self::new(fields.rate)?;
+        Ok(if fields.compound {
+            rate.compounded()
+        } else {
+            rate
+        })
+    }
+}
+
+/// A price that either excludes or includes its taxes.
+///
+/// Business-to-business prices usually exclude taxes, which are added on top of them,
+/// while consumer prices in many countries include taxes, which are taken out of them.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+pub enum TaxablePrice {
+    /// A net price, to which the taxes are added.
+    Exclusive(Money),
+    /// A gross price, which already contains the taxes.
+    Inclusive(Money),
+}
+
+impl TaxablePrice {
+    /// Get the money of the price, whether it includes the taxes or not.
+    pub fn money(&self) -> Money {
+        match self {
+            Self::Exclusive(money) | Self::Inclusive(money) => *money,
+        }
+    }
+}
+
+impl ValueObject<'_> for TaxablePrice {}
+
+/// When the taxes of several lines, such as the lines of an invoice, are rounded.
+#[derive(Debug, Default, PartialEq, Eq, Hash, Clone, Copy, Serialize, Deserialize)]
+pub enum TaxRounding {
+    /// Round the taxes of every line, and add up the rounded taxes.
+    #[default]
+    PerLine,
+    /// Add up the lines, and round the taxes of the total once.
+    PerInvoice,
+}
+
+/// The result of a tax calculation, which splits a gross amount into its net amount and taxes.
+///
+/// It always reconciles exactly: the net amount plus every tax equals the gross amount, to the minor unit.
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(try_from = "TaxBreakdownFields")]
+pub struct TaxBreakdown {
+    net: Money,
+    taxes: Vec<Money>,
+    gross: Money,
+}
+
+impl TaxBreakdown {
+    /// Get the amount without taxes.
+    pub fn net(&self) -> Money {
+        self.net
+    }
+
+    /// Get the amount of each tax, in the order of the tax rates.
+    pub fn taxes(&self) -> &[Money] {
+        &self.taxes
+    }
+
+    /// Get the total of every tax.
+    pub fn tax(&self) -> ValobsResult<Money> {
+        Money::try_sum(self.taxes.iter().copied(), *self.net.currency())
+    }
+
+    /// Get the amount with taxes.
+    pub fn gross(&self) -> Money {
+        self.gross
+    }
+
+    /// Add up two breakdowns calculated with the same tax rates.
+    ///
+    /// Fails if the breakdowns don't have the same number of taxes.
+    pub fn merge(&self, other: &TaxBreakdown) -> ValobsResult<TaxBreakdown> {
+        if self.taxes.len() != other.taxes.len() {
+            return Err(ValobsError::OutOfRange {
+                field: "taxes",
+                value: other.taxes.len() as f64,
+                min: self.taxes.len() as f64,
+                max: self.taxes.len() as f64,
+            });
+        }
+        let taxes = self
+            .taxes
+            .iter()
+            .zip(&other.taxes)
+            .map(|(tax, other)| tax.add(other))
+            .collect::<ValobsResult<Vec<_>>>()?;
+        Ok(Self {
+            net: self.net.add(&other.net)?,
+            taxes,
+            gross: self.gross.add(&other.gross)?,
+        })
+    }
+}
+
+impl ValueObject<'_> for TaxBreakdown {}
+
+#[derive(Deserialize)]
+struct TaxBreakdownFields {
+    net: Money,
+    taxes: Vec<Money>,
+    gross: Money,
+}
+
+impl TryFrom<TaxBreakdownFields> for TaxBreakdown {
+    type Error = ValobsError;
+
+    fn try_from(fields: TaxBreakdownFields) -> ValobsResult<Self> {
+        let total = Money::try_sum(fields.taxes.iter().copied(), *fields.net.currency())?
+            .add(&fields.net)?;
+        if *fields.gross.currency() != *total.currency() {
+            return Err(ValobsError::CurrencyMismatch {
+                expected: *total.currency(),
+                found: *fields.gross.currency(),
+            });
+        }
+        if fields.gross != total {
+            return Err(ValobsError::Unreconciled {
+                field: "gross",
+                expected: total.amount(),
+                found: fields.gross.amount(),
+            });
+        }
+        Ok(Self {
+            net: fields.net,
+            taxes: fields.taxes,
+            gross: fields.gross,
+        })
+    }
+}
+
+/// A calculator that splits prices into their net amounts and taxes.
+///
+/// ## When to use
+///
+/// Configure one calculator for each combination of taxes, such as the VAT of a country,
+/// and use it to calculate the taxes of single prices or of whole invoices.
+///
+/// ## Example
+///
+/// ```
+/// use valobs::financial::{Currency, Decimal, Money, tax::{TaxablePrice, TaxCalculator, TaxRate}};
+/// use valobs::result::ValobsResult;
+///
+/// fn main() -> ValobsResult<()> {
+///   let calculator = TaxCalculator::new(vec![TaxRate::from_percent(Decimal::from(20))?]);
+///
+///   // 10.00 EUR plus 20% VAT
+///   let result = calculator.breakdown(&TaxablePrice::Exclusive(Money::new(1000, Currency::EUR)))?;
+///
+///   assert_eq!(result.tax()?, Money::new(200, Currency::EUR));
+///   assert_eq!(result.gross(), Money::new(1200, Currency::EUR));
+///
+///   // 12.00 EUR including 20% VAT
+///   let result = calculator.breakdown(&TaxablePrice::Inclusive(Money::new(1200, Currency::EUR)))?;
+///
+///   assert_eq!(result.net(), Money::new(1000, Currency::EUR));
+///
+///   Ok(())
+/// }
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct TaxCalculator {
+    rates: Vec<TaxRate>,
+    rounding: RoundingMode,
+    strategy: TaxRounding,
+}
+
+impl TaxCalculator {
+    /// Create a new `TaxCalculator` instance, which rounds half to even for every line.
+    pub fn new(rates: Vec<TaxRate>) -> Self {
+        Self {
+            rates,
+            rounding: RoundingMode::default(),
+            strategy: TaxRounding::default(),
+        }
+    }
+
+    /// Set the rounding mode of the taxes.
+    pub fn with_rounding(mut self, rounding: RoundingMode) -> Self {
+        self.rounding = rounding;
+        self
+    }
+
+    /// Set when the taxes of several lines are rounded.
+    pub fn with_strategy(mut self, strategy: TaxRounding) -> Self {
+        self.strategy = strategy;
+        self
+    }
+
+    /// Get the tax rates, in the order they are applied.
+    pub fn rates(&self) -> &[TaxRate] {
+        &self.rates
+    }
+
+    /// Calculate the taxes of a single price.
+    pub fn breakdown(&self, price: &TaxablePrice) -> ValobsResult<TaxBreakdown> {
+        match price {
+            TaxablePrice::Exclusive(net) => self.exclusive(net),
+            TaxablePrice::Inclusive(gross) => self.inclusive(gross),
+        }
+    }
+
+    /// Calculate the taxes of several lines in the same currency, such as the lines of an invoice.
+    ///
+    /// With [TaxRounding::PerInvoice], the lines that exclude taxes and the lines that include taxes
+    /// are added up separately, and the taxes of each total are rounded once.
+    pub fn breakdown_lines(&self, lines: &[TaxablePrice]) -> ValobsResult<TaxBreakdown> {
+        let first = lines.first().ok_or(ValobsError::Empty { field: "lines" })?;
+        let currency = *first.money().currency();
+        let breakdowns = match self.strategy {
+            TaxRounding::PerLine => lines
+                .iter()
+                .map(|line| self.breakdown(line))
+                .collect::<ValobsResult<Vec<_>>>()?,
+            TaxRounding::PerInvoice => {
+                let exclusive = Money::try_sum(
+                    lines.iter().filter_map(|line| match line {
+                        TaxablePrice::Exclusive(net) => Some(*net),
+                        TaxablePrice::Inclusive(_) => None,
+                    }),
+                    currency,
+                )?;
+                let inclusive = Money::try_sum(
+                    lines.iter().filter_map(|line| match line {
+                        TaxablePrice::Exclusive(_) => None,
+                        TaxablePrice::Inclusive(gross) => Some(*gross),
+                    }),
+                    currency,
+                )?;
+                vec![self.exclusive(&exclusive)?, self.inclusive(&inclusive)?]
+            }
+        };
+        breakdowns
+            .iter()
+            .skip(1)
+            .try_fold(breakdowns[0].clone(), |total, breakdown| {
+                total.merge(breakdown)
+            })
+    }
+
+    fn exclusive(&self, net: &Money) -> ValobsResult<TaxBreakdown> {
+        let mut taxes: Vec<Money> = Vec::with_capacity(self.rates.len());
+        for rate in &self.rates {
+            let taxable = if rate.compound {
+                Money::try_sum(taxes.iter().copied(), *net.currency())?.add(net)?
+            } else {
+                *net
+            };
+            taxes.push(taxable.multiply(rate.rate, self.rounding)?);
+        }
+        let gross = Money::try_sum(taxes.iter().copied(), *net.currency())?.add(net)?;
+        Ok(TaxBreakdown {
+            net: *net,
+            taxes,
+            gross,
+        })
+    }
+
+    fn inclusive(&self, gross: &Money) -> ValobsResult<TaxBreakdown> {
+        // The gross amount is the net amount times this factor, such as 1.2 for a 20% tax.
+        let mut factor = Decimal::ONE;
+        for rate in &self.rates {
+            let taxable = if rate.compound { factor } else { Decimal::ONE };
+            factor = taxable
+                .checked_mul(rate.rate)
+                .and_then(|tax| factor.checked_add(tax))
+                .ok_or(ValobsError::Overflow { field: "rate" })?;
+        }
+        let net = gross.divide(factor, self.rounding)?;
+
+        // Every tax but the last is calculated from the net amount,
+        // and the last one takes what is left, so the breakdown always adds up to the gross amount.
+        let mut breakdown = self.exclusive(&net)?;
+        if breakdown.taxes.pop().is_some() {
+            let others = Money::try_sum(breakdown.taxes.iter().copied(), *net.currency())?;
+            breakdown
+                .taxes
+                .push(gross.subtract(&net)?.subtract(&others)?);
+        }
+        breakdown.gross = *gross;
+        Ok(breakdown)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::financial::Currency;
+
+    fn percent(percent: i64) -> TaxRate {
+        TaxRate::from_percent(Decimal::from(percent)).unwrap()
+    }
+
+    fn reconciles(breakdown: &TaxBreakdown) -> bool {
+        breakdown.tax().unwrap().add(&breakdown.net()).unwrap() == breakdown.gross()
+    }
+
+    #[test]
+    fn create_tax_rate() -> ValobsResult<()> {
+        // Act
+        let result = TaxRate::new(Decimal::new(75, 3))?.compounded();
+
+        // Assert
+        assert_eq!(result.rate(), Decimal::new(75, 3));
+        assert!(result.is_compound());
+        assert!(TaxRate::new(Decimal::ZERO).is_ok());
+        assert_eq!(
+            TaxRate::new(Decimal::NEGATIVE_ONE),
+            Err(ValobsError::Negative {
+                field: "rate",
+                value: -1.0,
+            })
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn calculate_tax_exclusive_price() -> ValobsResult<()> {
+        // Arrange
+        let calculator = TaxCalculator::new(vec![percent(19)]);
+
+        // Act
+        let result =
+            calculator.breakdown(&TaxablePrice::Exclusive(Money::new(999, Currency::EUR)))?;
+
+        // Assert
+        assert_eq!(result.net(), Money::new(999, Currency::EUR));
+        assert_eq!(result.taxes(), &[Money::new(190, Currency::EUR)]);
+        assert_eq!(result.gross(), Money::new(1189, Currency::EUR));
+
+        Ok(())
+    }
+
+    #[test]
+    fn calculate_tax_inclusive_price() -> ValobsResult<()> {
+        // Arrange
+        let calculator = TaxCalculator::new(vec![percent(19)]);
+
+        // Act
+        let result =
+            calculator.breakdown(&TaxablePrice::Inclusive(Money::new(1000, Currency::EUR)))?;
+
+        // Assert
+        assert_eq!(result.net(), Money::new(840, Currency::EUR));
+        assert_eq!(result.tax()?, Money::new(160, Currency::EUR));
+        assert_eq!(result.gross(), Money::new(1000, Currency::EUR));
+
+        Ok(())
+    }
+
+    #[test]
+    fn calculate_compound_taxes() -> ValobsResult<()> {
+        // Arrange
+        let gst = percent(5);
+        let qst = TaxRate::new(Decimal::new(95, 3))?.compounded();
+        let calculator = TaxCalculator::new(vec![gst, qst]);
+
+        // Act
+        let exclusive =
+            calculator.breakdown(&TaxablePrice::Exclusive(Money::new(10000, Currency::CAD)))?;
+        let inclusive =
+            calculator.breakdown(&TaxablePrice::Inclusive(Money::new(11498, Currency::CAD)))?;
+
+        // Assert
+        assert_eq!(
+            exclusive.taxes(),
+            &[
+                Money::new(500, Currency::CAD),
+                Money::new(998, Currency::CAD)
+            ]
+        );
+        assert_eq!(exclusive.gross(), Money::new(11498, Currency::CAD));
+        assert_eq!(inclusive, exclusive);
+
+        Ok(())
+    }
+
+    #[test]
+    fn round_taxes_per_line_or_per_invoice() -> ValobsResult<()> {
+        // Arrange
+        let lines = [TaxablePrice::Exclusive(Money::new(5, Currency::USD)); 3];
+        let calculator = TaxCalculator::new(vec![percent(10)]).with_rounding(RoundingMode::HalfUp);
+
+        // Act
+        let per_line = calculator.breakdown_lines(&lines)?;
+        let per_invoice = calculator
+            .with_strategy(TaxRounding::PerInvoice)
+            .breakdown_lines(&lines)?;
+
+        // Assert
+        assert_eq!(per_line.tax()?, Money::new(3, Currency::USD));
+        assert_eq!(per_invoice.tax()?, Money::new(2, Currency::USD));
+        assert!(reconciles(&per_line));
+        assert!(reconciles(&per_invoice));
+
+        Ok(())
+    }
+
+    #[test]
+    fn tax_breakdown_always_reconciles() -> ValobsResult<()> {
+        // Arrange
+        let calculator = TaxCalculator::new(vec![percent(7), percent(3), percent(11).compounded()]);
+
+        for amount in [1, 3, 99, 1001, 123_457, -4_999] {
+            for price in [
+                TaxablePrice::Exclusive(Money::new(amount, Currency::USD)),
+                TaxablePrice::Inclusive(Money::new(amount, Currency::USD)),
+            ] {
+                // Act
+                let result = calculator.breakdown(&price)?;
+
+                // Assert
+                assert!(reconciles(&result), "{price:?}");
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn fails_to_calculate_taxes_of_mixed_currencies() {
+        // Arrange
+        let calculator = TaxCalculator::new(vec![percent(10)]);
+        let lines = [
+            TaxablePrice::Exclusive(Money::new(100, Currency::USD)),
+            TaxablePrice::Exclusive(Money::new(100, Currency::EUR)),
+        ];
+
+        // Act
+        let result = calculator.breakdown_lines(&lines);
+
+        // Assert
+        assert!(result.is_err());
+        assert_eq!(
+            calculator.breakdown_lines(&[]),
+            Err(ValobsError::Empty { field: "lines" })
+        );
+    }
+
+    #[test]
+    fn fails_to_merge_breakdowns_with_different_numbers_of_taxes() -> ValobsResult<()> {
+        // Arrange
+        let price = TaxablePrice::Exclusive(Money::new(1000, Currency::CAD));
+        let gst = TaxCalculator::new(vec![percent(5)]).breakdown(&price)?;
+        let gst_and_pst = TaxCalculator::new(vec![percent(5), percent(7)]).breakdown(&price)?;
+
+        // Act
+        let result = gst.merge(&gst_and_pst);
+
+        // Assert
+        assert_eq!(
+            result,
+            Err(ValobsError::OutOfRange {
+                field: "taxes",
+                value: 2.0,
+                min: 1.0,
+                max: 1.0,
+            })
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn serialize_tax_breakdown_to_json() -> ValobsResult<()> {
+        // Arrange
+        let breakdown = TaxCalculator::new(vec![percent(19)])
+            .breakdown(&TaxablePrice::Exclusive(Money::new(999, Currency::EUR)))?;
+
+        // Act
+        let json = serde_json::to_string(&breakdown).unwrap();
+        let result = serde_json::from_str::<TaxBreakdown>(&json).unwrap();
+
+        // Assert
+        assert_eq!(
+            json,
+            r#"{"net":{"amount":999,"currency":"EUR"},"taxes":[{"amount":190,"currency":"EUR"}],"gross":{"amount":1189,"currency":"EUR"}}"#
+        );
+        assert_eq!(result, breakdown);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fails_to_deserialize_unreconciled_tax_breakdown() {
+        // Act
+        let unreconciled = serde_json::from_str::<TaxBreakdown>(
+            r#"{"net":{"amount":999,"currency":"EUR"},"taxes":[{"amount":190,"currency":"EUR"}],"gross":{"amount":1000,"currency":"EUR"}}"#,
+        );
+        let mixed = serde_json::from_str::<TaxBreakdown>(
+            r#"{"net":{"amount":999,"currency":"EUR"},"taxes":[{"amount":190,"currency":"USD"}],"gross":{"amount":1189,"currency":"EUR"}}"#,
+        );
+
+        // Assert
+        assert_eq!(
+            unreconciled.unwrap_err().to_string(),
+            "gross must equal the sum of its parts, 1189, got 1000"
+        );
+        assert!(mixed.is_err());
+    }
+
+    #[test]
+    fn serialize_tax_rate_to_json() -> ValobsResult<()> {
+        // Arrange
+        let rate = percent(20);
+
+        // Act
+        let result = serde_json::to_string(&rate).unwrap();
+
+        // Assert
+        assert_eq!(result, r#"{"rate":"0.2","compound":false}"#);
+        assert!(serde_json::from_str::<TaxRate>(r#"{"rate":"-0.2","compound":false}"#).is_err());
+
+        Ok(())
+    }
+}
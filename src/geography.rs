@@ -5,8 +5,52 @@ mod country;
 mod geo_location;
 mod latitude;
 mod longitude;
+mod postal_code;
+mod region;
+mod subdivision;
+
+/// Serde representations of countries
+///
+/// A [Country] serializes as its ISO 3166-1 alpha-3 code, such as `"USA"`, but many systems identify countries
+/// differently. Payment providers, locales and domain names use the alpha-2 code, such as `"US"`, while card networks
+/// and statistical offices use the numeric code, such as `840`, which stays the same when a country is renamed.
+/// Put one of these modules on a field with `#[serde(with = ...)]` to exchange countries with such a system.
+///
+/// Each module reads only its own code: an alpha-2 code is accepted in any case, but a field that expects one
+/// rejects alpha-3 codes and names.
+///
+/// ## Example
+///
+/// ```
+/// use serde::{Deserialize, Serialize};
+/// use valobs::geography::{Country, country_serde};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Customer {
+///   #[serde(with = "country_serde::alpha2")]
+///   country: Country,
+/// }
+///
+/// let customer: Customer = serde_json::from_str(r#"{"country":"DE"}"#).unwrap();
+///
+/// assert_eq!(customer.country, Country::DEU);
+/// ```
+///
+/// ## Representations
+///
+/// - [alpha2](crate::geography::country_serde::alpha2): `"US"`
+/// - [numeric](crate::geography::country_serde::numeric): `840`
+pub mod country_serde;
 
 pub use {
-    address::Address, altitude::Altitude, continent::Continent, country::Country,
-    geo_location::GeoLocation, latitude::Latitude, longitude::Longitude,
+    address::{Address, AddressBuilder},
+    altitude::Altitude,
+    continent::Continent,
+    country::Country,
+    geo_location::GeoLocation,
+    latitude::Latitude,
+    longitude::Longitude,
+    postal_code::PostalCode,
+    region::{Region, SubRegion},
+    subdivision::Subdivision,
 };
@@ -1,5 +1,9 @@
 use {
-    super::Country,
+    super::{Country, PostalCode, Subdivision},
+    crate::{
+        result::{ValidationReport, ValidationResult, ValobsError},
+        traits::ValueObject,
+    },
     serde::{Deserialize, Serialize},
 };
 
@@ -12,148 +16,572 @@ use {
 /// In some cases, it may also include additional information such as the apartment number or the
 /// name of the recipient.
 ///
-/// ## Limitations
+/// ## Validation
 ///
-/// Though this implementation is a good starting point, and useful for general applications, it has some limitations. For example, it does not support international addresses, which may have different formats and requirements.
+/// An address is created with an [AddressBuilder], which checks the fields against the rules of its country,
+/// and reports all of the invalid fields at once:
 ///
-/// It also does not separate too much details about the address, such as the name of the recipient,
-/// the apartment number, or the type of address (e.g., residential, business, etc.). But that can
-/// be easily added by extending the `Address` struct.
+/// - `street` and `city` are required everywhere.
+/// - `state` is required in countries such as the United States, Canada or Japan (where it is the prefecture),
+///   and not allowed in countries that don't use it in addresses, such as Germany or France.
+///   It is parsed as a [Subdivision] of the country, from its code or name.
+/// - `postal_code` is required, except in countries without postal codes, such as Hong Kong, where it is not allowed.
+///   It is parsed as a [PostalCode] of the country, which checks and normalizes its format.
+///
+/// Every text field is trimmed, and its runs of whitespace are collapsed into a single space.
+/// Optional fields that are blank are left out.
 ///
 /// ## Examples
 ///
 /// ```
-/// use valobs::geography::{Address, Country};
+/// use valobs::geography::{Address, Country, Subdivision};
+/// use valobs::result::ValidationResult;
+///
+/// fn main() -> ValidationResult<()> {
+///   let address = Address::builder(Country::USA)
+///     .with_organization("Acme Inc.")
+///     .with_street("123  Main St ")
+///     .with_unit("Suite 400")
+///     .with_city("Anytown")
+///     .with_state("ny")
+///     .with_postal_code("12345")
+///     .build()?;
+///
+///   assert_eq!(address.street(), "123 Main St");
+///   assert_eq!(address.unit(), Some("Suite 400"));
+///   assert_eq!(address.state(), Some(Subdivision::new("US-NY")?));
+///
+///   let report = Address::builder(Country::DEU)
+///     .with_street("Unter den Linden 1")
+///     .with_city("Berlin")
+///     .with_state("BE")
+///     .build()
+///     .unwrap_err();
 ///
-/// let address = Address {
-///    street: "123 Main St",
-///    city: "Anytown",
-///    state: "NY",
-///    postal_code: "12345",
-///    country: Country::USA,
-/// };
+///   assert_eq!(report.fields().collect::<Vec<_>>(), vec!["state", "postal_code"]);
 ///
-/// assert_eq!(address.street, "123 Main St");
-/// assert_eq!(address.city, "Anytown");
-/// assert_eq!(address.state, "NY");
-/// assert_eq!(address.postal_code, "12345");
-/// assert_eq!(address.country, Country::USA);
+///   Ok(())
+/// }
 /// ```
+#[derive(Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Clone)]
+#[serde(try_from = "AddressFields")]
+pub struct Address {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    recipient: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    organization: Option<String>,
+    street: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    unit: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    building: Option<String>,
+    city: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    state: Option<Subdivision>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_postal_code"
+    )]
+    postal_code: Option<PostalCode>,
+    country: Country,
+}
+
+impl Address {
+    /// Start building an address in the given country.
+    pub fn builder(country: Country) -> AddressBuilder {
+        AddressBuilder::new(country)
+    }
+
+    /// Get the name of the person the mail is addressed to.
+    pub fn recipient(&self) -> Option<&str> {
+        self.recipient.as_deref()
+    }
+
+    /// Get the name of the company or organization the mail is addressed to.
+    pub fn organization(&self) -> Option<&str> {
+        self.organization.as_deref()
+    }
+
+    /// Get the street name and number.
+    pub fn street(&self) -> &str {
+        &self.street
+    }
+
+    /// Get the apartment, suite or floor, such as `Apt 4B`.
+    pub fn unit(&self) -> Option<&str> {
+        self.unit.as_deref()
+    }
+
+    /// Get the name of the building or complex.
+    pub fn building(&self) -> Option<&str> {
+        self.building.as_deref()
+    }
+
+    /// Get the city, town or village.
+    pub fn city(&self) -> &str {
+        &self.city
+    }
+
+    /// Get the state, province or prefecture.
+    pub fn state(&self) -> Option<Subdivision> {
+        self.state
+    }
+
+    /// Get the postal code.
+    pub fn postal_code(&self) -> Option<&PostalCode> {
+        self.postal_code.as_ref()
+    }
+
+    /// Get the country.
+    pub fn country(&self) -> Country {
+        self.country
+    }
+}
+
+impl ValueObject<'_> for Address {}
+
+/// A builder of [Address], which validates the address against the rules of its country.
 ///
-#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Clone)]
-pub struct Address<'a> {
-    pub street: &'a str,
-    pub city: &'a str,
-    pub state: &'a str,
-    pub postal_code: &'a str,
-    pub country: Country,
+/// See [Address] for the rules, and an example.
+#[derive(Debug, Clone)]
+pub struct AddressBuilder {
+    country: Country,
+    recipient: Option<String>,
+    organization: Option<String>,
+    street: Option<String>,
+    unit: Option<String>,
+    building: Option<String>,
+    city: Option<String>,
+    state: Option<String>,
+    postal_code: Option<String>,
+}
+
+impl AddressBuilder {
+    /// Start building an address in the given country.
+    pub fn new(country: Country) -> Self {
+        Self {
+            country,
+            recipient: None,
+            organization: None,
+            street: None,
+            unit: None,
+            building: None,
+            city: None,
+            state: None,
+            postal_code: None,
+        }
+    }
+
+    /// Set the name of the person the mail is addressed to.
+    pub fn with_recipient(mut self, recipient: &str) -> Self {
+        self.recipient = normalize(recipient);
+        self
+    }
+
+    /// Set the name of the company or organization the mail is addressed to.
+    pub fn with_organization(mut self, organization: &str) -> Self {
+        self.organization = normalize(organization);
+        self
+    }
+
+    /// Set the street name and number.
+    pub fn with_street(mut self, street: &str) -> Self {
+        self.street = normalize(street);
+        self
+    }
+
+    /// Set the apartment, suite or floor, such as `Apt 4B`.
+    pub fn with_unit(mut self, unit: &str) -> Self {
+        self.unit = normalize(unit);
+        self
+    }
+
+    /// Set the name of the building or complex.
+    pub fn with_building(mut self, building: &str) -> Self {
+        self.building = normalize(building);
+        self
+    }
+
+    /// Set the city, town or village.
+    pub fn with_city(mut self, city: &str) -> Self {
+        self.city = normalize(city);
+        self
+    }
+
+    /// Set the state, province or prefecture, from its ISO 3166-2 code, with or without the country prefix,
+    /// or from its name, as described in [Subdivision::find].
+    pub fn with_state(mut self, state: &str) -> Self {
+        self.state = normalize(state);
+        self
+    }
+
+    /// Set the postal code.
+    pub fn with_postal_code(mut self, postal_code: &str) -> Self {
+        self.postal_code = normalize(postal_code);
+        self
+    }
+
+    /// Checks every field against the rules of the country, and builds the address.
+    ///
+    /// Reports all of the invalid fields at once, in the order of the fields of the address.
+    pub fn build(self) -> ValidationResult<Address> {
+        let rules = AddressRules::of(self.country);
+        let mut report = ValidationReport::new();
+
+        let street = required(&mut report, "street", self.street);
+        let city = required(&mut report, "city", self.city);
+        let state = match (rules.state, self.state) {
+            (Requirement::Unused, Some(value)) => {
+                report.push(ValobsError::NotAllowed {
+                    field: "state",
+                    value,
+                });
+                None
+            }
+            (_, Some(value)) => {
+                report.check(Subdivision::find(self.country, &value).map_err(|_| {
+                    ValobsError::UnknownCode {
+                        field: "state",
+                        value,
+                    }
+                }))
+            }
+            (Requirement::Required, None) => {
+                report.push(ValobsError::Empty { field: "state" });
+                None
+            }
+            (_, None) => None,
+        };
+        let postal_code = match (rules.postal_code, self.postal_code) {
+            (Requirement::Unused, Some(value)) => {
+                report.push(ValobsError::NotAllowed {
+                    field: "postal_code",
+                    value,
+                });
+                None
+            }
+            (Requirement::Required, None) => {
+                report.push(ValobsError::Empty {
+                    field: "postal_code",
+                });
+                None
+            }
+            (_, Some(value)) => report.check(PostalCode::new(self.country, &value)),
+            (_, None) => None,
+        };
+
+        match (street, city) {
+            (Some(street), Some(city)) if report.is_empty() => Ok(Address {
+                recipient: self.recipient,
+                organization: self.organization,
+                street,
+                unit: self.unit,
+                building: self.building,
+                city,
+                state,
+                postal_code,
+                country: self.country,
+            }),
+            _ => Err(report),
+        }
+    }
+}
+
+/// Whether a field of an address is used in a country.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Requirement {
+    Required,
+    Optional,
+    Unused,
+}
+
+/// The fields of an address that depend on its country.
+struct AddressRules {
+    state: Requirement,
+    postal_code: Requirement,
+}
+
+impl AddressRules {
+    fn of(country: Country) -> Self {
+        use Country::*;
+
+        let state = match country {
+            AUS | BRA | CAN | CHN | ESP | IND | ITA | JPN | KOR | USA => Requirement::Required,
+            AUT | BEL | CHE | CZE | DEU | DNK | EST | FIN | FRA | HUN | ISL | LIE | LTU | LUX
+            | LVA | MCO | MLT | NLD | NOR | POL | PRT | SMR | SVK | SVN | SWE | VAT => {
+                Requirement::Unused
+            }
+            _ => Requirement::Optional,
+        };
+        let postal_code = match country {
+            _ if !PostalCode::is_used_in(country) => Requirement::Unused,
+            IRL | PAN => Requirement::Optional,
+            _ => Requirement::Required,
+        };
+
+        Self { state, postal_code }
+    }
+}
+
+#[derive(Deserialize)]
+struct AddressFields {
+    recipient: Option<String>,
+    organization: Option<String>,
+    street: Option<String>,
+    unit: Option<String>,
+    building: Option<String>,
+    city: Option<String>,
+    state: Option<String>,
+    postal_code: Option<String>,
+    country: Country,
+}
+
+impl TryFrom<AddressFields> for Address {
+    type Error = ValidationReport;
+
+    fn try_from(fields: AddressFields) -> ValidationResult<Self> {
+        let value = |field: Option<String>| field.unwrap_or_default();
+        Address::builder(fields.country)
+            .with_recipient(&value(fields.recipient))
+            .with_organization(&value(fields.organization))
+            .with_street(&value(fields.street))
+            .with_unit(&value(fields.unit))
+            .with_building(&value(fields.building))
+            .with_city(&value(fields.city))
+            .with_state(&value(fields.state))
+            .with_postal_code(&value(fields.postal_code))
+            .build()
+    }
+}
+
+/// Serializes the postal code as a bare string, since its country is already the one of the address.
+fn serialize_postal_code<S: serde::Serializer>(
+    postal_code: &Option<PostalCode>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match postal_code {
+        Some(postal_code) => serializer.serialize_str(postal_code.as_str()),
+        None => serializer.serialize_none(),
+    }
+}
+
+/// Trims the value and collapses its runs of whitespace, or returns `None` if it is blank.
+fn normalize(value: &str) -> Option<String> {
+    let value = value.split_whitespace().collect::<Vec<_>>().join(" ");
+    (!value.is_empty()).then_some(value)
+}
+
+fn required(
+    report: &mut ValidationReport,
+    field: &'static str,
+    value: Option<String>,
+) -> Option<String> {
+    if value.is_none() {
+        report.push(ValobsError::Empty { field });
+    }
+    value
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::result::ValobsResult;
+
+    fn address() -> AddressBuilder {
+        Address::builder(Country::USA)
+            .with_street("123 Main St")
+            .with_city("Anytown")
+            .with_state("NY")
+            .with_postal_code("12345")
+    }
 
     #[test]
-    fn create_address_with_valid_data() {
-        let address = Address {
-            street: "123 Main St",
-            city: "Anytown",
-            state: "NY",
-            postal_code: "12345",
-            country: Country::USA,
-        };
+    fn create_address_with_valid_data() -> ValobsResult<()> {
+        let address = address().build().unwrap();
 
-        assert_eq!(address.street, "123 Main St");
-        assert_eq!(address.city, "Anytown");
-        assert_eq!(address.state, "NY");
-        assert_eq!(address.postal_code, "12345");
-        assert_eq!(address.country, Country::USA);
+        assert_eq!(address.street(), "123 Main St");
+        assert_eq!(address.city(), "Anytown");
+        assert_eq!(address.state(), Some(Subdivision::new("US-NY")?));
+        assert_eq!(
+            address.postal_code(),
+            Some(&PostalCode::new(Country::USA, "12345")?)
+        );
+        assert_eq!(address.country(), Country::USA);
+        assert_eq!(address.recipient(), None);
+        Ok(())
     }
 
     #[test]
-    fn create_address_with_invalid_data() {
-        let address = Address {
-            street: "123 Main St",
-            city: "Anytown",
-            state: "NY",
-            postal_code: "12345",
-            country: Country::USA,
-        };
+    fn create_address_with_extra_lines() {
+        let address = address()
+            .with_recipient("  Jane   Doe ")
+            .with_organization("Acme Inc.")
+            .with_unit("Apt\t4B")
+            .with_building(" ")
+            .build()
+            .unwrap();
 
-        assert_ne!(address.street, "123 Main St ");
-        assert_ne!(address.city, "Anytown ");
-        assert_ne!(address.state, "NY ");
-        assert_ne!(address.postal_code, "12345 ");
-        assert_ne!(address.country, Country::CAN);
+        assert_eq!(address.recipient(), Some("Jane Doe"));
+        assert_eq!(address.organization(), Some("Acme Inc."));
+        assert_eq!(address.unit(), Some("Apt 4B"));
+        assert_eq!(address.building(), None);
+    }
+
+    #[test]
+    fn normalize_whitespace_of_fields() {
+        let address = address()
+            .with_street(" 123\n Main   St ")
+            .with_city("Any town ")
+            .with_state(" new  york ")
+            .build()
+            .unwrap();
+
+        assert_eq!(address.street(), "123 Main St");
+        assert_eq!(address.city(), "Any town");
+        assert_eq!(address.state().map(|state| state.code()), Some("US-NY"));
+
+        let address = Address::builder(Country::CAN)
+            .with_street("111 Wellington St")
+            .with_city("Ottawa")
+            .with_state("ON")
+            .with_postal_code("k1a0a9")
+            .build()
+            .unwrap();
+        assert_eq!(
+            address.postal_code().map(PostalCode::as_str),
+            Some("K1A 0A9")
+        );
+    }
+
+    #[test]
+    fn validate_address_reports_every_invalid_field() {
+        let report = Address::builder(Country::USA)
+            .with_street(" ")
+            .with_state("ZZ")
+            .build()
+            .unwrap_err();
+
+        assert_eq!(
+            report.errors(),
+            &[
+                ValobsError::Empty { field: "street" },
+                ValobsError::Empty { field: "city" },
+                ValobsError::UnknownCode {
+                    field: "state",
+                    value: "ZZ".into(),
+                },
+                ValobsError::Empty {
+                    field: "postal_code"
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn validate_address_with_country_rules() {
+        // A prefecture is required in Japan
+        let report = Address::builder(Country::JPN)
+            .with_street("1-1 Chiyoda")
+            .with_city("Chiyoda-ku")
+            .with_postal_code("100-0001")
+            .build()
+            .unwrap_err();
+        assert_eq!(report.errors(), &[ValobsError::Empty { field: "state" }]);
+
+        // A state is not used in France
+        let report = Address::builder(Country::FRA)
+            .with_street("1 Rue de Rivoli")
+            .with_city("Paris")
+            .with_state("IDF")
+            .with_postal_code("75001")
+            .build()
+            .unwrap_err();
+        assert_eq!(
+            report.errors(),
+            &[ValobsError::NotAllowed {
+                field: "state",
+                value: "IDF".into(),
+            }]
+        );
+
+        // A state of another country is not allowed
+        let report = address().with_state("ON").build().unwrap_err();
+        assert_eq!(report.fields().collect::<Vec<_>>(), vec!["state"]);
+
+        // The postal code must match the format of the country
+        let report = address().with_postal_code("1234").build().unwrap_err();
+        assert_eq!(
+            report.errors(),
+            &[ValobsError::InvalidFormat {
+                field: "postal_code",
+                value: "1234".into(),
+            }]
+        );
+
+        // Hong Kong has no postal codes
+        let address = Address::builder(Country::HKG)
+            .with_street("1 Queen's Road Central")
+            .with_city("Central")
+            .build();
+        assert!(address.is_ok());
+    }
+
+    #[test]
+    fn create_address_with_everyday_state_names() -> ValobsResult<()> {
+        for (country, state, postal_code, expected) in [
+            (Country::KOR, "Seoul", "04524", "KR-11"),
+            (Country::BRA, "Sao Paulo", "01310-100", "BR-SP"),
+            (Country::IND, "Maharashtra", "400001", "IN-MH"),
+            (Country::CHN, "Beijing", "100000", "CN-BJ"),
+            (Country::ESP, "Barcelona", "08001", "ES-B"),
+            (Country::ESP, "Girona", "17001", "ES-GI"),
+            (Country::ESP, "Lleida", "25001", "ES-L"),
+            (Country::GBR, "Cardiff", "CF10 1EP", "GB-CRF"),
+        ] {
+            let address = Address::builder(country)
+                .with_street("1 Main Street")
+                .with_city("Capital")
+                .with_state(state)
+                .with_postal_code(postal_code)
+                .build()
+                .unwrap();
+
+            assert_eq!(address.state(), Some(Subdivision::new(expected)?));
+        }
+        Ok(())
     }
 
     #[test]
     fn equality() {
-        let address1 = Address {
-            street: "123 Main St",
-            city: "Anytown",
-            state: "NY",
-            postal_code: "12345",
-            country: Country::USA,
-        };
-        let address2 = Address {
-            street: "123 Main St",
-            city: "Anytown",
-            state: "NY",
-            postal_code: "12345",
-            country: Country::USA,
-        };
+        let address1 = address().build().unwrap();
+        let address2 = address().build().unwrap();
 
         assert_eq!(address1, address2);
     }
 
     #[test]
     fn inequality() {
-        let address1 = Address {
-            street: "123 Main St",
-            city: "Anytown",
-            state: "NY",
-            postal_code: "12345",
-            country: Country::USA,
-        };
-        let address2 = Address {
-            street: "123 Main St",
-            city: "Anytown",
-            state: "NY",
-            postal_code: "12345",
-            country: Country::CAN,
-        };
+        let address1 = address().build().unwrap();
+        let address2 = address().with_postal_code("12346").build().unwrap();
 
         assert_ne!(address1, address2);
     }
 
     #[test]
     fn serialize_address_to_json() {
-        let address = Address {
-            street: "123 Main St",
-            city: "Anytown",
-            state: "NY",
-            postal_code: "12345",
-            country: Country::USA,
-        };
+        let address = address().with_unit("Apt 4B").build().unwrap();
 
         let result = serde_json::to_string(&address).unwrap();
 
         assert_eq!(
             result,
-            r#"{"street":"123 Main St","city":"Anytown","state":"NY","postal_code":"12345","country":"USA"}"#
+            r#"{"street":"123 Main St","unit":"Apt 4B","city":"Anytown","state":"US-NY","postal_code":"12345","country":"USA"}"#
         );
     }
 
     #[test]
     fn deserialize_address_from_json() {
-        let address = Address {
-            street: "123 Main St",
-            city: "Anytown",
-            state: "NY",
-            postal_code: "12345",
-            country: Country::USA,
-        };
+        let address = address().build().unwrap();
 
         let result = serde_json::from_str::<Address>(
             r#"{"street":"123 Main St","city":"Anytown","state":"NY","postal_code":"12345","country":"USA"}"#,
@@ -161,5 +589,23 @@ mod tests {
         .unwrap();
 
         assert_eq!(result, address);
+        assert_eq!(
+            serde_json::from_str::<Address>(&serde_json::to_string(&address).unwrap()).unwrap(),
+            address
+        );
+        assert!(serde_json::from_str::<Address>(
+            r#"{"street":"123 Main St","city":" ","postal_code":"12345","country":"USA"}"#,
+        )
+        .is_err());
+        // The postal code is validated against the country of the address
+        assert!(serde_json::from_str::<Address>(
+            r#"{"street":"123 Main St","city":"Anytown","state":"NY","postal_code":"1011 AB","country":"USA"}"#,
+        )
+        .is_err());
+        // The object form is only used by a standalone postal code
+        assert!(serde_json::from_str::<Address>(
+            r#"{"street":"123 Main St","city":"Anytown","state":"NY","postal_code":{"code":"12345","country":"USA"},"country":"USA"}"#,
+        )
+        .is_err());
     }
 }
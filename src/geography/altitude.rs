@@ -1,4 +1,4 @@
-use crate::result::ValobsResult;
+use crate::result::{ValobsError, ValobsResult};
 use serde::{Deserialize, Serialize};
 
 /// A value object representing an altitude.
@@ -42,14 +42,22 @@ pub struct Altitude(f64);
 impl Altitude {
     /// Creates a new `Altitude` instance.
     pub fn new(altitude: f64) -> ValobsResult<Self> {
-        if altitude < -1000.0 || altitude > 10000.0 {
-            return Err("Altitude must be between -1000 and 10000".into());
-        }
         if altitude.is_nan() {
-            return Err("Altitude must not be NaN".into());
+            return Err(ValobsError::NotANumber { field: "altitude" });
         }
         if altitude.is_infinite() {
-            return Err("Altitude must not be infinite".into());
+            return Err(ValobsError::Infinite {
+                field: "altitude",
+                value: altitude,
+            });
+        }
+        if !(-1000.0..=10000.0).contains(&altitude) {
+            return Err(ValobsError::OutOfRange {
+                field: "altitude",
+                value: altitude,
+                min: -1000.0,
+                max: 10000.0,
+            });
         }
 
         Ok(Self(altitude))
@@ -82,6 +90,30 @@ mod tests {
         assert!(Altitude::new(f64::NEG_INFINITY).is_err());
     }
 
+    #[test]
+    fn fails_with_structured_error_for_invalid_data() {
+        assert_eq!(
+            Altitude::new(10000.1),
+            Err(ValobsError::OutOfRange {
+                field: "altitude",
+                value: 10000.1,
+                min: -1000.0,
+                max: 10000.0,
+            })
+        );
+        assert_eq!(
+            Altitude::new(f64::NAN),
+            Err(ValobsError::NotANumber { field: "altitude" })
+        );
+        assert_eq!(
+            Altitude::new(f64::INFINITY),
+            Err(ValobsError::Infinite {
+                field: "altitude",
+                value: f64::INFINITY,
+            })
+        );
+    }
+
     #[test]
     fn equality_between_two_altitude_values() -> ValobsResult<()> {
         // Arrange
@@ -92,7 +124,7 @@ mod tests {
         let result = altitude1 == altitude2;
 
         // Assert
-        assert_eq!(result, true);
+        assert!(result);
 
         Ok(())
     }
@@ -121,7 +153,7 @@ mod tests {
         let result = altitude1 == altitude2;
 
         // Assert
-        assert_eq!(result, false);
+        assert!(!result);
 
         Ok(())
     }
@@ -1,4 +1,13 @@
+mod data;
+
+use self::data::{CountryData, COUNTRIES};
+use super::{Continent, Region, SubRegion, Subdivision};
+use crate::{
+    financial::Currency,
+    result::{ValobsError, ValobsResult},
+};
 use serde::{Deserialize, Serialize};
+use std::{fmt, str::FromStr};
 
 /// A value object representing a country.
 /// The format is ISO 3166-1 alpha-3, which is a three-letter country code.
@@ -17,7 +26,28 @@ use serde::{Deserialize, Serialize};
 /// Use this type when you want to represent a country.
 /// For example, when you want user to pick a country from a list from their profile.
 ///
-#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Clone, Copy)]
+/// A country is serialized as its alpha-3 code. To serialize it as its alpha-2 or numeric code instead,
+/// see [country_serde](crate::geography::country_serde).
+///
+/// ## Example
+///
+/// ```
+/// use valobs::geography::Country;
+/// use valobs::result::ValobsResult;
+///
+/// fn main() -> ValobsResult<()> {
+///   let country: Country = "de".parse()?;
+///
+///   assert_eq!(country, Country::DEU);
+///   assert_eq!(country.alpha2(), "DE");
+///   assert_eq!(country.numeric(), 276);
+///   assert_eq!(country.official_name(), "Federal Republic of Germany");
+///   assert_eq!("federal republic of germany".parse::<Country>()?, country);
+///
+///   Ok(())
+/// }
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Clone, Copy)]
 pub enum Country {
     /// Aruba
     ABW,
@@ -519,6 +549,217 @@ pub enum Country {
     ZWE,
 }
 
+impl Country {
+    /// Returns an iterator over every country, in the alphabetical order of their alpha-3 codes.
+    pub fn all() -> impl Iterator<Item = Country> {
+        COUNTRIES.iter().map(|data| data.country)
+    }
+
+    /// Returns the ISO 3166-1 alpha-2 code of the country, such as `US`.
+    pub fn alpha2(&self) -> &'static str {
+        self.data().alpha2
+    }
+
+    /// Returns the ISO 3166-1 alpha-3 code of the country, such as `USA`.
+    pub fn alpha3(&self) -> &'static str {
+        self.data().alpha3
+    }
+
+    /// Returns the ISO 3166-1 numeric code of the country, such as 840 for `USA`.
+    ///
+    /// Numeric codes have leading zeros when written, such as `004` for Afghanistan.
+    pub fn numeric(&self) -> u16 {
+        self.data().numeric
+    }
+
+    /// Returns the English short name of the country, as defined by ISO 3166-1, such as `Korea, Republic of`.
+    pub fn name(&self) -> &'static str {
+        self.data().name
+    }
+
+    /// Returns the English official name of the country, such as `Republic of Korea`.
+    ///
+    /// Countries without an official name in ISO 3166-1 return their short name, such as `Japan`.
+    pub fn official_name(&self) -> &'static str {
+        self.data().official_name.unwrap_or(self.name())
+    }
+
+    /// Returns the English name of the country in everyday use, such as `South Korea`.
+    ///
+    /// Countries whose short name is already in everyday use return their short name, such as `Japan`.
+    pub fn common_name(&self) -> &'static str {
+        self.data().common_name.unwrap_or(self.name())
+    }
+
+    /// Returns the continent of the country, such as [Continent::Europe] for `FRA`.
+    ///
+    /// Continents follow the UN M49 sub-regions, so Central America and the Caribbean are in North America,
+    /// and transcontinental countries are in the continent of their sub-region, such as `RUS` in Europe and `TUR` in Asia.
+    pub fn continent(&self) -> Continent {
+        self.data().continent
+    }
+
+    /// Returns the UN M49 region of the country, such as [Region::Europe] for `FRA`.
+    ///
+    /// Antarctica is not part of any region, so it returns `None`.
+    pub fn region(&self) -> Option<Region> {
+        self.sub_region().map(|sub_region| sub_region.region())
+    }
+
+    /// Returns the UN M49 sub-region of the country, such as [SubRegion::WesternEurope] for `FRA`.
+    ///
+    /// Antarctica is not part of any sub-region, so it returns `None`.
+    pub fn sub_region(&self) -> Option<SubRegion> {
+        self.data().sub_region
+    }
+
+    /// Returns the English name of the capital of the country, such as `Paris` for `FRA`.
+    ///
+    /// Territories without a permanent population or seat of government, such as Antarctica, return `None`.
+    pub fn capital(&self) -> Option<&'static str> {
+        self.data().capital
+    }
+
+    /// Returns the currencies that are legal tender in the country, the main one first,
+    /// such as `[PAB, USD]` for `PAN`.
+    ///
+    /// Antarctica has no currency, so it returns an empty slice.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use valobs::financial::Currency;
+    /// use valobs::geography::Country;
+    ///
+    /// assert_eq!(Country::FRA.currencies(), &[Currency::EUR]);
+    /// assert_eq!(Country::NAM.currencies(), &[Currency::NAD, Currency::ZAR]);
+    /// ```
+    pub fn currencies(&self) -> &'static [Currency] {
+        self.data().currencies
+    }
+
+    /// Returns the ITU-T international calling codes of the country, the one in use first, such as `[33]` for `FRA`.
+    ///
+    /// Calling codes can be shared, such as `1` for the countries of the North American Numbering Plan,
+    /// and a country can have several, such as `[39, 379]` for `VAT`, which uses the Italian code and has its own assigned.
+    /// Territories without their own numbering plan, such as Antarctica, return an empty slice.
+    pub fn calling_codes(&self) -> &'static [u16] {
+        self.data().calling_codes
+    }
+
+    /// Returns the country code top-level domain of the country, such as `.fr` for `FRA`.
+    ///
+    /// It is the lower-case alpha-2 code, except for `GBR` which uses `.uk`.
+    /// Countries whose domain is not delegated, such as `BES`, return `None`.
+    pub fn tld(&self) -> Option<&'static str> {
+        self.data().tld
+    }
+
+    /// Returns an iterator over the ISO 3166-2 subdivisions of the country, in the order of their codes.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use valobs::geography::Country;
+    ///
+    /// assert_eq!(Country::USA.subdivisions().count(), 57);
+    /// assert_eq!(Country::USA.subdivisions().next().unwrap().code(), "US-AK");
+    /// ```
+    pub fn subdivisions(&self) -> impl Iterator<Item = Subdivision> {
+        Subdivision::of(*self)
+    }
+
+    /// Find a country by its ISO 3166-1 alpha-2 code, such as `US`, which must be in upper case.
+    pub(crate) fn from_alpha2(code: &str) -> Option<Country> {
+        COUNTRIES
+            .iter()
+            .find(|data| data.alpha2 == code)
+            .map(|data| data.country)
+    }
+
+    fn data(&self) -> &'static CountryData {
+        &COUNTRIES[*self as usize]
+    }
+}
+
+impl fmt::Display for Country {
+    /// Formats the country as its ISO 3166-1 alpha-3 code, such as `USA`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.alpha3())
+    }
+}
+
+impl FromStr for Country {
+    type Err = ValobsError;
+
+    /// Parses a country from its ISO 3166-1 alpha-2 code, such as `US`, its alpha-3 code, such as `USA`,
+    /// its three-digit numeric code, such as `840`, or its English short, official or common name,
+    /// such as `United States`. Codes and names are case-insensitive, and surrounding whitespace is removed.
+    fn from_str(value: &str) -> ValobsResult<Self> {
+        let country = value.trim();
+        if country.is_empty() {
+            return Err(ValobsError::Empty { field: "country" });
+        }
+        if country.bytes().all(|byte| byte.is_ascii_digit()) {
+            if country.len() != 3 {
+                return Err(ValobsError::InvalidFormat {
+                    field: "country",
+                    value: value.into(),
+                });
+            }
+            let numeric = country.parse::<u16>().expect("three digits fit in u16");
+            return Self::try_from(numeric);
+        }
+
+        let by_code = |data: &&CountryData| match country.len() {
+            2 => data.alpha2.eq_ignore_ascii_case(country),
+            3 => data.alpha3.eq_ignore_ascii_case(country),
+            _ => false,
+        };
+        let name = country.to_lowercase();
+        let by_name = |data: &&CountryData| {
+            [Some(data.name), data.official_name, data.common_name]
+                .into_iter()
+                .flatten()
+                .any(|candidate| candidate.to_lowercase() == name)
+        };
+        COUNTRIES
+            .iter()
+            .find(by_code)
+            .or_else(|| COUNTRIES.iter().find(by_name))
+            .map(|data| data.country)
+            .ok_or_else(|| ValobsError::UnknownCode {
+                field: "country",
+                value: value.into(),
+            })
+    }
+}
+
+impl TryFrom<&str> for Country {
+    type Error = ValobsError;
+
+    /// Parses a country as described in [Country::from_str].
+    fn try_from(value: &str) -> ValobsResult<Self> {
+        value.parse()
+    }
+}
+
+impl TryFrom<u16> for Country {
+    type Error = ValobsError;
+
+    /// Finds the country with the given ISO 3166-1 numeric code, such as 840 for `USA`.
+    fn try_from(numeric: u16) -> ValobsResult<Self> {
+        COUNTRIES
+            .iter()
+            .find(|data| data.numeric == numeric)
+            .map(|data| data.country)
+            .ok_or_else(|| ValobsError::UnknownCode {
+                field: "country",
+                value: format!("{numeric:03}"),
+            })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -554,6 +795,129 @@ mod tests {
         assert!(!result);
     }
 
+    #[test]
+    fn country_data_follows_variant_order() {
+        for (index, data) in COUNTRIES.iter().enumerate() {
+            assert_eq!(data.country as usize, index);
+            assert_eq!(format!("{:?}", data.country), data.alpha3);
+        }
+        assert_eq!(Country::from_alpha2("US"), Some(Country::USA));
+        assert_eq!(Country::from_alpha2("us"), None);
+    }
+
+    #[test]
+    fn codes_and_names_of_country() {
+        assert_eq!(Country::USA.alpha2(), "US");
+        assert_eq!(Country::USA.alpha3(), "USA");
+        assert_eq!(Country::AFG.numeric(), 4);
+        assert_eq!(Country::KOR.name(), "Korea, Republic of");
+        assert_eq!(Country::KOR.official_name(), "Republic of Korea");
+        assert_eq!(Country::KOR.common_name(), "South Korea");
+        assert_eq!(Country::JPN.official_name(), "Japan");
+        assert_eq!(Country::JPN.common_name(), "Japan");
+        assert_eq!(Country::all().count(), 249);
+    }
+
+    #[test]
+    fn reference_data_of_country() {
+        assert_eq!(Country::FRA.continent(), Continent::Europe);
+        assert_eq!(Country::BRA.continent(), Continent::SouthAmerica);
+        assert_eq!(Country::MEX.continent(), Continent::NorthAmerica);
+        assert_eq!(Country::ATA.continent(), Continent::Antarctica);
+        assert_eq!(Country::MEX.region(), Some(Region::Americas));
+        assert_eq!(
+            Country::MEX.sub_region(),
+            Some(SubRegion::LatinAmericaAndTheCaribbean)
+        );
+        assert_eq!(Country::ATA.region(), None);
+        assert_eq!(Country::JPN.capital(), Some("Tokyo"));
+        assert_eq!(Country::ATA.capital(), None);
+        assert_eq!(Country::PAN.currencies(), &[Currency::PAB, Currency::USD]);
+        assert!(Country::ATA.currencies().is_empty());
+        assert_eq!(Country::DEU.calling_codes(), &[49]);
+        assert_eq!(Country::JAM.calling_codes(), &[1]);
+        assert_eq!(Country::VAT.calling_codes(), &[39, 379]);
+        assert!(Country::ATA.calling_codes().is_empty());
+        assert_eq!(Country::DEU.tld(), Some(".de"));
+        assert_eq!(Country::GBR.tld(), Some(".uk"));
+        assert_eq!(Country::BES.tld(), None);
+    }
+
+    #[test]
+    fn every_country_has_current_currencies() {
+        for country in Country::all() {
+            assert!(
+                country
+                    .currencies()
+                    .iter()
+                    .all(|currency| !currency.is_historic()),
+                "{country}"
+            );
+        }
+    }
+
+    #[test]
+    fn parse_country_from_codes_and_names() -> ValobsResult<()> {
+        assert_eq!("US".parse::<Country>()?, Country::USA);
+        assert_eq!("us".parse::<Country>()?, Country::USA);
+        assert_eq!("usa".parse::<Country>()?, Country::USA);
+        assert_eq!("840".parse::<Country>()?, Country::USA);
+        assert_eq!("004".parse::<Country>()?, Country::AFG);
+        assert_eq!("United States".parse::<Country>()?, Country::USA);
+        assert_eq!("united states of america".parse::<Country>()?, Country::USA);
+        assert_eq!("SOUTH KOREA".parse::<Country>()?, Country::KOR);
+        assert_eq!("åland islands".parse::<Country>()?, Country::ALA);
+        assert_eq!(" US\n".parse::<Country>()?, Country::USA);
+        assert_eq!(" 840 ".parse::<Country>()?, Country::USA);
+        assert_eq!("Turkey".parse::<Country>()?, Country::TUR);
+        assert_eq!("Türkiye".parse::<Country>()?, Country::TUR);
+        assert_eq!("Czech Republic".parse::<Country>()?, Country::CZE);
+        assert_eq!(Country::try_from("Russia")?, Country::RUS);
+        assert_eq!(Country::try_from(276)?, Country::DEU);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fails_to_parse_unknown_country() {
+        assert_eq!(
+            "XK".parse::<Country>(),
+            Err(ValobsError::UnknownCode {
+                field: "country",
+                value: "XK".into(),
+            })
+        );
+        assert_eq!(
+            "Atlantis".parse::<Country>(),
+            Err(ValobsError::UnknownCode {
+                field: "country",
+                value: "Atlantis".into(),
+            })
+        );
+        assert_eq!(
+            Country::try_from(999),
+            Err(ValobsError::UnknownCode {
+                field: "country",
+                value: "999".into(),
+            })
+        );
+        assert_eq!(
+            "84".parse::<Country>(),
+            Err(ValobsError::InvalidFormat {
+                field: "country",
+                value: "84".into(),
+            })
+        );
+        assert_eq!(
+            "".parse::<Country>(),
+            Err(ValobsError::Empty { field: "country" })
+        );
+        assert_eq!(
+            "  ".parse::<Country>(),
+            Err(ValobsError::Empty { field: "country" })
+        );
+    }
+
     #[test]
     fn serialize_country_to_json() {
         // Arrange
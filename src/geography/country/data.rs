@@ -0,0 +1,3763 @@
+//! ISO 3166-1, UN M49 and ITU-T reference data of every [Country].
+//!
+//! The entries follow the order of the variants of [Country], so the data of a country is found by its discriminant.
+
+use super::Country;
+use crate::{
+    financial::Currency,
+    geography::{Continent, SubRegion},
+};
+
+pub(super) struct CountryData {
+    pub country: Country,
+    pub alpha2: &'static str,
+    pub alpha3: &'static str,
+    pub numeric: u16,
+    pub name: &'static str,
+    pub official_name: Option<&'static str>,
+    pub common_name: Option<&'static str>,
+    pub continent: Continent,
+    pub sub_region: Option<SubRegion>,
+    pub capital: Option<&'static str>,
+    pub currencies: &'static [Currency],
+    pub calling_codes: &'static [u16],
+    pub tld: Option<&'static str>,
+}
+
+pub(super) const COUNTRIES: &[CountryData] = &[
+    CountryData {
+        country: Country::ABW,
+        alpha2: "AW",
+        alpha3: "ABW",
+        numeric: 533,
+        name: "Aruba",
+        official_name: None,
+        common_name: None,
+        continent: Continent::NorthAmerica,
+        sub_region: Some(SubRegion::LatinAmericaAndTheCaribbean),
+        capital: Some("Oranjestad"),
+        currencies: &[Currency::AWG],
+        calling_codes: &[297],
+        tld: Some(".aw"),
+    },
+    CountryData {
+        country: Country::AFG,
+        alpha2: "AF",
+        alpha3: "AFG",
+        numeric: 4,
+        name: "Afghanistan",
+        official_name: Some("Islamic Republic of Afghanistan"),
+        common_name: None,
+        continent: Continent::Asia,
+        sub_region: Some(SubRegion::SouthernAsia),
+        capital: Some("Kabul"),
+        currencies: &[Currency::AFN],
+        calling_codes: &[93],
+        tld: Some(".af"),
+    },
+    CountryData {
+        country: Country::AGO,
+        alpha2: "AO",
+        alpha3: "AGO",
+        numeric: 24,
+        name: "Angola",
+        official_name: Some("Republic of Angola"),
+        common_name: None,
+        continent: Continent::Africa,
+        sub_region: Some(SubRegion::SubSaharanAfrica),
+        capital: Some("Luanda"),
+        currencies: &[Currency::AOA],
+        calling_codes: &[244],
+        tld: Some(".ao"),
+    },
+    CountryData {
+        country: Country::AIA,
+        alpha2: "AI",
+        alpha3: "AIA",
+        numeric: 660,
+        name: "Anguilla",
+        official_name: None,
+        common_name: None,
+        continent: Continent::NorthAmerica,
+        sub_region: Some(SubRegion::LatinAmericaAndTheCaribbean),
+        capital: Some("The Valley"),
+        currencies: &[Currency::XCD],
+        calling_codes: &[1],
+        tld: Some(".ai"),
+    },
+    CountryData {
+        country: Country::ALA,
+        alpha2: "AX",
+        alpha3: "ALA",
+        numeric: 248,
+        name: "Åland Islands",
+        official_name: None,
+        common_name: None,
+        continent: Continent::Europe,
+        sub_region: Some(SubRegion::NorthernEurope),
+        capital: Some("Mariehamn"),
+        currencies: &[Currency::EUR],
+        calling_codes: &[358],
+        tld: Some(".ax"),
+    },
+    CountryData {
+        country: Country::ALB,
+        alpha2: "AL",
+        alpha3: "ALB",
+        numeric: 8,
+        name: "Albania",
+        official_name: Some("Republic of Albania"),
+        common_name: None,
+        continent: Continent::Europe,
+        sub_region: Some(SubRegion::SouthernEurope),
+        capital: Some("Tirana"),
+        currencies: &[Currency::ALL],
+        calling_codes: &[355],
+        tld: Some(".al"),
+    },
+    CountryData {
+        country: Country::AND,
+        alpha2: "AD",
+        alpha3: "AND",
+        numeric: 20,
+        name: "Andorra",
+        official_name: Some("Principality of Andorra"),
+        common_name: None,
+        continent: Continent::Europe,
+        sub_region: Some(SubRegion::SouthernEurope),
+        capital: Some("Andorra la Vella"),
+        currencies: &[Currency::EUR],
+        calling_codes: &[376],
+        tld: Some(".ad"),
+    },
+    CountryData {
+        country: Country::ARE,
+        alpha2: "AE",
+        alpha3: "ARE",
+        numeric: 784,
+        name: "United Arab Emirates",
+        official_name: None,
+        common_name: None,
+        continent: Continent::Asia,
+        sub_region: Some(SubRegion::WesternAsia),
+        capital: Some("Abu Dhabi"),
+        currencies: &[Currency::AED],
+        calling_codes: &[971],
+        tld: Some(".ae"),
+    },
+    CountryData {
+        country: Country::ARG,
+        alpha2: "AR",
+        alpha3: "ARG",
+        numeric: 32,
+        name: "Argentina",
+        official_name: Some("Argentine Republic"),
+        common_name: None,
+        continent: Continent::SouthAmerica,
+        sub_region: Some(SubRegion::LatinAmericaAndTheCaribbean),
+        capital: Some("Buenos Aires"),
+        currencies: &[Currency::ARS],
+        calling_codes: &[54],
+        tld: Some(".ar"),
+    },
+    CountryData {
+        country: Country::ARM,
+        alpha2: "AM",
+        alpha3: "ARM",
+        numeric: 51,
+        name: "Armenia",
+        official_name: Some("Republic of Armenia"),
+        common_name: None,
+        continent: Continent::Asia,
+        sub_region: Some(SubRegion::WesternAsia),
+        capital: Some("Yerevan"),
+        currencies: &[Currency::AMD],
+        calling_codes: &[374],
+        tld: Some(".am"),
+    },
+    CountryData {
+        country: Country::ASM,
+        alpha2: "AS",
+        alpha3: "ASM",
+        numeric: 16,
+        name: "American Samoa",
+        official_name: None,
+        common_name: None,
+        continent: Continent::Oceania,
+        sub_region: Some(SubRegion::Polynesia),
+        capital: Some("Pago Pago"),
+        currencies: &[Currency::USD],
+        calling_codes: &[1],
+        tld: Some(".as"),
+    },
+    CountryData {
+        country: Country::ATA,
+        alpha2: "AQ",
+        alpha3: "ATA",
+        numeric: 10,
+        name: "Antarctica",
+        official_name: None,
+        common_name: None,
+        continent: Continent::Antarctica,
+        sub_region: None,
+        capital: None,
+        currencies: &[],
+        calling_codes: &[],
+        tld: Some(".aq"),
+    },
+    CountryData {
+        country: Country::ATF,
+        alpha2: "TF",
+        alpha3: "ATF",
+        numeric: 260,
+        name: "French Southern Territories",
+        official_name: None,
+        common_name: None,
+        continent: Continent::Africa,
+        sub_region: Some(SubRegion::SubSaharanAfrica),
+        capital: Some("Port-aux-Français"),
+        currencies: &[Currency::EUR],
+        calling_codes: &[],
+        tld: Some(".tf"),
+    },
+    CountryData {
+        country: Country::ATG,
+        alpha2: "AG",
+        alpha3: "ATG",
+        numeric: 28,
+        name: "Antigua and Barbuda",
+        official_name: None,
+        common_name: None,
+        continent: Continent::NorthAmerica,
+        sub_region: Some(SubRegion::LatinAmericaAndTheCaribbean),
+        capital: Some("Saint John's"),
+        currencies: &[Currency::XCD],
+        calling_codes: &[1],
+        tld: Some(".ag"),
+    },
+    CountryData {
+        country: Country::AUS,
+        alpha2: "AU",
+        alpha3: "AUS",
+        numeric: 36,
+        name: "Australia",
+        official_name: None,
+        common_name: None,
+        continent: Continent::Oceania,
+        sub_region: Some(SubRegion::AustraliaAndNewZealand),
+        capital: Some("Canberra"),
+        currencies: &[Currency::AUD],
+        calling_codes: &[61],
+        tld: Some(".au"),
+    },
+    CountryData {
+        country: Country::AUT,
+        alpha2: "AT",
+        alpha3: "AUT",
+        numeric: 40,
+        name: "Austria",
+        official_name: Some("Republic of Austria"),
+        common_name: None,
+        continent: Continent::Europe,
+        sub_region: Some(SubRegion::WesternEurope),
+        capital: Some("Vienna"),
+        currencies: &[Currency::EUR],
+        calling_codes: &[43],
+        tld: Some(".at"),
+    },
+    CountryData {
+        country: Country::AZE,
+        alpha2: "AZ",
+        alpha3: "AZE",
+        numeric: 31,
+        name: "Azerbaijan",
+        official_name: Some("Republic of Azerbaijan"),
+        common_name: None,
+        continent: Continent::Asia,
+        sub_region: Some(SubRegion::WesternAsia),
+        capital: Some("Baku"),
+        currencies: &[Currency::AZN],
+        calling_codes: &[994],
+        tld: Some(".az"),
+    },
+    CountryData {
+        country: Country::BDI,
+        alpha2: "BI",
+        alpha3: "BDI",
+        numeric: 108,
+        name: "Burundi",
+        official_name: Some("Republic of Burundi"),
+        common_name: None,
+        continent: Continent::Africa,
+        sub_region: Some(SubRegion::SubSaharanAfrica),
+        capital: Some("Gitega"),
+        currencies: &[Currency::BIF],
+        calling_codes: &[257],
+        tld: Some(".bi"),
+    },
+    CountryData {
+        country: Country::BEL,
+        alpha2: "BE",
+        alpha3: "BEL",
+        numeric: 56,
+        name: "Belgium",
+        official_name: Some("Kingdom of Belgium"),
+        common_name: None,
+        continent: Continent::Europe,
+        sub_region: Some(SubRegion::WesternEurope),
+        capital: Some("Brussels"),
+        currencies: &[Currency::EUR],
+        calling_codes: &[32],
+        tld: Some(".be"),
+    },
+    CountryData {
+        country: Country::BEN,
+        alpha2: "BJ",
+        alpha3: "BEN",
+        numeric: 204,
+        name: "Benin",
+        official_name: Some("Republic of Benin"),
+        common_name: None,
+        continent: Continent::Africa,
+        sub_region: Some(SubRegion::SubSaharanAfrica),
+        capital: Some("Porto-Novo"),
+        currencies: &[Currency::XOF],
+        calling_codes: &[229],
+        tld: Some(".bj"),
+    },
+    CountryData {
+        country: Country::BES,
+        alpha2: "BQ",
+        alpha3: "BES",
+        numeric: 535,
+        name: "Bonaire, Sint Eustatius and Saba",
+        official_name: None,
+        common_name: None,
+        continent: Continent::NorthAmerica,
+        sub_region: Some(SubRegion::LatinAmericaAndTheCaribbean),
+        capital: Some("Kralendijk"),
+        currencies: &[Currency::USD],
+        calling_codes: &[599],
+        tld: None,
+    },
+    CountryData {
+        country: Country::BFA,
+        alpha2: "BF",
+        alpha3: "BFA",
+        numeric: 854,
+        name: "Burkina Faso",
+        official_name: None,
+        common_name: None,
+        continent: Continent::Africa,
+        sub_region: Some(SubRegion::SubSaharanAfrica),
+        capital: Some("Ouagadougou"),
+        currencies: &[Currency::XOF],
+        calling_codes: &[226],
+        tld: Some(".bf"),
+    },
+    CountryData {
+        country: Country::BGD,
+        alpha2: "BD",
+        alpha3: "BGD",
+        numeric: 50,
+        name: "Bangladesh",
+        official_name: Some("People's Republic of Bangladesh"),
+        common_name: None,
+        continent: Continent::Asia,
+        sub_region: Some(SubRegion::SouthernAsia),
+        capital: Some("Dhaka"),
+        currencies: &[Currency::BDT],
+        calling_codes: &[880],
+        tld: Some(".bd"),
+    },
+    CountryData {
+        country: Country::BGR,
+        alpha2: "BG",
+        alpha3: "BGR",
+        numeric: 100,
+        name: "Bulgaria",
+        official_name: Some("Republic of Bulgaria"),
+        common_name: None,
+        continent: Continent::Europe,
+        sub_region: Some(SubRegion::EasternEurope),
+        capital: Some("Sofia"),
+        currencies: &[Currency::BGN],
+        calling_codes: &[359],
+        tld: Some(".bg"),
+    },
+    CountryData {
+        country: Country::BHR,
+        alpha2: "BH",
+        alpha3: "BHR",
+        numeric: 48,
+        name: "Bahrain",
+        official_name: Some("Kingdom of Bahrain"),
+        common_name: None,
+        continent: Continent::Asia,
+        sub_region: Some(SubRegion::WesternAsia),
+        capital: Some("Manama"),
+        currencies: &[Currency::BHD],
+        calling_codes: &[973],
+        tld: Some(".bh"),
+    },
+    CountryData {
+        country: Country::BHS,
+        alpha2: "BS",
+        alpha3: "BHS",
+        numeric: 44,
+        name: "Bahamas",
+        official_name: Some("Commonwealth of the Bahamas"),
+        common_name: None,
+        continent: Continent::NorthAmerica,
+        sub_region: Some(SubRegion::LatinAmericaAndTheCaribbean),
+        capital: Some("Nassau"),
+        currencies: &[Currency::BSD],
+        calling_codes: &[1],
+        tld: Some(".bs"),
+    },
+    CountryData {
+        country: Country::BIH,
+        alpha2: "BA",
+        alpha3: "BIH",
+        numeric: 70,
+        name: "Bosnia and Herzegovina",
+        official_name: Some("Republic of Bosnia and Herzegovina"),
+        common_name: None,
+        continent: Continent::Europe,
+        sub_region: Some(SubRegion::SouthernEurope),
+        capital: Some("Sarajevo"),
+        currencies: &[Currency::BAM],
+        calling_codes: &[387],
+        tld: Some(".ba"),
+    },
+    CountryData {
+        country: Country::BLM,
+        alpha2: "BL",
+        alpha3: "BLM",
+        numeric: 652,
+        name: "Saint Barthélemy",
+        official_name: None,
+        common_name: None,
+        continent: Continent::NorthAmerica,
+        sub_region: Some(SubRegion::LatinAmericaAndTheCaribbean),
+        capital: Some("Gustavia"),
+        currencies: &[Currency::EUR],
+        calling_codes: &[590],
+        tld: None,
+    },
+    CountryData {
+        country: Country::BLR,
+        alpha2: "BY",
+        alpha3: "BLR",
+        numeric: 112,
+        name: "Belarus",
+        official_name: Some("Republic of Belarus"),
+        common_name: None,
+        continent: Continent::Europe,
+        sub_region: Some(SubRegion::EasternEurope),
+        capital: Some("Minsk"),
+        currencies: &[Currency::BYN],
+        calling_codes: &[375],
+        tld: Some(".by"),
+    },
+    CountryData {
+        country: Country::BLZ,
+        alpha2: "BZ",
+        alpha3: "BLZ",
+        numeric: 84,
+        name: "Belize",
+        official_name: None,
+        common_name: None,
+        continent: Continent::NorthAmerica,
+        sub_region: Some(SubRegion::LatinAmericaAndTheCaribbean),
+        capital: Some("Belmopan"),
+        currencies: &[Currency::BZD],
+        calling_codes: &[501],
+        tld: Some(".bz"),
+    },
+    CountryData {
+        country: Country::BMU,
+        alpha2: "BM",
+        alpha3: "BMU",
+        numeric: 60,
+        name: "Bermuda",
+        official_name: None,
+        common_name: None,
+        continent: Continent::NorthAmerica,
+        sub_region: Some(SubRegion::NorthernAmerica),
+        capital: Some("Hamilton"),
+        currencies: &[Currency::BMD],
+        calling_codes: &[1],
+        tld: Some(".bm"),
+    },
+    CountryData {
+        country: Country::BOL,
+        alpha2: "BO",
+        alpha3: "BOL",
+        numeric: 68,
+        name: "Bolivia, Plurinational State of",
+        official_name: Some("Plurinational State of Bolivia"),
+        common_name: Some("Bolivia"),
+        continent: Continent::SouthAmerica,
+        sub_region: Some(SubRegion::LatinAmericaAndTheCaribbean),
+        capital: Some("Sucre"),
+        currencies: &[Currency::BOB],
+        calling_codes: &[591],
+        tld: Some(".bo"),
+    },
+    CountryData {
+        country: Country::BRA,
+        alpha2: "BR",
+        alpha3: "BRA",
+        numeric: 76,
+        name: "Brazil",
+        official_name: Some("Federative Republic of Brazil"),
+        common_name: None,
+        continent: Continent::SouthAmerica,
+        sub_region: Some(SubRegion::LatinAmericaAndTheCaribbean),
+        capital: Some("Brasília"),
+        currencies: &[Currency::BRL],
+        calling_codes: &[55],
+        tld: Some(".br"),
+    },
+    CountryData {
+        country: Country::BRB,
+        alpha2: "BB",
+        alpha3: "BRB",
+        numeric: 52,
+        name: "Barbados",
+        official_name: None,
+        common_name: None,
+        continent: Continent::NorthAmerica,
+        sub_region: Some(SubRegion::LatinAmericaAndTheCaribbean),
+        capital: Some("Bridgetown"),
+        currencies: &[Currency::BBD],
+        calling_codes: &[1],
+        tld: Some(".bb"),
+    },
+    CountryData {
+        country: Country::BRN,
+        alpha2: "BN",
+        alpha3: "BRN",
+        numeric: 96,
+        name: "Brunei Darussalam",
+        official_name: None,
+        common_name: Some("Brunei"),
+        continent: Continent::Asia,
+        sub_region: Some(SubRegion::SouthEasternAsia),
+        capital: Some("Bandar Seri Begawan"),
+        currencies: &[Currency::BND],
+        calling_codes: &[673],
+        tld: Some(".bn"),
+    },
+    CountryData {
+        country: Country::BTN,
+        alpha2: "BT",
+        alpha3: "BTN",
+        numeric: 64,
+        name: "Bhutan",
+        official_name: Some("Kingdom of Bhutan"),
+        common_name: None,
+        continent: Continent::Asia,
+        sub_region: Some(SubRegion::SouthernAsia),
+        capital: Some("Thimphu"),
+        currencies: &[Currency::BTN, Currency::INR],
+        calling_codes: &[975],
+        tld: Some(".bt"),
+    },
+    CountryData {
+        country: Country::BVT,
+        alpha2: "BV",
+        alpha3: "BVT",
+        numeric: 74,
+        name: "Bouvet Island",
+        official_name: None,
+        common_name: None,
+        continent: Continent::SouthAmerica,
+        sub_region: Some(SubRegion::LatinAmericaAndTheCaribbean),
+        capital: None,
+        currencies: &[Currency::NOK],
+        calling_codes: &[],
+        tld: Some(".bv"),
+    },
+    CountryData {
+        country: Country::BWA,
+        alpha2: "BW",
+        alpha3: "BWA",
+        numeric: 72,
+        name: "Botswana",
+        official_name: Some("Republic of Botswana"),
+        common_name: None,
+        continent: Continent::Africa,
+        sub_region: Some(SubRegion::SubSaharanAfrica),
+        capital: Some("Gaborone"),
+        currencies: &[Currency::BWP],
+        calling_codes: &[267],
+        tld: Some(".bw"),
+    },
+    CountryData {
+        country: Country::CAF,
+        alpha2: "CF",
+        alpha3: "CAF",
+        numeric: 140,
+        name: "Central African Republic",
+        official_name: None,
+        common_name: None,
+        continent: Continent::Africa,
+        sub_region: Some(SubRegion::SubSaharanAfrica),
+        capital: Some("Bangui"),
+        currencies: &[Currency::XAF],
+        calling_codes: &[236],
+        tld: Some(".cf"),
+    },
+    CountryData {
+        country: Country::CAN,
+        alpha2: "CA",
+        alpha3: "CAN",
+        numeric: 124,
+        name: "Canada",
+        official_name: None,
+        common_name: None,
+        continent: Continent::NorthAmerica,
+        sub_region: Some(SubRegion::NorthernAmerica),
+        capital: Some("Ottawa"),
+        currencies: &[Currency::CAD],
+        calling_codes: &[1],
+        tld: Some(".ca"),
+    },
+    CountryData {
+        country: Country::CCK,
+        alpha2: "CC",
+        alpha3: "CCK",
+        numeric: 166,
+        name: "Cocos (Keeling) Islands",
+        official_name: None,
+        common_name: None,
+        continent: Continent::Oceania,
+        sub_region: Some(SubRegion::AustraliaAndNewZealand),
+        capital: Some("West Island"),
+        currencies: &[Currency::AUD],
+        calling_codes: &[61],
+        tld: Some(".cc"),
+    },
+    CountryData {
+        country: Country::CHE,
+        alpha2: "CH",
+        alpha3: "CHE",
+        numeric: 756,
+        name: "Switzerland",
+        official_name: Some("Swiss Confederation"),
+        common_name: None,
+        continent: Continent::Europe,
+        sub_region: Some(SubRegion::WesternEurope),
+        capital: Some("Bern"),
+        currencies: &[Currency::CHF],
+        calling_codes: &[41],
+        tld: Some(".ch"),
+    },
+    CountryData {
+        country: Country::CHL,
+        alpha2: "CL",
+        alpha3: "CHL",
+        numeric: 152,
+        name: "Chile",
+        official_name: Some("Republic of Chile"),
+        common_name: None,
+        continent: Continent::SouthAmerica,
+        sub_region: Some(SubRegion::LatinAmericaAndTheCaribbean),
+        capital: Some("Santiago"),
+        currencies: &[Currency::CLP],
+        calling_codes: &[56],
+        tld: Some(".cl"),
+    },
+    CountryData {
+        country: Country::CHN,
+        alpha2: "CN",
+        alpha3: "CHN",
+        numeric: 156,
+        name: "China",
+        official_name: Some("People's Republic of China"),
+        common_name: None,
+        continent: Continent::Asia,
+        sub_region: Some(SubRegion::EasternAsia),
+        capital: Some("Beijing"),
+        currencies: &[Currency::CNY],
+        calling_codes: &[86],
+        tld: Some(".cn"),
+    },
+    CountryData {
+        country: Country::CIV,
+        alpha2: "CI",
+        alpha3: "CIV",
+        numeric: 384,
+        name: "Côte d'Ivoire",
+        official_name: Some("Republic of Côte d'Ivoire"),
+        common_name: Some("Ivory Coast"),
+        continent: Continent::Africa,
+        sub_region: Some(SubRegion::SubSaharanAfrica),
+        capital: Some("Yamoussoukro"),
+        currencies: &[Currency::XOF],
+        calling_codes: &[225],
+        tld: Some(".ci"),
+    },
+    CountryData {
+        country: Country::CMR,
+        alpha2: "CM",
+        alpha3: "CMR",
+        numeric: 120,
+        name: "Cameroon",
+        official_name: Some("Republic of Cameroon"),
+        common_name: None,
+        continent: Continent::Africa,
+        sub_region: Some(SubRegion::SubSaharanAfrica),
+        capital: Some("Yaoundé"),
+        currencies: &[Currency::XAF],
+        calling_codes: &[237],
+        tld: Some(".cm"),
+    },
+    CountryData {
+        country: Country::COD,
+        alpha2: "CD",
+        alpha3: "COD",
+        numeric: 180,
+        name: "Congo, The Democratic Republic of the",
+        official_name: Some("Democratic Republic of the Congo"),
+        common_name: Some("Democratic Republic of the Congo"),
+        continent: Continent::Africa,
+        sub_region: Some(SubRegion::SubSaharanAfrica),
+        capital: Some("Kinshasa"),
+        currencies: &[Currency::CDF],
+        calling_codes: &[243],
+        tld: Some(".cd"),
+    },
+    CountryData {
+        country: Country::COG,
+        alpha2: "CG",
+        alpha3: "COG",
+        numeric: 178,
+        name: "Congo",
+        official_name: Some("Republic of the Congo"),
+        common_name: Some("Republic of the Congo"),
+        continent: Continent::Africa,
+        sub_region: Some(SubRegion::SubSaharanAfrica),
+        capital: Some("Brazzaville"),
+        currencies: &[Currency::XAF],
+        calling_codes: &[242],
+        tld: Some(".cg"),
+    },
+    CountryData {
+        country: Country::COK,
+        alpha2: "CK",
+        alpha3: "COK",
+        numeric: 184,
+        name: "Cook Islands",
+        official_name: None,
+        common_name: None,
+        continent: Continent::Oceania,
+        sub_region: Some(SubRegion::Polynesia),
+        capital: Some("Avarua"),
+        currencies: &[Currency::NZD],
+        calling_codes: &[682],
+        tld: Some(".ck"),
+    },
+    CountryData {
+        country: Country::COL,
+        alpha2: "CO",
+        alpha3: "COL",
+        numeric: 170,
+        name: "Colombia",
+        official_name: Some("Republic of Colombia"),
+        common_name: None,
+        continent: Continent::SouthAmerica,
+        sub_region: Some(SubRegion::LatinAmericaAndTheCaribbean),
+        capital: Some("Bogotá"),
+        currencies: &[Currency::COP],
+        calling_codes: &[57],
+        tld: Some(".co"),
+    },
+    CountryData {
+        country: Country::COM,
+        alpha2: "KM",
+        alpha3: "COM",
+        numeric: 174,
+        name: "Comoros",
+        official_name: Some("Union of the Comoros"),
+        common_name: None,
+        continent: Continent::Africa,
+        sub_region: Some(SubRegion::SubSaharanAfrica),
+        capital: Some("Moroni"),
+        currencies: &[Currency::KMF],
+        calling_codes: &[269],
+        tld: Some(".km"),
+    },
+    CountryData {
+        country: Country::CPV,
+        alpha2: "CV",
+        alpha3: "CPV",
+        numeric: 132,
+        name: "Cabo Verde",
+        official_name: Some("Republic of Cabo Verde"),
+        common_name: Some("Cape Verde"),
+        continent: Continent::Africa,
+        sub_region: Some(SubRegion::SubSaharanAfrica),
+        capital: Some("Praia"),
+        currencies: &[Currency::CVE],
+        calling_codes: &[238],
+        tld: Some(".cv"),
+    },
+    CountryData {
+        country: Country::CRI,
+        alpha2: "CR",
+        alpha3: "CRI",
+        numeric: 188,
+        name: "Costa Rica",
+        official_name: Some("Republic of Costa Rica"),
+        common_name: None,
+        continent: Continent::NorthAmerica,
+        sub_region: Some(SubRegion::LatinAmericaAndTheCaribbean),
+        capital: Some("San José"),
+        currencies: &[Currency::CRC],
+        calling_codes: &[506],
+        tld: Some(".cr"),
+    },
+    CountryData {
+        country: Country::CUB,
+        alpha2: "CU",
+        alpha3: "CUB",
+        numeric: 192,
+        name: "Cuba",
+        official_name: Some("Republic of Cuba"),
+        common_name: None,
+        continent: Continent::NorthAmerica,
+        sub_region: Some(SubRegion::LatinAmericaAndTheCaribbean),
+        capital: Some("Havana"),
+        currencies: &[Currency::CUP],
+        calling_codes: &[53],
+        tld: Some(".cu"),
+    },
+    CountryData {
+        country: Country::CUW,
+        alpha2: "CW",
+        alpha3: "CUW",
+        numeric: 531,
+        name: "Curaçao",
+        official_name: None,
+        common_name: None,
+        continent: Continent::NorthAmerica,
+        sub_region: Some(SubRegion::LatinAmericaAndTheCaribbean),
+        capital: Some("Willemstad"),
+        currencies: &[Currency::ANG],
+        calling_codes: &[599],
+        tld: Some(".cw"),
+    },
+    CountryData {
+        country: Country::CXR,
+        alpha2: "CX",
+        alpha3: "CXR",
+        numeric: 162,
+        name: "Christmas Island",
+        official_name: None,
+        common_name: None,
+        continent: Continent::Oceania,
+        sub_region: Some(SubRegion::AustraliaAndNewZealand),
+        capital: Some("Flying Fish Cove"),
+        currencies: &[Currency::AUD],
+        calling_codes: &[61],
+        tld: Some(".cx"),
+    },
+    CountryData {
+        country: Country::CYM,
+        alpha2: "KY",
+        alpha3: "CYM",
+        numeric: 136,
+        name: "Cayman Islands",
+        official_name: None,
+        common_name: None,
+        continent: Continent::NorthAmerica,
+        sub_region: Some(SubRegion::LatinAmericaAndTheCaribbean),
+        capital: Some("George Town"),
+        currencies: &[Currency::KYD],
+        calling_codes: &[1],
+        tld: Some(".ky"),
+    },
+    CountryData {
+        country: Country::CYP,
+        alpha2: "CY",
+        alpha3: "CYP",
+        numeric: 196,
+        name: "Cyprus",
+        official_name: Some("Republic of Cyprus"),
+        common_name: None,
+        continent: Continent::Asia,
+        sub_region: Some(SubRegion::WesternAsia),
+        capital: Some("Nicosia"),
+        currencies: &[Currency::EUR],
+        calling_codes: &[357],
+        tld: Some(".cy"),
+    },
+    CountryData {
+        country: Country::CZE,
+        alpha2: "CZ",
+        alpha3: "CZE",
+        numeric: 203,
+        name: "Czechia",
+        official_name: Some("Czech Republic"),
+        common_name: Some("Czech Republic"),
+        continent: Continent::Europe,
+        sub_region: Some(SubRegion::EasternEurope),
+        capital: Some("Prague"),
+        currencies: &[Currency::CZK],
+        calling_codes: &[420],
+        tld: Some(".cz"),
+    },
+    CountryData {
+        country: Country::DEU,
+        alpha2: "DE",
+        alpha3: "DEU",
+        numeric: 276,
+        name: "Germany",
+        official_name: Some("Federal Republic of Germany"),
+        common_name: None,
+        continent: Continent::Europe,
+        sub_region: Some(SubRegion::WesternEurope),
+        capital: Some("Berlin"),
+        currencies: &[Currency::EUR],
+        calling_codes: &[49],
+        tld: Some(".de"),
+    },
+    CountryData {
+        country: Country::DJI,
+        alpha2: "DJ",
+        alpha3: "DJI",
+        numeric: 262,
+        name: "Djibouti",
+        official_name: Some("Republic of Djibouti"),
+        common_name: None,
+        continent: Continent::Africa,
+        sub_region: Some(SubRegion::SubSaharanAfrica),
+        capital: Some("Djibouti"),
+        currencies: &[Currency::DJF],
+        calling_codes: &[253],
+        tld: Some(".dj"),
+    },
+    CountryData {
+        country: Country::DMA,
+        alpha2: "DM",
+        alpha3: "DMA",
+        numeric: 212,
+        name: "Dominica",
+        official_name: Some("Commonwealth of Dominica"),
+        common_name: None,
+        continent: Continent::NorthAmerica,
+        sub_region: Some(SubRegion::LatinAmericaAndTheCaribbean),
+        capital: Some("Roseau"),
+        currencies: &[Currency::XCD],
+        calling_codes: &[1],
+        tld: Some(".dm"),
+    },
+    CountryData {
+        country: Country::DNK,
+        alpha2: "DK",
+        alpha3: "DNK",
+        numeric: 208,
+        name: "Denmark",
+        official_name: Some("Kingdom of Denmark"),
+        common_name: None,
+        continent: Continent::Europe,
+        sub_region: Some(SubRegion::NorthernEurope),
+        capital: Some("Copenhagen"),
+        currencies: &[Currency::DKK],
+        calling_codes: &[45],
+        tld: Some(".dk"),
+    },
+    CountryData {
+        country: Country::DOM,
+        alpha2: "DO",
+        alpha3: "DOM",
+        numeric: 214,
+        name: "Dominican Republic",
+        official_name: None,
+        common_name: None,
+        continent: Continent::NorthAmerica,
+        sub_region: Some(SubRegion::LatinAmericaAndTheCaribbean),
+        capital: Some("Santo Domingo"),
+        currencies: &[Currency::DOP],
+        calling_codes: &[1],
+        tld: Some(".do"),
+    },
+    CountryData {
+        country: Country::DZA,
+        alpha2: "DZ",
+        alpha3: "DZA",
+        numeric: 12,
+        name: "Algeria",
+        official_name: Some("People's Democratic Republic of Algeria"),
+        common_name: None,
+        continent: Continent::Africa,
+        sub_region: Some(SubRegion::NorthernAfrica),
+        capital: Some("Algiers"),
+        currencies: &[Currency::DZD],
+        calling_codes: &[213],
+        tld: Some(".dz"),
+    },
+    CountryData {
+        country: Country::ECU,
+        alpha2: "EC",
+        alpha3: "ECU",
+        numeric: 218,
+        name: "Ecuador",
+        official_name: Some("Republic of Ecuador"),
+        common_name: None,
+        continent: Continent::SouthAmerica,
+        sub_region: Some(SubRegion::LatinAmericaAndTheCaribbean),
+        capital: Some("Quito"),
+        currencies: &[Currency::USD],
+        calling_codes: &[593],
+        tld: Some(".ec"),
+    },
+    CountryData {
+        country: Country::EGY,
+        alpha2: "EG",
+        alpha3: "EGY",
+        numeric: 818,
+        name: "Egypt",
+        official_name: Some("Arab Republic of Egypt"),
+        common_name: None,
+        continent: Continent::Africa,
+        sub_region: Some(SubRegion::NorthernAfrica),
+        capital: Some("Cairo"),
+        currencies: &[Currency::EGP],
+        calling_codes: &[20],
+        tld: Some(".eg"),
+    },
+    CountryData {
+        country: Country::ERI,
+        alpha2: "ER",
+        alpha3: "ERI",
+        numeric: 232,
+        name: "Eritrea",
+        official_name: Some("the State of Eritrea"),
+        common_name: None,
+        continent: Continent::Africa,
+        sub_region: Some(SubRegion::SubSaharanAfrica),
+        capital: Some("Asmara"),
+        currencies: &[Currency::ERN],
+        calling_codes: &[291],
+        tld: Some(".er"),
+    },
+    CountryData {
+        country: Country::ESH,
+        alpha2: "EH",
+        alpha3: "ESH",
+        numeric: 732,
+        name: "Western Sahara",
+        official_name: None,
+        common_name: None,
+        continent: Continent::Africa,
+        sub_region: Some(SubRegion::NorthernAfrica),
+        capital: Some("Laayoune"),
+        currencies: &[Currency::MAD],
+        calling_codes: &[212],
+        tld: None,
+    },
+    CountryData {
+        country: Country::ESP,
+        alpha2: "ES",
+        alpha3: "ESP",
+        numeric: 724,
+        name: "Spain",
+        official_name: Some("Kingdom of Spain"),
+        common_name: None,
+        continent: Continent::Europe,
+        sub_region: Some(SubRegion::SouthernEurope),
+        capital: Some("Madrid"),
+        currencies: &[Currency::EUR],
+        calling_codes: &[34],
+        tld: Some(".es"),
+    },
+    CountryData {
+        country: Country::EST,
+        alpha2: "EE",
+        alpha3: "EST",
+        numeric: 233,
+        name: "Estonia",
+        official_name: Some("Republic of Estonia"),
+        common_name: None,
+        continent: Continent::Europe,
+        sub_region: Some(SubRegion::NorthernEurope),
+        capital: Some("Tallinn"),
+        currencies: &[Currency::EUR],
+        calling_codes: &[372],
+        tld: Some(".ee"),
+    },
+    CountryData {
+        country: Country::ETH,
+        alpha2: "ET",
+        alpha3: "ETH",
+        numeric: 231,
+        name: "Ethiopia",
+        official_name: Some("Federal Democratic Republic of Ethiopia"),
+        common_name: None,
+        continent: Continent::Africa,
+        sub_region: Some(SubRegion::SubSaharanAfrica),
+        capital: Some("Addis Ababa"),
+        currencies: &[Currency::ETB],
+        calling_codes: &[251],
+        tld: Some(".et"),
+    },
+    CountryData {
+        country: Country::FIN,
+        alpha2: "FI",
+        alpha3: "FIN",
+        numeric: 246,
+        name: "Finland",
+        official_name: Some("Republic of Finland"),
+        common_name: None,
+        continent: Continent::Europe,
+        sub_region: Some(SubRegion::NorthernEurope),
+        capital: Some("Helsinki"),
+        currencies: &[Currency::EUR],
+        calling_codes: &[358],
+        tld: Some(".fi"),
+    },
+    CountryData {
+        country: Country::FJI,
+        alpha2: "FJ",
+        alpha3: "FJI",
+        numeric: 242,
+        name: "Fiji",
+        official_name: Some("Republic of Fiji"),
+        common_name: None,
+        continent: Continent::Oceania,
+        sub_region: Some(SubRegion::Melanesia),
+        capital: Some("Suva"),
+        currencies: &[Currency::FJD],
+        calling_codes: &[679],
+        tld: Some(".fj"),
+    },
+    CountryData {
+        country: Country::FLK,
+        alpha2: "FK",
+        alpha3: "FLK",
+        numeric: 238,
+        name: "Falkland Islands (Malvinas)",
+        official_name: None,
+        common_name: Some("Falkland Islands"),
+        continent: Continent::SouthAmerica,
+        sub_region: Some(SubRegion::LatinAmericaAndTheCaribbean),
+        capital: Some("Stanley"),
+        currencies: &[Currency::FKP],
+        calling_codes: &[500],
+        tld: Some(".fk"),
+    },
+    CountryData {
+        country: Country::FRA,
+        alpha2: "FR",
+        alpha3: "FRA",
+        numeric: 250,
+        name: "France",
+        official_name: Some("French Republic"),
+        common_name: None,
+        continent: Continent::Europe,
+        sub_region: Some(SubRegion::WesternEurope),
+        capital: Some("Paris"),
+        currencies: &[Currency::EUR],
+        calling_codes: &[33],
+        tld: Some(".fr"),
+    },
+    CountryData {
+        country: Country::FRO,
+        alpha2: "FO",
+        alpha3: "FRO",
+        numeric: 234,
+        name: "Faroe Islands",
+        official_name: None,
+        common_name: None,
+        continent: Continent::Europe,
+        sub_region: Some(SubRegion::NorthernEurope),
+        capital: Some("Tórshavn"),
+        currencies: &[Currency::DKK],
+        calling_codes: &[298],
+        tld: Some(".fo"),
+    },
+    CountryData {
+        country: Country::FSM,
+        alpha2: "FM",
+        alpha3: "FSM",
+        numeric: 583,
+        name: "Micronesia, Federated States of",
+        official_name: Some("Federated States of Micronesia"),
+        common_name: Some("Micronesia"),
+        continent: Continent::Oceania,
+        sub_region: Some(SubRegion::Micronesia),
+        capital: Some("Palikir"),
+        currencies: &[Currency::USD],
+        calling_codes: &[691],
+        tld: Some(".fm"),
+    },
+    CountryData {
+        country: Country::GAB,
+        alpha2: "GA",
+        alpha3: "GAB",
+        numeric: 266,
+        name: "Gabon",
+        official_name: Some("Gabonese Republic"),
+        common_name: None,
+        continent: Continent::Africa,
+        sub_region: Some(SubRegion::SubSaharanAfrica),
+        capital: Some("Libreville"),
+        currencies: &[Currency::XAF],
+        calling_codes: &[241],
+        tld: Some(".ga"),
+    },
+    CountryData {
+        country: Country::GBR,
+        alpha2: "GB",
+        alpha3: "GBR",
+        numeric: 826,
+        name: "United Kingdom",
+        official_name: Some("United Kingdom of Great Britain and Northern Ireland"),
+        common_name: None,
+        continent: Continent::Europe,
+        sub_region: Some(SubRegion::NorthernEurope),
+        capital: Some("London"),
+        currencies: &[Currency::GBP],
+        calling_codes: &[44],
+        tld: Some(".uk"),
+    },
+    CountryData {
+        country: Country::GEO,
+        alpha2: "GE",
+        alpha3: "GEO",
+        numeric: 268,
+        name: "Georgia",
+        official_name: None,
+        common_name: None,
+        continent: Continent::Asia,
+        sub_region: Some(SubRegion::WesternAsia),
+        capital: Some("Tbilisi"),
+        currencies: &[Currency::GEL],
+        calling_codes: &[995],
+        tld: Some(".ge"),
+    },
+    CountryData {
+        country: Country::GGY,
+        alpha2: "GG",
+        alpha3: "GGY",
+        numeric: 831,
+        name: "Guernsey",
+        official_name: None,
+        common_name: None,
+        continent: Continent::Europe,
+        sub_region: Some(SubRegion::NorthernEurope),
+        capital: Some("Saint Peter Port"),
+        currencies: &[Currency::GBP],
+        calling_codes: &[44],
+        tld: Some(".gg"),
+    },
+    CountryData {
+        country: Country::GHA,
+        alpha2: "GH",
+        alpha3: "GHA",
+        numeric: 288,
+        name: "Ghana",
+        official_name: Some("Republic of Ghana"),
+        common_name: None,
+        continent: Continent::Africa,
+        sub_region: Some(SubRegion::SubSaharanAfrica),
+        capital: Some("Accra"),
+        currencies: &[Currency::GHS],
+        calling_codes: &[233],
+        tld: Some(".gh"),
+    },
+    CountryData {
+        country: Country::GIB,
+        alpha2: "GI",
+        alpha3: "GIB",
+        numeric: 292,
+        name: "Gibraltar",
+        official_name: None,
+        common_name: None,
+        continent: Continent::Europe,
+        sub_region: Some(SubRegion::SouthernEurope),
+        capital: Some("Gibraltar"),
+        currencies: &[Currency::GIP],
+        calling_codes: &[350],
+        tld: Some(".gi"),
+    },
+    CountryData {
+        country: Country::GIN,
+        alpha2: "GN",
+        alpha3: "GIN",
+        numeric: 324,
+        name: "Guinea",
+        official_name: Some("Republic of Guinea"),
+        common_name: None,
+        continent: Continent::Africa,
+        sub_region: Some(SubRegion::SubSaharanAfrica),
+        capital: Some("Conakry"),
+        currencies: &[Currency::GNF],
+        calling_codes: &[224],
+        tld: Some(".gn"),
+    },
+    CountryData {
+        country: Country::GLP,
+        alpha2: "GP",
+        alpha3: "GLP",
+        numeric: 312,
+        name: "Guadeloupe",
+        official_name: None,
+        common_name: None,
+        continent: Continent::NorthAmerica,
+        sub_region: Some(SubRegion::LatinAmericaAndTheCaribbean),
+        capital: Some("Basse-Terre"),
+        currencies: &[Currency::EUR],
+        calling_codes: &[590],
+        tld: Some(".gp"),
+    },
+    CountryData {
+        country: Country::GMB,
+        alpha2: "GM",
+        alpha3: "GMB",
+        numeric: 270,
+        name: "Gambia",
+        official_name: Some("Republic of the Gambia"),
+        common_name: None,
+        continent: Continent::Africa,
+        sub_region: Some(SubRegion::SubSaharanAfrica),
+        capital: Some("Banjul"),
+        currencies: &[Currency::GMD],
+        calling_codes: &[220],
+        tld: Some(".gm"),
+    },
+    CountryData {
+        country: Country::GNB,
+        alpha2: "GW",
+        alpha3: "GNB",
+        numeric: 624,
+        name: "Guinea-Bissau",
+        official_name: Some("Republic of Guinea-Bissau"),
+        common_name: None,
+        continent: Continent::Africa,
+        sub_region: Some(SubRegion::SubSaharanAfrica),
+        capital: Some("Bissau"),
+        currencies: &[Currency::XOF],
+        calling_codes: &[245],
+        tld: Some(".gw"),
+    },
+    CountryData {
+        country: Country::GNQ,
+        alpha2: "GQ",
+        alpha3: "GNQ",
+        numeric: 226,
+        name: "Equatorial Guinea",
+        official_name: Some("Republic of Equatorial Guinea"),
+        common_name: None,
+        continent: Continent::Africa,
+        sub_region: Some(SubRegion::SubSaharanAfrica),
+        capital: Some("Malabo"),
+        currencies: &[Currency::XAF],
+        calling_codes: &[240],
+        tld: Some(".gq"),
+    },
+    CountryData {
+        country: Country::GRC,
+        alpha2: "GR",
+        alpha3: "GRC",
+        numeric: 300,
+        name: "Greece",
+        official_name: Some("Hellenic Republic"),
+        common_name: None,
+        continent: Continent::Europe,
+        sub_region: Some(SubRegion::SouthernEurope),
+        capital: Some("Athens"),
+        currencies: &[Currency::EUR],
+        calling_codes: &[30],
+        tld: Some(".gr"),
+    },
+    CountryData {
+        country: Country::GRD,
+        alpha2: "GD",
+        alpha3: "GRD",
+        numeric: 308,
+        name: "Grenada",
+        official_name: None,
+        common_name: None,
+        continent: Continent::NorthAmerica,
+        sub_region: Some(SubRegion::LatinAmericaAndTheCaribbean),
+        capital: Some("Saint George's"),
+        currencies: &[Currency::XCD],
+        calling_codes: &[1],
+        tld: Some(".gd"),
+    },
+    CountryData {
+        country: Country::GRL,
+        alpha2: "GL",
+        alpha3: "GRL",
+        numeric: 304,
+        name: "Greenland",
+        official_name: None,
+        common_name: None,
+        continent: Continent::NorthAmerica,
+        sub_region: Some(SubRegion::NorthernAmerica),
+        capital: Some("Nuuk"),
+        currencies: &[Currency::DKK],
+        calling_codes: &[299],
+        tld: Some(".gl"),
+    },
+    CountryData {
+        country: Country::GTM,
+        alpha2: "GT",
+        alpha3: "GTM",
+        numeric: 320,
+        name: "Guatemala",
+        official_name: Some("Republic of Guatemala"),
+        common_name: None,
+        continent: Continent::NorthAmerica,
+        sub_region: Some(SubRegion::LatinAmericaAndTheCaribbean),
+        capital: Some("Guatemala City"),
+        currencies: &[Currency::GTQ],
+        calling_codes: &[502],
+        tld: Some(".gt"),
+    },
+    CountryData {
+        country: Country::GUF,
+        alpha2: "GF",
+        alpha3: "GUF",
+        numeric: 254,
+        name: "French Guiana",
+        official_name: None,
+        common_name: None,
+        continent: Continent::SouthAmerica,
+        sub_region: Some(SubRegion::LatinAmericaAndTheCaribbean),
+        capital: Some("Cayenne"),
+        currencies: &[Currency::EUR],
+        calling_codes: &[594],
+        tld: Some(".gf"),
+    },
+    CountryData {
+        country: Country::GUM,
+        alpha2: "GU",
+        alpha3: "GUM",
+        numeric: 316,
+        name: "Guam",
+        official_name: None,
+        common_name: None,
+        continent: Continent::Oceania,
+        sub_region: Some(SubRegion::Micronesia),
+        capital: Some("Hagåtña"),
+        currencies: &[Currency::USD],
+        calling_codes: &[1],
+        tld: Some(".gu"),
+    },
+    CountryData {
+        country: Country::GUY,
+        alpha2: "GY",
+        alpha3: "GUY",
+        numeric: 328,
+        name: "Guyana",
+        official_name: Some("Republic of Guyana"),
+        common_name: None,
+        continent: Continent::SouthAmerica,
+        sub_region: Some(SubRegion::LatinAmericaAndTheCaribbean),
+        capital: Some("Georgetown"),
+        currencies: &[Currency::GYD],
+        calling_codes: &[592],
+        tld: Some(".gy"),
+    },
+    CountryData {
+        country: Country::HKG,
+        alpha2: "HK",
+        alpha3: "HKG",
+        numeric: 344,
+        name: "Hong Kong",
+        official_name: Some("Hong Kong Special Administrative Region of China"),
+        common_name: None,
+        continent: Continent::Asia,
+        sub_region: Some(SubRegion::EasternAsia),
+        capital: Some("Hong Kong"),
+        currencies: &[Currency::HKD],
+        calling_codes: &[852],
+        tld: Some(".hk"),
+    },
+    CountryData {
+        country: Country::HMD,
+        alpha2: "HM",
+        alpha3: "HMD",
+        numeric: 334,
+        name: "Heard Island and McDonald Islands",
+        official_name: None,
+        common_name: None,
+        continent: Continent::Oceania,
+        sub_region: Some(SubRegion::AustraliaAndNewZealand),
+        capital: None,
+        currencies: &[Currency::AUD],
+        calling_codes: &[],
+        tld: Some(".hm"),
+    },
+    CountryData {
+        country: Country::HND,
+        alpha2: "HN",
+        alpha3: "HND",
+        numeric: 340,
+        name: "Honduras",
+        official_name: Some("Republic of Honduras"),
+        common_name: None,
+        continent: Continent::NorthAmerica,
+        sub_region: Some(SubRegion::LatinAmericaAndTheCaribbean),
+        capital: Some("Tegucigalpa"),
+        currencies: &[Currency::HNL],
+        calling_codes: &[504],
+        tld: Some(".hn"),
+    },
+    CountryData {
+        country: Country::HRV,
+        alpha2: "HR",
+        alpha3: "HRV",
+        numeric: 191,
+        name: "Croatia",
+        official_name: Some("Republic of Croatia"),
+        common_name: None,
+        continent: Continent::Europe,
+        sub_region: Some(SubRegion::SouthernEurope),
+        capital: Some("Zagreb"),
+        currencies: &[Currency::EUR],
+        calling_codes: &[385],
+        tld: Some(".hr"),
+    },
+    CountryData {
+        country: Country::HTI,
+        alpha2: "HT",
+        alpha3: "HTI",
+        numeric: 332,
+        name: "Haiti",
+        official_name: Some("Republic of Haiti"),
+        common_name: None,
+        continent: Continent::NorthAmerica,
+        sub_region: Some(SubRegion::LatinAmericaAndTheCaribbean),
+        capital: Some("Port-au-Prince"),
+        currencies: &[Currency::HTG, Currency::USD],
+        calling_codes: &[509],
+        tld: Some(".ht"),
+    },
+    CountryData {
+        country: Country::HUN,
+        alpha2: "HU",
+        alpha3: "HUN",
+        numeric: 348,
+        name: "Hungary",
+        official_name: None,
+        common_name: None,
+        continent: Continent::Europe,
+        sub_region: Some(SubRegion::EasternEurope),
+        capital: Some("Budapest"),
+        currencies: &[Currency::HUF],
+        calling_codes: &[36],
+        tld: Some(".hu"),
+    },
+    CountryData {
+        country: Country::IDN,
+        alpha2: "ID",
+        alpha3: "IDN",
+        numeric: 360,
+        name: "Indonesia",
+        official_name: Some("Republic of Indonesia"),
+        common_name: None,
+        continent: Continent::Asia,
+        sub_region: Some(SubRegion::SouthEasternAsia),
+        capital: Some("Jakarta"),
+        currencies: &[Currency::IDR],
+        calling_codes: &[62],
+        tld: Some(".id"),
+    },
+    CountryData {
+        country: Country::IMN,
+        alpha2: "IM",
+        alpha3: "IMN",
+        numeric: 833,
+        name: "Isle of Man",
+        official_name: None,
+        common_name: None,
+        continent: Continent::Europe,
+        sub_region: Some(SubRegion::NorthernEurope),
+        capital: Some("Douglas"),
+        currencies: &[Currency::GBP],
+        calling_codes: &[44],
+        tld: Some(".im"),
+    },
+    CountryData {
+        country: Country::IND,
+        alpha2: "IN",
+        alpha3: "IND",
+        numeric: 356,
+        name: "India",
+        official_name: Some("Republic of India"),
+        common_name: None,
+        continent: Continent::Asia,
+        sub_region: Some(SubRegion::SouthernAsia),
+        capital: Some("New Delhi"),
+        currencies: &[Currency::INR],
+        calling_codes: &[91],
+        tld: Some(".in"),
+    },
+    CountryData {
+        country: Country::IOT,
+        alpha2: "IO",
+        alpha3: "IOT",
+        numeric: 86,
+        name: "British Indian Ocean Territory",
+        official_name: None,
+        common_name: None,
+        continent: Continent::Africa,
+        sub_region: Some(SubRegion::SubSaharanAfrica),
+        capital: None,
+        currencies: &[Currency::USD],
+        calling_codes: &[246],
+        tld: Some(".io"),
+    },
+    CountryData {
+        country: Country::IRL,
+        alpha2: "IE",
+        alpha3: "IRL",
+        numeric: 372,
+        name: "Ireland",
+        official_name: None,
+        common_name: None,
+        continent: Continent::Europe,
+        sub_region: Some(SubRegion::NorthernEurope),
+        capital: Some("Dublin"),
+        currencies: &[Currency::EUR],
+        calling_codes: &[353],
+        tld: Some(".ie"),
+    },
+    CountryData {
+        country: Country::IRN,
+        alpha2: "IR",
+        alpha3: "IRN",
+        numeric: 364,
+        name: "Iran, Islamic Republic of",
+        official_name: Some("Islamic Republic of Iran"),
+        common_name: Some("Iran"),
+        continent: Continent::Asia,
+        sub_region: Some(SubRegion::SouthernAsia),
+        capital: Some("Tehran"),
+        currencies: &[Currency::IRR],
+        calling_codes: &[98],
+        tld: Some(".ir"),
+    },
+    CountryData {
+        country: Country::IRQ,
+        alpha2: "IQ",
+        alpha3: "IRQ",
+        numeric: 368,
+        name: "Iraq",
+        official_name: Some("Republic of Iraq"),
+        common_name: None,
+        continent: Continent::Asia,
+        sub_region: Some(SubRegion::WesternAsia),
+        capital: Some("Baghdad"),
+        currencies: &[Currency::IQD],
+        calling_codes: &[964],
+        tld: Some(".iq"),
+    },
+    CountryData {
+        country: Country::ISL,
+        alpha2: "IS",
+        alpha3: "ISL",
+        numeric: 352,
+        name: "Iceland",
+        official_name: Some("Republic of Iceland"),
+        common_name: None,
+        continent: Continent::Europe,
+        sub_region: Some(SubRegion::NorthernEurope),
+        capital: Some("Reykjavík"),
+        currencies: &[Currency::ISK],
+        calling_codes: &[354],
+        tld: Some(".is"),
+    },
+    CountryData {
+        country: Country::ISR,
+        alpha2: "IL",
+        alpha3: "ISR",
+        numeric: 376,
+        name: "Israel",
+        official_name: Some("State of Israel"),
+        common_name: None,
+        continent: Continent::Asia,
+        sub_region: Some(SubRegion::WesternAsia),
+        capital: Some("Jerusalem"),
+        currencies: &[Currency::ILS],
+        calling_codes: &[972],
+        tld: Some(".il"),
+    },
+    CountryData {
+        country: Country::ITA,
+        alpha2: "IT",
+        alpha3: "ITA",
+        numeric: 380,
+        name: "Italy",
+        official_name: Some("Italian Republic"),
+        common_name: None,
+        continent: Continent::Europe,
+        sub_region: Some(SubRegion::SouthernEurope),
+        capital: Some("Rome"),
+        currencies: &[Currency::EUR],
+        calling_codes: &[39],
+        tld: Some(".it"),
+    },
+    CountryData {
+        country: Country::JAM,
+        alpha2: "JM",
+        alpha3: "JAM",
+        numeric: 388,
+        name: "Jamaica",
+        official_name: None,
+        common_name: None,
+        continent: Continent::NorthAmerica,
+        sub_region: Some(SubRegion::LatinAmericaAndTheCaribbean),
+        capital: Some("Kingston"),
+        currencies: &[Currency::JMD],
+        calling_codes: &[1],
+        tld: Some(".jm"),
+    },
+    CountryData {
+        country: Country::JEY,
+        alpha2: "JE",
+        alpha3: "JEY",
+        numeric: 832,
+        name: "Jersey",
+        official_name: None,
+        common_name: None,
+        continent: Continent::Europe,
+        sub_region: Some(SubRegion::NorthernEurope),
+        capital: Some("Saint Helier"),
+        currencies: &[Currency::GBP],
+        calling_codes: &[44],
+        tld: Some(".je"),
+    },
+    CountryData {
+        country: Country::JOR,
+        alpha2: "JO",
+        alpha3: "JOR",
+        numeric: 400,
+        name: "Jordan",
+        official_name: Some("Hashemite Kingdom of Jordan"),
+        common_name: None,
+        continent: Continent::Asia,
+        sub_region: Some(SubRegion::WesternAsia),
+        capital: Some("Amman"),
+        currencies: &[Currency::JOD],
+        calling_codes: &[962],
+        tld: Some(".jo"),
+    },
+    CountryData {
+        country: Country::JPN,
+        alpha2: "JP",
+        alpha3: "JPN",
+        numeric: 392,
+        name: "Japan",
+        official_name: None,
+        common_name: None,
+        continent: Continent::Asia,
+        sub_region: Some(SubRegion::EasternAsia),
+        capital: Some("Tokyo"),
+        currencies: &[Currency::JPY],
+        calling_codes: &[81],
+        tld: Some(".jp"),
+    },
+    CountryData {
+        country: Country::KAZ,
+        alpha2: "KZ",
+        alpha3: "KAZ",
+        numeric: 398,
+        name: "Kazakhstan",
+        official_name: Some("Republic of Kazakhstan"),
+        common_name: None,
+        continent: Continent::Asia,
+        sub_region: Some(SubRegion::CentralAsia),
+        capital: Some("Astana"),
+        currencies: &[Currency::KZT],
+        calling_codes: &[7],
+        tld: Some(".kz"),
+    },
+    CountryData {
+        country: Country::KEN,
+        alpha2: "KE",
+        alpha3: "KEN",
+        numeric: 404,
+        name: "Kenya",
+        official_name: Some("Republic of Kenya"),
+        common_name: None,
+        continent: Continent::Africa,
+        sub_region: Some(SubRegion::SubSaharanAfrica),
+        capital: Some("Nairobi"),
+        currencies: &[Currency::KES],
+        calling_codes: &[254],
+        tld: Some(".ke"),
+    },
+    CountryData {
+        country: Country::KGZ,
+        alpha2: "KG",
+        alpha3: "KGZ",
+        numeric: 417,
+        name: "Kyrgyzstan",
+        official_name: Some("Kyrgyz Republic"),
+        common_name: None,
+        continent: Continent::Asia,
+        sub_region: Some(SubRegion::CentralAsia),
+        capital: Some("Bishkek"),
+        currencies: &[Currency::KGS],
+        calling_codes: &[996],
+        tld: Some(".kg"),
+    },
+    CountryData {
+        country: Country::KHM,
+        alpha2: "KH",
+        alpha3: "KHM",
+        numeric: 116,
+        name: "Cambodia",
+        official_name: Some("Kingdom of Cambodia"),
+        common_name: None,
+        continent: Continent::Asia,
+        sub_region: Some(SubRegion::SouthEasternAsia),
+        capital: Some("Phnom Penh"),
+        currencies: &[Currency::KHR],
+        calling_codes: &[855],
+        tld: Some(".kh"),
+    },
+    CountryData {
+        country: Country::KIR,
+        alpha2: "KI",
+        alpha3: "KIR",
+        numeric: 296,
+        name: "Kiribati",
+        official_name: Some("Republic of Kiribati"),
+        common_name: None,
+        continent: Continent::Oceania,
+        sub_region: Some(SubRegion::Micronesia),
+        capital: Some("South Tarawa"),
+        currencies: &[Currency::AUD],
+        calling_codes: &[686],
+        tld: Some(".ki"),
+    },
+    CountryData {
+        country: Country::KNA,
+        alpha2: "KN",
+        alpha3: "KNA",
+        numeric: 659,
+        name: "Saint Kitts and Nevis",
+        official_name: None,
+        common_name: None,
+        continent: Continent::NorthAmerica,
+        sub_region: Some(SubRegion::LatinAmericaAndTheCaribbean),
+        capital: Some("Basseterre"),
+        currencies: &[Currency::XCD],
+        calling_codes: &[1],
+        tld: Some(".kn"),
+    },
+    CountryData {
+        country: Country::KOR,
+        alpha2: "KR",
+        alpha3: "KOR",
+        numeric: 410,
+        name: "Korea, Republic of",
+        official_name: Some("Republic of Korea"),
+        common_name: Some("South Korea"),
+        continent: Continent::Asia,
+        sub_region: Some(SubRegion::EasternAsia),
+        capital: Some("Seoul"),
+        currencies: &[Currency::KRW],
+        calling_codes: &[82],
+        tld: Some(".kr"),
+    },
+    CountryData {
+        country: Country::KWT,
+        alpha2: "KW",
+        alpha3: "KWT",
+        numeric: 414,
+        name: "Kuwait",
+        official_name: Some("State of Kuwait"),
+        common_name: None,
+        continent: Continent::Asia,
+        sub_region: Some(SubRegion::WesternAsia),
+        capital: Some("Kuwait City"),
+        currencies: &[Currency::KWD],
+        calling_codes: &[965],
+        tld: Some(".kw"),
+    },
+    CountryData {
+        country: Country::LAO,
+        alpha2: "LA",
+        alpha3: "LAO",
+        numeric: 418,
+        name: "Lao People's Democratic Republic",
+        official_name: None,
+        common_name: Some("Laos"),
+        continent: Continent::Asia,
+        sub_region: Some(SubRegion::SouthEasternAsia),
+        capital: Some("Vientiane"),
+        currencies: &[Currency::LAK],
+        calling_codes: &[856],
+        tld: Some(".la"),
+    },
+    CountryData {
+        country: Country::LBN,
+        alpha2: "LB",
+        alpha3: "LBN",
+        numeric: 422,
+        name: "Lebanon",
+        official_name: Some("Lebanese Republic"),
+        common_name: None,
+        continent: Continent::Asia,
+        sub_region: Some(SubRegion::WesternAsia),
+        capital: Some("Beirut"),
+        currencies: &[Currency::LBP],
+        calling_codes: &[961],
+        tld: Some(".lb"),
+    },
+    CountryData {
+        country: Country::LBR,
+        alpha2: "LR",
+        alpha3: "LBR",
+        numeric: 430,
+        name: "Liberia",
+        official_name: Some("Republic of Liberia"),
+        common_name: None,
+        continent: Continent::Africa,
+        sub_region: Some(SubRegion::SubSaharanAfrica),
+        capital: Some("Monrovia"),
+        currencies: &[Currency::LRD],
+        calling_codes: &[231],
+        tld: Some(".lr"),
+    },
+    CountryData {
+        country: Country::LBY,
+        alpha2: "LY",
+        alpha3: "LBY",
+        numeric: 434,
+        name: "Libya",
+        official_name: None,
+        common_name: None,
+        continent: Continent::Africa,
+        sub_region: Some(SubRegion::NorthernAfrica),
+        capital: Some("Tripoli"),
+        currencies: &[Currency::LYD],
+        calling_codes: &[218],
+        tld: Some(".ly"),
+    },
+    CountryData {
+        country: Country::LCA,
+        alpha2: "LC",
+        alpha3: "LCA",
+        numeric: 662,
+        name: "Saint Lucia",
+        official_name: None,
+        common_name: None,
+        continent: Continent::NorthAmerica,
+        sub_region: Some(SubRegion::LatinAmericaAndTheCaribbean),
+        capital: Some("Castries"),
+        currencies: &[Currency::XCD],
+        calling_codes: &[1],
+        tld: Some(".lc"),
+    },
+    CountryData {
+        country: Country::LIE,
+        alpha2: "LI",
+        alpha3: "LIE",
+        numeric: 438,
+        name: "Liechtenstein",
+        official_name: Some("Principality of Liechtenstein"),
+        common_name: None,
+        continent: Continent::Europe,
+        sub_region: Some(SubRegion::WesternEurope),
+        capital: Some("Vaduz"),
+        currencies: &[Currency::CHF],
+        calling_codes: &[423],
+        tld: Some(".li"),
+    },
+    CountryData {
+        country: Country::LKA,
+        alpha2: "LK",
+        alpha3: "LKA",
+        numeric: 144,
+        name: "Sri Lanka",
+        official_name: Some("Democratic Socialist Republic of Sri Lanka"),
+        common_name: None,
+        continent: Continent::Asia,
+        sub_region: Some(SubRegion::SouthernAsia),
+        capital: Some("Sri Jayawardenepura Kotte"),
+        currencies: &[Currency::LKR],
+        calling_codes: &[94],
+        tld: Some(".lk"),
+    },
+    CountryData {
+        country: Country::LSO,
+        alpha2: "LS",
+        alpha3: "LSO",
+        numeric: 426,
+        name: "Lesotho",
+        official_name: Some("Kingdom of Lesotho"),
+        common_name: None,
+        continent: Continent::Africa,
+        sub_region: Some(SubRegion::SubSaharanAfrica),
+        capital: Some("Maseru"),
+        currencies: &[Currency::LSL, Currency::ZAR],
+        calling_codes: &[266],
+        tld: Some(".ls"),
+    },
+    CountryData {
+        country: Country::LTU,
+        alpha2: "LT",
+        alpha3: "LTU",
+        numeric: 440,
+        name: "Lithuania",
+        official_name: Some("Republic of Lithuania"),
+        common_name: None,
+        continent: Continent::Europe,
+        sub_region: Some(SubRegion::NorthernEurope),
+        capital: Some("Vilnius"),
+        currencies: &[Currency::EUR],
+        calling_codes: &[370],
+        tld: Some(".lt"),
+    },
+    CountryData {
+        country: Country::LUX,
+        alpha2: "LU",
+        alpha3: "LUX",
+        numeric: 442,
+        name: "Luxembourg",
+        official_name: Some("Grand Duchy of Luxembourg"),
+        common_name: None,
+        continent: Continent::Europe,
+        sub_region: Some(SubRegion::WesternEurope),
+        capital: Some("Luxembourg"),
+        currencies: &[Currency::EUR],
+        calling_codes: &[352],
+        tld: Some(".lu"),
+    },
+    CountryData {
+        country: Country::LVA,
+        alpha2: "LV",
+        alpha3: "LVA",
+        numeric: 428,
+        name: "Latvia",
+        official_name: Some("Republic of Latvia"),
+        common_name: None,
+        continent: Continent::Europe,
+        sub_region: Some(SubRegion::NorthernEurope),
+        capital: Some("Riga"),
+        currencies: &[Currency::EUR],
+        calling_codes: &[371],
+        tld: Some(".lv"),
+    },
+    CountryData {
+        country: Country::MAC,
+        alpha2: "MO",
+        alpha3: "MAC",
+        numeric: 446,
+        name: "Macao",
+        official_name: Some("Macao Special Administrative Region of China"),
+        common_name: None,
+        continent: Continent::Asia,
+        sub_region: Some(SubRegion::EasternAsia),
+        capital: Some("Macao"),
+        currencies: &[Currency::MOP],
+        calling_codes: &[853],
+        tld: Some(".mo"),
+    },
+    CountryData {
+        country: Country::MAF,
+        alpha2: "MF",
+        alpha3: "MAF",
+        numeric: 663,
+        name: "Saint Martin (French part)",
+        official_name: None,
+        common_name: None,
+        continent: Continent::NorthAmerica,
+        sub_region: Some(SubRegion::LatinAmericaAndTheCaribbean),
+        capital: Some("Marigot"),
+        currencies: &[Currency::EUR],
+        calling_codes: &[590],
+        tld: None,
+    },
+    CountryData {
+        country: Country::MAR,
+        alpha2: "MA",
+        alpha3: "MAR",
+        numeric: 504,
+        name: "Morocco",
+        official_name: Some("Kingdom of Morocco"),
+        common_name: None,
+        continent: Continent::Africa,
+        sub_region: Some(SubRegion::NorthernAfrica),
+        capital: Some("Rabat"),
+        currencies: &[Currency::MAD],
+        calling_codes: &[212],
+        tld: Some(".ma"),
+    },
+    CountryData {
+        country: Country::MCO,
+        alpha2: "MC",
+        alpha3: "MCO",
+        numeric: 492,
+        name: "Monaco",
+        official_name: Some("Principality of Monaco"),
+        common_name: None,
+        continent: Continent::Europe,
+        sub_region: Some(SubRegion::WesternEurope),
+        capital: Some("Monaco"),
+        currencies: &[Currency::EUR],
+        calling_codes: &[377],
+        tld: Some(".mc"),
+    },
+    CountryData {
+        country: Country::MDA,
+        alpha2: "MD",
+        alpha3: "MDA",
+        numeric: 498,
+        name: "Moldova, Republic of",
+        official_name: Some("Republic of Moldova"),
+        common_name: Some("Moldova"),
+        continent: Continent::Europe,
+        sub_region: Some(SubRegion::EasternEurope),
+        capital: Some("Chișinău"),
+        currencies: &[Currency::MDL],
+        calling_codes: &[373],
+        tld: Some(".md"),
+    },
+    CountryData {
+        country: Country::MDG,
+        alpha2: "MG",
+        alpha3: "MDG",
+        numeric: 450,
+        name: "Madagascar",
+        official_name: Some("Republic of Madagascar"),
+        common_name: None,
+        continent: Continent::Africa,
+        sub_region: Some(SubRegion::SubSaharanAfrica),
+        capital: Some("Antananarivo"),
+        currencies: &[Currency::MGA],
+        calling_codes: &[261],
+        tld: Some(".mg"),
+    },
+    CountryData {
+        country: Country::MDV,
+        alpha2: "MV",
+        alpha3: "MDV",
+        numeric: 462,
+        name: "Maldives",
+        official_name: Some("Republic of Maldives"),
+        common_name: None,
+        continent: Continent::Asia,
+        sub_region: Some(SubRegion::SouthernAsia),
+        capital: Some("Malé"),
+        currencies: &[Currency::MVR],
+        calling_codes: &[960],
+        tld: Some(".mv"),
+    },
+    CountryData {
+        country: Country::MEX,
+        alpha2: "MX",
+        alpha3: "MEX",
+        numeric: 484,
+        name: "Mexico",
+        official_name: Some("United Mexican States"),
+        common_name: None,
+        continent: Continent::NorthAmerica,
+        sub_region: Some(SubRegion::LatinAmericaAndTheCaribbean),
+        capital: Some("Mexico City"),
+        currencies: &[Currency::MXN],
+        calling_codes: &[52],
+        tld: Some(".mx"),
+    },
+    CountryData {
+        country: Country::MHL,
+        alpha2: "MH",
+        alpha3: "MHL",
+        numeric: 584,
+        name: "Marshall Islands",
+        official_name: Some("Republic of the Marshall Islands"),
+        common_name: None,
+        continent: Continent::Oceania,
+        sub_region: Some(SubRegion::Micronesia),
+        capital: Some("Majuro"),
+        currencies: &[Currency::USD],
+        calling_codes: &[692],
+        tld: Some(".mh"),
+    },
+    CountryData {
+        country: Country::MKD,
+        alpha2: "MK",
+        alpha3: "MKD",
+        numeric: 807,
+        name: "North Macedonia",
+        official_name: Some("Republic of North Macedonia"),
+        common_name: Some("Macedonia"),
+        continent: Continent::Europe,
+        sub_region: Some(SubRegion::SouthernEurope),
+        capital: Some("Skopje"),
+        currencies: &[Currency::MKD],
+        calling_codes: &[389],
+        tld: Some(".mk"),
+    },
+    CountryData {
+        country: Country::MLI,
+        alpha2: "ML",
+        alpha3: "MLI",
+        numeric: 466,
+        name: "Mali",
+        official_name: Some("Republic of Mali"),
+        common_name: None,
+        continent: Continent::Africa,
+        sub_region: Some(SubRegion::SubSaharanAfrica),
+        capital: Some("Bamako"),
+        currencies: &[Currency::XOF],
+        calling_codes: &[223],
+        tld: Some(".ml"),
+    },
+    CountryData {
+        country: Country::MLT,
+        alpha2: "MT",
+        alpha3: "MLT",
+        numeric: 470,
+        name: "Malta",
+        official_name: Some("Republic of Malta"),
+        common_name: None,
+        continent: Continent::Europe,
+        sub_region: Some(SubRegion::SouthernEurope),
+        capital: Some("Valletta"),
+        currencies: &[Currency::EUR],
+        calling_codes: &[356],
+        tld: Some(".mt"),
+    },
+    CountryData {
+        country: Country::MMR,
+        alpha2: "MM",
+        alpha3: "MMR",
+        numeric: 104,
+        name: "Myanmar",
+        official_name: Some("Republic of Myanmar"),
+        common_name: Some("Burma"),
+        continent: Continent::Asia,
+        sub_region: Some(SubRegion::SouthEasternAsia),
+        capital: Some("Naypyidaw"),
+        currencies: &[Currency::MMK],
+        calling_codes: &[95],
+        tld: Some(".mm"),
+    },
+    CountryData {
+        country: Country::MNE,
+        alpha2: "ME",
+        alpha3: "MNE",
+        numeric: 499,
+        name: "Montenegro",
+        official_name: None,
+        common_name: None,
+        continent: Continent::Europe,
+        sub_region: Some(SubRegion::SouthernEurope),
+        capital: Some("Podgorica"),
+        currencies: &[Currency::EUR],
+        calling_codes: &[382],
+        tld: Some(".me"),
+    },
+    CountryData {
+        country: Country::MNG,
+        alpha2: "MN",
+        alpha3: "MNG",
+        numeric: 496,
+        name: "Mongolia",
+        official_name: None,
+        common_name: None,
+        continent: Continent::Asia,
+        sub_region: Some(SubRegion::EasternAsia),
+        capital: Some("Ulaanbaatar"),
+        currencies: &[Currency::MNT],
+        calling_codes: &[976],
+        tld: Some(".mn"),
+    },
+    CountryData {
+        country: Country::MNP,
+        alpha2: "MP",
+        alpha3: "MNP",
+        numeric: 580,
+        name: "Northern Mariana Islands",
+        official_name: Some("Commonwealth of the Northern Mariana Islands"),
+        common_name: None,
+        continent: Continent::Oceania,
+        sub_region: Some(SubRegion::Micronesia),
+        capital: Some("Saipan"),
+        currencies: &[Currency::USD],
+        calling_codes: &[1],
+        tld: Some(".mp"),
+    },
+    CountryData {
+        country: Country::MOZ,
+        alpha2: "MZ",
+        alpha3: "MOZ",
+        numeric: 508,
+        name: "Mozambique",
+        official_name: Some("Republic of Mozambique"),
+        common_name: None,
+        continent: Continent::Africa,
+        sub_region: Some(SubRegion::SubSaharanAfrica),
+        capital: Some("Maputo"),
+        currencies: &[Currency::MZN],
+        calling_codes: &[258],
+        tld: Some(".mz"),
+    },
+    CountryData {
+        country: Country::MRT,
+        alpha2: "MR",
+        alpha3: "MRT",
+        numeric: 478,
+        name: "Mauritania",
+        official_name: Some("Islamic Republic of Mauritania"),
+        common_name: None,
+        continent: Continent::Africa,
+        sub_region: Some(SubRegion::SubSaharanAfrica),
+        capital: Some("Nouakchott"),
+        currencies: &[Currency::MRU],
+        calling_codes: &[222],
+        tld: Some(".mr"),
+    },
+    CountryData {
+        country: Country::MSR,
+        alpha2: "MS",
+        alpha3: "MSR",
+        numeric: 500,
+        name: "Montserrat",
+        official_name: None,
+        common_name: None,
+        continent: Continent::NorthAmerica,
+        sub_region: Some(SubRegion::LatinAmericaAndTheCaribbean),
+        capital: Some("Plymouth"),
+        currencies: &[Currency::XCD],
+        calling_codes: &[1],
+        tld: Some(".ms"),
+    },
+    CountryData {
+        country: Country::MTQ,
+        alpha2: "MQ",
+        alpha3: "MTQ",
+        numeric: 474,
+        name: "Martinique",
+        official_name: None,
+        common_name: None,
+        continent: Continent::NorthAmerica,
+        sub_region: Some(SubRegion::LatinAmericaAndTheCaribbean),
+        capital: Some("Fort-de-France"),
+        currencies: &[Currency::EUR],
+        calling_codes: &[596],
+        tld: Some(".mq"),
+    },
+    CountryData {
+        country: Country::MUS,
+        alpha2: "MU",
+        alpha3: "MUS",
+        numeric: 480,
+        name: "Mauritius",
+        official_name: Some("Republic of Mauritius"),
+        common_name: None,
+        continent: Continent::Africa,
+        sub_region: Some(SubRegion::SubSaharanAfrica),
+        capital: Some("Port Louis"),
+        currencies: &[Currency::MUR],
+        calling_codes: &[230],
+        tld: Some(".mu"),
+    },
+    CountryData {
+        country: Country::MWI,
+        alpha2: "MW",
+        alpha3: "MWI",
+        numeric: 454,
+        name: "Malawi",
+        official_name: Some("Republic of Malawi"),
+        common_name: None,
+        continent: Continent::Africa,
+        sub_region: Some(SubRegion::SubSaharanAfrica),
+        capital: Some("Lilongwe"),
+        currencies: &[Currency::MWK],
+        calling_codes: &[265],
+        tld: Some(".mw"),
+    },
+    CountryData {
+        country: Country::MYS,
+        alpha2: "MY",
+        alpha3: "MYS",
+        numeric: 458,
+        name: "Malaysia",
+        official_name: None,
+        common_name: None,
+        continent: Continent::Asia,
+        sub_region: Some(SubRegion::SouthEasternAsia),
+        capital: Some("Kuala Lumpur"),
+        currencies: &[Currency::MYR],
+        calling_codes: &[60],
+        tld: Some(".my"),
+    },
+    CountryData {
+        country: Country::MYT,
+        alpha2: "YT",
+        alpha3: "MYT",
+        numeric: 175,
+        name: "Mayotte",
+        official_name: None,
+        common_name: None,
+        continent: Continent::Africa,
+        sub_region: Some(SubRegion::SubSaharanAfrica),
+        capital: Some("Mamoudzou"),
+        currencies: &[Currency::EUR],
+        calling_codes: &[262],
+        tld: Some(".yt"),
+    },
+    CountryData {
+        country: Country::NAM,
+        alpha2: "NA",
+        alpha3: "NAM",
+        numeric: 516,
+        name: "Namibia",
+        official_name: Some("Republic of Namibia"),
+        common_name: None,
+        continent: Continent::Africa,
+        sub_region: Some(SubRegion::SubSaharanAfrica),
+        capital: Some("Windhoek"),
+        currencies: &[Currency::NAD, Currency::ZAR],
+        calling_codes: &[264],
+        tld: Some(".na"),
+    },
+    CountryData {
+        country: Country::NCL,
+        alpha2: "NC",
+        alpha3: "NCL",
+        numeric: 540,
+        name: "New Caledonia",
+        official_name: None,
+        common_name: None,
+        continent: Continent::Oceania,
+        sub_region: Some(SubRegion::Melanesia),
+        capital: Some("Nouméa"),
+        currencies: &[Currency::XPF],
+        calling_codes: &[687],
+        tld: Some(".nc"),
+    },
+    CountryData {
+        country: Country::NER,
+        alpha2: "NE",
+        alpha3: "NER",
+        numeric: 562,
+        name: "Niger",
+        official_name: Some("Republic of the Niger"),
+        common_name: None,
+        continent: Continent::Africa,
+        sub_region: Some(SubRegion::SubSaharanAfrica),
+        capital: Some("Niamey"),
+        currencies: &[Currency::XOF],
+        calling_codes: &[227],
+        tld: Some(".ne"),
+    },
+    CountryData {
+        country: Country::NFK,
+        alpha2: "NF",
+        alpha3: "NFK",
+        numeric: 574,
+        name: "Norfolk Island",
+        official_name: None,
+        common_name: None,
+        continent: Continent::Oceania,
+        sub_region: Some(SubRegion::AustraliaAndNewZealand),
+        capital: Some("Kingston"),
+        currencies: &[Currency::AUD],
+        calling_codes: &[672],
+        tld: Some(".nf"),
+    },
+    CountryData {
+        country: Country::NGA,
+        alpha2: "NG",
+        alpha3: "NGA",
+        numeric: 566,
+        name: "Nigeria",
+        official_name: Some("Federal Republic of Nigeria"),
+        common_name: None,
+        continent: Continent::Africa,
+        sub_region: Some(SubRegion::SubSaharanAfrica),
+        capital: Some("Abuja"),
+        currencies: &[Currency::NGN],
+        calling_codes: &[234],
+        tld: Some(".ng"),
+    },
+    CountryData {
+        country: Country::NIC,
+        alpha2: "NI",
+        alpha3: "NIC",
+        numeric: 558,
+        name: "Nicaragua",
+        official_name: Some("Republic of Nicaragua"),
+        common_name: None,
+        continent: Continent::NorthAmerica,
+        sub_region: Some(SubRegion::LatinAmericaAndTheCaribbean),
+        capital: Some("Managua"),
+        currencies: &[Currency::NIO],
+        calling_codes: &[505],
+        tld: Some(".ni"),
+    },
+    CountryData {
+        country: Country::NIU,
+        alpha2: "NU",
+        alpha3: "NIU",
+        numeric: 570,
+        name: "Niue",
+        official_name: None,
+        common_name: None,
+        continent: Continent::Oceania,
+        sub_region: Some(SubRegion::Polynesia),
+        capital: Some("Alofi"),
+        currencies: &[Currency::NZD],
+        calling_codes: &[683],
+        tld: Some(".nu"),
+    },
+    CountryData {
+        country: Country::NLD,
+        alpha2: "NL",
+        alpha3: "NLD",
+        numeric: 528,
+        name: "Netherlands",
+        official_name: Some("Kingdom of the Netherlands"),
+        common_name: None,
+        continent: Continent::Europe,
+        sub_region: Some(SubRegion::WesternEurope),
+        capital: Some("Amsterdam"),
+        currencies: &[Currency::EUR],
+        calling_codes: &[31],
+        tld: Some(".nl"),
+    },
+    CountryData {
+        country: Country::NOR,
+        alpha2: "NO",
+        alpha3: "NOR",
+        numeric: 578,
+        name: "Norway",
+        official_name: Some("Kingdom of Norway"),
+        common_name: None,
+        continent: Continent::Europe,
+        sub_region: Some(SubRegion::NorthernEurope),
+        capital: Some("Oslo"),
+        currencies: &[Currency::NOK],
+        calling_codes: &[47],
+        tld: Some(".no"),
+    },
+    CountryData {
+        country: Country::NPL,
+        alpha2: "NP",
+        alpha3: "NPL",
+        numeric: 524,
+        name: "Nepal",
+        official_name: Some("Federal Democratic Republic of Nepal"),
+        common_name: None,
+        continent: Continent::Asia,
+        sub_region: Some(SubRegion::SouthernAsia),
+        capital: Some("Kathmandu"),
+        currencies: &[Currency::NPR],
+        calling_codes: &[977],
+        tld: Some(".np"),
+    },
+    CountryData {
+        country: Country::NRU,
+        alpha2: "NR",
+        alpha3: "NRU",
+        numeric: 520,
+        name: "Nauru",
+        official_name: Some("Republic of Nauru"),
+        common_name: None,
+        continent: Continent::Oceania,
+        sub_region: Some(SubRegion::Micronesia),
+        capital: Some("Yaren"),
+        currencies: &[Currency::AUD],
+        calling_codes: &[674],
+        tld: Some(".nr"),
+    },
+    CountryData {
+        country: Country::NZL,
+        alpha2: "NZ",
+        alpha3: "NZL",
+        numeric: 554,
+        name: "New Zealand",
+        official_name: None,
+        common_name: None,
+        continent: Continent::Oceania,
+        sub_region: Some(SubRegion::AustraliaAndNewZealand),
+        capital: Some("Wellington"),
+        currencies: &[Currency::NZD],
+        calling_codes: &[64],
+        tld: Some(".nz"),
+    },
+    CountryData {
+        country: Country::OMN,
+        alpha2: "OM",
+        alpha3: "OMN",
+        numeric: 512,
+        name: "Oman",
+        official_name: Some("Sultanate of Oman"),
+        common_name: None,
+        continent: Continent::Asia,
+        sub_region: Some(SubRegion::WesternAsia),
+        capital: Some("Muscat"),
+        currencies: &[Currency::OMR],
+        calling_codes: &[968],
+        tld: Some(".om"),
+    },
+    CountryData {
+        country: Country::PAK,
+        alpha2: "PK",
+        alpha3: "PAK",
+        numeric: 586,
+        name: "Pakistan",
+        official_name: Some("Islamic Republic of Pakistan"),
+        common_name: None,
+        continent: Continent::Asia,
+        sub_region: Some(SubRegion::SouthernAsia),
+        capital: Some("Islamabad"),
+        currencies: &[Currency::PKR],
+        calling_codes: &[92],
+        tld: Some(".pk"),
+    },
+    CountryData {
+        country: Country::PAN,
+        alpha2: "PA",
+        alpha3: "PAN",
+        numeric: 591,
+        name: "Panama",
+        official_name: Some("Republic of Panama"),
+        common_name: None,
+        continent: Continent::NorthAmerica,
+        sub_region: Some(SubRegion::LatinAmericaAndTheCaribbean),
+        capital: Some("Panama City"),
+        currencies: &[Currency::PAB, Currency::USD],
+        calling_codes: &[507],
+        tld: Some(".pa"),
+    },
+    CountryData {
+        country: Country::PCN,
+        alpha2: "PN",
+        alpha3: "PCN",
+        numeric: 612,
+        name: "Pitcairn",
+        official_name: None,
+        common_name: None,
+        continent: Continent::Oceania,
+        sub_region: Some(SubRegion::Polynesia),
+        capital: Some("Adamstown"),
+        currencies: &[Currency::NZD],
+        calling_codes: &[],
+        tld: Some(".pn"),
+    },
+    CountryData {
+        country: Country::PER,
+        alpha2: "PE",
+        alpha3: "PER",
+        numeric: 604,
+        name: "Peru",
+        official_name: Some("Republic of Peru"),
+        common_name: None,
+        continent: Continent::SouthAmerica,
+        sub_region: Some(SubRegion::LatinAmericaAndTheCaribbean),
+        capital: Some("Lima"),
+        currencies: &[Currency::PEN],
+        calling_codes: &[51],
+        tld: Some(".pe"),
+    },
+    CountryData {
+        country: Country::PHL,
+        alpha2: "PH",
+        alpha3: "PHL",
+        numeric: 608,
+        name: "Philippines",
+        official_name: Some("Republic of the Philippines"),
+        common_name: None,
+        continent: Continent::Asia,
+        sub_region: Some(SubRegion::SouthEasternAsia),
+        capital: Some("Manila"),
+        currencies: &[Currency::PHP],
+        calling_codes: &[63],
+        tld: Some(".ph"),
+    },
+    CountryData {
+        country: Country::PLW,
+        alpha2: "PW",
+        alpha3: "PLW",
+        numeric: 585,
+        name: "Palau",
+        official_name: Some("Republic of Palau"),
+        common_name: None,
+        continent: Continent::Oceania,
+        sub_region: Some(SubRegion::Micronesia),
+        capital: Some("Ngerulmud"),
+        currencies: &[Currency::USD],
+        calling_codes: &[680],
+        tld: Some(".pw"),
+    },
+    CountryData {
+        country: Country::PNG,
+        alpha2: "PG",
+        alpha3: "PNG",
+        numeric: 598,
+        name: "Papua New Guinea",
+        official_name: Some("Independent State of Papua New Guinea"),
+        common_name: None,
+        continent: Continent::Oceania,
+        sub_region: Some(SubRegion::Melanesia),
+        capital: Some("Port Moresby"),
+        currencies: &[Currency::PGK],
+        calling_codes: &[675],
+        tld: Some(".pg"),
+    },
+    CountryData {
+        country: Country::POL,
+        alpha2: "PL",
+        alpha3: "POL",
+        numeric: 616,
+        name: "Poland",
+        official_name: Some("Republic of Poland"),
+        common_name: None,
+        continent: Continent::Europe,
+        sub_region: Some(SubRegion::EasternEurope),
+        capital: Some("Warsaw"),
+        currencies: &[Currency::PLN],
+        calling_codes: &[48],
+        tld: Some(".pl"),
+    },
+    CountryData {
+        country: Country::PRI,
+        alpha2: "PR",
+        alpha3: "PRI",
+        numeric: 630,
+        name: "Puerto Rico",
+        official_name: None,
+        common_name: None,
+        continent: Continent::NorthAmerica,
+        sub_region: Some(SubRegion::LatinAmericaAndTheCaribbean),
+        capital: Some("San Juan"),
+        currencies: &[Currency::USD],
+        calling_codes: &[1],
+        tld: Some(".pr"),
+    },
+    CountryData {
+        country: Country::PRK,
+        alpha2: "KP",
+        alpha3: "PRK",
+        numeric: 408,
+        name: "Korea, Democratic People's Republic of",
+        official_name: Some("Democratic People's Republic of Korea"),
+        common_name: Some("North Korea"),
+        continent: Continent::Asia,
+        sub_region: Some(SubRegion::EasternAsia),
+        capital: Some("Pyongyang"),
+        currencies: &[Currency::KPW],
+        calling_codes: &[850],
+        tld: Some(".kp"),
+    },
+    CountryData {
+        country: Country::PRT,
+        alpha2: "PT",
+        alpha3: "PRT",
+        numeric: 620,
+        name: "Portugal",
+        official_name: Some("Portuguese Republic"),
+        common_name: None,
+        continent: Continent::Europe,
+        sub_region: Some(SubRegion::SouthernEurope),
+        capital: Some("Lisbon"),
+        currencies: &[Currency::EUR],
+        calling_codes: &[351],
+        tld: Some(".pt"),
+    },
+    CountryData {
+        country: Country::PRY,
+        alpha2: "PY",
+        alpha3: "PRY",
+        numeric: 600,
+        name: "Paraguay",
+        official_name: Some("Republic of Paraguay"),
+        common_name: None,
+        continent: Continent::SouthAmerica,
+        sub_region: Some(SubRegion::LatinAmericaAndTheCaribbean),
+        capital: Some("Asunción"),
+        currencies: &[Currency::PYG],
+        calling_codes: &[595],
+        tld: Some(".py"),
+    },
+    CountryData {
+        country: Country::PSE,
+        alpha2: "PS",
+        alpha3: "PSE",
+        numeric: 275,
+        name: "Palestine, State of",
+        official_name: Some("State of Palestine"),
+        common_name: Some("Palestine"),
+        continent: Continent::Asia,
+        sub_region: Some(SubRegion::WesternAsia),
+        capital: Some("Ramallah"),
+        currencies: &[Currency::ILS, Currency::JOD],
+        calling_codes: &[970],
+        tld: Some(".ps"),
+    },
+    CountryData {
+        country: Country::PYF,
+        alpha2: "PF",
+        alpha3: "PYF",
+        numeric: 258,
+        name: "French Polynesia",
+        official_name: None,
+        common_name: None,
+        continent: Continent::Oceania,
+        sub_region: Some(SubRegion::Polynesia),
+        capital: Some("Papeete"),
+        currencies: &[Currency::XPF],
+        calling_codes: &[689],
+        tld: Some(".pf"),
+    },
+    CountryData {
+        country: Country::QAT,
+        alpha2: "QA",
+        alpha3: "QAT",
+        numeric: 634,
+        name: "Qatar",
+        official_name: Some("State of Qatar"),
+        common_name: None,
+        continent: Continent::Asia,
+        sub_region: Some(SubRegion::WesternAsia),
+        capital: Some("Doha"),
+        currencies: &[Currency::QAR],
+        calling_codes: &[974],
+        tld: Some(".qa"),
+    },
+    CountryData {
+        country: Country::REU,
+        alpha2: "RE",
+        alpha3: "REU",
+        numeric: 638,
+        name: "Réunion",
+        official_name: None,
+        common_name: None,
+        continent: Continent::Africa,
+        sub_region: Some(SubRegion::SubSaharanAfrica),
+        capital: Some("Saint-Denis"),
+        currencies: &[Currency::EUR],
+        calling_codes: &[262],
+        tld: Some(".re"),
+    },
+    CountryData {
+        country: Country::ROU,
+        alpha2: "RO",
+        alpha3: "ROU",
+        numeric: 642,
+        name: "Romania",
+        official_name: None,
+        common_name: None,
+        continent: Continent::Europe,
+        sub_region: Some(SubRegion::EasternEurope),
+        capital: Some("Bucharest"),
+        currencies: &[Currency::RON],
+        calling_codes: &[40],
+        tld: Some(".ro"),
+    },
+    CountryData {
+        country: Country::RUS,
+        alpha2: "RU",
+        alpha3: "RUS",
+        numeric: 643,
+        name: "Russian Federation",
+        official_name: None,
+        common_name: Some("Russia"),
+        continent: Continent::Europe,
+        sub_region: Some(SubRegion::EasternEurope),
+        capital: Some("Moscow"),
+        currencies: &[Currency::RUB],
+        calling_codes: &[7],
+        tld: Some(".ru"),
+    },
+    CountryData {
+        country: Country::RWA,
+        alpha2: "RW",
+        alpha3: "RWA",
+        numeric: 646,
+        name: "Rwanda",
+        official_name: Some("Rwandese Republic"),
+        common_name: None,
+        continent: Continent::Africa,
+        sub_region: Some(SubRegion::SubSaharanAfrica),
+        capital: Some("Kigali"),
+        currencies: &[Currency::RWF],
+        calling_codes: &[250],
+        tld: Some(".rw"),
+    },
+    CountryData {
+        country: Country::SAU,
+        alpha2: "SA",
+        alpha3: "SAU",
+        numeric: 682,
+        name: "Saudi Arabia",
+        official_name: Some("Kingdom of Saudi Arabia"),
+        common_name: None,
+        continent: Continent::Asia,
+        sub_region: Some(SubRegion::WesternAsia),
+        capital: Some("Riyadh"),
+        currencies: &[Currency::SAR],
+        calling_codes: &[966],
+        tld: Some(".sa"),
+    },
+    CountryData {
+        country: Country::SDN,
+        alpha2: "SD",
+        alpha3: "SDN",
+        numeric: 729,
+        name: "Sudan",
+        official_name: Some("Republic of the Sudan"),
+        common_name: None,
+        continent: Continent::Africa,
+        sub_region: Some(SubRegion::NorthernAfrica),
+        capital: Some("Khartoum"),
+        currencies: &[Currency::SDG],
+        calling_codes: &[249],
+        tld: Some(".sd"),
+    },
+    CountryData {
+        country: Country::SEN,
+        alpha2: "SN",
+        alpha3: "SEN",
+        numeric: 686,
+        name: "Senegal",
+        official_name: Some("Republic of Senegal"),
+        common_name: None,
+        continent: Continent::Africa,
+        sub_region: Some(SubRegion::SubSaharanAfrica),
+        capital: Some("Dakar"),
+        currencies: &[Currency::XOF],
+        calling_codes: &[221],
+        tld: Some(".sn"),
+    },
+    CountryData {
+        country: Country::SGP,
+        alpha2: "SG",
+        alpha3: "SGP",
+        numeric: 702,
+        name: "Singapore",
+        official_name: Some("Republic of Singapore"),
+        common_name: None,
+        continent: Continent::Asia,
+        sub_region: Some(SubRegion::SouthEasternAsia),
+        capital: Some("Singapore"),
+        currencies: &[Currency::SGD],
+        calling_codes: &[65],
+        tld: Some(".sg"),
+    },
+    CountryData {
+        country: Country::SGS,
+        alpha2: "GS",
+        alpha3: "SGS",
+        numeric: 239,
+        name: "South Georgia and the South Sandwich Islands",
+        official_name: None,
+        common_name: None,
+        continent: Continent::SouthAmerica,
+        sub_region: Some(SubRegion::LatinAmericaAndTheCaribbean),
+        capital: Some("King Edward Point"),
+        currencies: &[Currency::GBP],
+        calling_codes: &[],
+        tld: Some(".gs"),
+    },
+    CountryData {
+        country: Country::SHN,
+        alpha2: "SH",
+        alpha3: "SHN",
+        numeric: 654,
+        name: "Saint Helena, Ascension and Tristan da Cunha",
+        official_name: None,
+        common_name: None,
+        continent: Continent::Africa,
+        sub_region: Some(SubRegion::SubSaharanAfrica),
+        capital: Some("Jamestown"),
+        currencies: &[Currency::SHP],
+        calling_codes: &[290],
+        tld: Some(".sh"),
+    },
+    CountryData {
+        country: Country::SJM,
+        alpha2: "SJ",
+        alpha3: "SJM",
+        numeric: 744,
+        name: "Svalbard and Jan Mayen",
+        official_name: None,
+        common_name: None,
+        continent: Continent::Europe,
+        sub_region: Some(SubRegion::NorthernEurope),
+        capital: Some("Longyearbyen"),
+        currencies: &[Currency::NOK],
+        calling_codes: &[47],
+        tld: Some(".sj"),
+    },
+    CountryData {
+        country: Country::SLB,
+        alpha2: "SB",
+        alpha3: "SLB",
+        numeric: 90,
+        name: "Solomon Islands",
+        official_name: None,
+        common_name: None,
+        continent: Continent::Oceania,
+        sub_region: Some(SubRegion::Melanesia),
+        capital: Some("Honiara"),
+        currencies: &[Currency::SBD],
+        calling_codes: &[677],
+        tld: Some(".sb"),
+    },
+    CountryData {
+        country: Country::SLE,
+        alpha2: "SL",
+        alpha3: "SLE",
+        numeric: 694,
+        name: "Sierra Leone",
+        official_name: Some("Republic of Sierra Leone"),
+        common_name: None,
+        continent: Continent::Africa,
+        sub_region: Some(SubRegion::SubSaharanAfrica),
+        capital: Some("Freetown"),
+        currencies: &[Currency::SLE],
+        calling_codes: &[232],
+        tld: Some(".sl"),
+    },
+    CountryData {
+        country: Country::SLV,
+        alpha2: "SV",
+        alpha3: "SLV",
+        numeric: 222,
+        name: "El Salvador",
+        official_name: Some("Republic of El Salvador"),
+        common_name: None,
+        continent: Continent::NorthAmerica,
+        sub_region: Some(SubRegion::LatinAmericaAndTheCaribbean),
+        capital: Some("San Salvador"),
+        currencies: &[Currency::USD],
+        calling_codes: &[503],
+        tld: Some(".sv"),
+    },
+    CountryData {
+        country: Country::SMR,
+        alpha2: "SM",
+        alpha3: "SMR",
+        numeric: 674,
+        name: "San Marino",
+        official_name: Some("Republic of San Marino"),
+        common_name: None,
+        continent: Continent::Europe,
+        sub_region: Some(SubRegion::SouthernEurope),
+        capital: Some("San Marino"),
+        currencies: &[Currency::EUR],
+        calling_codes: &[378],
+        tld: Some(".sm"),
+    },
+    CountryData {
+        country: Country::SOM,
+        alpha2: "SO",
+        alpha3: "SOM",
+        numeric: 706,
+        name: "Somalia",
+        official_name: Some("Federal Republic of Somalia"),
+        common_name: None,
+        continent: Continent::Africa,
+        sub_region: Some(SubRegion::SubSaharanAfrica),
+        capital: Some("Mogadishu"),
+        currencies: &[Currency::SOS],
+        calling_codes: &[252],
+        tld: Some(".so"),
+    },
+    CountryData {
+        country: Country::SPM,
+        alpha2: "PM",
+        alpha3: "SPM",
+        numeric: 666,
+        name: "Saint Pierre and Miquelon",
+        official_name: None,
+        common_name: None,
+        continent: Continent::NorthAmerica,
+        sub_region: Some(SubRegion::NorthernAmerica),
+        capital: Some("Saint-Pierre"),
+        currencies: &[Currency::EUR],
+        calling_codes: &[508],
+        tld: Some(".pm"),
+    },
+    CountryData {
+        country: Country::SRB,
+        alpha2: "RS",
+        alpha3: "SRB",
+        numeric: 688,
+        name: "Serbia",
+        official_name: Some("Republic of Serbia"),
+        common_name: None,
+        continent: Continent::Europe,
+        sub_region: Some(SubRegion::SouthernEurope),
+        capital: Some("Belgrade"),
+        currencies: &[Currency::RSD],
+        calling_codes: &[381],
+        tld: Some(".rs"),
+    },
+    CountryData {
+        country: Country::SSD,
+        alpha2: "SS",
+        alpha3: "SSD",
+        numeric: 728,
+        name: "South Sudan",
+        official_name: Some("Republic of South Sudan"),
+        common_name: None,
+        continent: Continent::Africa,
+        sub_region: Some(SubRegion::SubSaharanAfrica),
+        capital: Some("Juba"),
+        currencies: &[Currency::SSP],
+        calling_codes: &[211],
+        tld: Some(".ss"),
+    },
+    CountryData {
+        country: Country::STP,
+        alpha2: "ST",
+        alpha3: "STP",
+        numeric: 678,
+        name: "Sao Tome and Principe",
+        official_name: Some("Democratic Republic of Sao Tome and Principe"),
+        common_name: None,
+        continent: Continent::Africa,
+        sub_region: Some(SubRegion::SubSaharanAfrica),
+        capital: Some("São Tomé"),
+        currencies: &[Currency::STN],
+        calling_codes: &[239],
+        tld: Some(".st"),
+    },
+    CountryData {
+        country: Country::SUR,
+        alpha2: "SR",
+        alpha3: "SUR",
+        numeric: 740,
+        name: "Suriname",
+        official_name: Some("Republic of Suriname"),
+        common_name: None,
+        continent: Continent::SouthAmerica,
+        sub_region: Some(SubRegion::LatinAmericaAndTheCaribbean),
+        capital: Some("Paramaribo"),
+        currencies: &[Currency::SRD],
+        calling_codes: &[597],
+        tld: Some(".sr"),
+    },
+    CountryData {
+        country: Country::SVK,
+        alpha2: "SK",
+        alpha3: "SVK",
+        numeric: 703,
+        name: "Slovakia",
+        official_name: Some("Slovak Republic"),
+        common_name: None,
+        continent: Continent::Europe,
+        sub_region: Some(SubRegion::EasternEurope),
+        capital: Some("Bratislava"),
+        currencies: &[Currency::EUR],
+        calling_codes: &[421],
+        tld: Some(".sk"),
+    },
+    CountryData {
+        country: Country::SVN,
+        alpha2: "SI",
+        alpha3: "SVN",
+        numeric: 705,
+        name: "Slovenia",
+        official_name: Some("Republic of Slovenia"),
+        common_name: None,
+        continent: Continent::Europe,
+        sub_region: Some(SubRegion::SouthernEurope),
+        capital: Some("Ljubljana"),
+        currencies: &[Currency::EUR],
+        calling_codes: &[386],
+        tld: Some(".si"),
+    },
+    CountryData {
+        country: Country::SWE,
+        alpha2: "SE",
+        alpha3: "SWE",
+        numeric: 752,
+        name: "Sweden",
+        official_name: Some("Kingdom of Sweden"),
+        common_name: None,
+        continent: Continent::Europe,
+        sub_region: Some(SubRegion::NorthernEurope),
+        capital: Some("Stockholm"),
+        currencies: &[Currency::SEK],
+        calling_codes: &[46],
+        tld: Some(".se"),
+    },
+    CountryData {
+        country: Country::SWZ,
+        alpha2: "SZ",
+        alpha3: "SWZ",
+        numeric: 748,
+        name: "Eswatini",
+        official_name: Some("Kingdom of Eswatini"),
+        common_name: Some("Swaziland"),
+        continent: Continent::Africa,
+        sub_region: Some(SubRegion::SubSaharanAfrica),
+        capital: Some("Mbabane"),
+        currencies: &[Currency::SZL, Currency::ZAR],
+        calling_codes: &[268],
+        tld: Some(".sz"),
+    },
+    CountryData {
+        country: Country::SXM,
+        alpha2: "SX",
+        alpha3: "SXM",
+        numeric: 534,
+        name: "Sint Maarten (Dutch part)",
+        official_name: None,
+        common_name: None,
+        continent: Continent::NorthAmerica,
+        sub_region: Some(SubRegion::LatinAmericaAndTheCaribbean),
+        capital: Some("Philipsburg"),
+        currencies: &[Currency::ANG],
+        calling_codes: &[1],
+        tld: Some(".sx"),
+    },
+    CountryData {
+        country: Country::SYC,
+        alpha2: "SC",
+        alpha3: "SYC",
+        numeric: 690,
+        name: "Seychelles",
+        official_name: Some("Republic of Seychelles"),
+        common_name: None,
+        continent: Continent::Africa,
+        sub_region: Some(SubRegion::SubSaharanAfrica),
+        capital: Some("Victoria"),
+        currencies: &[Currency::SCR],
+        calling_codes: &[248],
+        tld: Some(".sc"),
+    },
+    CountryData {
+        country: Country::SYR,
+        alpha2: "SY",
+        alpha3: "SYR",
+        numeric: 760,
+        name: "Syrian Arab Republic",
+        official_name: None,
+        common_name: Some("Syria"),
+        continent: Continent::Asia,
+        sub_region: Some(SubRegion::WesternAsia),
+        capital: Some("Damascus"),
+        currencies: &[Currency::SYP],
+        calling_codes: &[963],
+        tld: Some(".sy"),
+    },
+    CountryData {
+        country: Country::TCA,
+        alpha2: "TC",
+        alpha3: "TCA",
+        numeric: 796,
+        name: "Turks and Caicos Islands",
+        official_name: None,
+        common_name: None,
+        continent: Continent::NorthAmerica,
+        sub_region: Some(SubRegion::LatinAmericaAndTheCaribbean),
+        capital: Some("Cockburn Town"),
+        currencies: &[Currency::USD],
+        calling_codes: &[1],
+        tld: Some(".tc"),
+    },
+    CountryData {
+        country: Country::TCD,
+        alpha2: "TD",
+        alpha3: "TCD",
+        numeric: 148,
+        name: "Chad",
+        official_name: Some("Republic of Chad"),
+        common_name: None,
+        continent: Continent::Africa,
+        sub_region: Some(SubRegion::SubSaharanAfrica),
+        capital: Some("N'Djamena"),
+        currencies: &[Currency::XAF],
+        calling_codes: &[235],
+        tld: Some(".td"),
+    },
+    CountryData {
+        country: Country::TGO,
+        alpha2: "TG",
+        alpha3: "TGO",
+        numeric: 768,
+        name: "Togo",
+        official_name: Some("Togolese Republic"),
+        common_name: None,
+        continent: Continent::Africa,
+        sub_region: Some(SubRegion::SubSaharanAfrica),
+        capital: Some("Lomé"),
+        currencies: &[Currency::XOF],
+        calling_codes: &[228],
+        tld: Some(".tg"),
+    },
+    CountryData {
+        country: Country::THA,
+        alpha2: "TH",
+        alpha3: "THA",
+        numeric: 764,
+        name: "Thailand",
+        official_name: Some("Kingdom of Thailand"),
+        common_name: None,
+        continent: Continent::Asia,
+        sub_region: Some(SubRegion::SouthEasternAsia),
+        capital: Some("Bangkok"),
+        currencies: &[Currency::THB],
+        calling_codes: &[66],
+        tld: Some(".th"),
+    },
+    CountryData {
+        country: Country::TJK,
+        alpha2: "TJ",
+        alpha3: "TJK",
+        numeric: 762,
+        name: "Tajikistan",
+        official_name: Some("Republic of Tajikistan"),
+        common_name: None,
+        continent: Continent::Asia,
+        sub_region: Some(SubRegion::CentralAsia),
+        capital: Some("Dushanbe"),
+        currencies: &[Currency::TJS],
+        calling_codes: &[992],
+        tld: Some(".tj"),
+    },
+    CountryData {
+        country: Country::TKL,
+        alpha2: "TK",
+        alpha3: "TKL",
+        numeric: 772,
+        name: "Tokelau",
+        official_name: None,
+        common_name: None,
+        continent: Continent::Oceania,
+        sub_region: Some(SubRegion::Polynesia),
+        capital: None,
+        currencies: &[Currency::NZD],
+        calling_codes: &[690],
+        tld: Some(".tk"),
+    },
+    CountryData {
+        country: Country::TKM,
+        alpha2: "TM",
+        alpha3: "TKM",
+        numeric: 795,
+        name: "Turkmenistan",
+        official_name: None,
+        common_name: None,
+        continent: Continent::Asia,
+        sub_region: Some(SubRegion::CentralAsia),
+        capital: Some("Ashgabat"),
+        currencies: &[Currency::TMT],
+        calling_codes: &[993],
+        tld: Some(".tm"),
+    },
+    CountryData {
+        country: Country::TLS,
+        alpha2: "TL",
+        alpha3: "TLS",
+        numeric: 626,
+        name: "Timor-Leste",
+        official_name: Some("Democratic Republic of Timor-Leste"),
+        common_name: Some("East Timor"),
+        continent: Continent::Asia,
+        sub_region: Some(SubRegion::SouthEasternAsia),
+        capital: Some("Dili"),
+        currencies: &[Currency::USD],
+        calling_codes: &[670],
+        tld: Some(".tl"),
+    },
+    CountryData {
+        country: Country::TON,
+        alpha2: "TO",
+        alpha3: "TON",
+        numeric: 776,
+        name: "Tonga",
+        official_name: Some("Kingdom of Tonga"),
+        common_name: None,
+        continent: Continent::Oceania,
+        sub_region: Some(SubRegion::Polynesia),
+        capital: Some("Nuku'alofa"),
+        currencies: &[Currency::TOP],
+        calling_codes: &[676],
+        tld: Some(".to"),
+    },
+    CountryData {
+        country: Country::TTO,
+        alpha2: "TT",
+        alpha3: "TTO",
+        numeric: 780,
+        name: "Trinidad and Tobago",
+        official_name: Some("Republic of Trinidad and Tobago"),
+        common_name: None,
+        continent: Continent::NorthAmerica,
+        sub_region: Some(SubRegion::LatinAmericaAndTheCaribbean),
+        capital: Some("Port of Spain"),
+        currencies: &[Currency::TTD],
+        calling_codes: &[1],
+        tld: Some(".tt"),
+    },
+    CountryData {
+        country: Country::TUN,
+        alpha2: "TN",
+        alpha3: "TUN",
+        numeric: 788,
+        name: "Tunisia",
+        official_name: Some("Republic of Tunisia"),
+        common_name: None,
+        continent: Continent::Africa,
+        sub_region: Some(SubRegion::NorthernAfrica),
+        capital: Some("Tunis"),
+        currencies: &[Currency::TND],
+        calling_codes: &[216],
+        tld: Some(".tn"),
+    },
+    CountryData {
+        country: Country::TUR,
+        alpha2: "TR",
+        alpha3: "TUR",
+        numeric: 792,
+        name: "Türkiye",
+        official_name: Some("Republic of Türkiye"),
+        common_name: Some("Turkey"),
+        continent: Continent::Asia,
+        sub_region: Some(SubRegion::WesternAsia),
+        capital: Some("Ankara"),
+        currencies: &[Currency::TRY],
+        calling_codes: &[90],
+        tld: Some(".tr"),
+    },
+    CountryData {
+        country: Country::TUV,
+        alpha2: "TV",
+        alpha3: "TUV",
+        numeric: 798,
+        name: "Tuvalu",
+        official_name: None,
+        common_name: None,
+        continent: Continent::Oceania,
+        sub_region: Some(SubRegion::Polynesia),
+        capital: Some("Funafuti"),
+        currencies: &[Currency::AUD],
+        calling_codes: &[688],
+        tld: Some(".tv"),
+    },
+    CountryData {
+        country: Country::TWN,
+        alpha2: "TW",
+        alpha3: "TWN",
+        numeric: 158,
+        name: "Taiwan, Province of China",
+        official_name: None,
+        common_name: Some("Taiwan"),
+        continent: Continent::Asia,
+        sub_region: Some(SubRegion::EasternAsia),
+        capital: Some("Taipei"),
+        currencies: &[Currency::TWD],
+        calling_codes: &[886],
+        tld: Some(".tw"),
+    },
+    CountryData {
+        country: Country::TZA,
+        alpha2: "TZ",
+        alpha3: "TZA",
+        numeric: 834,
+        name: "Tanzania, United Republic of",
+        official_name: Some("United Republic of Tanzania"),
+        common_name: Some("Tanzania"),
+        continent: Continent::Africa,
+        sub_region: Some(SubRegion::SubSaharanAfrica),
+        capital: Some("Dodoma"),
+        currencies: &[Currency::TZS],
+        calling_codes: &[255],
+        tld: Some(".tz"),
+    },
+    CountryData {
+        country: Country::UGA,
+        alpha2: "UG",
+        alpha3: "UGA",
+        numeric: 800,
+        name: "Uganda",
+        official_name: Some("Republic of Uganda"),
+        common_name: None,
+        continent: Continent::Africa,
+        sub_region: Some(SubRegion::SubSaharanAfrica),
+        capital: Some("Kampala"),
+        currencies: &[Currency::UGX],
+        calling_codes: &[256],
+        tld: Some(".ug"),
+    },
+    CountryData {
+        country: Country::UKR,
+        alpha2: "UA",
+        alpha3: "UKR",
+        numeric: 804,
+        name: "Ukraine",
+        official_name: None,
+        common_name: None,
+        continent: Continent::Europe,
+        sub_region: Some(SubRegion::EasternEurope),
+        capital: Some("Kyiv"),
+        currencies: &[Currency::UAH],
+        calling_codes: &[380],
+        tld: Some(".ua"),
+    },
+    CountryData {
+        country: Country::UMI,
+        alpha2: "UM",
+        alpha3: "UMI",
+        numeric: 581,
+        name: "United States Minor Outlying Islands",
+        official_name: None,
+        common_name: None,
+        continent: Continent::Oceania,
+        sub_region: Some(SubRegion::Micronesia),
+        capital: None,
+        currencies: &[Currency::USD],
+        calling_codes: &[],
+        tld: None,
+    },
+    CountryData {
+        country: Country::URY,
+        alpha2: "UY",
+        alpha3: "URY",
+        numeric: 858,
+        name: "Uruguay",
+        official_name: Some("Eastern Republic of Uruguay"),
+        common_name: None,
+        continent: Continent::SouthAmerica,
+        sub_region: Some(SubRegion::LatinAmericaAndTheCaribbean),
+        capital: Some("Montevideo"),
+        currencies: &[Currency::UYU],
+        calling_codes: &[598],
+        tld: Some(".uy"),
+    },
+    CountryData {
+        country: Country::USA,
+        alpha2: "US",
+        alpha3: "USA",
+        numeric: 840,
+        name: "United States",
+        official_name: Some("United States of America"),
+        common_name: None,
+        continent: Continent::NorthAmerica,
+        sub_region: Some(SubRegion::NorthernAmerica),
+        capital: Some("Washington, D.C."),
+        currencies: &[Currency::USD],
+        calling_codes: &[1],
+        tld: Some(".us"),
+    },
+    CountryData {
+        country: Country::UZB,
+        alpha2: "UZ",
+        alpha3: "UZB",
+        numeric: 860,
+        name: "Uzbekistan",
+        official_name: Some("Republic of Uzbekistan"),
+        common_name: None,
+        continent: Continent::Asia,
+        sub_region: Some(SubRegion::CentralAsia),
+        capital: Some("Tashkent"),
+        currencies: &[Currency::UZS],
+        calling_codes: &[998],
+        tld: Some(".uz"),
+    },
+    CountryData {
+        country: Country::VAT,
+        alpha2: "VA",
+        alpha3: "VAT",
+        numeric: 336,
+        name: "Holy See (Vatican City State)",
+        official_name: None,
+        common_name: Some("Vatican City"),
+        continent: Continent::Europe,
+        sub_region: Some(SubRegion::SouthernEurope),
+        capital: Some("Vatican City"),
+        currencies: &[Currency::EUR],
+        calling_codes: &[39, 379],
+        tld: Some(".va"),
+    },
+    CountryData {
+        country: Country::VCT,
+        alpha2: "VC",
+        alpha3: "VCT",
+        numeric: 670,
+        name: "Saint Vincent and the Grenadines",
+        official_name: None,
+        common_name: None,
+        continent: Continent::NorthAmerica,
+        sub_region: Some(SubRegion::LatinAmericaAndTheCaribbean),
+        capital: Some("Kingstown"),
+        currencies: &[Currency::XCD],
+        calling_codes: &[1],
+        tld: Some(".vc"),
+    },
+    CountryData {
+        country: Country::VEN,
+        alpha2: "VE",
+        alpha3: "VEN",
+        numeric: 862,
+        name: "Venezuela, Bolivarian Republic of",
+        official_name: Some("Bolivarian Republic of Venezuela"),
+        common_name: Some("Venezuela"),
+        continent: Continent::SouthAmerica,
+        sub_region: Some(SubRegion::LatinAmericaAndTheCaribbean),
+        capital: Some("Caracas"),
+        currencies: &[Currency::VES],
+        calling_codes: &[58],
+        tld: Some(".ve"),
+    },
+    CountryData {
+        country: Country::VGB,
+        alpha2: "VG",
+        alpha3: "VGB",
+        numeric: 92,
+        name: "Virgin Islands, British",
+        official_name: Some("British Virgin Islands"),
+        common_name: Some("British Virgin Islands"),
+        continent: Continent::NorthAmerica,
+        sub_region: Some(SubRegion::LatinAmericaAndTheCaribbean),
+        capital: Some("Road Town"),
+        currencies: &[Currency::USD],
+        calling_codes: &[1],
+        tld: Some(".vg"),
+    },
+    CountryData {
+        country: Country::VIR,
+        alpha2: "VI",
+        alpha3: "VIR",
+        numeric: 850,
+        name: "Virgin Islands, U.S.",
+        official_name: Some("Virgin Islands of the United States"),
+        common_name: Some("U.S. Virgin Islands"),
+        continent: Continent::NorthAmerica,
+        sub_region: Some(SubRegion::LatinAmericaAndTheCaribbean),
+        capital: Some("Charlotte Amalie"),
+        currencies: &[Currency::USD],
+        calling_codes: &[1],
+        tld: Some(".vi"),
+    },
+    CountryData {
+        country: Country::VNM,
+        alpha2: "VN",
+        alpha3: "VNM",
+        numeric: 704,
+        name: "Viet Nam",
+        official_name: Some("Socialist Republic of Viet Nam"),
+        common_name: Some("Vietnam"),
+        continent: Continent::Asia,
+        sub_region: Some(SubRegion::SouthEasternAsia),
+        capital: Some("Hanoi"),
+        currencies: &[Currency::VND],
+        calling_codes: &[84],
+        tld: Some(".vn"),
+    },
+    CountryData {
+        country: Country::VUT,
+        alpha2: "VU",
+        alpha3: "VUT",
+        numeric: 548,
+        name: "Vanuatu",
+        official_name: Some("Republic of Vanuatu"),
+        common_name: None,
+        continent: Continent::Oceania,
+        sub_region: Some(SubRegion::Melanesia),
+        capital: Some("Port Vila"),
+        currencies: &[Currency::VUV],
+        calling_codes: &[678],
+        tld: Some(".vu"),
+    },
+    CountryData {
+        country: Country::WLF,
+        alpha2: "WF",
+        alpha3: "WLF",
+        numeric: 876,
+        name: "Wallis and Futuna",
+        official_name: None,
+        common_name: None,
+        continent: Continent::Oceania,
+        sub_region: Some(SubRegion::Polynesia),
+        capital: Some("Mata-Utu"),
+        currencies: &[Currency::XPF],
+        calling_codes: &[681],
+        tld: Some(".wf"),
+    },
+    CountryData {
+        country: Country::WSM,
+        alpha2: "WS",
+        alpha3: "WSM",
+        numeric: 882,
+        name: "Samoa",
+        official_name: Some("Independent State of Samoa"),
+        common_name: None,
+        continent: Continent::Oceania,
+        sub_region: Some(SubRegion::Polynesia),
+        capital: Some("Apia"),
+        currencies: &[Currency::WST],
+        calling_codes: &[685],
+        tld: Some(".ws"),
+    },
+    CountryData {
+        country: Country::YEM,
+        alpha2: "YE",
+        alpha3: "YEM",
+        numeric: 887,
+        name: "Yemen",
+        official_name: Some("Republic of Yemen"),
+        common_name: None,
+        continent: Continent::Asia,
+        sub_region: Some(SubRegion::WesternAsia),
+        capital: Some("Sana'a"),
+        currencies: &[Currency::YER],
+        calling_codes: &[967],
+        tld: Some(".ye"),
+    },
+    CountryData {
+        country: Country::ZAF,
+        alpha2: "ZA",
+        alpha3: "ZAF",
+        numeric: 710,
+        name: "South Africa",
+        official_name: Some("Republic of South Africa"),
+        common_name: None,
+        continent: Continent::Africa,
+        sub_region: Some(SubRegion::SubSaharanAfrica),
+        capital: Some("Pretoria"),
+        currencies: &[Currency::ZAR],
+        calling_codes: &[27],
+        tld: Some(".za"),
+    },
+    CountryData {
+        country: Country::ZMB,
+        alpha2: "ZM",
+        alpha3: "ZMB",
+        numeric: 894,
+        name: "Zambia",
+        official_name: Some("Republic of Zambia"),
+        common_name: None,
+        continent: Continent::Africa,
+        sub_region: Some(SubRegion::SubSaharanAfrica),
+        capital: Some("Lusaka"),
+        currencies: &[Currency::ZMW],
+        calling_codes: &[260],
+        tld: Some(".zm"),
+    },
+    CountryData {
+        country: Country::ZWE,
+        alpha2: "ZW",
+        alpha3: "ZWE",
+        numeric: 716,
+        name: "Zimbabwe",
+        official_name: Some("Republic of Zimbabwe"),
+        common_name: None,
+        continent: Continent::Africa,
+        sub_region: Some(SubRegion::SubSaharanAfrica),
+        capital: Some("Harare"),
+        currencies: &[Currency::ZWL],
+        calling_codes: &[263],
+        tld: Some(".zw"),
+    },
+];
@@ -0,0 +1,144 @@
+use super::country::Country;
+use crate::result::ValobsError;
+use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+/// Serializes a [Country] as its ISO 3166-1 alpha-2 code, such as `"US"`.
+///
+/// ## Example
+///
+/// ```
+/// use serde::{Deserialize, Serialize};
+/// use valobs::geography::{Country, country_serde};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Shipment {
+///   #[serde(with = "country_serde::alpha2")]
+///   destination: Country,
+/// }
+///
+/// let json = serde_json::to_string(&Shipment { destination: Country::USA }).unwrap();
+///
+/// assert_eq!(json, r#"{"destination":"US"}"#);
+/// ```
+pub mod alpha2 {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(country: &Country, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(country.alpha2())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Country, D::Error> {
+        let code = String::deserialize(deserializer)?;
+        Country::from_alpha2(&code.to_ascii_uppercase()).ok_or_else(|| {
+            D::Error::custom(ValobsError::UnknownCode {
+                field: "country",
+                value: code,
+            })
+        })
+    }
+}
+
+/// Serializes a [Country] as its ISO 3166-1 numeric code, such as `840` for the United States.
+///
+/// ## Example
+///
+/// ```
+/// use serde::{Deserialize, Serialize};
+/// use valobs::geography::{Country, country_serde};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Shipment {
+///   #[serde(with = "country_serde::numeric")]
+///   destination: Country,
+/// }
+///
+/// let json = serde_json::to_string(&Shipment { destination: Country::USA }).unwrap();
+///
+/// assert_eq!(json, r#"{"destination":840}"#);
+/// ```
+pub mod numeric {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(country: &Country, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u16(country.numeric())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Country, D::Error> {
+        Country::try_from(u16::deserialize(deserializer)?).map_err(D::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Serialize;
+
+    /// A card payment, where the terminal reports its country with a numeric code,
+    /// and the merchant is registered with an alpha-2 code.
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct CardPayment {
+        #[serde(with = "numeric")]
+        terminal_country: Country,
+        #[serde(with = "alpha2")]
+        merchant_country: Country,
+    }
+
+    #[test]
+    fn serialize_card_payment_countries() {
+        // Arrange
+        let payment = CardPayment {
+            terminal_country: Country::FRA,
+            merchant_country: Country::IRL,
+        };
+
+        // Act
+        let json = serde_json::to_string(&payment).unwrap();
+
+        // Assert
+        assert_eq!(json, r#"{"terminal_country":250,"merchant_country":"IE"}"#);
+    }
+
+    #[test]
+    fn deserialize_alpha2_code_in_any_case() {
+        // Act
+        let result = serde_json::from_str::<CardPayment>(
+            r#"{"terminal_country":4,"merchant_country":"ie"}"#,
+        )
+        .unwrap();
+
+        // Assert
+        assert_eq!(result.terminal_country, Country::AFG);
+        assert_eq!(result.merchant_country, Country::IRL);
+    }
+
+    #[test]
+    fn every_country_keeps_its_codes() {
+        for country in Country::all() {
+            // Arrange
+            let payment = CardPayment {
+                terminal_country: country,
+                merchant_country: country,
+            };
+
+            // Act
+            let json = serde_json::to_string(&payment).unwrap();
+            let result = serde_json::from_str::<CardPayment>(&json).unwrap();
+
+            // Assert
+            assert_eq!(result, payment, "{json}");
+        }
+    }
+
+    #[test]
+    fn fails_to_deserialize_codes_of_another_kind() {
+        for json in [
+            r#"{"terminal_country":250,"merchant_country":"IRL"}"#,
+            r#"{"terminal_country":250,"merchant_country":"Ireland"}"#,
+            r#"{"terminal_country":250,"merchant_country":"XK"}"#,
+            r#"{"terminal_country":"250","merchant_country":"IE"}"#,
+            r#"{"terminal_country":999,"merchant_country":"IE"}"#,
+        ] {
+            assert!(serde_json::from_str::<CardPayment>(json).is_err(), "{json}");
+        }
+    }
+}
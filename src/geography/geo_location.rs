@@ -1,6 +1,6 @@
 use {
     crate::geography::{Altitude, Latitude, Longitude},
-    crate::result::ValobsResult,
+    crate::result::{ValidationReport, ValidationResult, ValobsResult},
     serde::{Deserialize, Serialize},
 };
 
@@ -50,6 +50,36 @@ impl GeoLocation {
         })
     }
 
+    /// Creates a new `GeoLocation` instance, checking every coordinate.
+    ///
+    /// Unlike [GeoLocation::new], which stops at the first invalid coordinate,
+    /// this reports all of the invalid coordinates at once.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use valobs::geography::GeoLocation;
+    ///
+    /// let report = GeoLocation::validate(91.0, 60.0, 10001.0).unwrap_err();
+    ///
+    /// assert_eq!(report.fields().collect::<Vec<_>>(), vec!["latitude", "altitude"]);
+    /// ```
+    pub fn validate(latitude: f64, longitude: f64, altitude: f64) -> ValidationResult<Self> {
+        let mut report = ValidationReport::new();
+        let latitude = report.check(Latitude::new(latitude));
+        let longitude = report.check(Longitude::new(longitude));
+        let altitude = report.check(Altitude::new(altitude));
+
+        match (latitude, longitude, altitude) {
+            (Some(latitude), Some(longitude), Some(altitude)) => Ok(Self {
+                latitude,
+                longitude,
+                altitude,
+            }),
+            _ => Err(report),
+        }
+    }
+
     /// Creates a new `GeoLocation` instance without `Altitude`.
     /// This is useful when you don't have altitude information.
     /// For example, when you only have latitude and longitude from a GPS device.
@@ -104,6 +134,30 @@ pub mod tests {
         assert!(GeoLocation::new(60.0, 60.0, 10001.0).is_err());
     }
 
+    #[test]
+    fn validate_geo_location_with_valid_data() -> ValidationResult<()> {
+        // Act
+        let result = GeoLocation::validate(60.0, 60.0, 1000.0)?;
+
+        // Assert
+        assert_eq!(result, GeoLocation::new(60.0, 60.0, 1000.0).unwrap());
+
+        Ok(())
+    }
+
+    #[test]
+    fn validate_geo_location_reports_every_invalid_coordinate() {
+        // Act
+        let result = GeoLocation::validate(91.0, 181.0, f64::NAN);
+
+        // Assert
+        let report = result.unwrap_err();
+        assert_eq!(
+            report.fields().collect::<Vec<_>>(),
+            vec!["latitude", "longitude", "altitude"]
+        );
+    }
+
     #[test]
     fn create_geo_location_without_altitude() {
         assert!(GeoLocation::new_without_altitude(60.0, 60.0).is_ok());
@@ -119,7 +173,7 @@ pub mod tests {
         let result = location1 == location2;
 
         // Assert
-        assert_eq!(result, true);
+        assert!(result);
 
         Ok(())
     }
@@ -134,7 +188,7 @@ pub mod tests {
         let result = location1 == location2;
 
         // Assert
-        assert_eq!(result, false);
+        assert!(!result);
 
         Ok(())
     }
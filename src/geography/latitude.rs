@@ -1,4 +1,4 @@
-use crate::result::ValobsResult;
+use crate::result::{ValobsError, ValobsResult};
 use serde::{Deserialize, Serialize};
 
 /// A value object representing a latitude.
@@ -29,14 +29,22 @@ pub struct Latitude(f64);
 impl Latitude {
     /// Create a new `Latitude` instance.
     pub fn new(latitude: f64) -> ValobsResult<Self> {
-        if latitude < -90.0 || latitude > 90.0 {
-            return Err("Latitude must be between -90 and 90".into());
-        }
         if latitude.is_nan() {
-            return Err("Latitude must not be NaN".into());
+            return Err(ValobsError::NotANumber { field: "latitude" });
         }
         if latitude.is_infinite() {
-            return Err("Latitude must not be infinite".into());
+            return Err(ValobsError::Infinite {
+                field: "latitude",
+                value: latitude,
+            });
+        }
+        if !(-90.0..=90.0).contains(&latitude) {
+            return Err(ValobsError::OutOfRange {
+                field: "latitude",
+                value: latitude,
+                min: -90.0,
+                max: 90.0,
+            });
         }
 
         Ok(Self(latitude))
@@ -69,6 +77,30 @@ mod tests {
         assert!(Latitude::new(f64::NEG_INFINITY).is_err());
     }
 
+    #[test]
+    fn fails_with_structured_error_for_invalid_data() {
+        assert_eq!(
+            Latitude::new(90.1),
+            Err(ValobsError::OutOfRange {
+                field: "latitude",
+                value: 90.1,
+                min: -90.0,
+                max: 90.0,
+            })
+        );
+        assert_eq!(
+            Latitude::new(f64::NAN),
+            Err(ValobsError::NotANumber { field: "latitude" })
+        );
+        assert_eq!(
+            Latitude::new(f64::INFINITY),
+            Err(ValobsError::Infinite {
+                field: "latitude",
+                value: f64::INFINITY,
+            })
+        );
+    }
+
     #[test]
     fn equality_between_two_latitude_values() -> ValobsResult<()> {
         // Arrange
@@ -79,7 +111,7 @@ mod tests {
         let result = latitude1 == latitude2;
 
         // Assert
-        assert_eq!(result, true);
+        assert!(result);
 
         Ok(())
     }
@@ -94,7 +126,7 @@ mod tests {
         let result = latitude1 == latitude2;
 
         // Assert
-        assert_eq!(result, false);
+        assert!(!result);
 
         Ok(())
     }
@@ -1,4 +1,4 @@
-use crate::result::ValobsResult;
+use crate::result::{ValobsError, ValobsResult};
 use serde::{Deserialize, Serialize};
 
 /// A value object representing a longitude.
@@ -30,14 +30,22 @@ pub struct Longitude(f64);
 impl Longitude {
     /// Creates a new `Longitude` instance.
     pub fn new(longitude: f64) -> ValobsResult<Self> {
-        if longitude < -180.0 || longitude > 180.0 {
-            return Err("Longitude must be between -180 and 180".into());
-        }
         if longitude.is_nan() {
-            return Err("Longitude must not be NaN".into());
+            return Err(ValobsError::NotANumber { field: "longitude" });
         }
         if longitude.is_infinite() {
-            return Err("Longitude must not be infinite".into());
+            return Err(ValobsError::Infinite {
+                field: "longitude",
+                value: longitude,
+            });
+        }
+        if !(-180.0..=180.0).contains(&longitude) {
+            return Err(ValobsError::OutOfRange {
+                field: "longitude",
+                value: longitude,
+                min: -180.0,
+                max: 180.0,
+            });
         }
 
         Ok(Self(longitude))
@@ -70,6 +78,30 @@ mod tests {
         assert!(Longitude::new(f64::NEG_INFINITY).is_err());
     }
 
+    #[test]
+    fn fails_with_structured_error_for_invalid_data() {
+        assert_eq!(
+            Longitude::new(180.1),
+            Err(ValobsError::OutOfRange {
+                field: "longitude",
+                value: 180.1,
+                min: -180.0,
+                max: 180.0,
+            })
+        );
+        assert_eq!(
+            Longitude::new(f64::NAN),
+            Err(ValobsError::NotANumber { field: "longitude" })
+        );
+        assert_eq!(
+            Longitude::new(f64::INFINITY),
+            Err(ValobsError::Infinite {
+                field: "longitude",
+                value: f64::INFINITY,
+            })
+        );
+    }
+
     #[test]
     fn equality_between_two_longitude_values() -> ValobsResult<()> {
         // Arrange
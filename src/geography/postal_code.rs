@@ -0,0 +1,456 @@
+use {
+    super::Country,
+    crate::{
+        result::{ValobsError, ValobsResult},
+        traits::ValueObject,
+    },
+    serde::{Deserialize, Serialize},
+    std::fmt,
+};
+
+/// Countries that don't use postal codes in their addresses.
+const WITHOUT_POSTAL_CODES: &[Country] = {
+    use Country::*;
+
+    &[
+        ABW, AGO, ARE, ATA, ATG, BDI, BEN, BES, BFA, BHS, BLZ, BOL, BVT, BWA, CAF, CIV, CMR, COD,
+        COG, COK, COM, CUW, DJI, DMA, ERI, FJI, GHA, GMB, GNQ, GRD, GUY, HKG, KIR, KNA, LCA, MAC,
+        MLI, MRT, MWI, NIU, NRU, PRK, QAT, RWA, SLB, SLE, STP, SUR, SXM, SYC, SYR, TCD, TGO, TKL,
+        TLS, TON, TUV, UGA, VUT, YEM, ZWE,
+    ]
+};
+
+/// The formats of the postal codes of each country, where `#` is a digit, `@` is a letter,
+/// `*` is a digit or a letter, and any other character is written as is.
+///
+/// Countries that are not listed accept any postal code of letters, digits, spaces and hyphens.
+const POSTAL_CODE_FORMATS: &[(Country, &[&str])] = {
+    use Country::*;
+
+    const UK: &[&str] = &[
+        "@# #@@", "@## #@@", "@@# #@@", "@@## #@@", "@#@ #@@", "@@#@ #@@", "GIR 0AA",
+    ];
+    const US: &[&str] = &["#####", "#####-####"];
+    const THREE: &[&str] = &["###"];
+    const FOUR: &[&str] = &["####"];
+    const FIVE: &[&str] = &["#####"];
+    const SIX: &[&str] = &["######"];
+    const SEVEN: &[&str] = &["#######"];
+
+    &[
+        (ALB, FOUR),
+        (AND, &["AD###"]),
+        (ARG, &["####", "@####@@@"]),
+        (ARM, FOUR),
+        (ASM, US),
+        (AUS, FOUR),
+        (AUT, FOUR),
+        (AZE, &["AZ ####"]),
+        (BEL, FOUR),
+        (BGD, FOUR),
+        (BGR, FOUR),
+        (BHR, &["###", "####"]),
+        (BIH, FIVE),
+        (BLR, SIX),
+        (BRA, &["#####-###"]),
+        (BRN, &["@@####"]),
+        (CAN, &["@#@ #@#"]),
+        (CHE, FOUR),
+        (CHL, SEVEN),
+        (CHN, SIX),
+        (COL, SIX),
+        (CRI, FIVE),
+        (CUB, FIVE),
+        (CYP, FOUR),
+        (CZE, &["### ##"]),
+        (DEU, FIVE),
+        (DNK, FOUR),
+        (DOM, FIVE),
+        (DZA, FIVE),
+        (ECU, SIX),
+        (EGY, FIVE),
+        (ESP, FIVE),
+        (EST, FIVE),
+        (ETH, FOUR),
+        (FIN, FIVE),
+        (FRA, FIVE),
+        (FRO, THREE),
+        (GBR, UK),
+        (GEO, FOUR),
+        (GGY, &["GY# #@@", "GY## #@@"]),
+        (GIB, &["GX11 1AA"]),
+        (GRC, &["### ##"]),
+        (GRL, FOUR),
+        (GTM, FIVE),
+        (GUM, US),
+        (HND, FIVE),
+        (HRV, FIVE),
+        (HTI, FOUR),
+        (HUN, FOUR),
+        (IDN, FIVE),
+        (IMN, &["IM# #@@", "IM## #@@"]),
+        (IND, SIX),
+        (IRL, &["@** ****"]),
+        (IRN, &["#####-#####"]),
+        (IRQ, FIVE),
+        (ISL, THREE),
+        (ISR, SEVEN),
+        (ITA, FIVE),
+        (JEY, &["JE# #@@"]),
+        (JOR, FIVE),
+        (JPN, &["###-####"]),
+        (KAZ, SIX),
+        (KEN, FIVE),
+        (KGZ, SIX),
+        (KHM, FIVE),
+        (KOR, FIVE),
+        (KWT, FIVE),
+        (LAO, FIVE),
+        (LBN, &["#####", "#### ####"]),
+        (LIE, FOUR),
+        (LKA, FIVE),
+        (LTU, &["LT-#####"]),
+        (LUX, FOUR),
+        (LVA, &["LV-####"]),
+        (MAR, FIVE),
+        (MCO, FIVE),
+        (MDA, &["MD-####"]),
+        (MDG, THREE),
+        (MEX, FIVE),
+        (MKD, FOUR),
+        (MLT, &["@@@ ####"]),
+        (MMR, FIVE),
+        (MNE, FIVE),
+        (MNG, FIVE),
+        (MNP, US),
+        (MOZ, FOUR),
+        (MUS, FIVE),
+        (MYS, FIVE),
+        (NGA, SIX),
+        (NIC, FIVE),
+        (NLD, &["#### @@"]),
+        (NOR, FOUR),
+        (NPL, FIVE),
+        (NZL, FOUR),
+        (OMN, THREE),
+        (PAK, FIVE),
+        (PER, FIVE),
+        (PHL, FOUR),
+        (PNG, THREE),
+        (POL, &["##-###"]),
+        (PRI, US),
+        (PRT, &["####-###"]),
+        (PRY, FOUR),
+        (ROU, SIX),
+        (RUS, SIX),
+        (SAU, &["#####", "#####-####"]),
+        (SEN, FIVE),
+        (SGP, SIX),
+        (SMR, FIVE),
+        (SRB, FIVE),
+        (SVK, &["### ##"]),
+        (SVN, FOUR),
+        (SWE, &["### ##"]),
+        (THA, FIVE),
+        (TJK, SIX),
+        (TKM, SIX),
+        (TUN, FOUR),
+        (TUR, FIVE),
+        (TWN, &["###", "#####", "######"]),
+        (TZA, FIVE),
+        (UKR, FIVE),
+        (URY, FIVE),
+        (USA, US),
+        (UZB, SIX),
+        (VAT, FIVE),
+        (VEN, FOUR),
+        (VIR, US),
+        (VNM, SIX),
+        (ZAF, FOUR),
+        (ZMB, FIVE),
+    ]
+};
+
+/// A value object representing the postal code of an address in a given country.
+///
+/// ## What is a _Postal Code_?
+///
+/// A postal code, also known as a ZIP code in the United States or a postcode in the United Kingdom,
+/// is a series of letters and digits that identifies a delivery area. Each country has its own format,
+/// such as `12345-6789` in the United States, `SW1A 1AA` in the United Kingdom or `123-4567` in Japan.
+/// Some countries, such as Hong Kong or Qatar, don't use postal codes at all.
+///
+/// ## When to use
+///
+/// Use this type when you want to make sure a postal code is valid for its country,
+/// and store it in a canonical form, regardless of how the user typed it.
+///
+/// ## Example
+///
+/// ```
+/// use valobs::geography::{Country, PostalCode};
+/// use valobs::result::{ValobsError, ValobsResult};
+///
+/// fn main() -> ValobsResult<()> {
+///   // Spacing and case are normalized
+///   assert_eq!(PostalCode::new(Country::CAN, "k1a0b1")?.as_str(), "K1A 0B1");
+///   assert_eq!(PostalCode::new(Country::GBR, " sw1a  1aa")?.as_str(), "SW1A 1AA");
+///   assert_eq!(PostalCode::new(Country::JPN, "1000001")?.as_str(), "100-0001");
+///   assert_eq!(PostalCode::new(Country::USA, "12345 6789")?.as_str(), "12345-6789");
+///
+///   assert!(PostalCode::new(Country::USA, "1234").is_err());
+///
+///   // Some countries don't have postal codes
+///   assert!(!PostalCode::is_used_in(Country::HKG));
+///   assert_eq!(
+///     PostalCode::new(Country::HKG, "999077"),
+///     Err(ValobsError::NotAllowed { field: "postal_code", value: "999077".into() }),
+///   );
+///
+///   Ok(())
+/// }
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
+#[serde(try_from = "PostalCodeFields")]
+pub struct PostalCode {
+    code: String,
+    country: Country,
+}
+
+impl PostalCode {
+    /// Create a new `PostalCode` in the given country.
+    ///
+    /// The value is trimmed and turned to upper case, and its spaces and hyphens are rewritten
+    /// in the format of the country, such as `k1a0b1` into `K1A 0B1` in Canada.
+    /// A literal prefix of the format may be left out, such as `1050` for `LV-1050` in Latvia.
+    pub fn new(country: Country, value: &str) -> ValobsResult<PostalCode> {
+        let code = value.split_whitespace().collect::<Vec<_>>().join(" ");
+        if code.is_empty() {
+            return Err(ValobsError::Empty {
+                field: "postal_code",
+            });
+        }
+        if !Self::is_used_in(country) {
+            return Err(ValobsError::NotAllowed {
+                field: "postal_code",
+                value: value.into(),
+            });
+        }
+
+        let code = code.to_uppercase();
+        let code = match formats(country) {
+            Some(formats) => formats
+                .iter()
+                .find_map(|format| apply_format(format, &code)),
+            None => is_free_form(&code).then_some(code),
+        };
+
+        code.map(|code| PostalCode { code, country })
+            .ok_or_else(|| ValobsError::InvalidFormat {
+                field: "postal_code",
+                value: value.into(),
+            })
+    }
+
+    /// Returns `true` if the country uses postal codes in its addresses.
+    pub fn is_used_in(country: Country) -> bool {
+        !WITHOUT_POSTAL_CODES.contains(&country)
+    }
+
+    /// Get the postal code, such as `K1A 0B1`.
+    pub fn as_str(&self) -> &str {
+        &self.code
+    }
+
+    /// Get the country of the postal code.
+    pub fn country(&self) -> Country {
+        self.country
+    }
+}
+
+impl ValueObject<'_> for PostalCode {}
+
+impl AsRef<str> for PostalCode {
+    fn as_ref(&self) -> &str {
+        &self.code
+    }
+}
+
+impl fmt::Display for PostalCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.code)
+    }
+}
+
+#[derive(Deserialize)]
+struct PostalCodeFields {
+    code: String,
+    country: Country,
+}
+
+impl TryFrom<PostalCodeFields> for PostalCode {
+    type Error = ValobsError;
+
+    fn try_from(fields: PostalCodeFields) -> ValobsResult<Self> {
+        Self::new(fields.country, &fields.code)
+    }
+}
+
+fn formats(country: Country) -> Option<&'static [&'static str]> {
+    POSTAL_CODE_FORMATS
+        .iter()
+        .find(|(format_country, _)| *format_country == country)
+        .map(|(_, formats)| *formats)
+}
+
+/// Returns `true` if the code is made of at most 10 letters, digits, spaces and hyphens.
+fn is_free_form(code: &str) -> bool {
+    code.len() <= 10
+        && code
+            .chars()
+            .all(|char| char.is_ascii_alphanumeric() || char == ' ' || char == '-')
+}
+
+/// Rewrites the code in the format, ignoring its spaces and hyphens, or returns `None` if it does not match.
+fn apply_format(format: &str, code: &str) -> Option<String> {
+    let is_separator = |char: char| char == ' ' || char == '-';
+    let compact = code
+        .chars()
+        .filter(|char| !is_separator(*char))
+        .collect::<String>();
+    // The literal prefix of the format, such as `LV` in `LV-####`, may be left out
+    let prefix = format
+        .chars()
+        .take_while(|char| !matches!(char, '#' | '@' | '*'))
+        .filter(|char| !is_separator(*char))
+        .collect::<String>();
+    let compact = if compact.starts_with(&prefix) {
+        compact
+    } else {
+        format!("{prefix}{compact}")
+    };
+
+    let mut chars = compact.chars();
+    let mut result = String::with_capacity(format.len());
+    for expected in format.chars() {
+        if is_separator(expected) {
+            result.push(expected);
+            continue;
+        }
+        let char = chars.next()?;
+        let matches = match expected {
+            '#' => char.is_ascii_digit(),
+            '@' => char.is_ascii_alphabetic(),
+            '*' => char.is_ascii_alphanumeric(),
+            _ => char == expected,
+        };
+        if !matches {
+            return None;
+        }
+        result.push(char);
+    }
+
+    chars.next().is_none().then_some(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn create_postal_code_in_every_format() -> ValobsResult<()> {
+        for (country, value, expected) in [
+            (Country::USA, "12345", "12345"),
+            (Country::USA, "123456789", "12345-6789"),
+            (Country::GBR, "ec1a1bb", "EC1A 1BB"),
+            (Country::GBR, "M1 1AE", "M1 1AE"),
+            (Country::CAN, "K1A 0B1", "K1A 0B1"),
+            (Country::JPN, "100-0001", "100-0001"),
+            (Country::KOR, "03187", "03187"),
+            (Country::NLD, "1234ab", "1234 AB"),
+            (Country::BRA, "01310100", "01310-100"),
+            (Country::POL, "00-950", "00-950"),
+            (Country::LVA, "LV-1050", "LV-1050"),
+            (Country::LVA, "lv1050", "LV-1050"),
+            (Country::LVA, "1050", "LV-1050"),
+            (Country::IRL, "d02 x285", "D02 X285"),
+            (Country::LBY, "some code", "SOME CODE"),
+        ] {
+            // Act
+            let postal_code = PostalCode::new(country, value)?;
+
+            // Assert
+            assert_eq!(postal_code.as_str(), expected, "{country} {value}");
+            assert_eq!(postal_code.country(), country);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn fails_to_create_postal_code_with_invalid_format() {
+        for (country, value) in [
+            (Country::USA, "1234"),
+            (Country::USA, "123456"),
+            (Country::USA, "ABCDE"),
+            (Country::GBR, "SW1A"),
+            (Country::CAN, "123 456"),
+            (Country::NLD, "AB 1234"),
+            (Country::LVA, "LT-1050"),
+            (Country::LBY, "12#45"),
+        ] {
+            assert_eq!(
+                PostalCode::new(country, value),
+                Err(ValobsError::InvalidFormat {
+                    field: "postal_code",
+                    value: value.into(),
+                }),
+                "{country} {value}"
+            );
+        }
+        assert_eq!(
+            PostalCode::new(Country::USA, " "),
+            Err(ValobsError::Empty {
+                field: "postal_code"
+            })
+        );
+    }
+
+    #[test]
+    fn fails_to_create_postal_code_in_country_without_postal_codes() {
+        assert!(!PostalCode::is_used_in(Country::QAT));
+        assert!(PostalCode::is_used_in(Country::USA));
+        assert_eq!(
+            PostalCode::new(Country::QAT, "00000"),
+            Err(ValobsError::NotAllowed {
+                field: "postal_code",
+                value: "00000".into(),
+            })
+        );
+    }
+
+    #[test]
+    fn postal_code_formats_are_consistent() {
+        for (country, _) in POSTAL_CODE_FORMATS {
+            assert!(PostalCode::is_used_in(*country), "{country}");
+        }
+        assert!(POSTAL_CODE_FORMATS
+            .windows(2)
+            .all(|pair| pair[0].0.alpha3() < pair[1].0.alpha3()));
+    }
+
+    #[test]
+    fn serialize_postal_code_to_json() -> ValobsResult<()> {
+        // Arrange
+        let postal_code = PostalCode::new(Country::CAN, "K1A 0B1")?;
+
+        // Act
+        let json = serde_json::to_string(&postal_code).unwrap();
+        let result = serde_json::from_str::<PostalCode>(&json).unwrap();
+
+        // Assert
+        assert_eq!(json, r#"{"code":"K1A 0B1","country":"CAN"}"#);
+        assert_eq!(result, postal_code);
+        assert!(serde_json::from_str::<PostalCode>(r#"{"code":"12345","country":"CAN"}"#).is_err());
+        Ok(())
+    }
+}
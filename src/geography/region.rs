@@ -0,0 +1,139 @@
+use serde::{Deserialize, Serialize};
+
+/// A value object representing a geographic region, as defined by the UN M49 standard.
+///
+/// ## What is a _Region_?
+///
+/// The United Nations Statistics Division groups the countries of the world into five regions,
+/// which are divided into sub-regions. Each of them has a three-digit M49 code, such as `150` for Europe.
+///
+/// For more info, see [UN M49](https://unstats.un.org/unsd/methodology/m49/).
+///
+/// ## When to use
+///
+/// Use this type when you want to group countries for statistics or reporting.
+/// Unlike a [Continent](crate::geography::Continent), the Americas are a single region.
+///
+/// ## Example
+///
+/// ```
+/// use valobs::geography::{Country, Region, SubRegion};
+///
+/// assert_eq!(Country::FRA.sub_region(), Some(SubRegion::WesternEurope));
+/// assert_eq!(Country::FRA.region(), Some(Region::Europe));
+/// assert_eq!(Region::Europe.code(), 150);
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Clone, Copy)]
+pub enum Region {
+    Africa,
+    Americas,
+    Asia,
+    Europe,
+    Oceania,
+}
+
+impl Region {
+    /// Returns the UN M49 code of the region, such as `150` for Europe.
+    pub fn code(&self) -> u16 {
+        match self {
+            Self::Africa => 2,
+            Self::Americas => 19,
+            Self::Asia => 142,
+            Self::Europe => 150,
+            Self::Oceania => 9,
+        }
+    }
+}
+
+/// A value object representing a geographic sub-region, as defined by the UN M49 standard.
+///
+/// Every sub-region belongs to a [Region], such as [SubRegion::WesternEurope] to [Region::Europe].
+#[derive(Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Clone, Copy)]
+pub enum SubRegion {
+    NorthernAfrica,
+    SubSaharanAfrica,
+    LatinAmericaAndTheCaribbean,
+    NorthernAmerica,
+    CentralAsia,
+    EasternAsia,
+    SouthEasternAsia,
+    SouthernAsia,
+    WesternAsia,
+    EasternEurope,
+    NorthernEurope,
+    SouthernEurope,
+    WesternEurope,
+    AustraliaAndNewZealand,
+    Melanesia,
+    Micronesia,
+    Polynesia,
+}
+
+impl SubRegion {
+    /// Returns the UN M49 code of the sub-region, such as `155` for Western Europe.
+    pub fn code(&self) -> u16 {
+        match self {
+            Self::NorthernAfrica => 15,
+            Self::SubSaharanAfrica => 202,
+            Self::LatinAmericaAndTheCaribbean => 419,
+            Self::NorthernAmerica => 21,
+            Self::CentralAsia => 143,
+            Self::EasternAsia => 30,
+            Self::SouthEasternAsia => 35,
+            Self::SouthernAsia => 34,
+            Self::WesternAsia => 145,
+            Self::EasternEurope => 151,
+            Self::NorthernEurope => 154,
+            Self::SouthernEurope => 39,
+            Self::WesternEurope => 155,
+            Self::AustraliaAndNewZealand => 53,
+            Self::Melanesia => 54,
+            Self::Micronesia => 57,
+            Self::Polynesia => 61,
+        }
+    }
+
+    /// Returns the region the sub-region belongs to.
+    pub fn region(&self) -> Region {
+        match self {
+            Self::NorthernAfrica | Self::SubSaharanAfrica => Region::Africa,
+            Self::LatinAmericaAndTheCaribbean | Self::NorthernAmerica => Region::Americas,
+            Self::CentralAsia
+            | Self::EasternAsia
+            | Self::SouthEasternAsia
+            | Self::SouthernAsia
+            | Self::WesternAsia => Region::Asia,
+            Self::EasternEurope
+            | Self::NorthernEurope
+            | Self::SouthernEurope
+            | Self::WesternEurope => Region::Europe,
+            Self::AustraliaAndNewZealand | Self::Melanesia | Self::Micronesia | Self::Polynesia => {
+                Region::Oceania
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sub_region_belongs_to_region() {
+        assert_eq!(SubRegion::NorthernAmerica.region(), Region::Americas);
+        assert_eq!(SubRegion::SouthEasternAsia.region(), Region::Asia);
+        assert_eq!(SubRegion::Polynesia.region(), Region::Oceania);
+    }
+
+    #[test]
+    fn serialize_region_to_json() {
+        // Arrange
+        let region = SubRegion::WesternEurope;
+
+        // Act
+        let result = serde_json::to_string(&region).unwrap();
+
+        // Assert
+        assert_eq!(result, "\"WesternEurope\"");
+    }
+}
@@ -0,0 +1,580 @@
+mod data;
+
+use self::data::{SubdivisionData, SUBDIVISIONS};
+use super::Country;
+use crate::{
+    result::{ValobsError, ValobsResult},
+    traits::ValueObject,
+};
+use serde::{Deserialize, Serialize};
+use std::{fmt, str::FromStr};
+
+/// English and common names of subdivisions whose ISO 3166-2 name is in the local language,
+/// or carries its category, such as `Seoul` for `Seoul-teukbyeolsi`, and the alternate names
+/// listed by ISO 3166-2, such as `Gerona` for `Girona` or `Caerdydd` for `Cardiff`. The entries are sorted by code.
+const ALIASES: &[(&str, &[&str])] = &[
+    ("CN-AH", &["Anhui"]),
+    ("CN-BJ", &["Beijing", "Peking"]),
+    ("CN-CQ", &["Chongqing"]),
+    ("CN-FJ", &["Fujian"]),
+    ("CN-GD", &["Guangdong"]),
+    ("CN-GS", &["Gansu"]),
+    ("CN-GX", &["Guangxi"]),
+    ("CN-GZ", &["Guizhou"]),
+    ("CN-HA", &["Henan"]),
+    ("CN-HB", &["Hubei"]),
+    ("CN-HE", &["Hebei"]),
+    ("CN-HI", &["Hainan"]),
+    ("CN-HK", &["Hong Kong"]),
+    ("CN-HL", &["Heilongjiang"]),
+    ("CN-HN", &["Hunan"]),
+    ("CN-JL", &["Jilin"]),
+    ("CN-JS", &["Jiangsu"]),
+    ("CN-JX", &["Jiangxi"]),
+    ("CN-LN", &["Liaoning"]),
+    ("CN-MO", &["Macao", "Macau"]),
+    ("CN-NM", &["Inner Mongolia", "Nei Mongol"]),
+    ("CN-NX", &["Ningxia"]),
+    ("CN-QH", &["Qinghai"]),
+    ("CN-SC", &["Sichuan"]),
+    ("CN-SD", &["Shandong"]),
+    ("CN-SH", &["Shanghai"]),
+    ("CN-SN", &["Shaanxi"]),
+    ("CN-SX", &["Shanxi"]),
+    ("CN-TJ", &["Tianjin"]),
+    ("CN-TW", &["Taiwan"]),
+    ("CN-XJ", &["Xinjiang"]),
+    ("CN-XZ", &["Tibet", "Xizang"]),
+    ("CN-YN", &["Yunnan"]),
+    ("CN-ZJ", &["Zhejiang"]),
+    ("ES-AN", &["Andalusia"]),
+    ("ES-AS", &["Asturias"]),
+    ("ES-C", &["La Coruña"]),
+    ("ES-CL", &["Castile and León"]),
+    ("ES-CM", &["Castilla-La Mancha", "Castile-La Mancha"]),
+    ("ES-CN", &["Canary Islands"]),
+    ("ES-CT", &["Catalonia", "Cataluña"]),
+    ("ES-GI", &["Gerona"]),
+    ("ES-IB", &["Balearic Islands", "Islas Baleares"]),
+    ("ES-L", &["Lérida"]),
+    ("ES-MC", &["Murcia"]),
+    ("ES-MD", &["Madrid"]),
+    ("ES-NC", &["Navarre", "Navarra"]),
+    ("ES-OR", &["Orense"]),
+    ("ES-PM", &["Islas Baleares"]),
+    ("ES-PV", &["Basque Country", "País Vasco"]),
+    ("ES-VC", &["Valencia", "Comunidad Valenciana"]),
+    ("GB-AGY", &["Sir Ynys Môn"]),
+    ("GB-BGE", &["Pen-y-bont ar Ogwr"]),
+    ("GB-CAY", &["Caerffili"]),
+    ("GB-CGN", &["Sir Ceredigion"]),
+    ("GB-CMN", &["Sir Gaerfyrddin"]),
+    ("GB-CRF", &["Caerdydd"]),
+    ("GB-DEN", &["Sir Ddinbych"]),
+    ("GB-FLN", &["Sir y Fflint"]),
+    ("GB-MON", &["Sir Fynwy"]),
+    ("GB-MTY", &["Merthyr Tudful"]),
+    ("GB-NTL", &["Castell-nedd Port Talbot"]),
+    ("GB-NWP", &["Casnewydd"]),
+    ("GB-PEM", &["Sir Benfro"]),
+    ("GB-RCT", &["Rhondda CynonTaf"]),
+    ("GB-SWA", &["Abertawe"]),
+    ("GB-TOF", &["Tor-faen"]),
+    ("GB-VGL", &["Bro Morgannwg"]),
+    ("GB-WLS", &["Cymru"]),
+    ("GB-WRX", &["Wrecsam"]),
+    ("IN-OR", &["Orissa"]),
+    ("IN-PY", &["Pondicherry"]),
+    ("IT-21", &["Piedmont"]),
+    ("IT-23", &["Aosta Valley", "Valle d'Aosta"]),
+    ("IT-25", &["Lombardy"]),
+    ("IT-32", &["Trentino-South Tyrol"]),
+    ("IT-36", &["Friuli-Venezia Giulia"]),
+    ("IT-52", &["Tuscany"]),
+    ("IT-75", &["Apulia"]),
+    ("IT-82", &["Sicily"]),
+    ("IT-88", &["Sardinia"]),
+    ("KR-11", &["Seoul"]),
+    ("KR-26", &["Busan"]),
+    ("KR-27", &["Daegu"]),
+    ("KR-28", &["Incheon"]),
+    ("KR-29", &["Gwangju"]),
+    ("KR-30", &["Daejeon"]),
+    ("KR-31", &["Ulsan"]),
+    ("KR-41", &["Gyeonggi"]),
+    ("KR-42", &["Gangwon"]),
+    ("KR-43", &["North Chungcheong", "Chungbuk"]),
+    ("KR-44", &["South Chungcheong", "Chungnam"]),
+    ("KR-45", &["North Jeolla", "Jeonbuk"]),
+    ("KR-46", &["South Jeolla", "Jeonnam"]),
+    ("KR-47", &["North Gyeongsang", "Gyeongbuk"]),
+    ("KR-48", &["South Gyeongsang", "Gyeongnam"]),
+    ("KR-49", &["Jeju"]),
+    ("MD-BD", &["Tighina"]),
+];
+
+/// A value object representing a subdivision of a country, such as a state, a province or a prefecture.
+/// The format is ISO 3166-2, which is the ISO 3166-1 alpha-2 code of the country followed by up to three
+/// letters or digits, such as `US-NY` for New York, `KR-11` for Seoul or `DE-BY` for Bavaria.
+///
+/// For more info, see [ISO 3166-2](https://en.wikipedia.org/wiki/ISO_3166-2).
+///
+/// ## What is a _Subdivision_?
+///
+/// A subdivision is an administrative division of a country, such as a state of the United States,
+/// a province of Canada or a prefecture of Japan. Some subdivisions are part of a larger one,
+/// such as the French departments, which are part of regions.
+///
+/// ## When to use
+///
+/// Use this type when you want to represent the state or province of an address,
+/// instead of a free string where `NY`, `New York` and `ny` would all be different values.
+///
+/// ## Example
+///
+/// ```
+/// use valobs::geography::{Country, Subdivision};
+/// use valobs::result::ValobsResult;
+///
+/// fn main() -> ValobsResult<()> {
+///   let new_york = Subdivision::new("US-NY")?;
+///
+///   assert_eq!(new_york.country(), Country::USA);
+///   assert_eq!(new_york.name(), "New York");
+///   assert_eq!(new_york.kind(), "State");
+///
+///   // Parse a subdivision of a country from its code or name
+///   assert_eq!(Subdivision::find(Country::USA, "ny")?, new_york);
+///   assert_eq!(Subdivision::find(Country::USA, "new york")?, new_york);
+///   assert!(Subdivision::find(Country::CAN, "NY").is_err());
+///
+///   Ok(())
+/// }
+/// ```
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct Subdivision(u16);
+
+impl Subdivision {
+    /// Create a new `Subdivision` from its ISO 3166-2 code, such as `US-NY`, which is case-insensitive.
+    pub fn new(code: &str) -> ValobsResult<Subdivision> {
+        let (country, local) = code
+            .split_once('-')
+            .ok_or_else(|| ValobsError::InvalidFormat {
+                field: "subdivision",
+                value: code.into(),
+            })?;
+        if country.len() != 2
+            || !country.bytes().all(|byte| byte.is_ascii_alphabetic())
+            || local.is_empty()
+            || local.len() > 3
+            || !local.bytes().all(|byte| byte.is_ascii_alphanumeric())
+        {
+            return Err(ValobsError::InvalidFormat {
+                field: "subdivision",
+                value: code.into(),
+            });
+        }
+
+        Self::from_code(&code.to_ascii_uppercase()).ok_or_else(|| ValobsError::UnknownCode {
+            field: "subdivision",
+            value: code.into(),
+        })
+    }
+
+    /// Find a subdivision of the given country from its code, with or without the country prefix,
+    /// such as `NY` or `US-NY`, or from its name, such as `New York`.
+    /// Codes and names are trimmed and case-insensitive, and names ignore diacritics, so `Sao Paulo` finds `São Paulo`.
+    ///
+    /// Names are the ones of ISO 3166-2, which are often in the local language, such as `Bayern` for Bavaria.
+    /// The subdivisions of countries where addresses usually require them, such as South Korea, China, Spain or Italy,
+    /// are also found by their English or common names, such as `Seoul` for `Seoul-teukbyeolsi`,
+    /// `Beijing` for `Beijing Shi` or `Catalonia` for `Catalunya`, and by the alternate names of ISO 3166-2,
+    /// such as `Gerona` for `Girona`.
+    /// When a name is shared by several subdivisions of the country, the one that is not part of another is returned.
+    ///
+    /// Fails if the subdivision does not belong to the country.
+    pub fn find(country: Country, value: &str) -> ValobsResult<Subdivision> {
+        let value = value.trim();
+        let unknown = || ValobsError::UnknownCode {
+            field: "subdivision",
+            value: value.into(),
+        };
+        if value.is_empty() {
+            return Err(ValobsError::Empty {
+                field: "subdivision",
+            });
+        }
+
+        let code = match value.split_once('-') {
+            Some((prefix, local)) if prefix.eq_ignore_ascii_case(country.alpha2()) => local,
+            _ => value,
+        };
+        let by_code = Self::of(country)
+            .find(|subdivision| subdivision.local_code().eq_ignore_ascii_case(code));
+        if let Some(subdivision) = by_code {
+            return Ok(subdivision);
+        }
+
+        let name = fold(value);
+        Self::of(country)
+            .filter(|subdivision| {
+                std::iter::once(subdivision.name())
+                    .chain(subdivision.aliases().iter().copied())
+                    .any(|candidate| fold(candidate) == name)
+            })
+            .min_by_key(|subdivision| subdivision.data().parent.is_some())
+            .ok_or_else(unknown)
+    }
+
+    /// Get the ISO 3166-2 code of the subdivision, such as `US-NY`.
+    pub fn code(&self) -> &'static str {
+        self.data().code
+    }
+
+    /// Get the part of the code after the country prefix, such as `NY` for `US-NY`.
+    pub fn local_code(&self) -> &'static str {
+        &self.code()[3..]
+    }
+
+    /// Get the country the subdivision belongs to.
+    pub fn country(&self) -> Country {
+        Country::from_alpha2(&self.code()[..2])
+            .expect("every subdivision belongs to a known country")
+    }
+
+    /// Returns `true` if the subdivision belongs to the given country.
+    pub fn belongs_to(&self, country: Country) -> bool {
+        self.code()[..2] == *country.alpha2()
+    }
+
+    /// Get the ISO 3166-2 name of the subdivision, such as `New York`.
+    pub fn name(&self) -> &'static str {
+        self.data().name
+    }
+
+    /// Get the ISO 3166-2 category of the subdivision, such as `State`, `Province` or `Prefecture`.
+    pub fn kind(&self) -> &'static str {
+        self.data().kind
+    }
+
+    /// Get the subdivision this one is part of, such as the region `FR-ARA` for the department `FR-01`.
+    pub fn parent(&self) -> Option<Subdivision> {
+        self.data()
+            .parent
+            .map(|code| Self::from_code(code).expect("every parent is a known subdivision"))
+    }
+
+    /// Returns an iterator over the subdivisions of the given country, in the order of their codes.
+    pub(super) fn of(country: Country) -> impl Iterator<Item = Subdivision> {
+        let prefix = format!("{}-", country.alpha2());
+        let start = SUBDIVISIONS.partition_point(|data| data.code < prefix.as_str());
+        SUBDIVISIONS[start..]
+            .iter()
+            .take_while(move |data| data.code.starts_with(&prefix))
+            .enumerate()
+            .map(move |(offset, _)| Subdivision((start + offset) as u16))
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        ALIASES
+            .binary_search_by(|(code, _)| (*code).cmp(self.code()))
+            .map_or(&[], |index| ALIASES[index].1)
+    }
+
+    fn from_code(code: &str) -> Option<Subdivision> {
+        SUBDIVISIONS
+            .binary_search_by(|data| data.code.cmp(code))
+            .ok()
+            .map(|index| Subdivision(index as u16))
+    }
+
+    fn data(&self) -> &'static SubdivisionData {
+        &SUBDIVISIONS[self.0 as usize]
+    }
+}
+
+impl ValueObject<'_> for Subdivision {}
+
+impl AsRef<str> for Subdivision {
+    fn as_ref(&self) -> &str {
+        self.code()
+    }
+}
+
+impl TryFrom<String> for Subdivision {
+    type Error = ValobsError;
+
+    fn try_from(value: String) -> ValobsResult<Self> {
+        Self::new(&value)
+    }
+}
+
+impl From<Subdivision> for String {
+    fn from(subdivision: Subdivision) -> Self {
+        subdivision.code().into()
+    }
+}
+
+impl fmt::Debug for Subdivision {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Subdivision").field(&self.code()).finish()
+    }
+}
+
+impl fmt::Display for Subdivision {
+    /// Formats the subdivision as its ISO 3166-2 code, such as `US-NY`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+impl FromStr for Subdivision {
+    type Err = ValobsError;
+
+    fn from_str(code: &str) -> ValobsResult<Self> {
+        Self::new(code)
+    }
+}
+
+/// Turns a name to lower case and removes its diacritics, so that `Sao Paulo` matches `São Paulo`.
+fn fold(name: &str) -> String {
+    let mut folded = String::with_capacity(name.len());
+    for char in name.chars().flat_map(char::to_lowercase) {
+        match char {
+            // Combining diacritical marks, such as in `Z̧`
+            '\u{300}'..='\u{36f}' => {}
+            'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' | 'ā' | 'ă' | 'ą' | 'ǎ' => folded.push('a'),
+            'ç' | 'ć' | 'ĉ' | 'ċ' | 'č' => folded.push('c'),
+            'ď' | 'đ' | 'ð' => folded.push('d'),
+            'è' | 'é' | 'ê' | 'ë' | 'ē' | 'ĕ' | 'ė' | 'ę' | 'ě' => folded.push('e'),
+            'ĝ' | 'ğ' | 'ġ' | 'ģ' => folded.push('g'),
+            'ĥ' | 'ħ' => folded.push('h'),
+            'ì' | 'í' | 'î' | 'ï' | 'ĩ' | 'ī' | 'ĭ' | 'į' | 'ı' | 'ǐ' => folded.push('i'),
+            'ĵ' => folded.push('j'),
+            'ķ' => folded.push('k'),
+            'ĺ' | 'ļ' | 'ľ' | 'ŀ' | 'ł' => folded.push('l'),
+            'ñ' | 'ń' | 'ņ' | 'ň' => folded.push('n'),
+            'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ø' | 'ō' | 'ŏ' | 'ő' | 'ǒ' => folded.push('o'),
+            'ŕ' | 'ŗ' | 'ř' => folded.push('r'),
+            'ś' | 'ŝ' | 'ş' | 'š' | 'ș' => folded.push('s'),
+            'ţ' | 'ť' | 'ŧ' | 'ț' => folded.push('t'),
+            'ù' | 'ú' | 'û' | 'ü' | 'ũ' | 'ū' | 'ŭ' | 'ů' | 'ű' | 'ų' | 'ǔ' => {
+                folded.push('u')
+            }
+            'ŵ' => folded.push('w'),
+            'ý' | 'ÿ' | 'ŷ' => folded.push('y'),
+            'ź' | 'ż' | 'ž' => folded.push('z'),
+            'æ' => folded.push_str("ae"),
+            'œ' => folded.push_str("oe"),
+            'ß' => folded.push_str("ss"),
+            'þ' => folded.push_str("th"),
+            '‘' | '’' | 'ʼ' | 'ʻ' => folded.push('\''),
+            char => folded.push(char),
+        }
+    }
+    folded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn create_subdivision_with_valid_code() -> ValobsResult<()> {
+        // Act
+        let subdivision = Subdivision::new("kr-11")?;
+
+        // Assert
+        assert_eq!(subdivision.code(), "KR-11");
+        assert_eq!(subdivision.local_code(), "11");
+        assert_eq!(subdivision.country(), Country::KOR);
+        assert_eq!(subdivision.kind(), "Special city");
+        assert_eq!(subdivision.parent(), None);
+        Ok(())
+    }
+
+    #[test]
+    fn fails_to_create_subdivision_with_invalid_code() {
+        assert_eq!(
+            Subdivision::new("USNY"),
+            Err(ValobsError::InvalidFormat {
+                field: "subdivision",
+                value: "USNY".into(),
+            })
+        );
+        assert_eq!(
+            Subdivision::new("US-NYC1"),
+            Err(ValobsError::InvalidFormat {
+                field: "subdivision",
+                value: "US-NYC1".into(),
+            })
+        );
+        assert_eq!(
+            Subdivision::new("US-ZZ"),
+            Err(ValobsError::UnknownCode {
+                field: "subdivision",
+                value: "US-ZZ".into(),
+            })
+        );
+    }
+
+    #[test]
+    fn find_subdivision_of_country() -> ValobsResult<()> {
+        let bavaria = Subdivision::new("DE-BY")?;
+
+        assert_eq!(Subdivision::find(Country::DEU, "BY")?, bavaria);
+        assert_eq!(Subdivision::find(Country::DEU, "de-by")?, bavaria);
+        assert_eq!(Subdivision::find(Country::DEU, "bayern")?, bavaria);
+        assert_eq!(Subdivision::find(Country::DEU, " Bayern ")?, bavaria);
+        assert!(bavaria.belongs_to(Country::DEU));
+        assert!(!bavaria.belongs_to(Country::AUT));
+        assert_eq!(
+            Subdivision::find(Country::AUT, "DE-BY"),
+            Err(ValobsError::UnknownCode {
+                field: "subdivision",
+                value: "DE-BY".into(),
+            })
+        );
+        assert_eq!(
+            Subdivision::find(Country::USA, " "),
+            Err(ValobsError::Empty {
+                field: "subdivision"
+            })
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn find_subdivision_by_name_without_diacritics() -> ValobsResult<()> {
+        assert_eq!(
+            Subdivision::find(Country::BRA, "Sao Paulo")?.code(),
+            "BR-SP"
+        );
+        assert_eq!(
+            Subdivision::find(Country::IND, "maharashtra")?.code(),
+            "IN-MH"
+        );
+        assert_eq!(
+            Subdivision::find(Country::IND, "Mahārāshtra")?.code(),
+            "IN-MH"
+        );
+        assert_eq!(Subdivision::find(Country::AUT, "karnten")?.code(), "AT-2");
+        Ok(())
+    }
+
+    #[test]
+    fn find_subdivision_by_english_or_common_name() -> ValobsResult<()> {
+        assert_eq!(Subdivision::find(Country::KOR, "Seoul")?.code(), "KR-11");
+        assert_eq!(
+            Subdivision::find(Country::KOR, "Seoul-teukbyeolsi")?.code(),
+            "KR-11"
+        );
+        assert_eq!(Subdivision::find(Country::CHN, "beijing")?.code(), "CN-BJ");
+        assert_eq!(Subdivision::find(Country::CHN, "Tibet")?.code(), "CN-XZ");
+        assert_eq!(
+            Subdivision::find(Country::ESP, "Catalonia")?.code(),
+            "ES-CT"
+        );
+        assert_eq!(Subdivision::find(Country::ITA, "Sicily")?.code(), "IT-82");
+        assert!(Subdivision::find(Country::JPN, "Seoul").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn find_subdivision_by_primary_or_alternate_name() -> ValobsResult<()> {
+        for (code, name, alternate) in [
+            ("ES-B", "Barcelona", "barcelona"),
+            ("ES-GI", "Girona", "Gerona"),
+            ("GB-CRF", "Cardiff", "Caerdydd"),
+        ] {
+            // Arrange
+            let subdivision = Subdivision::new(code)?;
+
+            // Act
+            let by_name = Subdivision::find(subdivision.country(), name)?;
+            let by_alternate = Subdivision::find(subdivision.country(), alternate)?;
+
+            // Assert
+            assert_eq!(subdivision.name(), name);
+            assert_eq!(by_name, subdivision);
+            assert_eq!(by_alternate, subdivision);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn aliases_are_sorted_by_known_code() {
+        assert!(ALIASES.windows(2).all(|pair| pair[0].0 < pair[1].0));
+        for (code, _) in ALIASES {
+            assert!(Subdivision::new(code).is_ok(), "{code}");
+        }
+    }
+
+    #[test]
+    fn find_subdivision_by_shared_name() -> ValobsResult<()> {
+        // Arrange
+        let name = Subdivision::new("BD-A")?.name();
+
+        // Act
+        let subdivision = Subdivision::find(Country::BGD, name)?;
+
+        // Assert
+        assert_eq!(subdivision.code(), "BD-A");
+        assert_eq!(subdivision.parent(), None);
+        Ok(())
+    }
+
+    #[test]
+    fn parent_of_subdivision() -> ValobsResult<()> {
+        // Arrange
+        let ain = Subdivision::new("FR-01")?;
+
+        // Act
+        let parent = ain.parent();
+
+        // Assert
+        assert_eq!(parent, Some(Subdivision::new("FR-ARA")?));
+        Ok(())
+    }
+
+    #[test]
+    fn subdivisions_of_country() {
+        assert_eq!(Subdivision::of(Country::USA).count(), 57);
+        assert!(
+            Subdivision::of(Country::USA).all(|subdivision| subdivision.belongs_to(Country::USA))
+        );
+        assert_eq!(Subdivision::of(Country::ATA).count(), 0);
+    }
+
+    #[test]
+    fn subdivision_data_is_sorted_by_code() {
+        assert!(SUBDIVISIONS
+            .windows(2)
+            .all(|pair| pair[0].code < pair[1].code));
+        for data in SUBDIVISIONS {
+            assert!(!data.name.contains('['), "{}", data.code);
+            assert!(
+                Country::from_alpha2(&data.code[..2]).is_some(),
+                "{}",
+                data.code
+            );
+        }
+    }
+
+    #[test]
+    fn serialize_subdivision_to_json() -> ValobsResult<()> {
+        // Arrange
+        let subdivision = Subdivision::new("US-NY")?;
+
+        // Act
+        let json = serde_json::to_string(&subdivision).unwrap();
+        let result = serde_json::from_str::<Subdivision>(&json).unwrap();
+
+        // Assert
+        assert_eq!(json, "\"US-NY\"");
+        assert_eq!(result, subdivision);
+        assert!(serde_json::from_str::<Subdivision>("\"US-ZZ\"").is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,5142 @@
+//! ISO 3166-2 reference data of every [Subdivision](super::Subdivision).
+//!
+//! The entries are sorted by code, so the subdivisions of a country are next to each other.
+
+pub(super) struct SubdivisionData {
+    pub code: &'static str,
+    pub name: &'static str,
+    pub kind: &'static str,
+    /// The code of the subdivision this one is part of, such as `FR-ARA` for `FR-01`.
+    pub parent: Option<&'static str>,
+}
+
+#[rustfmt::skip]
+pub(super) const SUBDIVISIONS: &[SubdivisionData] = &[
+    SubdivisionData { code: "AD-02", name: "Canillo", kind: "Parish", parent: None },
+    SubdivisionData { code: "AD-03", name: "Encamp", kind: "Parish", parent: None },
+    SubdivisionData { code: "AD-04", name: "La Massana", kind: "Parish", parent: None },
+    SubdivisionData { code: "AD-05", name: "Ordino", kind: "Parish", parent: None },
+    SubdivisionData { code: "AD-06", name: "Sant Julià de Lòria", kind: "Parish", parent: None },
+    SubdivisionData { code: "AD-07", name: "Andorra la Vella", kind: "Parish", parent: None },
+    SubdivisionData { code: "AD-08", name: "Escaldes-Engordany", kind: "Parish", parent: None },
+    SubdivisionData { code: "AE-AJ", name: "‘Ajmān", kind: "Emirate", parent: None },
+    SubdivisionData { code: "AE-AZ", name: "Abū Z̧aby", kind: "Emirate", parent: None },
+    SubdivisionData { code: "AE-DU", name: "Dubayy", kind: "Emirate", parent: None },
+    SubdivisionData { code: "AE-FU", name: "Al Fujayrah", kind: "Emirate", parent: None },
+    SubdivisionData { code: "AE-RK", name: "Ra’s al Khaymah", kind: "Emirate", parent: None },
+    SubdivisionData { code: "AE-SH", name: "Ash Shāriqah", kind: "Emirate", parent: None },
+    SubdivisionData { code: "AE-UQ", name: "Umm al Qaywayn", kind: "Emirate", parent: None },
+    SubdivisionData { code: "AF-BAL", name: "Balkh", kind: "Province", parent: None },
+    SubdivisionData { code: "AF-BAM", name: "Bāmyān", kind: "Province", parent: None },
+    SubdivisionData { code: "AF-BDG", name: "Bādghīs", kind: "Province", parent: None },
+    SubdivisionData { code: "AF-BDS", name: "Badakhshān", kind: "Province", parent: None },
+    SubdivisionData { code: "AF-BGL", name: "Baghlān", kind: "Province", parent: None },
+    SubdivisionData { code: "AF-DAY", name: "Dāykundī", kind: "Province", parent: None },
+    SubdivisionData { code: "AF-FRA", name: "Farāh", kind: "Province", parent: None },
+    SubdivisionData { code: "AF-FYB", name: "Fāryāb", kind: "Province", parent: None },
+    SubdivisionData { code: "AF-GHA", name: "Ghaznī", kind: "Province", parent: None },
+    SubdivisionData { code: "AF-GHO", name: "Ghōr", kind: "Province", parent: None },
+    SubdivisionData { code: "AF-HEL", name: "Helmand", kind: "Province", parent: None },
+    SubdivisionData { code: "AF-HER", name: "Herāt", kind: "Province", parent: None },
+    SubdivisionData { code: "AF-JOW", name: "Jowzjān", kind: "Province", parent: None },
+    SubdivisionData { code: "AF-KAB", name: "Kābul", kind: "Province", parent: None },
+    SubdivisionData { code: "AF-KAN", name: "Kandahār", kind: "Province", parent: None },
+    SubdivisionData { code: "AF-KAP", name: "Kāpīsā", kind: "Province", parent: None },
+    SubdivisionData { code: "AF-KDZ", name: "Kunduz", kind: "Province", parent: None },
+    SubdivisionData { code: "AF-KHO", name: "Khōst", kind: "Province", parent: None },
+    SubdivisionData { code: "AF-KNR", name: "Kunaṟ", kind: "Province", parent: None },
+    SubdivisionData { code: "AF-LAG", name: "Laghmān", kind: "Province", parent: None },
+    SubdivisionData { code: "AF-LOG", name: "Lōgar", kind: "Province", parent: None },
+    SubdivisionData { code: "AF-NAN", name: "Nangarhār", kind: "Province", parent: None },
+    SubdivisionData { code: "AF-NIM", name: "Nīmrōz", kind: "Province", parent: None },
+    SubdivisionData { code: "AF-NUR", name: "Nūristān", kind: "Province", parent: None },
+    SubdivisionData { code: "AF-PAN", name: "Panjshayr", kind: "Province", parent: None },
+    SubdivisionData { code: "AF-PAR", name: "Parwān", kind: "Province", parent: None },
+    SubdivisionData { code: "AF-PIA", name: "Paktiyā", kind: "Province", parent: None },
+    SubdivisionData { code: "AF-PKA", name: "Paktīkā", kind: "Province", parent: None },
+    SubdivisionData { code: "AF-SAM", name: "Samangān", kind: "Province", parent: None },
+    SubdivisionData { code: "AF-SAR", name: "Sar-e Pul", kind: "Province", parent: None },
+    SubdivisionData { code: "AF-TAK", name: "Takhār", kind: "Province", parent: None },
+    SubdivisionData { code: "AF-URU", name: "Uruzgān", kind: "Province", parent: None },
+    SubdivisionData { code: "AF-WAR", name: "Wardak", kind: "Province", parent: None },
+    SubdivisionData { code: "AF-ZAB", name: "Zābul", kind: "Province", parent: None },
+    SubdivisionData { code: "AG-03", name: "Saint George", kind: "Parish", parent: None },
+    SubdivisionData { code: "AG-04", name: "Saint John", kind: "Parish", parent: None },
+    SubdivisionData { code: "AG-05", name: "Saint Mary", kind: "Parish", parent: None },
+    SubdivisionData { code: "AG-06", name: "Saint Paul", kind: "Parish", parent: None },
+    SubdivisionData { code: "AG-07", name: "Saint Peter", kind: "Parish", parent: None },
+    SubdivisionData { code: "AG-08", name: "Saint Philip", kind: "Parish", parent: None },
+    SubdivisionData { code: "AG-10", name: "Barbuda", kind: "Dependency", parent: None },
+    SubdivisionData { code: "AG-11", name: "Redonda", kind: "Dependency", parent: None },
+    SubdivisionData { code: "AL-01", name: "Berat", kind: "County", parent: None },
+    SubdivisionData { code: "AL-02", name: "Durrës", kind: "County", parent: None },
+    SubdivisionData { code: "AL-03", name: "Elbasan", kind: "County", parent: None },
+    SubdivisionData { code: "AL-04", name: "Fier", kind: "County", parent: None },
+    SubdivisionData { code: "AL-05", name: "Gjirokastër", kind: "County", parent: None },
+    SubdivisionData { code: "AL-06", name: "Korçë", kind: "County", parent: None },
+    SubdivisionData { code: "AL-07", name: "Kukës", kind: "County", parent: None },
+    SubdivisionData { code: "AL-08", name: "Lezhë", kind: "County", parent: None },
+    SubdivisionData { code: "AL-09", name: "Dibër", kind: "County", parent: None },
+    SubdivisionData { code: "AL-10", name: "Shkodër", kind: "County", parent: None },
+    SubdivisionData { code: "AL-11", name: "Tiranë", kind: "County", parent: None },
+    SubdivisionData { code: "AL-12", name: "Vlorë", kind: "County", parent: None },
+    SubdivisionData { code: "AM-AG", name: "Aragac̣otn", kind: "Region", parent: None },
+    SubdivisionData { code: "AM-AR", name: "Ararat", kind: "Region", parent: None },
+    SubdivisionData { code: "AM-AV", name: "Armavir", kind: "Region", parent: None },
+    SubdivisionData { code: "AM-ER", name: "Erevan", kind: "City", parent: None },
+    SubdivisionData { code: "AM-GR", name: "Geġark'unik'", kind: "Region", parent: None },
+    SubdivisionData { code: "AM-KT", name: "Kotayk'", kind: "Region", parent: None },
+    SubdivisionData { code: "AM-LO", name: "Loṙi", kind: "Region", parent: None },
+    SubdivisionData { code: "AM-SH", name: "Širak", kind: "Region", parent: None },
+    SubdivisionData { code: "AM-SU", name: "Syunik'", kind: "Region", parent: None },
+    SubdivisionData { code: "AM-TV", name: "Tavuš", kind: "Region", parent: None },
+    SubdivisionData { code: "AM-VD", name: "Vayoć Jor", kind: "Region", parent: None },
+    SubdivisionData { code: "AO-BGO", name: "Bengo", kind: "Province", parent: None },
+    SubdivisionData { code: "AO-BGU", name: "Benguela", kind: "Province", parent: None },
+    SubdivisionData { code: "AO-BIE", name: "Bié", kind: "Province", parent: None },
+    SubdivisionData { code: "AO-CAB", name: "Cabinda", kind: "Province", parent: None },
+    SubdivisionData { code: "AO-CCU", name: "Cuando Cubango", kind: "Province", parent: None },
+    SubdivisionData { code: "AO-CNN", name: "Cunene", kind: "Province", parent: None },
+    SubdivisionData { code: "AO-CNO", name: "Cuanza-Norte", kind: "Province", parent: None },
+    SubdivisionData { code: "AO-CUS", name: "Cuanza-Sul", kind: "Province", parent: None },
+    SubdivisionData { code: "AO-HUA", name: "Huambo", kind: "Province", parent: None },
+    SubdivisionData { code: "AO-HUI", name: "Huíla", kind: "Province", parent: None },
+    SubdivisionData { code: "AO-LNO", name: "Lunda-Norte", kind: "Province", parent: None },
+    SubdivisionData { code: "AO-LSU", name: "Lunda-Sul", kind: "Province", parent: None },
+    SubdivisionData { code: "AO-LUA", name: "Luanda", kind: "Province", parent: None },
+    SubdivisionData { code: "AO-MAL", name: "Malange", kind: "Province", parent: None },
+    SubdivisionData { code: "AO-MOX", name: "Moxico", kind: "Province", parent: None },
+    SubdivisionData { code: "AO-NAM", name: "Namibe", kind: "Province", parent: None },
+    SubdivisionData { code: "AO-UIG", name: "Uíge", kind: "Province", parent: None },
+    SubdivisionData { code: "AO-ZAI", name: "Zaire", kind: "Province", parent: None },
+    SubdivisionData { code: "AR-A", name: "Salta", kind: "Province", parent: None },
+    SubdivisionData { code: "AR-B", name: "Buenos Aires", kind: "Province", parent: None },
+    SubdivisionData { code: "AR-C", name: "Ciudad Autónoma de Buenos Aires", kind: "City", parent: None },
+    SubdivisionData { code: "AR-D", name: "San Luis", kind: "Province", parent: None },
+    SubdivisionData { code: "AR-E", name: "Entre Ríos", kind: "Province", parent: None },
+    SubdivisionData { code: "AR-F", name: "La Rioja", kind: "Province", parent: None },
+    SubdivisionData { code: "AR-G", name: "Santiago del Estero", kind: "Province", parent: None },
+    SubdivisionData { code: "AR-H", name: "Chaco", kind: "Province", parent: None },
+    SubdivisionData { code: "AR-J", name: "San Juan", kind: "Province", parent: None },
+    SubdivisionData { code: "AR-K", name: "Catamarca", kind: "Province", parent: None },
+    SubdivisionData { code: "AR-L", name: "La Pampa", kind: "Province", parent: None },
+    SubdivisionData { code: "AR-M", name: "Mendoza", kind: "Province", parent: None },
+    SubdivisionData { code: "AR-N", name: "Misiones", kind: "Province", parent: None },
+    SubdivisionData { code: "AR-P", name: "Formosa", kind: "Province", parent: None },
+    SubdivisionData { code: "AR-Q", name: "Neuquén", kind: "Province", parent: None },
+    SubdivisionData { code: "AR-R", name: "Río Negro", kind: "Province", parent: None },
+    SubdivisionData { code: "AR-S", name: "Santa Fe", kind: "Province", parent: None },
+    SubdivisionData { code: "AR-T", name: "Tucumán", kind: "Province", parent: None },
+    SubdivisionData { code: "AR-U", name: "Chubut", kind: "Province", parent: None },
+    SubdivisionData { code: "AR-V", name: "Tierra del Fuego", kind: "Province", parent: None },
+    SubdivisionData { code: "AR-W", name: "Corrientes", kind: "Province", parent: None },
+    SubdivisionData { code: "AR-X", name: "Córdoba", kind: "Province", parent: None },
+    SubdivisionData { code: "AR-Y", name: "Jujuy", kind: "Province", parent: None },
+    SubdivisionData { code: "AR-Z", name: "Santa Cruz", kind: "Province", parent: None },
+    SubdivisionData { code: "AT-1", name: "Burgenland", kind: "State", parent: None },
+    SubdivisionData { code: "AT-2", name: "Kärnten", kind: "State", parent: None },
+    SubdivisionData { code: "AT-3", name: "Niederösterreich", kind: "State", parent: None },
+    SubdivisionData { code: "AT-4", name: "Oberösterreich", kind: "State", parent: None },
+    SubdivisionData { code: "AT-5", name: "Salzburg", kind: "State", parent: None },
+    SubdivisionData { code: "AT-6", name: "Steiermark", kind: "State", parent: None },
+    SubdivisionData { code: "AT-7", name: "Tirol", kind: "State", parent: None },
+    SubdivisionData { code: "AT-8", name: "Vorarlberg", kind: "State", parent: None },
+    SubdivisionData { code: "AT-9", name: "Wien", kind: "State", parent: None },
+    SubdivisionData { code: "AU-ACT", name: "Australian Capital Territory", kind: "Territory", parent: None },
+    SubdivisionData { code: "AU-NSW", name: "New South Wales", kind: "State", parent: None },
+    SubdivisionData { code: "AU-NT", name: "Northern Territory", kind: "Territory", parent: None },
+    SubdivisionData { code: "AU-QLD", name: "Queensland", kind: "State", parent: None },
+    SubdivisionData { code: "AU-SA", name: "South Australia", kind: "State", parent: None },
+    SubdivisionData { code: "AU-TAS", name: "Tasmania", kind: "State", parent: None },
+    SubdivisionData { code: "AU-VIC", name: "Victoria", kind: "State", parent: None },
+    SubdivisionData { code: "AU-WA", name: "Western Australia", kind: "State", parent: None },
+    SubdivisionData { code: "AZ-ABS", name: "Abşeron", kind: "Rayon", parent: None },
+    SubdivisionData { code: "AZ-AGA", name: "Ağstafa", kind: "Rayon", parent: None },
+    SubdivisionData { code: "AZ-AGC", name: "Ağcabədi", kind: "Rayon", parent: None },
+    SubdivisionData { code: "AZ-AGM", name: "Ağdam", kind: "Rayon", parent: None },
+    SubdivisionData { code: "AZ-AGS", name: "Ağdaş", kind: "Rayon", parent: None },
+    SubdivisionData { code: "AZ-AGU", name: "Ağsu", kind: "Rayon", parent: None },
+    SubdivisionData { code: "AZ-AST", name: "Astara", kind: "Rayon", parent: None },
+    SubdivisionData { code: "AZ-BA", name: "Bakı", kind: "Municipality", parent: None },
+    SubdivisionData { code: "AZ-BAB", name: "Babək", kind: "Rayon", parent: Some("AZ-NX") },
+    SubdivisionData { code: "AZ-BAL", name: "Balakən", kind: "Rayon", parent: None },
+    SubdivisionData { code: "AZ-BAR", name: "Bərdə", kind: "Rayon", parent: None },
+    SubdivisionData { code: "AZ-BEY", name: "Beyləqan", kind: "Rayon", parent: None },
+    SubdivisionData { code: "AZ-BIL", name: "Biləsuvar", kind: "Rayon", parent: None },
+    SubdivisionData { code: "AZ-CAB", name: "Cəbrayıl", kind: "Rayon", parent: None },
+    SubdivisionData { code: "AZ-CAL", name: "Cəlilabad", kind: "Rayon", parent: None },
+    SubdivisionData { code: "AZ-CUL", name: "Culfa", kind: "Rayon", parent: Some("AZ-NX") },
+    SubdivisionData { code: "AZ-DAS", name: "Daşkəsən", kind: "Rayon", parent: None },
+    SubdivisionData { code: "AZ-FUZ", name: "Füzuli", kind: "Rayon", parent: None },
+    SubdivisionData { code: "AZ-GA", name: "Gəncə", kind: "Municipality", parent: None },
+    SubdivisionData { code: "AZ-GAD", name: "Gədəbəy", kind: "Rayon", parent: None },
+    SubdivisionData { code: "AZ-GOR", name: "Goranboy", kind: "Rayon", parent: None },
+    SubdivisionData { code: "AZ-GOY", name: "Göyçay", kind: "Rayon", parent: None },
+    SubdivisionData { code: "AZ-GYG", name: "Göygöl", kind: "Rayon", parent: None },
+    SubdivisionData { code: "AZ-HAC", name: "Hacıqabul", kind: "Rayon", parent: None },
+    SubdivisionData { code: "AZ-IMI", name: "İmişli", kind: "Rayon", parent: None },
+    SubdivisionData { code: "AZ-ISM", name: "İsmayıllı", kind: "Rayon", parent: None },
+    SubdivisionData { code: "AZ-KAL", name: "Kəlbəcər", kind: "Rayon", parent: None },
+    SubdivisionData { code: "AZ-KAN", name: "Kǝngǝrli", kind: "Rayon", parent: Some("AZ-NX") },
+    SubdivisionData { code: "AZ-KUR", name: "Kürdəmir", kind: "Rayon", parent: None },
+    SubdivisionData { code: "AZ-LA", name: "Lənkəran", kind: "Municipality", parent: None },
+    SubdivisionData { code: "AZ-LAC", name: "Laçın", kind: "Rayon", parent: None },
+    SubdivisionData { code: "AZ-LAN", name: "Lənkəran", kind: "Rayon", parent: None },
+    SubdivisionData { code: "AZ-LER", name: "Lerik", kind: "Rayon", parent: None },
+    SubdivisionData { code: "AZ-MAS", name: "Masallı", kind: "Rayon", parent: None },
+    SubdivisionData { code: "AZ-MI", name: "Mingəçevir", kind: "Municipality", parent: None },
+    SubdivisionData { code: "AZ-NA", name: "Naftalan", kind: "Municipality", parent: None },
+    SubdivisionData { code: "AZ-NEF", name: "Neftçala", kind: "Rayon", parent: None },
+    SubdivisionData { code: "AZ-NV", name: "Naxçıvan", kind: "Municipality", parent: Some("AZ-NX") },
+    SubdivisionData { code: "AZ-NX", name: "Naxçıvan", kind: "Autonomous republic", parent: None },
+    SubdivisionData { code: "AZ-OGU", name: "Oğuz", kind: "Rayon", parent: None },
+    SubdivisionData { code: "AZ-ORD", name: "Ordubad", kind: "Rayon", parent: Some("AZ-NX") },
+    SubdivisionData { code: "AZ-QAB", name: "Qəbələ", kind: "Rayon", parent: None },
+    SubdivisionData { code: "AZ-QAX", name: "Qax", kind: "Rayon", parent: None },
+    SubdivisionData { code: "AZ-QAZ", name: "Qazax", kind: "Rayon", parent: None },
+    SubdivisionData { code: "AZ-QBA", name: "Quba", kind: "Rayon", parent: None },
+    SubdivisionData { code: "AZ-QBI", name: "Qubadlı", kind: "Rayon", parent: None },
+    SubdivisionData { code: "AZ-QOB", name: "Qobustan", kind: "Rayon", parent: None },
+    SubdivisionData { code: "AZ-QUS", name: "Qusar", kind: "Rayon", parent: None },
+    SubdivisionData { code: "AZ-SA", name: "Şəki", kind: "Municipality", parent: None },
+    SubdivisionData { code: "AZ-SAB", name: "Sabirabad", kind: "Rayon", parent: None },
+    SubdivisionData { code: "AZ-SAD", name: "Sədərək", kind: "Rayon", parent: Some("AZ-NX") },
+    SubdivisionData { code: "AZ-SAH", name: "Şahbuz", kind: "Rayon", parent: Some("AZ-NX") },
+    SubdivisionData { code: "AZ-SAK", name: "Şəki", kind: "Rayon", parent: None },
+    SubdivisionData { code: "AZ-SAL", name: "Salyan", kind: "Rayon", parent: None },
+    SubdivisionData { code: "AZ-SAR", name: "Şərur", kind: "Rayon", parent: Some("AZ-NX") },
+    SubdivisionData { code: "AZ-SAT", name: "Saatlı", kind: "Rayon", parent: None },
+    SubdivisionData { code: "AZ-SBN", name: "Şabran", kind: "Rayon", parent: None },
+    SubdivisionData { code: "AZ-SIY", name: "Siyəzən", kind: "Rayon", parent: None },
+    SubdivisionData { code: "AZ-SKR", name: "Şəmkir", kind: "Rayon", parent: None },
+    SubdivisionData { code: "AZ-SM", name: "Sumqayıt", kind: "Municipality", parent: None },
+    SubdivisionData { code: "AZ-SMI", name: "Şamaxı", kind: "Rayon", parent: None },
+    SubdivisionData { code: "AZ-SMX", name: "Samux", kind: "Rayon", parent: None },
+    SubdivisionData { code: "AZ-SR", name: "Şirvan", kind: "Municipality", parent: None },
+    SubdivisionData { code: "AZ-SUS", name: "Şuşa", kind: "Rayon", parent: None },
+    SubdivisionData { code: "AZ-TAR", name: "Tərtər", kind: "Rayon", parent: None },
+    SubdivisionData { code: "AZ-TOV", name: "Tovuz", kind: "Rayon", parent: None },
+    SubdivisionData { code: "AZ-UCA", name: "Ucar", kind: "Rayon", parent: None },
+    SubdivisionData { code: "AZ-XA", name: "Xankəndi", kind: "Municipality", parent: None },
+    SubdivisionData { code: "AZ-XAC", name: "Xaçmaz", kind: "Rayon", parent: None },
+    SubdivisionData { code: "AZ-XCI", name: "Xocalı", kind: "Rayon", parent: None },
+    SubdivisionData { code: "AZ-XIZ", name: "Xızı", kind: "Rayon", parent: None },
+    SubdivisionData { code: "AZ-XVD", name: "Xocavənd", kind: "Rayon", parent: None },
+    SubdivisionData { code: "AZ-YAR", name: "Yardımlı", kind: "Rayon", parent: None },
+    SubdivisionData { code: "AZ-YE", name: "Yevlax", kind: "Municipality", parent: None },
+    SubdivisionData { code: "AZ-YEV", name: "Yevlax", kind: "Rayon", parent: None },
+    SubdivisionData { code: "AZ-ZAN", name: "Zəngilan", kind: "Rayon", parent: None },
+    SubdivisionData { code: "AZ-ZAQ", name: "Zaqatala", kind: "Rayon", parent: None },
+    SubdivisionData { code: "AZ-ZAR", name: "Zərdab", kind: "Rayon", parent: None },
+    SubdivisionData { code: "BA-BIH", name: "Federacija Bosne i Hercegovine", kind: "Entity", parent: None },
+    SubdivisionData { code: "BA-BRC", name: "Brčko distrikt", kind: "District with special status", parent: None },
+    SubdivisionData { code: "BA-SRP", name: "Republika Srpska", kind: "Entity", parent: None },
+    SubdivisionData { code: "BB-01", name: "Christ Church", kind: "Parish", parent: None },
+    SubdivisionData { code: "BB-02", name: "Saint Andrew", kind: "Parish", parent: None },
+    SubdivisionData { code: "BB-03", name: "Saint George", kind: "Parish", parent: None },
+    SubdivisionData { code: "BB-04", name: "Saint James", kind: "Parish", parent: None },
+    SubdivisionData { code: "BB-05", name: "Saint John", kind: "Parish", parent: None },
+    SubdivisionData { code: "BB-06", name: "Saint Joseph", kind: "Parish", parent: None },
+    SubdivisionData { code: "BB-07", name: "Saint Lucy", kind: "Parish", parent: None },
+    SubdivisionData { code: "BB-08", name: "Saint Michael", kind: "Parish", parent: None },
+    SubdivisionData { code: "BB-09", name: "Saint Peter", kind: "Parish", parent: None },
+    SubdivisionData { code: "BB-10", name: "Saint Philip", kind: "Parish", parent: None },
+    SubdivisionData { code: "BB-11", name: "Saint Thomas", kind: "Parish", parent: None },
+    SubdivisionData { code: "BD-01", name: "Bandarban", kind: "District", parent: Some("BD-B") },
+    SubdivisionData { code: "BD-02", name: "Barguna", kind: "District", parent: Some("BD-A") },
+    SubdivisionData { code: "BD-03", name: "Bogura", kind: "District", parent: Some("BD-E") },
+    SubdivisionData { code: "BD-04", name: "Brahmanbaria", kind: "District", parent: Some("BD-B") },
+    SubdivisionData { code: "BD-05", name: "Bagerhat", kind: "District", parent: Some("BD-D") },
+    SubdivisionData { code: "BD-06", name: "Barishal", kind: "District", parent: Some("BD-A") },
+    SubdivisionData { code: "BD-07", name: "Bhola", kind: "District", parent: Some("BD-A") },
+    SubdivisionData { code: "BD-08", name: "Cumilla", kind: "District", parent: Some("BD-B") },
+    SubdivisionData { code: "BD-09", name: "Chandpur", kind: "District", parent: Some("BD-B") },
+    SubdivisionData { code: "BD-10", name: "Chattogram", kind: "District", parent: Some("BD-B") },
+    SubdivisionData { code: "BD-11", name: "Cox's Bazar", kind: "District", parent: Some("BD-B") },
+    SubdivisionData { code: "BD-12", name: "Chuadanga", kind: "District", parent: Some("BD-D") },
+    SubdivisionData { code: "BD-13", name: "Dhaka", kind: "District", parent: Some("BD-C") },
+    SubdivisionData { code: "BD-14", name: "Dinajpur", kind: "District", parent: Some("BD-F") },
+    SubdivisionData { code: "BD-15", name: "Faridpur", kind: "District", parent: Some("BD-C") },
+    SubdivisionData { code: "BD-16", name: "Feni", kind: "District", parent: Some("BD-B") },
+    SubdivisionData { code: "BD-17", name: "Gopalganj", kind: "District", parent: Some("BD-C") },
+    SubdivisionData { code: "BD-18", name: "Gazipur", kind: "District", parent: Some("BD-C") },
+    SubdivisionData { code: "BD-19", name: "Gaibandha", kind: "District", parent: Some("BD-F") },
+    SubdivisionData { code: "BD-20", name: "Habiganj", kind: "District", parent: Some("BD-G") },
+    SubdivisionData { code: "BD-21", name: "Jamalpur", kind: "District", parent: Some("BD-H") },
+    SubdivisionData { code: "BD-22", name: "Jashore", kind: "District", parent: Some("BD-D") },
+    SubdivisionData { code: "BD-23", name: "Jhenaidah", kind: "District", parent: Some("BD-D") },
+    SubdivisionData { code: "BD-24", name: "Joypurhat", kind: "District", parent: Some("BD-E") },
+    SubdivisionData { code: "BD-25", name: "Jhalakathi", kind: "District", parent: Some("BD-A") },
+    SubdivisionData { code: "BD-26", name: "Kishoreganj", kind: "District", parent: Some("BD-C") },
+    SubdivisionData { code: "BD-27", name: "Khulna", kind: "District", parent: Some("BD-D") },
+    SubdivisionData { code: "BD-28", name: "Kurigram", kind: "District", parent: Some("BD-F") },
+    SubdivisionData { code: "BD-29", name: "Khagrachhari", kind: "District", parent: Some("BD-B") },
+    SubdivisionData { code: "BD-30", name: "Kushtia", kind: "District", parent: Some("BD-D") },
+    SubdivisionData { code: "BD-31", name: "Lakshmipur", kind: "District", parent: Some("BD-B") },
+    SubdivisionData { code: "BD-32", name: "Lalmonirhat", kind: "District", parent: Some("BD-F") },
+    SubdivisionData { code: "BD-33", name: "Manikganj", kind: "District", parent: Some("BD-C") },
+    SubdivisionData { code: "BD-34", name: "Mymensingh", kind: "District", parent: Some("BD-H") },
+    SubdivisionData { code: "BD-35", name: "Munshiganj", kind: "District", parent: Some("BD-C") },
+    SubdivisionData { code: "BD-36", name: "Madaripur", kind: "District", parent: Some("BD-C") },
+    SubdivisionData { code: "BD-37", name: "Magura", kind: "District", parent: Some("BD-D") },
+    SubdivisionData { code: "BD-38", name: "Moulvibazar", kind: "District", parent: Some("BD-G") },
+    SubdivisionData { code: "BD-39", name: "Meherpur", kind: "District", parent: Some("BD-D") },
+    SubdivisionData { code: "BD-40", name: "Narayanganj", kind: "District", parent: Some("BD-C") },
+    SubdivisionData { code: "BD-41", name: "Netrakona", kind: "District", parent: Some("BD-H") },
+    SubdivisionData { code: "BD-42", name: "Narsingdi", kind: "District", parent: Some("BD-C") },
+    SubdivisionData { code: "BD-43", name: "Narail", kind: "District", parent: Some("BD-D") },
+    SubdivisionData { code: "BD-44", name: "Natore", kind: "District", parent: Some("BD-E") },
+    SubdivisionData { code: "BD-45", name: "Chapai Nawabganj", kind: "District", parent: Some("BD-E") },
+    SubdivisionData { code: "BD-46", name: "Nilphamari", kind: "District", parent: Some("BD-F") },
+    SubdivisionData { code: "BD-47", name: "Noakhali", kind: "District", parent: Some("BD-B") },
+    SubdivisionData { code: "BD-48", name: "Naogaon", kind: "District", parent: Some("BD-E") },
+    SubdivisionData { code: "BD-49", name: "Pabna", kind: "District", parent: Some("BD-E") },
+    SubdivisionData { code: "BD-50", name: "Pirojpur", kind: "District", parent: Some("BD-A") },
+    SubdivisionData { code: "BD-51", name: "Patuakhali", kind: "District", parent: Some("BD-A") },
+    SubdivisionData { code: "BD-52", name: "Panchagarh", kind: "District", parent: Some("BD-F") },
+    SubdivisionData { code: "BD-53", name: "Rajbari", kind: "District", parent: Some("BD-C") },
+    SubdivisionData { code: "BD-54", name: "Rajshahi", kind: "District", parent: Some("BD-E") },
+    SubdivisionData { code: "BD-55", name: "Rangpur", kind: "District", parent: Some("BD-F") },
+    SubdivisionData { code: "BD-56", name: "Rangamati", kind: "District", parent: Some("BD-B") },
+    SubdivisionData { code: "BD-57", name: "Sherpur", kind: "District", parent: Some("BD-H") },
+    SubdivisionData { code: "BD-58", name: "Satkhira", kind: "District", parent: Some("BD-D") },
+    SubdivisionData { code: "BD-59", name: "Sirajganj", kind: "District", parent: Some("BD-E") },
+    SubdivisionData { code: "BD-60", name: "Sylhet", kind: "District", parent: Some("BD-G") },
+    SubdivisionData { code: "BD-61", name: "Sunamganj", kind: "District", parent: Some("BD-G") },
+    SubdivisionData { code: "BD-62", name: "Shariatpur", kind: "District", parent: Some("BD-C") },
+    SubdivisionData { code: "BD-63", name: "Tangail", kind: "District", parent: Some("BD-C") },
+    SubdivisionData { code: "BD-64", name: "Thakurgaon", kind: "District", parent: Some("BD-F") },
+    SubdivisionData { code: "BD-A", name: "Barishal", kind: "Division", parent: None },
+    SubdivisionData { code: "BD-B", name: "Chattogram", kind: "Division", parent: None },
+    SubdivisionData { code: "BD-C", name: "Dhaka", kind: "Division", parent: None },
+    SubdivisionData { code: "BD-D", name: "Khulna", kind: "Division", parent: None },
+    SubdivisionData { code: "BD-E", name: "Rajshahi", kind: "Division", parent: None },
+    SubdivisionData { code: "BD-F", name: "Rangpur", kind: "Division", parent: None },
+    SubdivisionData { code: "BD-G", name: "Sylhet", kind: "Division", parent: None },
+    SubdivisionData { code: "BD-H", name: "Mymensingh", kind: "Division", parent: None },
+    SubdivisionData { code: "BE-BRU", name: "Brussels Hoofdstedelijk Gewest", kind: "Region", parent: None },
+    SubdivisionData { code: "BE-VAN", name: "Antwerpen", kind: "Province", parent: Some("BE-VLG") },
+    SubdivisionData { code: "BE-VBR", name: "Vlaams-Brabant", kind: "Province", parent: Some("BE-VLG") },
+    SubdivisionData { code: "BE-VLG", name: "Vlaams Gewest", kind: "Region", parent: None },
+    SubdivisionData { code: "BE-VLI", name: "Limburg", kind: "Province", parent: Some("BE-VLG") },
+    SubdivisionData { code: "BE-VOV", name: "Oost-Vlaanderen", kind: "Province", parent: Some("BE-VLG") },
+    SubdivisionData { code: "BE-VWV", name: "West-Vlaanderen", kind: "Province", parent: Some("BE-VLG") },
+    SubdivisionData { code: "BE-WAL", name: "wallonne, Région", kind: "Region", parent: None },
+    SubdivisionData { code: "BE-WBR", name: "Brabant wallon", kind: "Province", parent: Some("BE-WAL") },
+    SubdivisionData { code: "BE-WHT", name: "Hainaut", kind: "Province", parent: Some("BE-WAL") },
+    SubdivisionData { code: "BE-WLG", name: "Liège", kind: "Province", parent: Some("BE-WAL") },
+    SubdivisionData { code: "BE-WLX", name: "Luxembourg", kind: "Province", parent: Some("BE-WAL") },
+    SubdivisionData { code: "BE-WNA", name: "Namur", kind: "Province", parent: Some("BE-WAL") },
+    SubdivisionData { code: "BF-01", name: "Boucle du Mouhoun", kind: "Region", parent: None },
+    SubdivisionData { code: "BF-02", name: "Cascades", kind: "Region", parent: None },
+    SubdivisionData { code: "BF-03", name: "Centre", kind: "Region", parent: None },
+    SubdivisionData { code: "BF-04", name: "Centre-Est", kind: "Region", parent: None },
+    SubdivisionData { code: "BF-05", name: "Centre-Nord", kind: "Region", parent: None },
+    SubdivisionData { code: "BF-06", name: "Centre-Ouest", kind: "Region", parent: None },
+    SubdivisionData { code: "BF-07", name: "Centre-Sud", kind: "Region", parent: None },
+    SubdivisionData { code: "BF-08", name: "Est", kind: "Region", parent: None },
+    SubdivisionData { code: "BF-09", name: "Hauts-Bassins", kind: "Region", parent: None },
+    SubdivisionData { code: "BF-10", name: "Nord", kind: "Region", parent: None },
+    SubdivisionData { code: "BF-11", name: "Plateau-Central", kind: "Region", parent: None },
+    SubdivisionData { code: "BF-12", name: "Sahel", kind: "Region", parent: None },
+    SubdivisionData { code: "BF-13", name: "Sud-Ouest", kind: "Region", parent: None },
+    SubdivisionData { code: "BF-BAL", name: "Balé", kind: "Province", parent: Some("BF-01") },
+    SubdivisionData { code: "BF-BAM", name: "Bam", kind: "Province", parent: Some("BF-05") },
+    SubdivisionData { code: "BF-BAN", name: "Banwa", kind: "Province", parent: Some("BF-01") },
+    SubdivisionData { code: "BF-BAZ", name: "Bazèga", kind: "Province", parent: Some("BF-07") },
+    SubdivisionData { code: "BF-BGR", name: "Bougouriba", kind: "Province", parent: Some("BF-13") },
+    SubdivisionData { code: "BF-BLG", name: "Boulgou", kind: "Province", parent: Some("BF-04") },
+    SubdivisionData { code: "BF-BLK", name: "Boulkiemdé", kind: "Province", parent: Some("BF-06") },
+    SubdivisionData { code: "BF-COM", name: "Comoé", kind: "Province", parent: Some("BF-02") },
+    SubdivisionData { code: "BF-GAN", name: "Ganzourgou", kind: "Province", parent: Some("BF-11") },
+    SubdivisionData { code: "BF-GNA", name: "Gnagna", kind: "Province", parent: Some("BF-08") },
+    SubdivisionData { code: "BF-GOU", name: "Gourma", kind: "Province", parent: Some("BF-08") },
+    SubdivisionData { code: "BF-HOU", name: "Houet", kind: "Province", parent: Some("BF-09") },
+    SubdivisionData { code: "BF-IOB", name: "Ioba", kind: "Province", parent: Some("BF-13") },
+    SubdivisionData { code: "BF-KAD", name: "Kadiogo", kind: "Province", parent: Some("BF-03") },
+    SubdivisionData { code: "BF-KEN", name: "Kénédougou", kind: "Province", parent: Some("BF-09") },
+    SubdivisionData { code: "BF-KMD", name: "Komondjari", kind: "Province", parent: Some("BF-08") },
+    SubdivisionData { code: "BF-KMP", name: "Kompienga", kind: "Province", parent: Some("BF-08") },
+    SubdivisionData { code: "BF-KOP", name: "Koulpélogo", kind: "Province", parent: Some("BF-04") },
+    SubdivisionData { code: "BF-KOS", name: "Kossi", kind: "Province", parent: Some("BF-01") },
+    SubdivisionData { code: "BF-KOT", name: "Kouritenga", kind: "Province", parent: Some("BF-04") },
+    SubdivisionData { code: "BF-KOW", name: "Kourwéogo", kind: "Province", parent: Some("BF-11") },
+    SubdivisionData { code: "BF-LER", name: "Léraba", kind: "Province", parent: Some("BF-02") },
+    SubdivisionData { code: "BF-LOR", name: "Loroum", kind: "Province", parent: Some("BF-10") },
+    SubdivisionData { code: "BF-MOU", name: "Mouhoun", kind: "Province", parent: Some("BF-01") },
+    SubdivisionData { code: "BF-NAM", name: "Namentenga", kind: "Province", parent: Some("BF-05") },
+    SubdivisionData { code: "BF-NAO", name: "Nahouri", kind: "Province", parent: Some("BF-07") },
+    SubdivisionData { code: "BF-NAY", name: "Nayala", kind: "Province", parent: Some("BF-01") },
+    SubdivisionData { code: "BF-NOU", name: "Noumbiel", kind: "Province", parent: Some("BF-13") },
+    SubdivisionData { code: "BF-OUB", name: "Oubritenga", kind: "Province", parent: Some("BF-11") },
+    SubdivisionData { code: "BF-OUD", name: "Oudalan", kind: "Province", parent: Some("BF-12") },
+    SubdivisionData { code: "BF-PAS", name: "Passoré", kind: "Province", parent: Some("BF-10") },
+    SubdivisionData { code: "BF-PON", name: "Poni", kind: "Province", parent: Some("BF-13") },
+    SubdivisionData { code: "BF-SEN", name: "Séno", kind: "Province", parent: Some("BF-12") },
+    SubdivisionData { code: "BF-SIS", name: "Sissili", kind: "Province", parent: Some("BF-06") },
+    SubdivisionData { code: "BF-SMT", name: "Sanmatenga", kind: "Province", parent: Some("BF-05") },
+    SubdivisionData { code: "BF-SNG", name: "Sanguié", kind: "Province", parent: Some("BF-06") },
+    SubdivisionData { code: "BF-SOM", name: "Soum", kind: "Province", parent: Some("BF-12") },
+    SubdivisionData { code: "BF-SOR", name: "Sourou", kind: "Province", parent: Some("BF-01") },
+    SubdivisionData { code: "BF-TAP", name: "Tapoa", kind: "Province", parent: Some("BF-08") },
+    SubdivisionData { code: "BF-TUI", name: "Tuy", kind: "Province", parent: Some("BF-09") },
+    SubdivisionData { code: "BF-YAG", name: "Yagha", kind: "Province", parent: Some("BF-12") },
+    SubdivisionData { code: "BF-YAT", name: "Yatenga", kind: "Province", parent: Some("BF-10") },
+    SubdivisionData { code: "BF-ZIR", name: "Ziro", kind: "Province", parent: Some("BF-06") },
+    SubdivisionData { code: "BF-ZON", name: "Zondoma", kind: "Province", parent: Some("BF-10") },
+    SubdivisionData { code: "BF-ZOU", name: "Zoundwéogo", kind: "Province", parent: Some("BF-07") },
+    SubdivisionData { code: "BG-01", name: "Blagoevgrad", kind: "District", parent: None },
+    SubdivisionData { code: "BG-02", name: "Burgas", kind: "District", parent: None },
+    SubdivisionData { code: "BG-03", name: "Varna", kind: "District", parent: None },
+    SubdivisionData { code: "BG-04", name: "Veliko Tarnovo", kind: "District", parent: None },
+    SubdivisionData { code: "BG-05", name: "Vidin", kind: "District", parent: None },
+    SubdivisionData { code: "BG-06", name: "Vratsa", kind: "District", parent: None },
+    SubdivisionData { code: "BG-07", name: "Gabrovo", kind: "District", parent: None },
+    SubdivisionData { code: "BG-08", name: "Dobrich", kind: "District", parent: None },
+    SubdivisionData { code: "BG-09", name: "Kardzhali", kind: "District", parent: None },
+    SubdivisionData { code: "BG-10", name: "Kyustendil", kind: "District", parent: None },
+    SubdivisionData { code: "BG-11", name: "Lovech", kind: "District", parent: None },
+    SubdivisionData { code: "BG-12", name: "Montana", kind: "District", parent: None },
+    SubdivisionData { code: "BG-13", name: "Pazardzhik", kind: "District", parent: None },
+    SubdivisionData { code: "BG-14", name: "Pernik", kind: "District", parent: None },
+    SubdivisionData { code: "BG-15", name: "Pleven", kind: "District", parent: None },
+    SubdivisionData { code: "BG-16", name: "Plovdiv", kind: "District", parent: None },
+    SubdivisionData { code: "BG-17", name: "Razgrad", kind: "District", parent: None },
+    SubdivisionData { code: "BG-18", name: "Ruse", kind: "District", parent: None },
+    SubdivisionData { code: "BG-19", name: "Silistra", kind: "District", parent: None },
+    SubdivisionData { code: "BG-20", name: "Sliven", kind: "District", parent: None },
+    SubdivisionData { code: "BG-21", name: "Smolyan", kind: "District", parent: None },
+    SubdivisionData { code: "BG-22", name: "Sofia (stolitsa)", kind: "District", parent: None },
+    SubdivisionData { code: "BG-23", name: "Sofia", kind: "District", parent: None },
+    SubdivisionData { code: "BG-24", name: "Stara Zagora", kind: "District", parent: None },
+    SubdivisionData { code: "BG-25", name: "Targovishte", kind: "District", parent: None },
+    SubdivisionData { code: "BG-26", name: "Haskovo", kind: "District", parent: None },
+    SubdivisionData { code: "BG-27", name: "Shumen", kind: "District", parent: None },
+    SubdivisionData { code: "BG-28", name: "Yambol", kind: "District", parent: None },
+    SubdivisionData { code: "BH-13", name: "Al ‘Āşimah", kind: "Governorate", parent: None },
+    SubdivisionData { code: "BH-14", name: "Al Janūbīyah", kind: "Governorate", parent: None },
+    SubdivisionData { code: "BH-15", name: "Al Muḩarraq", kind: "Governorate", parent: None },
+    SubdivisionData { code: "BH-17", name: "Ash Shamālīyah", kind: "Governorate", parent: None },
+    SubdivisionData { code: "BI-BB", name: "Bubanza", kind: "Province", parent: None },
+    SubdivisionData { code: "BI-BL", name: "Bujumbura Rural", kind: "Province", parent: None },
+    SubdivisionData { code: "BI-BM", name: "Bujumbura Mairie", kind: "Province", parent: None },
+    SubdivisionData { code: "BI-BR", name: "Bururi", kind: "Province", parent: None },
+    SubdivisionData { code: "BI-CA", name: "Cankuzo", kind: "Province", parent: None },
+    SubdivisionData { code: "BI-CI", name: "Cibitoke", kind: "Province", parent: None },
+    SubdivisionData { code: "BI-GI", name: "Gitega", kind: "Province", parent: None },
+    SubdivisionData { code: "BI-KI", name: "Kirundo", kind: "Province", parent: None },
+    SubdivisionData { code: "BI-KR", name: "Karuzi", kind: "Province", parent: None },
+    SubdivisionData { code: "BI-KY", name: "Kayanza", kind: "Province", parent: None },
+    SubdivisionData { code: "BI-MA", name: "Makamba", kind: "Province", parent: None },
+    SubdivisionData { code: "BI-MU", name: "Muramvya", kind: "Province", parent: None },
+    SubdivisionData { code: "BI-MW", name: "Mwaro", kind: "Province", parent: None },
+    SubdivisionData { code: "BI-MY", name: "Muyinga", kind: "Province", parent: None },
+    SubdivisionData { code: "BI-NG", name: "Ngozi", kind: "Province", parent: None },
+    SubdivisionData { code: "BI-RM", name: "Rumonge", kind: "Province", parent: None },
+    SubdivisionData { code: "BI-RT", name: "Rutana", kind: "Province", parent: None },
+    SubdivisionData { code: "BI-RY", name: "Ruyigi", kind: "Province", parent: None },
+    SubdivisionData { code: "BJ-AK", name: "Atacora", kind: "Department", parent: None },
+    SubdivisionData { code: "BJ-AL", name: "Alibori", kind: "Department", parent: None },
+    SubdivisionData { code: "BJ-AQ", name: "Atlantique", kind: "Department", parent: None },
+    SubdivisionData { code: "BJ-BO", name: "Borgou", kind: "Department", parent: None },
+    SubdivisionData { code: "BJ-CO", name: "Collines", kind: "Department", parent: None },
+    SubdivisionData { code: "BJ-DO", name: "Donga", kind: "Department", parent: None },
+    SubdivisionData { code: "BJ-KO", name: "Couffo", kind: "Department", parent: None },
+    SubdivisionData { code: "BJ-LI", name: "Littoral", kind: "Department", parent: None },
+    SubdivisionData { code: "BJ-MO", name: "Mono", kind: "Department", parent: None },
+    SubdivisionData { code: "BJ-OU", name: "Ouémé", kind: "Department", parent: None },
+    SubdivisionData { code: "BJ-PL", name: "Plateau", kind: "Department", parent: None },
+    SubdivisionData { code: "BJ-ZO", name: "Zou", kind: "Department", parent: None },
+    SubdivisionData { code: "BN-BE", name: "Belait", kind: "District", parent: None },
+    SubdivisionData { code: "BN-BM", name: "Brunei-Muara", kind: "District", parent: None },
+    SubdivisionData { code: "BN-TE", name: "Temburong", kind: "District", parent: None },
+    SubdivisionData { code: "BN-TU", name: "Tutong", kind: "District", parent: None },
+    SubdivisionData { code: "BO-B", name: "El Beni", kind: "Department", parent: None },
+    SubdivisionData { code: "BO-C", name: "Cochabamba", kind: "Department", parent: None },
+    SubdivisionData { code: "BO-H", name: "Chuquisaca", kind: "Department", parent: None },
+    SubdivisionData { code: "BO-L", name: "La Paz", kind: "Department", parent: None },
+    SubdivisionData { code: "BO-N", name: "Pando", kind: "Department", parent: None },
+    SubdivisionData { code: "BO-O", name: "Oruro", kind: "Department", parent: None },
+    SubdivisionData { code: "BO-P", name: "Potosí", kind: "Department", parent: None },
+    SubdivisionData { code: "BO-S", name: "Santa Cruz", kind: "Department", parent: None },
+    SubdivisionData { code: "BO-T", name: "Tarija", kind: "Department", parent: None },
+    SubdivisionData { code: "BQ-BO", name: "Bonaire", kind: "Special municipality", parent: None },
+    SubdivisionData { code: "BQ-SA", name: "Saba", kind: "Special municipality", parent: None },
+    SubdivisionData { code: "BQ-SE", name: "Sint Eustatius", kind: "Special municipality", parent: None },
+    SubdivisionData { code: "BR-AC", name: "Acre", kind: "State", parent: None },
+    SubdivisionData { code: "BR-AL", name: "Alagoas", kind: "State", parent: None },
+    SubdivisionData { code: "BR-AM", name: "Amazonas", kind: "State", parent: None },
+    SubdivisionData { code: "BR-AP", name: "Amapá", kind: "State", parent: None },
+    SubdivisionData { code: "BR-BA", name: "Bahia", kind: "State", parent: None },
+    SubdivisionData { code: "BR-CE", name: "Ceará", kind: "State", parent: None },
+    SubdivisionData { code: "BR-DF", name: "Distrito Federal", kind: "Federal district", parent: None },
+    SubdivisionData { code: "BR-ES", name: "Espírito Santo", kind: "State", parent: None },
+    SubdivisionData { code: "BR-GO", name: "Goiás", kind: "State", parent: None },
+    SubdivisionData { code: "BR-MA", name: "Maranhão", kind: "State", parent: None },
+    SubdivisionData { code: "BR-MG", name: "Minas Gerais", kind: "State", parent: None },
+    SubdivisionData { code: "BR-MS", name: "Mato Grosso do Sul", kind: "State", parent: None },
+    SubdivisionData { code: "BR-MT", name: "Mato Grosso", kind: "State", parent: None },
+    SubdivisionData { code: "BR-PA", name: "Pará", kind: "State", parent: None },
+    SubdivisionData { code: "BR-PB", name: "Paraíba", kind: "State", parent: None },
+    SubdivisionData { code: "BR-PE", name: "Pernambuco", kind: "State", parent: None },
+    SubdivisionData { code: "BR-PI", name: "Piauí", kind: "State", parent: None },
+    SubdivisionData { code: "BR-PR", name: "Paraná", kind: "State", parent: None },
+    SubdivisionData { code: "BR-RJ", name: "Rio de Janeiro", kind: "State", parent: None },
+    SubdivisionData { code: "BR-RN", name: "Rio Grande do Norte", kind: "State", parent: None },
+    SubdivisionData { code: "BR-RO", name: "Rondônia", kind: "State", parent: None },
+    SubdivisionData { code: "BR-RR", name: "Roraima", kind: "State", parent: None },
+    SubdivisionData { code: "BR-RS", name: "Rio Grande do Sul", kind: "State", parent: None },
+    SubdivisionData { code: "BR-SC", name: "Santa Catarina", kind: "State", parent: None },
+    SubdivisionData { code: "BR-SE", name: "Sergipe", kind: "State", parent: None },
+    SubdivisionData { code: "BR-SP", name: "São Paulo", kind: "State", parent: None },
+    SubdivisionData { code: "BR-TO", name: "Tocantins", kind: "State", parent: None },
+    SubdivisionData { code: "BS-AK", name: "Acklins", kind: "District", parent: None },
+    SubdivisionData { code: "BS-BI", name: "Bimini", kind: "District", parent: None },
+    SubdivisionData { code: "BS-BP", name: "Black Point", kind: "District", parent: None },
+    SubdivisionData { code: "BS-BY", name: "Berry Islands", kind: "District", parent: None },
+    SubdivisionData { code: "BS-CE", name: "Central Eleuthera", kind: "District", parent: None },
+    SubdivisionData { code: "BS-CI", name: "Cat Island", kind: "District", parent: None },
+    SubdivisionData { code: "BS-CK", name: "Crooked Island and Long Cay", kind: "District", parent: None },
+    SubdivisionData { code: "BS-CO", name: "Central Abaco", kind: "District", parent: None },
+    SubdivisionData { code: "BS-CS", name: "Central Andros", kind: "District", parent: None },
+    SubdivisionData { code: "BS-EG", name: "East Grand Bahama", kind: "District", parent: None },
+    SubdivisionData { code: "BS-EX", name: "Exuma", kind: "District", parent: None },
+    SubdivisionData { code: "BS-FP", name: "City of Freeport", kind: "District", parent: None },
+    SubdivisionData { code: "BS-GC", name: "Grand Cay", kind: "District", parent: None },
+    SubdivisionData { code: "BS-HI", name: "Harbour Island", kind: "District", parent: None },
+    SubdivisionData { code: "BS-HT", name: "Hope Town", kind: "District", parent: None },
+    SubdivisionData { code: "BS-IN", name: "Inagua", kind: "District", parent: None },
+    SubdivisionData { code: "BS-LI", name: "Long Island", kind: "District", parent: None },
+    SubdivisionData { code: "BS-MC", name: "Mangrove Cay", kind: "District", parent: None },
+    SubdivisionData { code: "BS-MG", name: "Mayaguana", kind: "District", parent: None },
+    SubdivisionData { code: "BS-MI", name: "Moore's Island", kind: "District", parent: None },
+    SubdivisionData { code: "BS-NE", name: "North Eleuthera", kind: "District", parent: None },
+    SubdivisionData { code: "BS-NO", name: "North Abaco", kind: "District", parent: None },
+    SubdivisionData { code: "BS-NP", name: "New Providence", kind: "Island", parent: None },
+    SubdivisionData { code: "BS-NS", name: "North Andros", kind: "District", parent: None },
+    SubdivisionData { code: "BS-RC", name: "Rum Cay", kind: "District", parent: None },
+    SubdivisionData { code: "BS-RI", name: "Ragged Island", kind: "District", parent: None },
+    SubdivisionData { code: "BS-SA", name: "South Andros", kind: "District", parent: None },
+    SubdivisionData { code: "BS-SE", name: "South Eleuthera", kind: "District", parent: None },
+    SubdivisionData { code: "BS-SO", name: "South Abaco", kind: "District", parent: None },
+    SubdivisionData { code: "BS-SS", name: "San Salvador", kind: "District", parent: None },
+    SubdivisionData { code: "BS-SW", name: "Spanish Wells", kind: "District", parent: None },
+    SubdivisionData { code: "BS-WG", name: "West Grand Bahama", kind: "District", parent: None },
+    SubdivisionData { code: "BT-11", name: "Paro", kind: "District", parent: None },
+    SubdivisionData { code: "BT-12", name: "Chhukha", kind: "District", parent: None },
+    SubdivisionData { code: "BT-13", name: "Haa", kind: "District", parent: None },
+    SubdivisionData { code: "BT-14", name: "Samtse", kind: "District", parent: None },
+    SubdivisionData { code: "BT-15", name: "Thimphu", kind: "District", parent: None },
+    SubdivisionData { code: "BT-21", name: "Tsirang", kind: "District", parent: None },
+    SubdivisionData { code: "BT-22", name: "Dagana", kind: "District", parent: None },
+    SubdivisionData { code: "BT-23", name: "Punakha", kind: "District", parent: None },
+    SubdivisionData { code: "BT-24", name: "Wangdue Phodrang", kind: "District", parent: None },
+    SubdivisionData { code: "BT-31", name: "Sarpang", kind: "District", parent: None },
+    SubdivisionData { code: "BT-32", name: "Trongsa", kind: "District", parent: None },
+    SubdivisionData { code: "BT-33", name: "Bumthang", kind: "District", parent: None },
+    SubdivisionData { code: "BT-34", name: "Zhemgang", kind: "District", parent: None },
+    SubdivisionData { code: "BT-41", name: "Trashigang", kind: "District", parent: None },
+    SubdivisionData { code: "BT-42", name: "Monggar", kind: "District", parent: None },
+    SubdivisionData { code: "BT-43", name: "Pema Gatshel", kind: "District", parent: None },
+    SubdivisionData { code: "BT-44", name: "Lhuentse", kind: "District", parent: None },
+    SubdivisionData { code: "BT-45", name: "Samdrup Jongkhar", kind: "District", parent: None },
+    SubdivisionData { code: "BT-GA", name: "Gasa", kind: "District", parent: None },
+    SubdivisionData { code: "BT-TY", name: "Trashi Yangtse", kind: "District", parent: None },
+    SubdivisionData { code: "BW-CE", name: "Central", kind: "District", parent: None },
+    SubdivisionData { code: "BW-CH", name: "Chobe", kind: "District", parent: None },
+    SubdivisionData { code: "BW-FR", name: "Francistown", kind: "City", parent: None },
+    SubdivisionData { code: "BW-GA", name: "Gaborone", kind: "City", parent: None },
+    SubdivisionData { code: "BW-GH", name: "Ghanzi", kind: "District", parent: None },
+    SubdivisionData { code: "BW-JW", name: "Jwaneng", kind: "Town", parent: None },
+    SubdivisionData { code: "BW-KG", name: "Kgalagadi", kind: "District", parent: None },
+    SubdivisionData { code: "BW-KL", name: "Kgatleng", kind: "District", parent: None },
+    SubdivisionData { code: "BW-KW", name: "Kweneng", kind: "District", parent: None },
+    SubdivisionData { code: "BW-LO", name: "Lobatse", kind: "Town", parent: None },
+    SubdivisionData { code: "BW-NE", name: "North East", kind: "District", parent: None },
+    SubdivisionData { code: "BW-NW", name: "North West", kind: "District", parent: None },
+    SubdivisionData { code: "BW-SE", name: "South East", kind: "District", parent: None },
+    SubdivisionData { code: "BW-SO", name: "Southern", kind: "District", parent: None },
+    SubdivisionData { code: "BW-SP", name: "Selibe Phikwe", kind: "Town", parent: None },
+    SubdivisionData { code: "BW-ST", name: "Sowa Town", kind: "Town", parent: None },
+    SubdivisionData { code: "BY-BR", name: "Bresckaja voblasć", kind: "Oblast", parent: None },
+    SubdivisionData { code: "BY-HM", name: "Gorod Minsk", kind: "City", parent: None },
+    SubdivisionData { code: "BY-HO", name: "Gomel'skaja oblast'", kind: "Oblast", parent: None },
+    SubdivisionData { code: "BY-HR", name: "Grodnenskaja oblast'", kind: "Oblast", parent: None },
+    SubdivisionData { code: "BY-MA", name: "Mahilioŭskaja voblasć", kind: "Oblast", parent: None },
+    SubdivisionData { code: "BY-MI", name: "Minskaja oblast'", kind: "Oblast", parent: None },
+    SubdivisionData { code: "BY-VI", name: "Viciebskaja voblasć", kind: "Oblast", parent: None },
+    SubdivisionData { code: "BZ-BZ", name: "Belize", kind: "District", parent: None },
+    SubdivisionData { code: "BZ-CY", name: "Cayo", kind: "District", parent: None },
+    SubdivisionData { code: "BZ-CZL", name: "Corozal", kind: "District", parent: None },
+    SubdivisionData { code: "BZ-OW", name: "Orange Walk", kind: "District", parent: None },
+    SubdivisionData { code: "BZ-SC", name: "Stann Creek", kind: "District", parent: None },
+    SubdivisionData { code: "BZ-TOL", name: "Toledo", kind: "District", parent: None },
+    SubdivisionData { code: "CA-AB", name: "Alberta", kind: "Province", parent: None },
+    SubdivisionData { code: "CA-BC", name: "British Columbia", kind: "Province", parent: None },
+    SubdivisionData { code: "CA-MB", name: "Manitoba", kind: "Province", parent: None },
+    SubdivisionData { code: "CA-NB", name: "New Brunswick", kind: "Province", parent: None },
+    SubdivisionData { code: "CA-NL", name: "Newfoundland and Labrador", kind: "Province", parent: None },
+    SubdivisionData { code: "CA-NS", name: "Nova Scotia", kind: "Province", parent: None },
+    SubdivisionData { code: "CA-NT", name: "Northwest Territories", kind: "Territory", parent: None },
+    SubdivisionData { code: "CA-NU", name: "Nunavut", kind: "Territory", parent: None },
+    SubdivisionData { code: "CA-ON", name: "Ontario", kind: "Province", parent: None },
+    SubdivisionData { code: "CA-PE", name: "Prince Edward Island", kind: "Province", parent: None },
+    SubdivisionData { code: "CA-QC", name: "Quebec", kind: "Province", parent: None },
+    SubdivisionData { code: "CA-SK", name: "Saskatchewan", kind: "Province", parent: None },
+    SubdivisionData { code: "CA-YT", name: "Yukon", kind: "Territory", parent: None },
+    SubdivisionData { code: "CD-BC", name: "Kongo Central", kind: "Province", parent: None },
+    SubdivisionData { code: "CD-BU", name: "Bas-Uélé", kind: "Province", parent: None },
+    SubdivisionData { code: "CD-EQ", name: "Équateur", kind: "Province", parent: None },
+    SubdivisionData { code: "CD-HK", name: "Haut-Katanga", kind: "Province", parent: None },
+    SubdivisionData { code: "CD-HL", name: "Haut-Lomami", kind: "Province", parent: None },
+    SubdivisionData { code: "CD-HU", name: "Haut-Uélé", kind: "Province", parent: None },
+    SubdivisionData { code: "CD-IT", name: "Ituri", kind: "Province", parent: None },
+    SubdivisionData { code: "CD-KC", name: "Kasaï Central", kind: "Province", parent: None },
+    SubdivisionData { code: "CD-KE", name: "Kasaï Oriental", kind: "Province", parent: None },
+    SubdivisionData { code: "CD-KG", name: "Kwango", kind: "Province", parent: None },
+    SubdivisionData { code: "CD-KL", name: "Kwilu", kind: "Province", parent: None },
+    SubdivisionData { code: "CD-KN", name: "Kinshasa", kind: "City", parent: None },
+    SubdivisionData { code: "CD-KS", name: "Kasaï", kind: "Province", parent: None },
+    SubdivisionData { code: "CD-LO", name: "Lomami", kind: "Province", parent: None },
+    SubdivisionData { code: "CD-LU", name: "Lualaba", kind: "Province", parent: None },
+    SubdivisionData { code: "CD-MA", name: "Maniema", kind: "Province", parent: None },
+    SubdivisionData { code: "CD-MN", name: "Mai-Ndombe", kind: "Province", parent: None },
+    SubdivisionData { code: "CD-MO", name: "Mongala", kind: "Province", parent: None },
+    SubdivisionData { code: "CD-NK", name: "Nord-Kivu", kind: "Province", parent: None },
+    SubdivisionData { code: "CD-NU", name: "Nord-Ubangi", kind: "Province", parent: None },
+    SubdivisionData { code: "CD-SA", name: "Sankuru", kind: "Province", parent: None },
+    SubdivisionData { code: "CD-SK", name: "Sud-Kivu", kind: "Province", parent: None },
+    SubdivisionData { code: "CD-SU", name: "Sud-Ubangi", kind: "Province", parent: None },
+    SubdivisionData { code: "CD-TA", name: "Tanganyika", kind: "Province", parent: None },
+    SubdivisionData { code: "CD-TO", name: "Tshopo", kind: "Province", parent: None },
+    SubdivisionData { code: "CD-TU", name: "Tshuapa", kind: "Province", parent: None },
+    SubdivisionData { code: "CF-AC", name: "Ouham", kind: "Prefecture", parent: None },
+    SubdivisionData { code: "CF-BB", name: "Bamingui-Bangoran", kind: "Prefecture", parent: None },
+    SubdivisionData { code: "CF-BGF", name: "Bangui", kind: "Commune", parent: None },
+    SubdivisionData { code: "CF-BK", name: "Basse-Kotto", kind: "Prefecture", parent: None },
+    SubdivisionData { code: "CF-HK", name: "Haute-Kotto", kind: "Prefecture", parent: None },
+    SubdivisionData { code: "CF-HM", name: "Haut-Mbomou", kind: "Prefecture", parent: None },
+    SubdivisionData { code: "CF-HS", name: "Haute-Sangha / Mambéré-Kadéï", kind: "Prefecture", parent: None },
+    SubdivisionData { code: "CF-KB", name: "Gribingui", kind: "Economic prefecture", parent: None },
+    SubdivisionData { code: "CF-KG", name: "Kemö-Gïrïbïngï", kind: "Prefecture", parent: None },
+    SubdivisionData { code: "CF-LB", name: "Lobaye", kind: "Prefecture", parent: None },
+    SubdivisionData { code: "CF-MB", name: "Mbomou", kind: "Prefecture", parent: None },
+    SubdivisionData { code: "CF-MP", name: "Ombella-Mpoko", kind: "Prefecture", parent: None },
+    SubdivisionData { code: "CF-NM", name: "Nana-Mambéré", kind: "Prefecture", parent: None },
+    SubdivisionData { code: "CF-OP", name: "Ouham-Pendé", kind: "Prefecture", parent: None },
+    SubdivisionData { code: "CF-SE", name: "Sangha", kind: "Economic prefecture", parent: None },
+    SubdivisionData { code: "CF-UK", name: "Ouaka", kind: "Prefecture", parent: None },
+    SubdivisionData { code: "CF-VK", name: "Vakaga", kind: "Prefecture", parent: None },
+    SubdivisionData { code: "CG-11", name: "Bouenza", kind: "Department", parent: None },
+    SubdivisionData { code: "CG-12", name: "Pool", kind: "Department", parent: None },
+    SubdivisionData { code: "CG-13", name: "Sangha", kind: "Department", parent: None },
+    SubdivisionData { code: "CG-14", name: "Plateaux", kind: "Department", parent: None },
+    SubdivisionData { code: "CG-15", name: "Cuvette-Ouest", kind: "Department", parent: None },
+    SubdivisionData { code: "CG-16", name: "Pointe-Noire", kind: "Department", parent: None },
+    SubdivisionData { code: "CG-2", name: "Lékoumou", kind: "Department", parent: None },
+    SubdivisionData { code: "CG-5", name: "Kouilou", kind: "Department", parent: None },
+    SubdivisionData { code: "CG-7", name: "Likouala", kind: "Department", parent: None },
+    SubdivisionData { code: "CG-8", name: "Cuvette", kind: "Department", parent: None },
+    SubdivisionData { code: "CG-9", name: "Niari", kind: "Department", parent: None },
+    SubdivisionData { code: "CG-BZV", name: "Brazzaville", kind: "Department", parent: None },
+    SubdivisionData { code: "CH-AG", name: "Aargau", kind: "Canton", parent: None },
+    SubdivisionData { code: "CH-AI", name: "Appenzell Innerrhoden", kind: "Canton", parent: None },
+    SubdivisionData { code: "CH-AR", name: "Appenzell Ausserrhoden", kind: "Canton", parent: None },
+    SubdivisionData { code: "CH-BE", name: "Bern", kind: "Canton", parent: None },
+    SubdivisionData { code: "CH-BL", name: "Basel-Landschaft", kind: "Canton", parent: None },
+    SubdivisionData { code: "CH-BS", name: "Basel-Stadt", kind: "Canton", parent: None },
+    SubdivisionData { code: "CH-FR", name: "Freiburg", kind: "Canton", parent: None },
+    SubdivisionData { code: "CH-GE", name: "Genève", kind: "Canton", parent: None },
+    SubdivisionData { code: "CH-GL", name: "Glarus", kind: "Canton", parent: None },
+    SubdivisionData { code: "CH-GR", name: "Graubünden", kind: "Canton", parent: None },
+    SubdivisionData { code: "CH-JU", name: "Jura", kind: "Canton", parent: None },
+    SubdivisionData { code: "CH-LU", name: "Luzern", kind: "Canton", parent: None },
+    SubdivisionData { code: "CH-NE", name: "Neuchâtel", kind: "Canton", parent: None },
+    SubdivisionData { code: "CH-NW", name: "Nidwalden", kind: "Canton", parent: None },
+    SubdivisionData { code: "CH-OW", name: "Obwalden", kind: "Canton", parent: None },
+    SubdivisionData { code: "CH-SG", name: "Sankt Gallen", kind: "Canton", parent: None },
+    SubdivisionData { code: "CH-SH", name: "Schaffhausen", kind: "Canton", parent: None },
+    SubdivisionData { code: "CH-SO", name: "Solothurn", kind: "Canton", parent: None },
+    SubdivisionData { code: "CH-SZ", name: "Schwyz", kind: "Canton", parent: None },
+    SubdivisionData { code: "CH-TG", name: "Thurgau", kind: "Canton", parent: None },
+    SubdivisionData { code: "CH-TI", name: "Ticino", kind: "Canton", parent: None },
+    SubdivisionData { code: "CH-UR", name: "Uri", kind: "Canton", parent: None },
+    SubdivisionData { code: "CH-VD", name: "Vaud", kind: "Canton", parent: None },
+    SubdivisionData { code: "CH-VS", name: "Valais", kind: "Canton", parent: None },
+    SubdivisionData { code: "CH-ZG", name: "Zug", kind: "Canton", parent: None },
+    SubdivisionData { code: "CH-ZH", name: "Zürich", kind: "Canton", parent: None },
+    SubdivisionData { code: "CI-AB", name: "Abidjan", kind: "Autonomous district", parent: None },
+    SubdivisionData { code: "CI-BS", name: "Bas-Sassandra", kind: "District", parent: None },
+    SubdivisionData { code: "CI-CM", name: "Comoé", kind: "District", parent: None },
+    SubdivisionData { code: "CI-DN", name: "Denguélé", kind: "District", parent: None },
+    SubdivisionData { code: "CI-GD", name: "Gôh-Djiboua", kind: "District", parent: None },
+    SubdivisionData { code: "CI-LC", name: "Lacs", kind: "District", parent: None },
+    SubdivisionData { code: "CI-LG", name: "Lagunes", kind: "District", parent: None },
+    SubdivisionData { code: "CI-MG", name: "Montagnes", kind: "District", parent: None },
+    SubdivisionData { code: "CI-SM", name: "Sassandra-Marahoué", kind: "District", parent: None },
+    SubdivisionData { code: "CI-SV", name: "Savanes", kind: "District", parent: None },
+    SubdivisionData { code: "CI-VB", name: "Vallée du Bandama", kind: "District", parent: None },
+    SubdivisionData { code: "CI-WR", name: "Woroba", kind: "District", parent: None },
+    SubdivisionData { code: "CI-YM", name: "Yamoussoukro", kind: "Autonomous district", parent: None },
+    SubdivisionData { code: "CI-ZZ", name: "Zanzan", kind: "District", parent: None },
+    SubdivisionData { code: "CL-AI", name: "Aisén del General Carlos Ibañez del Campo", kind: "Region", parent: None },
+    SubdivisionData { code: "CL-AN", name: "Antofagasta", kind: "Region", parent: None },
+    SubdivisionData { code: "CL-AP", name: "Arica y Parinacota", kind: "Region", parent: None },
+    SubdivisionData { code: "CL-AR", name: "La Araucanía", kind: "Region", parent: None },
+    SubdivisionData { code: "CL-AT", name: "Atacama", kind: "Region", parent: None },
+    SubdivisionData { code: "CL-BI", name: "Biobío", kind: "Region", parent: None },
+    SubdivisionData { code: "CL-CO", name: "Coquimbo", kind: "Region", parent: None },
+    SubdivisionData { code: "CL-LI", name: "Libertador General Bernardo O'Higgins", kind: "Region", parent: None },
+    SubdivisionData { code: "CL-LL", name: "Los Lagos", kind: "Region", parent: None },
+    SubdivisionData { code: "CL-LR", name: "Los Ríos", kind: "Region", parent: None },
+    SubdivisionData { code: "CL-MA", name: "Magallanes", kind: "Region", parent: None },
+    SubdivisionData { code: "CL-ML", name: "Maule", kind: "Region", parent: None },
+    SubdivisionData { code: "CL-NB", name: "Ñuble", kind: "Region", parent: None },
+    SubdivisionData { code: "CL-RM", name: "Región Metropolitana de Santiago", kind: "Region", parent: None },
+    SubdivisionData { code: "CL-TA", name: "Tarapacá", kind: "Region", parent: None },
+    SubdivisionData { code: "CL-VS", name: "Valparaíso", kind: "Region", parent: None },
+    SubdivisionData { code: "CM-AD", name: "Adamaoua", kind: "Region", parent: None },
+    SubdivisionData { code: "CM-CE", name: "Centre", kind: "Region", parent: None },
+    SubdivisionData { code: "CM-EN", name: "Far North", kind: "Region", parent: None },
+    SubdivisionData { code: "CM-ES", name: "East", kind: "Region", parent: None },
+    SubdivisionData { code: "CM-LT", name: "Littoral", kind: "Region", parent: None },
+    SubdivisionData { code: "CM-NO", name: "North", kind: "Region", parent: None },
+    SubdivisionData { code: "CM-NW", name: "North-West", kind: "Region", parent: None },
+    SubdivisionData { code: "CM-OU", name: "West", kind: "Region", parent: None },
+    SubdivisionData { code: "CM-SU", name: "South", kind: "Region", parent: None },
+    SubdivisionData { code: "CM-SW", name: "South-West", kind: "Region", parent: None },
+    SubdivisionData { code: "CN-AH", name: "Anhui Sheng", kind: "Province", parent: None },
+    SubdivisionData { code: "CN-BJ", name: "Beijing Shi", kind: "Municipality", parent: None },
+    SubdivisionData { code: "CN-CQ", name: "Chongqing Shi", kind: "Municipality", parent: None },
+    SubdivisionData { code: "CN-FJ", name: "Fujian Sheng", kind: "Province", parent: None },
+    SubdivisionData { code: "CN-GD", name: "Guangdong Sheng", kind: "Province", parent: None },
+    SubdivisionData { code: "CN-GS", name: "Gansu Sheng", kind: "Province", parent: None },
+    SubdivisionData { code: "CN-GX", name: "Guangxi Zhuangzu Zizhiqu", kind: "Autonomous region", parent: None },
+    SubdivisionData { code: "CN-GZ", name: "Guizhou Sheng", kind: "Province", parent: None },
+    SubdivisionData { code: "CN-HA", name: "Henan Sheng", kind: "Province", parent: None },
+    SubdivisionData { code: "CN-HB", name: "Hubei Sheng", kind: "Province", parent: None },
+    SubdivisionData { code: "CN-HE", name: "Hebei Sheng", kind: "Province", parent: None },
+    SubdivisionData { code: "CN-HI", name: "Hainan Sheng", kind: "Province", parent: None },
+    SubdivisionData { code: "CN-HK", name: "Hong Kong SAR", kind: "Special administrative region", parent: None },
+    SubdivisionData { code: "CN-HL", name: "Heilongjiang Sheng", kind: "Province", parent: None },
+    SubdivisionData { code: "CN-HN", name: "Hunan Sheng", kind: "Province", parent: None },
+    SubdivisionData { code: "CN-JL", name: "Jilin Sheng", kind: "Province", parent: None },
+    SubdivisionData { code: "CN-JS", name: "Jiangsu Sheng", kind: "Province", parent: None },
+    SubdivisionData { code: "CN-JX", name: "Jiangxi Sheng", kind: "Province", parent: None },
+    SubdivisionData { code: "CN-LN", name: "Liaoning Sheng", kind: "Province", parent: None },
+    SubdivisionData { code: "CN-MO", name: "Macao SAR", kind: "Special administrative region", parent: None },
+    SubdivisionData { code: "CN-NM", name: "Nei Mongol Zizhiqu", kind: "Autonomous region", parent: None },
+    SubdivisionData { code: "CN-NX", name: "Ningxia Huizi Zizhiqu", kind: "Autonomous region", parent: None },
+    SubdivisionData { code: "CN-QH", name: "Qinghai Sheng", kind: "Province", parent: None },
+    SubdivisionData { code: "CN-SC", name: "Sichuan Sheng", kind: "Province", parent: None },
+    SubdivisionData { code: "CN-SD", name: "Shandong Sheng", kind: "Province", parent: None },
+    SubdivisionData { code: "CN-SH", name: "Shanghai Shi", kind: "Municipality", parent: None },
+    SubdivisionData { code: "CN-SN", name: "Shaanxi Sheng", kind: "Province", parent: None },
+    SubdivisionData { code: "CN-SX", name: "Shanxi Sheng", kind: "Province", parent: None },
+    SubdivisionData { code: "CN-TJ", name: "Tianjin Shi", kind: "Municipality", parent: None },
+    SubdivisionData { code: "CN-TW", name: "Taiwan Sheng", kind: "Province", parent: None },
+    SubdivisionData { code: "CN-XJ", name: "Xinjiang Uygur Zizhiqu", kind: "Autonomous region", parent: None },
+    SubdivisionData { code: "CN-XZ", name: "Xizang Zizhiqu", kind: "Autonomous region", parent: None },
+    SubdivisionData { code: "CN-YN", name: "Yunnan Sheng", kind: "Province", parent: None },
+    SubdivisionData { code: "CN-ZJ", name: "Zhejiang Sheng", kind: "Province", parent: None },
+    SubdivisionData { code: "CO-AMA", name: "Amazonas", kind: "Department", parent: None },
+    SubdivisionData { code: "CO-ANT", name: "Antioquia", kind: "Department", parent: None },
+    SubdivisionData { code: "CO-ARA", name: "Arauca", kind: "Department", parent: None },
+    SubdivisionData { code: "CO-ATL", name: "Atlántico", kind: "Department", parent: None },
+    SubdivisionData { code: "CO-BOL", name: "Bolívar", kind: "Department", parent: None },
+    SubdivisionData { code: "CO-BOY", name: "Boyacá", kind: "Department", parent: None },
+    SubdivisionData { code: "CO-CAL", name: "Caldas", kind: "Department", parent: None },
+    SubdivisionData { code: "CO-CAQ", name: "Caquetá", kind: "Department", parent: None },
+    SubdivisionData { code: "CO-CAS", name: "Casanare", kind: "Department", parent: None },
+    SubdivisionData { code: "CO-CAU", name: "Cauca", kind: "Department", parent: None },
+    SubdivisionData { code: "CO-CES", name: "Cesar", kind: "Department", parent: None },
+    SubdivisionData { code: "CO-CHO", name: "Chocó", kind: "Department", parent: None },
+    SubdivisionData { code: "CO-COR", name: "Córdoba", kind: "Department", parent: None },
+    SubdivisionData { code: "CO-CUN", name: "Cundinamarca", kind: "Department", parent: None },
+    SubdivisionData { code: "CO-DC", name: "Distrito Capital de Bogotá", kind: "Capital district", parent: None },
+    SubdivisionData { code: "CO-GUA", name: "Guainía", kind: "Department", parent: None },
+    SubdivisionData { code: "CO-GUV", name: "Guaviare", kind: "Department", parent: None },
+    SubdivisionData { code: "CO-HUI", name: "Huila", kind: "Department", parent: None },
+    SubdivisionData { code: "CO-LAG", name: "La Guajira", kind: "Department", parent: None },
+    SubdivisionData { code: "CO-MAG", name: "Magdalena", kind: "Department", parent: None },
+    SubdivisionData { code: "CO-MET", name: "Meta", kind: "Department", parent: None },
+    SubdivisionData { code: "CO-NAR", name: "Nariño", kind: "Department", parent: None },
+    SubdivisionData { code: "CO-NSA", name: "Norte de Santander", kind: "Department", parent: None },
+    SubdivisionData { code: "CO-PUT", name: "Putumayo", kind: "Department", parent: None },
+    SubdivisionData { code: "CO-QUI", name: "Quindío", kind: "Department", parent: None },
+    SubdivisionData { code: "CO-RIS", name: "Risaralda", kind: "Department", parent: None },
+    SubdivisionData { code: "CO-SAN", name: "Santander", kind: "Department", parent: None },
+    SubdivisionData { code: "CO-SAP", name: "San Andrés, Providencia y Santa Catalina", kind: "Department", parent: None },
+    SubdivisionData { code: "CO-SUC", name: "Sucre", kind: "Department", parent: None },
+    SubdivisionData { code: "CO-TOL", name: "Tolima", kind: "Department", parent: None },
+    SubdivisionData { code: "CO-VAC", name: "Valle del Cauca", kind: "Department", parent: None },
+    SubdivisionData { code: "CO-VAU", name: "Vaupés", kind: "Department", parent: None },
+    SubdivisionData { code: "CO-VID", name: "Vichada", kind: "Department", parent: None },
+    SubdivisionData { code: "CR-A", name: "Alajuela", kind: "Province", parent: None },
+    SubdivisionData { code: "CR-C", name: "Cartago", kind: "Province", parent: None },
+    SubdivisionData { code: "CR-G", name: "Guanacaste", kind: "Province", parent: None },
+    SubdivisionData { code: "CR-H", name: "Heredia", kind: "Province", parent: None },
+    SubdivisionData { code: "CR-L", name: "Limón", kind: "Province", parent: None },
+    SubdivisionData { code: "CR-P", name: "Puntarenas", kind: "Province", parent: None },
+    SubdivisionData { code: "CR-SJ", name: "San José", kind: "Province", parent: None },
+    SubdivisionData { code: "CU-01", name: "Pinar del Río", kind: "Province", parent: None },
+    SubdivisionData { code: "CU-03", name: "La Habana", kind: "Province", parent: None },
+    SubdivisionData { code: "CU-04", name: "Matanzas", kind: "Province", parent: None },
+    SubdivisionData { code: "CU-05", name: "Villa Clara", kind: "Province", parent: None },
+    SubdivisionData { code: "CU-06", name: "Cienfuegos", kind: "Province", parent: None },
+    SubdivisionData { code: "CU-07", name: "Sancti Spíritus", kind: "Province", parent: None },
+    SubdivisionData { code: "CU-08", name: "Ciego de Ávila", kind: "Province", parent: None },
+    SubdivisionData { code: "CU-09", name: "Camagüey", kind: "Province", parent: None },
+    SubdivisionData { code: "CU-10", name: "Las Tunas", kind: "Province", parent: None },
+    SubdivisionData { code: "CU-11", name: "Holguín", kind: "Province", parent: None },
+    SubdivisionData { code: "CU-12", name: "Granma", kind: "Province", parent: None },
+    SubdivisionData { code: "CU-13", name: "Santiago de Cuba", kind: "Province", parent: None },
+    SubdivisionData { code: "CU-14", name: "Guantánamo", kind: "Province", parent: None },
+    SubdivisionData { code: "CU-15", name: "Artemisa", kind: "Province", parent: None },
+    SubdivisionData { code: "CU-16", name: "Mayabeque", kind: "Province", parent: None },
+    SubdivisionData { code: "CU-99", name: "Isla de la Juventud", kind: "Special municipality", parent: None },
+    SubdivisionData { code: "CV-B", name: "Ilhas de Barlavento", kind: "Geographical region", parent: None },
+    SubdivisionData { code: "CV-BR", name: "Brava", kind: "Municipality", parent: Some("CV-S") },
+    SubdivisionData { code: "CV-BV", name: "Boa Vista", kind: "Municipality", parent: Some("CV-B") },
+    SubdivisionData { code: "CV-CA", name: "Santa Catarina", kind: "Municipality", parent: Some("CV-S") },
+    SubdivisionData { code: "CV-CF", name: "Santa Catarina do Fogo", kind: "Municipality", parent: Some("CV-S") },
+    SubdivisionData { code: "CV-CR", name: "Santa Cruz", kind: "Municipality", parent: Some("CV-S") },
+    SubdivisionData { code: "CV-MA", name: "Maio", kind: "Municipality", parent: Some("CV-S") },
+    SubdivisionData { code: "CV-MO", name: "Mosteiros", kind: "Municipality", parent: Some("CV-S") },
+    SubdivisionData { code: "CV-PA", name: "Paul", kind: "Municipality", parent: Some("CV-B") },
+    SubdivisionData { code: "CV-PN", name: "Porto Novo", kind: "Municipality", parent: Some("CV-B") },
+    SubdivisionData { code: "CV-PR", name: "Praia", kind: "Municipality", parent: Some("CV-S") },
+    SubdivisionData { code: "CV-RB", name: "Ribeira Brava", kind: "Municipality", parent: Some("CV-B") },
+    SubdivisionData { code: "CV-RG", name: "Ribeira Grande", kind: "Municipality", parent: Some("CV-B") },
+    SubdivisionData { code: "CV-RS", name: "Ribeira Grande de Santiago", kind: "Municipality", parent: Some("CV-S") },
+    SubdivisionData { code: "CV-S", name: "Ilhas de Sotavento", kind: "Geographical region", parent: None },
+    SubdivisionData { code: "CV-SD", name: "São Domingos", kind: "Municipality", parent: Some("CV-S") },
+    SubdivisionData { code: "CV-SF", name: "São Filipe", kind: "Municipality", parent: Some("CV-S") },
+    SubdivisionData { code: "CV-SL", name: "Sal", kind: "Municipality", parent: Some("CV-B") },
+    SubdivisionData { code: "CV-SM", name: "São Miguel", kind: "Municipality", parent: Some("CV-S") },
+    SubdivisionData { code: "CV-SO", name: "São Lourenço dos Órgãos", kind: "Municipality", parent: Some("CV-S") },
+    SubdivisionData { code: "CV-SS", name: "São Salvador do Mundo", kind: "Municipality", parent: Some("CV-S") },
+    SubdivisionData { code: "CV-SV", name: "São Vicente", kind: "Municipality", parent: Some("CV-B") },
+    SubdivisionData { code: "CV-TA", name: "Tarrafal", kind: "Municipality", parent: Some("CV-S") },
+    SubdivisionData { code: "CV-TS", name: "Tarrafal de São Nicolau", kind: "Municipality", parent: Some("CV-B") },
+    SubdivisionData { code: "CY-01", name: "Lefkosia", kind: "District", parent: None },
+    SubdivisionData { code: "CY-02", name: "Lemesos", kind: "District", parent: None },
+    SubdivisionData { code: "CY-03", name: "Larnaka", kind: "District", parent: None },
+    SubdivisionData { code: "CY-04", name: "Ammochostos", kind: "District", parent: None },
+    SubdivisionData { code: "CY-05", name: "Baf", kind: "District", parent: None },
+    SubdivisionData { code: "CY-06", name: "Girne", kind: "District", parent: None },
+    SubdivisionData { code: "CZ-10", name: "Praha, Hlavní město", kind: "Capital city", parent: None },
+    SubdivisionData { code: "CZ-20", name: "Středočeský kraj", kind: "Region", parent: None },
+    SubdivisionData { code: "CZ-201", name: "Benešov", kind: "District", parent: Some("CZ-20") },
+    SubdivisionData { code: "CZ-202", name: "Beroun", kind: "District", parent: Some("CZ-20") },
+    SubdivisionData { code: "CZ-203", name: "Kladno", kind: "District", parent: Some("CZ-20") },
+    SubdivisionData { code: "CZ-204", name: "Kolín", kind: "District", parent: Some("CZ-20") },
+    SubdivisionData { code: "CZ-205", name: "Kutná Hora", kind: "District", parent: Some("CZ-20") },
+    SubdivisionData { code: "CZ-206", name: "Mělník", kind: "District", parent: Some("CZ-20") },
+    SubdivisionData { code: "CZ-207", name: "Mladá Boleslav", kind: "District", parent: Some("CZ-20") },
+    SubdivisionData { code: "CZ-208", name: "Nymburk", kind: "District", parent: Some("CZ-20") },
+    SubdivisionData { code: "CZ-209", name: "Praha-východ", kind: "District", parent: Some("CZ-20") },
+    SubdivisionData { code: "CZ-20A", name: "Praha-západ", kind: "District", parent: Some("CZ-20") },
+    SubdivisionData { code: "CZ-20B", name: "Příbram", kind: "District", parent: Some("CZ-20") },
+    SubdivisionData { code: "CZ-20C", name: "Rakovník", kind: "District", parent: Some("CZ-20") },
+    SubdivisionData { code: "CZ-31", name: "Jihočeský kraj", kind: "Region", parent: None },
+    SubdivisionData { code: "CZ-311", name: "České Budějovice", kind: "District", parent: Some("CZ-31") },
+    SubdivisionData { code: "CZ-312", name: "Český Krumlov", kind: "District", parent: Some("CZ-31") },
+    SubdivisionData { code: "CZ-313", name: "Jindřichův Hradec", kind: "District", parent: Some("CZ-31") },
+    SubdivisionData { code: "CZ-314", name: "Písek", kind: "District", parent: Some("CZ-31") },
+    SubdivisionData { code: "CZ-315", name: "Prachatice", kind: "District", parent: Some("CZ-31") },
+    SubdivisionData { code: "CZ-316", name: "Strakonice", kind: "District", parent: Some("CZ-31") },
+    SubdivisionData { code: "CZ-317", name: "Tábor", kind: "District", parent: Some("CZ-31") },
+    SubdivisionData { code: "CZ-32", name: "Plzeňský kraj", kind: "Region", parent: None },
+    SubdivisionData { code: "CZ-321", name: "Domažlice", kind: "District", parent: Some("CZ-32") },
+    SubdivisionData { code: "CZ-322", name: "Klatovy", kind: "District", parent: Some("CZ-32") },
+    SubdivisionData { code: "CZ-323", name: "Plzeň-město", kind: "District", parent: Some("CZ-32") },
+    SubdivisionData { code: "CZ-324", name: "Plzeň-jih", kind: "District", parent: Some("CZ-32") },
+    SubdivisionData { code: "CZ-325", name: "Plzeň-sever", kind: "District", parent: Some("CZ-32") },
+    SubdivisionData { code: "CZ-326", name: "Rokycany", kind: "District", parent: Some("CZ-32") },
+    SubdivisionData { code: "CZ-327", name: "Tachov", kind: "District", parent: Some("CZ-32") },
+    SubdivisionData { code: "CZ-41", name: "Karlovarský kraj", kind: "Region", parent: None },
+    SubdivisionData { code: "CZ-411", name: "Cheb", kind: "District", parent: Some("CZ-41") },
+    SubdivisionData { code: "CZ-412", name: "Karlovy Vary", kind: "District", parent: Some("CZ-41") },
+    SubdivisionData { code: "CZ-413", name: "Sokolov", kind: "District", parent: Some("CZ-41") },
+    SubdivisionData { code: "CZ-42", name: "Ústecký kraj", kind: "Region", parent: None },
+    SubdivisionData { code: "CZ-421", name: "Děčín", kind: "District", parent: Some("CZ-42") },
+    SubdivisionData { code: "CZ-422", name: "Chomutov", kind: "District", parent: Some("CZ-42") },
+    SubdivisionData { code: "CZ-423", name: "Litoměřice", kind: "District", parent: Some("CZ-42") },
+    SubdivisionData { code: "CZ-424", name: "Louny", kind: "District", parent: Some("CZ-42") },
+    SubdivisionData { code: "CZ-425", name: "Most", kind: "District", parent: Some("CZ-42") },
+    SubdivisionData { code: "CZ-426", name: "Teplice", kind: "District", parent: Some("CZ-42") },
+    SubdivisionData { code: "CZ-427", name: "Ústí nad Labem", kind: "District", parent: Some("CZ-42") },
+    SubdivisionData { code: "CZ-51", name: "Liberecký kraj", kind: "Region", parent: None },
+    SubdivisionData { code: "CZ-511", name: "Česká Lípa", kind: "District", parent: Some("CZ-51") },
+    SubdivisionData { code: "CZ-512", name: "Jablonec nad Nisou", kind: "District", parent: Some("CZ-51") },
+    SubdivisionData { code: "CZ-513", name: "Liberec", kind: "District", parent: Some("CZ-51") },
+    SubdivisionData { code: "CZ-514", name: "Semily", kind: "District", parent: Some("CZ-51") },
+    SubdivisionData { code: "CZ-52", name: "Královéhradecký kraj", kind: "Region", parent: None },
+    SubdivisionData { code: "CZ-521", name: "Hradec Králové", kind: "District", parent: Some("CZ-52") },
+    SubdivisionData { code: "CZ-522", name: "Jičín", kind: "District", parent: Some("CZ-52") },
+    SubdivisionData { code: "CZ-523", name: "Náchod", kind: "District", parent: Some("CZ-52") },
+    SubdivisionData { code: "CZ-524", name: "Rychnov nad Kněžnou", kind: "District", parent: Some("CZ-52") },
+    SubdivisionData { code: "CZ-525", name: "Trutnov", kind: "District", parent: Some("CZ-52") },
+    SubdivisionData { code: "CZ-53", name: "Pardubický kraj", kind: "Region", parent: None },
+    SubdivisionData { code: "CZ-531", name: "Chrudim", kind: "District", parent: Some("CZ-53") },
+    SubdivisionData { code: "CZ-532", name: "Pardubice", kind: "District", parent: Some("CZ-53") },
+    SubdivisionData { code: "CZ-533", name: "Svitavy", kind: "District", parent: Some("CZ-53") },
+    SubdivisionData { code: "CZ-534", name: "Ústí nad Orlicí", kind: "District", parent: Some("CZ-53") },
+    SubdivisionData { code: "CZ-63", name: "Kraj Vysočina", kind: "Region", parent: None },
+    SubdivisionData { code: "CZ-631", name: "Havlíčkův Brod", kind: "District", parent: Some("CZ-63") },
+    SubdivisionData { code: "CZ-632", name: "Jihlava", kind: "District", parent: Some("CZ-63") },
+    SubdivisionData { code: "CZ-633", name: "Pelhřimov", kind: "District", parent: Some("CZ-63") },
+    SubdivisionData { code: "CZ-634", name: "Třebíč", kind: "District", parent: Some("CZ-63") },
+    SubdivisionData { code: "CZ-635", name: "Žďár nad Sázavou", kind: "District", parent: Some("CZ-63") },
+    SubdivisionData { code: "CZ-64", name: "Jihomoravský kraj", kind: "Region", parent: None },
+    SubdivisionData { code: "CZ-641", name: "Blansko", kind: "District", parent: Some("CZ-64") },
+    SubdivisionData { code: "CZ-642", name: "Brno-město", kind: "District", parent: Some("CZ-64") },
+    SubdivisionData { code: "CZ-643", name: "Brno-venkov", kind: "District", parent: Some("CZ-64") },
+    SubdivisionData { code: "CZ-644", name: "Břeclav", kind: "District", parent: Some("CZ-64") },
+    SubdivisionData { code: "CZ-645", name: "Hodonín", kind: "District", parent: Some("CZ-64") },
+    SubdivisionData { code: "CZ-646", name: "Vyškov", kind: "District", parent: Some("CZ-64") },
+    SubdivisionData { code: "CZ-647", name: "Znojmo", kind: "District", parent: Some("CZ-64") },
+    SubdivisionData { code: "CZ-71", name: "Olomoucký kraj", kind: "Region", parent: None },
+    SubdivisionData { code: "CZ-711", name: "Jeseník", kind: "District", parent: Some("CZ-71") },
+    SubdivisionData { code: "CZ-712", name: "Olomouc", kind: "District", parent: Some("CZ-71") },
+    SubdivisionData { code: "CZ-713", name: "Prostějov", kind: "District", parent: Some("CZ-71") },
+    SubdivisionData { code: "CZ-714", name: "Přerov", kind: "District", parent: Some("CZ-71") },
+    SubdivisionData { code: "CZ-715", name: "Šumperk", kind: "District", parent: Some("CZ-71") },
+    SubdivisionData { code: "CZ-72", name: "Zlínský kraj", kind: "Region", parent: None },
+    SubdivisionData { code: "CZ-721", name: "Kroměříž", kind: "District", parent: Some("CZ-72") },
+    SubdivisionData { code: "CZ-722", name: "Uherské Hradiště", kind: "District", parent: Some("CZ-72") },
+    SubdivisionData { code: "CZ-723", name: "Vsetín", kind: "District", parent: Some("CZ-72") },
+    SubdivisionData { code: "CZ-724", name: "Zlín", kind: "District", parent: Some("CZ-72") },
+    SubdivisionData { code: "CZ-80", name: "Moravskoslezský kraj", kind: "Region", parent: None },
+    SubdivisionData { code: "CZ-801", name: "Bruntál", kind: "District", parent: Some("CZ-80") },
+    SubdivisionData { code: "CZ-802", name: "Frýdek-Místek", kind: "District", parent: Some("CZ-80") },
+    SubdivisionData { code: "CZ-803", name: "Karviná", kind: "District", parent: Some("CZ-80") },
+    SubdivisionData { code: "CZ-804", name: "Nový Jičín", kind: "District", parent: Some("CZ-80") },
+    SubdivisionData { code: "CZ-805", name: "Opava", kind: "District", parent: Some("CZ-80") },
+    SubdivisionData { code: "CZ-806", name: "Ostrava-město", kind: "District", parent: Some("CZ-80") },
+    SubdivisionData { code: "DE-BB", name: "Brandenburg", kind: "Land", parent: None },
+    SubdivisionData { code: "DE-BE", name: "Berlin", kind: "Land", parent: None },
+    SubdivisionData { code: "DE-BW", name: "Baden-Württemberg", kind: "Land", parent: None },
+    SubdivisionData { code: "DE-BY", name: "Bayern", kind: "Land", parent: None },
+    SubdivisionData { code: "DE-HB", name: "Bremen", kind: "Land", parent: None },
+    SubdivisionData { code: "DE-HE", name: "Hessen", kind: "Land", parent: None },
+    SubdivisionData { code: "DE-HH", name: "Hamburg", kind: "Land", parent: None },
+    SubdivisionData { code: "DE-MV", name: "Mecklenburg-Vorpommern", kind: "Land", parent: None },
+    SubdivisionData { code: "DE-NI", name: "Niedersachsen", kind: "Land", parent: None },
+    SubdivisionData { code: "DE-NW", name: "Nordrhein-Westfalen", kind: "Land", parent: None },
+    SubdivisionData { code: "DE-RP", name: "Rheinland-Pfalz", kind: "Land", parent: None },
+    SubdivisionData { code: "DE-SH", name: "Schleswig-Holstein", kind: "Land", parent: None },
+    SubdivisionData { code: "DE-SL", name: "Saarland", kind: "Land", parent: None },
+    SubdivisionData { code: "DE-SN", name: "Sachsen", kind: "Land", parent: None },
+    SubdivisionData { code: "DE-ST", name: "Sachsen-Anhalt", kind: "Land", parent: None },
+    SubdivisionData { code: "DE-TH", name: "Thüringen", kind: "Land", parent: None },
+    SubdivisionData { code: "DJ-AR", name: "Arta", kind: "Region", parent: None },
+    SubdivisionData { code: "DJ-AS", name: "Ali Sabieh", kind: "Region", parent: None },
+    SubdivisionData { code: "DJ-DI", name: "Dikhil", kind: "Region", parent: None },
+    SubdivisionData { code: "DJ-DJ", name: "Djibouti", kind: "City", parent: None },
+    SubdivisionData { code: "DJ-OB", name: "Awbūk", kind: "Region", parent: None },
+    SubdivisionData { code: "DJ-TA", name: "Tadjourah", kind: "Region", parent: None },
+    SubdivisionData { code: "DK-81", name: "Nordjylland", kind: "Region", parent: None },
+    SubdivisionData { code: "DK-82", name: "Midtjylland", kind: "Region", parent: None },
+    SubdivisionData { code: "DK-83", name: "Syddanmark", kind: "Region", parent: None },
+    SubdivisionData { code: "DK-84", name: "Hovedstaden", kind: "Region", parent: None },
+    SubdivisionData { code: "DK-85", name: "Sjælland", kind: "Region", parent: None },
+    SubdivisionData { code: "DM-02", name: "Saint Andrew", kind: "Parish", parent: None },
+    SubdivisionData { code: "DM-03", name: "Saint David", kind: "Parish", parent: None },
+    SubdivisionData { code: "DM-04", name: "Saint George", kind: "Parish", parent: None },
+    SubdivisionData { code: "DM-05", name: "Saint John", kind: "Parish", parent: None },
+    SubdivisionData { code: "DM-06", name: "Saint Joseph", kind: "Parish", parent: None },
+    SubdivisionData { code: "DM-07", name: "Saint Luke", kind: "Parish", parent: None },
+    SubdivisionData { code: "DM-08", name: "Saint Mark", kind: "Parish", parent: None },
+    SubdivisionData { code: "DM-09", name: "Saint Patrick", kind: "Parish", parent: None },
+    SubdivisionData { code: "DM-10", name: "Saint Paul", kind: "Parish", parent: None },
+    SubdivisionData { code: "DM-11", name: "Saint Peter", kind: "Parish", parent: None },
+    SubdivisionData { code: "DO-01", name: "Distrito Nacional (Santo Domingo)", kind: "District", parent: Some("DO-40") },
+    SubdivisionData { code: "DO-02", name: "Azua", kind: "Province", parent: Some("DO-41") },
+    SubdivisionData { code: "DO-03", name: "Baoruco", kind: "Province", parent: Some("DO-38") },
+    SubdivisionData { code: "DO-04", name: "Barahona", kind: "Province", parent: Some("DO-38") },
+    SubdivisionData { code: "DO-05", name: "Dajabón", kind: "Province", parent: Some("DO-34") },
+    SubdivisionData { code: "DO-06", name: "Duarte", kind: "Province", parent: Some("DO-33") },
+    SubdivisionData { code: "DO-07", name: "Elías Piña", kind: "Province", parent: Some("DO-37") },
+    SubdivisionData { code: "DO-08", name: "El Seibo", kind: "Province", parent: Some("DO-42") },
+    SubdivisionData { code: "DO-09", name: "Espaillat", kind: "Province", parent: Some("DO-35") },
+    SubdivisionData { code: "DO-10", name: "Independencia", kind: "Province", parent: Some("DO-38") },
+    SubdivisionData { code: "DO-11", name: "La Altagracia", kind: "Province", parent: Some("DO-42") },
+    SubdivisionData { code: "DO-12", name: "La Romana", kind: "Province", parent: Some("DO-42") },
+    SubdivisionData { code: "DO-13", name: "La Vega", kind: "Province", parent: Some("DO-36") },
+    SubdivisionData { code: "DO-14", name: "María Trinidad Sánchez", kind: "Province", parent: Some("DO-33") },
+    SubdivisionData { code: "DO-15", name: "Monte Cristi", kind: "Province", parent: Some("DO-34") },
+    SubdivisionData { code: "DO-16", name: "Pedernales", kind: "Province", parent: Some("DO-38") },
+    SubdivisionData { code: "DO-17", name: "Peravia", kind: "Province", parent: Some("DO-41") },
+    SubdivisionData { code: "DO-18", name: "Puerto Plata", kind: "Province", parent: Some("DO-35") },
+    SubdivisionData { code: "DO-19", name: "Hermanas Mirabal", kind: "Province", parent: Some("DO-33") },
+    SubdivisionData { code: "DO-20", name: "Samaná", kind: "Province", parent: Some("DO-33") },
+    SubdivisionData { code: "DO-21", name: "San Cristóbal", kind: "Province", parent: Some("DO-41") },
+    SubdivisionData { code: "DO-22", name: "San Juan", kind: "Province", parent: Some("DO-37") },
+    SubdivisionData { code: "DO-23", name: "San Pedro de Macorís", kind: "Province", parent: Some("DO-39") },
+    SubdivisionData { code: "DO-24", name: "Sánchez Ramírez", kind: "Province", parent: Some("DO-36") },
+    SubdivisionData { code: "DO-25", name: "Santiago", kind: "Province", parent: Some("DO-35") },
+    SubdivisionData { code: "DO-26", name: "Santiago Rodríguez", kind: "Province", parent: Some("DO-34") },
+    SubdivisionData { code: "DO-27", name: "Valverde", kind: "Province", parent: Some("DO-34") },
+    SubdivisionData { code: "DO-28", name: "Monseñor Nouel", kind: "Province", parent: Some("DO-36") },
+    SubdivisionData { code: "DO-29", name: "Monte Plata", kind: "Province", parent: Some("DO-39") },
+    SubdivisionData { code: "DO-30", name: "Hato Mayor", kind: "Province", parent: Some("DO-39") },
+    SubdivisionData { code: "DO-31", name: "San José de Ocoa", kind: "Province", parent: Some("DO-41") },
+    SubdivisionData { code: "DO-32", name: "Santo Domingo", kind: "Province", parent: Some("DO-40") },
+    SubdivisionData { code: "DO-33", name: "Cibao Nordeste", kind: "Region", parent: None },
+    SubdivisionData { code: "DO-34", name: "Cibao Noroeste", kind: "Region", parent: None },
+    SubdivisionData { code: "DO-35", name: "Cibao Norte", kind: "Region", parent: None },
+    SubdivisionData { code: "DO-36", name: "Cibao Sur", kind: "Region", parent: None },
+    SubdivisionData { code: "DO-37", name: "El Valle", kind: "Region", parent: None },
+    SubdivisionData { code: "DO-38", name: "Enriquillo", kind: "Region", parent: None },
+    SubdivisionData { code: "DO-39", name: "Higuamo", kind: "Region", parent: None },
+    SubdivisionData { code: "DO-40", name: "Ozama", kind: "Region", parent: None },
+    SubdivisionData { code: "DO-41", name: "Valdesia", kind: "Region", parent: None },
+    SubdivisionData { code: "DO-42", name: "Yuma", kind: "Region", parent: None },
+    SubdivisionData { code: "DZ-01", name: "Adrar", kind: "Province", parent: None },
+    SubdivisionData { code: "DZ-02", name: "Chlef", kind: "Province", parent: None },
+    SubdivisionData { code: "DZ-03", name: "Laghouat", kind: "Province", parent: None },
+    SubdivisionData { code: "DZ-04", name: "Oum el Bouaghi", kind: "Province", parent: None },
+    SubdivisionData { code: "DZ-05", name: "Batna", kind: "Province", parent: None },
+    SubdivisionData { code: "DZ-06", name: "Béjaïa", kind: "Province", parent: None },
+    SubdivisionData { code: "DZ-07", name: "Biskra", kind: "Province", parent: None },
+    SubdivisionData { code: "DZ-08", name: "Béchar", kind: "Province", parent: None },
+    SubdivisionData { code: "DZ-09", name: "Blida", kind: "Province", parent: None },
+    SubdivisionData { code: "DZ-10", name: "Bouira", kind: "Province", parent: None },
+    SubdivisionData { code: "DZ-11", name: "Tamanrasset", kind: "Province", parent: None },
+    SubdivisionData { code: "DZ-12", name: "Tébessa", kind: "Province", parent: None },
+    SubdivisionData { code: "DZ-13", name: "Tlemcen", kind: "Province", parent: None },
+    SubdivisionData { code: "DZ-14", name: "Tiaret", kind: "Province", parent: None },
+    SubdivisionData { code: "DZ-15", name: "Tizi Ouzou", kind: "Province", parent: None },
+    SubdivisionData { code: "DZ-16", name: "Alger", kind: "Province", parent: None },
+    SubdivisionData { code: "DZ-17", name: "Djelfa", kind: "Province", parent: None },
+    SubdivisionData { code: "DZ-18", name: "Jijel", kind: "Province", parent: None },
+    SubdivisionData { code: "DZ-19", name: "Sétif", kind: "Province", parent: None },
+    SubdivisionData { code: "DZ-20", name: "Saïda", kind: "Province", parent: None },
+    SubdivisionData { code: "DZ-21", name: "Skikda", kind: "Province", parent: None },
+    SubdivisionData { code: "DZ-22", name: "Sidi Bel Abbès", kind: "Province", parent: None },
+    SubdivisionData { code: "DZ-23", name: "Annaba", kind: "Province", parent: None },
+    SubdivisionData { code: "DZ-24", name: "Guelma", kind: "Province", parent: None },
+    SubdivisionData { code: "DZ-25", name: "Constantine", kind: "Province", parent: None },
+    SubdivisionData { code: "DZ-26", name: "Médéa", kind: "Province", parent: None },
+    SubdivisionData { code: "DZ-27", name: "Mostaganem", kind: "Province", parent: None },
+    SubdivisionData { code: "DZ-28", name: "M'sila", kind: "Province", parent: None },
+    SubdivisionData { code: "DZ-29", name: "Mascara", kind: "Province", parent: None },
+    SubdivisionData { code: "DZ-30", name: "Ouargla", kind: "Province", parent: None },
+    SubdivisionData { code: "DZ-31", name: "Oran", kind: "Province", parent: None },
+    SubdivisionData { code: "DZ-32", name: "El Bayadh", kind: "Province", parent: None },
+    SubdivisionData { code: "DZ-33", name: "Illizi", kind: "Province", parent: None },
+    SubdivisionData { code: "DZ-34", name: "Bordj Bou Arréridj", kind: "Province", parent: None },
+    SubdivisionData { code: "DZ-35", name: "Boumerdès", kind: "Province", parent: None },
+    SubdivisionData { code: "DZ-36", name: "El Tarf", kind: "Province", parent: None },
+    SubdivisionData { code: "DZ-37", name: "Tindouf", kind: "Province", parent: None },
+    SubdivisionData { code: "DZ-38", name: "Tissemsilt", kind: "Province", parent: None },
+    SubdivisionData { code: "DZ-39", name: "El Oued", kind: "Province", parent: None },
+    SubdivisionData { code: "DZ-40", name: "Khenchela", kind: "Province", parent: None },
+    SubdivisionData { code: "DZ-41", name: "Souk Ahras", kind: "Province", parent: None },
+    SubdivisionData { code: "DZ-42", name: "Tipaza", kind: "Province", parent: None },
+    SubdivisionData { code: "DZ-43", name: "Mila", kind: "Province", parent: None },
+    SubdivisionData { code: "DZ-44", name: "Aïn Defla", kind: "Province", parent: None },
+    SubdivisionData { code: "DZ-45", name: "Naama", kind: "Province", parent: None },
+    SubdivisionData { code: "DZ-46", name: "Aïn Témouchent", kind: "Province", parent: None },
+    SubdivisionData { code: "DZ-47", name: "Ghardaïa", kind: "Province", parent: None },
+    SubdivisionData { code: "DZ-48", name: "Relizane", kind: "Province", parent: None },
+    SubdivisionData { code: "EC-A", name: "Azuay", kind: "Province", parent: None },
+    SubdivisionData { code: "EC-B", name: "Bolívar", kind: "Province", parent: None },
+    SubdivisionData { code: "EC-C", name: "Carchi", kind: "Province", parent: None },
+    SubdivisionData { code: "EC-D", name: "Orellana", kind: "Province", parent: None },
+    SubdivisionData { code: "EC-E", name: "Esmeraldas", kind: "Province", parent: None },
+    SubdivisionData { code: "EC-F", name: "Cañar", kind: "Province", parent: None },
+    SubdivisionData { code: "EC-G", name: "Guayas", kind: "Province", parent: None },
+    SubdivisionData { code: "EC-H", name: "Chimborazo", kind: "Province", parent: None },
+    SubdivisionData { code: "EC-I", name: "Imbabura", kind: "Province", parent: None },
+    SubdivisionData { code: "EC-L", name: "Loja", kind: "Province", parent: None },
+    SubdivisionData { code: "EC-M", name: "Manabí", kind: "Province", parent: None },
+    SubdivisionData { code: "EC-N", name: "Napo", kind: "Province", parent: None },
+    SubdivisionData { code: "EC-O", name: "El Oro", kind: "Province", parent: None },
+    SubdivisionData { code: "EC-P", name: "Pichincha", kind: "Province", parent: None },
+    SubdivisionData { code: "EC-R", name: "Los Ríos", kind: "Province", parent: None },
+    SubdivisionData { code: "EC-S", name: "Morona Santiago", kind: "Province", parent: None },
+    SubdivisionData { code: "EC-SD", name: "Santo Domingo de los Tsáchilas", kind: "Province", parent: None },
+    SubdivisionData { code: "EC-SE", name: "Santa Elena", kind: "Province", parent: None },
+    SubdivisionData { code: "EC-T", name: "Tungurahua", kind: "Province", parent: None },
+    SubdivisionData { code: "EC-U", name: "Sucumbíos", kind: "Province", parent: None },
+    SubdivisionData { code: "EC-W", name: "Galápagos", kind: "Province", parent: None },
+    SubdivisionData { code: "EC-X", name: "Cotopaxi", kind: "Province", parent: None },
+    SubdivisionData { code: "EC-Y", name: "Pastaza", kind: "Province", parent: None },
+    SubdivisionData { code: "EC-Z", name: "Zamora Chinchipe", kind: "Province", parent: None },
+    SubdivisionData { code: "EE-130", name: "Alutaguse", kind: "Rural municipality", parent: Some("EE-45") },
+    SubdivisionData { code: "EE-141", name: "Anija", kind: "Rural municipality", parent: Some("EE-37") },
+    SubdivisionData { code: "EE-142", name: "Antsla", kind: "Rural municipality", parent: Some("EE-87") },
+    SubdivisionData { code: "EE-171", name: "Elva", kind: "Rural municipality", parent: Some("EE-79") },
+    SubdivisionData { code: "EE-184", name: "Haapsalu", kind: "Urban municipality", parent: Some("EE-56") },
+    SubdivisionData { code: "EE-191", name: "Haljala", kind: "Rural municipality", parent: Some("EE-60") },
+    SubdivisionData { code: "EE-198", name: "Harku", kind: "Rural municipality", parent: Some("EE-37") },
+    SubdivisionData { code: "EE-205", name: "Hiiumaa", kind: "Rural municipality", parent: Some("EE-39") },
+    SubdivisionData { code: "EE-214", name: "Häädemeeste", kind: "Rural municipality", parent: Some("EE-68") },
+    SubdivisionData { code: "EE-245", name: "Jõelähtme", kind: "Rural municipality", parent: Some("EE-37") },
+    SubdivisionData { code: "EE-247", name: "Jõgeva", kind: "Rural municipality", parent: Some("EE-50") },
+    SubdivisionData { code: "EE-251", name: "Jõhvi", kind: "Rural municipality", parent: Some("EE-45") },
+    SubdivisionData { code: "EE-255", name: "Järva", kind: "Rural municipality", parent: Some("EE-52") },
+    SubdivisionData { code: "EE-272", name: "Kadrina", kind: "Rural municipality", parent: Some("EE-60") },
+    SubdivisionData { code: "EE-283", name: "Kambja", kind: "Rural municipality", parent: Some("EE-79") },
+    SubdivisionData { code: "EE-284", name: "Kanepi", kind: "Rural municipality", parent: Some("EE-64") },
+    SubdivisionData { code: "EE-291", name: "Kastre", kind: "Rural municipality", parent: Some("EE-79") },
+    SubdivisionData { code: "EE-293", name: "Kehtna", kind: "Rural municipality", parent: Some("EE-71") },
+    SubdivisionData { code: "EE-296", name: "Keila", kind: "Urban municipality", parent: Some("EE-37") },
+    SubdivisionData { code: "EE-303", name: "Kihnu", kind: "Rural municipality", parent: Some("EE-68") },
+    SubdivisionData { code: "EE-305", name: "Kiili", kind: "Rural municipality", parent: Some("EE-37") },
+    SubdivisionData { code: "EE-317", name: "Kohila", kind: "Rural municipality", parent: Some("EE-71") },
+    SubdivisionData { code: "EE-321", name: "Kohtla-Järve", kind: "Urban municipality", parent: Some("EE-45") },
+    SubdivisionData { code: "EE-338", name: "Kose", kind: "Rural municipality", parent: Some("EE-37") },
+    SubdivisionData { code: "EE-353", name: "Kuusalu", kind: "Rural municipality", parent: Some("EE-37") },
+    SubdivisionData { code: "EE-37", name: "Harjumaa", kind: "County", parent: None },
+    SubdivisionData { code: "EE-39", name: "Hiiumaa", kind: "County", parent: None },
+    SubdivisionData { code: "EE-424", name: "Loksa", kind: "Urban municipality", parent: Some("EE-37") },
+    SubdivisionData { code: "EE-430", name: "Lääneranna", kind: "Rural municipality", parent: Some("EE-68") },
+    SubdivisionData { code: "EE-431", name: "Lääne-Harju", kind: "Rural municipality", parent: Some("EE-37") },
+    SubdivisionData { code: "EE-432", name: "Luunja", kind: "Rural municipality", parent: Some("EE-79") },
+    SubdivisionData { code: "EE-441", name: "Lääne-Nigula", kind: "Rural municipality", parent: Some("EE-56") },
+    SubdivisionData { code: "EE-442", name: "Lüganuse", kind: "Rural municipality", parent: Some("EE-45") },
+    SubdivisionData { code: "EE-446", name: "Maardu", kind: "Urban municipality", parent: Some("EE-37") },
+    SubdivisionData { code: "EE-45", name: "Ida-Virumaa", kind: "County", parent: None },
+    SubdivisionData { code: "EE-478", name: "Muhu", kind: "Rural municipality", parent: Some("EE-74") },
+    SubdivisionData { code: "EE-480", name: "Mulgi", kind: "Rural municipality", parent: Some("EE-84") },
+    SubdivisionData { code: "EE-486", name: "Mustvee", kind: "Rural municipality", parent: Some("EE-50") },
+    SubdivisionData { code: "EE-50", name: "Jõgevamaa", kind: "County", parent: None },
+    SubdivisionData { code: "EE-503", name: "Märjamaa", kind: "Rural municipality", parent: Some("EE-71") },
+    SubdivisionData { code: "EE-511", name: "Narva", kind: "Urban municipality", parent: Some("EE-45") },
+    SubdivisionData { code: "EE-514", name: "Narva-Jõesuu", kind: "Urban municipality", parent: Some("EE-45") },
+    SubdivisionData { code: "EE-52", name: "Järvamaa", kind: "County", parent: None },
+    SubdivisionData { code: "EE-528", name: "Nõo", kind: "Rural municipality", parent: Some("EE-79") },
+    SubdivisionData { code: "EE-557", name: "Otepää", kind: "Rural municipality", parent: Some("EE-81") },
+    SubdivisionData { code: "EE-56", name: "Läänemaa", kind: "County", parent: None },
+    SubdivisionData { code: "EE-567", name: "Paide", kind: "Urban municipality", parent: Some("EE-52") },
+    SubdivisionData { code: "EE-586", name: "Peipsiääre", kind: "Rural municipality", parent: Some("EE-79") },
+    SubdivisionData { code: "EE-60", name: "Lääne-Virumaa", kind: "County", parent: None },
+    SubdivisionData { code: "EE-615", name: "Põhja-Sakala", kind: "Rural municipality", parent: Some("EE-84") },
+    SubdivisionData { code: "EE-618", name: "Põltsamaa", kind: "Rural municipality", parent: Some("EE-50") },
+    SubdivisionData { code: "EE-622", name: "Põlva", kind: "Rural municipality", parent: Some("EE-64") },
+    SubdivisionData { code: "EE-624", name: "Pärnu", kind: "Urban municipality", parent: Some("EE-68") },
+    SubdivisionData { code: "EE-638", name: "Põhja-Pärnumaa", kind: "Rural municipality", parent: Some("EE-68") },
+    SubdivisionData { code: "EE-64", name: "Põlvamaa", kind: "County", parent: None },
+    SubdivisionData { code: "EE-651", name: "Raasiku", kind: "Rural municipality", parent: Some("EE-37") },
+    SubdivisionData { code: "EE-653", name: "Rae", kind: "Rural municipality", parent: Some("EE-37") },
+    SubdivisionData { code: "EE-661", name: "Rakvere", kind: "Rural municipality", parent: Some("EE-60") },
+    SubdivisionData { code: "EE-663", name: "Rakvere", kind: "Urban municipality", parent: Some("EE-60") },
+    SubdivisionData { code: "EE-668", name: "Rapla", kind: "Rural municipality", parent: Some("EE-71") },
+    SubdivisionData { code: "EE-68", name: "Pärnumaa", kind: "County", parent: None },
+    SubdivisionData { code: "EE-689", name: "Ruhnu", kind: "Rural municipality", parent: Some("EE-74") },
+    SubdivisionData { code: "EE-698", name: "Rõuge", kind: "Rural municipality", parent: Some("EE-87") },
+    SubdivisionData { code: "EE-708", name: "Räpina", kind: "Rural municipality", parent: Some("EE-64") },
+    SubdivisionData { code: "EE-71", name: "Raplamaa", kind: "County", parent: None },
+    SubdivisionData { code: "EE-712", name: "Saarde", kind: "Rural municipality", parent: Some("EE-68") },
+    SubdivisionData { code: "EE-714", name: "Saaremaa", kind: "Rural municipality", parent: Some("EE-74") },
+    SubdivisionData { code: "EE-719", name: "Saku", kind: "Rural municipality", parent: Some("EE-37") },
+    SubdivisionData { code: "EE-726", name: "Saue", kind: "Rural municipality", parent: Some("EE-37") },
+    SubdivisionData { code: "EE-732", name: "Setomaa", kind: "Rural municipality", parent: Some("EE-87") },
+    SubdivisionData { code: "EE-735", name: "Sillamäe", kind: "Urban municipality", parent: Some("EE-45") },
+    SubdivisionData { code: "EE-74", name: "Saaremaa", kind: "County", parent: None },
+    SubdivisionData { code: "EE-784", name: "Tallinn", kind: "Urban municipality", parent: Some("EE-37") },
+    SubdivisionData { code: "EE-79", name: "Tartumaa", kind: "County", parent: None },
+    SubdivisionData { code: "EE-792", name: "Tapa", kind: "Rural municipality", parent: Some("EE-60") },
+    SubdivisionData { code: "EE-793", name: "Tartu", kind: "Urban municipality", parent: Some("EE-79") },
+    SubdivisionData { code: "EE-796", name: "Tartu", kind: "Rural municipality", parent: Some("EE-79") },
+    SubdivisionData { code: "EE-803", name: "Toila", kind: "Rural municipality", parent: Some("EE-45") },
+    SubdivisionData { code: "EE-809", name: "Tori", kind: "Rural municipality", parent: Some("EE-68") },
+    SubdivisionData { code: "EE-81", name: "Valgamaa", kind: "County", parent: None },
+    SubdivisionData { code: "EE-824", name: "Tõrva", kind: "Rural municipality", parent: Some("EE-81") },
+    SubdivisionData { code: "EE-834", name: "Türi", kind: "Rural municipality", parent: Some("EE-52") },
+    SubdivisionData { code: "EE-84", name: "Viljandimaa", kind: "County", parent: None },
+    SubdivisionData { code: "EE-855", name: "Valga", kind: "Rural municipality", parent: Some("EE-81") },
+    SubdivisionData { code: "EE-87", name: "Võrumaa", kind: "County", parent: None },
+    SubdivisionData { code: "EE-890", name: "Viimsi", kind: "Rural municipality", parent: Some("EE-37") },
+    SubdivisionData { code: "EE-897", name: "Viljandi", kind: "Urban municipality", parent: Some("EE-84") },
+    SubdivisionData { code: "EE-899", name: "Viljandi", kind: "Rural municipality", parent: Some("EE-84") },
+    SubdivisionData { code: "EE-901", name: "Vinni", kind: "Rural municipality", parent: Some("EE-60") },
+    SubdivisionData { code: "EE-903", name: "Viru-Nigula", kind: "Rural municipality", parent: Some("EE-60") },
+    SubdivisionData { code: "EE-907", name: "Vormsi", kind: "Rural municipality", parent: Some("EE-56") },
+    SubdivisionData { code: "EE-917", name: "Võru", kind: "Rural municipality", parent: Some("EE-87") },
+    SubdivisionData { code: "EE-919", name: "Võru", kind: "Urban municipality", parent: Some("EE-87") },
+    SubdivisionData { code: "EE-928", name: "Väike-Maarja", kind: "Rural municipality", parent: Some("EE-60") },
+    SubdivisionData { code: "EG-ALX", name: "Al Iskandarīyah", kind: "Governorate", parent: None },
+    SubdivisionData { code: "EG-ASN", name: "Aswān", kind: "Governorate", parent: None },
+    SubdivisionData { code: "EG-AST", name: "Asyūţ", kind: "Governorate", parent: None },
+    SubdivisionData { code: "EG-BA", name: "Al Baḩr al Aḩmar", kind: "Governorate", parent: None },
+    SubdivisionData { code: "EG-BH", name: "Al Buḩayrah", kind: "Governorate", parent: None },
+    SubdivisionData { code: "EG-BNS", name: "Banī Suwayf", kind: "Governorate", parent: None },
+    SubdivisionData { code: "EG-C", name: "Al Qāhirah", kind: "Governorate", parent: None },
+    SubdivisionData { code: "EG-DK", name: "Ad Daqahlīyah", kind: "Governorate", parent: None },
+    SubdivisionData { code: "EG-DT", name: "Dumyāţ", kind: "Governorate", parent: None },
+    SubdivisionData { code: "EG-FYM", name: "Al Fayyūm", kind: "Governorate", parent: None },
+    SubdivisionData { code: "EG-GH", name: "Al Gharbīyah", kind: "Governorate", parent: None },
+    SubdivisionData { code: "EG-GZ", name: "Al Jīzah", kind: "Governorate", parent: None },
+    SubdivisionData { code: "EG-IS", name: "Al Ismā'īlīyah", kind: "Governorate", parent: None },
+    SubdivisionData { code: "EG-JS", name: "Janūb Sīnā'", kind: "Governorate", parent: None },
+    SubdivisionData { code: "EG-KB", name: "Al Qalyūbīyah", kind: "Governorate", parent: None },
+    SubdivisionData { code: "EG-KFS", name: "Kafr ash Shaykh", kind: "Governorate", parent: None },
+    SubdivisionData { code: "EG-KN", name: "Qinā", kind: "Governorate", parent: None },
+    SubdivisionData { code: "EG-LX", name: "Al Uqşur", kind: "Governorate", parent: None },
+    SubdivisionData { code: "EG-MN", name: "Al Minyā", kind: "Governorate", parent: None },
+    SubdivisionData { code: "EG-MNF", name: "Al Minūfīyah", kind: "Governorate", parent: None },
+    SubdivisionData { code: "EG-MT", name: "Maţrūḩ", kind: "Governorate", parent: None },
+    SubdivisionData { code: "EG-PTS", name: "Būr Sa‘īd", kind: "Governorate", parent: None },
+    SubdivisionData { code: "EG-SHG", name: "Sūhāj", kind: "Governorate", parent: None },
+    SubdivisionData { code: "EG-SHR", name: "Ash Sharqīyah", kind: "Governorate", parent: None },
+    SubdivisionData { code: "EG-SIN", name: "Shamāl Sīnā'", kind: "Governorate", parent: None },
+    SubdivisionData { code: "EG-SUZ", name: "As Suways", kind: "Governorate", parent: None },
+    SubdivisionData { code: "EG-WAD", name: "Al Wādī al Jadīd", kind: "Governorate", parent: None },
+    SubdivisionData { code: "ER-AN", name: "Ansabā", kind: "Region", parent: None },
+    SubdivisionData { code: "ER-DK", name: "Debubawi K’eyyĭḥ Baḥri", kind: "Region", parent: None },
+    SubdivisionData { code: "ER-DU", name: "Al Janūbī", kind: "Region", parent: None },
+    SubdivisionData { code: "ER-GB", name: "Gash-Barka", kind: "Region", parent: None },
+    SubdivisionData { code: "ER-MA", name: "Al Awsaţ", kind: "Region", parent: None },
+    SubdivisionData { code: "ER-SK", name: "Semienawi K’eyyĭḥ Baḥri", kind: "Region", parent: None },
+    SubdivisionData { code: "ES-A", name: "Alacant*", kind: "Province", parent: Some("ES-VC") },
+    SubdivisionData { code: "ES-AB", name: "Albacete", kind: "Province", parent: Some("ES-CM") },
+    SubdivisionData { code: "ES-AL", name: "Almería", kind: "Province", parent: Some("ES-AN") },
+    SubdivisionData { code: "ES-AN", name: "Andalucía", kind: "Autonomous community", parent: None },
+    SubdivisionData { code: "ES-AR", name: "Aragón", kind: "Autonomous community", parent: None },
+    SubdivisionData { code: "ES-AS", name: "Asturias, Principado de", kind: "Autonomous community", parent: None },
+    SubdivisionData { code: "ES-AV", name: "Ávila", kind: "Province", parent: Some("ES-CL") },
+    SubdivisionData { code: "ES-B", name: "Barcelona", kind: "Province", parent: Some("ES-CT") },
+    SubdivisionData { code: "ES-BA", name: "Badajoz", kind: "Province", parent: Some("ES-EX") },
+    SubdivisionData { code: "ES-BI", name: "Bizkaia", kind: "Province", parent: Some("ES-PV") },
+    SubdivisionData { code: "ES-BU", name: "Burgos", kind: "Province", parent: Some("ES-CL") },
+    SubdivisionData { code: "ES-C", name: "A Coruña", kind: "Province", parent: Some("ES-GA") },
+    SubdivisionData { code: "ES-CA", name: "Cádiz", kind: "Province", parent: Some("ES-AN") },
+    SubdivisionData { code: "ES-CB", name: "Cantabria", kind: "Autonomous community", parent: None },
+    SubdivisionData { code: "ES-CC", name: "Cáceres", kind: "Province", parent: Some("ES-EX") },
+    SubdivisionData { code: "ES-CE", name: "Ceuta", kind: "Autonomous city in north africa", parent: None },
+    SubdivisionData { code: "ES-CL", name: "Castilla y León", kind: "Autonomous community", parent: None },
+    SubdivisionData { code: "ES-CM", name: "Castilla-La Mancha", kind: "Autonomous community", parent: None },
+    SubdivisionData { code: "ES-CN", name: "Canarias", kind: "Autonomous community", parent: None },
+    SubdivisionData { code: "ES-CO", name: "Córdoba", kind: "Province", parent: Some("ES-AN") },
+    SubdivisionData { code: "ES-CR", name: "Ciudad Real", kind: "Province", parent: Some("ES-CM") },
+    SubdivisionData { code: "ES-CS", name: "Castelló*", kind: "Province", parent: Some("ES-VC") },
+    SubdivisionData { code: "ES-CT", name: "Catalunya", kind: "Autonomous community", parent: None },
+    SubdivisionData { code: "ES-CU", name: "Cuenca", kind: "Province", parent: Some("ES-CM") },
+    SubdivisionData { code: "ES-EX", name: "Extremadura", kind: "Autonomous community", parent: None },
+    SubdivisionData { code: "ES-GA", name: "Galicia", kind: "Autonomous community", parent: None },
+    SubdivisionData { code: "ES-GC", name: "Las Palmas", kind: "Province", parent: Some("ES-CN") },
+    SubdivisionData { code: "ES-GI", name: "Girona", kind: "Province", parent: Some("ES-CT") },
+    SubdivisionData { code: "ES-GR", name: "Granada", kind: "Province", parent: Some("ES-AN") },
+    SubdivisionData { code: "ES-GU", name: "Guadalajara", kind: "Province", parent: Some("ES-CM") },
+    SubdivisionData { code: "ES-H", name: "Huelva", kind: "Province", parent: Some("ES-AN") },
+    SubdivisionData { code: "ES-HU", name: "Huesca", kind: "Province", parent: Some("ES-AR") },
+    SubdivisionData { code: "ES-IB", name: "Illes Balears", kind: "Autonomous community", parent: None },
+    SubdivisionData { code: "ES-J", name: "Jaén", kind: "Province", parent: Some("ES-AN") },
+    SubdivisionData { code: "ES-L", name: "Lleida", kind: "Province", parent: Some("ES-CT") },
+    SubdivisionData { code: "ES-LE", name: "León", kind: "Province", parent: Some("ES-CL") },
+    SubdivisionData { code: "ES-LO", name: "La Rioja", kind: "Province", parent: Some("ES-RI") },
+    SubdivisionData { code: "ES-LU", name: "Lugo", kind: "Province", parent: Some("ES-GA") },
+    SubdivisionData { code: "ES-M", name: "Madrid", kind: "Province", parent: Some("ES-MD") },
+    SubdivisionData { code: "ES-MA", name: "Málaga", kind: "Province", parent: Some("ES-AN") },
+    SubdivisionData { code: "ES-MC", name: "Murcia, Región de", kind: "Autonomous community", parent: None },
+    SubdivisionData { code: "ES-MD", name: "Madrid, Comunidad de", kind: "Autonomous community", parent: None },
+    SubdivisionData { code: "ES-ML", name: "Melilla", kind: "Autonomous city in north africa", parent: None },
+    SubdivisionData { code: "ES-MU", name: "Murcia", kind: "Province", parent: Some("ES-MC") },
+    SubdivisionData { code: "ES-NA", name: "Nafarroa*", kind: "Province", parent: Some("ES-NC") },
+    SubdivisionData { code: "ES-NC", name: "Nafarroako Foru Komunitatea*", kind: "Autonomous community", parent: None },
+    SubdivisionData { code: "ES-O", name: "Asturias", kind: "Province", parent: Some("ES-AS") },
+    SubdivisionData { code: "ES-OR", name: "Ourense", kind: "Province", parent: Some("ES-GA") },
+    SubdivisionData { code: "ES-P", name: "Palencia", kind: "Province", parent: Some("ES-CL") },
+    SubdivisionData { code: "ES-PM", name: "Illes Balears", kind: "Province", parent: Some("ES-IB") },
+    SubdivisionData { code: "ES-PO", name: "Pontevedra", kind: "Province", parent: Some("ES-GA") },
+    SubdivisionData { code: "ES-PV", name: "Euskal Herria", kind: "Autonomous community", parent: None },
+    SubdivisionData { code: "ES-RI", name: "La Rioja", kind: "Autonomous community", parent: None },
+    SubdivisionData { code: "ES-S", name: "Cantabria", kind: "Province", parent: Some("ES-CB") },
+    SubdivisionData { code: "ES-SA", name: "Salamanca", kind: "Province", parent: Some("ES-CL") },
+    SubdivisionData { code: "ES-SE", name: "Sevilla", kind: "Province", parent: Some("ES-AN") },
+    SubdivisionData { code: "ES-SG", name: "Segovia", kind: "Province", parent: Some("ES-CL") },
+    SubdivisionData { code: "ES-SO", name: "Soria", kind: "Province", parent: Some("ES-CL") },
+    SubdivisionData { code: "ES-SS", name: "Gipuzkoa", kind: "Province", parent: Some("ES-PV") },
+    SubdivisionData { code: "ES-T", name: "Tarragona", kind: "Province", parent: Some("ES-CT") },
+    SubdivisionData { code: "ES-TE", name: "Teruel", kind: "Province", parent: Some("ES-AR") },
+    SubdivisionData { code: "ES-TF", name: "Santa Cruz de Tenerife", kind: "Province", parent: Some("ES-CN") },
+    SubdivisionData { code: "ES-TO", name: "Toledo", kind: "Province", parent: Some("ES-CM") },
+    SubdivisionData { code: "ES-V", name: "Valencia", kind: "Province", parent: Some("ES-VC") },
+    SubdivisionData { code: "ES-VA", name: "Valladolid", kind: "Province", parent: Some("ES-CL") },
+    SubdivisionData { code: "ES-VC", name: "Valenciana, Comunidad", kind: "Autonomous community", parent: None },
+    SubdivisionData { code: "ES-VI", name: "Araba*", kind: "Province", parent: Some("ES-PV") },
+    SubdivisionData { code: "ES-Z", name: "Zaragoza", kind: "Province", parent: Some("ES-AR") },
+    SubdivisionData { code: "ES-ZA", name: "Zamora", kind: "Province", parent: Some("ES-CL") },
+    SubdivisionData { code: "ET-AA", name: "Addis Ababa", kind: "Administration", parent: None },
+    SubdivisionData { code: "ET-AF", name: "Afar", kind: "Regional state", parent: None },
+    SubdivisionData { code: "ET-AM", name: "Amara", kind: "Regional state", parent: None },
+    SubdivisionData { code: "ET-BE", name: "Benshangul-Gumaz", kind: "Regional state", parent: None },
+    SubdivisionData { code: "ET-DD", name: "Dire Dawa", kind: "Administration", parent: None },
+    SubdivisionData { code: "ET-GA", name: "Gambela Peoples", kind: "Regional state", parent: None },
+    SubdivisionData { code: "ET-HA", name: "Harari People", kind: "Regional state", parent: None },
+    SubdivisionData { code: "ET-OR", name: "Oromia", kind: "Regional state", parent: None },
+    SubdivisionData { code: "ET-SN", name: "Southern Nations, Nationalities and Peoples", kind: "Regional state", parent: None },
+    SubdivisionData { code: "ET-SO", name: "Somali", kind: "Regional state", parent: None },
+    SubdivisionData { code: "ET-TI", name: "Tigrai", kind: "Regional state", parent: None },
+    SubdivisionData { code: "FI-01", name: "Åland", kind: "Region", parent: None },
+    SubdivisionData { code: "FI-02", name: "Etelä-Karjala", kind: "Region", parent: None },
+    SubdivisionData { code: "FI-03", name: "Etelä-Pohjanmaa", kind: "Region", parent: None },
+    SubdivisionData { code: "FI-04", name: "Etelä-Savo", kind: "Region", parent: None },
+    SubdivisionData { code: "FI-05", name: "Kainuu", kind: "Region", parent: None },
+    SubdivisionData { code: "FI-06", name: "Kanta-Häme", kind: "Region", parent: None },
+    SubdivisionData { code: "FI-07", name: "Keski-Pohjanmaa", kind: "Region", parent: None },
+    SubdivisionData { code: "FI-08", name: "Keski-Suomi", kind: "Region", parent: None },
+    SubdivisionData { code: "FI-09", name: "Kymenlaakso", kind: "Region", parent: None },
+    SubdivisionData { code: "FI-10", name: "Lappi", kind: "Region", parent: None },
+    SubdivisionData { code: "FI-11", name: "Pirkanmaa", kind: "Region", parent: None },
+    SubdivisionData { code: "FI-12", name: "Pohjanmaa", kind: "Region", parent: None },
+    SubdivisionData { code: "FI-13", name: "Pohjois-Karjala", kind: "Region", parent: None },
+    SubdivisionData { code: "FI-14", name: "Pohjois-Pohjanmaa", kind: "Region", parent: None },
+    SubdivisionData { code: "FI-15", name: "Pohjois-Savo", kind: "Region", parent: None },
+    SubdivisionData { code: "FI-16", name: "Päijät-Häme", kind: "Region", parent: None },
+    SubdivisionData { code: "FI-17", name: "Satakunta", kind: "Region", parent: None },
+    SubdivisionData { code: "FI-18", name: "Uusimaa", kind: "Region", parent: None },
+    SubdivisionData { code: "FI-19", name: "Varsinais-Suomi", kind: "Region", parent: None },
+    SubdivisionData { code: "FJ-01", name: "Ba", kind: "Province", parent: Some("FJ-W") },
+    SubdivisionData { code: "FJ-02", name: "Bua", kind: "Province", parent: Some("FJ-N") },
+    SubdivisionData { code: "FJ-03", name: "Cakaudrove", kind: "Province", parent: Some("FJ-N") },
+    SubdivisionData { code: "FJ-04", name: "Kadavu", kind: "Province", parent: Some("FJ-E") },
+    SubdivisionData { code: "FJ-05", name: "Lau", kind: "Province", parent: Some("FJ-E") },
+    SubdivisionData { code: "FJ-06", name: "Lomaiviti", kind: "Province", parent: Some("FJ-E") },
+    SubdivisionData { code: "FJ-07", name: "Macuata", kind: "Province", parent: Some("FJ-N") },
+    SubdivisionData { code: "FJ-08", name: "Nadroga and Navosa", kind: "Province", parent: Some("FJ-W") },
+    SubdivisionData { code: "FJ-09", name: "Naitasiri", kind: "Province", parent: Some("FJ-C") },
+    SubdivisionData { code: "FJ-10", name: "Namosi", kind: "Province", parent: Some("FJ-C") },
+    SubdivisionData { code: "FJ-11", name: "Ra", kind: "Province", parent: Some("FJ-W") },
+    SubdivisionData { code: "FJ-12", name: "Rewa", kind: "Province", parent: Some("FJ-C") },
+    SubdivisionData { code: "FJ-13", name: "Serua", kind: "Province", parent: Some("FJ-C") },
+    SubdivisionData { code: "FJ-14", name: "Tailevu", kind: "Province", parent: Some("FJ-C") },
+    SubdivisionData { code: "FJ-C", name: "Central", kind: "Division", parent: None },
+    SubdivisionData { code: "FJ-E", name: "Eastern", kind: "Division", parent: None },
+    SubdivisionData { code: "FJ-N", name: "Northern", kind: "Division", parent: None },
+    SubdivisionData { code: "FJ-R", name: "Rotuma", kind: "Dependency", parent: None },
+    SubdivisionData { code: "FJ-W", name: "Western", kind: "Division", parent: None },
+    SubdivisionData { code: "FM-KSA", name: "Kosrae", kind: "State", parent: None },
+    SubdivisionData { code: "FM-PNI", name: "Pohnpei", kind: "State", parent: None },
+    SubdivisionData { code: "FM-TRK", name: "Chuuk", kind: "State", parent: None },
+    SubdivisionData { code: "FM-YAP", name: "Yap", kind: "State", parent: None },
+    SubdivisionData { code: "FR-01", name: "Ain", kind: "Metropolitan department", parent: Some("FR-ARA") },
+    SubdivisionData { code: "FR-02", name: "Aisne", kind: "Metropolitan department", parent: Some("FR-HDF") },
+    SubdivisionData { code: "FR-03", name: "Allier", kind: "Metropolitan department", parent: Some("FR-ARA") },
+    SubdivisionData { code: "FR-04", name: "Alpes-de-Haute-Provence", kind: "Metropolitan department", parent: Some("FR-PAC") },
+    SubdivisionData { code: "FR-05", name: "Hautes-Alpes", kind: "Metropolitan department", parent: Some("FR-PAC") },
+    SubdivisionData { code: "FR-06", name: "Alpes-Maritimes", kind: "Metropolitan department", parent: Some("FR-PAC") },
+    SubdivisionData { code: "FR-07", name: "Ardèche", kind: "Metropolitan department", parent: Some("FR-ARA") },
+    SubdivisionData { code: "FR-08", name: "Ardennes", kind: "Metropolitan department", parent: Some("FR-GES") },
+    SubdivisionData { code: "FR-09", name: "Ariège", kind: "Metropolitan department", parent: Some("FR-OCC") },
+    SubdivisionData { code: "FR-10", name: "Aube", kind: "Metropolitan department", parent: Some("FR-GES") },
+    SubdivisionData { code: "FR-11", name: "Aude", kind: "Metropolitan department", parent: Some("FR-OCC") },
+    SubdivisionData { code: "FR-12", name: "Aveyron", kind: "Metropolitan department", parent: Some("FR-OCC") },
+    SubdivisionData { code: "FR-13", name: "Bouches-du-Rhône", kind: "Metropolitan department", parent: Some("FR-PAC") },
+    SubdivisionData { code: "FR-14", name: "Calvados", kind: "Metropolitan department", parent: Some("FR-NOR") },
+    SubdivisionData { code: "FR-15", name: "Cantal", kind: "Metropolitan department", parent: Some("FR-ARA") },
+    SubdivisionData { code: "FR-16", name: "Charente", kind: "Metropolitan department", parent: Some("FR-NAQ") },
+    SubdivisionData { code: "FR-17", name: "Charente-Maritime", kind: "Metropolitan department", parent: Some("FR-NAQ") },
+    SubdivisionData { code: "FR-18", name: "Cher", kind: "Metropolitan department", parent: Some("FR-CVL") },
+    SubdivisionData { code: "FR-19", name: "Corrèze", kind: "Metropolitan department", parent: Some("FR-NAQ") },
+    SubdivisionData { code: "FR-20R", name: "Corse", kind: "Metropolitan collectivity with special status", parent: None },
+    SubdivisionData { code: "FR-21", name: "Côte-d'Or", kind: "Metropolitan department", parent: Some("FR-BFC") },
+    SubdivisionData { code: "FR-22", name: "Côtes-d'Armor", kind: "Metropolitan department", parent: Some("FR-BRE") },
+    SubdivisionData { code: "FR-23", name: "Creuse", kind: "Metropolitan department", parent: Some("FR-NAQ") },
+    SubdivisionData { code: "FR-24", name: "Dordogne", kind: "Metropolitan department", parent: Some("FR-NAQ") },
+    SubdivisionData { code: "FR-25", name: "Doubs", kind: "Metropolitan department", parent: Some("FR-BFC") },
+    SubdivisionData { code: "FR-26", name: "Drôme", kind: "Metropolitan department", parent: Some("FR-ARA") },
+    SubdivisionData { code: "FR-27", name: "Eure", kind: "Metropolitan department", parent: Some("FR-NOR") },
+    SubdivisionData { code: "FR-28", name: "Eure-et-Loir", kind: "Metropolitan department", parent: Some("FR-CVL") },
+    SubdivisionData { code: "FR-29", name: "Finistère", kind: "Metropolitan department", parent: Some("FR-BRE") },
+    SubdivisionData { code: "FR-2A", name: "Corse-du-Sud", kind: "Metropolitan department", parent: Some("FR-20R") },
+    SubdivisionData { code: "FR-2B", name: "Haute-Corse", kind: "Metropolitan department", parent: Some("FR-20R") },
+    SubdivisionData { code: "FR-30", name: "Gard", kind: "Metropolitan department", parent: Some("FR-OCC") },
+    SubdivisionData { code: "FR-31", name: "Haute-Garonne", kind: "Metropolitan department", parent: Some("FR-OCC") },
+    SubdivisionData { code: "FR-32", name: "Gers", kind: "Metropolitan department", parent: Some("FR-OCC") },
+    SubdivisionData { code: "FR-33", name: "Gironde", kind: "Metropolitan department", parent: Some("FR-NAQ") },
+    SubdivisionData { code: "FR-34", name: "Hérault", kind: "Metropolitan department", parent: Some("FR-OCC") },
+    SubdivisionData { code: "FR-35", name: "Ille-et-Vilaine", kind: "Metropolitan department", parent: Some("FR-BRE") },
+    SubdivisionData { code: "FR-36", name: "Indre", kind: "Metropolitan department", parent: Some("FR-CVL") },
+    SubdivisionData { code: "FR-37", name: "Indre-et-Loire", kind: "Metropolitan department", parent: Some("FR-CVL") },
+    SubdivisionData { code: "FR-38", name: "Isère", kind: "Metropolitan department", parent: Some("FR-ARA") },
+    SubdivisionData { code: "FR-39", name: "Jura", kind: "Metropolitan department", parent: Some("FR-BFC") },
+    SubdivisionData { code: "FR-40", name: "Landes", kind: "Metropolitan department", parent: Some("FR-NAQ") },
+    SubdivisionData { code: "FR-41", name: "Loir-et-Cher", kind: "Metropolitan department", parent: Some("FR-CVL") },
+    SubdivisionData { code: "FR-42", name: "Loire", kind: "Metropolitan department", parent: Some("FR-ARA") },
+    SubdivisionData { code: "FR-43", name: "Haute-Loire", kind: "Metropolitan department", parent: Some("FR-ARA") },
+    SubdivisionData { code: "FR-44", name: "Loire-Atlantique", kind: "Metropolitan department", parent: Some("FR-PDL") },
+    SubdivisionData { code: "FR-45", name: "Loiret", kind: "Metropolitan department", parent: Some("FR-CVL") },
+    SubdivisionData { code: "FR-46", name: "Lot", kind: "Metropolitan department", parent: Some("FR-OCC") },
+    SubdivisionData { code: "FR-47", name: "Lot-et-Garonne", kind: "Metropolitan department", parent: Some("FR-NAQ") },
+    SubdivisionData { code: "FR-48", name: "Lozère", kind: "Metropolitan department", parent: Some("FR-OCC") },
+    SubdivisionData { code: "FR-49", name: "Maine-et-Loire", kind: "Metropolitan department", parent: Some("FR-PDL") },
+    SubdivisionData { code: "FR-50", name: "Manche", kind: "Metropolitan department", parent: Some("FR-NOR") },
+    SubdivisionData { code: "FR-51", name: "Marne", kind: "Metropolitan department", parent: Some("FR-GES") },
+    SubdivisionData { code: "FR-52", name: "Haute-Marne", kind: "Metropolitan department", parent: Some("FR-GES") },
+    SubdivisionData { code: "FR-53", name: "Mayenne", kind: "Metropolitan department", parent: Some("FR-PDL") },
+    SubdivisionData { code: "FR-54", name: "Meurthe-et-Moselle", kind: "Metropolitan department", parent: Some("FR-GES") },
+    SubdivisionData { code: "FR-55", name: "Meuse", kind: "Metropolitan department", parent: Some("FR-GES") },
+    SubdivisionData { code: "FR-56", name: "Morbihan", kind: "Metropolitan department", parent: Some("FR-BRE") },
+    SubdivisionData { code: "FR-57", name: "Moselle", kind: "Metropolitan department", parent: Some("FR-GES") },
+    SubdivisionData { code: "FR-58", name: "Nièvre", kind: "Metropolitan department", parent: Some("FR-BFC") },
+    SubdivisionData { code: "FR-59", name: "Nord", kind: "Metropolitan department", parent: Some("FR-HDF") },
+    SubdivisionData { code: "FR-60", name: "Oise", kind: "Metropolitan department", parent: Some("FR-HDF") },
+    SubdivisionData { code: "FR-61", name: "Orne", kind: "Metropolitan department", parent: Some("FR-NOR") },
+    SubdivisionData { code: "FR-62", name: "Pas-de-Calais", kind: "Metropolitan department", parent: Some("FR-HDF") },
+    SubdivisionData { code: "FR-63", name: "Puy-de-Dôme", kind: "Metropolitan department", parent: Some("FR-ARA") },
+    SubdivisionData { code: "FR-64", name: "Pyrénées-Atlantiques", kind: "Metropolitan department", parent: Some("FR-NAQ") },
+    SubdivisionData { code: "FR-65", name: "Hautes-Pyrénées", kind: "Metropolitan department", parent: Some("FR-OCC") },
+    SubdivisionData { code: "FR-66", name: "Pyrénées-Orientales", kind: "Metropolitan department", parent: Some("FR-OCC") },
+    SubdivisionData { code: "FR-67", name: "Bas-Rhin", kind: "Metropolitan department", parent: Some("FR-GES") },
+    SubdivisionData { code: "FR-68", name: "Haut-Rhin", kind: "Metropolitan department", parent: Some("FR-GES") },
+    SubdivisionData { code: "FR-69", name: "Rhône", kind: "Metropolitan department", parent: Some("FR-ARA") },
+    SubdivisionData { code: "FR-70", name: "Haute-Saône", kind: "Metropolitan department", parent: Some("FR-BFC") },
+    SubdivisionData { code: "FR-71", name: "Saône-et-Loire", kind: "Metropolitan department", parent: Some("FR-BFC") },
+    SubdivisionData { code: "FR-72", name: "Sarthe", kind: "Metropolitan department", parent: Some("FR-PDL") },
+    SubdivisionData { code: "FR-73", name: "Savoie", kind: "Metropolitan department", parent: Some("FR-ARA") },
+    SubdivisionData { code: "FR-74", name: "Haute-Savoie", kind: "Metropolitan department", parent: Some("FR-ARA") },
+    SubdivisionData { code: "FR-75", name: "Paris", kind: "Metropolitan department", parent: Some("FR-IDF") },
+    SubdivisionData { code: "FR-76", name: "Seine-Maritime", kind: "Metropolitan department", parent: Some("FR-NOR") },
+    SubdivisionData { code: "FR-77", name: "Seine-et-Marne", kind: "Metropolitan department", parent: Some("FR-IDF") },
+    SubdivisionData { code: "FR-78", name: "Yvelines", kind: "Metropolitan department", parent: Some("FR-IDF") },
+    SubdivisionData { code: "FR-79", name: "Deux-Sèvres", kind: "Metropolitan department", parent: Some("FR-NAQ") },
+    SubdivisionData { code: "FR-80", name: "Somme", kind: "Metropolitan department", parent: Some("FR-HDF") },
+    SubdivisionData { code: "FR-81", name: "Tarn", kind: "Metropolitan department", parent: Some("FR-OCC") },
+    SubdivisionData { code: "FR-82", name: "Tarn-et-Garonne", kind: "Metropolitan department", parent: Some("FR-OCC") },
+    SubdivisionData { code: "FR-83", name: "Var", kind: "Metropolitan department", parent: Some("FR-PAC") },
+    SubdivisionData { code: "FR-84", name: "Vaucluse", kind: "Metropolitan department", parent: Some("FR-PAC") },
+    SubdivisionData { code: "FR-85", name: "Vendée", kind: "Metropolitan department", parent: Some("FR-PDL") },
+    SubdivisionData { code: "FR-86", name: "Vienne", kind: "Metropolitan department", parent: Some("FR-NAQ") },
+    SubdivisionData { code: "FR-87", name: "Haute-Vienne", kind: "Metropolitan department", parent: Some("FR-NAQ") },
+    SubdivisionData { code: "FR-88", name: "Vosges", kind: "Metropolitan department", parent: Some("FR-GES") },
+    SubdivisionData { code: "FR-89", name: "Yonne", kind: "Metropolitan department", parent: Some("FR-BFC") },
+    SubdivisionData { code: "FR-90", name: "Territoire de Belfort", kind: "Metropolitan department", parent: Some("FR-BFC") },
+    SubdivisionData { code: "FR-91", name: "Essonne", kind: "Metropolitan department", parent: Some("FR-IDF") },
+    SubdivisionData { code: "FR-92", name: "Hauts-de-Seine", kind: "Metropolitan department", parent: Some("FR-IDF") },
+    SubdivisionData { code: "FR-93", name: "Seine-Saint-Denis", kind: "Metropolitan department", parent: Some("FR-IDF") },
+    SubdivisionData { code: "FR-94", name: "Val-de-Marne", kind: "Metropolitan department", parent: Some("FR-IDF") },
+    SubdivisionData { code: "FR-95", name: "Val-d'Oise", kind: "Metropolitan department", parent: Some("FR-IDF") },
+    SubdivisionData { code: "FR-971", name: "Guadeloupe", kind: "Overseas department", parent: Some("FR-GP") },
+    SubdivisionData { code: "FR-972", name: "Martinique", kind: "Overseas department", parent: Some("FR-MQ") },
+    SubdivisionData { code: "FR-973", name: "Guyane (française)", kind: "Overseas department", parent: Some("FR-GF") },
+    SubdivisionData { code: "FR-974", name: "La Réunion", kind: "Overseas department", parent: Some("FR-RE") },
+    SubdivisionData { code: "FR-976", name: "Mayotte", kind: "Overseas department", parent: Some("FR-YT") },
+    SubdivisionData { code: "FR-ARA", name: "Auvergne-Rhône-Alpes", kind: "Metropolitan region", parent: None },
+    SubdivisionData { code: "FR-BFC", name: "Bourgogne-Franche-Comté", kind: "Metropolitan region", parent: None },
+    SubdivisionData { code: "FR-BL", name: "Saint-Barthélemy", kind: "Overseas collectivity", parent: None },
+    SubdivisionData { code: "FR-BRE", name: "Bretagne", kind: "Metropolitan region", parent: None },
+    SubdivisionData { code: "FR-CP", name: "Clipperton", kind: "Dependency", parent: None },
+    SubdivisionData { code: "FR-CVL", name: "Centre-Val de Loire", kind: "Metropolitan region", parent: None },
+    SubdivisionData { code: "FR-GES", name: "Grand-Est", kind: "Metropolitan region", parent: None },
+    SubdivisionData { code: "FR-GF", name: "Guyane (française)", kind: "Overseas region", parent: None },
+    SubdivisionData { code: "FR-GP", name: "Guadeloupe", kind: "Overseas region", parent: None },
+    SubdivisionData { code: "FR-HDF", name: "Hauts-de-France", kind: "Metropolitan region", parent: None },
+    SubdivisionData { code: "FR-IDF", name: "Île-de-France", kind: "Metropolitan region", parent: None },
+    SubdivisionData { code: "FR-MF", name: "Saint-Martin", kind: "Overseas collectivity", parent: None },
+    SubdivisionData { code: "FR-MQ", name: "Martinique", kind: "Overseas region", parent: None },
+    SubdivisionData { code: "FR-NAQ", name: "Nouvelle-Aquitaine", kind: "Metropolitan region", parent: None },
+    SubdivisionData { code: "FR-NC", name: "Nouvelle-Calédonie", kind: "Overseas collectivity with special status", parent: None },
+    SubdivisionData { code: "FR-NOR", name: "Normandie", kind: "Metropolitan region", parent: None },
+    SubdivisionData { code: "FR-OCC", name: "Occitanie", kind: "Metropolitan region", parent: None },
+    SubdivisionData { code: "FR-PAC", name: "Provence-Alpes-Côte-d’Azur", kind: "Metropolitan region", parent: None },
+    SubdivisionData { code: "FR-PDL", name: "Pays-de-la-Loire", kind: "Metropolitan region", parent: None },
+    SubdivisionData { code: "FR-PF", name: "Polynésie française", kind: "Overseas collectivity", parent: None },
+    SubdivisionData { code: "FR-PM", name: "Saint-Pierre-et-Miquelon", kind: "Overseas collectivity", parent: None },
+    SubdivisionData { code: "FR-RE", name: "La Réunion", kind: "Overseas region", parent: None },
+    SubdivisionData { code: "FR-TF", name: "Terres australes françaises", kind: "Overseas territory", parent: None },
+    SubdivisionData { code: "FR-WF", name: "Wallis-et-Futuna", kind: "Overseas collectivity", parent: None },
+    SubdivisionData { code: "FR-YT", name: "Mayotte", kind: "Overseas region", parent: None },
+    SubdivisionData { code: "GA-1", name: "Estuaire", kind: "Province", parent: None },
+    SubdivisionData { code: "GA-2", name: "Haut-Ogooué", kind: "Province", parent: None },
+    SubdivisionData { code: "GA-3", name: "Moyen-Ogooué", kind: "Province", parent: None },
+    SubdivisionData { code: "GA-4", name: "Ngounié", kind: "Province", parent: None },
+    SubdivisionData { code: "GA-5", name: "Nyanga", kind: "Province", parent: None },
+    SubdivisionData { code: "GA-6", name: "Ogooué-Ivindo", kind: "Province", parent: None },
+    SubdivisionData { code: "GA-7", name: "Ogooué-Lolo", kind: "Province", parent: None },
+    SubdivisionData { code: "GA-8", name: "Ogooué-Maritime", kind: "Province", parent: None },
+    SubdivisionData { code: "GA-9", name: "Woleu-Ntem", kind: "Province", parent: None },
+    SubdivisionData { code: "GB-ABC", name: "Armagh City, Banbridge and Craigavon", kind: "District", parent: Some("GB-NIR") },
+    SubdivisionData { code: "GB-ABD", name: "Aberdeenshire", kind: "Council area", parent: Some("GB-SCT") },
+    SubdivisionData { code: "GB-ABE", name: "Aberdeen City", kind: "Council area", parent: Some("GB-SCT") },
+    SubdivisionData { code: "GB-AGB", name: "Argyll and Bute", kind: "Council area", parent: Some("GB-SCT") },
+    SubdivisionData { code: "GB-AGY", name: "Isle of Anglesey", kind: "Unitary authority", parent: Some("GB-WLS") },
+    SubdivisionData { code: "GB-AND", name: "Ards and North Down", kind: "District", parent: Some("GB-NIR") },
+    SubdivisionData { code: "GB-ANN", name: "Antrim and Newtownabbey", kind: "District", parent: Some("GB-NIR") },
+    SubdivisionData { code: "GB-ANS", name: "Angus", kind: "Council area", parent: Some("GB-SCT") },
+    SubdivisionData { code: "GB-BAS", name: "Bath and North East Somerset", kind: "Unitary authority", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-BBD", name: "Blackburn with Darwen", kind: "Unitary authority", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-BCP", name: "Bournemouth, Christchurch and Poole", kind: "Unitary authority", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-BDF", name: "Bedford", kind: "Unitary authority", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-BDG", name: "Barking and Dagenham", kind: "London borough", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-BEN", name: "Brent", kind: "London borough", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-BEX", name: "Bexley", kind: "London borough", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-BFS", name: "Belfast City", kind: "District", parent: Some("GB-NIR") },
+    SubdivisionData { code: "GB-BGE", name: "Bridgend", kind: "Unitary authority", parent: Some("GB-WLS") },
+    SubdivisionData { code: "GB-BGW", name: "Blaenau Gwent", kind: "Unitary authority", parent: Some("GB-WLS") },
+    SubdivisionData { code: "GB-BIR", name: "Birmingham", kind: "Metropolitan district", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-BKM", name: "Buckinghamshire", kind: "Two-tier county", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-BNE", name: "Barnet", kind: "London borough", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-BNH", name: "Brighton and Hove", kind: "Unitary authority", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-BNS", name: "Barnsley", kind: "Metropolitan district", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-BOL", name: "Bolton", kind: "Metropolitan district", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-BPL", name: "Blackpool", kind: "Unitary authority", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-BRC", name: "Bracknell Forest", kind: "Unitary authority", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-BRD", name: "Bradford", kind: "Metropolitan district", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-BRY", name: "Bromley", kind: "London borough", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-BST", name: "Bristol, City of", kind: "Unitary authority", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-BUR", name: "Bury", kind: "Metropolitan district", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-CAM", name: "Cambridgeshire", kind: "Two-tier county", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-CAY", name: "Caerphilly", kind: "Unitary authority", parent: Some("GB-WLS") },
+    SubdivisionData { code: "GB-CBF", name: "Central Bedfordshire", kind: "Unitary authority", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-CCG", name: "Causeway Coast and Glens", kind: "District", parent: Some("GB-NIR") },
+    SubdivisionData { code: "GB-CGN", name: "Ceredigion", kind: "Unitary authority", parent: Some("GB-WLS") },
+    SubdivisionData { code: "GB-CHE", name: "Cheshire East", kind: "Unitary authority", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-CHW", name: "Cheshire West and Chester", kind: "Unitary authority", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-CLD", name: "Calderdale", kind: "Metropolitan district", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-CLK", name: "Clackmannanshire", kind: "Council area", parent: Some("GB-SCT") },
+    SubdivisionData { code: "GB-CMA", name: "Cumbria", kind: "Two-tier county", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-CMD", name: "Camden", kind: "London borough", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-CMN", name: "Carmarthenshire", kind: "Unitary authority", parent: Some("GB-WLS") },
+    SubdivisionData { code: "GB-CON", name: "Cornwall", kind: "Unitary authority", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-COV", name: "Coventry", kind: "Metropolitan district", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-CRF", name: "Cardiff", kind: "Unitary authority", parent: Some("GB-WLS") },
+    SubdivisionData { code: "GB-CRY", name: "Croydon", kind: "London borough", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-CWY", name: "Conwy", kind: "Unitary authority", parent: Some("GB-WLS") },
+    SubdivisionData { code: "GB-DAL", name: "Darlington", kind: "Unitary authority", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-DBY", name: "Derbyshire", kind: "Two-tier county", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-DEN", name: "Denbighshire", kind: "Unitary authority", parent: Some("GB-WLS") },
+    SubdivisionData { code: "GB-DER", name: "Derby", kind: "Unitary authority", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-DEV", name: "Devon", kind: "Two-tier county", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-DGY", name: "Dumfries and Galloway", kind: "Council area", parent: Some("GB-SCT") },
+    SubdivisionData { code: "GB-DNC", name: "Doncaster", kind: "Metropolitan district", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-DND", name: "Dundee City", kind: "Council area", parent: Some("GB-SCT") },
+    SubdivisionData { code: "GB-DOR", name: "Dorset", kind: "Two-tier county", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-DRS", name: "Derry and Strabane", kind: "District", parent: Some("GB-NIR") },
+    SubdivisionData { code: "GB-DUD", name: "Dudley", kind: "Metropolitan district", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-DUR", name: "Durham, County", kind: "Unitary authority", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-EAL", name: "Ealing", kind: "London borough", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-EAY", name: "East Ayrshire", kind: "Council area", parent: Some("GB-SCT") },
+    SubdivisionData { code: "GB-EDH", name: "Edinburgh, City of", kind: "Council area", parent: Some("GB-SCT") },
+    SubdivisionData { code: "GB-EDU", name: "East Dunbartonshire", kind: "Council area", parent: Some("GB-SCT") },
+    SubdivisionData { code: "GB-ELN", name: "East Lothian", kind: "Council area", parent: Some("GB-SCT") },
+    SubdivisionData { code: "GB-ELS", name: "Eilean Siar", kind: "Council area", parent: Some("GB-SCT") },
+    SubdivisionData { code: "GB-ENF", name: "Enfield", kind: "London borough", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-ENG", name: "England", kind: "Country", parent: None },
+    SubdivisionData { code: "GB-ERW", name: "East Renfrewshire", kind: "Council area", parent: Some("GB-SCT") },
+    SubdivisionData { code: "GB-ERY", name: "East Riding of Yorkshire", kind: "Unitary authority", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-ESS", name: "Essex", kind: "Two-tier county", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-ESX", name: "East Sussex", kind: "Two-tier county", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-FAL", name: "Falkirk", kind: "Council area", parent: Some("GB-SCT") },
+    SubdivisionData { code: "GB-FIF", name: "Fife", kind: "Council area", parent: Some("GB-SCT") },
+    SubdivisionData { code: "GB-FLN", name: "Flintshire", kind: "Unitary authority", parent: Some("GB-WLS") },
+    SubdivisionData { code: "GB-FMO", name: "Fermanagh and Omagh", kind: "District", parent: Some("GB-NIR") },
+    SubdivisionData { code: "GB-GAT", name: "Gateshead", kind: "Metropolitan district", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-GLG", name: "Glasgow City", kind: "Council area", parent: Some("GB-SCT") },
+    SubdivisionData { code: "GB-GLS", name: "Gloucestershire", kind: "Two-tier county", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-GRE", name: "Greenwich", kind: "London borough", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-GWN", name: "Gwynedd", kind: "Unitary authority", parent: Some("GB-WLS") },
+    SubdivisionData { code: "GB-HAL", name: "Halton", kind: "Unitary authority", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-HAM", name: "Hampshire", kind: "Two-tier county", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-HAV", name: "Havering", kind: "London borough", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-HCK", name: "Hackney", kind: "London borough", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-HEF", name: "Herefordshire", kind: "Unitary authority", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-HIL", name: "Hillingdon", kind: "London borough", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-HLD", name: "Highland", kind: "Council area", parent: Some("GB-SCT") },
+    SubdivisionData { code: "GB-HMF", name: "Hammersmith and Fulham", kind: "London borough", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-HNS", name: "Hounslow", kind: "London borough", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-HPL", name: "Hartlepool", kind: "Unitary authority", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-HRT", name: "Hertfordshire", kind: "Two-tier county", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-HRW", name: "Harrow", kind: "London borough", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-HRY", name: "Haringey", kind: "London borough", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-IOS", name: "Isles of Scilly", kind: "Unitary authority", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-IOW", name: "Isle of Wight", kind: "Unitary authority", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-ISL", name: "Islington", kind: "London borough", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-IVC", name: "Inverclyde", kind: "Council area", parent: Some("GB-SCT") },
+    SubdivisionData { code: "GB-KEC", name: "Kensington and Chelsea", kind: "London borough", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-KEN", name: "Kent", kind: "Two-tier county", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-KHL", name: "Kingston upon Hull", kind: "Unitary authority", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-KIR", name: "Kirklees", kind: "Metropolitan district", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-KTT", name: "Kingston upon Thames", kind: "London borough", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-KWL", name: "Knowsley", kind: "Metropolitan district", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-LAN", name: "Lancashire", kind: "Two-tier county", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-LBC", name: "Lisburn and Castlereagh", kind: "District", parent: Some("GB-NIR") },
+    SubdivisionData { code: "GB-LBH", name: "Lambeth", kind: "London borough", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-LCE", name: "Leicester", kind: "Unitary authority", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-LDS", name: "Leeds", kind: "Metropolitan district", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-LEC", name: "Leicestershire", kind: "Two-tier county", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-LEW", name: "Lewisham", kind: "London borough", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-LIN", name: "Lincolnshire", kind: "Two-tier county", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-LIV", name: "Liverpool", kind: "Metropolitan district", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-LND", name: "London, City of", kind: "City corporation", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-LUT", name: "Luton", kind: "Unitary authority", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-MAN", name: "Manchester", kind: "Metropolitan district", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-MDB", name: "Middlesbrough", kind: "Unitary authority", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-MDW", name: "Medway", kind: "Unitary authority", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-MEA", name: "Mid and East Antrim", kind: "District", parent: Some("GB-NIR") },
+    SubdivisionData { code: "GB-MIK", name: "Milton Keynes", kind: "Unitary authority", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-MLN", name: "Midlothian", kind: "Council area", parent: Some("GB-SCT") },
+    SubdivisionData { code: "GB-MON", name: "Monmouthshire", kind: "Unitary authority", parent: Some("GB-WLS") },
+    SubdivisionData { code: "GB-MRT", name: "Merton", kind: "London borough", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-MRY", name: "Moray", kind: "Council area", parent: Some("GB-SCT") },
+    SubdivisionData { code: "GB-MTY", name: "Merthyr Tydfil", kind: "Unitary authority", parent: Some("GB-WLS") },
+    SubdivisionData { code: "GB-MUL", name: "Mid-Ulster", kind: "District", parent: Some("GB-NIR") },
+    SubdivisionData { code: "GB-NAY", name: "North Ayrshire", kind: "Council area", parent: Some("GB-SCT") },
+    SubdivisionData { code: "GB-NBL", name: "Northumberland", kind: "Unitary authority", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-NEL", name: "North East Lincolnshire", kind: "Unitary authority", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-NET", name: "Newcastle upon Tyne", kind: "Metropolitan district", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-NFK", name: "Norfolk", kind: "Two-tier county", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-NGM", name: "Nottingham", kind: "Unitary authority", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-NIR", name: "Northern Ireland", kind: "Province", parent: None },
+    SubdivisionData { code: "GB-NLK", name: "North Lanarkshire", kind: "Council area", parent: Some("GB-SCT") },
+    SubdivisionData { code: "GB-NLN", name: "North Lincolnshire", kind: "Unitary authority", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-NMD", name: "Newry, Mourne and Down", kind: "District", parent: Some("GB-NIR") },
+    SubdivisionData { code: "GB-NSM", name: "North Somerset", kind: "Unitary authority", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-NTH", name: "Northamptonshire", kind: "Two-tier county", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-NTL", name: "Neath Port Talbot", kind: "Unitary authority", parent: Some("GB-WLS") },
+    SubdivisionData { code: "GB-NTT", name: "Nottinghamshire", kind: "Two-tier county", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-NTY", name: "North Tyneside", kind: "Metropolitan district", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-NWM", name: "Newham", kind: "London borough", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-NWP", name: "Newport", kind: "Unitary authority", parent: Some("GB-WLS") },
+    SubdivisionData { code: "GB-NYK", name: "North Yorkshire", kind: "Two-tier county", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-OLD", name: "Oldham", kind: "Metropolitan district", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-ORK", name: "Orkney Islands", kind: "Council area", parent: Some("GB-SCT") },
+    SubdivisionData { code: "GB-OXF", name: "Oxfordshire", kind: "Two-tier county", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-PEM", name: "Pembrokeshire", kind: "Unitary authority", parent: Some("GB-WLS") },
+    SubdivisionData { code: "GB-PKN", name: "Perth and Kinross", kind: "Council area", parent: Some("GB-SCT") },
+    SubdivisionData { code: "GB-PLY", name: "Plymouth", kind: "Unitary authority", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-POR", name: "Portsmouth", kind: "Unitary authority", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-POW", name: "Powys", kind: "Unitary authority", parent: Some("GB-WLS") },
+    SubdivisionData { code: "GB-PTE", name: "Peterborough", kind: "Unitary authority", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-RCC", name: "Redcar and Cleveland", kind: "Unitary authority", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-RCH", name: "Rochdale", kind: "Metropolitan district", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-RCT", name: "Rhondda Cynon Taff", kind: "Unitary authority", parent: Some("GB-WLS") },
+    SubdivisionData { code: "GB-RDB", name: "Redbridge", kind: "London borough", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-RDG", name: "Reading", kind: "Unitary authority", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-RFW", name: "Renfrewshire", kind: "Council area", parent: Some("GB-SCT") },
+    SubdivisionData { code: "GB-RIC", name: "Richmond upon Thames", kind: "London borough", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-ROT", name: "Rotherham", kind: "Metropolitan district", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-RUT", name: "Rutland", kind: "Unitary authority", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-SAW", name: "Sandwell", kind: "Metropolitan district", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-SAY", name: "South Ayrshire", kind: "Council area", parent: Some("GB-SCT") },
+    SubdivisionData { code: "GB-SCB", name: "Scottish Borders", kind: "Council area", parent: Some("GB-SCT") },
+    SubdivisionData { code: "GB-SCT", name: "Scotland", kind: "Country", parent: None },
+    SubdivisionData { code: "GB-SFK", name: "Suffolk", kind: "Two-tier county", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-SFT", name: "Sefton", kind: "Metropolitan district", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-SGC", name: "South Gloucestershire", kind: "Unitary authority", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-SHF", name: "Sheffield", kind: "Metropolitan district", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-SHN", name: "St. Helens", kind: "Metropolitan district", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-SHR", name: "Shropshire", kind: "Unitary authority", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-SKP", name: "Stockport", kind: "Metropolitan district", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-SLF", name: "Salford", kind: "Metropolitan district", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-SLG", name: "Slough", kind: "Unitary authority", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-SLK", name: "South Lanarkshire", kind: "Council area", parent: Some("GB-SCT") },
+    SubdivisionData { code: "GB-SND", name: "Sunderland", kind: "Metropolitan district", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-SOL", name: "Solihull", kind: "Metropolitan district", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-SOM", name: "Somerset", kind: "Two-tier county", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-SOS", name: "Southend-on-Sea", kind: "Unitary authority", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-SRY", name: "Surrey", kind: "Two-tier county", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-STE", name: "Stoke-on-Trent", kind: "Unitary authority", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-STG", name: "Stirling", kind: "Council area", parent: Some("GB-SCT") },
+    SubdivisionData { code: "GB-STH", name: "Southampton", kind: "Unitary authority", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-STN", name: "Sutton", kind: "London borough", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-STS", name: "Staffordshire", kind: "Two-tier county", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-STT", name: "Stockton-on-Tees", kind: "Unitary authority", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-STY", name: "South Tyneside", kind: "Metropolitan district", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-SWA", name: "Swansea", kind: "Unitary authority", parent: Some("GB-WLS") },
+    SubdivisionData { code: "GB-SWD", name: "Swindon", kind: "Unitary authority", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-SWK", name: "Southwark", kind: "London borough", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-TAM", name: "Tameside", kind: "Metropolitan district", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-TFW", name: "Telford and Wrekin", kind: "Unitary authority", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-THR", name: "Thurrock", kind: "Unitary authority", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-TOB", name: "Torbay", kind: "Unitary authority", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-TOF", name: "Torfaen", kind: "Unitary authority", parent: Some("GB-WLS") },
+    SubdivisionData { code: "GB-TRF", name: "Trafford", kind: "Metropolitan district", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-TWH", name: "Tower Hamlets", kind: "London borough", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-VGL", name: "Vale of Glamorgan, The", kind: "Unitary authority", parent: Some("GB-WLS") },
+    SubdivisionData { code: "GB-WAR", name: "Warwickshire", kind: "Two-tier county", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-WBK", name: "West Berkshire", kind: "Unitary authority", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-WDU", name: "West Dunbartonshire", kind: "Council area", parent: Some("GB-SCT") },
+    SubdivisionData { code: "GB-WFT", name: "Waltham Forest", kind: "London borough", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-WGN", name: "Wigan", kind: "Metropolitan district", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-WIL", name: "Wiltshire", kind: "Unitary authority", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-WKF", name: "Wakefield", kind: "Metropolitan district", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-WLL", name: "Walsall", kind: "Metropolitan district", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-WLN", name: "West Lothian", kind: "Council area", parent: Some("GB-SCT") },
+    SubdivisionData { code: "GB-WLS", name: "Wales", kind: "Country", parent: None },
+    SubdivisionData { code: "GB-WLV", name: "Wolverhampton", kind: "Metropolitan district", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-WND", name: "Wandsworth", kind: "London borough", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-WNM", name: "Windsor and Maidenhead", kind: "Unitary authority", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-WOK", name: "Wokingham", kind: "Unitary authority", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-WOR", name: "Worcestershire", kind: "Two-tier county", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-WRL", name: "Wirral", kind: "Metropolitan district", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-WRT", name: "Warrington", kind: "Unitary authority", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-WRX", name: "Wrexham", kind: "Unitary authority", parent: Some("GB-WLS") },
+    SubdivisionData { code: "GB-WSM", name: "Westminster", kind: "London borough", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-WSX", name: "West Sussex", kind: "Two-tier county", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-YOR", name: "York", kind: "Unitary authority", parent: Some("GB-ENG") },
+    SubdivisionData { code: "GB-ZET", name: "Shetland Islands", kind: "Council area", parent: Some("GB-SCT") },
+    SubdivisionData { code: "GD-01", name: "Saint Andrew", kind: "Parish", parent: None },
+    SubdivisionData { code: "GD-02", name: "Saint David", kind: "Parish", parent: None },
+    SubdivisionData { code: "GD-03", name: "Saint George", kind: "Parish", parent: None },
+    SubdivisionData { code: "GD-04", name: "Saint John", kind: "Parish", parent: None },
+    SubdivisionData { code: "GD-05", name: "Saint Mark", kind: "Parish", parent: None },
+    SubdivisionData { code: "GD-06", name: "Saint Patrick", kind: "Parish", parent: None },
+    SubdivisionData { code: "GD-10", name: "Southern Grenadine Islands", kind: "Dependency", parent: None },
+    SubdivisionData { code: "GE-AB", name: "Abkhazia", kind: "Autonomous republic", parent: None },
+    SubdivisionData { code: "GE-AJ", name: "Ajaria", kind: "Autonomous republic", parent: None },
+    SubdivisionData { code: "GE-GU", name: "Guria", kind: "Region", parent: None },
+    SubdivisionData { code: "GE-IM", name: "Imereti", kind: "Region", parent: None },
+    SubdivisionData { code: "GE-KA", name: "K'akheti", kind: "Region", parent: None },
+    SubdivisionData { code: "GE-KK", name: "Kvemo Kartli", kind: "Region", parent: None },
+    SubdivisionData { code: "GE-MM", name: "Mtskheta-Mtianeti", kind: "Region", parent: None },
+    SubdivisionData { code: "GE-RL", name: "Rach'a-Lechkhumi-Kvemo Svaneti", kind: "Region", parent: None },
+    SubdivisionData { code: "GE-SJ", name: "Samtskhe-Javakheti", kind: "Region", parent: None },
+    SubdivisionData { code: "GE-SK", name: "Shida Kartli", kind: "Region", parent: None },
+    SubdivisionData { code: "GE-SZ", name: "Samegrelo-Zemo Svaneti", kind: "Region", parent: None },
+    SubdivisionData { code: "GE-TB", name: "Tbilisi", kind: "City", parent: None },
+    SubdivisionData { code: "GH-AA", name: "Greater Accra", kind: "Region", parent: None },
+    SubdivisionData { code: "GH-AF", name: "Ahafo", kind: "Region", parent: None },
+    SubdivisionData { code: "GH-AH", name: "Ashanti", kind: "Region", parent: None },
+    SubdivisionData { code: "GH-BE", name: "Bono East", kind: "Region", parent: None },
+    SubdivisionData { code: "GH-BO", name: "Bono", kind: "Region", parent: None },
+    SubdivisionData { code: "GH-CP", name: "Central", kind: "Region", parent: None },
+    SubdivisionData { code: "GH-EP", name: "Eastern", kind: "Region", parent: None },
+    SubdivisionData { code: "GH-NE", name: "North East", kind: "Region", parent: None },
+    SubdivisionData { code: "GH-NP", name: "Northern", kind: "Region", parent: None },
+    SubdivisionData { code: "GH-OT", name: "Oti", kind: "Region", parent: None },
+    SubdivisionData { code: "GH-SV", name: "Savannah", kind: "Region", parent: None },
+    SubdivisionData { code: "GH-TV", name: "Volta", kind: "Region", parent: None },
+    SubdivisionData { code: "GH-UE", name: "Upper East", kind: "Region", parent: None },
+    SubdivisionData { code: "GH-UW", name: "Upper West", kind: "Region", parent: None },
+    SubdivisionData { code: "GH-WN", name: "Western North", kind: "Region", parent: None },
+    SubdivisionData { code: "GH-WP", name: "Western", kind: "Region", parent: None },
+    SubdivisionData { code: "GL-AV", name: "Avannaata Kommunia", kind: "Municipality", parent: None },
+    SubdivisionData { code: "GL-KU", name: "Kommune Kujalleq", kind: "Municipality", parent: None },
+    SubdivisionData { code: "GL-QE", name: "Qeqqata Kommunia", kind: "Municipality", parent: None },
+    SubdivisionData { code: "GL-QT", name: "Kommune Qeqertalik", kind: "Municipality", parent: None },
+    SubdivisionData { code: "GL-SM", name: "Kommuneqarfik Sermersooq", kind: "Municipality", parent: None },
+    SubdivisionData { code: "GM-B", name: "Banjul", kind: "City", parent: None },
+    SubdivisionData { code: "GM-L", name: "Lower River", kind: "Division", parent: None },
+    SubdivisionData { code: "GM-M", name: "Central River", kind: "Division", parent: None },
+    SubdivisionData { code: "GM-N", name: "North Bank", kind: "Division", parent: None },
+    SubdivisionData { code: "GM-U", name: "Upper River", kind: "Division", parent: None },
+    SubdivisionData { code: "GM-W", name: "Western", kind: "Division", parent: None },
+    SubdivisionData { code: "GN-B", name: "Boké", kind: "Administrative region", parent: None },
+    SubdivisionData { code: "GN-BE", name: "Beyla", kind: "Prefecture", parent: Some("GN-N") },
+    SubdivisionData { code: "GN-BF", name: "Boffa", kind: "Prefecture", parent: Some("GN-B") },
+    SubdivisionData { code: "GN-BK", name: "Boké", kind: "Prefecture", parent: Some("GN-B") },
+    SubdivisionData { code: "GN-C", name: "Conakry", kind: "Governorate", parent: None },
+    SubdivisionData { code: "GN-CO", name: "Coyah", kind: "Prefecture", parent: Some("GN-D") },
+    SubdivisionData { code: "GN-D", name: "Kindia", kind: "Administrative region", parent: None },
+    SubdivisionData { code: "GN-DB", name: "Dabola", kind: "Prefecture", parent: Some("GN-F") },
+    SubdivisionData { code: "GN-DI", name: "Dinguiraye", kind: "Prefecture", parent: Some("GN-F") },
+    SubdivisionData { code: "GN-DL", name: "Dalaba", kind: "Prefecture", parent: Some("GN-M") },
+    SubdivisionData { code: "GN-DU", name: "Dubréka", kind: "Prefecture", parent: Some("GN-D") },
+    SubdivisionData { code: "GN-F", name: "Faranah", kind: "Administrative region", parent: None },
+    SubdivisionData { code: "GN-FA", name: "Faranah", kind: "Prefecture", parent: Some("GN-F") },
+    SubdivisionData { code: "GN-FO", name: "Forécariah", kind: "Prefecture", parent: Some("GN-D") },
+    SubdivisionData { code: "GN-FR", name: "Fria", kind: "Prefecture", parent: Some("GN-B") },
+    SubdivisionData { code: "GN-GA", name: "Gaoual", kind: "Prefecture", parent: Some("GN-B") },
+    SubdivisionData { code: "GN-GU", name: "Guékédou", kind: "Prefecture", parent: Some("GN-N") },
+    SubdivisionData { code: "GN-K", name: "Kankan", kind: "Administrative region", parent: None },
+    SubdivisionData { code: "GN-KA", name: "Kankan", kind: "Prefecture", parent: Some("GN-K") },
+    SubdivisionData { code: "GN-KB", name: "Koubia", kind: "Prefecture", parent: Some("GN-L") },
+    SubdivisionData { code: "GN-KD", name: "Kindia", kind: "Prefecture", parent: Some("GN-D") },
+    SubdivisionData { code: "GN-KE", name: "Kérouané", kind: "Prefecture", parent: Some("GN-K") },
+    SubdivisionData { code: "GN-KN", name: "Koundara", kind: "Prefecture", parent: Some("GN-B") },
+    SubdivisionData { code: "GN-KO", name: "Kouroussa", kind: "Prefecture", parent: Some("GN-K") },
+    SubdivisionData { code: "GN-KS", name: "Kissidougou", kind: "Prefecture", parent: Some("GN-F") },
+    SubdivisionData { code: "GN-L", name: "Labé", kind: "Administrative region", parent: None },
+    SubdivisionData { code: "GN-LA", name: "Labé", kind: "Prefecture", parent: Some("GN-L") },
+    SubdivisionData { code: "GN-LE", name: "Lélouma", kind: "Prefecture", parent: Some("GN-L") },
+    SubdivisionData { code: "GN-LO", name: "Lola", kind: "Prefecture", parent: Some("GN-N") },
+    SubdivisionData { code: "GN-M", name: "Mamou", kind: "Administrative region", parent: None },
+    SubdivisionData { code: "GN-MC", name: "Macenta", kind: "Prefecture", parent: Some("GN-N") },
+    SubdivisionData { code: "GN-MD", name: "Mandiana", kind: "Prefecture", parent: Some("GN-K") },
+    SubdivisionData { code: "GN-ML", name: "Mali", kind: "Prefecture", parent: Some("GN-L") },
+    SubdivisionData { code: "GN-MM", name: "Mamou", kind: "Prefecture", parent: Some("GN-M") },
+    SubdivisionData { code: "GN-N", name: "Nzérékoré", kind: "Administrative region", parent: None },
+    SubdivisionData { code: "GN-NZ", name: "Nzérékoré", kind: "Prefecture", parent: Some("GN-N") },
+    SubdivisionData { code: "GN-PI", name: "Pita", kind: "Prefecture", parent: Some("GN-M") },
+    SubdivisionData { code: "GN-SI", name: "Siguiri", kind: "Prefecture", parent: Some("GN-K") },
+    SubdivisionData { code: "GN-TE", name: "Télimélé", kind: "Prefecture", parent: Some("GN-D") },
+    SubdivisionData { code: "GN-TO", name: "Tougué", kind: "Prefecture", parent: Some("GN-L") },
+    SubdivisionData { code: "GN-YO", name: "Yomou", kind: "Prefecture", parent: Some("GN-N") },
+    SubdivisionData { code: "GQ-AN", name: "Annobon", kind: "Province", parent: Some("GQ-I") },
+    SubdivisionData { code: "GQ-BN", name: "Bioko Nord", kind: "Province", parent: Some("GQ-I") },
+    SubdivisionData { code: "GQ-BS", name: "Bioko Sud", kind: "Province", parent: Some("GQ-I") },
+    SubdivisionData { code: "GQ-C", name: "Região Continental", kind: "Region", parent: None },
+    SubdivisionData { code: "GQ-CS", name: "Centro Sud", kind: "Province", parent: Some("GQ-C") },
+    SubdivisionData { code: "GQ-DJ", name: "Djibloho", kind: "Province", parent: Some("GQ-C") },
+    SubdivisionData { code: "GQ-I", name: "Região Insular", kind: "Region", parent: None },
+    SubdivisionData { code: "GQ-KN", name: "Kié-Ntem", kind: "Province", parent: Some("GQ-C") },
+    SubdivisionData { code: "GQ-LI", name: "Litoral", kind: "Province", parent: Some("GQ-C") },
+    SubdivisionData { code: "GQ-WN", name: "Wele-Nzas", kind: "Province", parent: Some("GQ-C") },
+    SubdivisionData { code: "GR-69", name: "Ágion Óros", kind: "Self-governed part", parent: None },
+    SubdivisionData { code: "GR-A", name: "Anatolikí Makedonía kai Thráki", kind: "Administrative region", parent: None },
+    SubdivisionData { code: "GR-B", name: "Kentrikí Makedonía", kind: "Administrative region", parent: None },
+    SubdivisionData { code: "GR-C", name: "Dytikí Makedonía", kind: "Administrative region", parent: None },
+    SubdivisionData { code: "GR-D", name: "Ípeiros", kind: "Administrative region", parent: None },
+    SubdivisionData { code: "GR-E", name: "Thessalía", kind: "Administrative region", parent: None },
+    SubdivisionData { code: "GR-F", name: "Ionía Nísia", kind: "Administrative region", parent: None },
+    SubdivisionData { code: "GR-G", name: "Dytikí Elláda", kind: "Administrative region", parent: None },
+    SubdivisionData { code: "GR-H", name: "Stereá Elláda", kind: "Administrative region", parent: None },
+    SubdivisionData { code: "GR-I", name: "Attikí", kind: "Administrative region", parent: None },
+    SubdivisionData { code: "GR-J", name: "Pelopónnisos", kind: "Administrative region", parent: None },
+    SubdivisionData { code: "GR-K", name: "Vóreio Aigaío", kind: "Administrative region", parent: None },
+    SubdivisionData { code: "GR-L", name: "Nótio Aigaío", kind: "Administrative region", parent: None },
+    SubdivisionData { code: "GR-M", name: "Kríti", kind: "Administrative region", parent: None },
+    SubdivisionData { code: "GT-AV", name: "Alta Verapaz", kind: "Department", parent: None },
+    SubdivisionData { code: "GT-BV", name: "Baja Verapaz", kind: "Department", parent: None },
+    SubdivisionData { code: "GT-CM", name: "Chimaltenango", kind: "Department", parent: None },
+    SubdivisionData { code: "GT-CQ", name: "Chiquimula", kind: "Department", parent: None },
+    SubdivisionData { code: "GT-ES", name: "Escuintla", kind: "Department", parent: None },
+    SubdivisionData { code: "GT-GU", name: "Guatemala", kind: "Department", parent: None },
+    SubdivisionData { code: "GT-HU", name: "Huehuetenango", kind: "Department", parent: None },
+    SubdivisionData { code: "GT-IZ", name: "Izabal", kind: "Department", parent: None },
+    SubdivisionData { code: "GT-JA", name: "Jalapa", kind: "Department", parent: None },
+    SubdivisionData { code: "GT-JU", name: "Jutiapa", kind: "Department", parent: None },
+    SubdivisionData { code: "GT-PE", name: "Petén", kind: "Department", parent: None },
+    SubdivisionData { code: "GT-PR", name: "El Progreso", kind: "Department", parent: None },
+    SubdivisionData { code: "GT-QC", name: "Quiché", kind: "Department", parent: None },
+    SubdivisionData { code: "GT-QZ", name: "Quetzaltenango", kind: "Department", parent: None },
+    SubdivisionData { code: "GT-RE", name: "Retalhuleu", kind: "Department", parent: None },
+    SubdivisionData { code: "GT-SA", name: "Sacatepéquez", kind: "Department", parent: None },
+    SubdivisionData { code: "GT-SM", name: "San Marcos", kind: "Department", parent: None },
+    SubdivisionData { code: "GT-SO", name: "Sololá", kind: "Department", parent: None },
+    SubdivisionData { code: "GT-SR", name: "Santa Rosa", kind: "Department", parent: None },
+    SubdivisionData { code: "GT-SU", name: "Suchitepéquez", kind: "Department", parent: None },
+    SubdivisionData { code: "GT-TO", name: "Totonicapán", kind: "Department", parent: None },
+    SubdivisionData { code: "GT-ZA", name: "Zacapa", kind: "Department", parent: None },
+    SubdivisionData { code: "GW-BA", name: "Bafatá", kind: "Region", parent: Some("GW-L") },
+    SubdivisionData { code: "GW-BL", name: "Bolama / Bijagós", kind: "Region", parent: Some("GW-S") },
+    SubdivisionData { code: "GW-BM", name: "Biombo", kind: "Region", parent: Some("GW-N") },
+    SubdivisionData { code: "GW-BS", name: "Bissau", kind: "Autonomous sector", parent: None },
+    SubdivisionData { code: "GW-CA", name: "Cacheu", kind: "Region", parent: Some("GW-N") },
+    SubdivisionData { code: "GW-GA", name: "Gabú", kind: "Region", parent: Some("GW-L") },
+    SubdivisionData { code: "GW-L", name: "Leste", kind: "Province", parent: None },
+    SubdivisionData { code: "GW-N", name: "Norte", kind: "Province", parent: None },
+    SubdivisionData { code: "GW-OI", name: "Oio", kind: "Region", parent: Some("GW-N") },
+    SubdivisionData { code: "GW-QU", name: "Quinara", kind: "Region", parent: Some("GW-S") },
+    SubdivisionData { code: "GW-S", name: "Sul", kind: "Province", parent: None },
+    SubdivisionData { code: "GW-TO", name: "Tombali", kind: "Region", parent: Some("GW-S") },
+    SubdivisionData { code: "GY-BA", name: "Barima-Waini", kind: "Region", parent: None },
+    SubdivisionData { code: "GY-CU", name: "Cuyuni-Mazaruni", kind: "Region", parent: None },
+    SubdivisionData { code: "GY-DE", name: "Demerara-Mahaica", kind: "Region", parent: None },
+    SubdivisionData { code: "GY-EB", name: "East Berbice-Corentyne", kind: "Region", parent: None },
+    SubdivisionData { code: "GY-ES", name: "Essequibo Islands-West Demerara", kind: "Region", parent: None },
+    SubdivisionData { code: "GY-MA", name: "Mahaica-Berbice", kind: "Region", parent: None },
+    SubdivisionData { code: "GY-PM", name: "Pomeroon-Supenaam", kind: "Region", parent: None },
+    SubdivisionData { code: "GY-PT", name: "Potaro-Siparuni", kind: "Region", parent: None },
+    SubdivisionData { code: "GY-UD", name: "Upper Demerara-Berbice", kind: "Region", parent: None },
+    SubdivisionData { code: "GY-UT", name: "Upper Takutu-Upper Essequibo", kind: "Region", parent: None },
+    SubdivisionData { code: "HN-AT", name: "Atlántida", kind: "Department", parent: None },
+    SubdivisionData { code: "HN-CH", name: "Choluteca", kind: "Department", parent: None },
+    SubdivisionData { code: "HN-CL", name: "Colón", kind: "Department", parent: None },
+    SubdivisionData { code: "HN-CM", name: "Comayagua", kind: "Department", parent: None },
+    SubdivisionData { code: "HN-CP", name: "Copán", kind: "Department", parent: None },
+    SubdivisionData { code: "HN-CR", name: "Cortés", kind: "Department", parent: None },
+    SubdivisionData { code: "HN-EP", name: "El Paraíso", kind: "Department", parent: None },
+    SubdivisionData { code: "HN-FM", name: "Francisco Morazán", kind: "Department", parent: None },
+    SubdivisionData { code: "HN-GD", name: "Gracias a Dios", kind: "Department", parent: None },
+    SubdivisionData { code: "HN-IB", name: "Islas de la Bahía", kind: "Department", parent: None },
+    SubdivisionData { code: "HN-IN", name: "Intibucá", kind: "Department", parent: None },
+    SubdivisionData { code: "HN-LE", name: "Lempira", kind: "Department", parent: None },
+    SubdivisionData { code: "HN-LP", name: "La Paz", kind: "Department", parent: None },
+    SubdivisionData { code: "HN-OC", name: "Ocotepeque", kind: "Department", parent: None },
+    SubdivisionData { code: "HN-OL", name: "Olancho", kind: "Department", parent: None },
+    SubdivisionData { code: "HN-SB", name: "Santa Bárbara", kind: "Department", parent: None },
+    SubdivisionData { code: "HN-VA", name: "Valle", kind: "Department", parent: None },
+    SubdivisionData { code: "HN-YO", name: "Yoro", kind: "Department", parent: None },
+    SubdivisionData { code: "HR-01", name: "Zagrebačka županija", kind: "County", parent: None },
+    SubdivisionData { code: "HR-02", name: "Krapinsko-zagorska županija", kind: "County", parent: None },
+    SubdivisionData { code: "HR-03", name: "Sisačko-moslavačka županija", kind: "County", parent: None },
+    SubdivisionData { code: "HR-04", name: "Karlovačka županija", kind: "County", parent: None },
+    SubdivisionData { code: "HR-05", name: "Varaždinska županija", kind: "County", parent: None },
+    SubdivisionData { code: "HR-06", name: "Koprivničko-križevačka županija", kind: "County", parent: None },
+    SubdivisionData { code: "HR-07", name: "Bjelovarsko-bilogorska županija", kind: "County", parent: None },
+    SubdivisionData { code: "HR-08", name: "Primorsko-goranska županija", kind: "County", parent: None },
+    SubdivisionData { code: "HR-09", name: "Ličko-senjska županija", kind: "County", parent: None },
+    SubdivisionData { code: "HR-10", name: "Virovitičko-podravska županija", kind: "County", parent: None },
+    SubdivisionData { code: "HR-11", name: "Požeško-slavonska županija", kind: "County", parent: None },
+    SubdivisionData { code: "HR-12", name: "Brodsko-posavska županija", kind: "County", parent: None },
+    SubdivisionData { code: "HR-13", name: "Zadarska županija", kind: "County", parent: None },
+    SubdivisionData { code: "HR-14", name: "Osječko-baranjska županija", kind: "County", parent: None },
+    SubdivisionData { code: "HR-15", name: "Šibensko-kninska županija", kind: "County", parent: None },
+    SubdivisionData { code: "HR-16", name: "Vukovarsko-srijemska županija", kind: "County", parent: None },
+    SubdivisionData { code: "HR-17", name: "Splitsko-dalmatinska županija", kind: "County", parent: None },
+    SubdivisionData { code: "HR-18", name: "Istarska županija", kind: "County", parent: None },
+    SubdivisionData { code: "HR-19", name: "Dubrovačko-neretvanska županija", kind: "County", parent: None },
+    SubdivisionData { code: "HR-20", name: "Međimurska županija", kind: "County", parent: None },
+    SubdivisionData { code: "HR-21", name: "Grad Zagreb", kind: "City", parent: None },
+    SubdivisionData { code: "HT-AR", name: "Artibonite", kind: "Department", parent: None },
+    SubdivisionData { code: "HT-CE", name: "Centre", kind: "Department", parent: None },
+    SubdivisionData { code: "HT-GA", name: "Grandans", kind: "Department", parent: None },
+    SubdivisionData { code: "HT-ND", name: "Nord", kind: "Department", parent: None },
+    SubdivisionData { code: "HT-NE", name: "Nord-Est", kind: "Department", parent: None },
+    SubdivisionData { code: "HT-NI", name: "Nip", kind: "Department", parent: None },
+    SubdivisionData { code: "HT-NO", name: "Nord-Ouest", kind: "Department", parent: None },
+    SubdivisionData { code: "HT-OU", name: "Lwès", kind: "Department", parent: None },
+    SubdivisionData { code: "HT-SD", name: "Sid", kind: "Department", parent: None },
+    SubdivisionData { code: "HT-SE", name: "Sidès", kind: "Department", parent: None },
+    SubdivisionData { code: "HU-BA", name: "Baranya", kind: "County", parent: None },
+    SubdivisionData { code: "HU-BC", name: "Békéscsaba", kind: "City with county rights", parent: None },
+    SubdivisionData { code: "HU-BE", name: "Békés", kind: "County", parent: None },
+    SubdivisionData { code: "HU-BK", name: "Bács-Kiskun", kind: "County", parent: None },
+    SubdivisionData { code: "HU-BU", name: "Budapest", kind: "Capital city", parent: None },
+    SubdivisionData { code: "HU-BZ", name: "Borsod-Abaúj-Zemplén", kind: "County", parent: None },
+    SubdivisionData { code: "HU-CS", name: "Csongrád", kind: "County", parent: None },
+    SubdivisionData { code: "HU-DE", name: "Debrecen", kind: "City with county rights", parent: None },
+    SubdivisionData { code: "HU-DU", name: "Dunaújváros", kind: "City with county rights", parent: None },
+    SubdivisionData { code: "HU-EG", name: "Eger", kind: "City with county rights", parent: None },
+    SubdivisionData { code: "HU-ER", name: "Érd", kind: "City with county rights", parent: None },
+    SubdivisionData { code: "HU-FE", name: "Fejér", kind: "County", parent: None },
+    SubdivisionData { code: "HU-GS", name: "Győr-Moson-Sopron", kind: "County", parent: None },
+    SubdivisionData { code: "HU-GY", name: "Győr", kind: "City with county rights", parent: None },
+    SubdivisionData { code: "HU-HB", name: "Hajdú-Bihar", kind: "County", parent: None },
+    SubdivisionData { code: "HU-HE", name: "Heves", kind: "County", parent: None },
+    SubdivisionData { code: "HU-HV", name: "Hódmezővásárhely", kind: "City with county rights", parent: None },
+    SubdivisionData { code: "HU-JN", name: "Jász-Nagykun-Szolnok", kind: "County", parent: None },
+    SubdivisionData { code: "HU-KE", name: "Komárom-Esztergom", kind: "County", parent: None },
+    SubdivisionData { code: "HU-KM", name: "Kecskemét", kind: "City with county rights", parent: None },
+    SubdivisionData { code: "HU-KV", name: "Kaposvár", kind: "City with county rights", parent: None },
+    SubdivisionData { code: "HU-MI", name: "Miskolc", kind: "City with county rights", parent: None },
+    SubdivisionData { code: "HU-NK", name: "Nagykanizsa", kind: "City with county rights", parent: None },
+    SubdivisionData { code: "HU-NO", name: "Nógrád", kind: "County", parent: None },
+    SubdivisionData { code: "HU-NY", name: "Nyíregyháza", kind: "City with county rights", parent: None },
+    SubdivisionData { code: "HU-PE", name: "Pest", kind: "County", parent: None },
+    SubdivisionData { code: "HU-PS", name: "Pécs", kind: "City with county rights", parent: None },
+    SubdivisionData { code: "HU-SD", name: "Szeged", kind: "City with county rights", parent: None },
+    SubdivisionData { code: "HU-SF", name: "Székesfehérvár", kind: "City with county rights", parent: None },
+    SubdivisionData { code: "HU-SH", name: "Szombathely", kind: "City with county rights", parent: None },
+    SubdivisionData { code: "HU-SK", name: "Szolnok", kind: "City with county rights", parent: None },
+    SubdivisionData { code: "HU-SN", name: "Sopron", kind: "City with county rights", parent: None },
+    SubdivisionData { code: "HU-SO", name: "Somogy", kind: "County", parent: None },
+    SubdivisionData { code: "HU-SS", name: "Szekszárd", kind: "City with county rights", parent: None },
+    SubdivisionData { code: "HU-ST", name: "Salgótarján", kind: "City with county rights", parent: None },
+    SubdivisionData { code: "HU-SZ", name: "Szabolcs-Szatmár-Bereg", kind: "County", parent: None },
+    SubdivisionData { code: "HU-TB", name: "Tatabánya", kind: "City with county rights", parent: None },
+    SubdivisionData { code: "HU-TO", name: "Tolna", kind: "County", parent: None },
+    SubdivisionData { code: "HU-VA", name: "Vas", kind: "County", parent: None },
+    SubdivisionData { code: "HU-VE", name: "Veszprém", kind: "County", parent: None },
+    SubdivisionData { code: "HU-VM", name: "Veszprém", kind: "City with county rights", parent: None },
+    SubdivisionData { code: "HU-ZA", name: "Zala", kind: "County", parent: None },
+    SubdivisionData { code: "HU-ZE", name: "Zalaegerszeg", kind: "City with county rights", parent: None },
+    SubdivisionData { code: "ID-AC", name: "Aceh", kind: "Province", parent: Some("ID-SM") },
+    SubdivisionData { code: "ID-BA", name: "Bali", kind: "Province", parent: Some("ID-NU") },
+    SubdivisionData { code: "ID-BB", name: "Kepulauan Bangka Belitung", kind: "Province", parent: Some("ID-SM") },
+    SubdivisionData { code: "ID-BE", name: "Bengkulu", kind: "Province", parent: Some("ID-SM") },
+    SubdivisionData { code: "ID-BT", name: "Banten", kind: "Province", parent: Some("ID-JW") },
+    SubdivisionData { code: "ID-GO", name: "Gorontalo", kind: "Province", parent: Some("ID-SL") },
+    SubdivisionData { code: "ID-JA", name: "Jambi", kind: "Province", parent: Some("ID-SM") },
+    SubdivisionData { code: "ID-JB", name: "Jawa Barat", kind: "Province", parent: Some("ID-JW") },
+    SubdivisionData { code: "ID-JI", name: "Jawa Timur", kind: "Province", parent: Some("ID-JW") },
+    SubdivisionData { code: "ID-JK", name: "Jakarta Raya", kind: "Capital district", parent: Some("ID-JW") },
+    SubdivisionData { code: "ID-JT", name: "Jawa Tengah", kind: "Province", parent: Some("ID-JW") },
+    SubdivisionData { code: "ID-JW", name: "Jawa", kind: "Geographical unit", parent: None },
+    SubdivisionData { code: "ID-KA", name: "Kalimantan", kind: "Geographical unit", parent: None },
+    SubdivisionData { code: "ID-KB", name: "Kalimantan Barat", kind: "Province", parent: Some("ID-KA") },
+    SubdivisionData { code: "ID-KI", name: "Kalimantan Timur", kind: "Province", parent: Some("ID-KA") },
+    SubdivisionData { code: "ID-KR", name: "Kepulauan Riau", kind: "Province", parent: Some("ID-SM") },
+    SubdivisionData { code: "ID-KS", name: "Kalimantan Selatan", kind: "Province", parent: Some("ID-KA") },
+    SubdivisionData { code: "ID-KT", name: "Kalimantan Tengah", kind: "Province", parent: Some("ID-KA") },
+    SubdivisionData { code: "ID-KU", name: "Kalimantan Utara", kind: "Province", parent: Some("ID-KA") },
+    SubdivisionData { code: "ID-LA", name: "Lampung", kind: "Province", parent: Some("ID-SM") },
+    SubdivisionData { code: "ID-MA", name: "Maluku", kind: "Province", parent: Some("ID-ML") },
+    SubdivisionData { code: "ID-ML", name: "Maluku", kind: "Geographical unit", parent: None },
+    SubdivisionData { code: "ID-MU", name: "Maluku Utara", kind: "Province", parent: Some("ID-ML") },
+    SubdivisionData { code: "ID-NB", name: "Nusa Tenggara Barat", kind: "Province", parent: Some("ID-NU") },
+    SubdivisionData { code: "ID-NT", name: "Nusa Tenggara Timur", kind: "Province", parent: Some("ID-NU") },
+    SubdivisionData { code: "ID-NU", name: "Nusa Tenggara", kind: "Geographical unit", parent: None },
+    SubdivisionData { code: "ID-PA", name: "Papua", kind: "Province", parent: Some("ID-PP") },
+    SubdivisionData { code: "ID-PB", name: "Papua Barat", kind: "Province", parent: Some("ID-PP") },
+    SubdivisionData { code: "ID-PP", name: "Papua", kind: "Geographical unit", parent: None },
+    SubdivisionData { code: "ID-RI", name: "Riau", kind: "Province", parent: Some("ID-SM") },
+    SubdivisionData { code: "ID-SA", name: "Sulawesi Utara", kind: "Province", parent: Some("ID-SL") },
+    SubdivisionData { code: "ID-SB", name: "Sumatera Barat", kind: "Province", parent: Some("ID-SM") },
+    SubdivisionData { code: "ID-SG", name: "Sulawesi Tenggara", kind: "Province", parent: Some("ID-SL") },
+    SubdivisionData { code: "ID-SL", name: "Sulawesi", kind: "Geographical unit", parent: None },
+    SubdivisionData { code: "ID-SM", name: "Sumatera", kind: "Geographical unit", parent: None },
+    SubdivisionData { code: "ID-SN", name: "Sulawesi Selatan", kind: "Province", parent: Some("ID-SL") },
+    SubdivisionData { code: "ID-SR", name: "Sulawesi Barat", kind: "Province", parent: Some("ID-SL") },
+    SubdivisionData { code: "ID-SS", name: "Sumatera Selatan", kind: "Province", parent: Some("ID-SM") },
+    SubdivisionData { code: "ID-ST", name: "Sulawesi Tengah", kind: "Province", parent: Some("ID-SL") },
+    SubdivisionData { code: "ID-SU", name: "Sumatera Utara", kind: "Province", parent: Some("ID-SM") },
+    SubdivisionData { code: "ID-YO", name: "Yogyakarta", kind: "Special region", parent: Some("ID-JW") },
+    SubdivisionData { code: "IE-C", name: "Connaught", kind: "Province", parent: None },
+    SubdivisionData { code: "IE-CE", name: "Clare", kind: "County", parent: Some("IE-M") },
+    SubdivisionData { code: "IE-CN", name: "Cavan", kind: "County", parent: Some("IE-U") },
+    SubdivisionData { code: "IE-CO", name: "Cork", kind: "County", parent: Some("IE-M") },
+    SubdivisionData { code: "IE-CW", name: "Carlow", kind: "County", parent: Some("IE-L") },
+    SubdivisionData { code: "IE-D", name: "Dublin", kind: "County", parent: Some("IE-L") },
+    SubdivisionData { code: "IE-DL", name: "Donegal", kind: "County", parent: Some("IE-U") },
+    SubdivisionData { code: "IE-G", name: "Galway", kind: "County", parent: Some("IE-C") },
+    SubdivisionData { code: "IE-KE", name: "Kildare", kind: "County", parent: Some("IE-L") },
+    SubdivisionData { code: "IE-KK", name: "Kilkenny", kind: "County", parent: Some("IE-L") },
+    SubdivisionData { code: "IE-KY", name: "Kerry", kind: "County", parent: Some("IE-M") },
+    SubdivisionData { code: "IE-L", name: "Leinster", kind: "Province", parent: None },
+    SubdivisionData { code: "IE-LD", name: "Longford", kind: "County", parent: Some("IE-L") },
+    SubdivisionData { code: "IE-LH", name: "Louth", kind: "County", parent: Some("IE-L") },
+    SubdivisionData { code: "IE-LK", name: "Limerick", kind: "County", parent: Some("IE-M") },
+    SubdivisionData { code: "IE-LM", name: "Leitrim", kind: "County", parent: Some("IE-C") },
+    SubdivisionData { code: "IE-LS", name: "Laois", kind: "County", parent: Some("IE-L") },
+    SubdivisionData { code: "IE-M", name: "Munster", kind: "Province", parent: None },
+    SubdivisionData { code: "IE-MH", name: "Meath", kind: "County", parent: Some("IE-L") },
+    SubdivisionData { code: "IE-MN", name: "Monaghan", kind: "County", parent: Some("IE-U") },
+    SubdivisionData { code: "IE-MO", name: "Mayo", kind: "County", parent: Some("IE-C") },
+    SubdivisionData { code: "IE-OY", name: "Offaly", kind: "County", parent: Some("IE-L") },
+    SubdivisionData { code: "IE-RN", name: "Roscommon", kind: "County", parent: Some("IE-C") },
+    SubdivisionData { code: "IE-SO", name: "Sligo", kind: "County", parent: Some("IE-C") },
+    SubdivisionData { code: "IE-TA", name: "Tipperary", kind: "County", parent: Some("IE-M") },
+    SubdivisionData { code: "IE-U", name: "Ulster", kind: "Province", parent: None },
+    SubdivisionData { code: "IE-WD", name: "Waterford", kind: "County", parent: Some("IE-M") },
+    SubdivisionData { code: "IE-WH", name: "Westmeath", kind: "County", parent: Some("IE-L") },
+    SubdivisionData { code: "IE-WW", name: "Wicklow", kind: "County", parent: Some("IE-L") },
+    SubdivisionData { code: "IE-WX", name: "Wexford", kind: "County", parent: Some("IE-L") },
+    SubdivisionData { code: "IL-D", name: "Al Janūbī", kind: "District", parent: None },
+    SubdivisionData { code: "IL-HA", name: "H̱efa", kind: "District", parent: None },
+    SubdivisionData { code: "IL-JM", name: "Al Quds", kind: "District", parent: None },
+    SubdivisionData { code: "IL-M", name: "Al Awsaţ", kind: "District", parent: None },
+    SubdivisionData { code: "IL-TA", name: "Tall Abīb", kind: "District", parent: None },
+    SubdivisionData { code: "IL-Z", name: "Ash Shamālī", kind: "District", parent: None },
+    SubdivisionData { code: "IN-AN", name: "Andaman and Nicobar Islands", kind: "Union territory", parent: None },
+    SubdivisionData { code: "IN-AP", name: "Andhra Pradesh", kind: "State", parent: None },
+    SubdivisionData { code: "IN-AR", name: "Arunāchal Pradesh", kind: "State", parent: None },
+    SubdivisionData { code: "IN-AS", name: "Assam", kind: "State", parent: None },
+    SubdivisionData { code: "IN-BR", name: "Bihār", kind: "State", parent: None },
+    SubdivisionData { code: "IN-CH", name: "Chandīgarh", kind: "Union territory", parent: None },
+    SubdivisionData { code: "IN-CT", name: "Chhattīsgarh", kind: "State", parent: None },
+    SubdivisionData { code: "IN-DH", name: "Dādra and Nagar Haveli and Damān and Diu", kind: "Union territory", parent: None },
+    SubdivisionData { code: "IN-DL", name: "Delhi", kind: "Union territory", parent: None },
+    SubdivisionData { code: "IN-GA", name: "Goa", kind: "State", parent: None },
+    SubdivisionData { code: "IN-GJ", name: "Gujarāt", kind: "State", parent: None },
+    SubdivisionData { code: "IN-HP", name: "Himāchal Pradesh", kind: "State", parent: None },
+    SubdivisionData { code: "IN-HR", name: "Haryāna", kind: "State", parent: None },
+    SubdivisionData { code: "IN-JH", name: "Jhārkhand", kind: "State", parent: None },
+    SubdivisionData { code: "IN-JK", name: "Jammu and Kashmīr", kind: "Union territory", parent: None },
+    SubdivisionData { code: "IN-KA", name: "Karnātaka", kind: "State", parent: None },
+    SubdivisionData { code: "IN-KL", name: "Kerala", kind: "State", parent: None },
+    SubdivisionData { code: "IN-LA", name: "Ladākh", kind: "Union territory", parent: None },
+    SubdivisionData { code: "IN-LD", name: "Lakshadweep", kind: "Union territory", parent: None },
+    SubdivisionData { code: "IN-MH", name: "Mahārāshtra", kind: "State", parent: None },
+    SubdivisionData { code: "IN-ML", name: "Meghālaya", kind: "State", parent: None },
+    SubdivisionData { code: "IN-MN", name: "Manipur", kind: "State", parent: None },
+    SubdivisionData { code: "IN-MP", name: "Madhya Pradesh", kind: "State", parent: None },
+    SubdivisionData { code: "IN-MZ", name: "Mizoram", kind: "State", parent: None },
+    SubdivisionData { code: "IN-NL", name: "Nāgāland", kind: "State", parent: None },
+    SubdivisionData { code: "IN-OR", name: "Odisha", kind: "State", parent: None },
+    SubdivisionData { code: "IN-PB", name: "Punjab", kind: "State", parent: None },
+    SubdivisionData { code: "IN-PY", name: "Puducherry", kind: "Union territory", parent: None },
+    SubdivisionData { code: "IN-RJ", name: "Rājasthān", kind: "State", parent: None },
+    SubdivisionData { code: "IN-SK", name: "Sikkim", kind: "State", parent: None },
+    SubdivisionData { code: "IN-TG", name: "Telangāna", kind: "State", parent: None },
+    SubdivisionData { code: "IN-TN", name: "Tamil Nādu", kind: "State", parent: None },
+    SubdivisionData { code: "IN-TR", name: "Tripura", kind: "State", parent: None },
+    SubdivisionData { code: "IN-UP", name: "Uttar Pradesh", kind: "State", parent: None },
+    SubdivisionData { code: "IN-UT", name: "Uttarākhand", kind: "State", parent: None },
+    SubdivisionData { code: "IN-WB", name: "West Bengal", kind: "State", parent: None },
+    SubdivisionData { code: "IQ-AN", name: "Al Anbār", kind: "Governorate", parent: None },
+    SubdivisionData { code: "IQ-AR", name: "Arbīl", kind: "Governorate", parent: None },
+    SubdivisionData { code: "IQ-BA", name: "Al Başrah", kind: "Governorate", parent: None },
+    SubdivisionData { code: "IQ-BB", name: "Bābil", kind: "Governorate", parent: None },
+    SubdivisionData { code: "IQ-BG", name: "Baghdād", kind: "Governorate", parent: None },
+    SubdivisionData { code: "IQ-DA", name: "Dahūk", kind: "Governorate", parent: None },
+    SubdivisionData { code: "IQ-DI", name: "Diyālá", kind: "Governorate", parent: None },
+    SubdivisionData { code: "IQ-DQ", name: "Dhī Qār", kind: "Governorate", parent: None },
+    SubdivisionData { code: "IQ-KA", name: "Karbalā’", kind: "Governorate", parent: None },
+    SubdivisionData { code: "IQ-KI", name: "Kirkūk", kind: "Governorate", parent: None },
+    SubdivisionData { code: "IQ-MA", name: "Maysān", kind: "Governorate", parent: None },
+    SubdivisionData { code: "IQ-MU", name: "Al Muthanná", kind: "Governorate", parent: None },
+    SubdivisionData { code: "IQ-NA", name: "An Najaf", kind: "Governorate", parent: None },
+    SubdivisionData { code: "IQ-NI", name: "Nīnawá", kind: "Governorate", parent: None },
+    SubdivisionData { code: "IQ-QA", name: "Al Qādisīyah", kind: "Governorate", parent: None },
+    SubdivisionData { code: "IQ-SD", name: "Şalāḩ ad Dīn", kind: "Governorate", parent: None },
+    SubdivisionData { code: "IQ-SU", name: "As Sulaymānīyah", kind: "Governorate", parent: None },
+    SubdivisionData { code: "IQ-WA", name: "Wāsiţ", kind: "Governorate", parent: None },
+    SubdivisionData { code: "IR-00", name: "Markazī", kind: "Province", parent: None },
+    SubdivisionData { code: "IR-01", name: "Gīlān", kind: "Province", parent: None },
+    SubdivisionData { code: "IR-02", name: "Māzandarān", kind: "Province", parent: None },
+    SubdivisionData { code: "IR-03", name: "Āz̄ārbāyjān-e Shārqī", kind: "Province", parent: None },
+    SubdivisionData { code: "IR-04", name: "Āz̄ārbāyjān-e Ghārbī", kind: "Province", parent: None },
+    SubdivisionData { code: "IR-05", name: "Kermānshāh", kind: "Province", parent: None },
+    SubdivisionData { code: "IR-06", name: "Khūzestān", kind: "Province", parent: None },
+    SubdivisionData { code: "IR-07", name: "Fārs", kind: "Province", parent: None },
+    SubdivisionData { code: "IR-08", name: "Kermān", kind: "Province", parent: None },
+    SubdivisionData { code: "IR-09", name: "Khorāsān-e Raẕavī", kind: "Province", parent: None },
+    SubdivisionData { code: "IR-10", name: "Eşfahān", kind: "Province", parent: None },
+    SubdivisionData { code: "IR-11", name: "Sīstān va Balūchestān", kind: "Province", parent: None },
+    SubdivisionData { code: "IR-12", name: "Kordestān", kind: "Province", parent: None },
+    SubdivisionData { code: "IR-13", name: "Hamadān", kind: "Province", parent: None },
+    SubdivisionData { code: "IR-14", name: "Chahār Maḩāl va Bakhtīārī", kind: "Province", parent: None },
+    SubdivisionData { code: "IR-15", name: "Lorestān", kind: "Province", parent: None },
+    SubdivisionData { code: "IR-16", name: "Īlām", kind: "Province", parent: None },
+    SubdivisionData { code: "IR-17", name: "Kohgīlūyeh va Bowyer Aḩmad", kind: "Province", parent: None },
+    SubdivisionData { code: "IR-18", name: "Būshehr", kind: "Province", parent: None },
+    SubdivisionData { code: "IR-19", name: "Zanjān", kind: "Province", parent: None },
+    SubdivisionData { code: "IR-20", name: "Semnān", kind: "Province", parent: None },
+    SubdivisionData { code: "IR-21", name: "Yazd", kind: "Province", parent: None },
+    SubdivisionData { code: "IR-22", name: "Hormozgān", kind: "Province", parent: None },
+    SubdivisionData { code: "IR-23", name: "Tehrān", kind: "Province", parent: None },
+    SubdivisionData { code: "IR-24", name: "Ardabīl", kind: "Province", parent: None },
+    SubdivisionData { code: "IR-25", name: "Qom", kind: "Province", parent: None },
+    SubdivisionData { code: "IR-26", name: "Qazvīn", kind: "Province", parent: None },
+    SubdivisionData { code: "IR-27", name: "Golestān", kind: "Province", parent: None },
+    SubdivisionData { code: "IR-28", name: "Khorāsān-e Shomālī", kind: "Province", parent: None },
+    SubdivisionData { code: "IR-29", name: "Khorāsān-e Jonūbī", kind: "Province", parent: None },
+    SubdivisionData { code: "IR-30", name: "Alborz", kind: "Province", parent: None },
+    SubdivisionData { code: "IS-1", name: "Höfuðborgarsvæði", kind: "Region", parent: None },
+    SubdivisionData { code: "IS-2", name: "Suðurnes", kind: "Region", parent: None },
+    SubdivisionData { code: "IS-3", name: "Vesturland", kind: "Region", parent: None },
+    SubdivisionData { code: "IS-4", name: "Vestfirðir", kind: "Region", parent: None },
+    SubdivisionData { code: "IS-5", name: "Norðurland vestra", kind: "Region", parent: None },
+    SubdivisionData { code: "IS-6", name: "Norðurland eystra", kind: "Region", parent: None },
+    SubdivisionData { code: "IS-7", name: "Austurland", kind: "Region", parent: None },
+    SubdivisionData { code: "IS-8", name: "Suðurland", kind: "Region", parent: None },
+    SubdivisionData { code: "IS-AKH", name: "Akrahreppur", kind: "Municipality", parent: Some("IS-5") },
+    SubdivisionData { code: "IS-AKN", name: "Akraneskaupstaður", kind: "Municipality", parent: Some("IS-3") },
+    SubdivisionData { code: "IS-AKU", name: "Akureyrarbær", kind: "Municipality", parent: Some("IS-6") },
+    SubdivisionData { code: "IS-ARN", name: "Árneshreppur", kind: "Municipality", parent: Some("IS-4") },
+    SubdivisionData { code: "IS-ASA", name: "Ásahreppur", kind: "Municipality", parent: Some("IS-8") },
+    SubdivisionData { code: "IS-BFJ", name: "Borgarfjarðarhreppur", kind: "Municipality", parent: Some("IS-7") },
+    SubdivisionData { code: "IS-BLA", name: "Bláskógabyggð", kind: "Municipality", parent: Some("IS-8") },
+    SubdivisionData { code: "IS-BLO", name: "Blönduósbær", kind: "Municipality", parent: Some("IS-5") },
+    SubdivisionData { code: "IS-BOG", name: "Borgarbyggð", kind: "Municipality", parent: Some("IS-3") },
+    SubdivisionData { code: "IS-BOL", name: "Bolungarvíkurkaupstaður", kind: "Municipality", parent: Some("IS-4") },
+    SubdivisionData { code: "IS-DAB", name: "Dalabyggð", kind: "Municipality", parent: Some("IS-3") },
+    SubdivisionData { code: "IS-DAV", name: "Dalvíkurbyggð", kind: "Municipality", parent: Some("IS-6") },
+    SubdivisionData { code: "IS-DJU", name: "Djúpavogshreppur", kind: "Municipality", parent: Some("IS-7") },
+    SubdivisionData { code: "IS-EOM", name: "Eyja- og Miklaholtshreppur", kind: "Municipality", parent: Some("IS-3") },
+    SubdivisionData { code: "IS-EYF", name: "Eyjafjarðarsveit", kind: "Municipality", parent: Some("IS-6") },
+    SubdivisionData { code: "IS-FJD", name: "Fjarðabyggð", kind: "Municipality", parent: Some("IS-7") },
+    SubdivisionData { code: "IS-FJL", name: "Fjallabyggð", kind: "Municipality", parent: Some("IS-6") },
+    SubdivisionData { code: "IS-FLA", name: "Flóahreppur", kind: "Municipality", parent: Some("IS-8") },
+    SubdivisionData { code: "IS-FLD", name: "Fljótsdalshérað", kind: "Municipality", parent: Some("IS-7") },
+    SubdivisionData { code: "IS-FLR", name: "Fljótsdalshreppur", kind: "Municipality", parent: Some("IS-7") },
+    SubdivisionData { code: "IS-GAR", name: "Garðabær", kind: "Municipality", parent: Some("IS-1") },
+    SubdivisionData { code: "IS-GOG", name: "Grímsnes- og Grafningshreppur", kind: "Municipality", parent: Some("IS-8") },
+    SubdivisionData { code: "IS-GRN", name: "Grindavíkurbær", kind: "Municipality", parent: Some("IS-2") },
+    SubdivisionData { code: "IS-GRU", name: "Grundarfjarðarbær", kind: "Municipality", parent: Some("IS-3") },
+    SubdivisionData { code: "IS-GRY", name: "Grýtubakkahreppur", kind: "Municipality", parent: Some("IS-6") },
+    SubdivisionData { code: "IS-HAF", name: "Hafnarfjarðarkaupstaður", kind: "Municipality", parent: Some("IS-1") },
+    SubdivisionData { code: "IS-HEL", name: "Helgafellssveit", kind: "Municipality", parent: Some("IS-3") },
+    SubdivisionData { code: "IS-HRG", name: "Hörgársveit", kind: "Municipality", parent: Some("IS-6") },
+    SubdivisionData { code: "IS-HRU", name: "Hrunamannahreppur", kind: "Municipality", parent: Some("IS-8") },
+    SubdivisionData { code: "IS-HUT", name: "Húnavatnshreppur", kind: "Municipality", parent: Some("IS-5") },
+    SubdivisionData { code: "IS-HUV", name: "Húnaþing vestra", kind: "Municipality", parent: Some("IS-5") },
+    SubdivisionData { code: "IS-HVA", name: "Hvalfjarðarsveit", kind: "Municipality", parent: Some("IS-3") },
+    SubdivisionData { code: "IS-HVE", name: "Hveragerðisbær", kind: "Municipality", parent: Some("IS-8") },
+    SubdivisionData { code: "IS-ISA", name: "Ísafjarðarbær", kind: "Municipality", parent: Some("IS-4") },
+    SubdivisionData { code: "IS-KAL", name: "Kaldrananeshreppur", kind: "Municipality", parent: Some("IS-4") },
+    SubdivisionData { code: "IS-KJO", name: "Kjósarhreppur", kind: "Municipality", parent: Some("IS-1") },
+    SubdivisionData { code: "IS-KOP", name: "Kópavogsbær", kind: "Municipality", parent: Some("IS-1") },
+    SubdivisionData { code: "IS-LAN", name: "Langanesbyggð", kind: "Municipality", parent: Some("IS-6") },
+    SubdivisionData { code: "IS-MOS", name: "Mosfellsbær", kind: "Municipality", parent: Some("IS-1") },
+    SubdivisionData { code: "IS-MYR", name: "Mýrdalshreppur", kind: "Municipality", parent: Some("IS-8") },
+    SubdivisionData { code: "IS-NOR", name: "Norðurþing", kind: "Municipality", parent: Some("IS-6") },
+    SubdivisionData { code: "IS-RGE", name: "Rangárþing eystra", kind: "Municipality", parent: Some("IS-8") },
+    SubdivisionData { code: "IS-RGY", name: "Rangárþing ytra", kind: "Municipality", parent: Some("IS-8") },
+    SubdivisionData { code: "IS-RHH", name: "Reykhólahreppur", kind: "Municipality", parent: Some("IS-4") },
+    SubdivisionData { code: "IS-RKN", name: "Reykjanesbær", kind: "Municipality", parent: Some("IS-2") },
+    SubdivisionData { code: "IS-RKV", name: "Reykjavíkurborg", kind: "Municipality", parent: Some("IS-1") },
+    SubdivisionData { code: "IS-SBH", name: "Svalbarðshreppur", kind: "Municipality", parent: Some("IS-6") },
+    SubdivisionData { code: "IS-SBT", name: "Svalbarðsstrandarhreppur", kind: "Municipality", parent: Some("IS-6") },
+    SubdivisionData { code: "IS-SDN", name: "Suðurnesjabær", kind: "Municipality", parent: Some("IS-2") },
+    SubdivisionData { code: "IS-SDV", name: "Súðavíkurhreppur", kind: "Municipality", parent: Some("IS-4") },
+    SubdivisionData { code: "IS-SEL", name: "Seltjarnarnesbær", kind: "Municipality", parent: Some("IS-1") },
+    SubdivisionData { code: "IS-SEY", name: "Seyðisfjarðarkaupstaður", kind: "Municipality", parent: Some("IS-7") },
+    SubdivisionData { code: "IS-SFA", name: "Sveitarfélagið Árborg", kind: "Municipality", parent: Some("IS-8") },
+    SubdivisionData { code: "IS-SHF", name: "Sveitarfélagið Hornafjörður", kind: "Municipality", parent: Some("IS-7") },
+    SubdivisionData { code: "IS-SKF", name: "Skaftárhreppur", kind: "Municipality", parent: Some("IS-8") },
+    SubdivisionData { code: "IS-SKG", name: "Skagabyggð", kind: "Municipality", parent: Some("IS-5") },
+    SubdivisionData { code: "IS-SKO", name: "Skorradalshreppur", kind: "Municipality", parent: Some("IS-3") },
+    SubdivisionData { code: "IS-SKU", name: "Skútustaðahreppur", kind: "Municipality", parent: Some("IS-6") },
+    SubdivisionData { code: "IS-SNF", name: "Snæfellsbær", kind: "Municipality", parent: Some("IS-3") },
+    SubdivisionData { code: "IS-SOG", name: "Skeiða- og Gnúpverjahreppur", kind: "Municipality", parent: Some("IS-8") },
+    SubdivisionData { code: "IS-SOL", name: "Sveitarfélagið Ölfus", kind: "Municipality", parent: Some("IS-8") },
+    SubdivisionData { code: "IS-SSF", name: "Sveitarfélagið Skagafjörður", kind: "Municipality", parent: Some("IS-5") },
+    SubdivisionData { code: "IS-SSS", name: "Sveitarfélagið Skagaströnd", kind: "Municipality", parent: Some("IS-5") },
+    SubdivisionData { code: "IS-STR", name: "Strandabyggð", kind: "Municipality", parent: Some("IS-4") },
+    SubdivisionData { code: "IS-STY", name: "Stykkishólmsbær", kind: "Municipality", parent: Some("IS-3") },
+    SubdivisionData { code: "IS-SVG", name: "Sveitarfélagið Vogar", kind: "Municipality", parent: Some("IS-2") },
+    SubdivisionData { code: "IS-TAL", name: "Tálknafjarðarhreppur", kind: "Municipality", parent: Some("IS-4") },
+    SubdivisionData { code: "IS-THG", name: "Þingeyjarsveit", kind: "Municipality", parent: Some("IS-6") },
+    SubdivisionData { code: "IS-TJO", name: "Tjörneshreppur", kind: "Municipality", parent: Some("IS-6") },
+    SubdivisionData { code: "IS-VEM", name: "Vestmannaeyjabær", kind: "Municipality", parent: Some("IS-8") },
+    SubdivisionData { code: "IS-VER", name: "Vesturbyggð", kind: "Municipality", parent: Some("IS-4") },
+    SubdivisionData { code: "IS-VOP", name: "Vopnafjarðarhreppur", kind: "Municipality", parent: Some("IS-7") },
+    SubdivisionData { code: "IT-21", name: "Piemonte", kind: "Region", parent: None },
+    SubdivisionData { code: "IT-23", name: "Val d'Aoste", kind: "Autonomous region", parent: None },
+    SubdivisionData { code: "IT-25", name: "Lombardia", kind: "Region", parent: None },
+    SubdivisionData { code: "IT-32", name: "Trentino-Alto Adige", kind: "Autonomous region", parent: None },
+    SubdivisionData { code: "IT-34", name: "Veneto", kind: "Region", parent: None },
+    SubdivisionData { code: "IT-36", name: "Friuli Venezia Giulia", kind: "Autonomous region", parent: None },
+    SubdivisionData { code: "IT-42", name: "Liguria", kind: "Region", parent: None },
+    SubdivisionData { code: "IT-45", name: "Emilia-Romagna", kind: "Region", parent: None },
+    SubdivisionData { code: "IT-52", name: "Toscana", kind: "Region", parent: None },
+    SubdivisionData { code: "IT-55", name: "Umbria", kind: "Region", parent: None },
+    SubdivisionData { code: "IT-57", name: "Marche", kind: "Region", parent: None },
+    SubdivisionData { code: "IT-62", name: "Lazio", kind: "Region", parent: None },
+    SubdivisionData { code: "IT-65", name: "Abruzzo", kind: "Region", parent: None },
+    SubdivisionData { code: "IT-67", name: "Molise", kind: "Region", parent: None },
+    SubdivisionData { code: "IT-72", name: "Campania", kind: "Region", parent: None },
+    SubdivisionData { code: "IT-75", name: "Puglia", kind: "Region", parent: None },
+    SubdivisionData { code: "IT-77", name: "Basilicata", kind: "Region", parent: None },
+    SubdivisionData { code: "IT-78", name: "Calabria", kind: "Region", parent: None },
+    SubdivisionData { code: "IT-82", name: "Sicilia", kind: "Autonomous region", parent: None },
+    SubdivisionData { code: "IT-88", name: "Sardegna", kind: "Autonomous region", parent: None },
+    SubdivisionData { code: "IT-AG", name: "Agrigento", kind: "Free municipal consortium", parent: Some("IT-82") },
+    SubdivisionData { code: "IT-AL", name: "Alessandria", kind: "Province", parent: Some("IT-21") },
+    SubdivisionData { code: "IT-AN", name: "Ancona", kind: "Province", parent: Some("IT-57") },
+    SubdivisionData { code: "IT-AP", name: "Ascoli Piceno", kind: "Province", parent: Some("IT-57") },
+    SubdivisionData { code: "IT-AQ", name: "L'Aquila", kind: "Province", parent: Some("IT-65") },
+    SubdivisionData { code: "IT-AR", name: "Arezzo", kind: "Province", parent: Some("IT-52") },
+    SubdivisionData { code: "IT-AT", name: "Asti", kind: "Province", parent: Some("IT-21") },
+    SubdivisionData { code: "IT-AV", name: "Avellino", kind: "Province", parent: Some("IT-72") },
+    SubdivisionData { code: "IT-BA", name: "Bari", kind: "Metropolitan city", parent: Some("IT-75") },
+    SubdivisionData { code: "IT-BG", name: "Bergamo", kind: "Province", parent: Some("IT-25") },
+    SubdivisionData { code: "IT-BI", name: "Biella", kind: "Province", parent: Some("IT-21") },
+    SubdivisionData { code: "IT-BL", name: "Belluno", kind: "Province", parent: Some("IT-34") },
+    SubdivisionData { code: "IT-BN", name: "Benevento", kind: "Province", parent: Some("IT-72") },
+    SubdivisionData { code: "IT-BO", name: "Bologna", kind: "Metropolitan city", parent: Some("IT-45") },
+    SubdivisionData { code: "IT-BR", name: "Brindisi", kind: "Province", parent: Some("IT-75") },
+    SubdivisionData { code: "IT-BS", name: "Brescia", kind: "Province", parent: Some("IT-25") },
+    SubdivisionData { code: "IT-BT", name: "Barletta-Andria-Trani", kind: "Province", parent: Some("IT-75") },
+    SubdivisionData { code: "IT-BZ", name: "Bolzano", kind: "Autonomous province", parent: Some("IT-32") },
+    SubdivisionData { code: "IT-CA", name: "Cagliari", kind: "Metropolitan city", parent: Some("IT-88") },
+    SubdivisionData { code: "IT-CB", name: "Campobasso", kind: "Province", parent: Some("IT-67") },
+    SubdivisionData { code: "IT-CE", name: "Caserta", kind: "Province", parent: Some("IT-72") },
+    SubdivisionData { code: "IT-CH", name: "Chieti", kind: "Province", parent: Some("IT-65") },
+    SubdivisionData { code: "IT-CL", name: "Caltanissetta", kind: "Free municipal consortium", parent: Some("IT-82") },
+    SubdivisionData { code: "IT-CN", name: "Cuneo", kind: "Province", parent: Some("IT-21") },
+    SubdivisionData { code: "IT-CO", name: "Como", kind: "Province", parent: Some("IT-25") },
+    SubdivisionData { code: "IT-CR", name: "Cremona", kind: "Province", parent: Some("IT-25") },
+    SubdivisionData { code: "IT-CS", name: "Cosenza", kind: "Province", parent: Some("IT-78") },
+    SubdivisionData { code: "IT-CT", name: "Catania", kind: "Metropolitan city", parent: Some("IT-82") },
+    SubdivisionData { code: "IT-CZ", name: "Catanzaro", kind: "Province", parent: Some("IT-78") },
+    SubdivisionData { code: "IT-EN", name: "Enna", kind: "Free municipal consortium", parent: Some("IT-82") },
+    SubdivisionData { code: "IT-FC", name: "Forlì-Cesena", kind: "Province", parent: Some("IT-45") },
+    SubdivisionData { code: "IT-FE", name: "Ferrara", kind: "Province", parent: Some("IT-45") },
+    SubdivisionData { code: "IT-FG", name: "Foggia", kind: "Province", parent: Some("IT-75") },
+    SubdivisionData { code: "IT-FI", name: "Firenze", kind: "Metropolitan city", parent: Some("IT-52") },
+    SubdivisionData { code: "IT-FM", name: "Fermo", kind: "Province", parent: Some("IT-57") },
+    SubdivisionData { code: "IT-FR", name: "Frosinone", kind: "Province", parent: Some("IT-62") },
+    SubdivisionData { code: "IT-GE", name: "Genova", kind: "Metropolitan city", parent: Some("IT-42") },
+    SubdivisionData { code: "IT-GO", name: "Gorizia", kind: "Decentralized regional entity", parent: Some("IT-36") },
+    SubdivisionData { code: "IT-GR", name: "Grosseto", kind: "Province", parent: Some("IT-52") },
+    SubdivisionData { code: "IT-IM", name: "Imperia", kind: "Province", parent: Some("IT-42") },
+    SubdivisionData { code: "IT-IS", name: "Isernia", kind: "Province", parent: Some("IT-67") },
+    SubdivisionData { code: "IT-KR", name: "Crotone", kind: "Province", parent: Some("IT-78") },
+    SubdivisionData { code: "IT-LC", name: "Lecco", kind: "Province", parent: Some("IT-25") },
+    SubdivisionData { code: "IT-LE", name: "Lecce", kind: "Province", parent: Some("IT-75") },
+    SubdivisionData { code: "IT-LI", name: "Livorno", kind: "Province", parent: Some("IT-52") },
+    SubdivisionData { code: "IT-LO", name: "Lodi", kind: "Province", parent: Some("IT-25") },
+    SubdivisionData { code: "IT-LT", name: "Latina", kind: "Province", parent: Some("IT-62") },
+    SubdivisionData { code: "IT-LU", name: "Lucca", kind: "Province", parent: Some("IT-52") },
+    SubdivisionData { code: "IT-MB", name: "Monza e Brianza", kind: "Province", parent: Some("IT-25") },
+    SubdivisionData { code: "IT-MC", name: "Macerata", kind: "Province", parent: Some("IT-57") },
+    SubdivisionData { code: "IT-ME", name: "Messina", kind: "Metropolitan city", parent: Some("IT-82") },
+    SubdivisionData { code: "IT-MI", name: "Milano", kind: "Metropolitan city", parent: Some("IT-25") },
+    SubdivisionData { code: "IT-MN", name: "Mantova", kind: "Province", parent: Some("IT-25") },
+    SubdivisionData { code: "IT-MO", name: "Modena", kind: "Province", parent: Some("IT-45") },
+    SubdivisionData { code: "IT-MS", name: "Massa-Carrara", kind: "Province", parent: Some("IT-52") },
+    SubdivisionData { code: "IT-MT", name: "Matera", kind: "Province", parent: Some("IT-77") },
+    SubdivisionData { code: "IT-NA", name: "Napoli", kind: "Metropolitan city", parent: Some("IT-72") },
+    SubdivisionData { code: "IT-NO", name: "Novara", kind: "Province", parent: Some("IT-21") },
+    SubdivisionData { code: "IT-NU", name: "Nuoro", kind: "Province", parent: Some("IT-88") },
+    SubdivisionData { code: "IT-OR", name: "Oristano", kind: "Province", parent: Some("IT-88") },
+    SubdivisionData { code: "IT-PA", name: "Palermo", kind: "Metropolitan city", parent: Some("IT-82") },
+    SubdivisionData { code: "IT-PC", name: "Piacenza", kind: "Province", parent: Some("IT-45") },
+    SubdivisionData { code: "IT-PD", name: "Padova", kind: "Province", parent: Some("IT-34") },
+    SubdivisionData { code: "IT-PE", name: "Pescara", kind: "Province", parent: Some("IT-65") },
+    SubdivisionData { code: "IT-PG", name: "Perugia", kind: "Province", parent: Some("IT-55") },
+    SubdivisionData { code: "IT-PI", name: "Pisa", kind: "Province", parent: Some("IT-52") },
+    SubdivisionData { code: "IT-PN", name: "Pordenone", kind: "Decentralized regional entity", parent: Some("IT-36") },
+    SubdivisionData { code: "IT-PO", name: "Prato", kind: "Province", parent: Some("IT-52") },
+    SubdivisionData { code: "IT-PR", name: "Parma", kind: "Province", parent: Some("IT-45") },
+    SubdivisionData { code: "IT-PT", name: "Pistoia", kind: "Province", parent: Some("IT-52") },
+    SubdivisionData { code: "IT-PU", name: "Pesaro e Urbino", kind: "Province", parent: Some("IT-57") },
+    SubdivisionData { code: "IT-PV", name: "Pavia", kind: "Province", parent: Some("IT-25") },
+    SubdivisionData { code: "IT-PZ", name: "Potenza", kind: "Province", parent: Some("IT-77") },
+    SubdivisionData { code: "IT-RA", name: "Ravenna", kind: "Province", parent: Some("IT-45") },
+    SubdivisionData { code: "IT-RC", name: "Reggio Calabria", kind: "Metropolitan city", parent: Some("IT-78") },
+    SubdivisionData { code: "IT-RE", name: "Reggio Emilia", kind: "Province", parent: Some("IT-45") },
+    SubdivisionData { code: "IT-RG", name: "Ragusa", kind: "Free municipal consortium", parent: Some("IT-82") },
+    SubdivisionData { code: "IT-RI", name: "Rieti", kind: "Province", parent: Some("IT-62") },
+    SubdivisionData { code: "IT-RM", name: "Roma", kind: "Metropolitan city", parent: Some("IT-62") },
+    SubdivisionData { code: "IT-RN", name: "Rimini", kind: "Province", parent: Some("IT-45") },
+    SubdivisionData { code: "IT-RO", name: "Rovigo", kind: "Province", parent: Some("IT-34") },
+    SubdivisionData { code: "IT-SA", name: "Salerno", kind: "Province", parent: Some("IT-72") },
+    SubdivisionData { code: "IT-SI", name: "Siena", kind: "Province", parent: Some("IT-52") },
+    SubdivisionData { code: "IT-SO", name: "Sondrio", kind: "Province", parent: Some("IT-25") },
+    SubdivisionData { code: "IT-SP", name: "La Spezia", kind: "Province", parent: Some("IT-42") },
+    SubdivisionData { code: "IT-SR", name: "Siracusa", kind: "Free municipal consortium", parent: Some("IT-82") },
+    SubdivisionData { code: "IT-SS", name: "Sassari", kind: "Province", parent: Some("IT-88") },
+    SubdivisionData { code: "IT-SU", name: "Sud Sardegna", kind: "Province", parent: Some("IT-88") },
+    SubdivisionData { code: "IT-SV", name: "Savona", kind: "Province", parent: Some("IT-42") },
+    SubdivisionData { code: "IT-TA", name: "Taranto", kind: "Province", parent: Some("IT-75") },
+    SubdivisionData { code: "IT-TE", name: "Teramo", kind: "Province", parent: Some("IT-65") },
+    SubdivisionData { code: "IT-TN", name: "Trento", kind: "Autonomous province", parent: Some("IT-32") },
+    SubdivisionData { code: "IT-TO", name: "Torino", kind: "Metropolitan city", parent: Some("IT-21") },
+    SubdivisionData { code: "IT-TP", name: "Trapani", kind: "Free municipal consortium", parent: Some("IT-82") },
+    SubdivisionData { code: "IT-TR", name: "Terni", kind: "Province", parent: Some("IT-55") },
+    SubdivisionData { code: "IT-TS", name: "Trieste", kind: "Decentralized regional entity", parent: Some("IT-36") },
+    SubdivisionData { code: "IT-TV", name: "Treviso", kind: "Province", parent: Some("IT-34") },
+    SubdivisionData { code: "IT-UD", name: "Udine", kind: "Decentralized regional entity", parent: Some("IT-36") },
+    SubdivisionData { code: "IT-VA", name: "Varese", kind: "Province", parent: Some("IT-25") },
+    SubdivisionData { code: "IT-VB", name: "Verbano-Cusio-Ossola", kind: "Province", parent: Some("IT-21") },
+    SubdivisionData { code: "IT-VC", name: "Vercelli", kind: "Province", parent: Some("IT-21") },
+    SubdivisionData { code: "IT-VE", name: "Venezia", kind: "Metropolitan city", parent: Some("IT-34") },
+    SubdivisionData { code: "IT-VI", name: "Vicenza", kind: "Province", parent: Some("IT-34") },
+    SubdivisionData { code: "IT-VR", name: "Verona", kind: "Province", parent: Some("IT-34") },
+    SubdivisionData { code: "IT-VT", name: "Viterbo", kind: "Province", parent: Some("IT-62") },
+    SubdivisionData { code: "IT-VV", name: "Vibo Valentia", kind: "Province", parent: Some("IT-78") },
+    SubdivisionData { code: "JM-01", name: "Kingston", kind: "Parish", parent: None },
+    SubdivisionData { code: "JM-02", name: "Saint Andrew", kind: "Parish", parent: None },
+    SubdivisionData { code: "JM-03", name: "Saint Thomas", kind: "Parish", parent: None },
+    SubdivisionData { code: "JM-04", name: "Portland", kind: "Parish", parent: None },
+    SubdivisionData { code: "JM-05", name: "Saint Mary", kind: "Parish", parent: None },
+    SubdivisionData { code: "JM-06", name: "Saint Ann", kind: "Parish", parent: None },
+    SubdivisionData { code: "JM-07", name: "Trelawny", kind: "Parish", parent: None },
+    SubdivisionData { code: "JM-08", name: "Saint James", kind: "Parish", parent: None },
+    SubdivisionData { code: "JM-09", name: "Hanover", kind: "Parish", parent: None },
+    SubdivisionData { code: "JM-10", name: "Westmoreland", kind: "Parish", parent: None },
+    SubdivisionData { code: "JM-11", name: "Saint Elizabeth", kind: "Parish", parent: None },
+    SubdivisionData { code: "JM-12", name: "Manchester", kind: "Parish", parent: None },
+    SubdivisionData { code: "JM-13", name: "Clarendon", kind: "Parish", parent: None },
+    SubdivisionData { code: "JM-14", name: "Saint Catherine", kind: "Parish", parent: None },
+    SubdivisionData { code: "JO-AJ", name: "‘Ajlūn", kind: "Governorate", parent: None },
+    SubdivisionData { code: "JO-AM", name: "Al ‘A̅şimah", kind: "Governorate", parent: None },
+    SubdivisionData { code: "JO-AQ", name: "Al ‘Aqabah", kind: "Governorate", parent: None },
+    SubdivisionData { code: "JO-AT", name: "Aţ Ţafīlah", kind: "Governorate", parent: None },
+    SubdivisionData { code: "JO-AZ", name: "Az Zarqā’", kind: "Governorate", parent: None },
+    SubdivisionData { code: "JO-BA", name: "Al Balqā’", kind: "Governorate", parent: None },
+    SubdivisionData { code: "JO-IR", name: "Irbid", kind: "Governorate", parent: None },
+    SubdivisionData { code: "JO-JA", name: "Jarash", kind: "Governorate", parent: None },
+    SubdivisionData { code: "JO-KA", name: "Al Karak", kind: "Governorate", parent: None },
+    SubdivisionData { code: "JO-MA", name: "Al Mafraq", kind: "Governorate", parent: None },
+    SubdivisionData { code: "JO-MD", name: "Mādabā", kind: "Governorate", parent: None },
+    SubdivisionData { code: "JO-MN", name: "Ma‘ān", kind: "Governorate", parent: None },
+    SubdivisionData { code: "JP-01", name: "Hokkaido", kind: "Prefecture", parent: None },
+    SubdivisionData { code: "JP-02", name: "Aomori", kind: "Prefecture", parent: None },
+    SubdivisionData { code: "JP-03", name: "Iwate", kind: "Prefecture", parent: None },
+    SubdivisionData { code: "JP-04", name: "Miyagi", kind: "Prefecture", parent: None },
+    SubdivisionData { code: "JP-05", name: "Akita", kind: "Prefecture", parent: None },
+    SubdivisionData { code: "JP-06", name: "Yamagata", kind: "Prefecture", parent: None },
+    SubdivisionData { code: "JP-07", name: "Fukushima", kind: "Prefecture", parent: None },
+    SubdivisionData { code: "JP-08", name: "Ibaraki", kind: "Prefecture", parent: None },
+    SubdivisionData { code: "JP-09", name: "Tochigi", kind: "Prefecture", parent: None },
+    SubdivisionData { code: "JP-10", name: "Gunma", kind: "Prefecture", parent: None },
+    SubdivisionData { code: "JP-11", name: "Saitama", kind: "Prefecture", parent: None },
+    SubdivisionData { code: "JP-12", name: "Chiba", kind: "Prefecture", parent: None },
+    SubdivisionData { code: "JP-13", name: "Tokyo", kind: "Prefecture", parent: None },
+    SubdivisionData { code: "JP-14", name: "Kanagawa", kind: "Prefecture", parent: None },
+    SubdivisionData { code: "JP-15", name: "Niigata", kind: "Prefecture", parent: None },
+    SubdivisionData { code: "JP-16", name: "Toyama", kind: "Prefecture", parent: None },
+    SubdivisionData { code: "JP-17", name: "Ishikawa", kind: "Prefecture", parent: None },
+    SubdivisionData { code: "JP-18", name: "Fukui", kind: "Prefecture", parent: None },
+    SubdivisionData { code: "JP-19", name: "Yamanashi", kind: "Prefecture", parent: None },
+    SubdivisionData { code: "JP-20", name: "Nagano", kind: "Prefecture", parent: None },
+    SubdivisionData { code: "JP-21", name: "Gifu", kind: "Prefecture", parent: None },
+    SubdivisionData { code: "JP-22", name: "Shizuoka", kind: "Prefecture", parent: None },
+    SubdivisionData { code: "JP-23", name: "Aichi", kind: "Prefecture", parent: None },
+    SubdivisionData { code: "JP-24", name: "Mie", kind: "Prefecture", parent: None },
+    SubdivisionData { code: "JP-25", name: "Shiga", kind: "Prefecture", parent: None },
+    SubdivisionData { code: "JP-26", name: "Kyoto", kind: "Prefecture", parent: None },
+    SubdivisionData { code: "JP-27", name: "Osaka", kind: "Prefecture", parent: None },
+    SubdivisionData { code: "JP-28", name: "Hyogo", kind: "Prefecture", parent: None },
+    SubdivisionData { code: "JP-29", name: "Nara", kind: "Prefecture", parent: None },
+    SubdivisionData { code: "JP-30", name: "Wakayama", kind: "Prefecture", parent: None },
+    SubdivisionData { code: "JP-31", name: "Tottori", kind: "Prefecture", parent: None },
+    SubdivisionData { code: "JP-32", name: "Shimane", kind: "Prefecture", parent: None },
+    SubdivisionData { code: "JP-33", name: "Okayama", kind: "Prefecture", parent: None },
+    SubdivisionData { code: "JP-34", name: "Hiroshima", kind: "Prefecture", parent: None },
+    SubdivisionData { code: "JP-35", name: "Yamaguchi", kind: "Prefecture", parent: None },
+    SubdivisionData { code: "JP-36", name: "Tokushima", kind: "Prefecture", parent: None },
+    SubdivisionData { code: "JP-37", name: "Kagawa", kind: "Prefecture", parent: None },
+    SubdivisionData { code: "JP-38", name: "Ehime", kind: "Prefecture", parent: None },
+    SubdivisionData { code: "JP-39", name: "Kochi", kind: "Prefecture", parent: None },
+    SubdivisionData { code: "JP-40", name: "Fukuoka", kind: "Prefecture", parent: None },
+    SubdivisionData { code: "JP-41", name: "Saga", kind: "Prefecture", parent: None },
+    SubdivisionData { code: "JP-42", name: "Nagasaki", kind: "Prefecture", parent: None },
+    SubdivisionData { code: "JP-43", name: "Kumamoto", kind: "Prefecture", parent: None },
+    SubdivisionData { code: "JP-44", name: "Oita", kind: "Prefecture", parent: None },
+    SubdivisionData { code: "JP-45", name: "Miyazaki", kind: "Prefecture", parent: None },
+    SubdivisionData { code: "JP-46", name: "Kagoshima", kind: "Prefecture", parent: None },
+    SubdivisionData { code: "JP-47", name: "Okinawa", kind: "Prefecture", parent: None },
+    SubdivisionData { code: "KE-01", name: "Baringo", kind: "County", parent: None },
+    SubdivisionData { code: "KE-02", name: "Bomet", kind: "County", parent: None },
+    SubdivisionData { code: "KE-03", name: "Bungoma", kind: "County", parent: None },
+    SubdivisionData { code: "KE-04", name: "Busia", kind: "County", parent: None },
+    SubdivisionData { code: "KE-05", name: "Elgeyo/Marakwet", kind: "County", parent: None },
+    SubdivisionData { code: "KE-06", name: "Embu", kind: "County", parent: None },
+    SubdivisionData { code: "KE-07", name: "Garissa", kind: "County", parent: None },
+    SubdivisionData { code: "KE-08", name: "Homa Bay", kind: "County", parent: None },
+    SubdivisionData { code: "KE-09", name: "Isiolo", kind: "County", parent: None },
+    SubdivisionData { code: "KE-10", name: "Kajiado", kind: "County", parent: None },
+    SubdivisionData { code: "KE-11", name: "Kakamega", kind: "County", parent: None },
+    SubdivisionData { code: "KE-12", name: "Kericho", kind: "County", parent: None },
+    SubdivisionData { code: "KE-13", name: "Kiambu", kind: "County", parent: None },
+    SubdivisionData { code: "KE-14", name: "Kilifi", kind: "County", parent: None },
+    SubdivisionData { code: "KE-15", name: "Kirinyaga", kind: "County", parent: None },
+    SubdivisionData { code: "KE-16", name: "Kisii", kind: "County", parent: None },
+    SubdivisionData { code: "KE-17", name: "Kisumu", kind: "County", parent: None },
+    SubdivisionData { code: "KE-18", name: "Kitui", kind: "County", parent: None },
+    SubdivisionData { code: "KE-19", name: "Kwale", kind: "County", parent: None },
+    SubdivisionData { code: "KE-20", name: "Laikipia", kind: "County", parent: None },
+    SubdivisionData { code: "KE-21", name: "Lamu", kind: "County", parent: None },
+    SubdivisionData { code: "KE-22", name: "Machakos", kind: "County", parent: None },
+    SubdivisionData { code: "KE-23", name: "Makueni", kind: "County", parent: None },
+    SubdivisionData { code: "KE-24", name: "Mandera", kind: "County", parent: None },
+    SubdivisionData { code: "KE-25", name: "Marsabit", kind: "County", parent: None },
+    SubdivisionData { code: "KE-26", name: "Meru", kind: "County", parent: None },
+    SubdivisionData { code: "KE-27", name: "Migori", kind: "County", parent: None },
+    SubdivisionData { code: "KE-28", name: "Mombasa", kind: "County", parent: None },
+    SubdivisionData { code: "KE-29", name: "Murang'a", kind: "County", parent: None },
+    SubdivisionData { code: "KE-30", name: "Nairobi City", kind: "County", parent: None },
+    SubdivisionData { code: "KE-31", name: "Nakuru", kind: "County", parent: None },
+    SubdivisionData { code: "KE-32", name: "Nandi", kind: "County", parent: None },
+    SubdivisionData { code: "KE-33", name: "Narok", kind: "County", parent: None },
+    SubdivisionData { code: "KE-34", name: "Nyamira", kind: "County", parent: None },
+    SubdivisionData { code: "KE-35", name: "Nyandarua", kind: "County", parent: None },
+    SubdivisionData { code: "KE-36", name: "Nyeri", kind: "County", parent: None },
+    SubdivisionData { code: "KE-37", name: "Samburu", kind: "County", parent: None },
+    SubdivisionData { code: "KE-38", name: "Siaya", kind: "County", parent: None },
+    SubdivisionData { code: "KE-39", name: "Taita/Taveta", kind: "County", parent: None },
+    SubdivisionData { code: "KE-40", name: "Tana River", kind: "County", parent: None },
+    SubdivisionData { code: "KE-41", name: "Tharaka-Nithi", kind: "County", parent: None },
+    SubdivisionData { code: "KE-42", name: "Trans Nzoia", kind: "County", parent: None },
+    SubdivisionData { code: "KE-43", name: "Turkana", kind: "County", parent: None },
+    SubdivisionData { code: "KE-44", name: "Uasin Gishu", kind: "County", parent: None },
+    SubdivisionData { code: "KE-45", name: "Vihiga", kind: "County", parent: None },
+    SubdivisionData { code: "KE-46", name: "Wajir", kind: "County", parent: None },
+    SubdivisionData { code: "KE-47", name: "West Pokot", kind: "County", parent: None },
+    SubdivisionData { code: "KG-B", name: "Batken", kind: "Region", parent: None },
+    SubdivisionData { code: "KG-C", name: "Chuyskaya oblast'", kind: "Region", parent: None },
+    SubdivisionData { code: "KG-GB", name: "Bishkek Shaary", kind: "City", parent: None },
+    SubdivisionData { code: "KG-GO", name: "Gorod Osh", kind: "City", parent: None },
+    SubdivisionData { code: "KG-J", name: "Dzhalal-Abadskaya oblast'", kind: "Region", parent: None },
+    SubdivisionData { code: "KG-N", name: "Naryn", kind: "Region", parent: None },
+    SubdivisionData { code: "KG-O", name: "Osh", kind: "Region", parent: None },
+    SubdivisionData { code: "KG-T", name: "Talas", kind: "Region", parent: None },
+    SubdivisionData { code: "KG-Y", name: "Issyk-Kul'skaja oblast'", kind: "Region", parent: None },
+    SubdivisionData { code: "KH-1", name: "Banteay Mean Choăy", kind: "Province", parent: None },
+    SubdivisionData { code: "KH-10", name: "Kracheh", kind: "Province", parent: None },
+    SubdivisionData { code: "KH-11", name: "Mondol Kiri", kind: "Province", parent: None },
+    SubdivisionData { code: "KH-12", name: "Phnom Penh", kind: "Autonomous municipality", parent: None },
+    SubdivisionData { code: "KH-13", name: "Preah Vihear", kind: "Province", parent: None },
+    SubdivisionData { code: "KH-14", name: "Prey Veaeng", kind: "Province", parent: None },
+    SubdivisionData { code: "KH-15", name: "Pousaat", kind: "Province", parent: None },
+    SubdivisionData { code: "KH-16", name: "Rotanak Kiri", kind: "Province", parent: None },
+    SubdivisionData { code: "KH-17", name: "Siem Reab", kind: "Province", parent: None },
+    SubdivisionData { code: "KH-18", name: "Preah Sihanouk", kind: "Province", parent: None },
+    SubdivisionData { code: "KH-19", name: "Stoĕng Trêng", kind: "Province", parent: None },
+    SubdivisionData { code: "KH-2", name: "Baat Dambang", kind: "Province", parent: None },
+    SubdivisionData { code: "KH-20", name: "Svaay Rieng", kind: "Province", parent: None },
+    SubdivisionData { code: "KH-21", name: "Taakaev", kind: "Province", parent: None },
+    SubdivisionData { code: "KH-22", name: "Otdar Mean Chey", kind: "Province", parent: None },
+    SubdivisionData { code: "KH-23", name: "Kaeb", kind: "Province", parent: None },
+    SubdivisionData { code: "KH-24", name: "Pailin", kind: "Province", parent: None },
+    SubdivisionData { code: "KH-25", name: "Tbong Khmum", kind: "Province", parent: None },
+    SubdivisionData { code: "KH-3", name: "Kampong Chaam", kind: "Province", parent: None },
+    SubdivisionData { code: "KH-4", name: "Kampong Chhnang", kind: "Province", parent: None },
+    SubdivisionData { code: "KH-5", name: "Kampong Spueu", kind: "Province", parent: None },
+    SubdivisionData { code: "KH-6", name: "Kampong Thum", kind: "Province", parent: None },
+    SubdivisionData { code: "KH-7", name: "Kampot", kind: "Province", parent: None },
+    SubdivisionData { code: "KH-8", name: "Kandaal", kind: "Province", parent: None },
+    SubdivisionData { code: "KH-9", name: "Kaoh Kong", kind: "Province", parent: None },
+    SubdivisionData { code: "KI-G", name: "Gilbert Islands", kind: "Group of islands (20 inhabited islands)", parent: None },
+    SubdivisionData { code: "KI-L", name: "Line Islands", kind: "Group of islands (20 inhabited islands)", parent: None },
+    SubdivisionData { code: "KI-P", name: "Phoenix Islands", kind: "Group of islands (20 inhabited islands)", parent: None },
+    SubdivisionData { code: "KM-A", name: "Andjouân", kind: "Island", parent: None },
+    SubdivisionData { code: "KM-G", name: "Andjazîdja", kind: "Island", parent: None },
+    SubdivisionData { code: "KM-M", name: "Mohéli", kind: "Island", parent: None },
+    SubdivisionData { code: "KN-01", name: "Christ Church Nichola Town", kind: "Parish", parent: Some("KN-K") },
+    SubdivisionData { code: "KN-02", name: "Saint Anne Sandy Point", kind: "Parish", parent: Some("KN-K") },
+    SubdivisionData { code: "KN-03", name: "Saint George Basseterre", kind: "Parish", parent: Some("KN-K") },
+    SubdivisionData { code: "KN-04", name: "Saint George Gingerland", kind: "Parish", parent: Some("KN-N") },
+    SubdivisionData { code: "KN-05", name: "Saint James Windward", kind: "Parish", parent: Some("KN-N") },
+    SubdivisionData { code: "KN-06", name: "Saint John Capisterre", kind: "Parish", parent: Some("KN-K") },
+    SubdivisionData { code: "KN-07", name: "Saint John Figtree", kind: "Parish", parent: Some("KN-N") },
+    SubdivisionData { code: "KN-08", name: "Saint Mary Cayon", kind: "Parish", parent: Some("KN-K") },
+    SubdivisionData { code: "KN-09", name: "Saint Paul Capisterre", kind: "Parish", parent: Some("KN-K") },
+    SubdivisionData { code: "KN-10", name: "Saint Paul Charlestown", kind: "Parish", parent: Some("KN-N") },
+    SubdivisionData { code: "KN-11", name: "Saint Peter Basseterre", kind: "Parish", parent: Some("KN-K") },
+    SubdivisionData { code: "KN-12", name: "Saint Thomas Lowland", kind: "Parish", parent: Some("KN-N") },
+    SubdivisionData { code: "KN-13", name: "Saint Thomas Middle Island", kind: "Parish", parent: Some("KN-K") },
+    SubdivisionData { code: "KN-15", name: "Trinity Palmetto Point", kind: "Parish", parent: Some("KN-K") },
+    SubdivisionData { code: "KN-K", name: "Saint Kitts", kind: "State", parent: None },
+    SubdivisionData { code: "KN-N", name: "Nevis", kind: "State", parent: None },
+    SubdivisionData { code: "KP-01", name: "P'yǒngyang", kind: "Capital city", parent: None },
+    SubdivisionData { code: "KP-02", name: "P'yǒngan-namdo", kind: "Province", parent: None },
+    SubdivisionData { code: "KP-03", name: "P'yǒngan-bukto", kind: "Province", parent: None },
+    SubdivisionData { code: "KP-04", name: "Chagang-do", kind: "Province", parent: None },
+    SubdivisionData { code: "KP-05", name: "Hwanghae-namdo", kind: "Province", parent: None },
+    SubdivisionData { code: "KP-06", name: "Hwanghae-bukto", kind: "Province", parent: None },
+    SubdivisionData { code: "KP-07", name: "Kangweonto", kind: "Province", parent: None },
+    SubdivisionData { code: "KP-08", name: "Hamgyǒng-namdo", kind: "Province", parent: None },
+    SubdivisionData { code: "KP-09", name: "Hamgyǒng-bukto", kind: "Province", parent: None },
+    SubdivisionData { code: "KP-10", name: "Ryanggang-do", kind: "Province", parent: None },
+    SubdivisionData { code: "KP-13", name: "Raseon", kind: "Special city", parent: None },
+    SubdivisionData { code: "KP-14", name: "Nampho", kind: "Metropolitan city", parent: None },
+    SubdivisionData { code: "KR-11", name: "Seoul-teukbyeolsi", kind: "Special city", parent: None },
+    SubdivisionData { code: "KR-26", name: "Busan-gwangyeoksi", kind: "Metropolitan city", parent: None },
+    SubdivisionData { code: "KR-27", name: "Daegu-gwangyeoksi", kind: "Metropolitan city", parent: None },
+    SubdivisionData { code: "KR-28", name: "Incheon-gwangyeoksi", kind: "Metropolitan city", parent: None },
+    SubdivisionData { code: "KR-29", name: "Gwangju-gwangyeoksi", kind: "Metropolitan city", parent: None },
+    SubdivisionData { code: "KR-30", name: "Daejeon-gwangyeoksi", kind: "Metropolitan city", parent: None },
+    SubdivisionData { code: "KR-31", name: "Ulsan-gwangyeoksi", kind: "Metropolitan city", parent: None },
+    SubdivisionData { code: "KR-41", name: "Gyeonggi-do", kind: "Province", parent: None },
+    SubdivisionData { code: "KR-42", name: "Gangwon-do", kind: "Province", parent: None },
+    SubdivisionData { code: "KR-43", name: "Chungcheongbuk-do", kind: "Province", parent: None },
+    SubdivisionData { code: "KR-44", name: "Chungcheongnam-do", kind: "Province", parent: None },
+    SubdivisionData { code: "KR-45", name: "Jeollabuk-do", kind: "Province", parent: None },
+    SubdivisionData { code: "KR-46", name: "Jeollanam-do", kind: "Province", parent: None },
+    SubdivisionData { code: "KR-47", name: "Gyeongsangbuk-do", kind: "Province", parent: None },
+    SubdivisionData { code: "KR-48", name: "Gyeongsangnam-do", kind: "Province", parent: None },
+    SubdivisionData { code: "KR-49", name: "Jeju-teukbyeoljachido", kind: "Special self-governing province", parent: None },
+    SubdivisionData { code: "KR-50", name: "Sejong", kind: "Special self-governing city", parent: None },
+    SubdivisionData { code: "KW-AH", name: "Al Aḩmadī", kind: "Governorate", parent: None },
+    SubdivisionData { code: "KW-FA", name: "Al Farwānīyah", kind: "Governorate", parent: None },
+    SubdivisionData { code: "KW-HA", name: "Ḩawallī", kind: "Governorate", parent: None },
+    SubdivisionData { code: "KW-JA", name: "Al Jahrā’", kind: "Governorate", parent: None },
+    SubdivisionData { code: "KW-KU", name: "Al ‘Āşimah", kind: "Governorate", parent: None },
+    SubdivisionData { code: "KW-MU", name: "Mubārak al Kabīr", kind: "Governorate", parent: None },
+    SubdivisionData { code: "KZ-AKM", name: "Akmolinskaja oblast'", kind: "Region", parent: None },
+    SubdivisionData { code: "KZ-AKT", name: "Aktjubinskaja oblast'", kind: "Region", parent: None },
+    SubdivisionData { code: "KZ-ALA", name: "Almaty", kind: "City", parent: None },
+    SubdivisionData { code: "KZ-ALM", name: "Almatinskaja oblast'", kind: "Region", parent: None },
+    SubdivisionData { code: "KZ-AST", name: "Nur-Sultan", kind: "City", parent: None },
+    SubdivisionData { code: "KZ-ATY", name: "Atyrauskaja oblast'", kind: "Region", parent: None },
+    SubdivisionData { code: "KZ-KAR", name: "Karagandinskaja oblast'", kind: "Region", parent: None },
+    SubdivisionData { code: "KZ-KUS", name: "Kostanajskaja oblast'", kind: "Region", parent: None },
+    SubdivisionData { code: "KZ-KZY", name: "Kyzylordinskaja oblast'", kind: "Region", parent: None },
+    SubdivisionData { code: "KZ-MAN", name: "Mangghystaū oblysy", kind: "Region", parent: None },
+    SubdivisionData { code: "KZ-PAV", name: "Pavlodar oblysy", kind: "Region", parent: None },
+    SubdivisionData { code: "KZ-SEV", name: "Severo-Kazahstanskaja oblast'", kind: "Region", parent: None },
+    SubdivisionData { code: "KZ-SHY", name: "Shymkent", kind: "City", parent: None },
+    SubdivisionData { code: "KZ-VOS", name: "Shyghys Qazaqstan oblysy", kind: "Region", parent: None },
+    SubdivisionData { code: "KZ-YUZ", name: "Turkestankaya oblast'", kind: "Region", parent: None },
+    SubdivisionData { code: "KZ-ZAP", name: "Batys Qazaqstan oblysy", kind: "Region", parent: None },
+    SubdivisionData { code: "KZ-ZHA", name: "Zhambyl oblysy", kind: "Region", parent: None },
+    SubdivisionData { code: "LA-AT", name: "Attapu", kind: "Province", parent: None },
+    SubdivisionData { code: "LA-BK", name: "Bokèo", kind: "Province", parent: None },
+    SubdivisionData { code: "LA-BL", name: "Bolikhamxai", kind: "Province", parent: None },
+    SubdivisionData { code: "LA-CH", name: "Champasak", kind: "Province", parent: None },
+    SubdivisionData { code: "LA-HO", name: "Houaphan", kind: "Province", parent: None },
+    SubdivisionData { code: "LA-KH", name: "Khammouan", kind: "Province", parent: None },
+    SubdivisionData { code: "LA-LM", name: "Louang Namtha", kind: "Province", parent: None },
+    SubdivisionData { code: "LA-LP", name: "Louangphabang", kind: "Province", parent: None },
+    SubdivisionData { code: "LA-OU", name: "Oudômxai", kind: "Province", parent: None },
+    SubdivisionData { code: "LA-PH", name: "Phôngsali", kind: "Province", parent: None },
+    SubdivisionData { code: "LA-SL", name: "Salavan", kind: "Province", parent: None },
+    SubdivisionData { code: "LA-SV", name: "Savannakhét", kind: "Province", parent: None },
+    SubdivisionData { code: "LA-VI", name: "Viangchan", kind: "Province", parent: None },
+    SubdivisionData { code: "LA-VT", name: "Viangchan", kind: "Prefecture", parent: None },
+    SubdivisionData { code: "LA-XA", name: "Xaignabouli", kind: "Province", parent: None },
+    SubdivisionData { code: "LA-XE", name: "Xékong", kind: "Province", parent: None },
+    SubdivisionData { code: "LA-XI", name: "Xiangkhouang", kind: "Province", parent: None },
+    SubdivisionData { code: "LA-XS", name: "Xaisômboun", kind: "Province", parent: None },
+    SubdivisionData { code: "LB-AK", name: "Aakkâr", kind: "Governorate", parent: None },
+    SubdivisionData { code: "LB-AS", name: "Ash Shimāl", kind: "Governorate", parent: None },
+    SubdivisionData { code: "LB-BA", name: "Bayrūt", kind: "Governorate", parent: None },
+    SubdivisionData { code: "LB-BH", name: "Baalbek-Hermel", kind: "Governorate", parent: None },
+    SubdivisionData { code: "LB-BI", name: "Al Biqā‘", kind: "Governorate", parent: None },
+    SubdivisionData { code: "LB-JA", name: "Al Janūb", kind: "Governorate", parent: None },
+    SubdivisionData { code: "LB-JL", name: "Jabal Lubnān", kind: "Governorate", parent: None },
+    SubdivisionData { code: "LB-NA", name: "An Nabaţīyah", kind: "Governorate", parent: None },
+    SubdivisionData { code: "LC-01", name: "Anse la Raye", kind: "District", parent: None },
+    SubdivisionData { code: "LC-02", name: "Castries", kind: "District", parent: None },
+    SubdivisionData { code: "LC-03", name: "Choiseul", kind: "District", parent: None },
+    SubdivisionData { code: "LC-05", name: "Dennery", kind: "District", parent: None },
+    SubdivisionData { code: "LC-06", name: "Gros Islet", kind: "District", parent: None },
+    SubdivisionData { code: "LC-07", name: "Laborie", kind: "District", parent: None },
+    SubdivisionData { code: "LC-08", name: "Micoud", kind: "District", parent: None },
+    SubdivisionData { code: "LC-10", name: "Soufrière", kind: "District", parent: None },
+    SubdivisionData { code: "LC-11", name: "Vieux Fort", kind: "District", parent: None },
+    SubdivisionData { code: "LC-12", name: "Canaries", kind: "District", parent: None },
+    SubdivisionData { code: "LI-01", name: "Balzers", kind: "Commune", parent: None },
+    SubdivisionData { code: "LI-02", name: "Eschen", kind: "Commune", parent: None },
+    SubdivisionData { code: "LI-03", name: "Gamprin", kind: "Commune", parent: None },
+    SubdivisionData { code: "LI-04", name: "Mauren", kind: "Commune", parent: None },
+    SubdivisionData { code: "LI-05", name: "Planken", kind: "Commune", parent: None },
+    SubdivisionData { code: "LI-06", name: "Ruggell", kind: "Commune", parent: None },
+    SubdivisionData { code: "LI-07", name: "Schaan", kind: "Commune", parent: None },
+    SubdivisionData { code: "LI-08", name: "Schellenberg", kind: "Commune", parent: None },
+    SubdivisionData { code: "LI-09", name: "Triesen", kind: "Commune", parent: None },
+    SubdivisionData { code: "LI-10", name: "Triesenberg", kind: "Commune", parent: None },
+    SubdivisionData { code: "LI-11", name: "Vaduz", kind: "Commune", parent: None },
+    SubdivisionData { code: "LK-1", name: "Western Province", kind: "Province", parent: None },
+    SubdivisionData { code: "LK-11", name: "Colombo", kind: "District", parent: Some("LK-1") },
+    SubdivisionData { code: "LK-12", name: "Gampaha", kind: "District", parent: Some("LK-1") },
+    SubdivisionData { code: "LK-13", name: "Kalutara", kind: "District", parent: Some("LK-1") },
+    SubdivisionData { code: "LK-2", name: "Central Province", kind: "Province", parent: None },
+    SubdivisionData { code: "LK-21", name: "Kandy", kind: "District", parent: Some("LK-2") },
+    SubdivisionData { code: "LK-22", name: "Matale", kind: "District", parent: Some("LK-2") },
+    SubdivisionData { code: "LK-23", name: "Nuwara Eliya", kind: "District", parent: Some("LK-2") },
+    SubdivisionData { code: "LK-3", name: "Southern Province", kind: "Province", parent: None },
+    SubdivisionData { code: "LK-31", name: "Galle", kind: "District", parent: Some("LK-3") },
+    SubdivisionData { code: "LK-32", name: "Matara", kind: "District", parent: Some("LK-3") },
+    SubdivisionData { code: "LK-33", name: "Hambantota", kind: "District", parent: Some("LK-3") },
+    SubdivisionData { code: "LK-4", name: "Northern Province", kind: "Province", parent: None },
+    SubdivisionData { code: "LK-41", name: "Jaffna", kind: "District", parent: Some("LK-4") },
+    SubdivisionData { code: "LK-42", name: "Kilinochchi", kind: "District", parent: Some("LK-4") },
+    SubdivisionData { code: "LK-43", name: "Mannar", kind: "District", parent: Some("LK-4") },
+    SubdivisionData { code: "LK-44", name: "Vavuniya", kind: "District", parent: Some("LK-4") },
+    SubdivisionData { code: "LK-45", name: "Mullaittivu", kind: "District", parent: Some("LK-4") },
+    SubdivisionData { code: "LK-5", name: "Eastern Province", kind: "Province", parent: None },
+    SubdivisionData { code: "LK-51", name: "Batticaloa", kind: "District", parent: Some("LK-5") },
+    SubdivisionData { code: "LK-52", name: "Ampara", kind: "District", parent: Some("LK-5") },
+    SubdivisionData { code: "LK-53", name: "Trincomalee", kind: "District", parent: Some("LK-5") },
+    SubdivisionData { code: "LK-6", name: "North Western Province", kind: "Province", parent: None },
+    SubdivisionData { code: "LK-61", name: "Kurunegala", kind: "District", parent: Some("LK-6") },
+    SubdivisionData { code: "LK-62", name: "Puttalam", kind: "District", parent: Some("LK-6") },
+    SubdivisionData { code: "LK-7", name: "North Central Province", kind: "Province", parent: None },
+    SubdivisionData { code: "LK-71", name: "Anuradhapura", kind: "District", parent: Some("LK-7") },
+    SubdivisionData { code: "LK-72", name: "Polonnaruwa", kind: "District", parent: Some("LK-7") },
+    SubdivisionData { code: "LK-8", name: "Uva Province", kind: "Province", parent: None },
+    SubdivisionData { code: "LK-81", name: "Badulla", kind: "District", parent: Some("LK-8") },
+    SubdivisionData { code: "LK-82", name: "Monaragala", kind: "District", parent: Some("LK-8") },
+    SubdivisionData { code: "LK-9", name: "Sabaragamuwa Province", kind: "Province", parent: None },
+    SubdivisionData { code: "LK-91", name: "Ratnapura", kind: "District", parent: Some("LK-9") },
+    SubdivisionData { code: "LK-92", name: "Kegalla", kind: "District", parent: Some("LK-9") },
+    SubdivisionData { code: "LR-BG", name: "Bong", kind: "County", parent: None },
+    SubdivisionData { code: "LR-BM", name: "Bomi", kind: "County", parent: None },
+    SubdivisionData { code: "LR-CM", name: "Grand Cape Mount", kind: "County", parent: None },
+    SubdivisionData { code: "LR-GB", name: "Grand Bassa", kind: "County", parent: None },
+    SubdivisionData { code: "LR-GG", name: "Grand Gedeh", kind: "County", parent: None },
+    SubdivisionData { code: "LR-GK", name: "Grand Kru", kind: "County", parent: None },
+    SubdivisionData { code: "LR-GP", name: "Gbarpolu", kind: "County", parent: None },
+    SubdivisionData { code: "LR-LO", name: "Lofa", kind: "County", parent: None },
+    SubdivisionData { code: "LR-MG", name: "Margibi", kind: "County", parent: None },
+    SubdivisionData { code: "LR-MO", name: "Montserrado", kind: "County", parent: None },
+    SubdivisionData { code: "LR-MY", name: "Maryland", kind: "County", parent: None },
+    SubdivisionData { code: "LR-NI", name: "Nimba", kind: "County", parent: None },
+    SubdivisionData { code: "LR-RG", name: "River Gee", kind: "County", parent: None },
+    SubdivisionData { code: "LR-RI", name: "River Cess", kind: "County", parent: None },
+    SubdivisionData { code: "LR-SI", name: "Sinoe", kind: "County", parent: None },
+    SubdivisionData { code: "LS-A", name: "Maseru", kind: "District", parent: None },
+    SubdivisionData { code: "LS-B", name: "Botha-Bothe", kind: "District", parent: None },
+    SubdivisionData { code: "LS-C", name: "Leribe", kind: "District", parent: None },
+    SubdivisionData { code: "LS-D", name: "Berea", kind: "District", parent: None },
+    SubdivisionData { code: "LS-E", name: "Mafeteng", kind: "District", parent: None },
+    SubdivisionData { code: "LS-F", name: "Mohale's Hoek", kind: "District", parent: None },
+    SubdivisionData { code: "LS-G", name: "Quthing", kind: "District", parent: None },
+    SubdivisionData { code: "LS-H", name: "Qacha's Nek", kind: "District", parent: None },
+    SubdivisionData { code: "LS-J", name: "Mokhotlong", kind: "District", parent: None },
+    SubdivisionData { code: "LS-K", name: "Thaba-Tseka", kind: "District", parent: None },
+    SubdivisionData { code: "LT-01", name: "Akmenė", kind: "District municipality", parent: None },
+    SubdivisionData { code: "LT-02", name: "Alytaus miestas", kind: "City municipality", parent: None },
+    SubdivisionData { code: "LT-03", name: "Alytus", kind: "District municipality", parent: None },
+    SubdivisionData { code: "LT-04", name: "Anykščiai", kind: "District municipality", parent: None },
+    SubdivisionData { code: "LT-05", name: "Birštono", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LT-06", name: "Biržai", kind: "District municipality", parent: None },
+    SubdivisionData { code: "LT-07", name: "Druskininkai", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LT-08", name: "Elektrėnai", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LT-09", name: "Ignalina", kind: "District municipality", parent: None },
+    SubdivisionData { code: "LT-10", name: "Jonava", kind: "District municipality", parent: None },
+    SubdivisionData { code: "LT-11", name: "Joniškis", kind: "District municipality", parent: None },
+    SubdivisionData { code: "LT-12", name: "Jurbarkas", kind: "District municipality", parent: None },
+    SubdivisionData { code: "LT-13", name: "Kaišiadorys", kind: "District municipality", parent: None },
+    SubdivisionData { code: "LT-14", name: "Kalvarijos", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LT-15", name: "Kauno miestas", kind: "City municipality", parent: None },
+    SubdivisionData { code: "LT-16", name: "Kaunas", kind: "District municipality", parent: None },
+    SubdivisionData { code: "LT-17", name: "Kazlų Rūdos", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LT-18", name: "Kėdainiai", kind: "District municipality", parent: None },
+    SubdivisionData { code: "LT-19", name: "Kelmė", kind: "District municipality", parent: None },
+    SubdivisionData { code: "LT-20", name: "Klaipėdos miestas", kind: "City municipality", parent: None },
+    SubdivisionData { code: "LT-21", name: "Klaipėda", kind: "District municipality", parent: None },
+    SubdivisionData { code: "LT-22", name: "Kretinga", kind: "District municipality", parent: None },
+    SubdivisionData { code: "LT-23", name: "Kupiškis", kind: "District municipality", parent: None },
+    SubdivisionData { code: "LT-24", name: "Lazdijai", kind: "District municipality", parent: None },
+    SubdivisionData { code: "LT-25", name: "Marijampolė", kind: "District municipality", parent: None },
+    SubdivisionData { code: "LT-26", name: "Mažeikiai", kind: "District municipality", parent: None },
+    SubdivisionData { code: "LT-27", name: "Molėtai", kind: "District municipality", parent: None },
+    SubdivisionData { code: "LT-28", name: "Neringa", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LT-29", name: "Pagėgiai", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LT-30", name: "Pakruojis", kind: "District municipality", parent: None },
+    SubdivisionData { code: "LT-31", name: "Palangos miestas", kind: "City municipality", parent: None },
+    SubdivisionData { code: "LT-32", name: "Panevėžio miestas", kind: "City municipality", parent: None },
+    SubdivisionData { code: "LT-33", name: "Panevėžys", kind: "District municipality", parent: None },
+    SubdivisionData { code: "LT-34", name: "Pasvalys", kind: "District municipality", parent: None },
+    SubdivisionData { code: "LT-35", name: "Plungė", kind: "District municipality", parent: None },
+    SubdivisionData { code: "LT-36", name: "Prienai", kind: "District municipality", parent: None },
+    SubdivisionData { code: "LT-37", name: "Radviliškis", kind: "District municipality", parent: None },
+    SubdivisionData { code: "LT-38", name: "Raseiniai", kind: "District municipality", parent: None },
+    SubdivisionData { code: "LT-39", name: "Rietavo", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LT-40", name: "Rokiškis", kind: "District municipality", parent: None },
+    SubdivisionData { code: "LT-41", name: "Šakiai", kind: "District municipality", parent: None },
+    SubdivisionData { code: "LT-42", name: "Šalčininkai", kind: "District municipality", parent: None },
+    SubdivisionData { code: "LT-43", name: "Šiaulių miestas", kind: "City municipality", parent: None },
+    SubdivisionData { code: "LT-44", name: "Šiauliai", kind: "District municipality", parent: None },
+    SubdivisionData { code: "LT-45", name: "Šilalė", kind: "District municipality", parent: None },
+    SubdivisionData { code: "LT-46", name: "Šilutė", kind: "District municipality", parent: None },
+    SubdivisionData { code: "LT-47", name: "Širvintos", kind: "District municipality", parent: None },
+    SubdivisionData { code: "LT-48", name: "Skuodas", kind: "District municipality", parent: None },
+    SubdivisionData { code: "LT-49", name: "Švenčionys", kind: "District municipality", parent: None },
+    SubdivisionData { code: "LT-50", name: "Tauragė", kind: "District municipality", parent: None },
+    SubdivisionData { code: "LT-51", name: "Telšiai", kind: "District municipality", parent: None },
+    SubdivisionData { code: "LT-52", name: "Trakai", kind: "District municipality", parent: None },
+    SubdivisionData { code: "LT-53", name: "Ukmergė", kind: "District municipality", parent: None },
+    SubdivisionData { code: "LT-54", name: "Utena", kind: "District municipality", parent: None },
+    SubdivisionData { code: "LT-55", name: "Varėna", kind: "District municipality", parent: None },
+    SubdivisionData { code: "LT-56", name: "Vilkaviškis", kind: "District municipality", parent: None },
+    SubdivisionData { code: "LT-57", name: "Vilniaus miestas", kind: "City municipality", parent: None },
+    SubdivisionData { code: "LT-58", name: "Vilnius", kind: "District municipality", parent: None },
+    SubdivisionData { code: "LT-59", name: "Visaginas", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LT-60", name: "Zarasai", kind: "District municipality", parent: None },
+    SubdivisionData { code: "LT-AL", name: "Alytaus apskritis", kind: "County", parent: None },
+    SubdivisionData { code: "LT-KL", name: "Klaipėdos apskritis", kind: "County", parent: None },
+    SubdivisionData { code: "LT-KU", name: "Kauno apskritis", kind: "County", parent: None },
+    SubdivisionData { code: "LT-MR", name: "Marijampolės apskritis", kind: "County", parent: None },
+    SubdivisionData { code: "LT-PN", name: "Panevėžio apskritis", kind: "County", parent: None },
+    SubdivisionData { code: "LT-SA", name: "Šiaulių apskritis", kind: "County", parent: None },
+    SubdivisionData { code: "LT-TA", name: "Tauragės apskritis", kind: "County", parent: None },
+    SubdivisionData { code: "LT-TE", name: "Telšių apskritis", kind: "County", parent: None },
+    SubdivisionData { code: "LT-UT", name: "Utenos apskritis", kind: "County", parent: None },
+    SubdivisionData { code: "LT-VL", name: "Vilniaus apskritis", kind: "County", parent: None },
+    SubdivisionData { code: "LU-CA", name: "Capellen", kind: "Canton", parent: None },
+    SubdivisionData { code: "LU-CL", name: "Clerf", kind: "Canton", parent: None },
+    SubdivisionData { code: "LU-DI", name: "Diekirch", kind: "Canton", parent: None },
+    SubdivisionData { code: "LU-EC", name: "Echternach", kind: "Canton", parent: None },
+    SubdivisionData { code: "LU-ES", name: "Esch an der Alzette", kind: "Canton", parent: None },
+    SubdivisionData { code: "LU-GR", name: "Grevenmacher", kind: "Canton", parent: None },
+    SubdivisionData { code: "LU-LU", name: "Luxembourg", kind: "Canton", parent: None },
+    SubdivisionData { code: "LU-ME", name: "Mersch", kind: "Canton", parent: None },
+    SubdivisionData { code: "LU-RD", name: "Redange", kind: "Canton", parent: None },
+    SubdivisionData { code: "LU-RM", name: "Remich", kind: "Canton", parent: None },
+    SubdivisionData { code: "LU-VD", name: "Veianen", kind: "Canton", parent: None },
+    SubdivisionData { code: "LU-WI", name: "Wiltz", kind: "Canton", parent: None },
+    SubdivisionData { code: "LV-001", name: "Aglonas novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-002", name: "Aizkraukles novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-003", name: "Aizputes novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-004", name: "Aknīstes novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-005", name: "Alojas novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-006", name: "Alsungas novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-007", name: "Alūksnes novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-008", name: "Amatas novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-009", name: "Apes novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-010", name: "Auces novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-011", name: "Ādažu novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-012", name: "Babītes novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-013", name: "Baldones novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-014", name: "Baltinavas novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-015", name: "Balvu novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-016", name: "Bauskas novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-017", name: "Beverīnas novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-018", name: "Brocēnu novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-019", name: "Burtnieku novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-020", name: "Carnikavas novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-021", name: "Cesvaines novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-022", name: "Cēsu novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-023", name: "Ciblas novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-024", name: "Dagdas novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-025", name: "Daugavpils novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-026", name: "Dobeles novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-027", name: "Dundagas novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-028", name: "Durbes novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-029", name: "Engures novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-030", name: "Ērgļu novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-031", name: "Garkalnes novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-032", name: "Grobiņas novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-033", name: "Gulbenes novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-034", name: "Iecavas novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-035", name: "Ikšķiles novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-036", name: "Ilūkstes novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-037", name: "Inčukalna novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-038", name: "Jaunjelgavas novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-039", name: "Jaunpiebalgas novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-040", name: "Jaunpils novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-041", name: "Jelgavas novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-042", name: "Jēkabpils novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-043", name: "Kandavas novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-044", name: "Kārsavas novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-045", name: "Kocēnu novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-046", name: "Kokneses novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-047", name: "Krāslavas novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-048", name: "Krimuldas novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-049", name: "Krustpils novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-050", name: "Kuldīgas novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-051", name: "Ķeguma novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-052", name: "Ķekavas novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-053", name: "Lielvārdes novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-054", name: "Limbažu novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-055", name: "Līgatnes novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-056", name: "Līvānu novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-057", name: "Lubānas novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-058", name: "Ludzas novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-059", name: "Madonas novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-060", name: "Mazsalacas novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-061", name: "Mālpils novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-062", name: "Mārupes novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-063", name: "Mērsraga novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-064", name: "Naukšēnu novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-065", name: "Neretas novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-066", name: "Nīcas novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-067", name: "Ogres novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-068", name: "Olaines novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-069", name: "Ozolnieku novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-070", name: "Pārgaujas novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-071", name: "Pāvilostas novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-072", name: "Pļaviņu novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-073", name: "Preiļu novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-074", name: "Priekules novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-075", name: "Priekuļu novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-076", name: "Raunas novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-077", name: "Rēzeknes novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-078", name: "Riebiņu novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-079", name: "Rojas novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-080", name: "Ropažu novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-081", name: "Rucavas novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-082", name: "Rugāju novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-083", name: "Rundāles novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-084", name: "Rūjienas novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-085", name: "Salas novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-086", name: "Salacgrīvas novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-087", name: "Salaspils novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-088", name: "Saldus novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-089", name: "Saulkrastu novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-090", name: "Sējas novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-091", name: "Siguldas novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-092", name: "Skrīveru novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-093", name: "Skrundas novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-094", name: "Smiltenes novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-095", name: "Stopiņu novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-096", name: "Strenču novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-097", name: "Talsu novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-098", name: "Tērvetes novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-099", name: "Tukuma novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-100", name: "Vaiņodes novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-101", name: "Valkas novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-102", name: "Varakļānu novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-103", name: "Vārkavas novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-104", name: "Vecpiebalgas novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-105", name: "Vecumnieku novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-106", name: "Ventspils novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-107", name: "Viesītes novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-108", name: "Viļakas novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-109", name: "Viļānu novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-110", name: "Zilupes novads", kind: "Municipality", parent: None },
+    SubdivisionData { code: "LV-DGV", name: "Daugavpils", kind: "Republican city", parent: None },
+    SubdivisionData { code: "LV-JEL", name: "Jelgava", kind: "Republican city", parent: None },
+    SubdivisionData { code: "LV-JKB", name: "Jēkabpils", kind: "Republican city", parent: None },
+    SubdivisionData { code: "LV-JUR", name: "Jūrmala", kind: "Republican city", parent: None },
+    SubdivisionData { code: "LV-LPX", name: "Liepāja", kind: "Republican city", parent: None },
+    SubdivisionData { code: "LV-REZ", name: "Rēzekne", kind: "Republican city", parent: None },
+    SubdivisionData { code: "LV-RIX", name: "Rīga", kind: "Republican city", parent: None },
+    SubdivisionData { code: "LV-VEN", name: "Ventspils", kind: "Republican city", parent: None },
+    SubdivisionData { code: "LV-VMR", name: "Valmiera", kind: "Republican city", parent: None },
+    SubdivisionData { code: "LY-BA", name: "Banghāzī", kind: "Popularate", parent: None },
+    SubdivisionData { code: "LY-BU", name: "Al Buţnān", kind: "Popularate", parent: None },
+    SubdivisionData { code: "LY-DR", name: "Darnah", kind: "Popularate", parent: None },
+    SubdivisionData { code: "LY-GT", name: "Ghāt", kind: "Popularate", parent: None },
+    SubdivisionData { code: "LY-JA", name: "Al Jabal al Akhḑar", kind: "Popularate", parent: None },
+    SubdivisionData { code: "LY-JG", name: "Al Jabal al Gharbī", kind: "Popularate", parent: None },
+    SubdivisionData { code: "LY-JI", name: "Al Jafārah", kind: "Popularate", parent: None },
+    SubdivisionData { code: "LY-JU", name: "Al Jufrah", kind: "Popularate", parent: None },
+    SubdivisionData { code: "LY-KF", name: "Al Kufrah", kind: "Popularate", parent: None },
+    SubdivisionData { code: "LY-MB", name: "Al Marqab", kind: "Popularate", parent: None },
+    SubdivisionData { code: "LY-MI", name: "Mişrātah", kind: "Popularate", parent: None },
+    SubdivisionData { code: "LY-MJ", name: "Al Marj", kind: "Popularate", parent: None },
+    SubdivisionData { code: "LY-MQ", name: "Murzuq", kind: "Popularate", parent: None },
+    SubdivisionData { code: "LY-NL", name: "Nālūt", kind: "Popularate", parent: None },
+    SubdivisionData { code: "LY-NQ", name: "An Nuqāţ al Khams", kind: "Popularate", parent: None },
+    SubdivisionData { code: "LY-SB", name: "Sabhā", kind: "Popularate", parent: None },
+    SubdivisionData { code: "LY-SR", name: "Surt", kind: "Popularate", parent: None },
+    SubdivisionData { code: "LY-TB", name: "Ţarābulus", kind: "Popularate", parent: None },
+    SubdivisionData { code: "LY-WA", name: "Al Wāḩāt", kind: "Popularate", parent: None },
+    SubdivisionData { code: "LY-WD", name: "Wādī al Ḩayāt", kind: "Popularate", parent: None },
+    SubdivisionData { code: "LY-WS", name: "Wādī ash Shāţi’", kind: "Popularate", parent: None },
+    SubdivisionData { code: "LY-ZA", name: "Az Zāwiyah", kind: "Popularate", parent: None },
+    SubdivisionData { code: "MA-01", name: "Tanger-Tétouan-Al Hoceïma", kind: "Region", parent: None },
+    SubdivisionData { code: "MA-02", name: "L'Oriental", kind: "Region", parent: None },
+    SubdivisionData { code: "MA-03", name: "Fès-Meknès", kind: "Region", parent: None },
+    SubdivisionData { code: "MA-04", name: "Rabat-Salé-Kénitra", kind: "Region", parent: None },
+    SubdivisionData { code: "MA-05", name: "Béni Mellal-Khénifra", kind: "Region", parent: None },
+    SubdivisionData { code: "MA-06", name: "Casablanca-Settat", kind: "Region", parent: None },
+    SubdivisionData { code: "MA-07", name: "Marrakech-Safi", kind: "Region", parent: None },
+    SubdivisionData { code: "MA-08", name: "Drâa-Tafilalet", kind: "Region", parent: None },
+    SubdivisionData { code: "MA-09", name: "Souss-Massa", kind: "Region", parent: None },
+    SubdivisionData { code: "MA-10", name: "Guelmim-Oued Noun (EH-partial)", kind: "Region", parent: None },
+    SubdivisionData { code: "MA-11", name: "Laâyoune-Sakia El Hamra (EH-partial)", kind: "Region", parent: None },
+    SubdivisionData { code: "MA-12", name: "Dakhla-Oued Ed-Dahab (EH)", kind: "Region", parent: None },
+    SubdivisionData { code: "MA-AGD", name: "Agadir-Ida-Ou-Tanane", kind: "Prefecture", parent: Some("MA-09") },
+    SubdivisionData { code: "MA-AOU", name: "Aousserd (EH)", kind: "Province", parent: Some("MA-12") },
+    SubdivisionData { code: "MA-ASZ", name: "Assa-Zag (EH-partial)", kind: "Province", parent: Some("MA-10") },
+    SubdivisionData { code: "MA-AZI", name: "Azilal", kind: "Province", parent: Some("MA-05") },
+    SubdivisionData { code: "MA-BEM", name: "Béni Mellal", kind: "Province", parent: Some("MA-05") },
+    SubdivisionData { code: "MA-BER", name: "Berkane", kind: "Province", parent: Some("MA-02") },
+    SubdivisionData { code: "MA-BES", name: "Benslimane", kind: "Province", parent: Some("MA-06") },
+    SubdivisionData { code: "MA-BOD", name: "Boujdour (EH)", kind: "Province", parent: Some("MA-11") },
+    SubdivisionData { code: "MA-BOM", name: "Boulemane", kind: "Province", parent: Some("MA-03") },
+    SubdivisionData { code: "MA-BRR", name: "Berrechid", kind: "Province", parent: Some("MA-06") },
+    SubdivisionData { code: "MA-CAS", name: "Casablanca", kind: "Prefecture", parent: Some("MA-06") },
+    SubdivisionData { code: "MA-CHE", name: "Chefchaouen", kind: "Province", parent: Some("MA-01") },
+    SubdivisionData { code: "MA-CHI", name: "Chichaoua", kind: "Province", parent: Some("MA-07") },
+    SubdivisionData { code: "MA-CHT", name: "Chtouka-Ait Baha", kind: "Province", parent: Some("MA-06") },
+    SubdivisionData { code: "MA-DRI", name: "Driouch", kind: "Province", parent: Some("MA-02") },
+    SubdivisionData { code: "MA-ERR", name: "Errachidia", kind: "Province", parent: Some("MA-08") },
+    SubdivisionData { code: "MA-ESI", name: "Essaouira", kind: "Province", parent: Some("MA-07") },
+    SubdivisionData { code: "MA-ESM", name: "Es-Semara (EH-partial)", kind: "Province", parent: Some("MA-11") },
+    SubdivisionData { code: "MA-FAH", name: "Fahs-Anjra", kind: "Province", parent: Some("MA-01") },
+    SubdivisionData { code: "MA-FES", name: "Fès", kind: "Prefecture", parent: Some("MA-03") },
+    SubdivisionData { code: "MA-FIG", name: "Figuig", kind: "Province", parent: Some("MA-02") },
+    SubdivisionData { code: "MA-FQH", name: "Fquih Ben Salah", kind: "Province", parent: Some("MA-05") },
+    SubdivisionData { code: "MA-GUE", name: "Guelmim", kind: "Province", parent: Some("MA-10") },
+    SubdivisionData { code: "MA-GUF", name: "Guercif", kind: "Province", parent: Some("MA-02") },
+    SubdivisionData { code: "MA-HAJ", name: "El Hajeb", kind: "Province", parent: Some("MA-03") },
+    SubdivisionData { code: "MA-HAO", name: "Al Haouz", kind: "Province", parent: Some("MA-07") },
+    SubdivisionData { code: "MA-HOC", name: "Al Hoceïma", kind: "Province", parent: Some("MA-01") },
+    SubdivisionData { code: "MA-IFR", name: "Ifrane", kind: "Province", parent: Some("MA-03") },
+    SubdivisionData { code: "MA-INE", name: "Inezgane-Ait Melloul", kind: "Prefecture", parent: Some("MA-09") },
+    SubdivisionData { code: "MA-JDI", name: "El Jadida", kind: "Province", parent: Some("MA-06") },
+    SubdivisionData { code: "MA-JRA", name: "Jerada", kind: "Province", parent: Some("MA-02") },
+    SubdivisionData { code: "MA-KEN", name: "Kénitra", kind: "Province", parent: Some("MA-04") },
+    SubdivisionData { code: "MA-KES", name: "El Kelâa des Sraghna", kind: "Province", parent: Some("MA-07") },
+    SubdivisionData { code: "MA-KHE", name: "Khémisset", kind: "Province", parent: Some("MA-04") },
+    SubdivisionData { code: "MA-KHN", name: "Khénifra", kind: "Province", parent: Some("MA-05") },
+    SubdivisionData { code: "MA-KHO", name: "Khouribga", kind: "Province", parent: Some("MA-05") },
+    SubdivisionData { code: "MA-LAA", name: "Laâyoune (EH)", kind: "Province", parent: Some("MA-11") },
+    SubdivisionData { code: "MA-LAR", name: "Larache", kind: "Province", parent: Some("MA-01") },
+    SubdivisionData { code: "MA-MAR", name: "Marrakech", kind: "Prefecture", parent: Some("MA-07") },
+    SubdivisionData { code: "MA-MDF", name: "M’diq-Fnideq", kind: "Prefecture", parent: Some("MA-01") },
+    SubdivisionData { code: "MA-MED", name: "Médiouna", kind: "Province", parent: Some("MA-06") },
+    SubdivisionData { code: "MA-MEK", name: "Meknès", kind: "Prefecture", parent: Some("MA-03") },
+    SubdivisionData { code: "MA-MID", name: "Midelt", kind: "Province", parent: Some("MA-08") },
+    SubdivisionData { code: "MA-MOH", name: "Mohammadia", kind: "Prefecture", parent: Some("MA-06") },
+    SubdivisionData { code: "MA-MOU", name: "Moulay Yacoub", kind: "Province", parent: Some("MA-03") },
+    SubdivisionData { code: "MA-NAD", name: "Nador", kind: "Province", parent: Some("MA-02") },
+    SubdivisionData { code: "MA-NOU", name: "Nouaceur", kind: "Province", parent: Some("MA-04") },
+    SubdivisionData { code: "MA-OUA", name: "Ouarzazate", kind: "Province", parent: Some("MA-08") },
+    SubdivisionData { code: "MA-OUD", name: "Oued Ed-Dahab (EH)", kind: "Province", parent: Some("MA-12") },
+    SubdivisionData { code: "MA-OUJ", name: "Oujda-Angad", kind: "Prefecture", parent: Some("MA-02") },
+    SubdivisionData { code: "MA-OUZ", name: "Ouezzane", kind: "Province", parent: Some("MA-01") },
+    SubdivisionData { code: "MA-RAB", name: "Rabat", kind: "Prefecture", parent: Some("MA-04") },
+    SubdivisionData { code: "MA-REH", name: "Rehamna", kind: "Province", parent: Some("MA-07") },
+    SubdivisionData { code: "MA-SAF", name: "Safi", kind: "Province", parent: Some("MA-07") },
+    SubdivisionData { code: "MA-SAL", name: "Salé", kind: "Prefecture", parent: Some("MA-04") },
+    SubdivisionData { code: "MA-SEF", name: "Sefrou", kind: "Province", parent: Some("MA-03") },
+    SubdivisionData { code: "MA-SET", name: "Settat", kind: "Province", parent: Some("MA-06") },
+    SubdivisionData { code: "MA-SIB", name: "Sidi Bennour", kind: "Province", parent: Some("MA-06") },
+    SubdivisionData { code: "MA-SIF", name: "Sidi Ifni", kind: "Province", parent: Some("MA-10") },
+    SubdivisionData { code: "MA-SIK", name: "Sidi Kacem", kind: "Province", parent: Some("MA-04") },
+    SubdivisionData { code: "MA-SIL", name: "Sidi Slimane", kind: "Province", parent: Some("MA-04") },
+    SubdivisionData { code: "MA-SKH", name: "Skhirate-Témara", kind: "Prefecture", parent: Some("MA-04") },
+    SubdivisionData { code: "MA-TAF", name: "Tarfaya (EH-partial)", kind: "Province", parent: Some("MA-11") },
+    SubdivisionData { code: "MA-TAI", name: "Taourirt", kind: "Province", parent: Some("MA-02") },
+    SubdivisionData { code: "MA-TAO", name: "Taounate", kind: "Province", parent: Some("MA-03") },
+    SubdivisionData { code: "MA-TAR", name: "Taroudannt", kind: "Province", parent: Some("MA-09") },
+    SubdivisionData { code: "MA-TAT", name: "Tata", kind: "Province", parent: Some("MA-09") },
+    SubdivisionData { code: "MA-TAZ", name: "Taza", kind: "Province", parent: Some("MA-03") },
+    SubdivisionData { code: "MA-TET", name: "Tétouan", kind: "Province", parent: Some("MA-01") },
+    SubdivisionData { code: "MA-TIN", name: "Tinghir", kind: "Province", parent: Some("MA-08") },
+    SubdivisionData { code: "MA-TIZ", name: "Tiznit", kind: "Province", parent: Some("MA-09") },
+    SubdivisionData { code: "MA-TNG", name: "Tanger-Assilah", kind: "Prefecture", parent: Some("MA-01") },
+    SubdivisionData { code: "MA-TNT", name: "Tan-Tan (EH-partial)", kind: "Province", parent: Some("MA-10") },
+    SubdivisionData { code: "MA-YUS", name: "Youssoufia", kind: "Province", parent: Some("MA-07") },
+    SubdivisionData { code: "MA-ZAG", name: "Zagora", kind: "Province", parent: Some("MA-08") },
+    SubdivisionData { code: "MC-CL", name: "La Colle", kind: "Quarter", parent: None },
+    SubdivisionData { code: "MC-CO", name: "La Condamine", kind: "Quarter", parent: None },
+    SubdivisionData { code: "MC-FO", name: "Fontvieille", kind: "Quarter", parent: None },
+    SubdivisionData { code: "MC-GA", name: "La Gare", kind: "Quarter", parent: None },
+    SubdivisionData { code: "MC-JE", name: "Jardin Exotique", kind: "Quarter", parent: None },
+    SubdivisionData { code: "MC-LA", name: "Larvotto", kind: "Quarter", parent: None },
+    SubdivisionData { code: "MC-MA", name: "Malbousquet", kind: "Quarter", parent: None },
+    SubdivisionData { code: "MC-MC", name: "Monte-Carlo", kind: "Quarter", parent: None },
+    SubdivisionData { code: "MC-MG", name: "Moneghetti", kind: "Quarter", parent: None },
+    SubdivisionData { code: "MC-MO", name: "Monaco-Ville", kind: "Quarter", parent: None },
+    SubdivisionData { code: "MC-MU", name: "Moulins", kind: "Quarter", parent: None },
+    SubdivisionData { code: "MC-PH", name: "Port-Hercule", kind: "Quarter", parent: None },
+    SubdivisionData { code: "MC-SD", name: "Sainte-Dévote", kind: "Quarter", parent: None },
+    SubdivisionData { code: "MC-SO", name: "La Source", kind: "Quarter", parent: None },
+    SubdivisionData { code: "MC-SP", name: "Spélugues", kind: "Quarter", parent: None },
+    SubdivisionData { code: "MC-SR", name: "Saint-Roman", kind: "Quarter", parent: None },
+    SubdivisionData { code: "MC-VR", name: "Vallon de la Rousse", kind: "Quarter", parent: None },
+    SubdivisionData { code: "MD-AN", name: "Anenii Noi", kind: "District", parent: None },
+    SubdivisionData { code: "MD-BA", name: "Bălți", kind: "City", parent: None },
+    SubdivisionData { code: "MD-BD", name: "Bender", kind: "City", parent: None },
+    SubdivisionData { code: "MD-BR", name: "Briceni", kind: "District", parent: None },
+    SubdivisionData { code: "MD-BS", name: "Basarabeasca", kind: "District", parent: None },
+    SubdivisionData { code: "MD-CA", name: "Cahul", kind: "District", parent: None },
+    SubdivisionData { code: "MD-CL", name: "Călărași", kind: "District", parent: None },
+    SubdivisionData { code: "MD-CM", name: "Cimișlia", kind: "District", parent: None },
+    SubdivisionData { code: "MD-CR", name: "Criuleni", kind: "District", parent: None },
+    SubdivisionData { code: "MD-CS", name: "Căușeni", kind: "District", parent: None },
+    SubdivisionData { code: "MD-CT", name: "Cantemir", kind: "District", parent: None },
+    SubdivisionData { code: "MD-CU", name: "Chișinău", kind: "City", parent: None },
+    SubdivisionData { code: "MD-DO", name: "Dondușeni", kind: "District", parent: None },
+    SubdivisionData { code: "MD-DR", name: "Drochia", kind: "District", parent: None },
+    SubdivisionData { code: "MD-DU", name: "Dubăsari", kind: "District", parent: None },
+    SubdivisionData { code: "MD-ED", name: "Edineț", kind: "District", parent: None },
+    SubdivisionData { code: "MD-FA", name: "Fălești", kind: "District", parent: None },
+    SubdivisionData { code: "MD-FL", name: "Florești", kind: "District", parent: None },
+    SubdivisionData { code: "MD-GA", name: "Găgăuzia, Unitatea teritorială autonomă (UTAG)", kind: "Autonomous territorial unit", parent: None },
+    SubdivisionData { code: "MD-GL", name: "Glodeni", kind: "District", parent: None },
+    SubdivisionData { code: "MD-HI", name: "Hîncești", kind: "District", parent: None },
+    SubdivisionData { code: "MD-IA", name: "Ialoveni", kind: "District", parent: None },
+    SubdivisionData { code: "MD-LE", name: "Leova", kind: "District", parent: None },
+    SubdivisionData { code: "MD-NI", name: "Nisporeni", kind: "District", parent: None },
+    SubdivisionData { code: "MD-OC", name: "Ocnița", kind: "District", parent: None },
+    SubdivisionData { code: "MD-OR", name: "Orhei", kind: "District", parent: None },
+    SubdivisionData { code: "MD-RE", name: "Rezina", kind: "District", parent: None },
+    SubdivisionData { code: "MD-RI", name: "Rîșcani", kind: "District", parent: None },
+    SubdivisionData { code: "MD-SD", name: "Șoldănești", kind: "District", parent: None },
+    SubdivisionData { code: "MD-SI", name: "Sîngerei", kind: "District", parent: None },
+    SubdivisionData { code: "MD-SN", name: "Stînga Nistrului, unitatea teritorială din", kind: "Territorial unit", parent: None },
+    SubdivisionData { code: "MD-SO", name: "Soroca", kind: "District", parent: None },
+    SubdivisionData { code: "MD-ST", name: "Strășeni", kind: "District", parent: None },
+    SubdivisionData { code: "MD-SV", name: "Ștefan Vodă", kind: "District", parent: None },
+    SubdivisionData { code: "MD-TA", name: "Taraclia", kind: "District", parent: None },
+    SubdivisionData { code: "MD-TE", name: "Telenești", kind: "District", parent: None },
+    SubdivisionData { code: "MD-UN", name: "Ungheni", kind: "District", parent: None },
+    SubdivisionData { code: "ME-01", name: "Andrijevica", kind: "Municipality", parent: None },
+    SubdivisionData { code: "ME-02", name: "Bar", kind: "Municipality", parent: None },
+    SubdivisionData { code: "ME-03", name: "Berane", kind: "Municipality", parent: None },
+    SubdivisionData { code: "ME-04", name: "Bijelo Polje", kind: "Municipality", parent: None },
+    SubdivisionData { code: "ME-05", name: "Budva", kind: "Municipality", parent: None },
+    SubdivisionData { code: "ME-06", name: "Cetinje", kind: "Municipality", parent: None },
+    SubdivisionData { code: "ME-07", name: "Danilovgrad", kind: "Municipality", parent: None },
+    SubdivisionData { code: "ME-08", name: "Herceg-Novi", kind: "Municipality", parent: None },
+    SubdivisionData { code: "ME-09", name: "Kolašin", kind: "Municipality", parent: None },
+    SubdivisionData { code: "ME-10", name: "Kotor", kind: "Municipality", parent: None },
+    SubdivisionData { code: "ME-11", name: "Mojkovac", kind: "Municipality", parent: None },
+    SubdivisionData { code: "ME-12", name: "Nikšić", kind: "Municipality", parent: None },
+    SubdivisionData { code: "ME-13", name: "Plav", kind: "Municipality", parent: None },
+    SubdivisionData { code: "ME-14", name: "Pljevlja", kind: "Municipality", parent: None },
+    SubdivisionData { code: "ME-15", name: "Plužine", kind: "Municipality", parent: None },
+    SubdivisionData { code: "ME-16", name: "Podgorica", kind: "Municipality", parent: None },
+    SubdivisionData { code: "ME-17", name: "Rožaje", kind: "Municipality", parent: None },
+    SubdivisionData { code: "ME-18", name: "Šavnik", kind: "Municipality", parent: None },
+    SubdivisionData { code: "ME-19", name: "Tivat", kind: "Municipality", parent: None },
+    SubdivisionData { code: "ME-20", name: "Ulcinj", kind: "Municipality", parent: None },
+    SubdivisionData { code: "ME-21", name: "Žabljak", kind: "Municipality", parent: None },
+    SubdivisionData { code: "ME-22", name: "Gusinje", kind: "Municipality", parent: None },
+    SubdivisionData { code: "ME-23", name: "Petnjica", kind: "Municipality", parent: None },
+    SubdivisionData { code: "ME-24", name: "Tuzi", kind: "Municipality", parent: None },
+    SubdivisionData { code: "MG-A", name: "Toamasina", kind: "Province", parent: None },
+    SubdivisionData { code: "MG-D", name: "Antsiranana", kind: "Province", parent: None },
+    SubdivisionData { code: "MG-F", name: "Fianarantsoa", kind: "Province", parent: None },
+    SubdivisionData { code: "MG-M", name: "Mahajanga", kind: "Province", parent: None },
+    SubdivisionData { code: "MG-T", name: "Antananarivo", kind: "Province", parent: None },
+    SubdivisionData { code: "MG-U", name: "Toliara", kind: "Province", parent: None },
+    SubdivisionData { code: "MH-ALK", name: "Ailuk", kind: "Municipality", parent: Some("MH-T") },
+    SubdivisionData { code: "MH-ALL", name: "Ailinglaplap", kind: "Municipality", parent: Some("MH-L") },
+    SubdivisionData { code: "MH-ARN", name: "Arno", kind: "Municipality", parent: Some("MH-T") },
+    SubdivisionData { code: "MH-AUR", name: "Aur", kind: "Municipality", parent: Some("MH-T") },
+    SubdivisionData { code: "MH-EBO", name: "Ebon", kind: "Municipality", parent: Some("MH-L") },
+    SubdivisionData { code: "MH-ENI", name: "Enewetak & Ujelang", kind: "Municipality", parent: Some("MH-L") },
+    SubdivisionData { code: "MH-JAB", name: "Jabat", kind: "Municipality", parent: Some("MH-L") },
+    SubdivisionData { code: "MH-JAL", name: "Jaluit", kind: "Municipality", parent: Some("MH-L") },
+    SubdivisionData { code: "MH-KIL", name: "Bikini & Kili", kind: "Municipality", parent: Some("MH-L") },
+    SubdivisionData { code: "MH-KWA", name: "Kwajalein", kind: "Municipality", parent: Some("MH-L") },
+    SubdivisionData { code: "MH-L", name: "Ralik chain", kind: "Chain (of islands)", parent: None },
+    SubdivisionData { code: "MH-LAE", name: "Lae", kind: "Municipality", parent: Some("MH-L") },
+    SubdivisionData { code: "MH-LIB", name: "Lib", kind: "Municipality", parent: Some("MH-L") },
+    SubdivisionData { code: "MH-LIK", name: "Likiep", kind: "Municipality", parent: Some("MH-T") },
+    SubdivisionData { code: "MH-MAJ", name: "Majuro", kind: "Municipality", parent: Some("MH-T") },
+    SubdivisionData { code: "MH-MAL", name: "Maloelap", kind: "Municipality", parent: Some("MH-T") },
+    SubdivisionData { code: "MH-MEJ", name: "Mejit", kind: "Municipality", parent: Some("MH-T") },
+    SubdivisionData { code: "MH-MIL", name: "Mili", kind: "Municipality", parent: Some("MH-T") },
+    SubdivisionData { code: "MH-NMK", name: "Namdrik", kind: "Municipality", parent: Some("MH-L") },
+    SubdivisionData { code: "MH-NMU", name: "Namu", kind: "Municipality", parent: Some("MH-L") },
+    SubdivisionData { code: "MH-RON", name: "Rongelap", kind: "Municipality", parent: Some("MH-L") },
+    SubdivisionData { code: "MH-T", name: "Ratak chain", kind: "Chain (of islands)", parent: None },
+    SubdivisionData { code: "MH-UJA", name: "Ujae", kind: "Municipality", parent: Some("MH-L") },
+    SubdivisionData { code: "MH-UTI", name: "Utrik", kind: "Municipality", parent: Some("MH-T") },
+    SubdivisionData { code: "MH-WTH", name: "Wotho", kind: "Municipality", parent: Some("MH-L") },
+    SubdivisionData { code: "MH-WTJ", name: "Wotje", kind: "Municipality", parent: Some("MH-T") },
+    SubdivisionData { code: "MK-101", name: "Veles", kind: "Municipality", parent: None },
+    SubdivisionData { code: "MK-102", name: "Gradsko", kind: "Municipality", parent: None },
+    SubdivisionData { code: "MK-103", name: "Demir Kapija", kind: "Municipality", parent: None },
+    SubdivisionData { code: "MK-104", name: "Kavadarci", kind: "Municipality", parent: None },
+    SubdivisionData { code: "MK-105", name: "Lozovo", kind: "Municipality", parent: None },
+    SubdivisionData { code: "MK-106", name: "Negotino", kind: "Municipality", parent: None },
+    SubdivisionData { code: "MK-107", name: "Rosoman", kind: "Municipality", parent: None },
+    SubdivisionData { code: "MK-108", name: "Sveti Nikole", kind: "Municipality", parent: None },
+    SubdivisionData { code: "MK-109", name: "Čaška", kind: "Municipality", parent: None },
+    SubdivisionData { code: "MK-201", name: "Berovo", kind: "Municipality", parent: None },
+    SubdivisionData { code: "MK-202", name: "Vinica", kind: "Municipality", parent: None },
+    SubdivisionData { code: "MK-203", name: "Delčevo", kind: "Municipality", parent: None },
+    SubdivisionData { code: "MK-204", name: "Zrnovci", kind: "Municipality", parent: None },
+    SubdivisionData { code: "MK-205", name: "Karbinci", kind: "Municipality", parent: None },
+    SubdivisionData { code: "MK-206", name: "Kočani", kind: "Municipality", parent: None },
+    SubdivisionData { code: "MK-207", name: "Makedonska Kamenica", kind: "Municipality", parent: None },
+    SubdivisionData { code: "MK-208", name: "Pehčevo", kind: "Municipality", parent: None },
+    SubdivisionData { code: "MK-209", name: "Probištip", kind: "Municipality", parent: None },
+    SubdivisionData { code: "MK-210", name: "Češinovo-Obleševo", kind: "Municipality", parent: None },
+    SubdivisionData { code: "MK-211", name: "Štip", kind: "Municipality", parent: None },
+    SubdivisionData { code: "MK-301", name: "Vevčani", kind: "Municipality", parent: None },
+    SubdivisionData { code: "MK-303", name: "Debar", kind: "Municipality", parent: None },
+    SubdivisionData { code: "MK-304", name: "Debrca", kind: "Municipality", parent: None },
+    SubdivisionData { code: "MK-307", name: "Kičevo", kind: "Municipality", parent: None },
+    SubdivisionData { code: "MK-308", name: "Makedonski Brod", kind: "Municipality", parent: None },
+    SubdivisionData { code: "MK-310", name: "Ohrid", kind: "Municipality", parent: None },
+    SubdivisionData { code: "MK-311", name: "Plasnica", kind: "Municipality", parent: None },
+    SubdivisionData { code: "MK-312", name: "Struga", kind: "Municipality", parent: None },
+    SubdivisionData { code: "MK-313", name: "Centar Župa", kind: "Municipality", parent: None },
+    SubdivisionData { code: "MK-401", name: "Bogdanci", kind: "Municipality", parent: None },
+    SubdivisionData { code: "MK-402", name: "Bosilovo", kind: "Municipality", parent: None },
+    SubdivisionData { code: "MK-403", name: "Valandovo", kind: "Municipality", parent: None },
+    SubdivisionData { code: "MK-404", name: "Vasilevo", kind: "Municipality", parent: None },
+    SubdivisionData { code: "MK-405", name: "Gevgelija", kind: "Municipality", parent: None },
+    SubdivisionData { code: "MK-406", name: "Dojran", kind: "Municipality", parent: None },
+    SubdivisionData { code: "MK-407", name: "Konče", kind: "Municipality", parent: None },
+    SubdivisionData { code: "MK-408", name: "Novo Selo", kind: "Municipality", parent: None },
+    SubdivisionData { code: "MK-409", name: "Radoviš", kind: "Municipality", parent: None },
+    SubdivisionData { code: "MK-410", name: "Strumica", kind: "Municipality", parent: None },
+    SubdivisionData { code: "MK-501", name: "Bitola", kind: "Municipality", parent: None },
+    SubdivisionData { code: "MK-502", name: "Demir Hisar", kind: "Municipality", parent: None },
+    SubdivisionData { code: "MK-503", name: "Dolneni", kind: "Municipality", parent: None },
+    SubdivisionData { code: "MK-504", name: "Krivogaštani", kind: "Municipality", parent: None },
+    SubdivisionData { code: "MK-505", name: "Kruševo", kind: "Municipality", parent: None },
+    SubdivisionData { code: "MK-506", name: "Mogila", kind: "Municipality", parent: None },
+    SubdivisionData { code: "MK-507", name: "Novaci", kind: "Municipality", parent: None },
+    SubdivisionData { code: "MK-508", name: "Prilep", kind: "Municipality", parent: None },
+    SubdivisionData { code: "MK-509", name: "Resen", kind: "Municipality", parent: None },
+    SubdivisionData { code: "MK-601", name: "Bogovinje", kind: "Municipality", parent: None },
+    SubdivisionData { code: "MK-602", name: "Brvenica", kind: "Municipality", parent: None },
+    SubdivisionData { code: "MK-603", name: "Vrapčište", kind: "Municipality", parent: None },
+    SubdivisionData { code: "MK-604", name: "Gostivar", kind: "Municipality", parent: None },
+    SubdivisionData { code: "MK-605", name: "Želino", kind: "Municipality", parent: None },
+    SubdivisionData { code: "MK-606", name: "Jegunovce", kind: "Municipality", parent: None },
+    SubdivisionData { code: "MK-607", name: "Mavrovo i Rostuše", kind: "Municipality", parent: None },
+    SubdivisionData { code: "MK-608", name: "Tearce", kind: "Municipality", parent: None },
+    SubdivisionData { code: "MK-609", name: "Tetovo", kind: "Municipality", parent: None },
+    SubdivisionData { code: "MK-701", name: "Kratovo", kind: "Municipality", parent: None },
+    SubdivisionData { code: "MK-702", name: "Kriva Palanka", kind: "Municipality", parent: None },
+    SubdivisionData { code: "MK-703", name: "Kumanovo", kind: "Municipality", parent: None },
+    SubdivisionData { code: "MK-704", name: "Lipkovo", kind: "Municipality", parent: None },
+    SubdivisionData { code: "MK-705", name: "Rankovce", kind: "Municipality", parent: None },
+    SubdivisionData { code: "MK-706", name: "Staro Nagoričane", kind: "Municipality", parent: None },
+    SubdivisionData { code: "MK-801", name: "Aerodrom †", kind: "Municipality", parent: None },
+    SubdivisionData { code: "MK-802", name: "Aračinovo", kind: "Municipality", parent: None },
+    SubdivisionData { code: "MK-803", name: "Butel †", kind: "Municipality", parent: None },
+    SubdivisionData { code: "MK-804", name: "Gazi Baba †", kind: "Municipality", parent: None },
+    SubdivisionData { code: "MK-805", name: "Gjorče Petrov †", kind: "Municipality", parent: None },
+    SubdivisionData { code: "MK-806", name: "Zelenikovo", kind: "Municipality", parent: None },
+    SubdivisionData { code: "MK-807", name: "Ilinden", kind: "Municipality", parent: None },
+    SubdivisionData { code: "MK-808", name: "Karpoš †", kind: "Municipality", parent: None },
+    SubdivisionData { code: "MK-809", name: "Kisela Voda †", kind: "Municipality", parent: None },
+    SubdivisionData { code: "MK-810", name: "Petrovec", kind: "Municipality", parent: None },
+    SubdivisionData { code: "MK-811", name: "Saraj †", kind: "Municipality", parent: None },
+    SubdivisionData { code: "MK-812", name: "Sopište", kind: "Municipality", parent: None },
+    SubdivisionData { code: "MK-813", name: "Studeničani", kind: "Municipality", parent: None },
+    SubdivisionData { code: "MK-814", name: "Centar †", kind: "Municipality", parent: None },
+    SubdivisionData { code: "MK-815", name: "Čair †", kind: "Municipality", parent: None },
+    SubdivisionData { code: "MK-816", name: "Čučer-Sandevo", kind: "Municipality", parent: None },
+    SubdivisionData { code: "MK-817", name: "Šuto Orizari †", kind: "Municipality", parent: None },
+    SubdivisionData { code: "ML-1", name: "Kayes", kind: "Region", parent: None },
+    SubdivisionData { code: "ML-10", name: "Taoudénit", kind: "Region", parent: None },
+    SubdivisionData { code: "ML-2", name: "Koulikoro", kind: "Region", parent: None },
+    SubdivisionData { code: "ML-3", name: "Sikasso", kind: "Region", parent: None },
+    SubdivisionData { code: "ML-4", name: "Ségou", kind: "Region", parent: None },
+    SubdivisionData { code: "ML-5", name: "Mopti", kind: "Region", parent: None },
+    SubdivisionData { code: "ML-6", name: "Tombouctou", kind: "Region", parent: None },
+    SubdivisionData { code: "ML-7", name: "Gao", kind: "Region", parent: None },
+    SubdivisionData { code: "ML-8", name: "Kidal", kind: "Region", parent: None },
+    SubdivisionData { code: "ML-9", name: "Ménaka", kind: "Region", parent: None },
+    SubdivisionData { code: "ML-BKO", name: "Bamako", kind: "District", parent: None },
+    SubdivisionData { code: "MM-01", name: "Sagaing", kind: "Region", parent: None },
+    SubdivisionData { code: "MM-02", name: "Bago", kind: "Region", parent: None },
+    SubdivisionData { code: "MM-03", name: "Magway", kind: "Region", parent: None },
+    SubdivisionData { code: "MM-04", name: "Mandalay", kind: "Region", parent: None },
+    SubdivisionData { code: "MM-05", name: "Tanintharyi", kind: "Region", parent: None },
+    SubdivisionData { code: "MM-06", name: "Yangon", kind: "Region", parent: None },
+    SubdivisionData { code: "MM-07", name: "Ayeyarwady", kind: "Region", parent: None },
+    SubdivisionData { code: "MM-11", name: "Kachin", kind: "State", parent: None },
+    SubdivisionData { code: "MM-12", name: "Kayah", kind: "State", parent: None },
+    SubdivisionData { code: "MM-13", name: "Kayin", kind: "State", parent: None },
+    SubdivisionData { code: "MM-14", name: "Chin", kind: "State", parent: None },
+    SubdivisionData { code: "MM-15", name: "Mon", kind: "State", parent: None },
+    SubdivisionData { code: "MM-16", name: "Rakhine", kind: "State", parent: None },
+    SubdivisionData { code: "MM-17", name: "Shan", kind: "State", parent: None },
+    SubdivisionData { code: "MM-18", name: "Nay Pyi Taw", kind: "Union territory", parent: None },
+    SubdivisionData { code: "MN-035", name: "Orhon", kind: "Province", parent: None },
+    SubdivisionData { code: "MN-037", name: "Darhan uul", kind: "Province", parent: None },
+    SubdivisionData { code: "MN-039", name: "Hentiy", kind: "Province", parent: None },
+    SubdivisionData { code: "MN-041", name: "Hövsgöl", kind: "Province", parent: None },
+    SubdivisionData { code: "MN-043", name: "Hovd", kind: "Province", parent: None },
+    SubdivisionData { code: "MN-046", name: "Uvs", kind: "Province", parent: None },
+    SubdivisionData { code: "MN-047", name: "Töv", kind: "Province", parent: None },
+    SubdivisionData { code: "MN-049", name: "Selenge", kind: "Province", parent: None },
+    SubdivisionData { code: "MN-051", name: "Sühbaatar", kind: "Province", parent: None },
+    SubdivisionData { code: "MN-053", name: "Ömnögovĭ", kind: "Province", parent: None },
+    SubdivisionData { code: "MN-055", name: "Övörhangay", kind: "Province", parent: None },
+    SubdivisionData { code: "MN-057", name: "Dzavhan", kind: "Province", parent: None },
+    SubdivisionData { code: "MN-059", name: "Dundgovĭ", kind: "Province", parent: None },
+    SubdivisionData { code: "MN-061", name: "Dornod", kind: "Province", parent: None },
+    SubdivisionData { code: "MN-063", name: "Dornogovĭ", kind: "Province", parent: None },
+    SubdivisionData { code: "MN-064", name: "Govĭ-Sümber", kind: "Province", parent: None },
+    SubdivisionData { code: "MN-065", name: "Govĭ-Altay", kind: "Province", parent: None },
+    SubdivisionData { code: "MN-067", name: "Bulgan", kind: "Province", parent: None },
+    SubdivisionData { code: "MN-069", name: "Bayanhongor", kind: "Province", parent: None },
+    SubdivisionData { code: "MN-071", name: "Bayan-Ölgiy", kind: "Province", parent: None },
+    SubdivisionData { code: "MN-073", name: "Arhangay", kind: "Province", parent: None },
+    SubdivisionData { code: "MN-1", name: "Ulaanbaatar", kind: "Capital city", parent: None },
+    SubdivisionData { code: "MR-01", name: "Hodh ech Chargui", kind: "Region", parent: None },
+    SubdivisionData { code: "MR-02", name: "Hodh el Gharbi", kind: "Region", parent: None },
+    SubdivisionData { code: "MR-03", name: "Assaba", kind: "Region", parent: None },
+    SubdivisionData { code: "MR-04", name: "Gorgol", kind: "Region", parent: None },
+    SubdivisionData { code: "MR-05", name: "Brakna", kind: "Region", parent: None },
+    SubdivisionData { code: "MR-06", name: "Trarza", kind: "Region", parent: None },
+    SubdivisionData { code: "MR-07", name: "Adrar", kind: "Region", parent: None },
+    SubdivisionData { code: "MR-08", name: "Dakhlet Nouâdhibou", kind: "Region", parent: None },
+    SubdivisionData { code: "MR-09", name: "Tagant", kind: "Region", parent: None },
+    SubdivisionData { code: "MR-10", name: "Guidimaka", kind: "Region", parent: None },
+    SubdivisionData { code: "MR-11", name: "Tiris Zemmour", kind: "Region", parent: None },
+    SubdivisionData { code: "MR-12", name: "Inchiri", kind: "Region", parent: None },
+    SubdivisionData { code: "MR-13", name: "Nouakchott Ouest", kind: "Region", parent: None },
+    SubdivisionData { code: "MR-14", name: "Nouakchott Nord", kind: "Region", parent: None },
+    SubdivisionData { code: "MR-15", name: "Nouakchott Sud", kind: "Region", parent: None },
+    SubdivisionData { code: "MT-01", name: "Attard", kind: "Local council", parent: None },
+    SubdivisionData { code: "MT-02", name: "Balzan", kind: "Local council", parent: None },
+    SubdivisionData { code: "MT-03", name: "Birgu", kind: "Local council", parent: None },
+    SubdivisionData { code: "MT-04", name: "Birkirkara", kind: "Local council", parent: None },
+    SubdivisionData { code: "MT-05", name: "Birżebbuġa", kind: "Local council", parent: None },
+    SubdivisionData { code: "MT-06", name: "Bormla", kind: "Local council", parent: None },
+    SubdivisionData { code: "MT-07", name: "Dingli", kind: "Local council", parent: None },
+    SubdivisionData { code: "MT-08", name: "Fgura", kind: "Local council", parent: None },
+    SubdivisionData { code: "MT-09", name: "Floriana", kind: "Local council", parent: None },
+    SubdivisionData { code: "MT-10", name: "Fontana", kind: "Local council", parent: None },
+    SubdivisionData { code: "MT-11", name: "Gudja", kind: "Local council", parent: None },
+    SubdivisionData { code: "MT-12", name: "Gżira", kind: "Local council", parent: None },
+    SubdivisionData { code: "MT-13", name: "Għajnsielem", kind: "Local council", parent: None },
+    SubdivisionData { code: "MT-14", name: "Għarb", kind: "Local council", parent: None },
+    SubdivisionData { code: "MT-15", name: "Għargħur", kind: "Local council", parent: None },
+    SubdivisionData { code: "MT-16", name: "Għasri", kind: "Local council", parent: None },
+    SubdivisionData { code: "MT-17", name: "Għaxaq", kind: "Local council", parent: None },
+    SubdivisionData { code: "MT-18", name: "Ħamrun", kind: "Local council", parent: None },
+    SubdivisionData { code: "MT-19", name: "Iklin", kind: "Local council", parent: None },
+    SubdivisionData { code: "MT-20", name: "Isla", kind: "Local council", parent: None },
+    SubdivisionData { code: "MT-21", name: "Kalkara", kind: "Local council", parent: None },
+    SubdivisionData { code: "MT-22", name: "Kerċem", kind: "Local council", parent: None },
+    SubdivisionData { code: "MT-23", name: "Kirkop", kind: "Local council", parent: None },
+    SubdivisionData { code: "MT-24", name: "Lija", kind: "Local council", parent: None },
+    SubdivisionData { code: "MT-25", name: "Luqa", kind: "Local council", parent: None },
+    SubdivisionData { code: "MT-26", name: "Marsa", kind: "Local council", parent: None },
+    SubdivisionData { code: "MT-27", name: "Marsaskala", kind: "Local council", parent: None },
+    SubdivisionData { code: "MT-28", name: "Marsaxlokk", kind: "Local council", parent: None },
+    SubdivisionData { code: "MT-29", name: "Mdina", kind: "Local council", parent: None },
+    SubdivisionData { code: "MT-30", name: "Mellieħa", kind: "Local council", parent: None },
+    SubdivisionData { code: "MT-31", name: "Mġarr", kind: "Local council", parent: None },
+    SubdivisionData { code: "MT-32", name: "Mosta", kind: "Local council", parent: None },
+    SubdivisionData { code: "MT-33", name: "Mqabba", kind: "Local council", parent: None },
+    SubdivisionData { code: "MT-34", name: "Msida", kind: "Local council", parent: None },
+    SubdivisionData { code: "MT-35", name: "Mtarfa", kind: "Local council", parent: None },
+    SubdivisionData { code: "MT-36", name: "Munxar", kind: "Local council", parent: None },
+    SubdivisionData { code: "MT-37", name: "Nadur", kind: "Local council", parent: None },
+    SubdivisionData { code: "MT-38", name: "Naxxar", kind: "Local council", parent: None },
+    SubdivisionData { code: "MT-39", name: "Paola", kind: "Local council", parent: None },
+    SubdivisionData { code: "MT-40", name: "Pembroke", kind: "Local council", parent: None },
+    SubdivisionData { code: "MT-41", name: "Pietà", kind: "Local council", parent: None },
+    SubdivisionData { code: "MT-42", name: "Qala", kind: "Local council", parent: None },
+    SubdivisionData { code: "MT-43", name: "Qormi", kind: "Local council", parent: None },
+    SubdivisionData { code: "MT-44", name: "Qrendi", kind: "Local council", parent: None },
+    SubdivisionData { code: "MT-45", name: "Rabat Gozo", kind: "Local council", parent: None },
+    SubdivisionData { code: "MT-46", name: "Rabat Malta", kind: "Local council", parent: None },
+    SubdivisionData { code: "MT-47", name: "Safi", kind: "Local council", parent: None },
+    SubdivisionData { code: "MT-48", name: "Saint Julian's", kind: "Local council", parent: None },
+    SubdivisionData { code: "MT-49", name: "Saint John", kind: "Local council", parent: None },
+    SubdivisionData { code: "MT-50", name: "Saint Lawrence", kind: "Local council", parent: None },
+    SubdivisionData { code: "MT-51", name: "Saint Paul's Bay", kind: "Local council", parent: None },
+    SubdivisionData { code: "MT-52", name: "Sannat", kind: "Local council", parent: None },
+    SubdivisionData { code: "MT-53", name: "Saint Lucia's", kind: "Local council", parent: None },
+    SubdivisionData { code: "MT-54", name: "Santa Venera", kind: "Local council", parent: None },
+    SubdivisionData { code: "MT-55", name: "Siġġiewi", kind: "Local council", parent: None },
+    SubdivisionData { code: "MT-56", name: "Sliema", kind: "Local council", parent: None },
+    SubdivisionData { code: "MT-57", name: "Swieqi", kind: "Local council", parent: None },
+    SubdivisionData { code: "MT-58", name: "Ta' Xbiex", kind: "Local council", parent: None },
+    SubdivisionData { code: "MT-59", name: "Tarxien", kind: "Local council", parent: None },
+    SubdivisionData { code: "MT-60", name: "Valletta", kind: "Local council", parent: None },
+    SubdivisionData { code: "MT-61", name: "Xagħra", kind: "Local council", parent: None },
+    SubdivisionData { code: "MT-62", name: "Xewkija", kind: "Local council", parent: None },
+    SubdivisionData { code: "MT-63", name: "Xgħajra", kind: "Local council", parent: None },
+    SubdivisionData { code: "MT-64", name: "Żabbar", kind: "Local council", parent: None },
+    SubdivisionData { code: "MT-65", name: "Żebbuġ Gozo", kind: "Local council", parent: None },
+    SubdivisionData { code: "MT-66", name: "Żebbuġ Malta", kind: "Local council", parent: None },
+    SubdivisionData { code: "MT-67", name: "Żejtun", kind: "Local council", parent: None },
+    SubdivisionData { code: "MT-68", name: "Żurrieq", kind: "Local council", parent: None },
+    SubdivisionData { code: "MU-AG", name: "Agalega Islands", kind: "Dependency", parent: None },
+    SubdivisionData { code: "MU-BL", name: "Black River", kind: "District", parent: None },
+    SubdivisionData { code: "MU-CC", name: "Cargados Carajos Shoals", kind: "Dependency", parent: None },
+    SubdivisionData { code: "MU-FL", name: "Flacq", kind: "District", parent: None },
+    SubdivisionData { code: "MU-GP", name: "Grand Port", kind: "District", parent: None },
+    SubdivisionData { code: "MU-MO", name: "Moka", kind: "District", parent: None },
+    SubdivisionData { code: "MU-PA", name: "Pamplemousses", kind: "District", parent: None },
+    SubdivisionData { code: "MU-PL", name: "Port Louis", kind: "District", parent: None },
+    SubdivisionData { code: "MU-PW", name: "Plaines Wilhems", kind: "District", parent: None },
+    SubdivisionData { code: "MU-RO", name: "Rodrigues Island", kind: "Dependency", parent: None },
+    SubdivisionData { code: "MU-RR", name: "Rivière du Rempart", kind: "District", parent: None },
+    SubdivisionData { code: "MU-SA", name: "Savanne", kind: "District", parent: None },
+    SubdivisionData { code: "MV-00", name: "South Ari Atoll", kind: "Administrative atoll", parent: None },
+    SubdivisionData { code: "MV-01", name: "Addu City", kind: "City", parent: None },
+    SubdivisionData { code: "MV-02", name: "North Ari Atoll", kind: "Administrative atoll", parent: None },
+    SubdivisionData { code: "MV-03", name: "Faadhippolhu", kind: "Administrative atoll", parent: None },
+    SubdivisionData { code: "MV-04", name: "Felidhu Atoll", kind: "Administrative atoll", parent: None },
+    SubdivisionData { code: "MV-05", name: "Hahdhunmathi", kind: "Administrative atoll", parent: None },
+    SubdivisionData { code: "MV-07", name: "North Thiladhunmathi", kind: "Administrative atoll", parent: None },
+    SubdivisionData { code: "MV-08", name: "Kolhumadulu", kind: "Administrative atoll", parent: None },
+    SubdivisionData { code: "MV-12", name: "Mulaku Atoll", kind: "Administrative atoll", parent: None },
+    SubdivisionData { code: "MV-13", name: "North Maalhosmadulu", kind: "Administrative atoll", parent: None },
+    SubdivisionData { code: "MV-14", name: "North Nilandhe Atoll", kind: "Administrative atoll", parent: None },
+    SubdivisionData { code: "MV-17", name: "South Nilandhe Atoll", kind: "Administrative atoll", parent: None },
+    SubdivisionData { code: "MV-20", name: "South Maalhosmadulu", kind: "Administrative atoll", parent: None },
+    SubdivisionData { code: "MV-23", name: "South Thiladhunmathi", kind: "Administrative atoll", parent: None },
+    SubdivisionData { code: "MV-24", name: "North Miladhunmadulu", kind: "Administrative atoll", parent: None },
+    SubdivisionData { code: "MV-25", name: "South Miladhunmadulu", kind: "Administrative atoll", parent: None },
+    SubdivisionData { code: "MV-26", name: "Male Atoll", kind: "Administrative atoll", parent: None },
+    SubdivisionData { code: "MV-27", name: "North Huvadhu Atoll", kind: "Administrative atoll", parent: None },
+    SubdivisionData { code: "MV-28", name: "South Huvadhu Atoll", kind: "Administrative atoll", parent: None },
+    SubdivisionData { code: "MV-29", name: "Fuvammulah", kind: "Administrative atoll", parent: None },
+    SubdivisionData { code: "MV-MLE", name: "Male", kind: "City", parent: None },
+    SubdivisionData { code: "MW-BA", name: "Balaka", kind: "District", parent: Some("MW-S") },
+    SubdivisionData { code: "MW-BL", name: "Blantyre", kind: "District", parent: Some("MW-S") },
+    SubdivisionData { code: "MW-C", name: "Central Region", kind: "Region", parent: None },
+    SubdivisionData { code: "MW-CK", name: "Chikwawa", kind: "District", parent: Some("MW-S") },
+    SubdivisionData { code: "MW-CR", name: "Chiradzulu", kind: "District", parent: Some("MW-S") },
+    SubdivisionData { code: "MW-CT", name: "Chitipa", kind: "District", parent: Some("MW-N") },
+    SubdivisionData { code: "MW-DE", name: "Dedza", kind: "District", parent: Some("MW-C") },
+    SubdivisionData { code: "MW-DO", name: "Dowa", kind: "District", parent: Some("MW-C") },
+    SubdivisionData { code: "MW-KR", name: "Karonga", kind: "District", parent: Some("MW-N") },
+    SubdivisionData { code: "MW-KS", name: "Kasungu", kind: "District", parent: Some("MW-C") },
+    SubdivisionData { code: "MW-LI", name: "Lilongwe", kind: "District", parent: Some("MW-C") },
+    SubdivisionData { code: "MW-LK", name: "Likoma", kind: "District", parent: Some("MW-N") },
+    SubdivisionData { code: "MW-MC", name: "Mchinji", kind: "District", parent: Some("MW-C") },
+    SubdivisionData { code: "MW-MG", name: "Mangochi", kind: "District", parent: Some("MW-S") },
+    SubdivisionData { code: "MW-MH", name: "Machinga", kind: "District", parent: Some("MW-S") },
+    SubdivisionData { code: "MW-MU", name: "Mulanje", kind: "District", parent: Some("MW-S") },
+    SubdivisionData { code: "MW-MW", name: "Mwanza", kind: "District", parent: Some("MW-S") },
+    SubdivisionData { code: "MW-MZ", name: "Mzimba", kind: "District", parent: Some("MW-N") },
+    SubdivisionData { code: "MW-N", name: "Northern Region", kind: "Region", parent: None },
+    SubdivisionData { code: "MW-NB", name: "Nkhata Bay", kind: "District", parent: Some("MW-N") },
+    SubdivisionData { code: "MW-NE", name: "Neno", kind: "District", parent: Some("MW-S") },
+    SubdivisionData { code: "MW-NI", name: "Ntchisi", kind: "District", parent: Some("MW-C") },
+    SubdivisionData { code: "MW-NK", name: "Nkhotakota", kind: "District", parent: Some("MW-C") },
+    SubdivisionData { code: "MW-NS", name: "Nsanje", kind: "District", parent: Some("MW-S") },
+    SubdivisionData { code: "MW-NU", name: "Ntcheu", kind: "District", parent: Some("MW-C") },
+    SubdivisionData { code: "MW-PH", name: "Phalombe", kind: "District", parent: Some("MW-S") },
+    SubdivisionData { code: "MW-RU", name: "Rumphi", kind: "District", parent: Some("MW-N") },
+    SubdivisionData { code: "MW-S", name: "Southern Region", kind: "Region", parent: None },
+    SubdivisionData { code: "MW-SA", name: "Salima", kind: "District", parent: Some("MW-C") },
+    SubdivisionData { code: "MW-TH", name: "Thyolo", kind: "District", parent: Some("MW-S") },
+    SubdivisionData { code: "MW-ZO", name: "Zomba", kind: "District", parent: Some("MW-S") },
+    SubdivisionData { code: "MX-AGU", name: "Aguascalientes", kind: "State", parent: None },
+    SubdivisionData { code: "MX-BCN", name: "Baja California", kind: "State", parent: None },
+    SubdivisionData { code: "MX-BCS", name: "Baja California Sur", kind: "State", parent: None },
+    SubdivisionData { code: "MX-CAM", name: "Campeche", kind: "State", parent: None },
+    SubdivisionData { code: "MX-CHH", name: "Chihuahua", kind: "State", parent: None },
+    SubdivisionData { code: "MX-CHP", name: "Chiapas", kind: "State", parent: None },
+    SubdivisionData { code: "MX-CMX", name: "Ciudad de México", kind: "Federal district", parent: None },
+    SubdivisionData { code: "MX-COA", name: "Coahuila de Zaragoza", kind: "State", parent: None },
+    SubdivisionData { code: "MX-COL", name: "Colima", kind: "State", parent: None },
+    SubdivisionData { code: "MX-DUR", name: "Durango", kind: "State", parent: None },
+    SubdivisionData { code: "MX-GRO", name: "Guerrero", kind: "State", parent: None },
+    SubdivisionData { code: "MX-GUA", name: "Guanajuato", kind: "State", parent: None },
+    SubdivisionData { code: "MX-HID", name: "Hidalgo", kind: "State", parent: None },
+    SubdivisionData { code: "MX-JAL", name: "Jalisco", kind: "State", parent: None },
+    SubdivisionData { code: "MX-MEX", name: "México", kind: "State", parent: None },
+    SubdivisionData { code: "MX-MIC", name: "Michoacán de Ocampo", kind: "State", parent: None },
+    SubdivisionData { code: "MX-MOR", name: "Morelos", kind: "State", parent: None },
+    SubdivisionData { code: "MX-NAY", name: "Nayarit", kind: "State", parent: None },
+    SubdivisionData { code: "MX-NLE", name: "Nuevo León", kind: "State", parent: None },
+    SubdivisionData { code: "MX-OAX", name: "Oaxaca", kind: "State", parent: None },
+    SubdivisionData { code: "MX-PUE", name: "Puebla", kind: "State", parent: None },
+    SubdivisionData { code: "MX-QUE", name: "Querétaro", kind: "State", parent: None },
+    SubdivisionData { code: "MX-ROO", name: "Quintana Roo", kind: "State", parent: None },
+    SubdivisionData { code: "MX-SIN", name: "Sinaloa", kind: "State", parent: None },
+    SubdivisionData { code: "MX-SLP", name: "San Luis Potosí", kind: "State", parent: None },
+    SubdivisionData { code: "MX-SON", name: "Sonora", kind: "State", parent: None },
+    SubdivisionData { code: "MX-TAB", name: "Tabasco", kind: "State", parent: None },
+    SubdivisionData { code: "MX-TAM", name: "Tamaulipas", kind: "State", parent: None },
+    SubdivisionData { code: "MX-TLA", name: "Tlaxcala", kind: "State", parent: None },
+    SubdivisionData { code: "MX-VER", name: "Veracruz de Ignacio de la Llave", kind: "State", parent: None },
+    SubdivisionData { code: "MX-YUC", name: "Yucatán", kind: "State", parent: None },
+    SubdivisionData { code: "MX-ZAC", name: "Zacatecas", kind: "State", parent: None },
+    SubdivisionData { code: "MY-01", name: "Johor", kind: "State", parent: None },
+    SubdivisionData { code: "MY-02", name: "Kedah", kind: "State", parent: None },
+    SubdivisionData { code: "MY-03", name: "Kelantan", kind: "State", parent: None },
+    SubdivisionData { code: "MY-04", name: "Melaka", kind: "State", parent: None },
+    SubdivisionData { code: "MY-05", name: "Negeri Sembilan", kind: "State", parent: None },
+    SubdivisionData { code: "MY-06", name: "Pahang", kind: "State", parent: None },
+    SubdivisionData { code: "MY-07", name: "Pulau Pinang", kind: "State", parent: None },
+    SubdivisionData { code: "MY-08", name: "Perak", kind: "State", parent: None },
+    SubdivisionData { code: "MY-09", name: "Perlis", kind: "State", parent: None },
+    SubdivisionData { code: "MY-10", name: "Selangor", kind: "State", parent: None },
+    SubdivisionData { code: "MY-11", name: "Terengganu", kind: "State", parent: None },
+    SubdivisionData { code: "MY-12", name: "Sabah", kind: "State", parent: None },
+    SubdivisionData { code: "MY-13", name: "Sarawak", kind: "State", parent: None },
+    SubdivisionData { code: "MY-14", name: "Wilayah Persekutuan Kuala Lumpur", kind: "Federal territory", parent: None },
+    SubdivisionData { code: "MY-15", name: "Wilayah Persekutuan Labuan", kind: "Federal territory", parent: None },
+    SubdivisionData { code: "MY-16", name: "Wilayah Persekutuan Putrajaya", kind: "Federal territory", parent: None },
+    SubdivisionData { code: "MZ-A", name: "Niassa", kind: "Province", parent: None },
+    SubdivisionData { code: "MZ-B", name: "Manica", kind: "Province", parent: None },
+    SubdivisionData { code: "MZ-G", name: "Gaza", kind: "Province", parent: None },
+    SubdivisionData { code: "MZ-I", name: "Inhambane", kind: "Province", parent: None },
+    SubdivisionData { code: "MZ-L", name: "Maputo", kind: "Province", parent: None },
+    SubdivisionData { code: "MZ-MPM", name: "Maputo", kind: "City", parent: None },
+    SubdivisionData { code: "MZ-N", name: "Nampula", kind: "Province", parent: None },
+    SubdivisionData { code: "MZ-P", name: "Cabo Delgado", kind: "Province", parent: None },
+    SubdivisionData { code: "MZ-Q", name: "Zambézia", kind: "Province", parent: None },
+    SubdivisionData { code: "MZ-S", name: "Sofala", kind: "Province", parent: None },
+    SubdivisionData { code: "MZ-T", name: "Tete", kind: "Province", parent: None },
+    SubdivisionData { code: "NA-CA", name: "Zambezi", kind: "Region", parent: None },
+    SubdivisionData { code: "NA-ER", name: "Erongo", kind: "Region", parent: None },
+    SubdivisionData { code: "NA-HA", name: "Hardap", kind: "Region", parent: None },
+    SubdivisionData { code: "NA-KA", name: "//Karas", kind: "Region", parent: None },
+    SubdivisionData { code: "NA-KE", name: "Kavango East", kind: "Region", parent: None },
+    SubdivisionData { code: "NA-KH", name: "Khomas", kind: "Region", parent: None },
+    SubdivisionData { code: "NA-KU", name: "Kunene", kind: "Region", parent: None },
+    SubdivisionData { code: "NA-KW", name: "Kavango West", kind: "Region", parent: None },
+    SubdivisionData { code: "NA-OD", name: "Otjozondjupa", kind: "Region", parent: None },
+    SubdivisionData { code: "NA-OH", name: "Omaheke", kind: "Region", parent: None },
+    SubdivisionData { code: "NA-ON", name: "Oshana", kind: "Region", parent: None },
+    SubdivisionData { code: "NA-OS", name: "Omusati", kind: "Region", parent: None },
+    SubdivisionData { code: "NA-OT", name: "Oshikoto", kind: "Region", parent: None },
+    SubdivisionData { code: "NA-OW", name: "Ohangwena", kind: "Region", parent: None },
+    SubdivisionData { code: "NE-1", name: "Agadez", kind: "Region", parent: None },
+    SubdivisionData { code: "NE-2", name: "Diffa", kind: "Region", parent: None },
+    SubdivisionData { code: "NE-3", name: "Dosso", kind: "Region", parent: None },
+    SubdivisionData { code: "NE-4", name: "Maradi", kind: "Region", parent: None },
+    SubdivisionData { code: "NE-5", name: "Tahoua", kind: "Region", parent: None },
+    SubdivisionData { code: "NE-6", name: "Tillabéri", kind: "Region", parent: None },
+    SubdivisionData { code: "NE-7", name: "Zinder", kind: "Region", parent: None },
+    SubdivisionData { code: "NE-8", name: "Niamey", kind: "Urban community", parent: None },
+    SubdivisionData { code: "NG-AB", name: "Abia", kind: "State", parent: None },
+    SubdivisionData { code: "NG-AD", name: "Adamawa", kind: "State", parent: None },
+    SubdivisionData { code: "NG-AK", name: "Akwa Ibom", kind: "State", parent: None },
+    SubdivisionData { code: "NG-AN", name: "Anambra", kind: "State", parent: None },
+    SubdivisionData { code: "NG-BA", name: "Bauchi", kind: "State", parent: None },
+    SubdivisionData { code: "NG-BE", name: "Benue", kind: "State", parent: None },
+    SubdivisionData { code: "NG-BO", name: "Borno", kind: "State", parent: None },
+    SubdivisionData { code: "NG-BY", name: "Bayelsa", kind: "State", parent: None },
+    SubdivisionData { code: "NG-CR", name: "Cross River", kind: "State", parent: None },
+    SubdivisionData { code: "NG-DE", name: "Delta", kind: "State", parent: None },
+    SubdivisionData { code: "NG-EB", name: "Ebonyi", kind: "State", parent: None },
+    SubdivisionData { code: "NG-ED", name: "Edo", kind: "State", parent: None },
+    SubdivisionData { code: "NG-EK", name: "Ekiti", kind: "State", parent: None },
+    SubdivisionData { code: "NG-EN", name: "Enugu", kind: "State", parent: None },
+    SubdivisionData { code: "NG-FC", name: "Abuja Federal Capital Territory", kind: "Capital territory", parent: None },
+    SubdivisionData { code: "NG-GO", name: "Gombe", kind: "State", parent: None },
+    SubdivisionData { code: "NG-IM", name: "Imo", kind: "State", parent: None },
+    SubdivisionData { code: "NG-JI", name: "Jigawa", kind: "State", parent: None },
+    SubdivisionData { code: "NG-KD", name: "Kaduna", kind: "State", parent: None },
+    SubdivisionData { code: "NG-KE", name: "Kebbi", kind: "State", parent: None },
+    SubdivisionData { code: "NG-KN", name: "Kano", kind: "State", parent: None },
+    SubdivisionData { code: "NG-KO", name: "Kogi", kind: "State", parent: None },
+    SubdivisionData { code: "NG-KT", name: "Katsina", kind: "State", parent: None },
+    SubdivisionData { code: "NG-KW", name: "Kwara", kind: "State", parent: None },
+    SubdivisionData { code: "NG-LA", name: "Lagos", kind: "State", parent: None },
+    SubdivisionData { code: "NG-NA", name: "Nasarawa", kind: "State", parent: None },
+    SubdivisionData { code: "NG-NI", name: "Niger", kind: "State", parent: None },
+    SubdivisionData { code: "NG-OG", name: "Ogun", kind: "State", parent: None },
+    SubdivisionData { code: "NG-ON", name: "Ondo", kind: "State", parent: None },
+    SubdivisionData { code: "NG-OS", name: "Osun", kind: "State", parent: None },
+    SubdivisionData { code: "NG-OY", name: "Oyo", kind: "State", parent: None },
+    SubdivisionData { code: "NG-PL", name: "Plateau", kind: "State", parent: None },
+    SubdivisionData { code: "NG-RI", name: "Rivers", kind: "State", parent: None },
+    SubdivisionData { code: "NG-SO", name: "Sokoto", kind: "State", parent: None },
+    SubdivisionData { code: "NG-TA", name: "Taraba", kind: "State", parent: None },
+    SubdivisionData { code: "NG-YO", name: "Yobe", kind: "State", parent: None },
+    SubdivisionData { code: "NG-ZA", name: "Zamfara", kind: "State", parent: None },
+    SubdivisionData { code: "NI-AN", name: "Costa Caribe Norte", kind: "Autonomous region", parent: None },
+    SubdivisionData { code: "NI-AS", name: "Costa Caribe Sur", kind: "Autonomous region", parent: None },
+    SubdivisionData { code: "NI-BO", name: "Boaco", kind: "Department", parent: None },
+    SubdivisionData { code: "NI-CA", name: "Carazo", kind: "Department", parent: None },
+    SubdivisionData { code: "NI-CI", name: "Chinandega", kind: "Department", parent: None },
+    SubdivisionData { code: "NI-CO", name: "Chontales", kind: "Department", parent: None },
+    SubdivisionData { code: "NI-ES", name: "Estelí", kind: "Department", parent: None },
+    SubdivisionData { code: "NI-GR", name: "Granada", kind: "Department", parent: None },
+    SubdivisionData { code: "NI-JI", name: "Jinotega", kind: "Department", parent: None },
+    SubdivisionData { code: "NI-LE", name: "León", kind: "Department", parent: None },
+    SubdivisionData { code: "NI-MD", name: "Madriz", kind: "Department", parent: None },
+    SubdivisionData { code: "NI-MN", name: "Managua", kind: "Department", parent: None },
+    SubdivisionData { code: "NI-MS", name: "Masaya", kind: "Department", parent: None },
+    SubdivisionData { code: "NI-MT", name: "Matagalpa", kind: "Department", parent: None },
+    SubdivisionData { code: "NI-NS", name: "Nueva Segovia", kind: "Department", parent: None },
+    SubdivisionData { code: "NI-RI", name: "Rivas", kind: "Department", parent: None },
+    SubdivisionData { code: "NI-SJ", name: "Río San Juan", kind: "Department", parent: None },
+    SubdivisionData { code: "NL-AW", name: "Aruba", kind: "Country", parent: None },
+    SubdivisionData { code: "NL-BQ1", name: "Bonaire", kind: "Special municipality", parent: None },
+    SubdivisionData { code: "NL-BQ2", name: "Saba", kind: "Special municipality", parent: None },
+    SubdivisionData { code: "NL-BQ3", name: "Sint Eustatius", kind: "Special municipality", parent: None },
+    SubdivisionData { code: "NL-CW", name: "Curaçao", kind: "Country", parent: None },
+    SubdivisionData { code: "NL-DR", name: "Drenthe", kind: "Province", parent: None },
+    SubdivisionData { code: "NL-FL", name: "Flevoland", kind: "Province", parent: None },
+    SubdivisionData { code: "NL-FR", name: "Fryslân", kind: "Province", parent: None },
+    SubdivisionData { code: "NL-GE", name: "Gelderland", kind: "Province", parent: None },
+    SubdivisionData { code: "NL-GR", name: "Groningen", kind: "Province", parent: None },
+    SubdivisionData { code: "NL-LI", name: "Limburg", kind: "Province", parent: None },
+    SubdivisionData { code: "NL-NB", name: "Noord-Brabant", kind: "Province", parent: None },
+    SubdivisionData { code: "NL-NH", name: "Noord-Holland", kind: "Province", parent: None },
+    SubdivisionData { code: "NL-OV", name: "Overijssel", kind: "Province", parent: None },
+    SubdivisionData { code: "NL-SX", name: "Sint Maarten", kind: "Country", parent: None },
+    SubdivisionData { code: "NL-UT", name: "Utrecht", kind: "Province", parent: None },
+    SubdivisionData { code: "NL-ZE", name: "Zeeland", kind: "Province", parent: None },
+    SubdivisionData { code: "NL-ZH", name: "Zuid-Holland", kind: "Province", parent: None },
+    SubdivisionData { code: "NO-03", name: "Oslo", kind: "County", parent: None },
+    SubdivisionData { code: "NO-11", name: "Rogaland", kind: "County", parent: None },
+    SubdivisionData { code: "NO-15", name: "Møre og Romsdal", kind: "County", parent: None },
+    SubdivisionData { code: "NO-18", name: "Nordland", kind: "County", parent: None },
+    SubdivisionData { code: "NO-21", name: "Svalbard (Arctic Region)", kind: "Arctic region", parent: None },
+    SubdivisionData { code: "NO-22", name: "Jan Mayen (Arctic Region)", kind: "Arctic region", parent: None },
+    SubdivisionData { code: "NO-30", name: "Viken", kind: "County", parent: None },
+    SubdivisionData { code: "NO-34", name: "Innlandet", kind: "County", parent: None },
+    SubdivisionData { code: "NO-38", name: "Vestfold og Telemark", kind: "County", parent: None },
+    SubdivisionData { code: "NO-42", name: "Agder", kind: "County", parent: None },
+    SubdivisionData { code: "NO-46", name: "Vestland", kind: "County", parent: None },
+    SubdivisionData { code: "NO-50", name: "Trööndelage", kind: "County", parent: None },
+    SubdivisionData { code: "NO-54", name: "Romssa ja Finnmárkku", kind: "County", parent: None },
+    SubdivisionData { code: "NP-1", name: "Central", kind: "Development region", parent: None },
+    SubdivisionData { code: "NP-2", name: "Mid Western", kind: "Development region", parent: None },
+    SubdivisionData { code: "NP-3", name: "Western", kind: "Development region", parent: None },
+    SubdivisionData { code: "NP-4", name: "Eastern", kind: "Development region", parent: None },
+    SubdivisionData { code: "NP-5", name: "Far Western", kind: "Development region", parent: None },
+    SubdivisionData { code: "NP-BA", name: "Bagmati", kind: "Zone", parent: Some("NP-1") },
+    SubdivisionData { code: "NP-BH", name: "Bheri", kind: "Zone", parent: Some("NP-2") },
+    SubdivisionData { code: "NP-DH", name: "Dhawalagiri", kind: "Zone", parent: Some("NP-3") },
+    SubdivisionData { code: "NP-GA", name: "Gandaki", kind: "Zone", parent: Some("NP-3") },
+    SubdivisionData { code: "NP-JA", name: "Janakpur", kind: "Zone", parent: Some("NP-1") },
+    SubdivisionData { code: "NP-KA", name: "Karnali", kind: "Zone", parent: Some("NP-2") },
+    SubdivisionData { code: "NP-KO", name: "Kosi", kind: "Zone", parent: Some("NP-4") },
+    SubdivisionData { code: "NP-LU", name: "Lumbini", kind: "Zone", parent: Some("NP-3") },
+    SubdivisionData { code: "NP-MA", name: "Mahakali", kind: "Zone", parent: Some("NP-5") },
+    SubdivisionData { code: "NP-ME", name: "Mechi", kind: "Zone", parent: Some("NP-4") },
+    SubdivisionData { code: "NP-NA", name: "Narayani", kind: "Zone", parent: Some("NP-1") },
+    SubdivisionData { code: "NP-P1", name: "Province 1", kind: "Province", parent: None },
+    SubdivisionData { code: "NP-P2", name: "Province 2", kind: "Province", parent: None },
+    SubdivisionData { code: "NP-P3", name: "Bāgmatī", kind: "Province", parent: None },
+    SubdivisionData { code: "NP-P4", name: "Gandaki", kind: "Province", parent: None },
+    SubdivisionData { code: "NP-P5", name: "Province 5", kind: "Province", parent: None },
+    SubdivisionData { code: "NP-P6", name: "Karnali", kind: "Province", parent: None },
+    SubdivisionData { code: "NP-P7", name: "Sudūr Pashchim", kind: "Province", parent: None },
+    SubdivisionData { code: "NP-RA", name: "Rapti", kind: "Zone", parent: Some("NP-2") },
+    SubdivisionData { code: "NP-SA", name: "Sagarmatha", kind: "Zone", parent: Some("NP-4") },
+    SubdivisionData { code: "NP-SE", name: "Seti", kind: "Zone", parent: Some("NP-5") },
+    SubdivisionData { code: "NR-01", name: "Aiwo", kind: "District", parent: None },
+    SubdivisionData { code: "NR-02", name: "Anabar", kind: "District", parent: None },
+    SubdivisionData { code: "NR-03", name: "Anetan", kind: "District", parent: None },
+    SubdivisionData { code: "NR-04", name: "Anibare", kind: "District", parent: None },
+    SubdivisionData { code: "NR-05", name: "Baitsi", kind: "District", parent: None },
+    SubdivisionData { code: "NR-06", name: "Boe", kind: "District", parent: None },
+    SubdivisionData { code: "NR-07", name: "Buada", kind: "District", parent: None },
+    SubdivisionData { code: "NR-08", name: "Denigomodu", kind: "District", parent: None },
+    SubdivisionData { code: "NR-09", name: "Ewa", kind: "District", parent: None },
+    SubdivisionData { code: "NR-10", name: "Ijuw", kind: "District", parent: None },
+    SubdivisionData { code: "NR-11", name: "Meneng", kind: "District", parent: None },
+    SubdivisionData { code: "NR-12", name: "Nibok", kind: "District", parent: None },
+    SubdivisionData { code: "NR-13", name: "Uaboe", kind: "District", parent: None },
+    SubdivisionData { code: "NR-14", name: "Yaren", kind: "District", parent: None },
+    SubdivisionData { code: "NZ-AUK", name: "Auckland", kind: "Region", parent: None },
+    SubdivisionData { code: "NZ-BOP", name: "Bay of Plenty", kind: "Region", parent: None },
+    SubdivisionData { code: "NZ-CAN", name: "Canterbury", kind: "Region", parent: None },
+    SubdivisionData { code: "NZ-CIT", name: "Chatham Islands Territory", kind: "Special island authority", parent: None },
+    SubdivisionData { code: "NZ-GIS", name: "Gisborne", kind: "Region", parent: None },
+    SubdivisionData { code: "NZ-HKB", name: "Hawke's Bay", kind: "Region", parent: None },
+    SubdivisionData { code: "NZ-MBH", name: "Marlborough", kind: "Region", parent: None },
+    SubdivisionData { code: "NZ-MWT", name: "Manawatu-Wanganui", kind: "Region", parent: None },
+    SubdivisionData { code: "NZ-NSN", name: "Nelson", kind: "Region", parent: None },
+    SubdivisionData { code: "NZ-NTL", name: "Northland", kind: "Region", parent: None },
+    SubdivisionData { code: "NZ-OTA", name: "Otago", kind: "Region", parent: None },
+    SubdivisionData { code: "NZ-STL", name: "Southland", kind: "Region", parent: None },
+    SubdivisionData { code: "NZ-TAS", name: "Tasman", kind: "Region", parent: None },
+    SubdivisionData { code: "NZ-TKI", name: "Taranaki", kind: "Region", parent: None },
+    SubdivisionData { code: "NZ-WGN", name: "Wellington", kind: "Region", parent: None },
+    SubdivisionData { code: "NZ-WKO", name: "Waikato", kind: "Region", parent: None },
+    SubdivisionData { code: "NZ-WTC", name: "West Coast", kind: "Region", parent: None },
+    SubdivisionData { code: "OM-BJ", name: "Janūb al Bāţinah", kind: "Governorate", parent: None },
+    SubdivisionData { code: "OM-BS", name: "Shamāl al Bāţinah", kind: "Governorate", parent: None },
+    SubdivisionData { code: "OM-BU", name: "Al Buraymī", kind: "Governorate", parent: None },
+    SubdivisionData { code: "OM-DA", name: "Ad Dākhilīyah", kind: "Governorate", parent: None },
+    SubdivisionData { code: "OM-MA", name: "Masqaţ", kind: "Governorate", parent: None },
+    SubdivisionData { code: "OM-MU", name: "Musandam", kind: "Governorate", parent: None },
+    SubdivisionData { code: "OM-SJ", name: "Janūb ash Sharqīyah", kind: "Governorate", parent: None },
+    SubdivisionData { code: "OM-SS", name: "Shamāl ash Sharqīyah", kind: "Governorate", parent: None },
+    SubdivisionData { code: "OM-WU", name: "Al Wusţá", kind: "Governorate", parent: None },
+    SubdivisionData { code: "OM-ZA", name: "Az̧ Z̧āhirah", kind: "Governorate", parent: None },
+    SubdivisionData { code: "OM-ZU", name: "Z̧ufār", kind: "Governorate", parent: None },
+    SubdivisionData { code: "PA-1", name: "Bocas del Toro", kind: "Province", parent: None },
+    SubdivisionData { code: "PA-10", name: "Panamá Oeste", kind: "Province", parent: None },
+    SubdivisionData { code: "PA-2", name: "Coclé", kind: "Province", parent: None },
+    SubdivisionData { code: "PA-3", name: "Colón", kind: "Province", parent: None },
+    SubdivisionData { code: "PA-4", name: "Chiriquí", kind: "Province", parent: None },
+    SubdivisionData { code: "PA-5", name: "Darién", kind: "Province", parent: None },
+    SubdivisionData { code: "PA-6", name: "Herrera", kind: "Province", parent: None },
+    SubdivisionData { code: "PA-7", name: "Los Santos", kind: "Province", parent: None },
+    SubdivisionData { code: "PA-8", name: "Panamá", kind: "Province", parent: None },
+    SubdivisionData { code: "PA-9", name: "Veraguas", kind: "Province", parent: None },
+    SubdivisionData { code: "PA-EM", name: "Emberá", kind: "Indigenous region", parent: None },
+    SubdivisionData { code: "PA-KY", name: "Guna Yala", kind: "Indigenous region", parent: None },
+    SubdivisionData { code: "PA-NB", name: "Ngöbe-Buglé", kind: "Indigenous region", parent: None },
+    SubdivisionData { code: "PE-AMA", name: "Amarumayu", kind: "Region", parent: None },
+    SubdivisionData { code: "PE-ANC", name: "Ancash", kind: "Region", parent: None },
+    SubdivisionData { code: "PE-APU", name: "Apurimaq", kind: "Region", parent: None },
+    SubdivisionData { code: "PE-ARE", name: "Arequipa", kind: "Region", parent: None },
+    SubdivisionData { code: "PE-AYA", name: "Ayacucho", kind: "Region", parent: None },
+    SubdivisionData { code: "PE-CAJ", name: "Cajamarca", kind: "Region", parent: None },
+    SubdivisionData { code: "PE-CAL", name: "El Callao", kind: "Region", parent: None },
+    SubdivisionData { code: "PE-CUS", name: "Cusco", kind: "Region", parent: None },
+    SubdivisionData { code: "PE-HUC", name: "Huánuco", kind: "Region", parent: None },
+    SubdivisionData { code: "PE-HUV", name: "Huancavelica", kind: "Region", parent: None },
+    SubdivisionData { code: "PE-ICA", name: "Ica", kind: "Region", parent: None },
+    SubdivisionData { code: "PE-JUN", name: "Hunin", kind: "Region", parent: None },
+    SubdivisionData { code: "PE-LAL", name: "La Libertad", kind: "Region", parent: None },
+    SubdivisionData { code: "PE-LAM", name: "Lambayeque", kind: "Region", parent: None },
+    SubdivisionData { code: "PE-LIM", name: "Lima", kind: "Region", parent: None },
+    SubdivisionData { code: "PE-LMA", name: "Lima hatun llaqta", kind: "Municipality", parent: None },
+    SubdivisionData { code: "PE-LOR", name: "Loreto", kind: "Region", parent: None },
+    SubdivisionData { code: "PE-MDD", name: "Madre de Dios", kind: "Region", parent: None },
+    SubdivisionData { code: "PE-MOQ", name: "Moquegua", kind: "Region", parent: None },
+    SubdivisionData { code: "PE-PAS", name: "Pasco", kind: "Region", parent: None },
+    SubdivisionData { code: "PE-PIU", name: "Piura", kind: "Region", parent: None },
+    SubdivisionData { code: "PE-PUN", name: "Puno", kind: "Region", parent: None },
+    SubdivisionData { code: "PE-SAM", name: "San Martin", kind: "Region", parent: None },
+    SubdivisionData { code: "PE-TAC", name: "Tacna", kind: "Region", parent: None },
+    SubdivisionData { code: "PE-TUM", name: "Tumbes", kind: "Region", parent: None },
+    SubdivisionData { code: "PE-UCA", name: "Ucayali", kind: "Region", parent: None },
+    SubdivisionData { code: "PG-CPK", name: "Chimbu", kind: "Province", parent: None },
+    SubdivisionData { code: "PG-CPM", name: "Central", kind: "Province", parent: None },
+    SubdivisionData { code: "PG-EBR", name: "East New Britain", kind: "Province", parent: None },
+    SubdivisionData { code: "PG-EHG", name: "Eastern Highlands", kind: "Province", parent: None },
+    SubdivisionData { code: "PG-EPW", name: "Enga", kind: "Province", parent: None },
+    SubdivisionData { code: "PG-ESW", name: "East Sepik", kind: "Province", parent: None },
+    SubdivisionData { code: "PG-GPK", name: "Gulf", kind: "Province", parent: None },
+    SubdivisionData { code: "PG-HLA", name: "Hela", kind: "Province", parent: None },
+    SubdivisionData { code: "PG-JWK", name: "Jiwaka", kind: "Province", parent: None },
+    SubdivisionData { code: "PG-MBA", name: "Milne Bay", kind: "Province", parent: None },
+    SubdivisionData { code: "PG-MPL", name: "Morobe", kind: "Province", parent: None },
+    SubdivisionData { code: "PG-MPM", name: "Madang", kind: "Province", parent: None },
+    SubdivisionData { code: "PG-MRL", name: "Manus", kind: "Province", parent: None },
+    SubdivisionData { code: "PG-NCD", name: "National Capital District (Port Moresby)", kind: "District", parent: None },
+    SubdivisionData { code: "PG-NIK", name: "New Ireland", kind: "Province", parent: None },
+    SubdivisionData { code: "PG-NPP", name: "Northern", kind: "Province", parent: None },
+    SubdivisionData { code: "PG-NSB", name: "Bougainville", kind: "Autonomous region", parent: None },
+    SubdivisionData { code: "PG-SAN", name: "West Sepik", kind: "Province", parent: None },
+    SubdivisionData { code: "PG-SHM", name: "Southern Highlands", kind: "Province", parent: None },
+    SubdivisionData { code: "PG-WBK", name: "West New Britain", kind: "Province", parent: None },
+    SubdivisionData { code: "PG-WHM", name: "Western Highlands", kind: "Province", parent: None },
+    SubdivisionData { code: "PG-WPD", name: "Western", kind: "Province", parent: None },
+    SubdivisionData { code: "PH-00", name: "National Capital Region", kind: "Region", parent: None },
+    SubdivisionData { code: "PH-01", name: "Ilocos (Region I)", kind: "Region", parent: None },
+    SubdivisionData { code: "PH-02", name: "Cagayan Valley (Region II)", kind: "Region", parent: None },
+    SubdivisionData { code: "PH-03", name: "Central Luzon (Region III)", kind: "Region", parent: None },
+    SubdivisionData { code: "PH-05", name: "Bicol (Region V)", kind: "Region", parent: None },
+    SubdivisionData { code: "PH-06", name: "Western Visayas (Region VI)", kind: "Region", parent: None },
+    SubdivisionData { code: "PH-07", name: "Central Visayas (Region VII)", kind: "Region", parent: None },
+    SubdivisionData { code: "PH-08", name: "Eastern Visayas (Region VIII)", kind: "Region", parent: None },
+    SubdivisionData { code: "PH-09", name: "Zamboanga Peninsula (Region IX)", kind: "Region", parent: None },
+    SubdivisionData { code: "PH-10", name: "Northern Mindanao (Region X)", kind: "Region", parent: None },
+    SubdivisionData { code: "PH-11", name: "Davao (Region XI)", kind: "Region", parent: None },
+    SubdivisionData { code: "PH-12", name: "Soccsksargen (Region XII)", kind: "Region", parent: None },
+    SubdivisionData { code: "PH-13", name: "Caraga (Region XIII)", kind: "Region", parent: None },
+    SubdivisionData { code: "PH-14", name: "Autonomous Region in Muslim Mindanao (ARMM)", kind: "Region", parent: None },
+    SubdivisionData { code: "PH-15", name: "Cordillera Administrative Region (CAR)", kind: "Region", parent: None },
+    SubdivisionData { code: "PH-40", name: "Calabarzon (Region IV-A)", kind: "Region", parent: None },
+    SubdivisionData { code: "PH-41", name: "Mimaropa (Region IV-B)", kind: "Region", parent: None },
+    SubdivisionData { code: "PH-ABR", name: "Abra", kind: "Province", parent: Some("PH-15") },
+    SubdivisionData { code: "PH-AGN", name: "Agusan del Norte", kind: "Province", parent: Some("PH-13") },
+    SubdivisionData { code: "PH-AGS", name: "Agusan del Sur", kind: "Province", parent: Some("PH-13") },
+    SubdivisionData { code: "PH-AKL", name: "Aklan", kind: "Province", parent: Some("PH-06") },
+    SubdivisionData { code: "PH-ALB", name: "Albay", kind: "Province", parent: Some("PH-05") },
+    SubdivisionData { code: "PH-ANT", name: "Antique", kind: "Province", parent: Some("PH-06") },
+    SubdivisionData { code: "PH-APA", name: "Apayao", kind: "Province", parent: Some("PH-15") },
+    SubdivisionData { code: "PH-AUR", name: "Aurora", kind: "Province", parent: Some("PH-03") },
+    SubdivisionData { code: "PH-BAN", name: "Bataan", kind: "Province", parent: Some("PH-03") },
+    SubdivisionData { code: "PH-BAS", name: "Basilan", kind: "Province", parent: Some("PH-09") },
+    SubdivisionData { code: "PH-BEN", name: "Benguet", kind: "Province", parent: Some("PH-15") },
+    SubdivisionData { code: "PH-BIL", name: "Biliran", kind: "Province", parent: Some("PH-08") },
+    SubdivisionData { code: "PH-BOH", name: "Bohol", kind: "Province", parent: Some("PH-07") },
+    SubdivisionData { code: "PH-BTG", name: "Batangas", kind: "Province", parent: Some("PH-40") },
+    SubdivisionData { code: "PH-BTN", name: "Batanes", kind: "Province", parent: Some("PH-02") },
+    SubdivisionData { code: "PH-BUK", name: "Bukidnon", kind: "Province", parent: Some("PH-10") },
+    SubdivisionData { code: "PH-BUL", name: "Bulacan", kind: "Province", parent: Some("PH-03") },
+    SubdivisionData { code: "PH-CAG", name: "Cagayan", kind: "Province", parent: Some("PH-02") },
+    SubdivisionData { code: "PH-CAM", name: "Camiguin", kind: "Province", parent: Some("PH-10") },
+    SubdivisionData { code: "PH-CAN", name: "Camarines Norte", kind: "Province", parent: Some("PH-05") },
+    SubdivisionData { code: "PH-CAP", name: "Capiz", kind: "Province", parent: Some("PH-06") },
+    SubdivisionData { code: "PH-CAS", name: "Camarines Sur", kind: "Province", parent: Some("PH-05") },
+    SubdivisionData { code: "PH-CAT", name: "Catanduanes", kind: "Province", parent: Some("PH-05") },
+    SubdivisionData { code: "PH-CAV", name: "Cavite", kind: "Province", parent: Some("PH-40") },
+    SubdivisionData { code: "PH-CEB", name: "Cebu", kind: "Province", parent: Some("PH-07") },
+    SubdivisionData { code: "PH-COM", name: "Davao de Oro", kind: "Province", parent: Some("PH-11") },
+    SubdivisionData { code: "PH-DAO", name: "Davao Oriental", kind: "Province", parent: Some("PH-11") },
+    SubdivisionData { code: "PH-DAS", name: "Davao del Sur", kind: "Province", parent: Some("PH-11") },
+    SubdivisionData { code: "PH-DAV", name: "Davao del Norte", kind: "Province", parent: Some("PH-11") },
+    SubdivisionData { code: "PH-DIN", name: "Dinagat Islands", kind: "Province", parent: Some("PH-13") },
+    SubdivisionData { code: "PH-DVO", name: "Davao Occidental", kind: "Province", parent: Some("PH-11") },
+    SubdivisionData { code: "PH-EAS", name: "Eastern Samar", kind: "Province", parent: Some("PH-08") },
+    SubdivisionData { code: "PH-GUI", name: "Guimaras", kind: "Province", parent: Some("PH-06") },
+    SubdivisionData { code: "PH-IFU", name: "Ifugao", kind: "Province", parent: Some("PH-15") },
+    SubdivisionData { code: "PH-ILI", name: "Iloilo", kind: "Province", parent: Some("PH-06") },
+    SubdivisionData { code: "PH-ILN", name: "Ilocos Norte", kind: "Province", parent: Some("PH-01") },
+    SubdivisionData { code: "PH-ILS", name: "Ilocos Sur", kind: "Province", parent: Some("PH-01") },
+    SubdivisionData { code: "PH-ISA", name: "Isabela", kind: "Province", parent: Some("PH-02") },
+    SubdivisionData { code: "PH-KAL", name: "Kalinga", kind: "Province", parent: Some("PH-15") },
+    SubdivisionData { code: "PH-LAG", name: "Laguna", kind: "Province", parent: Some("PH-40") },
+    SubdivisionData { code: "PH-LAN", name: "Lanao del Norte", kind: "Province", parent: Some("PH-12") },
+    SubdivisionData { code: "PH-LAS", name: "Lanao del Sur", kind: "Province", parent: Some("PH-14") },
+    SubdivisionData { code: "PH-LEY", name: "Leyte", kind: "Province", parent: Some("PH-08") },
+    SubdivisionData { code: "PH-LUN", name: "La Union", kind: "Province", parent: Some("PH-01") },
+    SubdivisionData { code: "PH-MAD", name: "Marinduque", kind: "Province", parent: Some("PH-41") },
+    SubdivisionData { code: "PH-MAG", name: "Maguindanao", kind: "Province", parent: Some("PH-14") },
+    SubdivisionData { code: "PH-MAS", name: "Masbate", kind: "Province", parent: Some("PH-05") },
+    SubdivisionData { code: "PH-MDC", name: "Mindoro Occidental", kind: "Province", parent: Some("PH-41") },
+    SubdivisionData { code: "PH-MDR", name: "Mindoro Oriental", kind: "Province", parent: Some("PH-41") },
+    SubdivisionData { code: "PH-MOU", name: "Mountain Province", kind: "Province", parent: Some("PH-15") },
+    SubdivisionData { code: "PH-MSC", name: "Misamis Occidental", kind: "Province", parent: Some("PH-10") },
+    SubdivisionData { code: "PH-MSR", name: "Misamis Oriental", kind: "Province", parent: Some("PH-10") },
+    SubdivisionData { code: "PH-NCO", name: "Cotabato", kind: "Province", parent: Some("PH-12") },
+    SubdivisionData { code: "PH-NEC", name: "Negros Occidental", kind: "Province", parent: Some("PH-06") },
+    SubdivisionData { code: "PH-NER", name: "Negros Oriental", kind: "Province", parent: Some("PH-07") },
+    SubdivisionData { code: "PH-NSA", name: "Northern Samar", kind: "Province", parent: Some("PH-08") },
+    SubdivisionData { code: "PH-NUE", name: "Nueva Ecija", kind: "Province", parent: Some("PH-03") },
+    SubdivisionData { code: "PH-NUV", name: "Nueva Vizcaya", kind: "Province", parent: Some("PH-02") },
+    SubdivisionData { code: "PH-PAM", name: "Pampanga", kind: "Province", parent: Some("PH-03") },
+    SubdivisionData { code: "PH-PAN", name: "Pangasinan", kind: "Province", parent: Some("PH-01") },
+    SubdivisionData { code: "PH-PLW", name: "Palawan", kind: "Province", parent: Some("PH-41") },
+    SubdivisionData { code: "PH-QUE", name: "Quezon", kind: "Province", parent: Some("PH-40") },
+    SubdivisionData { code: "PH-QUI", name: "Quirino", kind: "Province", parent: Some("PH-02") },
+    SubdivisionData { code: "PH-RIZ", name: "Rizal", kind: "Province", parent: Some("PH-40") },
+    SubdivisionData { code: "PH-ROM", name: "Romblon", kind: "Province", parent: Some("PH-41") },
+    SubdivisionData { code: "PH-SAR", name: "Sarangani", kind: "Province", parent: Some("PH-11") },
+    SubdivisionData { code: "PH-SCO", name: "South Cotabato", kind: "Province", parent: Some("PH-11") },
+    SubdivisionData { code: "PH-SIG", name: "Siquijor", kind: "Province", parent: Some("PH-07") },
+    SubdivisionData { code: "PH-SLE", name: "Southern Leyte", kind: "Province", parent: Some("PH-08") },
+    SubdivisionData { code: "PH-SLU", name: "Sulu", kind: "Province", parent: Some("PH-14") },
+    SubdivisionData { code: "PH-SOR", name: "Sorsogon", kind: "Province", parent: Some("PH-05") },
+    SubdivisionData { code: "PH-SUK", name: "Sultan Kudarat", kind: "Province", parent: Some("PH-12") },
+    SubdivisionData { code: "PH-SUN", name: "Surigao del Norte", kind: "Province", parent: Some("PH-13") },
+    SubdivisionData { code: "PH-SUR", name: "Surigao del Sur", kind: "Province", parent: Some("PH-13") },
+    SubdivisionData { code: "PH-TAR", name: "Tarlac", kind: "Province", parent: Some("PH-03") },
+    SubdivisionData { code: "PH-TAW", name: "Tawi-Tawi", kind: "Province", parent: Some("PH-14") },
+    SubdivisionData { code: "PH-WSA", name: "Samar", kind: "Province", parent: Some("PH-08") },
+    SubdivisionData { code: "PH-ZAN", name: "Zamboanga del Norte", kind: "Province", parent: Some("PH-09") },
+    SubdivisionData { code: "PH-ZAS", name: "Zamboanga del Sur", kind: "Province", parent: Some("PH-09") },
+    SubdivisionData { code: "PH-ZMB", name: "Zambales", kind: "Province", parent: Some("PH-03") },
+    SubdivisionData { code: "PH-ZSI", name: "Zamboanga Sibugay", kind: "Province", parent: Some("PH-09") },
+    SubdivisionData { code: "PK-BA", name: "Balochistan", kind: "Province", parent: None },
+    SubdivisionData { code: "PK-GB", name: "Gilgit-Baltistan", kind: "Pakistan administered area", parent: None },
+    SubdivisionData { code: "PK-IS", name: "Islamabad", kind: "Federal capital territory", parent: None },
+    SubdivisionData { code: "PK-JK", name: "Azad Jammu and Kashmir", kind: "Pakistan administered area", parent: None },
+    SubdivisionData { code: "PK-KP", name: "Khyber Pakhtunkhwa", kind: "Province", parent: None },
+    SubdivisionData { code: "PK-PB", name: "Punjab", kind: "Province", parent: None },
+    SubdivisionData { code: "PK-SD", name: "Sindh", kind: "Province", parent: None },
+    SubdivisionData { code: "PL-02", name: "Dolnośląskie", kind: "Voivodship", parent: None },
+    SubdivisionData { code: "PL-04", name: "Kujawsko-pomorskie", kind: "Voivodship", parent: None },
+    SubdivisionData { code: "PL-06", name: "Lubelskie", kind: "Voivodship", parent: None },
+    SubdivisionData { code: "PL-08", name: "Lubuskie", kind: "Voivodship", parent: None },
+    SubdivisionData { code: "PL-10", name: "Łódzkie", kind: "Voivodship", parent: None },
+    SubdivisionData { code: "PL-12", name: "Małopolskie", kind: "Voivodship", parent: None },
+    SubdivisionData { code: "PL-14", name: "Mazowieckie", kind: "Voivodship", parent: None },
+    SubdivisionData { code: "PL-16", name: "Opolskie", kind: "Voivodship", parent: None },
+    SubdivisionData { code: "PL-18", name: "Podkarpackie", kind: "Voivodship", parent: None },
+    SubdivisionData { code: "PL-20", name: "Podlaskie", kind: "Voivodship", parent: None },
+    SubdivisionData { code: "PL-22", name: "Pomorskie", kind: "Voivodship", parent: None },
+    SubdivisionData { code: "PL-24", name: "Śląskie", kind: "Voivodship", parent: None },
+    SubdivisionData { code: "PL-26", name: "Świętokrzyskie", kind: "Voivodship", parent: None },
+    SubdivisionData { code: "PL-28", name: "Warmińsko-mazurskie", kind: "Voivodship", parent: None },
+    SubdivisionData { code: "PL-30", name: "Wielkopolskie", kind: "Voivodship", parent: None },
+    SubdivisionData { code: "PL-32", name: "Zachodniopomorskie", kind: "Voivodship", parent: None },
+    SubdivisionData { code: "PS-BTH", name: "Bethlehem", kind: "Governorate", parent: None },
+    SubdivisionData { code: "PS-DEB", name: "Deir El Balah", kind: "Governorate", parent: None },
+    SubdivisionData { code: "PS-GZA", name: "Gaza", kind: "Governorate", parent: None },
+    SubdivisionData { code: "PS-HBN", name: "Hebron", kind: "Governorate", parent: None },
+    SubdivisionData { code: "PS-JEM", name: "Jerusalem", kind: "Governorate", parent: None },
+    SubdivisionData { code: "PS-JEN", name: "Jenin", kind: "Governorate", parent: None },
+    SubdivisionData { code: "PS-JRH", name: "Jericho and Al Aghwar", kind: "Governorate", parent: None },
+    SubdivisionData { code: "PS-KYS", name: "Khan Yunis", kind: "Governorate", parent: None },
+    SubdivisionData { code: "PS-NBS", name: "Nablus", kind: "Governorate", parent: None },
+    SubdivisionData { code: "PS-NGZ", name: "North Gaza", kind: "Governorate", parent: None },
+    SubdivisionData { code: "PS-QQA", name: "Qalqilya", kind: "Governorate", parent: None },
+    SubdivisionData { code: "PS-RBH", name: "Ramallah", kind: "Governorate", parent: None },
+    SubdivisionData { code: "PS-RFH", name: "Rafah", kind: "Governorate", parent: None },
+    SubdivisionData { code: "PS-SLT", name: "Salfit", kind: "Governorate", parent: None },
+    SubdivisionData { code: "PS-TBS", name: "Tubas", kind: "Governorate", parent: None },
+    SubdivisionData { code: "PS-TKM", name: "Tulkarm", kind: "Governorate", parent: None },
+    SubdivisionData { code: "PT-01", name: "Aveiro", kind: "District", parent: None },
+    SubdivisionData { code: "PT-02", name: "Beja", kind: "District", parent: None },
+    SubdivisionData { code: "PT-03", name: "Braga", kind: "District", parent: None },
+    SubdivisionData { code: "PT-04", name: "Bragança", kind: "District", parent: None },
+    SubdivisionData { code: "PT-05", name: "Castelo Branco", kind: "District", parent: None },
+    SubdivisionData { code: "PT-06", name: "Coimbra", kind: "District", parent: None },
+    SubdivisionData { code: "PT-07", name: "Évora", kind: "District", parent: None },
+    SubdivisionData { code: "PT-08", name: "Faro", kind: "District", parent: None },
+    SubdivisionData { code: "PT-09", name: "Guarda", kind: "District", parent: None },
+    SubdivisionData { code: "PT-10", name: "Leiria", kind: "District", parent: None },
+    SubdivisionData { code: "PT-11", name: "Lisboa", kind: "District", parent: None },
+    SubdivisionData { code: "PT-12", name: "Portalegre", kind: "District", parent: None },
+    SubdivisionData { code: "PT-13", name: "Porto", kind: "District", parent: None },
+    SubdivisionData { code: "PT-14", name: "Santarém", kind: "District", parent: None },
+    SubdivisionData { code: "PT-15", name: "Setúbal", kind: "District", parent: None },
+    SubdivisionData { code: "PT-16", name: "Viana do Castelo", kind: "District", parent: None },
+    SubdivisionData { code: "PT-17", name: "Vila Real", kind: "District", parent: None },
+    SubdivisionData { code: "PT-18", name: "Viseu", kind: "District", parent: None },
+    SubdivisionData { code: "PT-20", name: "Região Autónoma dos Açores", kind: "Autonomous region", parent: None },
+    SubdivisionData { code: "PT-30", name: "Região Autónoma da Madeira", kind: "Autonomous region", parent: None },
+    SubdivisionData { code: "PW-002", name: "Aimeliik", kind: "State", parent: None },
+    SubdivisionData { code: "PW-004", name: "Airai", kind: "State", parent: None },
+    SubdivisionData { code: "PW-010", name: "Angaur", kind: "State", parent: None },
+    SubdivisionData { code: "PW-050", name: "Hatohobei", kind: "State", parent: None },
+    SubdivisionData { code: "PW-100", name: "Kayangel", kind: "State", parent: None },
+    SubdivisionData { code: "PW-150", name: "Koror", kind: "State", parent: None },
+    SubdivisionData { code: "PW-212", name: "Melekeok", kind: "State", parent: None },
+    SubdivisionData { code: "PW-214", name: "Ngaraard", kind: "State", parent: None },
+    SubdivisionData { code: "PW-218", name: "Ngarchelong", kind: "State", parent: None },
+    SubdivisionData { code: "PW-222", name: "Ngardmau", kind: "State", parent: None },
+    SubdivisionData { code: "PW-224", name: "Ngatpang", kind: "State", parent: None },
+    SubdivisionData { code: "PW-226", name: "Ngchesar", kind: "State", parent: None },
+    SubdivisionData { code: "PW-227", name: "Ngeremlengui", kind: "State", parent: None },
+    SubdivisionData { code: "PW-228", name: "Ngiwal", kind: "State", parent: None },
+    SubdivisionData { code: "PW-350", name: "Peleliu", kind: "State", parent: None },
+    SubdivisionData { code: "PW-370", name: "Sonsorol", kind: "State", parent: None },
+    SubdivisionData { code: "PY-1", name: "Concepción", kind: "Department", parent: None },
+    SubdivisionData { code: "PY-10", name: "Alto Paraná", kind: "Department", parent: None },
+    SubdivisionData { code: "PY-11", name: "Central", kind: "Department", parent: None },
+    SubdivisionData { code: "PY-12", name: "Ñeembucú", kind: "Department", parent: None },
+    SubdivisionData { code: "PY-13", name: "Amambay", kind: "Department", parent: None },
+    SubdivisionData { code: "PY-14", name: "Canindeyú", kind: "Department", parent: None },
+    SubdivisionData { code: "PY-15", name: "Presidente Hayes", kind: "Department", parent: None },
+    SubdivisionData { code: "PY-16", name: "Alto Paraguay", kind: "Department", parent: None },
+    SubdivisionData { code: "PY-19", name: "Boquerón", kind: "Department", parent: None },
+    SubdivisionData { code: "PY-2", name: "San Pedro", kind: "Department", parent: None },
+    SubdivisionData { code: "PY-3", name: "Cordillera", kind: "Department", parent: None },
+    SubdivisionData { code: "PY-4", name: "Guairá", kind: "Department", parent: None },
+    SubdivisionData { code: "PY-5", name: "Caaguazú", kind: "Department", parent: None },
+    SubdivisionData { code: "PY-6", name: "Caazapá", kind: "Department", parent: None },
+    SubdivisionData { code: "PY-7", name: "Itapúa", kind: "Department", parent: None },
+    SubdivisionData { code: "PY-8", name: "Misiones", kind: "Department", parent: None },
+    SubdivisionData { code: "PY-9", name: "Paraguarí", kind: "Department", parent: None },
+    SubdivisionData { code: "PY-ASU", name: "Asunción", kind: "Capital", parent: None },
+    SubdivisionData { code: "QA-DA", name: "Ad Dawḩah", kind: "Municipality", parent: None },
+    SubdivisionData { code: "QA-KH", name: "Al Khawr wa adh Dhakhīrah", kind: "Municipality", parent: None },
+    SubdivisionData { code: "QA-MS", name: "Ash Shamāl", kind: "Municipality", parent: None },
+    SubdivisionData { code: "QA-RA", name: "Ar Rayyān", kind: "Municipality", parent: None },
+    SubdivisionData { code: "QA-SH", name: "Ash Shīḩānīyah", kind: "Municipality", parent: None },
+    SubdivisionData { code: "QA-US", name: "Umm Şalāl", kind: "Municipality", parent: None },
+    SubdivisionData { code: "QA-WA", name: "Al Wakrah", kind: "Municipality", parent: None },
+    SubdivisionData { code: "QA-ZA", name: "Az̧ Z̧a‘āyin", kind: "Municipality", parent: None },
+    SubdivisionData { code: "RO-AB", name: "Alba", kind: "Department", parent: None },
+    SubdivisionData { code: "RO-AG", name: "Argeș", kind: "Department", parent: None },
+    SubdivisionData { code: "RO-AR", name: "Arad", kind: "Department", parent: None },
+    SubdivisionData { code: "RO-B", name: "București", kind: "Municipality", parent: None },
+    SubdivisionData { code: "RO-BC", name: "Bacău", kind: "Department", parent: None },
+    SubdivisionData { code: "RO-BH", name: "Bihor", kind: "Department", parent: None },
+    SubdivisionData { code: "RO-BN", name: "Bistrița-Năsăud", kind: "Department", parent: None },
+    SubdivisionData { code: "RO-BR", name: "Brăila", kind: "Department", parent: None },
+    SubdivisionData { code: "RO-BT", name: "Botoșani", kind: "Department", parent: None },
+    SubdivisionData { code: "RO-BV", name: "Brașov", kind: "Department", parent: None },
+    SubdivisionData { code: "RO-BZ", name: "Buzău", kind: "Department", parent: None },
+    SubdivisionData { code: "RO-CJ", name: "Cluj", kind: "Department", parent: None },
+    SubdivisionData { code: "RO-CL", name: "Călărași", kind: "Department", parent: None },
+    SubdivisionData { code: "RO-CS", name: "Caraș-Severin", kind: "Department", parent: None },
+    SubdivisionData { code: "RO-CT", name: "Constanța", kind: "Department", parent: None },
+    SubdivisionData { code: "RO-CV", name: "Covasna", kind: "Department", parent: None },
+    SubdivisionData { code: "RO-DB", name: "Dâmbovița", kind: "Department", parent: None },
+    SubdivisionData { code: "RO-DJ", name: "Dolj", kind: "Department", parent: None },
+    SubdivisionData { code: "RO-GJ", name: "Gorj", kind: "Department", parent: None },
+    SubdivisionData { code: "RO-GL", name: "Galați", kind: "Department", parent: None },
+    SubdivisionData { code: "RO-GR", name: "Giurgiu", kind: "Department", parent: None },
+    SubdivisionData { code: "RO-HD", name: "Hunedoara", kind: "Department", parent: None },
+    SubdivisionData { code: "RO-HR", name: "Harghita", kind: "Department", parent: None },
+    SubdivisionData { code: "RO-IF", name: "Ilfov", kind: "Department", parent: None },
+    SubdivisionData { code: "RO-IL", name: "Ialomița", kind: "Department", parent: None },
+    SubdivisionData { code: "RO-IS", name: "Iași", kind: "Department", parent: None },
+    SubdivisionData { code: "RO-MH", name: "Mehedinți", kind: "Department", parent: None },
+    SubdivisionData { code: "RO-MM", name: "Maramureș", kind: "Department", parent: None },
+    SubdivisionData { code: "RO-MS", name: "Mureș", kind: "Department", parent: None },
+    SubdivisionData { code: "RO-NT", name: "Neamț", kind: "Department", parent: None },
+    SubdivisionData { code: "RO-OT", name: "Olt", kind: "Department", parent: None },
+    SubdivisionData { code: "RO-PH", name: "Prahova", kind: "Department", parent: None },
+    SubdivisionData { code: "RO-SB", name: "Sibiu", kind: "Department", parent: None },
+    SubdivisionData { code: "RO-SJ", name: "Sălaj", kind: "Department", parent: None },
+    SubdivisionData { code: "RO-SM", name: "Satu Mare", kind: "Department", parent: None },
+    SubdivisionData { code: "RO-SV", name: "Suceava", kind: "Department", parent: None },
+    SubdivisionData { code: "RO-TL", name: "Tulcea", kind: "Department", parent: None },
+    SubdivisionData { code: "RO-TM", name: "Timiș", kind: "Department", parent: None },
+    SubdivisionData { code: "RO-TR", name: "Teleorman", kind: "Department", parent: None },
+    SubdivisionData { code: "RO-VL", name: "Vâlcea", kind: "Department", parent: None },
+    SubdivisionData { code: "RO-VN", name: "Vrancea", kind: "Department", parent: None },
+    SubdivisionData { code: "RO-VS", name: "Vaslui", kind: "Department", parent: None },
+    SubdivisionData { code: "RS-00", name: "Beograd", kind: "City", parent: None },
+    SubdivisionData { code: "RS-01", name: "Severnobački okrug", kind: "District", parent: Some("RS-VO") },
+    SubdivisionData { code: "RS-02", name: "Srednjebanatski okrug", kind: "District", parent: Some("RS-VO") },
+    SubdivisionData { code: "RS-03", name: "Severnobanatski okrug", kind: "District", parent: Some("RS-VO") },
+    SubdivisionData { code: "RS-04", name: "Južnobanatski okrug", kind: "District", parent: Some("RS-VO") },
+    SubdivisionData { code: "RS-05", name: "Zapadnobački okrug", kind: "District", parent: Some("RS-VO") },
+    SubdivisionData { code: "RS-06", name: "Južnobački okrug", kind: "District", parent: Some("RS-VO") },
+    SubdivisionData { code: "RS-07", name: "Sremski okrug", kind: "District", parent: Some("RS-VO") },
+    SubdivisionData { code: "RS-08", name: "Mačvanski okrug", kind: "District", parent: None },
+    SubdivisionData { code: "RS-09", name: "Kolubarski okrug", kind: "District", parent: None },
+    SubdivisionData { code: "RS-10", name: "Podunavski okrug", kind: "District", parent: None },
+    SubdivisionData { code: "RS-11", name: "Braničevski okrug", kind: "District", parent: None },
+    SubdivisionData { code: "RS-12", name: "Šumadijski okrug", kind: "District", parent: None },
+    SubdivisionData { code: "RS-13", name: "Pomoravski okrug", kind: "District", parent: None },
+    SubdivisionData { code: "RS-14", name: "Borski okrug", kind: "District", parent: None },
+    SubdivisionData { code: "RS-15", name: "Zaječarski okrug", kind: "District", parent: None },
+    SubdivisionData { code: "RS-16", name: "Zlatiborski okrug", kind: "District", parent: None },
+    SubdivisionData { code: "RS-17", name: "Moravički okrug", kind: "District", parent: None },
+    SubdivisionData { code: "RS-18", name: "Raški okrug", kind: "District", parent: None },
+    SubdivisionData { code: "RS-19", name: "Rasinski okrug", kind: "District", parent: None },
+    SubdivisionData { code: "RS-20", name: "Nišavski okrug", kind: "District", parent: None },
+    SubdivisionData { code: "RS-21", name: "Toplički okrug", kind: "District", parent: None },
+    SubdivisionData { code: "RS-22", name: "Pirotski okrug", kind: "District", parent: None },
+    SubdivisionData { code: "RS-23", name: "Jablanički okrug", kind: "District", parent: None },
+    SubdivisionData { code: "RS-24", name: "Pčinjski okrug", kind: "District", parent: None },
+    SubdivisionData { code: "RS-25", name: "Kosovski okrug", kind: "District", parent: Some("RS-KM") },
+    SubdivisionData { code: "RS-26", name: "Pećki okrug", kind: "District", parent: Some("RS-KM") },
+    SubdivisionData { code: "RS-27", name: "Prizrenski okrug", kind: "District", parent: Some("RS-KM") },
+    SubdivisionData { code: "RS-28", name: "Kosovsko-Mitrovački okrug", kind: "District", parent: Some("RS-KM") },
+    SubdivisionData { code: "RS-29", name: "Kosovsko-Pomoravski okrug", kind: "District", parent: Some("RS-KM") },
+    SubdivisionData { code: "RS-KM", name: "Kosovo-Metohija", kind: "Autonomous province", parent: None },
+    SubdivisionData { code: "RS-VO", name: "Vojvodina", kind: "Autonomous province", parent: None },
+    SubdivisionData { code: "RU-AD", name: "Adygeja, Respublika", kind: "Republic", parent: None },
+    SubdivisionData { code: "RU-AL", name: "Altaj, Respublika", kind: "Republic", parent: None },
+    SubdivisionData { code: "RU-ALT", name: "Altajskij kraj", kind: "Administrative territory", parent: None },
+    SubdivisionData { code: "RU-AMU", name: "Amurskaja oblast'", kind: "Administrative region", parent: None },
+    SubdivisionData { code: "RU-ARK", name: "Arhangel'skaja oblast'", kind: "Administrative region", parent: None },
+    SubdivisionData { code: "RU-AST", name: "Astrahanskaja oblast'", kind: "Administrative region", parent: None },
+    SubdivisionData { code: "RU-BA", name: "Bashkortostan, Respublika", kind: "Republic", parent: None },
+    SubdivisionData { code: "RU-BEL", name: "Belgorodskaja oblast'", kind: "Administrative region", parent: None },
+    SubdivisionData { code: "RU-BRY", name: "Brjanskaja oblast'", kind: "Administrative region", parent: None },
+    SubdivisionData { code: "RU-BU", name: "Burjatija, Respublika", kind: "Republic", parent: None },
+    SubdivisionData { code: "RU-CE", name: "Chechenskaya Respublika", kind: "Republic", parent: None },
+    SubdivisionData { code: "RU-CHE", name: "Chelyabinskaya oblast'", kind: "Administrative region", parent: None },
+    SubdivisionData { code: "RU-CHU", name: "Chukotskiy avtonomnyy okrug", kind: "Autonomous district", parent: None },
+    SubdivisionData { code: "RU-CU", name: "Chuvashskaya Respublika", kind: "Republic", parent: None },
+    SubdivisionData { code: "RU-DA", name: "Dagestan, Respublika", kind: "Republic", parent: None },
+    SubdivisionData { code: "RU-IN", name: "Ingushetiya, Respublika", kind: "Republic", parent: None },
+    SubdivisionData { code: "RU-IRK", name: "Irkutskaja oblast'", kind: "Administrative region", parent: None },
+    SubdivisionData { code: "RU-IVA", name: "Ivanovskaja oblast'", kind: "Administrative region", parent: None },
+    SubdivisionData { code: "RU-KAM", name: "Kamchatskiy kray", kind: "Administrative territory", parent: None },
+    SubdivisionData { code: "RU-KB", name: "Kabardino-Balkarskaja Respublika", kind: "Republic", parent: None },
+    SubdivisionData { code: "RU-KC", name: "Karachayevo-Cherkesskaya Respublika", kind: "Republic", parent: None },
+    SubdivisionData { code: "RU-KDA", name: "Krasnodarskij kraj", kind: "Administrative territory", parent: None },
+    SubdivisionData { code: "RU-KEM", name: "Kemerovskaja oblast'", kind: "Administrative region", parent: None },
+    SubdivisionData { code: "RU-KGD", name: "Kaliningradskaja oblast'", kind: "Administrative region", parent: None },
+    SubdivisionData { code: "RU-KGN", name: "Kurganskaja oblast'", kind: "Administrative region", parent: None },
+    SubdivisionData { code: "RU-KHA", name: "Habarovskij kraj", kind: "Administrative territory", parent: None },
+    SubdivisionData { code: "RU-KHM", name: "Hanty-Mansijskij avtonomnyj okrug", kind: "Autonomous district", parent: None },
+    SubdivisionData { code: "RU-KIR", name: "Kirovskaja oblast'", kind: "Administrative region", parent: None },
+    SubdivisionData { code: "RU-KK", name: "Hakasija, Respublika", kind: "Republic", parent: None },
+    SubdivisionData { code: "RU-KL", name: "Kalmykija, Respublika", kind: "Republic", parent: None },
+    SubdivisionData { code: "RU-KLU", name: "Kaluzhskaya oblast'", kind: "Administrative region", parent: None },
+    SubdivisionData { code: "RU-KO", name: "Komi, Respublika", kind: "Republic", parent: None },
+    SubdivisionData { code: "RU-KOS", name: "Kostromskaja oblast'", kind: "Administrative region", parent: None },
+    SubdivisionData { code: "RU-KR", name: "Karelija, Respublika", kind: "Republic", parent: None },
+    SubdivisionData { code: "RU-KRS", name: "Kurskaja oblast'", kind: "Administrative region", parent: None },
+    SubdivisionData { code: "RU-KYA", name: "Krasnojarskij kraj", kind: "Administrative territory", parent: None },
+    SubdivisionData { code: "RU-LEN", name: "Leningradskaja oblast'", kind: "Administrative region", parent: None },
+    SubdivisionData { code: "RU-LIP", name: "Lipeckaja oblast'", kind: "Administrative region", parent: None },
+    SubdivisionData { code: "RU-MAG", name: "Magadanskaja oblast'", kind: "Administrative region", parent: None },
+    SubdivisionData { code: "RU-ME", name: "Marij Èl, Respublika", kind: "Republic", parent: None },
+    SubdivisionData { code: "RU-MO", name: "Mordovija, Respublika", kind: "Republic", parent: None },
+    SubdivisionData { code: "RU-MOS", name: "Moskovskaja oblast'", kind: "Administrative region", parent: None },
+    SubdivisionData { code: "RU-MOW", name: "Moskva", kind: "Autonomous city", parent: None },
+    SubdivisionData { code: "RU-MUR", name: "Murmanskaja oblast'", kind: "Administrative region", parent: None },
+    SubdivisionData { code: "RU-NEN", name: "Neneckij avtonomnyj okrug", kind: "Autonomous district", parent: None },
+    SubdivisionData { code: "RU-NGR", name: "Novgorodskaja oblast'", kind: "Administrative region", parent: None },
+    SubdivisionData { code: "RU-NIZ", name: "Nizhegorodskaya oblast'", kind: "Administrative region", parent: None },
+    SubdivisionData { code: "RU-NVS", name: "Novosibirskaja oblast'", kind: "Administrative region", parent: None },
+    SubdivisionData { code: "RU-OMS", name: "Omskaja oblast'", kind: "Administrative region", parent: None },
+    SubdivisionData { code: "RU-ORE", name: "Orenburgskaja oblast'", kind: "Administrative region", parent: None },
+    SubdivisionData { code: "RU-ORL", name: "Orlovskaja oblast'", kind: "Administrative region", parent: None },
+    SubdivisionData { code: "RU-PER", name: "Permskij kraj", kind: "Administrative territory", parent: None },
+    SubdivisionData { code: "RU-PNZ", name: "Penzenskaja oblast'", kind: "Administrative region", parent: None },
+    SubdivisionData { code: "RU-PRI", name: "Primorskij kraj", kind: "Administrative territory", parent: None },
+    SubdivisionData { code: "RU-PSK", name: "Pskovskaja oblast'", kind: "Administrative region", parent: None },
+    SubdivisionData { code: "RU-ROS", name: "Rostovskaja oblast'", kind: "Administrative region", parent: None },
+    SubdivisionData { code: "RU-RYA", name: "Rjazanskaja oblast'", kind: "Administrative region", parent: None },
+    SubdivisionData { code: "RU-SA", name: "Saha, Respublika", kind: "Republic", parent: None },
+    SubdivisionData { code: "RU-SAK", name: "Sahalinskaja oblast'", kind: "Administrative region", parent: None },
+    SubdivisionData { code: "RU-SAM", name: "Samarskaja oblast'", kind: "Administrative region", parent: None },
+    SubdivisionData { code: "RU-SAR", name: "Saratovskaja oblast'", kind: "Administrative region", parent: None },
+    SubdivisionData { code: "RU-SE", name: "Severnaja Osetija, Respublika", kind: "Republic", parent: None },
+    SubdivisionData { code: "RU-SMO", name: "Smolenskaja oblast'", kind: "Administrative region", parent: None },
+    SubdivisionData { code: "RU-SPE", name: "Sankt-Peterburg", kind: "Autonomous city", parent: None },
+    SubdivisionData { code: "RU-STA", name: "Stavropol'skij kraj", kind: "Administrative territory", parent: None },
+    SubdivisionData { code: "RU-SVE", name: "Sverdlovskaja oblast'", kind: "Administrative region", parent: None },
+    SubdivisionData { code: "RU-TA", name: "Tatarstan, Respublika", kind: "Republic", parent: None },
+    SubdivisionData { code: "RU-TAM", name: "Tambovskaja oblast'", kind: "Administrative region", parent: None },
+    SubdivisionData { code: "RU-TOM", name: "Tomskaja oblast'", kind: "Administrative region", parent: None },
+    SubdivisionData { code: "RU-TUL", name: "Tul'skaja oblast'", kind: "Administrative region", parent: None },
+    SubdivisionData { code: "RU-TVE", name: "Tverskaja oblast'", kind: "Administrative region", parent: None },
+    SubdivisionData { code: "RU-TY", name: "Tyva, Respublika", kind: "Republic", parent: None },
+    SubdivisionData { code: "RU-TYU", name: "Tjumenskaja oblast'", kind: "Administrative region", parent: None },
+    SubdivisionData { code: "RU-UD", name: "Udmurtskaja Respublika", kind: "Republic", parent: None },
+    SubdivisionData { code: "RU-ULY", name: "Ul'janovskaja oblast'", kind: "Administrative region", parent: None },
+    SubdivisionData { code: "RU-VGG", name: "Volgogradskaja oblast'", kind: "Administrative region", parent: None },
+    SubdivisionData { code: "RU-VLA", name: "Vladimirskaja oblast'", kind: "Administrative region", parent: None },
+    SubdivisionData { code: "RU-VLG", name: "Vologodskaja oblast'", kind: "Administrative region", parent: None },
+    SubdivisionData { code: "RU-VOR", name: "Voronezhskaya oblast'", kind: "Administrative region", parent: None },
+    SubdivisionData { code: "RU-YAN", name: "Jamalo-Neneckij avtonomnyj okrug", kind: "Autonomous district", parent: None },
+    SubdivisionData { code: "RU-YAR", name: "Jaroslavskaja oblast'", kind: "Administrative region", parent: None },
+    SubdivisionData { code: "RU-YEV", name: "Evrejskaja avtonomnaja oblast'", kind: "Autonomous region", parent: None },
+    SubdivisionData { code: "RU-ZAB", name: "Zabajkal'skij kraj", kind: "Administrative territory", parent: None },
+    SubdivisionData { code: "RW-01", name: "City of Kigali", kind: "City", parent: None },
+    SubdivisionData { code: "RW-02", name: "Eastern", kind: "Province", parent: None },
+    SubdivisionData { code: "RW-03", name: "Northern", kind: "Province", parent: None },
+    SubdivisionData { code: "RW-04", name: "Western", kind: "Province", parent: None },
+    SubdivisionData { code: "RW-05", name: "Southern", kind: "Province", parent: None },
+    SubdivisionData { code: "SA-01", name: "Ar Riyāḑ", kind: "Region", parent: None },
+    SubdivisionData { code: "SA-02", name: "Makkah al Mukarramah", kind: "Region", parent: None },
+    SubdivisionData { code: "SA-03", name: "Al Madīnah al Munawwarah", kind: "Region", parent: None },
+    SubdivisionData { code: "SA-04", name: "Ash Sharqīyah", kind: "Region", parent: None },
+    SubdivisionData { code: "SA-05", name: "Al Qaşīm", kind: "Region", parent: None },
+    SubdivisionData { code: "SA-06", name: "Ḩā'il", kind: "Region", parent: None },
+    SubdivisionData { code: "SA-07", name: "Tabūk", kind: "Region", parent: None },
+    SubdivisionData { code: "SA-08", name: "Al Ḩudūd ash Shamālīyah", kind: "Region", parent: None },
+    SubdivisionData { code: "SA-09", name: "Jāzān", kind: "Region", parent: None },
+    SubdivisionData { code: "SA-10", name: "Najrān", kind: "Region", parent: None },
+    SubdivisionData { code: "SA-11", name: "Al Bāḩah", kind: "Region", parent: None },
+    SubdivisionData { code: "SA-12", name: "Al Jawf", kind: "Region", parent: None },
+    SubdivisionData { code: "SA-14", name: "'Asīr", kind: "Region", parent: None },
+    SubdivisionData { code: "SB-CE", name: "Central", kind: "Province", parent: None },
+    SubdivisionData { code: "SB-CH", name: "Choiseul", kind: "Province", parent: None },
+    SubdivisionData { code: "SB-CT", name: "Capital Territory (Honiara)", kind: "Capital territory", parent: None },
+    SubdivisionData { code: "SB-GU", name: "Guadalcanal", kind: "Province", parent: None },
+    SubdivisionData { code: "SB-IS", name: "Isabel", kind: "Province", parent: None },
+    SubdivisionData { code: "SB-MK", name: "Makira-Ulawa", kind: "Province", parent: None },
+    SubdivisionData { code: "SB-ML", name: "Malaita", kind: "Province", parent: None },
+    SubdivisionData { code: "SB-RB", name: "Rennell and Bellona", kind: "Province", parent: None },
+    SubdivisionData { code: "SB-TE", name: "Temotu", kind: "Province", parent: None },
+    SubdivisionData { code: "SB-WE", name: "Western", kind: "Province", parent: None },
+    SubdivisionData { code: "SC-01", name: "Anse aux Pins", kind: "District", parent: None },
+    SubdivisionData { code: "SC-02", name: "Anse Boileau", kind: "District", parent: None },
+    SubdivisionData { code: "SC-03", name: "Anse Etoile", kind: "District", parent: None },
+    SubdivisionData { code: "SC-04", name: "Au Cap", kind: "District", parent: None },
+    SubdivisionData { code: "SC-05", name: "Anse Royale", kind: "District", parent: None },
+    SubdivisionData { code: "SC-06", name: "Baie Lazare", kind: "District", parent: None },
+    SubdivisionData { code: "SC-07", name: "Baie Sainte Anne", kind: "District", parent: None },
+    SubdivisionData { code: "SC-08", name: "Beau Vallon", kind: "District", parent: None },
+    SubdivisionData { code: "SC-09", name: "Bel Air", kind: "District", parent: None },
+    SubdivisionData { code: "SC-10", name: "Bel Ombre", kind: "District", parent: None },
+    SubdivisionData { code: "SC-11", name: "Cascade", kind: "District", parent: None },
+    SubdivisionData { code: "SC-12", name: "Glacis", kind: "District", parent: None },
+    SubdivisionData { code: "SC-13", name: "Grand Anse Mahe", kind: "District", parent: None },
+    SubdivisionData { code: "SC-14", name: "Grand Anse Praslin", kind: "District", parent: None },
+    SubdivisionData { code: "SC-15", name: "La Digue", kind: "District", parent: None },
+    SubdivisionData { code: "SC-16", name: "English River", kind: "District", parent: None },
+    SubdivisionData { code: "SC-17", name: "Mont Buxton", kind: "District", parent: None },
+    SubdivisionData { code: "SC-18", name: "Mont Fleuri", kind: "District", parent: None },
+    SubdivisionData { code: "SC-19", name: "Plaisance", kind: "District", parent: None },
+    SubdivisionData { code: "SC-20", name: "Pointe Larue", kind: "District", parent: None },
+    SubdivisionData { code: "SC-21", name: "Port Glaud", kind: "District", parent: None },
+    SubdivisionData { code: "SC-22", name: "Saint Louis", kind: "District", parent: None },
+    SubdivisionData { code: "SC-23", name: "Takamaka", kind: "District", parent: None },
+    SubdivisionData { code: "SC-24", name: "Les Mamelles", kind: "District", parent: None },
+    SubdivisionData { code: "SC-25", name: "Roche Caiman", kind: "District", parent: None },
+    SubdivisionData { code: "SC-26", name: "Ile Perseverance I", kind: "District", parent: None },
+    SubdivisionData { code: "SC-27", name: "Ile Perseverance II", kind: "District", parent: None },
+    SubdivisionData { code: "SD-DC", name: "Central Darfur", kind: "State", parent: None },
+    SubdivisionData { code: "SD-DE", name: "East Darfur", kind: "State", parent: None },
+    SubdivisionData { code: "SD-DN", name: "North Darfur", kind: "State", parent: None },
+    SubdivisionData { code: "SD-DS", name: "South Darfur", kind: "State", parent: None },
+    SubdivisionData { code: "SD-DW", name: "West Darfur", kind: "State", parent: None },
+    SubdivisionData { code: "SD-GD", name: "Gedaref", kind: "State", parent: None },
+    SubdivisionData { code: "SD-GK", name: "West Kordofan", kind: "State", parent: None },
+    SubdivisionData { code: "SD-GZ", name: "Gezira", kind: "State", parent: None },
+    SubdivisionData { code: "SD-KA", name: "Kassala", kind: "State", parent: None },
+    SubdivisionData { code: "SD-KH", name: "Khartoum", kind: "State", parent: None },
+    SubdivisionData { code: "SD-KN", name: "North Kordofan", kind: "State", parent: None },
+    SubdivisionData { code: "SD-KS", name: "South Kordofan", kind: "State", parent: None },
+    SubdivisionData { code: "SD-NB", name: "Blue Nile", kind: "State", parent: None },
+    SubdivisionData { code: "SD-NO", name: "Northern", kind: "State", parent: None },
+    SubdivisionData { code: "SD-NR", name: "River Nile", kind: "State", parent: None },
+    SubdivisionData { code: "SD-NW", name: "White Nile", kind: "State", parent: None },
+    SubdivisionData { code: "SD-RS", name: "Red Sea", kind: "State", parent: None },
+    SubdivisionData { code: "SD-SI", name: "Sennar", kind: "State", parent: None },
+    SubdivisionData { code: "SE-AB", name: "Stockholms län", kind: "County", parent: None },
+    SubdivisionData { code: "SE-AC", name: "Västerbottens län", kind: "County", parent: None },
+    SubdivisionData { code: "SE-BD", name: "Norrbottens län", kind: "County", parent: None },
+    SubdivisionData { code: "SE-C", name: "Uppsala län", kind: "County", parent: None },
+    SubdivisionData { code: "SE-D", name: "Södermanlands län", kind: "County", parent: None },
+    SubdivisionData { code: "SE-E", name: "Östergötlands län", kind: "County", parent: None },
+    SubdivisionData { code: "SE-F", name: "Jönköpings län", kind: "County", parent: None },
+    SubdivisionData { code: "SE-G", name: "Kronobergs län", kind: "County", parent: None },
+    SubdivisionData { code: "SE-H", name: "Kalmar län", kind: "County", parent: None },
+    SubdivisionData { code: "SE-I", name: "Gotlands län", kind: "County", parent: None },
+    SubdivisionData { code: "SE-K", name: "Blekinge län", kind: "County", parent: None },
+    SubdivisionData { code: "SE-M", name: "Skåne län", kind: "County", parent: None },
+    SubdivisionData { code: "SE-N", name: "Hallands län", kind: "County", parent: None },
+    SubdivisionData { code: "SE-O", name: "Västra Götalands län", kind: "County", parent: None },
+    SubdivisionData { code: "SE-S", name: "Värmlands län", kind: "County", parent: None },
+    SubdivisionData { code: "SE-T", name: "Örebro län", kind: "County", parent: None },
+    SubdivisionData { code: "SE-U", name: "Västmanlands län", kind: "County", parent: None },
+    SubdivisionData { code: "SE-W", name: "Dalarnas län", kind: "County", parent: None },
+    SubdivisionData { code: "SE-X", name: "Gävleborgs län", kind: "County", parent: None },
+    SubdivisionData { code: "SE-Y", name: "Västernorrlands län", kind: "County", parent: None },
+    SubdivisionData { code: "SE-Z", name: "Jämtlands län", kind: "County", parent: None },
+    SubdivisionData { code: "SG-01", name: "Central Singapore", kind: "District", parent: None },
+    SubdivisionData { code: "SG-02", name: "North East", kind: "District", parent: None },
+    SubdivisionData { code: "SG-03", name: "North West", kind: "District", parent: None },
+    SubdivisionData { code: "SG-04", name: "South East", kind: "District", parent: None },
+    SubdivisionData { code: "SG-05", name: "South West", kind: "District", parent: None },
+    SubdivisionData { code: "SH-AC", name: "Ascension", kind: "Geographical entity", parent: None },
+    SubdivisionData { code: "SH-HL", name: "Saint Helena", kind: "Geographical entity", parent: None },
+    SubdivisionData { code: "SH-TA", name: "Tristan da Cunha", kind: "Geographical entity", parent: None },
+    SubdivisionData { code: "SI-001", name: "Ajdovščina", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-002", name: "Beltinci", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-003", name: "Bled", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-004", name: "Bohinj", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-005", name: "Borovnica", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-006", name: "Bovec", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-007", name: "Brda", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-008", name: "Brezovica", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-009", name: "Brežice", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-010", name: "Tišina", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-011", name: "Celje", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-012", name: "Cerklje na Gorenjskem", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-013", name: "Cerknica", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-014", name: "Cerkno", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-015", name: "Črenšovci", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-016", name: "Črna na Koroškem", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-017", name: "Črnomelj", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-018", name: "Destrnik", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-019", name: "Divača", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-020", name: "Dobrepolje", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-021", name: "Dobrova-Polhov Gradec", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-022", name: "Dol pri Ljubljani", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-023", name: "Domžale", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-024", name: "Dornava", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-025", name: "Dravograd", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-026", name: "Duplek", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-027", name: "Gorenja vas-Poljane", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-028", name: "Gorišnica", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-029", name: "Gornja Radgona", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-030", name: "Gornji Grad", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-031", name: "Gornji Petrovci", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-032", name: "Grosuplje", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-033", name: "Šalovci", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-034", name: "Hrastnik", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-035", name: "Hrpelje-Kozina", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-036", name: "Idrija", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-037", name: "Ig", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-038", name: "Ilirska Bistrica", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-039", name: "Ivančna Gorica", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-040", name: "Izola", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-041", name: "Jesenice", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-042", name: "Juršinci", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-043", name: "Kamnik", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-044", name: "Kanal", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-045", name: "Kidričevo", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-046", name: "Kobarid", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-047", name: "Kobilje", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-048", name: "Kočevje", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-049", name: "Komen", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-050", name: "Koper", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-051", name: "Kozje", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-052", name: "Kranj", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-053", name: "Kranjska Gora", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-054", name: "Krško", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-055", name: "Kungota", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-056", name: "Kuzma", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-057", name: "Laško", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-058", name: "Lenart", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-059", name: "Lendava", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-060", name: "Litija", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-061", name: "Ljubljana", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-062", name: "Ljubno", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-063", name: "Ljutomer", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-064", name: "Logatec", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-065", name: "Loška dolina", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-066", name: "Loški Potok", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-067", name: "Luče", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-068", name: "Lukovica", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-069", name: "Majšperk", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-070", name: "Maribor", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-071", name: "Medvode", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-072", name: "Mengeš", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-073", name: "Metlika", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-074", name: "Mežica", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-075", name: "Miren-Kostanjevica", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-076", name: "Mislinja", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-077", name: "Moravče", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-078", name: "Moravske Toplice", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-079", name: "Mozirje", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-080", name: "Murska Sobota", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-081", name: "Muta", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-082", name: "Naklo", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-083", name: "Nazarje", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-084", name: "Nova Gorica", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-085", name: "Novo Mesto", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-086", name: "Odranci", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-087", name: "Ormož", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-088", name: "Osilnica", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-089", name: "Pesnica", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-090", name: "Piran", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-091", name: "Pivka", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-092", name: "Podčetrtek", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-093", name: "Podvelka", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-094", name: "Postojna", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-095", name: "Preddvor", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-096", name: "Ptuj", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-097", name: "Puconci", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-098", name: "Rače-Fram", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-099", name: "Radeče", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-100", name: "Radenci", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-101", name: "Radlje ob Dravi", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-102", name: "Radovljica", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-103", name: "Ravne na Koroškem", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-104", name: "Ribnica", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-105", name: "Rogašovci", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-106", name: "Rogaška Slatina", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-107", name: "Rogatec", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-108", name: "Ruše", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-109", name: "Semič", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-110", name: "Sevnica", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-111", name: "Sežana", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-112", name: "Slovenj Gradec", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-113", name: "Slovenska Bistrica", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-114", name: "Slovenske Konjice", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-115", name: "Starše", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-116", name: "Sveti Jurij ob Ščavnici", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-117", name: "Šenčur", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-118", name: "Šentilj", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-119", name: "Šentjernej", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-120", name: "Šentjur", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-121", name: "Škocjan", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-122", name: "Škofja Loka", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-123", name: "Škofljica", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-124", name: "Šmarje pri Jelšah", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-125", name: "Šmartno ob Paki", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-126", name: "Šoštanj", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-127", name: "Štore", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-128", name: "Tolmin", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-129", name: "Trbovlje", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-130", name: "Trebnje", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-131", name: "Tržič", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-132", name: "Turnišče", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-133", name: "Velenje", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-134", name: "Velike Lašče", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-135", name: "Videm", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-136", name: "Vipava", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-137", name: "Vitanje", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-138", name: "Vodice", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-139", name: "Vojnik", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-140", name: "Vrhnika", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-141", name: "Vuzenica", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-142", name: "Zagorje ob Savi", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-143", name: "Zavrč", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-144", name: "Zreče", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-146", name: "Železniki", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-147", name: "Žiri", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-148", name: "Benedikt", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-149", name: "Bistrica ob Sotli", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-150", name: "Bloke", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-151", name: "Braslovče", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-152", name: "Cankova", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-153", name: "Cerkvenjak", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-154", name: "Dobje", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-155", name: "Dobrna", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-156", name: "Dobrovnik", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-157", name: "Dolenjske Toplice", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-158", name: "Grad", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-159", name: "Hajdina", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-160", name: "Hoče-Slivnica", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-161", name: "Hodoš", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-162", name: "Horjul", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-163", name: "Jezersko", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-164", name: "Komenda", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-165", name: "Kostel", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-166", name: "Križevci", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-167", name: "Lovrenc na Pohorju", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-168", name: "Markovci", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-169", name: "Miklavž na Dravskem polju", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-170", name: "Mirna Peč", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-171", name: "Oplotnica", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-172", name: "Podlehnik", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-173", name: "Polzela", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-174", name: "Prebold", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-175", name: "Prevalje", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-176", name: "Razkrižje", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-177", name: "Ribnica na Pohorju", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-178", name: "Selnica ob Dravi", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-179", name: "Sodražica", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-180", name: "Solčava", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-181", name: "Sveta Ana", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-182", name: "Sveti Andraž v Slovenskih goricah", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-183", name: "Šempeter-Vrtojba", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-184", name: "Tabor", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-185", name: "Trnovska Vas", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-186", name: "Trzin", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-187", name: "Velika Polana", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-188", name: "Veržej", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-189", name: "Vransko", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-190", name: "Žalec", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-191", name: "Žetale", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-192", name: "Žirovnica", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-193", name: "Žužemberk", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-194", name: "Šmartno pri Litiji", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-195", name: "Apače", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-196", name: "Cirkulane", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-197", name: "Kosanjevica na Krki", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-198", name: "Makole", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-199", name: "Mokronog-Trebelno", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-200", name: "Poljčane", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-201", name: "Renče-Vogrsko", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-202", name: "Središče ob Dravi", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-203", name: "Straža", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-204", name: "Sveta Trojica v Slovenskih goricah", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-205", name: "Sveti Tomaž", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-206", name: "Šmarješke Toplice", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-207", name: "Gorje", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-208", name: "Log-Dragomer", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-209", name: "Rečica ob Savinji", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-210", name: "Sveti Jurij v Slovenskih goricah", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-211", name: "Šentrupert", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-212", name: "Mirna", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SI-213", name: "Ankaran", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SK-BC", name: "Banskobystrický kraj", kind: "Region", parent: None },
+    SubdivisionData { code: "SK-BL", name: "Bratislavský kraj", kind: "Region", parent: None },
+    SubdivisionData { code: "SK-KI", name: "Košický kraj", kind: "Region", parent: None },
+    SubdivisionData { code: "SK-NI", name: "Nitriansky kraj", kind: "Region", parent: None },
+    SubdivisionData { code: "SK-PV", name: "Prešovský kraj", kind: "Region", parent: None },
+    SubdivisionData { code: "SK-TA", name: "Trnavský kraj", kind: "Region", parent: None },
+    SubdivisionData { code: "SK-TC", name: "Trenčiansky kraj", kind: "Region", parent: None },
+    SubdivisionData { code: "SK-ZI", name: "Žilinský kraj", kind: "Region", parent: None },
+    SubdivisionData { code: "SL-E", name: "Eastern", kind: "Province", parent: None },
+    SubdivisionData { code: "SL-N", name: "Northern", kind: "Province", parent: None },
+    SubdivisionData { code: "SL-NW", name: "North Western", kind: "Province", parent: None },
+    SubdivisionData { code: "SL-S", name: "Southern", kind: "Province", parent: None },
+    SubdivisionData { code: "SL-W", name: "Western Area (Freetown)", kind: "Area", parent: None },
+    SubdivisionData { code: "SM-01", name: "Acquaviva", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SM-02", name: "Chiesanuova", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SM-03", name: "Domagnano", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SM-04", name: "Faetano", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SM-05", name: "Fiorentino", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SM-06", name: "Borgo Maggiore", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SM-07", name: "Città di San Marino", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SM-08", name: "Montegiardino", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SM-09", name: "Serravalle", kind: "Municipality", parent: None },
+    SubdivisionData { code: "SN-DB", name: "Diourbel", kind: "Region", parent: None },
+    SubdivisionData { code: "SN-DK", name: "Dakar", kind: "Region", parent: None },
+    SubdivisionData { code: "SN-FK", name: "Fatick", kind: "Region", parent: None },
+    SubdivisionData { code: "SN-KA", name: "Kaffrine", kind: "Region", parent: None },
+    SubdivisionData { code: "SN-KD", name: "Kolda", kind: "Region", parent: None },
+    SubdivisionData { code: "SN-KE", name: "Kédougou", kind: "Region", parent: None },
+    SubdivisionData { code: "SN-KL", name: "Kaolack", kind: "Region", parent: None },
+    SubdivisionData { code: "SN-LG", name: "Louga", kind: "Region", parent: None },
+    SubdivisionData { code: "SN-MT", name: "Matam", kind: "Region", parent: None },
+    SubdivisionData { code: "SN-SE", name: "Sédhiou", kind: "Region", parent: None },
+    SubdivisionData { code: "SN-SL", name: "Saint-Louis", kind: "Region", parent: None },
+    SubdivisionData { code: "SN-TC", name: "Tambacounda", kind: "Region", parent: None },
+    SubdivisionData { code: "SN-TH", name: "Thiès", kind: "Region", parent: None },
+    SubdivisionData { code: "SN-ZG", name: "Ziguinchor", kind: "Region", parent: None },
+    SubdivisionData { code: "SO-AW", name: "Awdal", kind: "Region", parent: None },
+    SubdivisionData { code: "SO-BK", name: "Bakool", kind: "Region", parent: None },
+    SubdivisionData { code: "SO-BN", name: "Banaadir", kind: "Region", parent: None },
+    SubdivisionData { code: "SO-BR", name: "Bari", kind: "Region", parent: None },
+    SubdivisionData { code: "SO-BY", name: "Bay", kind: "Region", parent: None },
+    SubdivisionData { code: "SO-GA", name: "Galguduud", kind: "Region", parent: None },
+    SubdivisionData { code: "SO-GE", name: "Gedo", kind: "Region", parent: None },
+    SubdivisionData { code: "SO-HI", name: "Hiiraan", kind: "Region", parent: None },
+    SubdivisionData { code: "SO-JD", name: "Jubbada Dhexe", kind: "Region", parent: None },
+    SubdivisionData { code: "SO-JH", name: "Jubbada Hoose", kind: "Region", parent: None },
+    SubdivisionData { code: "SO-MU", name: "Mudug", kind: "Region", parent: None },
+    SubdivisionData { code: "SO-NU", name: "Nugaal", kind: "Region", parent: None },
+    SubdivisionData { code: "SO-SA", name: "Sanaag", kind: "Region", parent: None },
+    SubdivisionData { code: "SO-SD", name: "Shabeellaha Dhexe", kind: "Region", parent: None },
+    SubdivisionData { code: "SO-SH", name: "Shabeellaha Hoose", kind: "Region", parent: None },
+    SubdivisionData { code: "SO-SO", name: "Sool", kind: "Region", parent: None },
+    SubdivisionData { code: "SO-TO", name: "Togdheer", kind: "Region", parent: None },
+    SubdivisionData { code: "SO-WO", name: "Woqooyi Galbeed", kind: "Region", parent: None },
+    SubdivisionData { code: "SR-BR", name: "Brokopondo", kind: "District", parent: None },
+    SubdivisionData { code: "SR-CM", name: "Commewijne", kind: "District", parent: None },
+    SubdivisionData { code: "SR-CR", name: "Coronie", kind: "District", parent: None },
+    SubdivisionData { code: "SR-MA", name: "Marowijne", kind: "District", parent: None },
+    SubdivisionData { code: "SR-NI", name: "Nickerie", kind: "District", parent: None },
+    SubdivisionData { code: "SR-PM", name: "Paramaribo", kind: "District", parent: None },
+    SubdivisionData { code: "SR-PR", name: "Para", kind: "District", parent: None },
+    SubdivisionData { code: "SR-SA", name: "Saramacca", kind: "District", parent: None },
+    SubdivisionData { code: "SR-SI", name: "Sipaliwini", kind: "District", parent: None },
+    SubdivisionData { code: "SR-WA", name: "Wanica", kind: "District", parent: None },
+    SubdivisionData { code: "SS-BN", name: "Northern Bahr el Ghazal", kind: "State", parent: None },
+    SubdivisionData { code: "SS-BW", name: "Western Bahr el Ghazal", kind: "State", parent: None },
+    SubdivisionData { code: "SS-EC", name: "Central Equatoria", kind: "State", parent: None },
+    SubdivisionData { code: "SS-EE", name: "Eastern Equatoria", kind: "State", parent: None },
+    SubdivisionData { code: "SS-EW", name: "Western Equatoria", kind: "State", parent: None },
+    SubdivisionData { code: "SS-JG", name: "Jonglei", kind: "State", parent: None },
+    SubdivisionData { code: "SS-LK", name: "Lakes", kind: "State", parent: None },
+    SubdivisionData { code: "SS-NU", name: "Upper Nile", kind: "State", parent: None },
+    SubdivisionData { code: "SS-UY", name: "Unity", kind: "State", parent: None },
+    SubdivisionData { code: "SS-WR", name: "Warrap", kind: "State", parent: None },
+    SubdivisionData { code: "ST-01", name: "Água Grande", kind: "District", parent: None },
+    SubdivisionData { code: "ST-02", name: "Cantagalo", kind: "District", parent: None },
+    SubdivisionData { code: "ST-03", name: "Caué", kind: "District", parent: None },
+    SubdivisionData { code: "ST-04", name: "Lembá", kind: "District", parent: None },
+    SubdivisionData { code: "ST-05", name: "Lobata", kind: "District", parent: None },
+    SubdivisionData { code: "ST-06", name: "Mé-Zóchi", kind: "District", parent: None },
+    SubdivisionData { code: "ST-P", name: "Príncipe", kind: "Autonomous region", parent: None },
+    SubdivisionData { code: "SV-AH", name: "Ahuachapán", kind: "Department", parent: None },
+    SubdivisionData { code: "SV-CA", name: "Cabañas", kind: "Department", parent: None },
+    SubdivisionData { code: "SV-CH", name: "Chalatenango", kind: "Department", parent: None },
+    SubdivisionData { code: "SV-CU", name: "Cuscatlán", kind: "Department", parent: None },
+    SubdivisionData { code: "SV-LI", name: "La Libertad", kind: "Department", parent: None },
+    SubdivisionData { code: "SV-MO", name: "Morazán", kind: "Department", parent: None },
+    SubdivisionData { code: "SV-PA", name: "La Paz", kind: "Department", parent: None },
+    SubdivisionData { code: "SV-SA", name: "Santa Ana", kind: "Department", parent: None },
+    SubdivisionData { code: "SV-SM", name: "San Miguel", kind: "Department", parent: None },
+    SubdivisionData { code: "SV-SO", name: "Sonsonate", kind: "Department", parent: None },
+    SubdivisionData { code: "SV-SS", name: "San Salvador", kind: "Department", parent: None },
+    SubdivisionData { code: "SV-SV", name: "San Vicente", kind: "Department", parent: None },
+    SubdivisionData { code: "SV-UN", name: "La Unión", kind: "Department", parent: None },
+    SubdivisionData { code: "SV-US", name: "Usulután", kind: "Department", parent: None },
+    SubdivisionData { code: "SY-DI", name: "Dimashq", kind: "Province", parent: None },
+    SubdivisionData { code: "SY-DR", name: "Dar'ā", kind: "Province", parent: None },
+    SubdivisionData { code: "SY-DY", name: "Dayr az Zawr", kind: "Province", parent: None },
+    SubdivisionData { code: "SY-HA", name: "Al Ḩasakah", kind: "Province", parent: None },
+    SubdivisionData { code: "SY-HI", name: "Ḩimş", kind: "Province", parent: None },
+    SubdivisionData { code: "SY-HL", name: "Ḩalab", kind: "Province", parent: None },
+    SubdivisionData { code: "SY-HM", name: "Ḩamāh", kind: "Province", parent: None },
+    SubdivisionData { code: "SY-ID", name: "Idlib", kind: "Province", parent: None },
+    SubdivisionData { code: "SY-LA", name: "Al Lādhiqīyah", kind: "Province", parent: None },
+    SubdivisionData { code: "SY-QU", name: "Al Qunayţirah", kind: "Province", parent: None },
+    SubdivisionData { code: "SY-RA", name: "Ar Raqqah", kind: "Province", parent: None },
+    SubdivisionData { code: "SY-RD", name: "Rīf Dimashq", kind: "Province", parent: None },
+    SubdivisionData { code: "SY-SU", name: "As Suwaydā'", kind: "Province", parent: None },
+    SubdivisionData { code: "SY-TA", name: "Ţarţūs", kind: "Province", parent: None },
+    SubdivisionData { code: "SZ-HH", name: "Hhohho", kind: "Region", parent: None },
+    SubdivisionData { code: "SZ-LU", name: "Lubombo", kind: "Region", parent: None },
+    SubdivisionData { code: "SZ-MA", name: "Manzini", kind: "Region", parent: None },
+    SubdivisionData { code: "SZ-SH", name: "Shiselweni", kind: "Region", parent: None },
+    SubdivisionData { code: "TD-BA", name: "Al Baţḩā’", kind: "Province", parent: None },
+    SubdivisionData { code: "TD-BG", name: "Bahr el Ghazal", kind: "Province", parent: None },
+    SubdivisionData { code: "TD-BO", name: "Borkou", kind: "Province", parent: None },
+    SubdivisionData { code: "TD-CB", name: "Chari-Baguirmi", kind: "Province", parent: None },
+    SubdivisionData { code: "TD-EE", name: "Ennedi-Est", kind: "Province", parent: None },
+    SubdivisionData { code: "TD-EO", name: "Ennedi-Ouest", kind: "Province", parent: None },
+    SubdivisionData { code: "TD-GR", name: "Guéra", kind: "Province", parent: None },
+    SubdivisionData { code: "TD-HL", name: "Hadjer Lamis", kind: "Province", parent: None },
+    SubdivisionData { code: "TD-KA", name: "Kanem", kind: "Province", parent: None },
+    SubdivisionData { code: "TD-LC", name: "Al Buḩayrah", kind: "Province", parent: None },
+    SubdivisionData { code: "TD-LO", name: "Logone-Occidental", kind: "Province", parent: None },
+    SubdivisionData { code: "TD-LR", name: "Logone-Oriental", kind: "Province", parent: None },
+    SubdivisionData { code: "TD-MA", name: "Mandoul", kind: "Province", parent: None },
+    SubdivisionData { code: "TD-MC", name: "Moyen-Chari", kind: "Province", parent: None },
+    SubdivisionData { code: "TD-ME", name: "Mayo-Kebbi-Est", kind: "Province", parent: None },
+    SubdivisionData { code: "TD-MO", name: "Mayo-Kebbi-Ouest", kind: "Province", parent: None },
+    SubdivisionData { code: "TD-ND", name: "Madīnat Injamīnā", kind: "Province", parent: None },
+    SubdivisionData { code: "TD-OD", name: "Ouaddaï", kind: "Province", parent: None },
+    SubdivisionData { code: "TD-SA", name: "Salamat", kind: "Province", parent: None },
+    SubdivisionData { code: "TD-SI", name: "Sila", kind: "Province", parent: None },
+    SubdivisionData { code: "TD-TA", name: "Tandjilé", kind: "Province", parent: None },
+    SubdivisionData { code: "TD-TI", name: "Tibastī", kind: "Province", parent: None },
+    SubdivisionData { code: "TD-WF", name: "Wadi Fira", kind: "Province", parent: None },
+    SubdivisionData { code: "TG-C", name: "Centrale", kind: "Region", parent: None },
+    SubdivisionData { code: "TG-K", name: "Kara", kind: "Region", parent: None },
+    SubdivisionData { code: "TG-M", name: "Maritime (Région)", kind: "Region", parent: None },
+    SubdivisionData { code: "TG-P", name: "Plateaux", kind: "Region", parent: None },
+    SubdivisionData { code: "TG-S", name: "Savanes", kind: "Region", parent: None },
+    SubdivisionData { code: "TH-10", name: "Krung Thep Maha Nakhon", kind: "Metropolitan administration", parent: None },
+    SubdivisionData { code: "TH-11", name: "Samut Prakan", kind: "Province", parent: None },
+    SubdivisionData { code: "TH-12", name: "Nonthaburi", kind: "Province", parent: None },
+    SubdivisionData { code: "TH-13", name: "Pathum Thani", kind: "Province", parent: None },
+    SubdivisionData { code: "TH-14", name: "Phra Nakhon Si Ayutthaya", kind: "Province", parent: None },
+    SubdivisionData { code: "TH-15", name: "Ang Thong", kind: "Province", parent: None },
+    SubdivisionData { code: "TH-16", name: "Lop Buri", kind: "Province", parent: None },
+    SubdivisionData { code: "TH-17", name: "Sing Buri", kind: "Province", parent: None },
+    SubdivisionData { code: "TH-18", name: "Chai Nat", kind: "Province", parent: None },
+    SubdivisionData { code: "TH-19", name: "Saraburi", kind: "Province", parent: None },
+    SubdivisionData { code: "TH-20", name: "Chon Buri", kind: "Province", parent: None },
+    SubdivisionData { code: "TH-21", name: "Rayong", kind: "Province", parent: None },
+    SubdivisionData { code: "TH-22", name: "Chanthaburi", kind: "Province", parent: None },
+    SubdivisionData { code: "TH-23", name: "Trat", kind: "Province", parent: None },
+    SubdivisionData { code: "TH-24", name: "Chachoengsao", kind: "Province", parent: None },
+    SubdivisionData { code: "TH-25", name: "Prachin Buri", kind: "Province", parent: None },
+    SubdivisionData { code: "TH-26", name: "Nakhon Nayok", kind: "Province", parent: None },
+    SubdivisionData { code: "TH-27", name: "Sa Kaeo", kind: "Province", parent: None },
+    SubdivisionData { code: "TH-30", name: "Nakhon Ratchasima", kind: "Province", parent: None },
+    SubdivisionData { code: "TH-31", name: "Buri Ram", kind: "Province", parent: None },
+    SubdivisionData { code: "TH-32", name: "Surin", kind: "Province", parent: None },
+    SubdivisionData { code: "TH-33", name: "Si Sa Ket", kind: "Province", parent: None },
+    SubdivisionData { code: "TH-34", name: "Ubon Ratchathani", kind: "Province", parent: None },
+    SubdivisionData { code: "TH-35", name: "Yasothon", kind: "Province", parent: None },
+    SubdivisionData { code: "TH-36", name: "Chaiyaphum", kind: "Province", parent: None },
+    SubdivisionData { code: "TH-37", name: "Amnat Charoen", kind: "Province", parent: None },
+    SubdivisionData { code: "TH-38", name: "Bueng Kan", kind: "Province", parent: None },
+    SubdivisionData { code: "TH-39", name: "Nong Bua Lam Phu", kind: "Province", parent: None },
+    SubdivisionData { code: "TH-40", name: "Khon Kaen", kind: "Province", parent: None },
+    SubdivisionData { code: "TH-41", name: "Udon Thani", kind: "Province", parent: None },
+    SubdivisionData { code: "TH-42", name: "Loei", kind: "Province", parent: None },
+    SubdivisionData { code: "TH-43", name: "Nong Khai", kind: "Province", parent: None },
+    SubdivisionData { code: "TH-44", name: "Maha Sarakham", kind: "Province", parent: None },
+    SubdivisionData { code: "TH-45", name: "Roi Et", kind: "Province", parent: None },
+    SubdivisionData { code: "TH-46", name: "Kalasin", kind: "Province", parent: None },
+    SubdivisionData { code: "TH-47", name: "Sakon Nakhon", kind: "Province", parent: None },
+    SubdivisionData { code: "TH-48", name: "Nakhon Phanom", kind: "Province", parent: None },
+    SubdivisionData { code: "TH-49", name: "Mukdahan", kind: "Province", parent: None },
+    SubdivisionData { code: "TH-50", name: "Chiang Mai", kind: "Province", parent: None },
+    SubdivisionData { code: "TH-51", name: "Lamphun", kind: "Province", parent: None },
+    SubdivisionData { code: "TH-52", name: "Lampang", kind: "Province", parent: None },
+    SubdivisionData { code: "TH-53", name: "Uttaradit", kind: "Province", parent: None },
+    SubdivisionData { code: "TH-54", name: "Phrae", kind: "Province", parent: None },
+    SubdivisionData { code: "TH-55", name: "Nan", kind: "Province", parent: None },
+    SubdivisionData { code: "TH-56", name: "Phayao", kind: "Province", parent: None },
+    SubdivisionData { code: "TH-57", name: "Chiang Rai", kind: "Province", parent: None },
+    SubdivisionData { code: "TH-58", name: "Mae Hong Son", kind: "Province", parent: None },
+    SubdivisionData { code: "TH-60", name: "Nakhon Sawan", kind: "Province", parent: None },
+    SubdivisionData { code: "TH-61", name: "Uthai Thani", kind: "Province", parent: None },
+    SubdivisionData { code: "TH-62", name: "Kamphaeng Phet", kind: "Province", parent: None },
+    SubdivisionData { code: "TH-63", name: "Tak", kind: "Province", parent: None },
+    SubdivisionData { code: "TH-64", name: "Sukhothai", kind: "Province", parent: None },
+    SubdivisionData { code: "TH-65", name: "Phitsanulok", kind: "Province", parent: None },
+    SubdivisionData { code: "TH-66", name: "Phichit", kind: "Province", parent: None },
+    SubdivisionData { code: "TH-67", name: "Phetchabun", kind: "Province", parent: None },
+    SubdivisionData { code: "TH-70", name: "Ratchaburi", kind: "Province", parent: None },
+    SubdivisionData { code: "TH-71", name: "Kanchanaburi", kind: "Province", parent: None },
+    SubdivisionData { code: "TH-72", name: "Suphan Buri", kind: "Province", parent: None },
+    SubdivisionData { code: "TH-73", name: "Nakhon Pathom", kind: "Province", parent: None },
+    SubdivisionData { code: "TH-74", name: "Samut Sakhon", kind: "Province", parent: None },
+    SubdivisionData { code: "TH-75", name: "Samut Songkhram", kind: "Province", parent: None },
+    SubdivisionData { code: "TH-76", name: "Phetchaburi", kind: "Province", parent: None },
+    SubdivisionData { code: "TH-77", name: "Prachuap Khiri Khan", kind: "Province", parent: None },
+    SubdivisionData { code: "TH-80", name: "Nakhon Si Thammarat", kind: "Province", parent: None },
+    SubdivisionData { code: "TH-81", name: "Krabi", kind: "Province", parent: None },
+    SubdivisionData { code: "TH-82", name: "Phangnga", kind: "Province", parent: None },
+    SubdivisionData { code: "TH-83", name: "Phuket", kind: "Province", parent: None },
+    SubdivisionData { code: "TH-84", name: "Surat Thani", kind: "Province", parent: None },
+    SubdivisionData { code: "TH-85", name: "Ranong", kind: "Province", parent: None },
+    SubdivisionData { code: "TH-86", name: "Chumphon", kind: "Province", parent: None },
+    SubdivisionData { code: "TH-90", name: "Songkhla", kind: "Province", parent: None },
+    SubdivisionData { code: "TH-91", name: "Satun", kind: "Province", parent: None },
+    SubdivisionData { code: "TH-92", name: "Trang", kind: "Province", parent: None },
+    SubdivisionData { code: "TH-93", name: "Phatthalung", kind: "Province", parent: None },
+    SubdivisionData { code: "TH-94", name: "Pattani", kind: "Province", parent: None },
+    SubdivisionData { code: "TH-95", name: "Yala", kind: "Province", parent: None },
+    SubdivisionData { code: "TH-96", name: "Narathiwat", kind: "Province", parent: None },
+    SubdivisionData { code: "TH-S", name: "Phatthaya", kind: "Special administrative city", parent: None },
+    SubdivisionData { code: "TJ-DU", name: "Dushanbe", kind: "Capital territory", parent: None },
+    SubdivisionData { code: "TJ-GB", name: "Kŭhistoni Badakhshon", kind: "Autonomous region", parent: None },
+    SubdivisionData { code: "TJ-KT", name: "Khatlon", kind: "Region", parent: None },
+    SubdivisionData { code: "TJ-RA", name: "nohiyahoi tobei jumhurí", kind: "Districts under republic administration", parent: None },
+    SubdivisionData { code: "TJ-SU", name: "Sughd", kind: "Region", parent: None },
+    SubdivisionData { code: "TL-AL", name: "Aileu", kind: "Municipality", parent: None },
+    SubdivisionData { code: "TL-AN", name: "Ainaro", kind: "Municipality", parent: None },
+    SubdivisionData { code: "TL-BA", name: "Baucau", kind: "Municipality", parent: None },
+    SubdivisionData { code: "TL-BO", name: "Bobonaro", kind: "Municipality", parent: None },
+    SubdivisionData { code: "TL-CO", name: "Cova Lima", kind: "Municipality", parent: None },
+    SubdivisionData { code: "TL-DI", name: "Díli", kind: "Municipality", parent: None },
+    SubdivisionData { code: "TL-ER", name: "Ermera", kind: "Municipality", parent: None },
+    SubdivisionData { code: "TL-LA", name: "Lautein", kind: "Municipality", parent: None },
+    SubdivisionData { code: "TL-LI", name: "Likisá", kind: "Municipality", parent: None },
+    SubdivisionData { code: "TL-MF", name: "Manufahi", kind: "Municipality", parent: None },
+    SubdivisionData { code: "TL-MT", name: "Manatuto", kind: "Municipality", parent: None },
+    SubdivisionData { code: "TL-OE", name: "Oekusi-Ambenu", kind: "Special administrative region", parent: None },
+    SubdivisionData { code: "TL-VI", name: "Vikeke", kind: "Municipality", parent: None },
+    SubdivisionData { code: "TM-A", name: "Ahal", kind: "Region", parent: None },
+    SubdivisionData { code: "TM-B", name: "Balkan", kind: "Region", parent: None },
+    SubdivisionData { code: "TM-D", name: "Daşoguz", kind: "Region", parent: None },
+    SubdivisionData { code: "TM-L", name: "Lebap", kind: "Region", parent: None },
+    SubdivisionData { code: "TM-M", name: "Mary", kind: "Region", parent: None },
+    SubdivisionData { code: "TM-S", name: "Aşgabat", kind: "City", parent: None },
+    SubdivisionData { code: "TN-11", name: "Tunis", kind: "Governorate", parent: None },
+    SubdivisionData { code: "TN-12", name: "L'Ariana", kind: "Governorate", parent: None },
+    SubdivisionData { code: "TN-13", name: "Ben Arous", kind: "Governorate", parent: None },
+    SubdivisionData { code: "TN-14", name: "La Manouba", kind: "Governorate", parent: None },
+    SubdivisionData { code: "TN-21", name: "Nabeul", kind: "Governorate", parent: None },
+    SubdivisionData { code: "TN-22", name: "Zaghouan", kind: "Governorate", parent: None },
+    SubdivisionData { code: "TN-23", name: "Bizerte", kind: "Governorate", parent: None },
+    SubdivisionData { code: "TN-31", name: "Béja", kind: "Governorate", parent: None },
+    SubdivisionData { code: "TN-32", name: "Jendouba", kind: "Governorate", parent: None },
+    SubdivisionData { code: "TN-33", name: "Le Kef", kind: "Governorate", parent: None },
+    SubdivisionData { code: "TN-34", name: "Siliana", kind: "Governorate", parent: None },
+    SubdivisionData { code: "TN-41", name: "Kairouan", kind: "Governorate", parent: None },
+    SubdivisionData { code: "TN-42", name: "Kasserine", kind: "Governorate", parent: None },
+    SubdivisionData { code: "TN-43", name: "Sidi Bouzid", kind: "Governorate", parent: None },
+    SubdivisionData { code: "TN-51", name: "Sousse", kind: "Governorate", parent: None },
+    SubdivisionData { code: "TN-52", name: "Monastir", kind: "Governorate", parent: None },
+    SubdivisionData { code: "TN-53", name: "Mahdia", kind: "Governorate", parent: None },
+    SubdivisionData { code: "TN-61", name: "Sfax", kind: "Governorate", parent: None },
+    SubdivisionData { code: "TN-71", name: "Gafsa", kind: "Governorate", parent: None },
+    SubdivisionData { code: "TN-72", name: "Tozeur", kind: "Governorate", parent: None },
+    SubdivisionData { code: "TN-73", name: "Kébili", kind: "Governorate", parent: None },
+    SubdivisionData { code: "TN-81", name: "Gabès", kind: "Governorate", parent: None },
+    SubdivisionData { code: "TN-82", name: "Médenine", kind: "Governorate", parent: None },
+    SubdivisionData { code: "TN-83", name: "Tataouine", kind: "Governorate", parent: None },
+    SubdivisionData { code: "TO-01", name: "'Eua", kind: "Division", parent: None },
+    SubdivisionData { code: "TO-02", name: "Ha'apai", kind: "Division", parent: None },
+    SubdivisionData { code: "TO-03", name: "Niuas", kind: "Division", parent: None },
+    SubdivisionData { code: "TO-04", name: "Tongatapu", kind: "Division", parent: None },
+    SubdivisionData { code: "TO-05", name: "Vava'u", kind: "Division", parent: None },
+    SubdivisionData { code: "TR-01", name: "Adana", kind: "Province", parent: None },
+    SubdivisionData { code: "TR-02", name: "Adıyaman", kind: "Province", parent: None },
+    SubdivisionData { code: "TR-03", name: "Afyonkarahisar", kind: "Province", parent: None },
+    SubdivisionData { code: "TR-04", name: "Ağrı", kind: "Province", parent: None },
+    SubdivisionData { code: "TR-05", name: "Amasya", kind: "Province", parent: None },
+    SubdivisionData { code: "TR-06", name: "Ankara", kind: "Province", parent: None },
+    SubdivisionData { code: "TR-07", name: "Antalya", kind: "Province", parent: None },
+    SubdivisionData { code: "TR-08", name: "Artvin", kind: "Province", parent: None },
+    SubdivisionData { code: "TR-09", name: "Aydın", kind: "Province", parent: None },
+    SubdivisionData { code: "TR-10", name: "Balıkesir", kind: "Province", parent: None },
+    SubdivisionData { code: "TR-11", name: "Bilecik", kind: "Province", parent: None },
+    SubdivisionData { code: "TR-12", name: "Bingöl", kind: "Province", parent: None },
+    SubdivisionData { code: "TR-13", name: "Bitlis", kind: "Province", parent: None },
+    SubdivisionData { code: "TR-14", name: "Bolu", kind: "Province", parent: None },
+    SubdivisionData { code: "TR-15", name: "Burdur", kind: "Province", parent: None },
+    SubdivisionData { code: "TR-16", name: "Bursa", kind: "Province", parent: None },
+    SubdivisionData { code: "TR-17", name: "Çanakkale", kind: "Province", parent: None },
+    SubdivisionData { code: "TR-18", name: "Çankırı", kind: "Province", parent: None },
+    SubdivisionData { code: "TR-19", name: "Çorum", kind: "Province", parent: None },
+    SubdivisionData { code: "TR-20", name: "Denizli", kind: "Province", parent: None },
+    SubdivisionData { code: "TR-21", name: "Diyarbakır", kind: "Province", parent: None },
+    SubdivisionData { code: "TR-22", name: "Edirne", kind: "Province", parent: None },
+    SubdivisionData { code: "TR-23", name: "Elazığ", kind: "Province", parent: None },
+    SubdivisionData { code: "TR-24", name: "Erzincan", kind: "Province", parent: None },
+    SubdivisionData { code: "TR-25", name: "Erzurum", kind: "Province", parent: None },
+    SubdivisionData { code: "TR-26", name: "Eskişehir", kind: "Province", parent: None },
+    SubdivisionData { code: "TR-27", name: "Gaziantep", kind: "Province", parent: None },
+    SubdivisionData { code: "TR-28", name: "Giresun", kind: "Province", parent: None },
+    SubdivisionData { code: "TR-29", name: "Gümüşhane", kind: "Province", parent: None },
+    SubdivisionData { code: "TR-30", name: "Hakkâri", kind: "Province", parent: None },
+    SubdivisionData { code: "TR-31", name: "Hatay", kind: "Province", parent: None },
+    SubdivisionData { code: "TR-32", name: "Isparta", kind: "Province", parent: None },
+    SubdivisionData { code: "TR-33", name: "Mersin", kind: "Province", parent: None },
+    SubdivisionData { code: "TR-34", name: "İstanbul", kind: "Province", parent: None },
+    SubdivisionData { code: "TR-35", name: "İzmir", kind: "Province", parent: None },
+    SubdivisionData { code: "TR-36", name: "Kars", kind: "Province", parent: None },
+    SubdivisionData { code: "TR-37", name: "Kastamonu", kind: "Province", parent: None },
+    SubdivisionData { code: "TR-38", name: "Kayseri", kind: "Province", parent: None },
+    SubdivisionData { code: "TR-39", name: "Kırklareli", kind: "Province", parent: None },
+    SubdivisionData { code: "TR-40", name: "Kırşehir", kind: "Province", parent: None },
+    SubdivisionData { code: "TR-41", name: "Kocaeli", kind: "Province", parent: None },
+    SubdivisionData { code: "TR-42", name: "Konya", kind: "Province", parent: None },
+    SubdivisionData { code: "TR-43", name: "Kütahya", kind: "Province", parent: None },
+    SubdivisionData { code: "TR-44", name: "Malatya", kind: "Province", parent: None },
+    SubdivisionData { code: "TR-45", name: "Manisa", kind: "Province", parent: None },
+    SubdivisionData { code: "TR-46", name: "Kahramanmaraş", kind: "Province", parent: None },
+    SubdivisionData { code: "TR-47", name: "Mardin", kind: "Province", parent: None },
+    SubdivisionData { code: "TR-48", name: "Muğla", kind: "Province", parent: None },
+    SubdivisionData { code: "TR-49", name: "Muş", kind: "Province", parent: None },
+    SubdivisionData { code: "TR-50", name: "Nevşehir", kind: "Province", parent: None },
+    SubdivisionData { code: "TR-51", name: "Niğde", kind: "Province", parent: None },
+    SubdivisionData { code: "TR-52", name: "Ordu", kind: "Province", parent: None },
+    SubdivisionData { code: "TR-53", name: "Rize", kind: "Province", parent: None },
+    SubdivisionData { code: "TR-54", name: "Sakarya", kind: "Province", parent: None },
+    SubdivisionData { code: "TR-55", name: "Samsun", kind: "Province", parent: None },
+    SubdivisionData { code: "TR-56", name: "Siirt", kind: "Province", parent: None },
+    SubdivisionData { code: "TR-57", name: "Sinop", kind: "Province", parent: None },
+    SubdivisionData { code: "TR-58", name: "Sivas", kind: "Province", parent: None },
+    SubdivisionData { code: "TR-59", name: "Tekirdağ", kind: "Province", parent: None },
+    SubdivisionData { code: "TR-60", name: "Tokat", kind: "Province", parent: None },
+    SubdivisionData { code: "TR-61", name: "Trabzon", kind: "Province", parent: None },
+    SubdivisionData { code: "TR-62", name: "Tunceli", kind: "Province", parent: None },
+    SubdivisionData { code: "TR-63", name: "Şanlıurfa", kind: "Province", parent: None },
+    SubdivisionData { code: "TR-64", name: "Uşak", kind: "Province", parent: None },
+    SubdivisionData { code: "TR-65", name: "Van", kind: "Province", parent: None },
+    SubdivisionData { code: "TR-66", name: "Yozgat", kind: "Province", parent: None },
+    SubdivisionData { code: "TR-67", name: "Zonguldak", kind: "Province", parent: None },
+    SubdivisionData { code: "TR-68", name: "Aksaray", kind: "Province", parent: None },
+    SubdivisionData { code: "TR-69", name: "Bayburt", kind: "Province", parent: None },
+    SubdivisionData { code: "TR-70", name: "Karaman", kind: "Province", parent: None },
+    SubdivisionData { code: "TR-71", name: "Kırıkkale", kind: "Province", parent: None },
+    SubdivisionData { code: "TR-72", name: "Batman", kind: "Province", parent: None },
+    SubdivisionData { code: "TR-73", name: "Şırnak", kind: "Province", parent: None },
+    SubdivisionData { code: "TR-74", name: "Bartın", kind: "Province", parent: None },
+    SubdivisionData { code: "TR-75", name: "Ardahan", kind: "Province", parent: None },
+    SubdivisionData { code: "TR-76", name: "Iğdır", kind: "Province", parent: None },
+    SubdivisionData { code: "TR-77", name: "Yalova", kind: "Province", parent: None },
+    SubdivisionData { code: "TR-78", name: "Karabük", kind: "Province", parent: None },
+    SubdivisionData { code: "TR-79", name: "Kilis", kind: "Province", parent: None },
+    SubdivisionData { code: "TR-80", name: "Osmaniye", kind: "Province", parent: None },
+    SubdivisionData { code: "TR-81", name: "Düzce", kind: "Province", parent: None },
+    SubdivisionData { code: "TT-ARI", name: "Arima", kind: "Borough", parent: None },
+    SubdivisionData { code: "TT-CHA", name: "Chaguanas", kind: "Borough", parent: None },
+    SubdivisionData { code: "TT-CTT", name: "Couva-Tabaquite-Talparo", kind: "Region", parent: None },
+    SubdivisionData { code: "TT-DMN", name: "Diego Martin", kind: "Region", parent: None },
+    SubdivisionData { code: "TT-MRC", name: "Mayaro-Rio Claro", kind: "Region", parent: None },
+    SubdivisionData { code: "TT-PED", name: "Penal-Debe", kind: "Region", parent: None },
+    SubdivisionData { code: "TT-POS", name: "Port of Spain", kind: "City", parent: None },
+    SubdivisionData { code: "TT-PRT", name: "Princes Town", kind: "Region", parent: None },
+    SubdivisionData { code: "TT-PTF", name: "Point Fortin", kind: "Borough", parent: None },
+    SubdivisionData { code: "TT-SFO", name: "San Fernando", kind: "City", parent: None },
+    SubdivisionData { code: "TT-SGE", name: "Sangre Grande", kind: "Region", parent: None },
+    SubdivisionData { code: "TT-SIP", name: "Siparia", kind: "Region", parent: None },
+    SubdivisionData { code: "TT-SJL", name: "San Juan-Laventille", kind: "Region", parent: None },
+    SubdivisionData { code: "TT-TOB", name: "Tobago", kind: "Ward", parent: None },
+    SubdivisionData { code: "TT-TUP", name: "Tunapuna-Piarco", kind: "Region", parent: None },
+    SubdivisionData { code: "TV-FUN", name: "Funafuti", kind: "Town council", parent: None },
+    SubdivisionData { code: "TV-NIT", name: "Niutao", kind: "Island council", parent: None },
+    SubdivisionData { code: "TV-NKF", name: "Nukufetau", kind: "Island council", parent: None },
+    SubdivisionData { code: "TV-NKL", name: "Nukulaelae", kind: "Island council", parent: None },
+    SubdivisionData { code: "TV-NMA", name: "Nanumea", kind: "Island council", parent: None },
+    SubdivisionData { code: "TV-NMG", name: "Nanumaga", kind: "Island council", parent: None },
+    SubdivisionData { code: "TV-NUI", name: "Nui", kind: "Island council", parent: None },
+    SubdivisionData { code: "TV-VAI", name: "Vaitupu", kind: "Island council", parent: None },
+    SubdivisionData { code: "TW-CHA", name: "Changhua", kind: "County", parent: None },
+    SubdivisionData { code: "TW-CYI", name: "Chiayi", kind: "City", parent: None },
+    SubdivisionData { code: "TW-CYQ", name: "Chiayi", kind: "County", parent: None },
+    SubdivisionData { code: "TW-HSQ", name: "Hsinchu", kind: "County", parent: None },
+    SubdivisionData { code: "TW-HSZ", name: "Hsinchu", kind: "City", parent: None },
+    SubdivisionData { code: "TW-HUA", name: "Hualien", kind: "County", parent: None },
+    SubdivisionData { code: "TW-ILA", name: "Yilan", kind: "County", parent: None },
+    SubdivisionData { code: "TW-KEE", name: "Keelung", kind: "City", parent: None },
+    SubdivisionData { code: "TW-KHH", name: "Kaohsiung", kind: "Special municipality", parent: None },
+    SubdivisionData { code: "TW-KIN", name: "Kinmen", kind: "County", parent: None },
+    SubdivisionData { code: "TW-LIE", name: "Lienchiang", kind: "County", parent: None },
+    SubdivisionData { code: "TW-MIA", name: "Miaoli", kind: "County", parent: None },
+    SubdivisionData { code: "TW-NAN", name: "Nantou", kind: "County", parent: None },
+    SubdivisionData { code: "TW-NWT", name: "New Taipei", kind: "Special municipality", parent: None },
+    SubdivisionData { code: "TW-PEN", name: "Penghu", kind: "County", parent: None },
+    SubdivisionData { code: "TW-PIF", name: "Pingtung", kind: "County", parent: None },
+    SubdivisionData { code: "TW-TAO", name: "Taoyuan", kind: "Special municipality", parent: None },
+    SubdivisionData { code: "TW-TNN", name: "Tainan", kind: "Special municipality", parent: None },
+    SubdivisionData { code: "TW-TPE", name: "Taipei", kind: "Special municipality", parent: None },
+    SubdivisionData { code: "TW-TTT", name: "Taitung", kind: "County", parent: None },
+    SubdivisionData { code: "TW-TXG", name: "Taichung", kind: "Special municipality", parent: None },
+    SubdivisionData { code: "TW-YUN", name: "Yunlin", kind: "County", parent: None },
+    SubdivisionData { code: "TZ-01", name: "Arusha", kind: "Region", parent: None },
+    SubdivisionData { code: "TZ-02", name: "Dar es Salaam", kind: "Region", parent: None },
+    SubdivisionData { code: "TZ-03", name: "Dodoma", kind: "Region", parent: None },
+    SubdivisionData { code: "TZ-04", name: "Iringa", kind: "Region", parent: None },
+    SubdivisionData { code: "TZ-05", name: "Kagera", kind: "Region", parent: None },
+    SubdivisionData { code: "TZ-06", name: "Pemba North", kind: "Region", parent: None },
+    SubdivisionData { code: "TZ-07", name: "Zanzibar North", kind: "Region", parent: None },
+    SubdivisionData { code: "TZ-08", name: "Kigoma", kind: "Region", parent: None },
+    SubdivisionData { code: "TZ-09", name: "Kilimanjaro", kind: "Region", parent: None },
+    SubdivisionData { code: "TZ-10", name: "Pemba South", kind: "Region", parent: None },
+    SubdivisionData { code: "TZ-11", name: "Zanzibar South", kind: "Region", parent: None },
+    SubdivisionData { code: "TZ-12", name: "Lindi", kind: "Region", parent: None },
+    SubdivisionData { code: "TZ-13", name: "Mara", kind: "Region", parent: None },
+    SubdivisionData { code: "TZ-14", name: "Mbeya", kind: "Region", parent: None },
+    SubdivisionData { code: "TZ-15", name: "Zanzibar West", kind: "Region", parent: None },
+    SubdivisionData { code: "TZ-16", name: "Morogoro", kind: "Region", parent: None },
+    SubdivisionData { code: "TZ-17", name: "Mtwara", kind: "Region", parent: None },
+    SubdivisionData { code: "TZ-18", name: "Mwanza", kind: "Region", parent: None },
+    SubdivisionData { code: "TZ-19", name: "Coast", kind: "Region", parent: None },
+    SubdivisionData { code: "TZ-20", name: "Rukwa", kind: "Region", parent: None },
+    SubdivisionData { code: "TZ-21", name: "Ruvuma", kind: "Region", parent: None },
+    SubdivisionData { code: "TZ-22", name: "Shinyanga", kind: "Region", parent: None },
+    SubdivisionData { code: "TZ-23", name: "Singida", kind: "Region", parent: None },
+    SubdivisionData { code: "TZ-24", name: "Tabora", kind: "Region", parent: None },
+    SubdivisionData { code: "TZ-25", name: "Tanga", kind: "Region", parent: None },
+    SubdivisionData { code: "TZ-26", name: "Manyara", kind: "Region", parent: None },
+    SubdivisionData { code: "TZ-27", name: "Geita", kind: "Region", parent: None },
+    SubdivisionData { code: "TZ-28", name: "Katavi", kind: "Region", parent: None },
+    SubdivisionData { code: "TZ-29", name: "Njombe", kind: "Region", parent: None },
+    SubdivisionData { code: "TZ-30", name: "Simiyu", kind: "Region", parent: None },
+    SubdivisionData { code: "TZ-31", name: "Songwe", kind: "Region", parent: None },
+    SubdivisionData { code: "UA-05", name: "Vinnytska oblast", kind: "Region", parent: None },
+    SubdivisionData { code: "UA-07", name: "Volynska oblast", kind: "Region", parent: None },
+    SubdivisionData { code: "UA-09", name: "Luhanska oblast", kind: "Region", parent: None },
+    SubdivisionData { code: "UA-12", name: "Dnipropetrovska oblast", kind: "Region", parent: None },
+    SubdivisionData { code: "UA-14", name: "Donetska oblast", kind: "Region", parent: None },
+    SubdivisionData { code: "UA-18", name: "Zhytomyrska oblast", kind: "Region", parent: None },
+    SubdivisionData { code: "UA-21", name: "Zakarpatska oblast", kind: "Region", parent: None },
+    SubdivisionData { code: "UA-23", name: "Zaporizka oblast", kind: "Region", parent: None },
+    SubdivisionData { code: "UA-26", name: "Ivano-Frankivska oblast", kind: "Region", parent: None },
+    SubdivisionData { code: "UA-30", name: "Kyiv", kind: "City", parent: None },
+    SubdivisionData { code: "UA-32", name: "Kyivska oblast", kind: "Region", parent: None },
+    SubdivisionData { code: "UA-35", name: "Kirovohradska oblast", kind: "Region", parent: None },
+    SubdivisionData { code: "UA-40", name: "Sevastopol", kind: "City", parent: None },
+    SubdivisionData { code: "UA-43", name: "Avtonomna Respublika Krym", kind: "Republic", parent: None },
+    SubdivisionData { code: "UA-46", name: "Lvivska oblast", kind: "Region", parent: None },
+    SubdivisionData { code: "UA-48", name: "Mykolaivska oblast", kind: "Region", parent: None },
+    SubdivisionData { code: "UA-51", name: "Odeska oblast", kind: "Region", parent: None },
+    SubdivisionData { code: "UA-53", name: "Poltavska oblast", kind: "Region", parent: None },
+    SubdivisionData { code: "UA-56", name: "Rivnenska oblast", kind: "Region", parent: None },
+    SubdivisionData { code: "UA-59", name: "Sumska oblast", kind: "Region", parent: None },
+    SubdivisionData { code: "UA-61", name: "Ternopilska oblast", kind: "Region", parent: None },
+    SubdivisionData { code: "UA-63", name: "Kharkivska oblast", kind: "Region", parent: None },
+    SubdivisionData { code: "UA-65", name: "Khersonska oblast", kind: "Region", parent: None },
+    SubdivisionData { code: "UA-68", name: "Khmelnytska oblast", kind: "Region", parent: None },
+    SubdivisionData { code: "UA-71", name: "Cherkaska oblast", kind: "Region", parent: None },
+    SubdivisionData { code: "UA-74", name: "Chernihivska oblast", kind: "Region", parent: None },
+    SubdivisionData { code: "UA-77", name: "Chernivetska oblast", kind: "Region", parent: None },
+    SubdivisionData { code: "UG-101", name: "Kalangala", kind: "District", parent: Some("UG-C") },
+    SubdivisionData { code: "UG-102", name: "Kampala", kind: "City", parent: Some("UG-C") },
+    SubdivisionData { code: "UG-103", name: "Kiboga", kind: "District", parent: Some("UG-C") },
+    SubdivisionData { code: "UG-104", name: "Luwero", kind: "District", parent: Some("UG-C") },
+    SubdivisionData { code: "UG-105", name: "Masaka", kind: "District", parent: Some("UG-C") },
+    SubdivisionData { code: "UG-106", name: "Mpigi", kind: "District", parent: Some("UG-C") },
+    SubdivisionData { code: "UG-107", name: "Mubende", kind: "District", parent: Some("UG-C") },
+    SubdivisionData { code: "UG-108", name: "Mukono", kind: "District", parent: Some("UG-C") },
+    SubdivisionData { code: "UG-109", name: "Nakasongola", kind: "District", parent: Some("UG-C") },
+    SubdivisionData { code: "UG-110", name: "Rakai", kind: "District", parent: Some("UG-C") },
+    SubdivisionData { code: "UG-111", name: "Sembabule", kind: "District", parent: Some("UG-C") },
+    SubdivisionData { code: "UG-112", name: "Kayunga", kind: "District", parent: Some("UG-C") },
+    SubdivisionData { code: "UG-113", name: "Wakiso", kind: "District", parent: Some("UG-C") },
+    SubdivisionData { code: "UG-114", name: "Lyantonde", kind: "District", parent: Some("UG-C") },
+    SubdivisionData { code: "UG-115", name: "Mityana", kind: "District", parent: Some("UG-C") },
+    SubdivisionData { code: "UG-116", name: "Nakaseke", kind: "District", parent: Some("UG-C") },
+    SubdivisionData { code: "UG-117", name: "Buikwe", kind: "District", parent: Some("UG-C") },
+    SubdivisionData { code: "UG-118", name: "Bukomansibi", kind: "District", parent: Some("UG-C") },
+    SubdivisionData { code: "UG-119", name: "Butambala", kind: "District", parent: Some("UG-C") },
+    SubdivisionData { code: "UG-120", name: "Buvuma", kind: "District", parent: Some("UG-C") },
+    SubdivisionData { code: "UG-121", name: "Gomba", kind: "District", parent: Some("UG-C") },
+    SubdivisionData { code: "UG-122", name: "Kalungu", kind: "District", parent: Some("UG-C") },
+    SubdivisionData { code: "UG-123", name: "Kyankwanzi", kind: "District", parent: Some("UG-C") },
+    SubdivisionData { code: "UG-124", name: "Lwengo", kind: "District", parent: Some("UG-C") },
+    SubdivisionData { code: "UG-125", name: "Kyotera", kind: "District", parent: Some("UG-C") },
+    SubdivisionData { code: "UG-126", name: "Kasanda", kind: "District", parent: Some("UG-C") },
+    SubdivisionData { code: "UG-201", name: "Bugiri", kind: "District", parent: Some("UG-E") },
+    SubdivisionData { code: "UG-202", name: "Busia", kind: "District", parent: Some("UG-E") },
+    SubdivisionData { code: "UG-203", name: "Iganga", kind: "District", parent: Some("UG-E") },
+    SubdivisionData { code: "UG-204", name: "Jinja", kind: "District", parent: Some("UG-E") },
+    SubdivisionData { code: "UG-205", name: "Kamuli", kind: "District", parent: Some("UG-E") },
+    SubdivisionData { code: "UG-206", name: "Kapchorwa", kind: "District", parent: Some("UG-E") },
+    SubdivisionData { code: "UG-207", name: "Katakwi", kind: "District", parent: Some("UG-E") },
+    SubdivisionData { code: "UG-208", name: "Kumi", kind: "District", parent: Some("UG-E") },
+    SubdivisionData { code: "UG-209", name: "Mbale", kind: "District", parent: Some("UG-E") },
+    SubdivisionData { code: "UG-210", name: "Pallisa", kind: "District", parent: Some("UG-E") },
+    SubdivisionData { code: "UG-211", name: "Soroti", kind: "District", parent: Some("UG-E") },
+    SubdivisionData { code: "UG-212", name: "Tororo", kind: "District", parent: Some("UG-E") },
+    SubdivisionData { code: "UG-213", name: "Kaberamaido", kind: "District", parent: Some("UG-E") },
+    SubdivisionData { code: "UG-214", name: "Mayuge", kind: "District", parent: Some("UG-E") },
+    SubdivisionData { code: "UG-215", name: "Sironko", kind: "District", parent: Some("UG-E") },
+    SubdivisionData { code: "UG-216", name: "Amuria", kind: "District", parent: Some("UG-E") },
+    SubdivisionData { code: "UG-217", name: "Budaka", kind: "District", parent: Some("UG-E") },
+    SubdivisionData { code: "UG-218", name: "Bududa", kind: "District", parent: Some("UG-E") },
+    SubdivisionData { code: "UG-219", name: "Bukedea", kind: "District", parent: Some("UG-E") },
+    SubdivisionData { code: "UG-220", name: "Bukwo", kind: "District", parent: Some("UG-E") },
+    SubdivisionData { code: "UG-221", name: "Butaleja", kind: "District", parent: Some("UG-E") },
+    SubdivisionData { code: "UG-222", name: "Kaliro", kind: "District", parent: Some("UG-E") },
+    SubdivisionData { code: "UG-223", name: "Manafwa", kind: "District", parent: Some("UG-E") },
+    SubdivisionData { code: "UG-224", name: "Namutumba", kind: "District", parent: Some("UG-E") },
+    SubdivisionData { code: "UG-225", name: "Bulambuli", kind: "District", parent: Some("UG-E") },
+    SubdivisionData { code: "UG-226", name: "Buyende", kind: "District", parent: Some("UG-E") },
+    SubdivisionData { code: "UG-227", name: "Kibuku", kind: "District", parent: Some("UG-E") },
+    SubdivisionData { code: "UG-228", name: "Kween", kind: "District", parent: Some("UG-E") },
+    SubdivisionData { code: "UG-229", name: "Luuka", kind: "District", parent: Some("UG-E") },
+    SubdivisionData { code: "UG-230", name: "Namayingo", kind: "District", parent: Some("UG-E") },
+    SubdivisionData { code: "UG-231", name: "Ngora", kind: "District", parent: Some("UG-E") },
+    SubdivisionData { code: "UG-232", name: "Serere", kind: "District", parent: Some("UG-E") },
+    SubdivisionData { code: "UG-233", name: "Butebo", kind: "District", parent: Some("UG-E") },
+    SubdivisionData { code: "UG-234", name: "Namisindwa", kind: "District", parent: Some("UG-E") },
+    SubdivisionData { code: "UG-235", name: "Bugweri", kind: "District", parent: Some("UG-E") },
+    SubdivisionData { code: "UG-236", name: "Kapelebyong", kind: "District", parent: Some("UG-E") },
+    SubdivisionData { code: "UG-237", name: "Kalaki", kind: "District", parent: Some("UG-E") },
+    SubdivisionData { code: "UG-301", name: "Adjumani", kind: "District", parent: Some("UG-N") },
+    SubdivisionData { code: "UG-302", name: "Apac", kind: "District", parent: Some("UG-N") },
+    SubdivisionData { code: "UG-303", name: "Arua", kind: "District", parent: Some("UG-N") },
+    SubdivisionData { code: "UG-304", name: "Gulu", kind: "District", parent: Some("UG-N") },
+    SubdivisionData { code: "UG-305", name: "Kitgum", kind: "District", parent: Some("UG-N") },
+    SubdivisionData { code: "UG-306", name: "Kotido", kind: "District", parent: Some("UG-N") },
+    SubdivisionData { code: "UG-307", name: "Lira", kind: "District", parent: Some("UG-N") },
+    SubdivisionData { code: "UG-308", name: "Moroto", kind: "District", parent: Some("UG-N") },
+    SubdivisionData { code: "UG-309", name: "Moyo", kind: "District", parent: Some("UG-N") },
+    SubdivisionData { code: "UG-310", name: "Nebbi", kind: "District", parent: Some("UG-N") },
+    SubdivisionData { code: "UG-311", name: "Nakapiripirit", kind: "District", parent: Some("UG-N") },
+    SubdivisionData { code: "UG-312", name: "Pader", kind: "District", parent: Some("UG-N") },
+    SubdivisionData { code: "UG-313", name: "Yumbe", kind: "District", parent: Some("UG-N") },
+    SubdivisionData { code: "UG-314", name: "Abim", kind: "District", parent: Some("UG-N") },
+    SubdivisionData { code: "UG-315", name: "Amolatar", kind: "District", parent: Some("UG-N") },
+    SubdivisionData { code: "UG-316", name: "Amuru", kind: "District", parent: Some("UG-N") },
+    SubdivisionData { code: "UG-317", name: "Dokolo", kind: "District", parent: Some("UG-N") },
+    SubdivisionData { code: "UG-318", name: "Kaabong", kind: "District", parent: Some("UG-N") },
+    SubdivisionData { code: "UG-319", name: "Koboko", kind: "District", parent: Some("UG-N") },
+    SubdivisionData { code: "UG-320", name: "Maracha", kind: "District", parent: Some("UG-N") },
+    SubdivisionData { code: "UG-321", name: "Oyam", kind: "District", parent: Some("UG-N") },
+    SubdivisionData { code: "UG-322", name: "Agago", kind: "District", parent: Some("UG-N") },
+    SubdivisionData { code: "UG-323", name: "Alebtong", kind: "District", parent: Some("UG-N") },
+    SubdivisionData { code: "UG-324", name: "Amudat", kind: "District", parent: Some("UG-N") },
+    SubdivisionData { code: "UG-325", name: "Kole", kind: "District", parent: Some("UG-N") },
+    SubdivisionData { code: "UG-326", name: "Lamwo", kind: "District", parent: Some("UG-N") },
+    SubdivisionData { code: "UG-327", name: "Napak", kind: "District", parent: Some("UG-N") },
+    SubdivisionData { code: "UG-328", name: "Nwoya", kind: "District", parent: Some("UG-N") },
+    SubdivisionData { code: "UG-329", name: "Otuke", kind: "District", parent: Some("UG-N") },
+    SubdivisionData { code: "UG-330", name: "Zombo", kind: "District", parent: Some("UG-N") },
+    SubdivisionData { code: "UG-331", name: "Omoro", kind: "District", parent: Some("UG-N") },
+    SubdivisionData { code: "UG-332", name: "Pakwach", kind: "District", parent: Some("UG-N") },
+    SubdivisionData { code: "UG-333", name: "Kwania", kind: "District", parent: Some("UG-N") },
+    SubdivisionData { code: "UG-334", name: "Nabilatuk", kind: "District", parent: Some("UG-N") },
+    SubdivisionData { code: "UG-335", name: "Karenga", kind: "District", parent: Some("UG-N") },
+    SubdivisionData { code: "UG-336", name: "Madi-Okollo", kind: "District", parent: Some("UG-N") },
+    SubdivisionData { code: "UG-337", name: "Obongi", kind: "District", parent: Some("UG-N") },
+    SubdivisionData { code: "UG-401", name: "Bundibugyo", kind: "District", parent: Some("UG-W") },
+    SubdivisionData { code: "UG-402", name: "Bushenyi", kind: "District", parent: Some("UG-W") },
+    SubdivisionData { code: "UG-403", name: "Hoima", kind: "District", parent: Some("UG-W") },
+    SubdivisionData { code: "UG-404", name: "Kabale", kind: "District", parent: Some("UG-W") },
+    SubdivisionData { code: "UG-405", name: "Kabarole", kind: "District", parent: Some("UG-W") },
+    SubdivisionData { code: "UG-406", name: "Kasese", kind: "District", parent: Some("UG-W") },
+    SubdivisionData { code: "UG-407", name: "Kibaale", kind: "District", parent: Some("UG-W") },
+    SubdivisionData { code: "UG-408", name: "Kisoro", kind: "District", parent: Some("UG-W") },
+    SubdivisionData { code: "UG-409", name: "Masindi", kind: "District", parent: Some("UG-W") },
+    SubdivisionData { code: "UG-410", name: "Mbarara", kind: "District", parent: Some("UG-W") },
+    SubdivisionData { code: "UG-411", name: "Ntungamo", kind: "District", parent: Some("UG-W") },
+    SubdivisionData { code: "UG-412", name: "Rukungiri", kind: "District", parent: Some("UG-W") },
+    SubdivisionData { code: "UG-413", name: "Kamwenge", kind: "District", parent: Some("UG-W") },
+    SubdivisionData { code: "UG-414", name: "Kanungu", kind: "District", parent: Some("UG-W") },
+    SubdivisionData { code: "UG-415", name: "Kyenjojo", kind: "District", parent: Some("UG-W") },
+    SubdivisionData { code: "UG-416", name: "Buliisa", kind: "District", parent: Some("UG-W") },
+    SubdivisionData { code: "UG-417", name: "Ibanda", kind: "District", parent: Some("UG-W") },
+    SubdivisionData { code: "UG-418", name: "Isingiro", kind: "District", parent: Some("UG-W") },
+    SubdivisionData { code: "UG-419", name: "Kiruhura", kind: "District", parent: Some("UG-W") },
+    SubdivisionData { code: "UG-420", name: "Buhweju", kind: "District", parent: Some("UG-W") },
+    SubdivisionData { code: "UG-421", name: "Kiryandongo", kind: "District", parent: Some("UG-W") },
+    SubdivisionData { code: "UG-422", name: "Kyegegwa", kind: "District", parent: Some("UG-W") },
+    SubdivisionData { code: "UG-423", name: "Mitooma", kind: "District", parent: Some("UG-W") },
+    SubdivisionData { code: "UG-424", name: "Ntoroko", kind: "District", parent: Some("UG-W") },
+    SubdivisionData { code: "UG-425", name: "Rubirizi", kind: "District", parent: Some("UG-W") },
+    SubdivisionData { code: "UG-426", name: "Sheema", kind: "District", parent: Some("UG-W") },
+    SubdivisionData { code: "UG-427", name: "Kagadi", kind: "District", parent: Some("UG-W") },
+    SubdivisionData { code: "UG-428", name: "Kakumiro", kind: "District", parent: Some("UG-W") },
+    SubdivisionData { code: "UG-429", name: "Rubanda", kind: "District", parent: Some("UG-W") },
+    SubdivisionData { code: "UG-430", name: "Bunyangabu", kind: "District", parent: Some("UG-W") },
+    SubdivisionData { code: "UG-431", name: "Rukiga", kind: "District", parent: Some("UG-W") },
+    SubdivisionData { code: "UG-432", name: "Kikuube", kind: "District", parent: Some("UG-W") },
+    SubdivisionData { code: "UG-433", name: "Kazo", kind: "District", parent: Some("UG-W") },
+    SubdivisionData { code: "UG-434", name: "Kitagwenda", kind: "District", parent: Some("UG-W") },
+    SubdivisionData { code: "UG-435", name: "Rwampara", kind: "District", parent: Some("UG-W") },
+    SubdivisionData { code: "UG-C", name: "Central", kind: "Geographical region", parent: None },
+    SubdivisionData { code: "UG-E", name: "Eastern", kind: "Geographical region", parent: None },
+    SubdivisionData { code: "UG-N", name: "Northern", kind: "Geographical region", parent: None },
+    SubdivisionData { code: "UG-W", name: "Western", kind: "Geographical region", parent: None },
+    SubdivisionData { code: "UM-67", name: "Johnston Atoll", kind: "Islands, groups of islands", parent: None },
+    SubdivisionData { code: "UM-71", name: "Midway Islands", kind: "Islands, groups of islands", parent: None },
+    SubdivisionData { code: "UM-76", name: "Navassa Island", kind: "Islands, groups of islands", parent: None },
+    SubdivisionData { code: "UM-79", name: "Wake Island", kind: "Islands, groups of islands", parent: None },
+    SubdivisionData { code: "UM-81", name: "Baker Island", kind: "Islands, groups of islands", parent: None },
+    SubdivisionData { code: "UM-84", name: "Howland Island", kind: "Islands, groups of islands", parent: None },
+    SubdivisionData { code: "UM-86", name: "Jarvis Island", kind: "Islands, groups of islands", parent: None },
+    SubdivisionData { code: "UM-89", name: "Kingman Reef", kind: "Islands, groups of islands", parent: None },
+    SubdivisionData { code: "UM-95", name: "Palmyra Atoll", kind: "Islands, groups of islands", parent: None },
+    SubdivisionData { code: "US-AK", name: "Alaska", kind: "State", parent: None },
+    SubdivisionData { code: "US-AL", name: "Alabama", kind: "State", parent: None },
+    SubdivisionData { code: "US-AR", name: "Arkansas", kind: "State", parent: None },
+    SubdivisionData { code: "US-AS", name: "American Samoa", kind: "Outlying area", parent: None },
+    SubdivisionData { code: "US-AZ", name: "Arizona", kind: "State", parent: None },
+    SubdivisionData { code: "US-CA", name: "California", kind: "State", parent: None },
+    SubdivisionData { code: "US-CO", name: "Colorado", kind: "State", parent: None },
+    SubdivisionData { code: "US-CT", name: "Connecticut", kind: "State", parent: None },
+    SubdivisionData { code: "US-DC", name: "District of Columbia", kind: "District", parent: None },
+    SubdivisionData { code: "US-DE", name: "Delaware", kind: "State", parent: None },
+    SubdivisionData { code: "US-FL", name: "Florida", kind: "State", parent: None },
+    SubdivisionData { code: "US-GA", name: "Georgia", kind: "State", parent: None },
+    SubdivisionData { code: "US-GU", name: "Guam", kind: "Outlying area", parent: None },
+    SubdivisionData { code: "US-HI", name: "Hawaii", kind: "State", parent: None },
+    SubdivisionData { code: "US-IA", name: "Iowa", kind: "State", parent: None },
+    SubdivisionData { code: "US-ID", name: "Idaho", kind: "State", parent: None },
+    SubdivisionData { code: "US-IL", name: "Illinois", kind: "State", parent: None },
+    SubdivisionData { code: "US-IN", name: "Indiana", kind: "State", parent: None },
+    SubdivisionData { code: "US-KS", name: "Kansas", kind: "State", parent: None },
+    SubdivisionData { code: "US-KY", name: "Kentucky", kind: "State", parent: None },
+    SubdivisionData { code: "US-LA", name: "Louisiana", kind: "State", parent: None },
+    SubdivisionData { code: "US-MA", name: "Massachusetts", kind: "State", parent: None },
+    SubdivisionData { code: "US-MD", name: "Maryland", kind: "State", parent: None },
+    SubdivisionData { code: "US-ME", name: "Maine", kind: "State", parent: None },
+    SubdivisionData { code: "US-MI", name: "Michigan", kind: "State", parent: None },
+    SubdivisionData { code: "US-MN", name: "Minnesota", kind: "State", parent: None },
+    SubdivisionData { code: "US-MO", name: "Missouri", kind: "State", parent: None },
+    SubdivisionData { code: "US-MP", name: "Northern Mariana Islands", kind: "Outlying area", parent: None },
+    SubdivisionData { code: "US-MS", name: "Mississippi", kind: "State", parent: None },
+    SubdivisionData { code: "US-MT", name: "Montana", kind: "State", parent: None },
+    SubdivisionData { code: "US-NC", name: "North Carolina", kind: "State", parent: None },
+    SubdivisionData { code: "US-ND", name: "North Dakota", kind: "State", parent: None },
+    SubdivisionData { code: "US-NE", name: "Nebraska", kind: "State", parent: None },
+    SubdivisionData { code: "US-NH", name: "New Hampshire", kind: "State", parent: None },
+    SubdivisionData { code: "US-NJ", name: "New Jersey", kind: "State", parent: None },
+    SubdivisionData { code: "US-NM", name: "New Mexico", kind: "State", parent: None },
+    SubdivisionData { code: "US-NV", name: "Nevada", kind: "State", parent: None },
+    SubdivisionData { code: "US-NY", name: "New York", kind: "State", parent: None },
+    SubdivisionData { code: "US-OH", name: "Ohio", kind: "State", parent: None },
+    SubdivisionData { code: "US-OK", name: "Oklahoma", kind: "State", parent: None },
+    SubdivisionData { code: "US-OR", name: "Oregon", kind: "State", parent: None },
+    SubdivisionData { code: "US-PA", name: "Pennsylvania", kind: "State", parent: None },
+    SubdivisionData { code: "US-PR", name: "Puerto Rico", kind: "Outlying area", parent: None },
+    SubdivisionData { code: "US-RI", name: "Rhode Island", kind: "State", parent: None },
+    SubdivisionData { code: "US-SC", name: "South Carolina", kind: "State", parent: None },
+    SubdivisionData { code: "US-SD", name: "South Dakota", kind: "State", parent: None },
+    SubdivisionData { code: "US-TN", name: "Tennessee", kind: "State", parent: None },
+    SubdivisionData { code: "US-TX", name: "Texas", kind: "State", parent: None },
+    SubdivisionData { code: "US-UM", name: "United States Minor Outlying Islands", kind: "Outlying area", parent: None },
+    SubdivisionData { code: "US-UT", name: "Utah", kind: "State", parent: None },
+    SubdivisionData { code: "US-VA", name: "Virginia", kind: "State", parent: None },
+    SubdivisionData { code: "US-VI", name: "Virgin Islands, U.S.", kind: "Outlying area", parent: None },
+    SubdivisionData { code: "US-VT", name: "Vermont", kind: "State", parent: None },
+    SubdivisionData { code: "US-WA", name: "Washington", kind: "State", parent: None },
+    SubdivisionData { code: "US-WI", name: "Wisconsin", kind: "State", parent: None },
+    SubdivisionData { code: "US-WV", name: "West Virginia", kind: "State", parent: None },
+    SubdivisionData { code: "US-WY", name: "Wyoming", kind: "State", parent: None },
+    SubdivisionData { code: "UY-AR", name: "Artigas", kind: "Department", parent: None },
+    SubdivisionData { code: "UY-CA", name: "Canelones", kind: "Department", parent: None },
+    SubdivisionData { code: "UY-CL", name: "Cerro Largo", kind: "Department", parent: None },
+    SubdivisionData { code: "UY-CO", name: "Colonia", kind: "Department", parent: None },
+    SubdivisionData { code: "UY-DU", name: "Durazno", kind: "Department", parent: None },
+    SubdivisionData { code: "UY-FD", name: "Florida", kind: "Department", parent: None },
+    SubdivisionData { code: "UY-FS", name: "Flores", kind: "Department", parent: None },
+    SubdivisionData { code: "UY-LA", name: "Lavalleja", kind: "Department", parent: None },
+    SubdivisionData { code: "UY-MA", name: "Maldonado", kind: "Department", parent: None },
+    SubdivisionData { code: "UY-MO", name: "Montevideo", kind: "Department", parent: None },
+    SubdivisionData { code: "UY-PA", name: "Paysandú", kind: "Department", parent: None },
+    SubdivisionData { code: "UY-RN", name: "Río Negro", kind: "Department", parent: None },
+    SubdivisionData { code: "UY-RO", name: "Rocha", kind: "Department", parent: None },
+    SubdivisionData { code: "UY-RV", name: "Rivera", kind: "Department", parent: None },
+    SubdivisionData { code: "UY-SA", name: "Salto", kind: "Department", parent: None },
+    SubdivisionData { code: "UY-SJ", name: "San José", kind: "Department", parent: None },
+    SubdivisionData { code: "UY-SO", name: "Soriano", kind: "Department", parent: None },
+    SubdivisionData { code: "UY-TA", name: "Tacuarembó", kind: "Department", parent: None },
+    SubdivisionData { code: "UY-TT", name: "Treinta y Tres", kind: "Department", parent: None },
+    SubdivisionData { code: "UZ-AN", name: "Andijon", kind: "Region", parent: None },
+    SubdivisionData { code: "UZ-BU", name: "Buxoro", kind: "Region", parent: None },
+    SubdivisionData { code: "UZ-FA", name: "Farg‘ona", kind: "Region", parent: None },
+    SubdivisionData { code: "UZ-JI", name: "Jizzax", kind: "Region", parent: None },
+    SubdivisionData { code: "UZ-NG", name: "Namangan", kind: "Region", parent: None },
+    SubdivisionData { code: "UZ-NW", name: "Navoiy", kind: "Region", parent: None },
+    SubdivisionData { code: "UZ-QA", name: "Qashqadaryo", kind: "Region", parent: None },
+    SubdivisionData { code: "UZ-QR", name: "Qoraqalpog‘iston Respublikasi", kind: "Republic", parent: None },
+    SubdivisionData { code: "UZ-SA", name: "Samarqand", kind: "Region", parent: None },
+    SubdivisionData { code: "UZ-SI", name: "Sirdaryo", kind: "Region", parent: None },
+    SubdivisionData { code: "UZ-SU", name: "Surxondaryo", kind: "Region", parent: None },
+    SubdivisionData { code: "UZ-TK", name: "Toshkent", kind: "City", parent: None },
+    SubdivisionData { code: "UZ-TO", name: "Toshkent", kind: "Region", parent: None },
+    SubdivisionData { code: "UZ-XO", name: "Xorazm", kind: "Region", parent: None },
+    SubdivisionData { code: "VC-01", name: "Charlotte", kind: "Parish", parent: None },
+    SubdivisionData { code: "VC-02", name: "Saint Andrew", kind: "Parish", parent: None },
+    SubdivisionData { code: "VC-03", name: "Saint David", kind: "Parish", parent: None },
+    SubdivisionData { code: "VC-04", name: "Saint George", kind: "Parish", parent: None },
+    SubdivisionData { code: "VC-05", name: "Saint Patrick", kind: "Parish", parent: None },
+    SubdivisionData { code: "VC-06", name: "Grenadines", kind: "Parish", parent: None },
+    SubdivisionData { code: "VE-A", name: "Distrito Capital", kind: "Capital district", parent: None },
+    SubdivisionData { code: "VE-B", name: "Anzoátegui", kind: "State", parent: None },
+    SubdivisionData { code: "VE-C", name: "Apure", kind: "State", parent: None },
+    SubdivisionData { code: "VE-D", name: "Aragua", kind: "State", parent: None },
+    SubdivisionData { code: "VE-E", name: "Barinas", kind: "State", parent: None },
+    SubdivisionData { code: "VE-F", name: "Bolívar", kind: "State", parent: None },
+    SubdivisionData { code: "VE-G", name: "Carabobo", kind: "State", parent: None },
+    SubdivisionData { code: "VE-H", name: "Cojedes", kind: "State", parent: None },
+    SubdivisionData { code: "VE-I", name: "Falcón", kind: "State", parent: None },
+    SubdivisionData { code: "VE-J", name: "Guárico", kind: "State", parent: None },
+    SubdivisionData { code: "VE-K", name: "Lara", kind: "State", parent: None },
+    SubdivisionData { code: "VE-L", name: "Mérida", kind: "State", parent: None },
+    SubdivisionData { code: "VE-M", name: "Miranda", kind: "State", parent: None },
+    SubdivisionData { code: "VE-N", name: "Monagas", kind: "State", parent: None },
+    SubdivisionData { code: "VE-O", name: "Nueva Esparta", kind: "State", parent: None },
+    SubdivisionData { code: "VE-P", name: "Portuguesa", kind: "State", parent: None },
+    SubdivisionData { code: "VE-R", name: "Sucre", kind: "State", parent: None },
+    SubdivisionData { code: "VE-S", name: "Táchira", kind: "State", parent: None },
+    SubdivisionData { code: "VE-T", name: "Trujillo", kind: "State", parent: None },
+    SubdivisionData { code: "VE-U", name: "Yaracuy", kind: "State", parent: None },
+    SubdivisionData { code: "VE-V", name: "Zulia", kind: "State", parent: None },
+    SubdivisionData { code: "VE-W", name: "Dependencias Federales", kind: "Federal dependency", parent: None },
+    SubdivisionData { code: "VE-X", name: "La Guaira", kind: "State", parent: None },
+    SubdivisionData { code: "VE-Y", name: "Delta Amacuro", kind: "State", parent: None },
+    SubdivisionData { code: "VE-Z", name: "Amazonas", kind: "State", parent: None },
+    SubdivisionData { code: "VN-01", name: "Lai Châu", kind: "Province", parent: None },
+    SubdivisionData { code: "VN-02", name: "Lào Cai", kind: "Province", parent: None },
+    SubdivisionData { code: "VN-03", name: "Hà Giang", kind: "Province", parent: None },
+    SubdivisionData { code: "VN-04", name: "Cao Bằng", kind: "Province", parent: None },
+    SubdivisionData { code: "VN-05", name: "Sơn La", kind: "Province", parent: None },
+    SubdivisionData { code: "VN-06", name: "Yên Bái", kind: "Province", parent: None },
+    SubdivisionData { code: "VN-07", name: "Tuyên Quang", kind: "Province", parent: None },
+    SubdivisionData { code: "VN-09", name: "Lạng Sơn", kind: "Province", parent: None },
+    SubdivisionData { code: "VN-13", name: "Quảng Ninh", kind: "Province", parent: None },
+    SubdivisionData { code: "VN-14", name: "Hòa Bình", kind: "Province", parent: None },
+    SubdivisionData { code: "VN-18", name: "Ninh Bình", kind: "Province", parent: None },
+    SubdivisionData { code: "VN-20", name: "Thái Bình", kind: "Province", parent: None },
+    SubdivisionData { code: "VN-21", name: "Thanh Hóa", kind: "Province", parent: None },
+    SubdivisionData { code: "VN-22", name: "Nghệ An", kind: "Province", parent: None },
+    SubdivisionData { code: "VN-23", name: "Hà Tĩnh", kind: "Province", parent: None },
+    SubdivisionData { code: "VN-24", name: "Quảng Bình", kind: "Province", parent: None },
+    SubdivisionData { code: "VN-25", name: "Quảng Trị", kind: "Province", parent: None },
+    SubdivisionData { code: "VN-26", name: "Thừa Thiên-Huế", kind: "Province", parent: None },
+    SubdivisionData { code: "VN-27", name: "Quảng Nam", kind: "Province", parent: None },
+    SubdivisionData { code: "VN-28", name: "Kon Tum", kind: "Province", parent: None },
+    SubdivisionData { code: "VN-29", name: "Quảng Ngãi", kind: "Province", parent: None },
+    SubdivisionData { code: "VN-30", name: "Gia Lai", kind: "Province", parent: None },
+    SubdivisionData { code: "VN-31", name: "Bình Định", kind: "Province", parent: None },
+    SubdivisionData { code: "VN-32", name: "Phú Yên", kind: "Province", parent: None },
+    SubdivisionData { code: "VN-33", name: "Đắk Lắk", kind: "Province", parent: None },
+    SubdivisionData { code: "VN-34", name: "Khánh Hòa", kind: "Province", parent: None },
+    SubdivisionData { code: "VN-35", name: "Lâm Đồng", kind: "Province", parent: None },
+    SubdivisionData { code: "VN-36", name: "Ninh Thuận", kind: "Province", parent: None },
+    SubdivisionData { code: "VN-37", name: "Tây Ninh", kind: "Province", parent: None },
+    SubdivisionData { code: "VN-39", name: "Đồng Nai", kind: "Province", parent: None },
+    SubdivisionData { code: "VN-40", name: "Bình Thuận", kind: "Province", parent: None },
+    SubdivisionData { code: "VN-41", name: "Long An", kind: "Province", parent: None },
+    SubdivisionData { code: "VN-43", name: "Bà Rịa - Vũng Tàu", kind: "Province", parent: None },
+    SubdivisionData { code: "VN-44", name: "An Giang", kind: "Province", parent: None },
+    SubdivisionData { code: "VN-45", name: "Đồng Tháp", kind: "Province", parent: None },
+    SubdivisionData { code: "VN-46", name: "Tiền Giang", kind: "Province", parent: None },
+    SubdivisionData { code: "VN-47", name: "Kiến Giang", kind: "Province", parent: None },
+    SubdivisionData { code: "VN-49", name: "Vĩnh Long", kind: "Province", parent: None },
+    SubdivisionData { code: "VN-50", name: "Bến Tre", kind: "Province", parent: None },
+    SubdivisionData { code: "VN-51", name: "Trà Vinh", kind: "Province", parent: None },
+    SubdivisionData { code: "VN-52", name: "Sóc Trăng", kind: "Province", parent: None },
+    SubdivisionData { code: "VN-53", name: "Bắc Kạn", kind: "Province", parent: None },
+    SubdivisionData { code: "VN-54", name: "Bắc Giang", kind: "Province", parent: None },
+    SubdivisionData { code: "VN-55", name: "Bạc Liêu", kind: "Province", parent: None },
+    SubdivisionData { code: "VN-56", name: "Bắc Ninh", kind: "Province", parent: None },
+    SubdivisionData { code: "VN-57", name: "Bình Dương", kind: "Province", parent: None },
+    SubdivisionData { code: "VN-58", name: "Bình Phước", kind: "Province", parent: None },
+    SubdivisionData { code: "VN-59", name: "Cà Mau", kind: "Province", parent: None },
+    SubdivisionData { code: "VN-61", name: "Hải Dương", kind: "Province", parent: None },
+    SubdivisionData { code: "VN-63", name: "Hà Nam", kind: "Province", parent: None },
+    SubdivisionData { code: "VN-66", name: "Hưng Yên", kind: "Province", parent: None },
+    SubdivisionData { code: "VN-67", name: "Nam Định", kind: "Province", parent: None },
+    SubdivisionData { code: "VN-68", name: "Phú Thọ", kind: "Province", parent: None },
+    SubdivisionData { code: "VN-69", name: "Thái Nguyên", kind: "Province", parent: None },
+    SubdivisionData { code: "VN-70", name: "Vĩnh Phúc", kind: "Province", parent: None },
+    SubdivisionData { code: "VN-71", name: "Điện Biên", kind: "Province", parent: None },
+    SubdivisionData { code: "VN-72", name: "Đắk Nông", kind: "Province", parent: None },
+    SubdivisionData { code: "VN-73", name: "Hậu Giang", kind: "Province", parent: None },
+    SubdivisionData { code: "VN-CT", name: "Cần Thơ", kind: "Municipality", parent: None },
+    SubdivisionData { code: "VN-DN", name: "Đà Nẵng", kind: "Municipality", parent: None },
+    SubdivisionData { code: "VN-HN", name: "Hà Nội", kind: "Municipality", parent: None },
+    SubdivisionData { code: "VN-HP", name: "Hải Phòng", kind: "Municipality", parent: None },
+    SubdivisionData { code: "VN-SG", name: "Hồ Chí Minh", kind: "Municipality", parent: None },
+    SubdivisionData { code: "VU-MAP", name: "Malampa", kind: "Province", parent: None },
+    SubdivisionData { code: "VU-PAM", name: "Pénama", kind: "Province", parent: None },
+    SubdivisionData { code: "VU-SAM", name: "Sanma", kind: "Province", parent: None },
+    SubdivisionData { code: "VU-SEE", name: "Shéfa", kind: "Province", parent: None },
+    SubdivisionData { code: "VU-TAE", name: "Taféa", kind: "Province", parent: None },
+    SubdivisionData { code: "VU-TOB", name: "Torba", kind: "Province", parent: None },
+    SubdivisionData { code: "WF-AL", name: "Alo", kind: "Administrative precinct", parent: None },
+    SubdivisionData { code: "WF-SG", name: "Sigave", kind: "Administrative precinct", parent: None },
+    SubdivisionData { code: "WF-UV", name: "Uvea", kind: "Administrative precinct", parent: None },
+    SubdivisionData { code: "WS-AA", name: "A'ana", kind: "District", parent: None },
+    SubdivisionData { code: "WS-AL", name: "Aiga-i-le-Tai", kind: "District", parent: None },
+    SubdivisionData { code: "WS-AT", name: "Atua", kind: "District", parent: None },
+    SubdivisionData { code: "WS-FA", name: "Fa'asaleleaga", kind: "District", parent: None },
+    SubdivisionData { code: "WS-GE", name: "Gaga'emauga", kind: "District", parent: None },
+    SubdivisionData { code: "WS-GI", name: "Gagaifomauga", kind: "District", parent: None },
+    SubdivisionData { code: "WS-PA", name: "Palauli", kind: "District", parent: None },
+    SubdivisionData { code: "WS-SA", name: "Satupa'itea", kind: "District", parent: None },
+    SubdivisionData { code: "WS-TU", name: "Tuamasaga", kind: "District", parent: None },
+    SubdivisionData { code: "WS-VF", name: "Va'a-o-Fonoti", kind: "District", parent: None },
+    SubdivisionData { code: "WS-VS", name: "Vaisigano", kind: "District", parent: None },
+    SubdivisionData { code: "YE-AB", name: "Abyan", kind: "Governorate", parent: None },
+    SubdivisionData { code: "YE-AD", name: "‘Adan", kind: "Governorate", parent: None },
+    SubdivisionData { code: "YE-AM", name: "‘Amrān", kind: "Governorate", parent: None },
+    SubdivisionData { code: "YE-BA", name: "Al Bayḑā’", kind: "Governorate", parent: None },
+    SubdivisionData { code: "YE-DA", name: "Aḑ Ḑāli‘", kind: "Governorate", parent: None },
+    SubdivisionData { code: "YE-DH", name: "Dhamār", kind: "Governorate", parent: None },
+    SubdivisionData { code: "YE-HD", name: "Ḩaḑramawt", kind: "Governorate", parent: None },
+    SubdivisionData { code: "YE-HJ", name: "Ḩajjah", kind: "Governorate", parent: None },
+    SubdivisionData { code: "YE-HU", name: "Al Ḩudaydah", kind: "Governorate", parent: None },
+    SubdivisionData { code: "YE-IB", name: "Ibb", kind: "Governorate", parent: None },
+    SubdivisionData { code: "YE-JA", name: "Al Jawf", kind: "Governorate", parent: None },
+    SubdivisionData { code: "YE-LA", name: "Laḩij", kind: "Governorate", parent: None },
+    SubdivisionData { code: "YE-MA", name: "Ma’rib", kind: "Governorate", parent: None },
+    SubdivisionData { code: "YE-MR", name: "Al Mahrah", kind: "Governorate", parent: None },
+    SubdivisionData { code: "YE-MW", name: "Al Maḩwīt", kind: "Governorate", parent: None },
+    SubdivisionData { code: "YE-RA", name: "Raymah", kind: "Governorate", parent: None },
+    SubdivisionData { code: "YE-SA", name: "Amānat al ‘Āşimah", kind: "Municipality", parent: None },
+    SubdivisionData { code: "YE-SD", name: "Şāʻdah", kind: "Governorate", parent: None },
+    SubdivisionData { code: "YE-SH", name: "Shabwah", kind: "Governorate", parent: None },
+    SubdivisionData { code: "YE-SN", name: "Şanʻā’", kind: "Governorate", parent: None },
+    SubdivisionData { code: "YE-SU", name: "Arkhabīl Suquţrá", kind: "Governorate", parent: None },
+    SubdivisionData { code: "YE-TA", name: "Tāʻizz", kind: "Governorate", parent: None },
+    SubdivisionData { code: "ZA-EC", name: "Eastern Cape", kind: "Province", parent: None },
+    SubdivisionData { code: "ZA-FS", name: "Free State", kind: "Province", parent: None },
+    SubdivisionData { code: "ZA-GP", name: "Gauteng", kind: "Province", parent: None },
+    SubdivisionData { code: "ZA-KZN", name: "Kwazulu-Natal", kind: "Province", parent: None },
+    SubdivisionData { code: "ZA-LP", name: "Limpopo", kind: "Province", parent: None },
+    SubdivisionData { code: "ZA-MP", name: "Mpumalanga", kind: "Province", parent: None },
+    SubdivisionData { code: "ZA-NC", name: "Northern Cape", kind: "Province", parent: None },
+    SubdivisionData { code: "ZA-NW", name: "North-West", kind: "Province", parent: None },
+    SubdivisionData { code: "ZA-WC", name: "Western Cape", kind: "Province", parent: None },
+    SubdivisionData { code: "ZM-01", name: "Western", kind: "Province", parent: None },
+    SubdivisionData { code: "ZM-02", name: "Central", kind: "Province", parent: None },
+    SubdivisionData { code: "ZM-03", name: "Eastern", kind: "Province", parent: None },
+    SubdivisionData { code: "ZM-04", name: "Luapula", kind: "Province", parent: None },
+    SubdivisionData { code: "ZM-05", name: "Northern", kind: "Province", parent: None },
+    SubdivisionData { code: "ZM-06", name: "North-Western", kind: "Province", parent: None },
+    SubdivisionData { code: "ZM-07", name: "Southern", kind: "Province", parent: None },
+    SubdivisionData { code: "ZM-08", name: "Copperbelt", kind: "Province", parent: None },
+    SubdivisionData { code: "ZM-09", name: "Lusaka", kind: "Province", parent: None },
+    SubdivisionData { code: "ZM-10", name: "Muchinga", kind: "Province", parent: None },
+    SubdivisionData { code: "ZW-BU", name: "Bulawayo", kind: "Province", parent: None },
+    SubdivisionData { code: "ZW-HA", name: "Harare", kind: "Province", parent: None },
+    SubdivisionData { code: "ZW-MA", name: "Manicaland", kind: "Province", parent: None },
+    SubdivisionData { code: "ZW-MC", name: "Mashonaland Central", kind: "Province", parent: None },
+    SubdivisionData { code: "ZW-ME", name: "Mashonaland East", kind: "Province", parent: None },
+    SubdivisionData { code: "ZW-MI", name: "Midlands", kind: "Province", parent: None },
+    SubdivisionData { code: "ZW-MN", name: "Matabeleland North", kind: "Province", parent: None },
+    SubdivisionData { code: "ZW-MS", name: "Matabeleland South", kind: "Province", parent: None },
+    SubdivisionData { code: "ZW-MV", name: "Masvingo", kind: "Province", parent: None },
+    SubdivisionData { code: "ZW-MW", name: "Mashonaland West", kind: "Province", parent: None },
+];
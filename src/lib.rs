@@ -30,6 +30,31 @@ pub mod traits;
 /// - [Longitude](crate::geography::Longitude)
 pub mod geography;
 
+/// Numeric value objects
+///
+/// This module contains value objects that represent numbers with a meaning, such as percentages.
+/// They are stored as exact decimals, so they can be combined with [Money](crate::financial::Money) without losing precision.
+///
+/// ## Example
+///
+/// ```
+/// use valobs::numeric::Percentage;
+/// use valobs::result::ValobsResult;
+///
+/// fn main() -> ValobsResult<()> {
+///   let percentage: Percentage = "12.5%".parse()?;
+///
+///   assert_eq!(percentage.basis_points(), 1250);
+///
+///   Ok(())
+/// }
+/// ```
+///
+/// ## Value objects
+///
+/// - [Percentage](crate::numeric::Percentage)
+pub mod numeric;
+
 pub mod prelude {
     pub use crate::{
         communication::{Email, PhoneNumber},
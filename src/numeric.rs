@@ -0,0 +1,3 @@
+mod percentage;
+
+pub use percentage::Percentage;
@@ -0,0 +1,221 @@
+use crate::{
+    financial::{Money, RoundingMode},
+    result::{ValobsError, ValobsResult},
+    traits::ValueObject,
+};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::{fmt, str::FromStr};
+
+/// A value object representing a percentage, such as `12.5%`.
+///
+/// ## What is a _Percentage_?
+///
+/// A percentage is a number expressed as a fraction of 100, so `12.5%` is the fraction `0.125`.
+/// It is stored as an exact decimal, with the precision of a basis point, which is one hundredth of a percent (`0.01%`).
+/// Percentages may be negative, such as a decrease, or greater than 100, such as a growth.
+///
+/// ## When to use
+///
+/// Use it for discounts, interest rates, commissions and shares, where `f64` would lose precision.
+///
+/// ## Example
+///
+/// ```
+/// use valobs::financial::{Currency, Decimal, Money, RoundingMode};
+/// use valobs::numeric::Percentage;
+/// use valobs::result::ValobsResult;
+///
+/// fn main() -> ValobsResult<()> {
+///   let percentage: Percentage = "12.5%".parse()?;
+///
+///   assert_eq!(percentage.basis_points(), 1250);
+///   assert_eq!(percentage.fraction(), Decimal::new(125, 3));
+///
+///   // 12.5% of 10.00 USD
+///   let result = percentage.of(&Money::new(1000, Currency::USD), RoundingMode::HalfEven)?;
+///
+///   assert_eq!(result, Money::new(125, Currency::USD));
+///
+///   Ok(())
+/// }
+/// ```
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Serialize, Deserialize)]
+#[serde(try_from = "Decimal", into = "Decimal")]
+pub struct Percentage(Decimal);
+
+impl Percentage {
+    /// Create a new `Percentage` instance from a number of percent, such as `12.5` for `12.5%`.
+    ///
+    /// The number must not be more precise than a basis point, so `12.345` is rejected,
+    /// and its number of basis points must fit in `i64`.
+    pub fn new(percent: Decimal) -> ValobsResult<Percentage> {
+        let percent = percent.normalize();
+        if percent.scale() > 2 {
+            return Err(ValobsError::InvalidFormat {
+                field: "percentage",
+                value: percent.to_string(),
+            });
+        }
+        let basis_points = percent.mantissa() * 10i128.pow(2 - percent.scale());
+        if i64::try_from(basis_points).is_err() {
+            return Err(ValobsError::Overflow {
+                field: "percentage",
+            });
+        }
+        Ok(Self(percent))
+    }
+
+    /// Create a new `Percentage` instance from a number of basis points, such as `1250` for `12.5%`.
+    pub fn from_basis_points(basis_points: i64) -> Percentage {
+        Self(Decimal::new(basis_points, 2).normalize())
+    }
+
+    /// Create a new `Percentage` instance from a fraction, such as `0.125` for `12.5%`.
+    pub fn from_fraction(fraction: Decimal) -> ValobsResult<Percentage> {
+        let percent = fraction
+            .checked_mul(Decimal::ONE_HUNDRED)
+            .ok_or(ValobsError::Overflow {
+                field: "percentage",
+            })?;
+        Self::new(percent)
+    }
+
+    /// Get the number of percent, such as `12.5` for `12.5%`.
+    pub fn percent(&self) -> Decimal {
+        self.0
+    }
+
+    /// Get the number of basis points, such as `1250` for `12.5%`.
+    pub fn basis_points(&self) -> i64 {
+        (self.0.mantissa() * 10i128.pow(2 - self.0.scale())) as i64
+    }
+
+    /// Get the fraction, such as `0.125` for `12.5%`.
+    pub fn fraction(&self) -> Decimal {
+        self.0 / Decimal::ONE_HUNDRED
+    }
+
+    /// Returns `true` if the percentage is between 0% and 100%, inclusive.
+    pub fn is_proportion(&self) -> bool {
+        (Decimal::ZERO..=Decimal::ONE_HUNDRED).contains(&self.0)
+    }
+
+    /// Get this percentage of an amount of money, rounded to the minor unit of its currency.
+    pub fn of(&self, money: &Money, rounding: RoundingMode) -> ValobsResult<Money> {
+        money.multiply(self.fraction(), rounding)
+    }
+}
+
+impl ValueObject<'_> for Percentage {}
+
+impl TryFrom<Decimal> for Percentage {
+    type Error = ValobsError;
+
+    fn try_from(percent: Decimal) -> ValobsResult<Self> {
+        Self::new(percent)
+    }
+}
+
+impl From<Percentage> for Decimal {
+    fn from(percentage: Percentage) -> Self {
+        percentage.0
+    }
+}
+
+impl fmt::Display for Percentage {
+    /// Formats the percentage with a percent sign, such as `12.5%`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}%", self.0)
+    }
+}
+
+impl FromStr for Percentage {
+    type Err = ValobsError;
+
+    /// Parses a percentage from a number, with or without a percent sign, such as `12.5%` or `12.5`.
+    fn from_str(value: &str) -> ValobsResult<Self> {
+        let number = value.trim();
+        let number = number.strip_suffix('%').unwrap_or(number).trim_end();
+        let percent = Decimal::from_str_exact(number).map_err(|_| ValobsError::InvalidFormat {
+            field: "percentage",
+            value: value.into(),
+        })?;
+        Self::new(percent)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::financial::Currency;
+
+    #[test]
+    fn create_percentage() -> ValobsResult<()> {
+        // Act
+        let result = Percentage::new(Decimal::new(1250, 2))?;
+
+        // Assert
+        assert_eq!(result.percent(), Decimal::new(125, 1));
+        assert_eq!(result.basis_points(), 1250);
+        assert_eq!(result, Percentage::from_basis_points(1250));
+        assert_eq!(result, Percentage::from_fraction(Decimal::new(125, 3))?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fails_to_create_percentage_more_precise_than_basis_point() {
+        assert_eq!(
+            Percentage::new(Decimal::new(12345, 3)),
+            Err(ValobsError::InvalidFormat {
+                field: "percentage",
+                value: "12.345".into(),
+            })
+        );
+        assert!(Percentage::from_fraction(Decimal::new(1, 5)).is_err());
+    }
+
+    #[test]
+    fn take_percentage_of_money() -> ValobsResult<()> {
+        // Arrange
+        let money = Money::new(999, Currency::USD);
+
+        // Act
+        let result = Percentage::from_basis_points(1250).of(&money, RoundingMode::HalfUp)?;
+
+        // Assert
+        assert_eq!(result, Money::new(125, Currency::USD));
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse_and_display_percentage() -> ValobsResult<()> {
+        assert_eq!("12.5%".parse::<Percentage>()?.basis_points(), 1250);
+        assert_eq!("-3".parse::<Percentage>()?.basis_points(), -300);
+        assert_eq!(" 0.01 % ".parse::<Percentage>()?.basis_points(), 1);
+        assert!("twelve%".parse::<Percentage>().is_err());
+        assert_eq!(Percentage::from_basis_points(1250).to_string(), "12.5%");
+        assert_eq!(Percentage::from_basis_points(10000).to_string(), "100%");
+
+        Ok(())
+    }
+
+    #[test]
+    fn serialize_percentage_to_json() -> ValobsResult<()> {
+        // Arrange
+        let percentage = Percentage::from_basis_points(1250);
+
+        // Act
+        let json = serde_json::to_string(&percentage).unwrap();
+        let result = serde_json::from_str::<Percentage>(&json).unwrap();
+
+        // Assert
+        assert_eq!(json, r#""12.5""#);
+        assert_eq!(result, percentage);
+        assert!(serde_json::from_str::<Percentage>(r#""12.345""#).is_err());
+
+        Ok(())
+    }
+}
@@ -1 +1,444 @@
-pub type ValobsResult<T> = std::result::Result<T, String>;
+use {
+    crate::financial::{Currency, MoneyAmount},
+    std::{borrow::Cow, fmt},
+};
+
+pub type ValobsResult<T> = std::result::Result<T, ValobsError>;
+
+pub type ValidationResult<T> = std::result::Result<T, ValidationReport>;
+
+/// The error returned by every fallible constructor and operation in this crate.
+///
+/// Each variant describes one kind of failure, and carries the name of the offending field
+/// together with the offending value, so callers can match on the failure instead of parsing a message.
+///
+/// ## Example
+///
+/// ```
+/// use valobs::geography::Latitude;
+/// use valobs::result::ValobsError;
+///
+/// let result = Latitude::new(91.0);
+///
+/// assert_eq!(
+///     result,
+///     Err(ValobsError::OutOfRange {
+///         field: "latitude",
+///         value: 91.0,
+///         min: -90.0,
+///         max: 90.0,
+///     })
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum ValobsError {
+    /// The value is outside of the inclusive range between `min` and `max`.
+    OutOfRange {
+        field: &'static str,
+        value: f64,
+        min: f64,
+        max: f64,
+    },
+    /// The value is NaN.
+    NotANumber { field: &'static str },
+    /// The value is positive or negative infinity.
+    Infinite { field: &'static str, value: f64 },
+    /// The value must be greater than zero.
+    NotPositive { field: &'static str, value: f64 },
+    /// The value must be greater than or equal to zero.
+    Negative { field: &'static str, value: f64 },
+    /// The value was used as a divisor, but it is zero.
+    DivisionByZero { field: &'static str },
+    /// Two amounts of money with different currencies were combined.
+    CurrencyMismatch { expected: Currency, found: Currency },
+    /// No exchange rate is known to convert from the `base` currency to the `quote` currency.
+    MissingExchangeRate { base: Currency, quote: Currency },
+    /// The available amount is smaller than the requested amount.
+    InsufficientAmount {
+        field: &'static str,
+        available: MoneyAmount,
+        requested: MoneyAmount,
+    },
+    /// The result of an arithmetic operation does not fit in the underlying type.
+    Overflow { field: &'static str },
+    /// The number of characters is outside of the inclusive range between `min` and `max`.
+    InvalidLength {
+        field: &'static str,
+        length: usize,
+        min: usize,
+        max: usize,
+    },
+    /// The value does not match the expected format.
+    InvalidFormat { field: &'static str, value: String },
+    /// The value is well-formed, but it is not one of the known codes.
+    UnknownCode { field: &'static str, value: String },
+    /// The value is well-formed, but its check digits do not match the rest of it.
+    InvalidChecksum { field: &'static str, value: String },
+    /// The value is empty, or contains only whitespace.
+    Empty { field: &'static str },
+    /// The value is well-formed, but the field is not used in this context, such as a state in a German address.
+    NotAllowed { field: &'static str, value: String },
+    /// The total does not equal the sum of its parts, such as a gross amount that is not the net amount plus its taxes.
+    Unreconciled {
+        field: &'static str,
+        expected: MoneyAmount,
+        found: MoneyAmount,
+    },
+}
+
+impl ValobsError {
+    /// Returns the name of the field that failed.
+    pub fn field(&self) -> &'static str {
+        match self {
+            Self::OutOfRange { field, .. }
+            | Self::NotANumber { field }
+            | Self::Infinite { field, .. }
+            | Self::NotPositive { field, .. }
+            | Self::Negative { field, .. }
+            | Self::DivisionByZero { field }
+            | Self::InsufficientAmount { field, .. }
+            | Self::Overflow { field }
+            | Self::InvalidLength { field, .. }
+            | Self::InvalidFormat { field, .. }
+            | Self::UnknownCode { field, .. }
+            | Self::InvalidChecksum { field, .. }
+            | Self::Empty { field }
+            | Self::NotAllowed { field, .. }
+            | Self::Unreconciled { field, .. } => field,
+            Self::CurrencyMismatch { .. } | Self::MissingExchangeRate { .. } => "currency",
+        }
+    }
+
+    /// Returns a short, machine-readable code for the kind of failure, such as `out_of_range`.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::OutOfRange { .. } => "out_of_range",
+            Self::NotANumber { .. } => "not_a_number",
+            Self::Infinite { .. } => "infinite",
+            Self::NotPositive { .. } => "not_positive",
+            Self::Negative { .. } => "negative",
+            Self::DivisionByZero { .. } => "division_by_zero",
+            Self::CurrencyMismatch { .. } => "currency_mismatch",
+            Self::MissingExchangeRate { .. } => "missing_exchange_rate",
+            Self::InsufficientAmount { .. } => "insufficient_amount",
+            Self::Overflow { .. } => "overflow",
+            Self::InvalidLength { .. } => "invalid_length",
+            Self::InvalidFormat { .. } => "invalid_format",
+            Self::UnknownCode { .. } => "unknown_code",
+            Self::InvalidChecksum { .. } => "invalid_checksum",
+            Self::Empty { .. } => "empty",
+            Self::NotAllowed { .. } => "not_allowed",
+            Self::Unreconciled { .. } => "unreconciled",
+        }
+    }
+}
+
+impl fmt::Display for ValobsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::OutOfRange {
+                field,
+                value,
+                min,
+                max,
+            } => write!(f, "{field} must be between {min} and {max}, got {value}"),
+            Self::NotANumber { field } => write!(f, "{field} must not be NaN"),
+            Self::Infinite { field, value } => {
+                write!(f, "{field} must not be infinite, got {value}")
+            }
+            Self::NotPositive { field, value } => {
+                write!(f, "{field} must be greater than 0, got {value}")
+            }
+            Self::Negative { field, value } => {
+                write!(f, "{field} must not be negative, got {value}")
+            }
+            Self::DivisionByZero { field } => write!(f, "{field} must not be zero"),
+            Self::CurrencyMismatch { expected, found } => {
+                write!(
+                    f,
+                    "currencies must be the same, expected {expected:?}, got {found:?}"
+                )
+            }
+            Self::MissingExchangeRate { base, quote } => {
+                write!(f, "no exchange rate from {base} to {quote}")
+            }
+            Self::InsufficientAmount {
+                field,
+                available,
+                requested,
+            } => write!(
+                f,
+                "{field} must be greater than or equal to {requested}, got {available}"
+            ),
+            Self::Overflow { field } => write!(f, "{field} overflowed"),
+            Self::InvalidLength {
+                field,
+                length,
+                min,
+                max,
+            } => write!(
+                f,
+                "{field} must be between {min} and {max} characters long, got {length}"
+            ),
+            Self::InvalidFormat { field, value } => {
+                write!(f, "{field} has an invalid format: {value:?}")
+            }
+            Self::UnknownCode { field, value } => write!(f, "{field} is unknown: {value:?}"),
+            Self::InvalidChecksum { field, value } => {
+                write!(f, "{field} has an invalid checksum: {value:?}")
+            }
+            Self::Empty { field } => write!(f, "{field} must not be empty"),
+            Self::NotAllowed { field, value } => write!(f, "{field} is not allowed: {value:?}"),
+            Self::Unreconciled {
+                field,
+                expected,
+                found,
+            } => write!(
+                f,
+                "{field} must equal the sum of its parts, {expected}, got {found}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ValobsError {}
+
+/// A report of every field failure found while validating a composite value object.
+///
+/// Constructors such as [GeoLocation::new](crate::geography::GeoLocation::new) stop at the first invalid field.
+/// Their validating counterparts, such as [GeoLocation::validate](crate::geography::GeoLocation::validate),
+/// check every field instead, and return all of the failures at once in this report.
+///
+/// The report converts into [validator::ValidationErrors], so it can be returned by handlers
+/// that already report errors from the `validator` crate.
+///
+/// ## Example
+///
+/// ```
+/// use valobs::geography::GeoLocation;
+///
+/// let report = GeoLocation::validate(91.0, 60.0, 10001.0).unwrap_err();
+///
+/// assert_eq!(report.len(), 2);
+/// assert_eq!(report.fields().collect::<Vec<_>>(), vec!["latitude", "altitude"]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ValidationReport {
+    errors: Vec<ValobsError>,
+}
+
+impl ValidationReport {
+    /// Creates an empty `ValidationReport`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records the error of a failed result, and returns the value of a successful one.
+    pub fn check<T>(&mut self, result: ValobsResult<T>) -> Option<T> {
+        match result {
+            Ok(value) => Some(value),
+            Err(error) => {
+                self.push(error);
+                None
+            }
+        }
+    }
+
+    /// Records an error.
+    pub fn push(&mut self, error: ValobsError) {
+        self.errors.push(error);
+    }
+
+    /// Returns the recorded errors, in the order they were found.
+    pub fn errors(&self) -> &[ValobsError] {
+        &self.errors
+    }
+
+    /// Returns the names of the fields that failed, in the order they were found.
+    pub fn fields(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.errors.iter().map(ValobsError::field)
+    }
+
+    /// Returns the errors recorded for the given field.
+    pub fn field_errors<'a>(&'a self, field: &'a str) -> impl Iterator<Item = &'a ValobsError> {
+        self.errors
+            .iter()
+            .filter(move |error| error.field() == field)
+    }
+
+    /// Returns the number of recorded errors.
+    pub fn len(&self) -> usize {
+        self.errors.len()
+    }
+
+    /// Returns `true` if no error has been recorded.
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
+impl From<ValobsError> for ValidationReport {
+    fn from(error: ValobsError) -> Self {
+        Self {
+            errors: vec![error],
+        }
+    }
+}
+
+impl IntoIterator for ValidationReport {
+    type Item = ValobsError;
+    type IntoIter = std::vec::IntoIter<ValobsError>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.errors.into_iter()
+    }
+}
+
+impl fmt::Display for ValidationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, error) in self.errors.iter().enumerate() {
+            if index > 0 {
+                write!(f, "; ")?;
+            }
+            write!(f, "{error}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ValidationReport {}
+
+impl From<ValidationReport> for validator::ValidationErrors {
+    fn from(report: ValidationReport) -> Self {
+        let mut errors = validator::ValidationErrors::new();
+        for error in report {
+            let mut validation_error = validator::ValidationError::new(error.code());
+            validation_error.message = Some(Cow::Owned(error.to_string()));
+            match &error {
+                ValobsError::OutOfRange {
+                    value, min, max, ..
+                } => {
+                    validation_error.add_param(Cow::Borrowed("value"), value);
+                    validation_error.add_param(Cow::Borrowed("min"), min);
+                    validation_error.add_param(Cow::Borrowed("max"), max);
+                }
+                ValobsError::Infinite { value, .. }
+                | ValobsError::NotPositive { value, .. }
+                | ValobsError::Negative { value, .. } => {
+                    validation_error.add_param(Cow::Borrowed("value"), value);
+                }
+                ValobsError::InvalidLength {
+                    length, min, max, ..
+                } => {
+                    validation_error.add_param(Cow::Borrowed("length"), length);
+                    validation_error.add_param(Cow::Borrowed("min"), min);
+                    validation_error.add_param(Cow::Borrowed("max"), max);
+                }
+                ValobsError::InvalidFormat { value, .. }
+                | ValobsError::UnknownCode { value, .. }
+                | ValobsError::InvalidChecksum { value, .. }
+                | ValobsError::NotAllowed { value, .. } => {
+                    validation_error.add_param(Cow::Borrowed("value"), value);
+                }
+                ValobsError::Unreconciled {
+                    expected, found, ..
+                } => {
+                    validation_error.add_param(Cow::Borrowed("expected"), expected);
+                    validation_error.add_param(Cow::Borrowed("found"), found);
+                }
+                _ => {}
+            }
+            errors.add(error.field(), validation_error);
+        }
+        errors
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_out_of_range_error() {
+        // Arrange
+        let error = ValobsError::OutOfRange {
+            field: "latitude",
+            value: 91.0,
+            min: -90.0,
+            max: 90.0,
+        };
+
+        // Act
+        let result = error.to_string();
+
+        // Assert
+        assert_eq!(result, "latitude must be between -90 and 90, got 91");
+    }
+
+    #[test]
+    fn display_currency_mismatch_error() {
+        // Arrange
+        let error = ValobsError::CurrencyMismatch {
+            expected: Currency::USD,
+            found: Currency::EUR,
+        };
+
+        // Act
+        let result = error.to_string();
+
+        // Assert
+        assert_eq!(result, "currencies must be the same, expected USD, got EUR");
+    }
+
+    #[test]
+    fn converts_into_boxed_std_error() {
+        // Arrange
+        let error = ValobsError::NotANumber { field: "altitude" };
+
+        // Act
+        let result: Box<dyn std::error::Error> = error.into();
+
+        // Assert
+        assert_eq!(result.to_string(), "altitude must not be NaN");
+    }
+
+    #[test]
+    fn collect_errors_into_report() {
+        // Arrange
+        let mut report = ValidationReport::new();
+
+        // Act
+        let valid = report.check(Ok::<_, ValobsError>(1));
+        let invalid = report.check::<i32>(Err(ValobsError::Empty { field: "city" }));
+
+        // Assert
+        assert_eq!(valid, Some(1));
+        assert_eq!(invalid, None);
+        assert_eq!(report.len(), 1);
+        assert_eq!(report.fields().collect::<Vec<_>>(), vec!["city"]);
+        assert_eq!(report.to_string(), "city must not be empty");
+    }
+
+    #[test]
+    fn convert_report_into_validator_errors() {
+        // Arrange
+        let mut report = ValidationReport::new();
+        report.push(ValobsError::OutOfRange {
+            field: "latitude",
+            value: 91.0,
+            min: -90.0,
+            max: 90.0,
+        });
+        report.push(ValobsError::NotANumber { field: "altitude" });
+
+        // Act
+        let result = validator::ValidationErrors::from(report);
+
+        // Assert
+        let field_errors = result.field_errors();
+        assert_eq!(field_errors.len(), 2);
+        assert_eq!(field_errors["latitude"][0].code, "out_of_range");
+        assert_eq!(field_errors["latitude"][0].params["max"], 90.0);
+        assert_eq!(field_errors["altitude"][0].code, "not_a_number");
+    }
+}